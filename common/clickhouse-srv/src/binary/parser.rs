@@ -47,6 +47,7 @@ impl<'a, T: Read> Parser<T> {
         &mut self,
         hello: &Option<HelloRequest>,
         compress: bool,
+        client_revision: u64,
     ) -> Result<Packet> {
         let packet = self.reader.read_uvarint()?;
         match packet {
@@ -55,7 +56,7 @@ impl<'a, T: Read> Parser<T> {
             protocols::CLIENT_DATA | protocols::CLIENT_SCALAR => {
                 Ok(self.parse_data(packet == protocols::CLIENT_SCALAR, compress)?)
             }
-            protocols::CLIENT_QUERY => Ok(self.parse_query(hello, compress)?),
+            protocols::CLIENT_QUERY => Ok(self.parse_query(hello, compress, client_revision)?),
             protocols::CLIENT_HELLO => Ok(self.parse_hello()?),
 
             _ => Err(Error::Driver(DriverError::UnknownPacket { packet })),
@@ -66,10 +67,15 @@ impl<'a, T: Read> Parser<T> {
         Ok(Packet::Hello(HelloRequest::read_from(&mut self.reader)?))
     }
 
-    fn parse_query(&mut self, hello: &Option<HelloRequest>, _compress: bool) -> Result<Packet> {
+    fn parse_query(
+        &mut self,
+        hello: &Option<HelloRequest>,
+        _compress: bool,
+        client_revision: u64,
+    ) -> Result<Packet> {
         match hello {
             Some(ref hello) => {
-                let query = QueryRequest::read_from(&mut self.reader, hello)?;
+                let query = QueryRequest::read_from(&mut self.reader, hello, client_revision)?;
                 Ok(Packet::Query(query))
             }
             _ => Err(Error::Driver(DriverError::UnexpectedPacket)),
@@ -75,8 +75,10 @@ impl Cmd {
                 response.encode(&mut encoder, ctx.client_revision)?;
             }
             Packet::Query(query) => {
+                ctx.state.query_id = query.query_id.clone();
                 ctx.state.query = query.query.clone();
                 ctx.state.compression = query.compression;
+                ctx.state.settings = query.settings;
 
                 let session = connection.session.clone();
                 session.execute_query(ctx, connection).await?;
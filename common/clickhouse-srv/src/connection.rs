@@ -18,10 +18,11 @@ use std::sync::Arc;
 use bytes::Buf;
 use bytes::BytesMut;
 use chrono_tz::Tz;
+use tokio::io::AsyncRead;
 use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWrite;
 use tokio::io::AsyncWriteExt;
 use tokio::io::BufWriter;
-use tokio::net::TcpStream;
 
 use crate::binary::Encoder;
 use crate::binary::Parser;
@@ -35,6 +36,11 @@ use crate::types::Progress;
 use crate::CHContext;
 use crate::ClickHouseSession;
 
+/// The stream of a connection, a `TcpStream` or a TLS stream over it.
+pub trait ConnectionStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> ConnectionStream for T {}
+
 /// Send and receive `Packet` values from a remote peer.
 ///
 /// When implementing networking protocols, a message on that protocol is
@@ -53,7 +59,7 @@ pub struct Connection {
     // sufficient for our needs.
     pub buffer: BytesMut,
 
-    stream: BufWriter<TcpStream>,
+    stream: BufWriter<Box<dyn ConnectionStream>>,
     pub session: Arc<dyn ClickHouseSession>,
 
     // The buffer for reading frames.
@@ -65,14 +71,14 @@ pub struct Connection {
 impl Connection {
     /// Create a new `Connection`, backed by `socket`. Read and write buffers
     /// are initialized.
-    pub fn new(
-        stream: TcpStream,
+    pub fn new<S: ConnectionStream + 'static>(
+        stream: S,
         session: Arc<dyn ClickHouseSession>,
         timezone: String,
     ) -> Result<Connection> {
         let tz: Tz = timezone.parse()?;
         Ok(Connection {
-            stream: BufWriter::new(stream),
+            stream: BufWriter::new(Box::new(stream)),
             buffer: BytesMut::with_capacity(4 * 1024),
             session,
             tz,
@@ -132,7 +138,7 @@ impl Connection {
         let mut parser = Parser::new(&mut buf, self.tz);
 
         let hello = ctx.hello.clone();
-        let packet = parser.parse_packet(&hello, self.compress);
+        let packet = parser.parse_packet(&hello, self.compress, ctx.client_revision);
 
         match packet {
             Ok(packet) => {
@@ -172,6 +178,15 @@ impl Connection {
         Ok(())
     }
 
+    /// Server logs are sent in a separate packet, the block is never compressed.
+    pub async fn write_log_block(&mut self, block: &Block) -> Result<()> {
+        let mut encoder = Encoder::new();
+        block.send_server_log(&mut encoder);
+        self.stream.write_all(&encoder.get_buffer()).await?;
+        self.stream.flush().await?;
+        Ok(())
+    }
+
     pub async fn write_progress(&mut self, progress: Progress, client_revision: u64) -> Result<()> {
         let mut encoder = Encoder::new();
         progress.write(&mut encoder, client_revision);
@@ -17,13 +17,14 @@ use std::sync::Arc;
 use errors::Result;
 use log::debug;
 use protocols::Stage;
-use tokio::net::TcpStream;
 use tokio::sync::mpsc::Sender;
 use tokio::sync::Notify;
 
 use crate::cmd::Cmd;
 use crate::connection::Connection;
+use crate::connection::ConnectionStream;
 use crate::protocols::HelloRequest;
+use crate::protocols::QuerySetting;
 use crate::types::Block;
 use crate::types::Progress;
 
@@ -87,6 +88,8 @@ pub struct QueryState {
     pub stage: Stage,
     pub compression: u64,
     pub query: String,
+    /// The settings sent by the client along with the query.
+    pub settings: Vec<QuerySetting>,
     pub is_cancelled: bool,
     pub is_connection_closed: bool,
     /// empty or not
@@ -129,22 +132,29 @@ impl CHContext {
 pub struct ClickHouseServer {}
 
 impl ClickHouseServer {
-    pub async fn run_on_stream(
+    pub async fn run_on_stream<S: ConnectionStream + 'static>(
         session: Arc<dyn ClickHouseSession>,
-        stream: TcpStream,
+        stream: S,
     ) -> Result<()> {
         ClickHouseServer::run_on(session, stream).await
     }
 }
 
 impl ClickHouseServer {
-    async fn run_on(session: Arc<dyn ClickHouseSession>, stream: TcpStream) -> Result<()> {
+    async fn run_on<S: ConnectionStream + 'static>(
+        session: Arc<dyn ClickHouseSession>,
+        stream: S,
+    ) -> Result<()> {
         let mut srv = ClickHouseServer {};
         srv.run(session, stream).await?;
         Ok(())
     }
 
-    async fn run(&mut self, session: Arc<dyn ClickHouseSession>, stream: TcpStream) -> Result<()> {
+    async fn run<S: ConnectionStream + 'static>(
+        &mut self,
+        session: Arc<dyn ClickHouseSession>,
+        stream: S,
+    ) -> Result<()> {
         debug!("Handle New session");
         let tz = session.timezone().to_string();
         let mut ctx = CHContext::new(QueryState::default());
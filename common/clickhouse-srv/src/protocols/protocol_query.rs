@@ -23,6 +23,9 @@ use crate::errors::Result;
 const TCP: u8 = 1;
 const HTTP: u8 = 2;
 
+// The setting is important, the server must fail the query if it does not know it.
+const SETTING_FLAG_IMPORTANT: u64 = 0x01;
+
 #[derive(Default, Debug)]
 pub struct QueryClientInfo {
     pub query_kind: u8,
@@ -106,11 +109,20 @@ impl QueryClientInfo {
     }
 }
 
+/// A setting sent by the client along with the query, e.g. `clickhouse-client --max_threads=4`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuerySetting {
+    pub name: String,
+    pub value: String,
+    pub important: bool,
+}
+
 #[allow(dead_code)]
 #[derive(Default, Debug)]
 pub struct QueryRequest {
     pub(crate) query_id: String,
     pub(crate) client_info: QueryClientInfo,
+    pub(crate) settings: Vec<QuerySetting>,
     pub(crate) stage: u64,
     pub(crate) compression: u64,
     pub(crate) query: String,
@@ -120,6 +132,7 @@ impl QueryRequest {
     pub fn read_from<R: Read>(
         reader: &mut R,
         hello_request: &HelloRequest,
+        client_revision: u64,
     ) -> Result<QueryRequest> {
         let query_id = reader.read_string()?;
 
@@ -139,6 +152,22 @@ impl QueryRequest {
 
         client_info.interface = TCP;
 
+        let settings = Self::read_settings(reader, client_revision)?;
+
+        let query_protocol = QueryRequest {
+            query_id,
+            client_info,
+            settings,
+            stage: reader.read_uvarint()?,
+            compression: reader.read_uvarint()?,
+            query: reader.read_string()?,
+        };
+
+        Ok(query_protocol)
+    }
+
+    fn read_settings<R: Read>(reader: &mut R, client_revision: u64) -> Result<Vec<QuerySetting>> {
+        let mut settings = vec![];
         loop {
             let name = reader.read_string()?;
 
@@ -146,24 +175,33 @@ impl QueryRequest {
                 break;
             }
 
-            match name.as_str() {
-                "max_block_size" | "max_threads" => {
-                    let _ = reader.read_uvarint()?;
-                }
+            if client_revision >= DBMS_MIN_REVISION_WITH_SETTINGS_SERIALIZED_AS_STRINGS {
+                let flags = reader.read_uvarint()?;
+                settings.push(QuerySetting {
+                    name,
+                    value: reader.read_string()?,
+                    important: flags & SETTING_FLAG_IMPORTANT != 0,
+                });
+                continue;
+            }
+
+            // Before the settings are serialized as strings, the value is serialized by the type
+            // of the setting, so the unknown settings cannot be skipped.
+            let value = match name.as_str() {
+                "max_block_size" | "max_threads" => reader.read_uvarint()?.to_string(),
+                "send_logs_level" => reader.read_string()?,
                 _ => {
                     return Err(Error::Driver(UnknownSetting { name }));
                 }
-            }
-        }
+            };
 
-        let query_protocol = QueryRequest {
-            query_id,
-            client_info,
-            stage: reader.read_uvarint()?,
-            compression: reader.read_uvarint()?,
-            query: reader.read_string()?,
-        };
+            settings.push(QuerySetting {
+                name,
+                value,
+                important: false,
+            });
+        }
 
-        Ok(query_protocol)
+        Ok(settings)
     }
 }
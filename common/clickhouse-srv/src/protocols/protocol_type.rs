@@ -43,6 +43,8 @@ pub const SERVER_END_OF_STREAM: u64 = 5;
 pub const SERVER_PROFILE_INFO: u64 = 6;
 pub const SERVER_TOTALS: u64 = 7;
 pub const SERVER_EXTREMES: u64 = 8;
+// Server logs of the query, a block with the log entries.
+pub const SERVER_LOG: u64 = 10;
 
 pub const NO_QUERY: u8 = 0;
 pub const INITIAL_QUERY: u8 = 1;
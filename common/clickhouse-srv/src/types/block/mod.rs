@@ -397,6 +397,12 @@ impl<K: ColumnType> Block<K> {
         }
     }
 
+    pub(crate) fn send_server_log(&self, encoder: &mut Encoder) {
+        encoder.uvarint(protocols::SERVER_LOG);
+        encoder.string(""); // table name
+        self.write(encoder, false);
+    }
+
     pub(crate) fn chunks(&self, n: usize) -> ChunkIterator<K> {
         ChunkIterator::new(n, self)
     }
@@ -21,6 +21,10 @@ use crate::errors::Result;
 use crate::types::column::column_data::BoxColumnData;
 use crate::types::column::column_data::ColumnData;
 use crate::types::column::list::List;
+use crate::types::column::nullable::NullableColumnData;
+use crate::types::column::ArcColumnWrapper;
+use crate::types::column::ColumnFrom;
+use crate::types::column::ColumnWrapper;
 use crate::types::DateTimeType;
 use crate::types::SqlType;
 use crate::types::Value;
@@ -57,6 +61,57 @@ impl DateTime64ColumnData {
     }
 }
 
+/// The source of a DateTime64 column, values are counted in 10^-precision seconds.
+pub struct DateTime64Values<T> {
+    pub values: Vec<T>,
+    pub precision: u32,
+    pub tz: Tz,
+}
+
+impl ColumnFrom for DateTime64Values<i64> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let mut data = List::with_capacity(source.values.len());
+        for value in source.values {
+            data.push(value);
+        }
+        W::wrap(DateTime64ColumnData {
+            data,
+            params: (source.precision, source.tz),
+        })
+    }
+}
+
+impl ColumnFrom for DateTime64Values<Option<i64>> {
+    fn column_from<W: ColumnWrapper>(source: Self) -> W::Wrapper {
+        let n = source.values.len();
+        let mut values: Vec<i64> = Vec::with_capacity(n);
+        let mut nulls = Vec::with_capacity(n);
+
+        for value in source.values {
+            match value {
+                None => {
+                    nulls.push(1);
+                    values.push(0)
+                }
+                Some(value) => {
+                    nulls.push(0);
+                    values.push(value)
+                }
+            }
+        }
+
+        let inner = DateTime64Values {
+            values,
+            precision: source.precision,
+            tz: source.tz,
+        };
+        W::wrap(NullableColumnData {
+            inner: ColumnFrom::column_from::<ArcColumnWrapper>(inner),
+            nulls,
+        })
+    }
+}
+
 impl ColumnData for DateTime64ColumnData {
     fn sql_type(&self) -> SqlType {
         let (precision, tz) = self.params;
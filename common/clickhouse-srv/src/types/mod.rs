@@ -30,6 +30,7 @@ pub use self::block::RNil;
 pub use self::block::Row;
 pub use self::block::RowBuilder;
 pub use self::block::Rows;
+pub use self::column::datetime64::DateTime64Values;
 pub use self::column::Column;
 pub use self::column::ColumnType;
 pub use self::column::Complex;
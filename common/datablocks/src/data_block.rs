@@ -18,12 +18,14 @@ use std::sync::Arc;
 
 use common_arrow::arrow;
 use common_arrow::arrow::array::ArrayRef;
+use common_arrow::arrow::array::PrimitiveArray;
 use common_arrow::arrow::record_batch::RecordBatch;
 use common_datavalues::columns::DataColumn;
 use common_datavalues::series::IntoSeries;
 use common_datavalues::series::Series;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
 use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -146,7 +148,23 @@ impl TryFrom<DataBlock> for RecordBatch {
         let arrays = v
             .columns()
             .iter()
-            .map(|c| c.to_array().map(|series| series.get_array_ref()))
+            .zip(v.schema().fields().iter())
+            .map(|(c, f)| {
+                let series = c.to_array()?;
+                match f.data_type() {
+                    // DateTime64 is physically Int64, label it with the arrow Timestamp type.
+                    DataType::DateTime64(_, _) => {
+                        let array = series.i64()?.inner();
+                        let array = PrimitiveArray::<i64>::from_data(
+                            f.data_type().to_arrow(),
+                            array.values().clone(),
+                            array.validity().clone(),
+                        );
+                        Ok(Arc::new(array) as ArrayRef)
+                    }
+                    _ => Ok(series.get_array_ref()),
+                }
+            })
             .collect::<Result<Vec<_>>>()?;
 
         Ok(RecordBatch::try_new(Arc::new(v.schema.to_arrow()), arrays)?)
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_arrow::arrow::array::Array;
 use common_arrow::arrow::array::ArrayRef;
 use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -35,7 +36,11 @@ pub trait ArrayCast: Debug {
 }
 
 fn cast_ca(ca: &dyn Array, data_type: &DataType) -> Result<Series> {
-    let arrow_type = data_type.to_arrow();
+    // DateTime64 is labeled as arrow Timestamp, but it's physically Int64
+    let arrow_type = match data_type {
+        DataType::DateTime64(_, _) => ArrowDataType::Int64,
+        _ => data_type.to_arrow(),
+    };
     let arrow_type = get_physical_arrow_type(&arrow_type);
     // we enable ignore_overflow by default
     let array = cast::wrapping_cast(ca, arrow_type)?;
@@ -75,7 +80,9 @@ impl ArrayCast for DFNullArray {
             DataType::Int8 => Ok(DFInt8Array::full_null(self.len()).into_series()),
             DataType::Int16 => Ok(DFInt16Array::full_null(self.len()).into_series()),
             DataType::Int32 => Ok(DFInt32Array::full_null(self.len()).into_series()),
-            DataType::Int64 => Ok(DFInt64Array::full_null(self.len()).into_series()),
+            DataType::Int64 | DataType::DateTime64(_, _) => {
+                Ok(DFInt64Array::full_null(self.len()).into_series())
+            }
             DataType::Float32 => Ok(DFFloat32Array::full_null(self.len()).into_series()),
             DataType::Float64 => Ok(DFFloat64Array::full_null(self.len()).into_series()),
             DataType::String => Ok(DFStringArray::full_null(self.len()).into_series()),
@@ -68,6 +68,20 @@ impl<T: DFPrimitiveType> DFPrimitiveArray<T> {
 
         if &expected_arrow_type != arrow_type {
             match array.data_type() {
+                // i64, DateTime64 keeps the raw ticks of the timestamp
+                ArrowDataType::Timestamp(_, _) if T::data_type() == DataType::Int64 => {
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<i64>>()
+                        .expect("primitive cast should be ok");
+
+                    let array = PrimitiveArray::<i64>::from_data(
+                        expected_arrow_type,
+                        array.values().clone(),
+                        array.validity().clone(),
+                    );
+                    Self::from_arrow_array(&array)
+                }
                 // u32
                 ArrowDataType::Timestamp(x, _) => {
                     let p = precision(x);
@@ -68,11 +68,14 @@ impl DataField {
             DataType::Date16 => Some("Date16"),
             DataType::Date32 => Some("Date32"),
             DataType::DateTime32(_) => Some("DateTime32"),
+            DataType::DateTime64(_, _) => Some("DateTime64"),
             _ => None,
         };
 
+        // The timezone of DateTime64 is kept by the arrow Timestamp type, but not the precision.
         let custom_metadata = match self.data_type() {
             DataType::DateTime32(tz) => tz.clone(),
+            DataType::DateTime64(precision, _) => Some(precision.to_string()),
             _ => None,
         };
 
@@ -104,6 +107,14 @@ impl From<&ArrowField> for DataField {
                     "Date16" => dt = DataType::Date16,
                     "Date32" => dt = DataType::Date32,
                     "DateTime32" => dt = DataType::DateTime32(metatada.cloned()),
+                    "DateTime64" => {
+                        if let DataType::DateTime64(precision, tz) = dt {
+                            let precision = metatada
+                                .and_then(|m| m.parse::<u32>().ok())
+                                .unwrap_or(precision);
+                            dt = DataType::DateTime64(precision, tz)
+                        }
+                    }
                     _ => {}
                 }
             }
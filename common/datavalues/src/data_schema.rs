@@ -137,7 +137,7 @@ impl From<&ArrowSchema> for DataSchema {
             .map(|arrow_f| arrow_f.into())
             .collect::<Vec<_>>();
 
-        DataSchema::new(fields)
+        DataSchema::new_from(fields, a_schema.metadata().clone())
     }
 }
 
@@ -85,6 +85,24 @@ impl DataValue {
             }
             DataType::Boolean => try_build_array! {values},
             DataType::String => try_build_array! {String, values},
+            DataType::List(f)
+                if is_numeric(f.data_type())
+                    || matches!(f.data_type(), DataType::Boolean | DataType::String) =>
+            {
+                let item_type = f.data_type();
+                let mut builder = get_list_builder(item_type, values.len(), values.len());
+                for value in values.iter() {
+                    match value {
+                        DataValue::List(Some(v), _) => {
+                            let series = DataValue::try_into_data_array(v, item_type)?;
+                            builder.append_series(&series);
+                        }
+                        DataValue::List(None, _) | DataValue::Null => builder.append_null(),
+                        _ => unreachable!(),
+                    }
+                }
+                Ok(builder.finish().into_series())
+            }
             other => Result::Err(ErrorCode::BadDataValueType(format!(
                 "Unexpected type:{} for DataValue List",
                 other
@@ -15,6 +15,7 @@
 use core::fmt;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::TimeUnit;
 
 use crate::DataField;
 use crate::PhysicalDataType;
@@ -45,6 +46,13 @@ pub enum DataType {
     /// Option<String> indicates the timezone, if it's None, it's UTC
     DateTime32(Option<String>),
 
+    /// A 64-bit datetime representing the elapsed time since UNIX epoch (1970-01-01)
+    /// with sub-second precision (0 to 9 decimal digits), it's physical type is Int64.
+    /// The values are counted in the finest arrow TimeUnit the precision needs
+    /// (second, millisecond, microsecond or nanosecond), see `datetime64_time_unit`.
+    /// Option<String> indicates the timezone, if it's None, it's UTC
+    DateTime64(u32, Option<String>),
+
     Interval(IntervalUnit),
 
     List(Box<DataField>),
@@ -94,6 +102,9 @@ impl DataType {
             Date32 => ArrowDataType::UInt32,
             // we don't use DataType::Extension because extension types are not supported in parquet
             DateTime32(_) => ArrowDataType::UInt32,
+            DateTime64(precision, tz) => {
+                ArrowDataType::Timestamp(datetime64_time_unit(*precision), tz.clone())
+            }
             List(dt) => ArrowDataType::LargeList(Box::new(dt.to_arrow())),
             Struct(fs) => {
                 let arrows_fields = fs.iter().map(|f| f.to_arrow()).collect();
@@ -134,7 +145,15 @@ impl From<&ArrowDataType> for DataType {
             ArrowDataType::Binary | ArrowDataType::LargeBinary => DataType::String,
             ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => DataType::String,
 
-            ArrowDataType::Timestamp(_, tz) => DataType::DateTime32(tz.clone()),
+            ArrowDataType::Timestamp(unit, tz) => {
+                let precision = match unit {
+                    TimeUnit::Second => 0,
+                    TimeUnit::Millisecond => 3,
+                    TimeUnit::Microsecond => 6,
+                    TimeUnit::Nanosecond => 9,
+                };
+                DataType::DateTime64(precision, tz.clone())
+            }
             ArrowDataType::Date32 => DataType::Date16,
             ArrowDataType::Date64 => DataType::Date32,
 
@@ -153,6 +172,26 @@ impl From<&ArrowDataType> for DataType {
     }
 }
 
+/// The arrow TimeUnit the values of DateTime64(precision) are counted in.
+pub fn datetime64_time_unit(precision: u32) -> TimeUnit {
+    match precision {
+        0 => TimeUnit::Second,
+        1..=3 => TimeUnit::Millisecond,
+        4..=6 => TimeUnit::Microsecond,
+        _ => TimeUnit::Nanosecond,
+    }
+}
+
+/// The number of DateTime64(precision) ticks in one second.
+pub fn datetime64_ticks_per_second(precision: u32) -> i64 {
+    match datetime64_time_unit(precision) {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
 pub fn get_physical_arrow_type(data_type: &ArrowDataType) -> &ArrowDataType {
     if let ArrowDataType::Extension(_name, arrow_type, _extra) = data_type {
         return get_physical_arrow_type(arrow_type.as_ref());
@@ -185,6 +224,13 @@ impl fmt::Debug for DataType {
                     write!(f, "DateTime32")
                 }
             }
+            Self::DateTime64(precision, tz) => {
+                if let Some(tz) = tz {
+                    write!(f, "DateTime64({}, {:?})", precision, tz)
+                } else {
+                    write!(f, "DateTime64({})", precision)
+                }
+            }
             Self::List(arg0) => f.debug_tuple("List").field(arg0).finish(),
             Self::Struct(arg0) => f.debug_tuple("Struct").field(arg0).finish(),
             Self::String => write!(f, "String"),
@@ -61,7 +61,7 @@ pub fn is_floating(dt: &DataType) -> bool {
 pub fn is_date_or_date_time(dt: &DataType) -> bool {
     matches!(
         dt,
-        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_)
+        DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) | DataType::DateTime64(_, _)
     )
 }
 
@@ -281,7 +281,11 @@ pub fn datetime_arithmetic_coercion(
                 Ok(a)
             } else {
                 // Date minus Date or DateTime minus DateTime
-                Ok(DataType::Int32)
+                match a {
+                    // the difference of DateTime64 is counted in ticks
+                    DataType::DateTime64(_, _) => Ok(DataType::Int64),
+                    _ => Ok(DataType::Int32),
+                }
             }
         }
         _ => e,
@@ -351,6 +355,47 @@ pub fn numerical_unary_arithmetic_coercion(
     }
 }
 
+#[inline]
+pub fn numerical_math_coercion(val_type: &DataType) -> Result<DataType> {
+    // error on any non-numeric type
+    if !is_numeric(val_type) {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported math function on ({:?})",
+            val_type
+        )));
+    };
+
+    Ok(DataType::Float64)
+}
+
+#[inline]
+pub fn numerical_int_div_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    // error on any non-numeric type
+    if !is_numeric(lhs_type) || !is_numeric(rhs_type) {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported intDiv ({:?}) ({:?})",
+            lhs_type, rhs_type
+        )));
+    };
+
+    let has_signed = is_signed_numeric(lhs_type) || is_signed_numeric(rhs_type);
+    let max_size = cmp::max(numeric_byte_size(lhs_type)?, numeric_byte_size(rhs_type)?);
+    construct_numeric_type(has_signed, false, max_size)
+}
+
+#[inline]
+pub fn numerical_bitwise_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
+    // bit operations are only defined on integers
+    if !is_integer(lhs_type) || !is_integer(rhs_type) {
+        return Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported bit operation ({:?}) ({:?})",
+            lhs_type, rhs_type
+        )));
+    };
+
+    numerical_coercion(lhs_type, rhs_type, true)
+}
+
 // coercion rules for equality operations. This is a superset of all numerical coercion rules.
 pub fn equal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Result<DataType> {
     if lhs_type == rhs_type {
@@ -49,7 +49,7 @@ impl From<DataType> for PhysicalDataType {
             DataType::Int8 => Int8,
             DataType::Int16 => Int16,
             DataType::Int32 => Int32,
-            DataType::Int64 => Int64,
+            DataType::Int64 | DataType::DateTime64(_, _) => Int64,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::List(x) => List(x),
@@ -110,6 +110,7 @@ where
 pub trait DateConverter {
     fn to_date(&self, tz: &Tz) -> Date<Tz>;
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz>;
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz>;
 }
 
 impl<T> DateConverter for T
@@ -124,4 +125,11 @@ where T: AsPrimitive<i64>
     fn to_date_time(&self, tz: &Tz) -> DateTime<Tz> {
         tz.timestamp_millis(self.as_() * 1000)
     }
+
+    fn to_date_time64(&self, precision: u32, tz: &Tz) -> DateTime<Tz> {
+        let ticks = datetime64_ticks_per_second(precision);
+        let value: i64 = self.as_();
+        let nanos = value.rem_euclid(ticks) * (1_000_000_000 / ticks);
+        tz.timestamp(value.div_euclid(ticks), nanos as u32)
+    }
 }
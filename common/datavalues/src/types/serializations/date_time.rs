@@ -17,6 +17,7 @@ use std::ops::AddAssign;
 use chrono::Duration;
 use chrono::NaiveDateTime;
 use chrono::TimeZone;
+use chrono::Timelike;
 use chrono_tz::Tz;
 use common_exception::*;
 use common_io::prelude::*;
@@ -105,3 +106,90 @@ where
         self.builder.finish().into_series()
     }
 }
+
+/// Render a DateTime64 value with `precision` fractional digits, e.g. `2021-09-05 09:23:17.123`.
+pub fn format_date_time64(value: i64, precision: u32, tz: &Tz) -> String {
+    let date_time = value.to_date_time64(precision, tz);
+    let seconds = date_time.format("%Y-%m-%d %H:%M:%S");
+    match precision {
+        0 => seconds.to_string(),
+        p => {
+            let fraction = date_time.nanosecond() / 10_u32.pow(9 - p.min(9));
+            format!("{}.{:0width$}", seconds, fraction, width = p as usize)
+        }
+    }
+}
+
+pub struct DateTime64Serializer {
+    pub builder: PrimitiveArrayBuilder<i64>,
+    pub precision: u32,
+    pub tz: Tz,
+}
+
+impl TypeSerializer for DateTime64Serializer {
+    fn serialize_strings(&self, column: &DataColumn) -> Result<Vec<String>> {
+        let array = column.to_array()?;
+        let array: &DFInt64Array = array.static_cast();
+
+        let result: Vec<String> = array
+            .iter()
+            .map(|x| {
+                x.map(|v| format_date_time64(*v, self.precision, &self.tz))
+                    .unwrap_or_else(|| "NULL".to_owned())
+            })
+            .collect();
+        Ok(result)
+    }
+
+    fn de(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let value: i64 = reader.read_scalar()?;
+        self.builder.append_value(value);
+        Ok(())
+    }
+
+    fn de_batch(&mut self, reader: &[u8], step: usize, rows: usize) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            let value: i64 = reader.read_scalar()?;
+            self.builder.append_value(value);
+        }
+        Ok(())
+    }
+
+    fn de_text(&mut self, reader: &[u8]) -> Result<()> {
+        if reader.eq_ignore_ascii_case(b"null") {
+            self.builder.append_null();
+            return Ok(());
+        }
+
+        match lexical_core::parse::<i64>(reader) {
+            Ok(v) => {
+                self.builder.append_value(v);
+                Ok(())
+            }
+            Err(_) => {
+                let v = std::str::from_utf8(reader)
+                    .map_err_to_code(ErrorCode::BadBytes, || "Cannot convert value to utf8")?;
+                let res = self
+                    .tz
+                    .datetime_from_str(v, "%Y-%m-%d %H:%M:%S%.f")
+                    .map_err_to_code(ErrorCode::BadBytes, || {
+                        "Cannot parse value to DateTime64 type"
+                    })?;
+                let ticks = datetime64_ticks_per_second(self.precision);
+                let fraction = res.timestamp_subsec_nanos() as i64 / (1_000_000_000 / ticks);
+                self.builder
+                    .append_value(res.timestamp() * ticks + fraction);
+                Ok(())
+            }
+        }
+    }
+
+    fn de_null(&mut self) {
+        self.builder.append_null()
+    }
+
+    fn finish_to_series(&mut self) -> Series {
+        self.builder.finish().into_series()
+    }
+}
@@ -70,6 +70,14 @@ impl DataType {
                         tz: tz.parse::<Tz>().unwrap(),
                     }))
                 }
+                DataType::DateTime64(precision, tz) => {
+                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
+                    Ok(Box::new(DateTime64Serializer {
+                        builder: PrimitiveArrayBuilder::<i64>::with_capacity(capacity),
+                        precision,
+                        tz: tz.parse::<Tz>().unwrap(),
+                    }))
+                }
                 DataType::String => Ok(Box::new(StringSerializer {
                     builder: StringArrayBuilder::with_capacity(capacity),
                 })),
@@ -178,6 +178,14 @@ build_exceptions! {
     TLSConfigurationFailure(52),
     UnknownSession(53),
     UnexpectedError(54),
+    TooManyRecursiveIterations(55),
+    UnknownFormat(56),
+    TransactionError(57),
+    UnknownResourceGroup(58),
+    ResourceGroupAlreadyExists(59),
+    TooManyQueuedQueries(60),
+    MemoryLimitExceeded(61),
+    PermissionDenied(62),
 
     // uncategorized
     UnexpectedResponseType(600),
@@ -248,6 +256,7 @@ build_exceptions! {
     IllegalMetaState(4005),
     MetaNodeInternalError(4006),
     TruncateTableFailedError(4007),
+    CommitTableConflict(4011),
 
     // namespace error.
     NamespaceUnknownNode(4008),
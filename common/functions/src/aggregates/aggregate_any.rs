@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateAnyState {
+    pub value: Option<DataValue>,
+}
+
+impl AggregateAnyState {
+    #[inline(always)]
+    fn set(&mut self, value: DataValue, is_last: bool) {
+        if is_last || self.value.is_none() {
+            self.value = Some(value);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateAnyFunction {
+    display_name: String,
+    is_last: bool,
+    arguments: Vec<DataField>,
+}
+
+impl AggregateAnyFunction {
+    pub fn try_create(
+        display_name: &str,
+        is_last: bool,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(AggregateAnyFunction {
+            display_name: display_name.to_string(),
+            is_last,
+            arguments,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateAnyFunction {
+    fn name(&self) -> &str {
+        "AggregateAnyFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.arguments[0].data_type().clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateAnyState { value: None });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateAnyState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        if !self.is_last && state.value.is_some() {
+            return Ok(());
+        }
+
+        let mut rows: Box<dyn Iterator<Item = usize>> = if self.is_last {
+            Box::new((0..input_rows).rev())
+        } else {
+            Box::new(0..input_rows)
+        };
+        if let Some(row) = rows.find(|row| !arrays[0].is_null(*row)) {
+            state.set(arrays[0].try_get(row)?, self.is_last);
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            if arrays[0].is_null(row) {
+                continue;
+            }
+
+            let place = place.next(offset);
+            let state = place.get::<AggregateAnyState>();
+            if self.is_last || state.value.is_none() {
+                state.set(arrays[0].try_get(row)?, self.is_last);
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        state.value.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        state.value = Option::<DataValue>::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateAnyState>();
+        let rhs = rhs.get::<AggregateAnyState>();
+        if let Some(value) = &rhs.value {
+            state.set(value.clone(), self.is_last);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateAnyState>();
+        match &state.value {
+            Some(value) => Ok(value.clone()),
+            None => Ok(DataValue::from(self.arguments[0].data_type())),
+        }
+    }
+}
+
+impl fmt::Display for AggregateAnyFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_any_function(
+    is_last: bool,
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+    if !is_numeric(data_type) && !matches!(data_type, DataType::Boolean | DataType::String) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "AggregateAnyFunction does not support type '{:?}'",
+            data_type
+        )));
+    }
+    AggregateAnyFunction::try_create(display_name, is_last, arguments)
+}
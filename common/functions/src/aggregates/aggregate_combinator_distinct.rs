@@ -103,8 +103,8 @@ impl AggregateDistinctCombinator {
         let name = format!("DistinctCombinator({})", nested_name);
         assert_variadic_arguments(&name, arguments.len(), (1, 32))?;
 
-        let nested_arguments = match nested_name {
-            "count" | "uniq" => vec![],
+        let nested_arguments = match nested_name.to_lowercase().as_str() {
+            "count" | "uniq" | "uniqexact" => vec![],
             _ => arguments.clone(),
         };
 
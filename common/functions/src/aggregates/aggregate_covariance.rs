@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CovarianceKind {
+    CovarPop,
+    CovarSamp,
+    Corr,
+}
+
+struct AggregateCovarianceState {
+    pub count: u64,
+    pub mean_x: f64,
+    pub mean_y: f64,
+    pub co_moment: f64,
+    pub m2_x: f64,
+    pub m2_y: f64,
+}
+
+impl AggregateCovarianceState {
+    #[inline(always)]
+    fn add(&mut self, x: f64, y: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x += delta_x / n;
+        self.mean_y += delta_y / n;
+        self.co_moment += delta_x * (y - self.mean_y);
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean_x = other.mean_x;
+            self.mean_y = other.mean_y;
+            self.co_moment = other.co_moment;
+            self.m2_x = other.m2_x;
+            self.m2_y = other.m2_y;
+            return;
+        }
+
+        let (n1, n2) = (self.count as f64, other.count as f64);
+        let n = n1 + n2;
+        let delta_x = other.mean_x - self.mean_x;
+        let delta_y = other.mean_y - self.mean_y;
+        self.co_moment += other.co_moment + delta_x * delta_y * n1 * n2 / n;
+        self.m2_x += other.m2_x + delta_x * delta_x * n1 * n2 / n;
+        self.m2_y += other.m2_y + delta_y * delta_y * n1 * n2 / n;
+        self.mean_x += delta_x * n2 / n;
+        self.mean_y += delta_y * n2 / n;
+        self.count += other.count;
+    }
+
+    fn result(&self, kind: CovarianceKind) -> Option<f64> {
+        match kind {
+            CovarianceKind::CovarPop if self.count > 0 => Some(self.co_moment / self.count as f64),
+            CovarianceKind::CovarSamp if self.count > 1 => {
+                Some(self.co_moment / (self.count - 1) as f64)
+            }
+            CovarianceKind::Corr if self.count > 1 => {
+                Some(self.co_moment / (self.m2_x * self.m2_y).sqrt())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateCovarianceFunction {
+    display_name: String,
+    kind: CovarianceKind,
+    _arguments: Vec<DataField>,
+}
+
+impl AggregateCovarianceFunction {
+    pub fn try_create(
+        display_name: &str,
+        kind: CovarianceKind,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            kind,
+            _arguments: arguments,
+        }))
+    }
+
+    fn to_f64_series(arrays: &[Series]) -> Result<(Series, Series)> {
+        Ok((
+            arrays[0].cast_with_type(&DataType::Float64)?,
+            arrays[1].cast_with_type(&DataType::Float64)?,
+        ))
+    }
+}
+
+impl AggregateFunction for AggregateCovarianceFunction {
+    fn name(&self) -> &str {
+        "AggregateCovarianceFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateCovarianceState {
+            count: 0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            co_moment: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateCovarianceState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        let (x, y) = Self::to_f64_series(arrays)?;
+
+        x.f64()?
+            .into_iter()
+            .zip(y.f64()?.into_iter())
+            .for_each(|(x, y)| {
+                if let (Some(x), Some(y)) = (x, y) {
+                    state.add(*x, *y);
+                }
+            });
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let (x, y) = Self::to_f64_series(arrays)?;
+
+        x.f64()?
+            .into_iter()
+            .zip(y.f64()?.into_iter())
+            .zip(places.iter())
+            .for_each(|((x, y), place)| {
+                if let (Some(x), Some(y)) = (x, y) {
+                    let place = place.next(offset);
+                    let state = place.get::<AggregateCovarianceState>();
+                    state.add(*x, *y);
+                }
+            });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        state.count.serialize_to_buf(writer)?;
+        state.mean_x.serialize_to_buf(writer)?;
+        state.mean_y.serialize_to_buf(writer)?;
+        state.co_moment.serialize_to_buf(writer)?;
+        state.m2_x.serialize_to_buf(writer)?;
+        state.m2_y.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        state.count = u64::deserialize(reader)?;
+        state.mean_x = f64::deserialize(reader)?;
+        state.mean_y = f64::deserialize(reader)?;
+        state.co_moment = f64::deserialize(reader)?;
+        state.m2_x = f64::deserialize(reader)?;
+        state.m2_y = f64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateCovarianceState>();
+        let rhs = rhs.get::<AggregateCovarianceState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateCovarianceState>();
+        Ok(DataValue::Float64(state.result(self.kind)))
+    }
+}
+
+impl fmt::Display for AggregateCovarianceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_covariance_function(
+    kind: CovarianceKind,
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    for argument in arguments.iter() {
+        if !is_numeric(argument.data_type()) {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "AggregateCovarianceFunction does not support type '{:?}'",
+                argument.data_type()
+            )));
+        }
+    }
+    AggregateCovarianceFunction::try_create(display_name, kind, arguments)
+}
@@ -176,6 +176,212 @@ fn test_aggregate_function() -> Result<()> {
             expect: DataValue::Float64(Some(1.118033988749895)),
             error: "",
         },
+        Test {
+            name: "var-pop-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_pop",
+            func_name: "var_pop",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.25)),
+            error: "",
+        },
+        Test {
+            name: "var-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_samp",
+            func_name: "var_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.6666666666666667)),
+            error: "",
+        },
+        Test {
+            name: "stddev-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "stddev_samp",
+            func_name: "stddev_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(1.2909944487358056)),
+            error: "",
+        },
+        Test {
+            name: "covar-pop-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "covar_pop",
+            func_name: "covar_pop",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.25)),
+            error: "",
+        },
+        Test {
+            name: "covar-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "covar_samp",
+            func_name: "covar_samp",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.6666666666666667)),
+            error: "",
+        },
+        Test {
+            name: "corr-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: args.clone(),
+            display: "corr",
+            func_name: "corr",
+            arrays: arrays.clone(),
+            expect: DataValue::Float64(Some(-1.0)),
+            error: "",
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantile-notpassed",
+            eval_nums: 1,
+            params: vec![DataValue::UInt8(Some(2))],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "Code: 6, displayText = quantile expect level to be in [0, 1], but got 2.",
+        },
+        Test {
+            name: "median-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "median",
+            func_name: "median",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "quantileTDigest-passed",
+            eval_nums: 2,
+            params: vec![DataValue::Float64(Some(0.5))],
+            args: vec![args[0].clone()],
+            display: "quantileTDigest",
+            func_name: "quantileTDigest",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(Some(2.5)),
+            error: "",
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "uniqExact-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "uniqExact",
+            func_name: "uniqExact",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(4)),
+            error: "",
+        },
+        Test {
+            name: "anyLast-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "anyLast",
+            func_name: "anyLast",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(Some(1)),
+            error: "",
+        },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Int64(Some(4)),
+                    DataValue::Int64(Some(3)),
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(Some(1)),
+                ]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "groupUniqArray-passed",
+            eval_nums: 2,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupUniqArray",
+            func_name: "groupUniqArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![
+                    DataValue::Int64(Some(4)),
+                    DataValue::Int64(Some(3)),
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(Some(1)),
+                ]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
+        Test {
+            name: "topK-passed",
+            eval_nums: 2,
+            params: vec![DataValue::UInt8(Some(2))],
+            args: vec![args[0].clone()],
+            display: "topK",
+            func_name: "topK",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(
+                Some(vec![DataValue::Int64(Some(4)), DataValue::Int64(Some(3))]),
+                DataType::Int64,
+            ),
+            error: "",
+        },
     ];
 
     for t in tests {
@@ -360,6 +566,72 @@ fn test_aggregate_function_on_empty_data() -> Result<()> {
             expect: DataValue::Float64(None),
             error: "",
         },
+        Test {
+            name: "var-samp-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "var_samp",
+            func_name: "var_samp",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "covar-pop-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone(), args[1].clone()],
+            display: "covar_pop",
+            func_name: "covar_pop",
+            arrays: vec![arrays[0].clone(), arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "quantile-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "quantile",
+            func_name: "quantile",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Float64(None),
+            error: "",
+        },
+        Test {
+            name: "approx-count-distinct-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "approx_count_distinct",
+            func_name: "approx_count_distinct",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::UInt64(Some(0)),
+            error: "",
+        },
+        Test {
+            name: "any-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "any",
+            func_name: "any",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::Int64(None),
+            error: "",
+        },
+        Test {
+            name: "groupArray-passed",
+            eval_nums: 1,
+            params: vec![],
+            args: vec![args[0].clone()],
+            display: "groupArray",
+            func_name: "groupArray",
+            arrays: vec![arrays[0].clone()],
+            expect: DataValue::List(Some(vec![]), DataType::Int64),
+            error: "",
+        },
     ];
 
     for t in tests {
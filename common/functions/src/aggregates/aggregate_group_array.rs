@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+struct AggregateGroupArrayState {
+    pub values: Vec<DataValue>,
+    // Only filled for groupUniqArray.
+    pub seen: HashSet<DataGroupValue>,
+}
+
+impl AggregateGroupArrayState {
+    fn add(&mut self, value: DataValue, distinct: bool, max_size: usize) -> Result<()> {
+        if self.values.len() >= max_size {
+            return Ok(());
+        }
+        if distinct && !self.seen.insert(DataGroupValue::try_from(&value)?) {
+            return Ok(());
+        }
+        self.values.push(value);
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateGroupArrayFunction {
+    display_name: String,
+    distinct: bool,
+    max_size: usize,
+    arguments: Vec<DataField>,
+}
+
+impl AggregateGroupArrayFunction {
+    pub fn try_create(
+        display_name: &str,
+        distinct: bool,
+        max_size: usize,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(AggregateGroupArrayFunction {
+            display_name: display_name.to_string(),
+            distinct,
+            max_size,
+            arguments,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateGroupArrayFunction {
+    fn name(&self) -> &str {
+        "AggregateGroupArrayFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            self.arguments[0].data_type().clone(),
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateGroupArrayState {
+            values: vec![],
+            seen: HashSet::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateGroupArrayState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        for row in 0..input_rows {
+            if !arrays[0].is_null(row) {
+                state.add(arrays[0].try_get(row)?, self.distinct, self.max_size)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            if !arrays[0].is_null(row) {
+                let place = place.next(offset);
+                let state = place.get::<AggregateGroupArrayState>();
+                state.add(arrays[0].try_get(row)?, self.distinct, self.max_size)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        writer.write_uvarint(state.values.len() as u64)?;
+        for value in state.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        state.values.clear();
+        state.seen.clear();
+
+        let size = reader.read_uvarint()?;
+        for _i in 0..size {
+            let value = DataValue::deserialize(reader)?;
+            state.add(value, self.distinct, self.max_size)?;
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateGroupArrayState>();
+        let rhs = rhs.get::<AggregateGroupArrayState>();
+        for value in rhs.values.iter() {
+            state.add(value.clone(), self.distinct, self.max_size)?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateGroupArrayState>();
+        Ok(DataValue::List(
+            Some(state.values.clone()),
+            self.arguments[0].data_type().clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateGroupArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_group_array_function(
+    distinct: bool,
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let max_size = match params.len() {
+        0 => usize::MAX,
+        1 => params[0].as_u64()? as usize,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )))
+        }
+    };
+
+    let data_type = arguments[0].data_type();
+    if !is_numeric(data_type) && !matches!(data_type, DataType::Boolean | DataType::String) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "AggregateGroupArrayFunction does not support type '{:?}'",
+            data_type
+        )));
+    }
+    AggregateGroupArrayFunction::try_create(display_name, distinct, max_size, arguments)
+}
@@ -0,0 +1,385 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+pub trait AggregateQuantileState: Send + Sync + 'static {
+    fn default() -> Self;
+    fn add(&mut self, value: f64);
+    fn merge(&mut self, rhs: &Self);
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()>;
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()>;
+    fn quantile(&mut self, level: f64) -> Option<f64>;
+}
+
+/// Keeps every value, the result is interpolated between the two closest ranks.
+pub struct QuantileExactState {
+    values: Vec<f64>,
+}
+
+impl AggregateQuantileState for QuantileExactState {
+    fn default() -> Self {
+        Self { values: vec![] }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.values.push(value);
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        self.values.extend_from_slice(&rhs.values);
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.values.len() as u64)?;
+        for value in self.values.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let size = reader.read_uvarint()? as usize;
+        self.values = Vec::with_capacity(size);
+        for _i in 0..size {
+            self.values.push(f64::deserialize(reader)?);
+        }
+        Ok(())
+    }
+
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        if self.values.is_empty() {
+            return None;
+        }
+        self.values
+            .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let pos = level * (self.values.len() - 1) as f64;
+        let lower = pos.floor() as usize;
+        let upper = pos.ceil() as usize;
+        let (lo, hi) = (self.values[lower], self.values[upper]);
+        Some(lo + (hi - lo) * (pos - lower as f64))
+    }
+}
+
+const TDIGEST_COMPRESSION: f64 = 100.0;
+const TDIGEST_BUFFER_SIZE: usize = 512;
+
+/// A merging t-digest: values are buffered and periodically folded into a bounded
+/// number of centroids, so the state size does not grow with the input.
+pub struct QuantileTDigestState {
+    centroids: Vec<(f64, f64)>,
+    buffer: Vec<f64>,
+}
+
+impl QuantileTDigestState {
+    fn total_weight(&self) -> f64 {
+        self.centroids.iter().map(|(_, weight)| weight).sum()
+    }
+
+    fn compress(&mut self) {
+        let mut points = std::mem::take(&mut self.centroids);
+        points.extend(self.buffer.drain(..).map(|value| (value, 1.0)));
+        points.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+        let total: f64 = points.iter().map(|(_, weight)| weight).sum();
+        let mut merged: Vec<(f64, f64)> = Vec::with_capacity(TDIGEST_COMPRESSION as usize);
+        let mut seen = 0.0;
+
+        for (mean, weight) in points {
+            if let Some(last) = merged.last_mut() {
+                // The scale function k = q * (1 - q) keeps the tails precise.
+                let q = (seen + (last.1 + weight) / 2.0) / total;
+                let limit = 4.0 * total * q * (1.0 - q) / TDIGEST_COMPRESSION;
+                if last.1 + weight <= limit.max(1.0) {
+                    last.0 += (mean - last.0) * weight / (last.1 + weight);
+                    last.1 += weight;
+                    continue;
+                }
+                seen += last.1;
+            }
+            merged.push((mean, weight));
+        }
+        self.centroids = merged;
+    }
+}
+
+impl AggregateQuantileState for QuantileTDigestState {
+    fn default() -> Self {
+        Self {
+            centroids: vec![],
+            buffer: vec![],
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.buffer.push(value);
+        if self.buffer.len() >= TDIGEST_BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    fn merge(&mut self, rhs: &Self) {
+        self.centroids.extend_from_slice(&rhs.centroids);
+        self.buffer.extend_from_slice(&rhs.buffer);
+        self.compress();
+    }
+
+    fn serialize(&self, writer: &mut BytesMut) -> Result<()> {
+        writer.write_uvarint(self.centroids.len() as u64)?;
+        for (mean, weight) in self.centroids.iter() {
+            mean.serialize_to_buf(writer)?;
+            weight.serialize_to_buf(writer)?;
+        }
+        writer.write_uvarint(self.buffer.len() as u64)?;
+        for value in self.buffer.iter() {
+            value.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&mut self, reader: &mut &[u8]) -> Result<()> {
+        let size = reader.read_uvarint()? as usize;
+        self.centroids = Vec::with_capacity(size);
+        for _i in 0..size {
+            let mean = f64::deserialize(reader)?;
+            let weight = f64::deserialize(reader)?;
+            self.centroids.push((mean, weight));
+        }
+        let size = reader.read_uvarint()? as usize;
+        self.buffer = Vec::with_capacity(size);
+        for _i in 0..size {
+            self.buffer.push(f64::deserialize(reader)?);
+        }
+        Ok(())
+    }
+
+    fn quantile(&mut self, level: f64) -> Option<f64> {
+        if !self.buffer.is_empty() {
+            self.compress();
+        }
+        if self.centroids.is_empty() {
+            return None;
+        }
+        if self.centroids.len() == 1 {
+            return Some(self.centroids[0].0);
+        }
+
+        let target = level * self.total_weight();
+        let mut seen = 0.0;
+        let mut prev: Option<(f64, f64)> = None;
+        for &(mean, weight) in self.centroids.iter() {
+            let center = seen + weight / 2.0;
+            if target <= center {
+                return Some(match prev {
+                    None => mean,
+                    Some((prev_mean, prev_center)) => {
+                        prev_mean
+                            + (mean - prev_mean) * (target - prev_center) / (center - prev_center)
+                    }
+                });
+            }
+            prev = Some((mean, center));
+            seen += weight;
+        }
+        self.centroids.last().map(|(mean, _)| *mean)
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateQuantileFunction<T, S> {
+    display_name: String,
+    level: f64,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+    state: PhantomData<S>,
+}
+
+impl<T, S> AggregateFunction for AggregateQuantileFunction<T, S>
+where
+    T: DFPrimitiveType + AsPrimitive<f64>,
+    S: AggregateQuantileState,
+{
+    fn name(&self) -> &str {
+        "AggregateQuantileFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(S::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<S>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<S>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().for_each(|value| {
+            if let Some(value) = value {
+                state.add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<S>();
+                state.add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<S>();
+        state.serialize(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<S>();
+        state.deserialize(reader)
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<S>();
+        let rhs = rhs.get::<S>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<S>();
+        Ok(DataValue::Float64(state.quantile(self.level)))
+    }
+}
+
+impl<T, S> fmt::Display for AggregateQuantileFunction<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T, S> AggregateQuantileFunction<T, S>
+where
+    T: DFPrimitiveType + AsPrimitive<f64>,
+    S: AggregateQuantileState,
+{
+    pub fn try_create(
+        display_name: &str,
+        level: f64,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            level,
+            _arguments: arguments,
+            t: PhantomData,
+            state: PhantomData,
+        }))
+    }
+}
+
+fn get_quantile_level(display_name: &str, params: &[DataValue]) -> Result<f64> {
+    match params.len() {
+        0 => Ok(0.5),
+        1 => {
+            let level = params[0].as_f64()?;
+            if !(0.0..=1.0).contains(&level) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "{} expect level to be in [0, 1], but got {}",
+                    display_name, level
+                )));
+            }
+            Ok(level)
+        }
+        n => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at most one parameter, but got {}",
+            display_name, n
+        ))),
+    }
+}
+
+pub fn try_create_aggregate_quantile_function<S: AggregateQuantileState>(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    let level = get_quantile_level(display_name, &params)?;
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateQuantileFunction::<$T, S>::try_create(display_name, level, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateQuantileFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
+
+pub fn try_create_aggregate_median_function<S: AggregateQuantileState>(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    if !params.is_empty() {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have no parameters, but got {}",
+            display_name,
+            params.len()
+        )));
+    }
+    try_create_aggregate_quantile_function::<S>(display_name, params, arguments)
+}
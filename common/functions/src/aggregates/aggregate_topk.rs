@@ -0,0 +1,242 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use indexmap::IndexMap;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+const TOPK_DEFAULT_SIZE: usize = 10;
+// Track more counters than requested to reduce the error of the Space-Saving algorithm.
+const TOPK_RESERVED_FACTOR: usize = 3;
+
+struct AggregateTopKState {
+    pub counters: IndexMap<DataGroupValue, u64>,
+}
+
+impl AggregateTopKState {
+    #[inline(always)]
+    fn add(&mut self, value: DataGroupValue, count: u64, capacity: usize) {
+        if let Some(counter) = self.counters.get_mut(&value) {
+            *counter += count;
+            return;
+        }
+        if self.counters.len() < capacity {
+            self.counters.insert(value, count);
+            return;
+        }
+
+        // Space-Saving: the new value replaces the least frequent one and inherits its count.
+        let (index, min) = self
+            .counters
+            .values()
+            .enumerate()
+            .min_by_key(|(_, count)| **count)
+            .map(|(index, count)| (index, *count))
+            .unwrap();
+        self.counters.swap_remove_index(index);
+        self.counters.insert(value, min + count);
+    }
+
+    fn sorted(&self) -> Vec<(&DataGroupValue, u64)> {
+        let mut counters = self
+            .counters
+            .iter()
+            .map(|(value, count)| (value, *count))
+            .collect::<Vec<_>>();
+        counters.sort_by(|a, b| b.1.cmp(&a.1));
+        counters
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateTopKFunction {
+    display_name: String,
+    k: usize,
+    arguments: Vec<DataField>,
+}
+
+impl AggregateTopKFunction {
+    pub fn try_create(
+        display_name: &str,
+        k: usize,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(AggregateTopKFunction {
+            display_name: display_name.to_string(),
+            k,
+            arguments,
+        }))
+    }
+
+    fn capacity(&self) -> usize {
+        self.k * TOPK_RESERVED_FACTOR
+    }
+}
+
+impl AggregateFunction for AggregateTopKFunction {
+    fn name(&self) -> &str {
+        "AggregateTopKFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::List(Box::new(DataField::new(
+            "item",
+            self.arguments[0].data_type().clone(),
+            true,
+        ))))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateTopKState {
+            counters: IndexMap::new(),
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateTopKState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        for row in 0..input_rows {
+            if !arrays[0].is_null(row) {
+                let value = DataGroupValue::try_from(&arrays[0].try_get(row)?)?;
+                state.add(value, 1, self.capacity());
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            if !arrays[0].is_null(row) {
+                let place = place.next(offset);
+                let state = place.get::<AggregateTopKState>();
+                let value = DataGroupValue::try_from(&arrays[0].try_get(row)?)?;
+                state.add(value, 1, self.capacity());
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        writer.write_uvarint(state.counters.len() as u64)?;
+        for (value, count) in state.counters.iter() {
+            DataValue::from(value).serialize_to_buf(writer)?;
+            count.serialize_to_buf(writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        state.counters.clear();
+
+        let size = reader.read_uvarint()?;
+        for _i in 0..size {
+            let value = DataValue::deserialize(reader)?;
+            let count = u64::deserialize(reader)?;
+            state
+                .counters
+                .insert(DataGroupValue::try_from(&value)?, count);
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateTopKState>();
+        let rhs = rhs.get::<AggregateTopKState>();
+        for (value, count) in rhs.sorted() {
+            state.add(value.clone(), count, self.capacity());
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateTopKState>();
+        let values = state
+            .sorted()
+            .into_iter()
+            .take(self.k)
+            .map(|(value, _)| DataValue::from(value))
+            .collect::<Vec<_>>();
+        Ok(DataValue::List(
+            Some(values),
+            self.arguments[0].data_type().clone(),
+        ))
+    }
+}
+
+impl fmt::Display for AggregateTopKFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_topk_function(
+    display_name: &str,
+    params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let k = match params.len() {
+        0 => TOPK_DEFAULT_SIZE,
+        1 => params[0].as_u64()? as usize,
+        n => {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at most one parameter, but got {}",
+                display_name, n
+            )))
+        }
+    };
+    if k == 0 {
+        return Err(ErrorCode::BadArguments(format!(
+            "{} expect the parameter to be greater than 0",
+            display_name
+        )));
+    }
+
+    let data_type = arguments[0].data_type();
+    if !is_numeric(data_type) && !matches!(data_type, DataType::Boolean | DataType::String) {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "AggregateTopKFunction does not support type '{:?}'",
+            data_type
+        )));
+    }
+    AggregateTopKFunction::try_create(display_name, k, arguments)
+}
@@ -0,0 +1,228 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use num::cast::AsPrimitive;
+
+use super::StateAddr;
+use crate::aggregates::aggregator_common::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::with_match_primitive_type;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VarianceKind {
+    VarPop,
+    VarSamp,
+    StddevSamp,
+}
+
+struct AggregateVarianceState {
+    pub count: u64,
+    pub mean: f64,
+    pub m2: f64,
+}
+
+impl AggregateVarianceState {
+    #[inline(always)]
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            self.count = other.count;
+            self.mean = other.mean;
+            self.m2 = other.m2;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.count = count;
+    }
+
+    fn result(&self, kind: VarianceKind) -> Option<f64> {
+        match kind {
+            VarianceKind::VarPop if self.count > 0 => Some(self.m2 / self.count as f64),
+            VarianceKind::VarSamp if self.count > 1 => Some(self.m2 / (self.count - 1) as f64),
+            VarianceKind::StddevSamp if self.count > 1 => {
+                Some((self.m2 / (self.count - 1) as f64).sqrt())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateVarianceFunction<T> {
+    display_name: String,
+    kind: VarianceKind,
+    _arguments: Vec<DataField>,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateVarianceFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    fn name(&self) -> &str {
+        "AggregateVarianceFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateVarianceState {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateVarianceState>()
+    }
+
+    fn accumulate(&self, place: StateAddr, arrays: &[Series], _input_rows: usize) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+
+        if array.null_count() == 0 {
+            for value in array.into_no_null_iter() {
+                state.add(value.as_());
+            }
+        } else {
+            array.iter().for_each(|value| {
+                if let Some(value) = value {
+                    state.add(value.as_());
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        arrays: &[Series],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let array: &DFPrimitiveArray<T> = arrays[0].static_cast();
+        array.iter().zip(places.iter()).for_each(|(value, place)| {
+            if let Some(value) = value {
+                let place = place.next(offset);
+                let state = place.get::<AggregateVarianceState>();
+                state.add(value.as_());
+            }
+        });
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        state.count.serialize_to_buf(writer)?;
+        state.mean.serialize_to_buf(writer)?;
+        state.m2.serialize_to_buf(writer)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        state.count = u64::deserialize(reader)?;
+        state.mean = f64::deserialize(reader)?;
+        state.m2 = f64::deserialize(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateVarianceState>();
+        let rhs = rhs.get::<AggregateVarianceState>();
+        state.merge(rhs);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr) -> Result<DataValue> {
+        let state = place.get::<AggregateVarianceState>();
+        Ok(DataValue::Float64(state.result(self.kind)))
+    }
+}
+
+impl<T> fmt::Display for AggregateVarianceFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateVarianceFunction<T>
+where T: DFPrimitiveType + AsPrimitive<f64>
+{
+    pub fn try_create(
+        display_name: &str,
+        kind: VarianceKind,
+        arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            kind,
+            _arguments: arguments,
+            t: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_variance_function(
+    kind: VarianceKind,
+    display_name: &str,
+    _params: Vec<DataValue>,
+    arguments: Vec<DataField>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].data_type();
+
+    with_match_primitive_type!(data_type, |$T| {
+        AggregateVarianceFunction::<$T>::try_create(display_name, kind, arguments)
+    },
+
+    {
+        Err(ErrorCode::BadDataValueType(format!(
+            "AggregateVarianceFunction does not support type '{:?}'",
+            data_type
+        )))
+    })
+}
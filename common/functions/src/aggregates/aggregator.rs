@@ -14,17 +14,29 @@
 
 use common_exception::Result;
 
+use super::aggregate_any::try_create_aggregate_any_function;
 use super::aggregate_arg_min_max::try_create_aggregate_arg_minmax_function;
 use super::aggregate_avg::try_create_aggregate_avg_function;
+use super::aggregate_covariance::try_create_aggregate_covariance_function;
+use super::aggregate_group_array::try_create_aggregate_group_array_function;
 use super::aggregate_min_max::try_create_aggregate_minmax_function;
+use super::aggregate_quantile::try_create_aggregate_median_function;
+use super::aggregate_quantile::try_create_aggregate_quantile_function;
 use super::aggregate_stddev_pop::try_create_aggregate_stddev_pop_function;
 use super::aggregate_sum::try_create_aggregate_sum_function;
+use super::aggregate_topk::try_create_aggregate_topk_function;
+use super::aggregate_variance::try_create_aggregate_variance_function;
 use super::aggregate_window_funnel::try_create_aggregate_window_funnel_function;
 use crate::aggregates::aggregate_function_factory::FactoryCombinatorFuncRef;
 use crate::aggregates::aggregate_function_factory::FactoryFuncRef;
+use crate::aggregates::AggregateApproxCountDistinctFunction;
 use crate::aggregates::AggregateCountFunction;
 use crate::aggregates::AggregateDistinctCombinator;
 use crate::aggregates::AggregateIfCombinator;
+use crate::aggregates::CovarianceKind;
+use crate::aggregates::QuantileExactState;
+use crate::aggregates::QuantileTDigestState;
+use crate::aggregates::VarianceKind;
 
 pub struct Aggregators;
 
@@ -54,6 +66,88 @@ impl Aggregators {
             "stddev_pop".into(),
             try_create_aggregate_stddev_pop_function,
         );
+        map.insert("stddev_samp".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::StddevSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("var_pop".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::VarPop,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("var_samp".into(), |display_name, params, arguments| {
+            try_create_aggregate_variance_function(
+                VarianceKind::VarSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("covar_pop".into(), |display_name, params, arguments| {
+            try_create_aggregate_covariance_function(
+                CovarianceKind::CovarPop,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("covar_samp".into(), |display_name, params, arguments| {
+            try_create_aggregate_covariance_function(
+                CovarianceKind::CovarSamp,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+        map.insert("corr".into(), |display_name, params, arguments| {
+            try_create_aggregate_covariance_function(
+                CovarianceKind::Corr,
+                display_name,
+                params,
+                arguments,
+            )
+        });
+
+        map.insert(
+            "quantile".into(),
+            try_create_aggregate_quantile_function::<QuantileExactState>,
+        );
+        map.insert(
+            "median".into(),
+            try_create_aggregate_median_function::<QuantileExactState>,
+        );
+        map.insert(
+            "quantileTDigest".into(),
+            try_create_aggregate_quantile_function::<QuantileTDigestState>,
+        );
+        map.insert(
+            "medianTDigest".into(),
+            try_create_aggregate_median_function::<QuantileTDigestState>,
+        );
+
+        map.insert("any".into(), |display_name, params, arguments| {
+            try_create_aggregate_any_function(false, display_name, params, arguments)
+        });
+        map.insert("anyLast".into(), |display_name, params, arguments| {
+            try_create_aggregate_any_function(true, display_name, params, arguments)
+        });
+        map.insert("groupArray".into(), |display_name, params, arguments| {
+            try_create_aggregate_group_array_function(false, display_name, params, arguments)
+        });
+        map.insert(
+            "groupUniqArray".into(),
+            |display_name, params, arguments| {
+                try_create_aggregate_group_array_function(true, display_name, params, arguments)
+            },
+        );
+        map.insert("topK".into(), try_create_aggregate_topk_function);
 
         map.insert(
             "windowFunnel".into(),
@@ -61,6 +155,14 @@ impl Aggregators {
         );
 
         map.insert("uniq".into(), AggregateDistinctCombinator::try_create_uniq);
+        map.insert(
+            "uniqExact".into(),
+            AggregateDistinctCombinator::try_create_uniq,
+        );
+        map.insert(
+            "approx_count_distinct".into(),
+            AggregateApproxCountDistinctFunction::try_create,
+        );
         Ok(())
     }
 
@@ -17,15 +17,22 @@ mod aggregate_combinator_test;
 #[cfg(test)]
 mod aggregate_function_test;
 
+mod aggregate_any;
+mod aggregate_approx_count_distinct;
 mod aggregate_arg_min_max;
 mod aggregate_avg;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_count;
+mod aggregate_covariance;
 mod aggregate_function;
 mod aggregate_function_factory;
 mod aggregate_function_state;
+mod aggregate_group_array;
 mod aggregate_min_max;
+mod aggregate_quantile;
+mod aggregate_topk;
+mod aggregate_variance;
 mod aggregate_window_funnel;
 
 // mod aggregate_min_max;
@@ -37,19 +44,31 @@ mod aggregator_common;
 #[macro_use]
 mod macros;
 
+pub use aggregate_any::AggregateAnyFunction;
+pub use aggregate_approx_count_distinct::AggregateApproxCountDistinctFunction;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_avg::AggregateAvgFunction;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
+pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_covariance::CovarianceKind;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_function_state::get_layout_offsets;
 pub use aggregate_function_state::StateAddr;
 pub use aggregate_function_state::StateAddrs;
+pub use aggregate_group_array::AggregateGroupArrayFunction;
 pub use aggregate_min_max::AggregateMinMaxFunction;
+pub use aggregate_quantile::AggregateQuantileFunction;
+pub use aggregate_quantile::AggregateQuantileState;
+pub use aggregate_quantile::QuantileExactState;
+pub use aggregate_quantile::QuantileTDigestState;
 pub use aggregate_stddev_pop::AggregateStddevPopFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_topk::AggregateTopKFunction;
+pub use aggregate_variance::AggregateVarianceFunction;
+pub use aggregate_variance::VarianceKind;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
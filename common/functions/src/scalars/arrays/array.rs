@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::build_list_column;
+use crate::scalars::arrays::array_common::list_data_type;
+use crate::scalars::Function;
+
+/// array(x1, ..., xn) builds an array from its arguments, which are cast to their common type.
+#[derive(Clone)]
+pub struct ArrayFunction {
+    display_name: String,
+}
+
+impl ArrayFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        list_data_type(aggregate_types(args)?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let item_type = aggregate_types(&args)?;
+
+        let values = columns
+            .iter()
+            .map(|c| c.column().cast_with_type(&item_type)?.to_values())
+            .collect::<Result<Vec<_>>>()?;
+
+        let rows = (0..input_rows)
+            .map(|row| Some(values.iter().map(|v| v[row].clone()).collect()))
+            .collect();
+        build_list_column(rows, &item_type)
+    }
+}
+
+impl fmt::Display for ArrayFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Returns the item type of the list argument of function `func_name`.
+pub fn list_item_type(func_name: &str, data_type: &DataType) -> Result<DataType> {
+    match data_type {
+        DataType::List(field) => Ok(field.data_type().clone()),
+        other => Result::Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?} of argument of function {}, expect an array",
+            other, func_name
+        ))),
+    }
+}
+
+pub fn list_data_type(item_type: DataType) -> Result<DataType> {
+    if !is_numeric(&item_type) && !matches!(item_type, DataType::Boolean | DataType::String) {
+        return Result::Err(ErrorCode::IllegalDataType(format!(
+            "Unsupported array item type {:?}, expect a number, boolean or string",
+            item_type
+        )));
+    }
+    Ok(DataType::List(Box::new(DataField::new(
+        "item", item_type, true,
+    ))))
+}
+
+/// Collects the items of every row of a list column, casting them to `item_type`.
+/// A null list is returned as `None`.
+pub(crate) fn list_rows(
+    func_name: &str,
+    column: &DataColumnWithField,
+    item_type: &DataType,
+) -> Result<Vec<Option<Vec<DataValue>>>> {
+    let from_type = list_item_type(func_name, column.data_type())?;
+    column
+        .column()
+        .to_values()?
+        .into_iter()
+        .map(|value| match value {
+            DataValue::List(Some(items), _) => cast_items(&items, &from_type, item_type).map(Some),
+            _ => Ok(None),
+        })
+        .collect()
+}
+
+pub(crate) fn cast_items(
+    items: &[DataValue],
+    from_type: &DataType,
+    to_type: &DataType,
+) -> Result<Vec<DataValue>> {
+    if from_type == to_type {
+        return Ok(items.to_vec());
+    }
+    DataValue::try_into_data_array(items, from_type)?
+        .cast_with_type(to_type)?
+        .to_values()
+}
+
+pub(crate) fn build_list_column(
+    rows: Vec<Option<Vec<DataValue>>>,
+    item_type: &DataType,
+) -> Result<DataColumn> {
+    let values = rows
+        .into_iter()
+        .map(|row| DataValue::List(row, item_type.clone()))
+        .collect::<Vec<_>>();
+    let series = DataValue::try_into_data_array(&values, &list_data_type(item_type.clone())?)?;
+    Ok(series.into())
+}
+
+/// Finds the first position of the second argument in the array of the first argument, row by row.
+pub(crate) fn find_positions(
+    func_name: &str,
+    columns: &DataColumnsWithField,
+) -> Result<Vec<Option<usize>>> {
+    let item_type = list_item_type(func_name, columns[0].data_type())?;
+    let common_type = merge_types(&item_type, columns[1].data_type())?;
+
+    let rows = list_rows(func_name, &columns[0], &common_type)?;
+    let needles = columns[1]
+        .column()
+        .cast_with_type(&common_type)?
+        .to_values()?;
+
+    Ok(rows
+        .iter()
+        .zip(needles.iter())
+        .map(|(items, needle)| {
+            items
+                .as_ref()
+                .and_then(|items| items.iter().position(|item| item == needle))
+        })
+        .collect())
+}
+
+/// Orders two items of the same type, nulls are placed last.
+pub(crate) fn compare_items(lhs: &DataValue, rhs: &DataValue) -> Ordering {
+    match (lhs.is_null(), rhs.is_null()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    match (lhs, rhs) {
+        (DataValue::String(Some(l)), DataValue::String(Some(r))) => l.cmp(r),
+        (DataValue::Boolean(Some(l)), DataValue::Boolean(Some(r))) => l.cmp(r),
+        (DataValue::Int64(Some(l)), DataValue::Int64(Some(r))) => l.cmp(r),
+        (DataValue::UInt64(Some(l)), DataValue::UInt64(Some(r))) => l.cmp(r),
+        _ => match (lhs.as_f64(), rhs.as_f64()) {
+            (Ok(l), Ok(r)) => l.partial_cmp(&r).unwrap_or(Ordering::Equal),
+            _ => Ordering::Equal,
+        },
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::build_list_column;
+use crate::scalars::arrays::array_common::list_data_type;
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::arrays::array_common::list_rows;
+use crate::scalars::Function;
+
+/// arrayConcat(arr1, ..., arrn) concatenates the arrays, null arrays are treated as empty ones.
+#[derive(Clone)]
+pub struct ArrayConcatFunction {
+    display_name: String,
+}
+
+impl ArrayConcatFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayConcatFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    fn item_type(&self, args: &[DataType]) -> Result<DataType> {
+        let item_types = args
+            .iter()
+            .map(|arg| list_item_type(&self.display_name, arg))
+            .collect::<Result<Vec<_>>>()?;
+        aggregate_types(&item_types)
+    }
+}
+
+impl Function for ArrayConcatFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        list_data_type(self.item_type(args)?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let item_type = self.item_type(&args)?;
+
+        let mut rows = vec![Some(vec![]); input_rows];
+        for column in columns.iter() {
+            let column_rows = list_rows(&self.display_name, column, &item_type)?;
+            for (row, items) in rows.iter_mut().zip(column_rows.into_iter()) {
+                if let (Some(row), Some(items)) = (row, items) {
+                    row.extend(items);
+                }
+            }
+        }
+        build_list_column(rows, &item_type)
+    }
+}
+
+impl fmt::Display for ArrayConcatFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
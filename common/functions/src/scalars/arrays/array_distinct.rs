@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::build_list_column;
+use crate::scalars::arrays::array_common::list_data_type;
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::arrays::array_common::list_rows;
+use crate::scalars::Function;
+
+/// arrayDistinct(arr) keeps the first occurrence of every distinct non-null item.
+#[derive(Clone)]
+pub struct ArrayDistinctFunction {
+    display_name: String,
+}
+
+impl ArrayDistinctFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayDistinctFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayDistinctFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        list_data_type(list_item_type(&self.display_name, &args[0])?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let item_type = list_item_type(&self.display_name, columns[0].data_type())?;
+        let rows = list_rows(&self.display_name, &columns[0], &item_type)?
+            .into_iter()
+            .map(|items| {
+                items.map(|items| {
+                    let mut distinct: Vec<DataValue> = Vec::with_capacity(items.len());
+                    for item in items {
+                        if !item.is_null() && !distinct.contains(&item) {
+                            distinct.push(item);
+                        }
+                    }
+                    distinct
+                })
+            })
+            .collect();
+        build_list_column(rows, &item_type)
+    }
+}
+
+impl fmt::Display for ArrayDistinctFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
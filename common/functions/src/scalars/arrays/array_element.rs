@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::arrays::array_common::list_rows;
+use crate::scalars::Function;
+
+/// arrayElement(arr, n) returns the n-th item of the array, `a[n]` is planned as this function.
+/// Indexes are 1-based, negative indexes count from the end, out of range indexes give NULL.
+#[derive(Clone)]
+pub struct ArrayElementFunction {
+    display_name: String,
+}
+
+impl ArrayElementFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayElementFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayElementFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !is_integer(&args[1]) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of index argument of function {}, expect an integer",
+                args[1], self.display_name
+            )));
+        }
+        list_item_type(&self.display_name, &args[0])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let item_type = list_item_type(&self.display_name, columns[0].data_type())?;
+        let rows = list_rows(&self.display_name, &columns[0], &item_type)?;
+        let indexes = columns[1].column().to_values()?;
+
+        let values = rows
+            .into_iter()
+            .zip(indexes.iter())
+            .map(|(items, index)| {
+                let items = match items {
+                    Some(items) if !index.is_null() => items,
+                    _ => return Ok(DataValue::from(&item_type)),
+                };
+
+                let index = index.as_i64()?;
+                let len = items.len() as i64;
+                let pos = if index > 0 { index - 1 } else { len + index };
+                match index != 0 && pos >= 0 && pos < len {
+                    true => Ok(items[pos as usize].clone()),
+                    false => Ok(DataValue::from(&item_type)),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let series = DataValue::try_into_data_array(&values, &item_type)?;
+        Ok(series.into())
+    }
+}
+
+impl fmt::Display for ArrayElementFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
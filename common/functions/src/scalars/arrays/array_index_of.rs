@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::find_positions;
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::Function;
+
+/// indexOf(arr, x) returns the 1-based position of the first occurrence of the value, 0 if not found.
+#[derive(Clone)]
+pub struct ArrayIndexOfFunction {
+    display_name: String,
+}
+
+impl ArrayIndexOfFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayIndexOfFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayIndexOfFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        let item_type = list_item_type(&self.display_name, &args[0])?;
+        merge_types(&item_type, &args[1])?;
+        Ok(DataType::UInt64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let positions = find_positions(&self.display_name, columns)?;
+        let result = DFUInt64Array::new_from_iter(
+            positions
+                .iter()
+                .map(|p| p.map(|p| p as u64 + 1).unwrap_or(0)),
+        );
+        Ok(result.into())
+    }
+}
+
+impl fmt::Display for ArrayIndexOfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// length(x) returns the number of items of an array, or the number of bytes of a string.
+#[derive(Clone)]
+pub struct ArrayLengthFunction {
+    display_name: String,
+}
+
+impl ArrayLengthFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArrayLengthFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArrayLengthFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::List(_) | DataType::String | DataType::Null => Ok(DataType::UInt64),
+            other => Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expect an array or a string",
+                other, self.display_name
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = columns[0].column().to_minimal_array()?;
+
+        let result: DFUInt64Array = match columns[0].data_type() {
+            DataType::List(_) => DFUInt64Array::new_from_opt_iter(
+                series
+                    .list()?
+                    .into_iter()
+                    .map(|v| v.map(|v| v.len() as u64)),
+            ),
+            DataType::String => DFUInt64Array::new_from_opt_iter(
+                series
+                    .string()?
+                    .into_iter()
+                    .map(|v| v.map(|v| v.len() as u64)),
+            ),
+            _ => DFUInt64Array::full_null(series.len()),
+        };
+
+        let column: DataColumn = result.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ArrayLengthFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::build_list_column;
+use crate::scalars::arrays::array_common::list_data_type;
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::arrays::array_common::list_rows;
+use crate::scalars::Function;
+
+/// arraySlice(arr, offset[, length]) returns a slice of the array, as ClickHouse does:
+/// a negative offset counts from the end, a negative length leaves that many items out of the end.
+#[derive(Clone)]
+pub struct ArraySliceFunction {
+    display_name: String,
+}
+
+impl ArraySliceFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySliceFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    fn slice_range(len: i64, offset: i64, length: Option<i64>) -> (usize, usize) {
+        let start = match offset {
+            0 => return (0, 0),
+            o if o > 0 => (o - 1).min(len),
+            o => (len + o).max(0),
+        };
+        let end = match length {
+            None => len,
+            Some(l) if l >= 0 => (start + l).min(len),
+            Some(l) => (len + l).max(start),
+        };
+        (start as usize, end as usize)
+    }
+}
+
+impl Function for ArraySliceFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args[1..].iter() {
+            if !is_integer(arg) {
+                return Result::Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}, expect an integer",
+                    arg, self.display_name
+                )));
+            }
+        }
+        list_data_type(list_item_type(&self.display_name, &args[0])?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let item_type = list_item_type(&self.display_name, columns[0].data_type())?;
+        let rows = list_rows(&self.display_name, &columns[0], &item_type)?;
+        let offsets = columns[1].column().to_values()?;
+        let lengths = match columns.len() {
+            3 => Some(columns[2].column().to_values()?),
+            _ => None,
+        };
+
+        let rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(row, items)| {
+                let (items, offset) = match items {
+                    Some(items) if !offsets[row].is_null() => (items, offsets[row].as_i64()?),
+                    _ => return Ok(None),
+                };
+                let length = match &lengths {
+                    Some(lengths) if lengths[row].is_null() => return Ok(None),
+                    Some(lengths) => Some(lengths[row].as_i64()?),
+                    None => None,
+                };
+
+                let (start, end) = Self::slice_range(items.len() as i64, offset, length);
+                Ok(Some(items[start..end].to_vec()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        build_list_column(rows, &item_type)
+    }
+}
+
+impl fmt::Display for ArraySliceFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::arrays::array_common::build_list_column;
+use crate::scalars::arrays::array_common::compare_items;
+use crate::scalars::arrays::array_common::list_data_type;
+use crate::scalars::arrays::array_common::list_item_type;
+use crate::scalars::arrays::array_common::list_rows;
+use crate::scalars::Function;
+
+/// arraySort(arr) sorts the items in ascending order, nulls are placed last.
+#[derive(Clone)]
+pub struct ArraySortFunction {
+    display_name: String,
+}
+
+impl ArraySortFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ArraySortFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for ArraySortFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        list_data_type(list_item_type(&self.display_name, &args[0])?)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let item_type = list_item_type(&self.display_name, columns[0].data_type())?;
+        let rows = list_rows(&self.display_name, &columns[0], &item_type)?
+            .into_iter()
+            .map(|items| {
+                items.map(|mut items| {
+                    items.sort_by(compare_items);
+                    items
+                })
+            })
+            .collect();
+        build_list_column(rows, &item_type)
+    }
+}
+
+impl fmt::Display for ArraySortFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::ArrayConcatFunction;
+use crate::scalars::ArrayDistinctFunction;
+use crate::scalars::ArrayElementFunction;
+use crate::scalars::ArrayFunction;
+use crate::scalars::ArrayHasFunction;
+use crate::scalars::ArrayIndexOfFunction;
+use crate::scalars::ArrayJoinFunction;
+use crate::scalars::ArrayLengthFunction;
+use crate::scalars::ArraySliceFunction;
+use crate::scalars::ArraySortFunction;
+use crate::scalars::FactoryFuncRef;
+
+#[derive(Clone)]
+pub struct ArraysFunction;
+
+impl ArraysFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("array".into(), ArrayFunction::try_create);
+        map.insert("length".into(), ArrayLengthFunction::try_create);
+        map.insert("has".into(), ArrayHasFunction::try_create);
+        map.insert("indexOf".into(), ArrayIndexOfFunction::try_create);
+        map.insert("arrayElement".into(), ArrayElementFunction::try_create);
+        map.insert("arrayConcat".into(), ArrayConcatFunction::try_create);
+        map.insert("arraySlice".into(), ArraySliceFunction::try_create);
+        map.insert("arrayDistinct".into(), ArrayDistinctFunction::try_create);
+        map.insert("arraySort".into(), ArraySortFunction::try_create);
+        map.insert("arrayJoin".into(), ArrayJoinFunction::try_create);
+        map.insert("unnest".into(), ArrayJoinFunction::try_create);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+fn list_column(rows: Vec<Option<Vec<DataValue>>>, item_type: DataType) -> Result<DataColumn> {
+    let values = rows
+        .into_iter()
+        .map(|row| DataValue::List(row, item_type.clone()))
+        .collect::<Vec<_>>();
+    let series = DataValue::try_into_data_array(&values, &list_data_type(item_type)?)?;
+    Ok(series.into())
+}
+
+fn ints(values: &[i64]) -> Option<Vec<DataValue>> {
+    Some(values.iter().map(|v| DataValue::Int64(Some(*v))).collect())
+}
+
+fn strings(values: &[&str]) -> Option<Vec<DataValue>> {
+    Some(
+        values
+            .iter()
+            .map(|v| DataValue::from(v.as_bytes()))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_array_function() -> Result<()> {
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        arg_names: Vec<&'static str>,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+        error: &'static str,
+        func: Box<dyn Function>,
+    }
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", list_data_type(DataType::Int64)?, true),
+        DataField::new("b", list_data_type(DataType::Int64)?, true),
+        DataField::new("s", list_data_type(DataType::String)?, true),
+        DataField::new("i", DataType::Int64, false),
+        DataField::new("u", DataType::UInt8, false),
+        DataField::new("str", DataType::String, false),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "array-passed",
+            arg_names: vec!["i", "u"],
+            func: ArrayFunction::try_create("array")?,
+            columns: vec![
+                Series::new(vec![1i64, -2]).into(),
+                Series::new(vec![3u8, 4]).into(),
+            ],
+            expect: list_column(vec![ints(&[1, 3]), ints(&[-2, 4])], DataType::Int64)?,
+            error: "",
+        },
+        Test {
+            name: "length-array-passed",
+            arg_names: vec!["a"],
+            func: ArrayLengthFunction::try_create("length")?,
+            columns: vec![list_column(
+                vec![ints(&[1, 2, 3]), None, ints(&[])],
+                DataType::Int64,
+            )?],
+            expect: DFUInt64Array::new_from_opt_slice(&[Some(3), None, Some(0)]).into(),
+            error: "",
+        },
+        Test {
+            name: "length-string-passed",
+            arg_names: vec!["str"],
+            func: ArrayLengthFunction::try_create("length")?,
+            columns: vec![Series::new(vec!["abc", ""]).into()],
+            expect: Series::new(vec![3u64, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "has-passed",
+            arg_names: vec!["a", "u"],
+            func: ArrayHasFunction::try_create("has")?,
+            columns: vec![
+                list_column(vec![ints(&[1, 2, 3]), ints(&[4]), None], DataType::Int64)?,
+                Series::new(vec![2u8, 2, 2]).into(),
+            ],
+            expect: Series::new(vec![true, false, false]).into(),
+            error: "",
+        },
+        Test {
+            name: "has-string-passed",
+            arg_names: vec!["s", "str"],
+            func: ArrayHasFunction::try_create("has")?,
+            columns: vec![
+                list_column(vec![strings(&["x", "y"])], DataType::String)?,
+                Series::new(vec!["y"]).into(),
+            ],
+            expect: Series::new(vec![true]).into(),
+            error: "",
+        },
+        Test {
+            name: "index-of-passed",
+            arg_names: vec!["a", "i"],
+            func: ArrayIndexOfFunction::try_create("indexOf")?,
+            columns: vec![
+                list_column(vec![ints(&[5, 6, 6]), ints(&[5])], DataType::Int64)?,
+                Series::new(vec![6i64, 6]).into(),
+            ],
+            expect: Series::new(vec![2u64, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "array-element-passed",
+            arg_names: vec!["a", "i"],
+            func: ArrayElementFunction::try_create("arrayElement")?,
+            columns: vec![
+                list_column(
+                    vec![ints(&[1, 2, 3]), ints(&[1, 2, 3]), ints(&[1, 2, 3])],
+                    DataType::Int64,
+                )?,
+                Series::new(vec![1i64, -1, 4]).into(),
+            ],
+            expect: DFInt64Array::new_from_opt_slice(&[Some(1), Some(3), None]).into(),
+            error: "",
+        },
+        Test {
+            name: "array-element-bad-index-failed",
+            arg_names: vec!["a", "str"],
+            func: ArrayElementFunction::try_create("arrayElement")?,
+            columns: vec![
+                list_column(vec![ints(&[1])], DataType::Int64)?,
+                Series::new(vec!["1"]).into(),
+            ],
+            expect: Series::new(vec![1i64]).into(),
+            error: "Code: 7, displayText = Illegal type String of index argument of function arrayElement, expect an integer.",
+        },
+        Test {
+            name: "array-concat-passed",
+            arg_names: vec!["a", "b"],
+            func: ArrayConcatFunction::try_create("arrayConcat")?,
+            columns: vec![
+                list_column(vec![ints(&[1, 2]), None], DataType::Int64)?,
+                list_column(vec![ints(&[3]), ints(&[4])], DataType::Int64)?,
+            ],
+            expect: list_column(vec![ints(&[1, 2, 3]), ints(&[4])], DataType::Int64)?,
+            error: "",
+        },
+        Test {
+            name: "array-concat-not-array-failed",
+            arg_names: vec!["a", "i"],
+            func: ArrayConcatFunction::try_create("arrayConcat")?,
+            columns: vec![
+                list_column(vec![ints(&[1])], DataType::Int64)?,
+                Series::new(vec![1i64]).into(),
+            ],
+            expect: Series::new(vec![1i64]).into(),
+            error: "Code: 7, displayText = Illegal type Int64 of argument of function arrayConcat, expect an array.",
+        },
+        Test {
+            name: "array-slice-passed",
+            arg_names: vec!["a", "i", "i"],
+            func: ArraySliceFunction::try_create("arraySlice")?,
+            columns: vec![
+                list_column(
+                    vec![ints(&[1, 2, 3, 4, 5]), ints(&[1, 2, 3, 4, 5])],
+                    DataType::Int64,
+                )?,
+                Series::new(vec![2i64, -3]).into(),
+                Series::new(vec![2i64, -1]).into(),
+            ],
+            expect: list_column(vec![ints(&[2, 3]), ints(&[3, 4])], DataType::Int64)?,
+            error: "",
+        },
+        Test {
+            name: "array-slice-without-length-passed",
+            arg_names: vec!["a", "i"],
+            func: ArraySliceFunction::try_create("arraySlice")?,
+            columns: vec![
+                list_column(vec![ints(&[1, 2, 3]), ints(&[1, 2, 3])], DataType::Int64)?,
+                Series::new(vec![2i64, 0]).into(),
+            ],
+            expect: list_column(vec![ints(&[2, 3]), ints(&[])], DataType::Int64)?,
+            error: "",
+        },
+        Test {
+            name: "array-distinct-passed",
+            arg_names: vec!["a"],
+            func: ArrayDistinctFunction::try_create("arrayDistinct")?,
+            columns: vec![list_column(
+                vec![Some(vec![
+                    DataValue::Int64(Some(2)),
+                    DataValue::Int64(None),
+                    DataValue::Int64(Some(1)),
+                    DataValue::Int64(Some(2)),
+                ])],
+                DataType::Int64,
+            )?],
+            expect: list_column(vec![ints(&[2, 1])], DataType::Int64)?,
+            error: "",
+        },
+        Test {
+            name: "array-sort-passed",
+            arg_names: vec!["s"],
+            func: ArraySortFunction::try_create("arraySort")?,
+            columns: vec![list_column(
+                vec![strings(&["b", "c", "a"]), None],
+                DataType::String,
+            )?],
+            expect: list_column(vec![strings(&["a", "b", "c"]), None], DataType::String)?,
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let rows = t.columns[0].len();
+
+        let mut args = vec![];
+        let mut fields = vec![];
+        for name in t.arg_names {
+            args.push(schema.field_with_name(name)?.data_type().clone());
+            fields.push(schema.field_with_name(name)?.clone());
+        }
+
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .zip(fields.iter())
+            .map(|(c, f)| DataColumnWithField::new(c.clone(), f.clone()))
+            .collect();
+
+        let result = func
+            .return_type(&args)
+            .and_then(|expect_type| Ok((expect_type, func.eval(&columns, rows)?)));
+        match result {
+            Ok((expect_type, v)) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                // Type check.
+                assert_eq!(expect_type, v.data_type(), "{}", t.name);
+                assert_eq!(&v, &t.expect, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+
+    Ok(())
+}
@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_datavalues::DataValueComparisonOperator;
 use common_exception::Result;
 
+use crate::scalars::cast_to_datetime64;
 use crate::scalars::ComparisonEqFunction;
 use crate::scalars::ComparisonGtEqFunction;
 use crate::scalars::ComparisonGtFunction;
@@ -57,6 +58,19 @@ impl ComparisonFunction {
     pub fn try_create_func(op: DataValueComparisonOperator) -> Result<Box<dyn Function>> {
         Ok(Box::new(ComparisonFunction { op }))
     }
+
+    fn to_datetime64(
+        column: &DataColumn,
+        data_type: &DataType,
+        precision: u32,
+        tz: &Option<String>,
+    ) -> Result<DataColumn> {
+        if !is_date_or_date_time(data_type) && data_type != &DataType::String {
+            return Ok(column.clone());
+        }
+        let series = column.to_minimal_array()?;
+        Ok(cast_to_datetime64(&series, data_type, precision, tz)?.into())
+    }
 }
 
 impl Function for ComparisonFunction {
@@ -72,10 +86,26 @@ impl Function for ComparisonFunction {
         Ok(false)
     }
 
-    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
-        columns[0]
-            .column()
-            .compare(self.op.clone(), columns[1].column())
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let (lhs_type, rhs_type) = (columns[0].data_type(), columns[1].data_type());
+        // DateTime64 ticks are only comparable with the ticks of the same precision,
+        // so the other side is converted to the DateTime64 type first.
+        match (lhs_type, rhs_type) {
+            (DataType::DateTime64(precision, tz), other) if lhs_type != rhs_type => {
+                let rhs = Self::to_datetime64(columns[1].column(), other, *precision, tz)?;
+                columns[0]
+                    .column()
+                    .compare(self.op.clone(), &rhs.resize_constant(input_rows))
+            }
+            (other, DataType::DateTime64(precision, tz)) if lhs_type != rhs_type => {
+                let lhs = Self::to_datetime64(columns[0].column(), other, *precision, tz)?;
+                lhs.resize_constant(input_rows)
+                    .compare(self.op.clone(), columns[1].column())
+            }
+            _ => columns[0]
+                .column()
+                .compare(self.op.clone(), columns[1].column()),
+        }
     }
 
     fn num_arguments(&self) -> usize {
@@ -18,12 +18,22 @@ use common_exception::Result;
 use super::interval_function::MonthsArithmeticFunction;
 use super::interval_function::SecondsArithmeticFunction;
 use super::now::NowFunction;
+use super::DateDiffFunction;
+use super::FormatDateTimeFunction;
+use super::FromUnixTimestampFunction;
+use super::ParseDateTimeFunction;
 use super::RoundFunction;
+use super::ToDayOfWeekFunction;
+use super::ToHourFunction;
+use super::ToMinuteFunction;
+use super::ToQuarterFunction;
+use super::ToSecondFunction;
 use super::ToStartOfISOYearFunction;
 use super::ToStartOfMonthFunction;
 use super::ToStartOfQuarterFunction;
 use super::ToStartOfWeekFunction;
 use super::ToStartOfYearFunction;
+use super::ToUnixTimestampFunction;
 use super::ToYYYYMMDDFunction;
 use super::ToYYYYMMDDhhmmssFunction;
 use super::ToYYYYMMFunction;
@@ -36,6 +46,38 @@ use crate::scalars::FactoryFuncRef;
 pub struct DateFunction {}
 
 impl DateFunction {
+    /// The functions which evaluate a DateTime in its timezone, the planner gives the DateTimes
+    /// without a timezone the timezone of the session.
+    pub fn is_timezone_aware(name: &str) -> bool {
+        matches!(
+            name.to_lowercase().as_str(),
+            "toyyyymm"
+                | "toyyyymmdd"
+                | "toyyyymmddhhmmss"
+                | "tostartofyear"
+                | "tostartofisoyear"
+                | "tostartofquarter"
+                | "tostartofmonth"
+                | "tostartofweek"
+                | "tostartofday"
+                | "tostartofhour"
+                | "tostartoffifteenminutes"
+                | "tostartoftenminutes"
+                | "tostartoffiveminutes"
+                | "tostartofminute"
+                | "tostartofsecond"
+                | "timeslot"
+                | "toquarter"
+                | "todayofweek"
+                | "tohour"
+                | "tominute"
+                | "tosecond"
+                | "datediff"
+                | "formatdatetime"
+                | "date_format"
+        )
+    }
+
     pub fn register(map: FactoryFuncRef) -> Result<()> {
         let mut map = map.write();
         map.insert("today".into(), TodayFunction::try_create);
@@ -59,6 +101,36 @@ impl DateFunction {
         );
         map.insert("toStartOfWeek".into(), ToStartOfWeekFunction::try_create);
         map.insert("toStartOfMonth".into(), ToStartOfMonthFunction::try_create);
+        map.insert("toQuarter".into(), ToQuarterFunction::try_create);
+        map.insert("toDayOfWeek".into(), ToDayOfWeekFunction::try_create);
+        map.insert("toHour".into(), ToHourFunction::try_create);
+        map.insert("toMinute".into(), ToMinuteFunction::try_create);
+        map.insert("toSecond".into(), ToSecondFunction::try_create);
+        map.insert(
+            "toUnixTimestamp".into(),
+            ToUnixTimestampFunction::try_create,
+        );
+        map.insert(
+            "fromUnixTimestamp".into(),
+            FromUnixTimestampFunction::try_create,
+        );
+        map.insert("dateDiff".into(), DateDiffFunction::try_create);
+
+        // formatting and parsing
+        {
+            map.insert("formatDateTime".into(), |display_name| {
+                FormatDateTimeFunction::try_create(display_name, false)
+            });
+            map.insert("date_format".into(), |display_name| {
+                FormatDateTimeFunction::try_create(display_name, true)
+            });
+            map.insert("parseDateTime".into(), |display_name| {
+                ParseDateTimeFunction::try_create(display_name, false)
+            });
+            map.insert("str_to_date".into(), |display_name| {
+                ParseDateTimeFunction::try_create(display_name, true)
+            });
+        }
 
         // rounders
         {
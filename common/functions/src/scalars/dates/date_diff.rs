@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::number_function::get_timestamps;
+use super::number_function::get_timezone;
+use crate::scalars::Function;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DateDiffUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DateDiffUnit {
+    fn try_from_str(unit: &str) -> Result<Self> {
+        match unit.to_lowercase().as_str() {
+            "second" | "seconds" | "ss" | "s" => Ok(DateDiffUnit::Second),
+            "minute" | "minutes" | "mi" | "n" => Ok(DateDiffUnit::Minute),
+            "hour" | "hours" | "hh" | "h" => Ok(DateDiffUnit::Hour),
+            "day" | "days" | "dd" | "d" => Ok(DateDiffUnit::Day),
+            "week" | "weeks" | "wk" | "ww" => Ok(DateDiffUnit::Week),
+            "month" | "months" | "mm" | "m" => Ok(DateDiffUnit::Month),
+            "quarter" | "quarters" | "qq" | "q" => Ok(DateDiffUnit::Quarter),
+            "year" | "years" | "yyyy" | "yy" => Ok(DateDiffUnit::Year),
+            _ => Result::Err(ErrorCode::BadArguments(format!(
+                "Unknown unit {:?} of function dateDiff",
+                unit
+            ))),
+        }
+    }
+
+    /// The number of unit boundaries from the epoch to the given time, in the local time of tz.
+    fn ordinal(&self, timestamp: i64, tz: &Tz) -> i64 {
+        let date_time: DateTime<Tz> = tz.timestamp(timestamp, 0);
+        let local = date_time.naive_local();
+        match self {
+            DateDiffUnit::Second => local.timestamp(),
+            DateDiffUnit::Minute => local.timestamp().div_euclid(60),
+            DateDiffUnit::Hour => local.timestamp().div_euclid(3600),
+            DateDiffUnit::Day => local.timestamp().div_euclid(24 * 3600),
+            // 1970-01-01 is a Thursday, shift it so that weeks start on Monday.
+            DateDiffUnit::Week => (local.timestamp().div_euclid(24 * 3600) + 3).div_euclid(7),
+            DateDiffUnit::Month => local.year() as i64 * 12 + local.month0() as i64,
+            DateDiffUnit::Quarter => local.year() as i64 * 4 + (local.month0() / 3) as i64,
+            DateDiffUnit::Year => local.year() as i64,
+        }
+    }
+}
+
+/// dateDiff(unit, start, end), the number of unit boundaries crossed between start and end.
+/// The unit must be a constant string such as 'second', 'minute', 'hour', 'day', 'week', 'month', 'quarter' or 'year'.
+#[derive(Clone)]
+pub struct DateDiffFunction {
+    display_name: String,
+}
+
+impl DateDiffFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(DateDiffFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for DateDiffFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        3
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args[0] != DataType::String {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of first argument of function {}, expect a string",
+                args[0], self.display_name
+            )));
+        }
+
+        for arg in &args[1..] {
+            if !matches!(
+                arg,
+                DataType::Date16
+                    | DataType::Date32
+                    | DataType::DateTime32(_)
+                    | DataType::DateTime64(_, _)
+            ) {
+                return Result::Err(ErrorCode::IllegalDataType(format!(
+                    "Illegal type {:?} of argument of function {}, expect a date or a datetime",
+                    arg, self.display_name
+                )));
+            }
+        }
+        Ok(DataType::Int64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let unit = match columns[0].column() {
+            DataColumn::Constant(DataValue::String(Some(v)), _) => {
+                DateDiffUnit::try_from_str(&String::from_utf8_lossy(v))?
+            }
+            _ => {
+                return Result::Err(ErrorCode::BadArguments(format!(
+                    "The first argument of function {} must be a constant string",
+                    self.display_name
+                )))
+            }
+        };
+
+        let mut ordinals = Vec::with_capacity(2);
+        for column in &columns[1..] {
+            let data_type = column.data_type();
+            let tz = get_timezone(data_type)?;
+            let series = column.column().resize_constant(input_rows).to_array()?;
+            let values = get_timestamps(&series, data_type)?
+                .into_iter()
+                .map(|v| v.map(|v| unit.ordinal(v, &tz)))
+                .collect::<Vec<_>>();
+            ordinals.push(values);
+        }
+
+        let array =
+            DFInt64Array::new_from_opt_iter(ordinals[0].iter().zip(ordinals[1].iter()).map(
+                |(start, end)| match (start, end) {
+                    (Some(start), Some(end)) => Some(end - start),
+                    _ => None,
+                },
+            ));
+        Ok(array.into())
+    }
+}
+
+impl fmt::Display for DateDiffFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -67,6 +67,387 @@ fn test_to_start_of_function() -> Result<()> {
     Ok(())
 }
 
+#[allow(dead_code)]
+struct FieldTest {
+    name: &'static str,
+    fields: Vec<DataField>,
+    columns: Vec<DataColumn>,
+    expect: Series,
+    error: &'static str,
+    func: Result<Box<dyn Function>>,
+}
+
+fn string_constant(value: &str, rows: usize) -> DataColumn {
+    DataColumn::Constant(DataValue::String(Some(value.as_bytes().to_vec())), rows)
+}
+
+#[test]
+fn test_date_time_timezone_function() -> Result<()> {
+    // 2021-09-05 09:23:17 UTC, Sunday --- 1630833797
+    // 2021-09-05 00:00:00 UTC --- 1630800000
+    let utc = DataField::new("a", DataType::DateTime32(None), false);
+    let shanghai = DataField::new(
+        "a",
+        DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+        false,
+    );
+    let new_york = DataField::new(
+        "a",
+        DataType::DateTime32(Some("America/New_York".to_string())),
+        false,
+    );
+    let kolkata = DataField::new(
+        "a",
+        DataType::DateTime32(Some("Asia/Kolkata".to_string())),
+        false,
+    );
+    let datetimes = || -> DataColumn { Series::new(vec![1630833797u32]).into() };
+
+    let tests = vec![
+        FieldTest {
+            name: "toHour-utc-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: ToHourFunction::try_create("toHour"),
+            expect: Series::new(vec![9u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toHour-shanghai-passed",
+            fields: vec![shanghai.clone()],
+            columns: vec![datetimes()],
+            func: ToHourFunction::try_create("toHour"),
+            expect: Series::new(vec![17u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toMinute-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: ToMinuteFunction::try_create("toMinute"),
+            expect: Series::new(vec![23u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toSecond-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: ToSecondFunction::try_create("toSecond"),
+            expect: Series::new(vec![17u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toDayOfWeek-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: ToDayOfWeekFunction::try_create("toDayOfWeek"),
+            expect: Series::new(vec![7u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toQuarter-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: ToQuarterFunction::try_create("toQuarter"),
+            expect: Series::new(vec![3u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toUnixTimestamp-passed",
+            fields: vec![shanghai.clone()],
+            columns: vec![datetimes()],
+            func: ToUnixTimestampFunction::try_create("toUnixTimestamp"),
+            expect: Series::new(vec![1630833797u32]),
+            error: "",
+        },
+        FieldTest {
+            name: "toYYYYMMDD-new-york-passed",
+            fields: vec![new_york.clone()],
+            columns: vec![Series::new(vec![1630800000u32]).into()],
+            func: ToYYYYMMDDFunction::try_create("toYYYYMMDD"),
+            expect: Series::new(vec![20210904u32]),
+            error: "",
+        },
+        FieldTest {
+            // 2021-09-05 00:00:00 Asia/Shanghai
+            name: "toStartOfDay-shanghai-passed",
+            fields: vec![shanghai.clone()],
+            columns: vec![datetimes()],
+            func: RoundFunction::try_create("toStartOfDay", 60 * 60 * 24),
+            expect: Series::new(vec![1630771200u32]),
+            error: "",
+        },
+        FieldTest {
+            // 2021-09-05 14:00:00 Asia/Kolkata, 30 minutes before the start of the UTC hour
+            name: "toStartOfHour-kolkata-passed",
+            fields: vec![kolkata.clone()],
+            columns: vec![datetimes()],
+            func: RoundFunction::try_create("toStartOfHour", 60 * 60),
+            expect: Series::new(vec![1630830600u32]),
+            error: "",
+        },
+        FieldTest {
+            // 2021-09-04 20:00:00 America/New_York, Saturday
+            name: "toStartOfWeek-new-york-passed",
+            fields: vec![new_york.clone()],
+            columns: vec![Series::new(vec![1630800000u32]).into()],
+            func: ToStartOfWeekFunction::try_create("toStartOfWeek"),
+            expect: Series::new(vec![18869u32]),
+            error: "",
+        },
+        FieldTest {
+            name: "fromUnixTimestamp-passed",
+            fields: vec![DataField::new("a", DataType::UInt32, false)],
+            columns: vec![datetimes()],
+            func: FromUnixTimestampFunction::try_create("fromUnixTimestamp"),
+            expect: Series::new(vec![1630833797u32]),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        do_field_test(t)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_format_parse_date_time_function() -> Result<()> {
+    let datetime = DataField::new("a", DataType::DateTime32(None), false);
+    let shanghai = DataField::new(
+        "a",
+        DataType::DateTime32(Some("Asia/Shanghai".to_string())),
+        false,
+    );
+    let string = DataField::new("b", DataType::String, false);
+
+    let tests = vec![
+        FieldTest {
+            name: "formatDateTime-passed",
+            fields: vec![datetime.clone(), string.clone()],
+            columns: vec![
+                Series::new(vec![1630833797u32, 1630800000u32]).into(),
+                string_constant("%Y-%m-%d %H:%M:%S", 2),
+            ],
+            func: FormatDateTimeFunction::try_create("formatDateTime", false),
+            expect: Series::new(vec!["2021-09-05 09:23:17", "2021-09-05 00:00:00"]),
+            error: "",
+        },
+        FieldTest {
+            name: "formatDateTime-timezone-passed",
+            fields: vec![shanghai, string.clone()],
+            columns: vec![
+                Series::new(vec![1630833797u32]).into(),
+                string_constant("%F %T", 1),
+            ],
+            func: FormatDateTimeFunction::try_create("formatDateTime", false),
+            expect: Series::new(vec!["2021-09-05 17:23:17"]),
+            error: "",
+        },
+        FieldTest {
+            name: "date_format-passed",
+            fields: vec![datetime.clone(), string.clone()],
+            columns: vec![
+                Series::new(vec![1630833797u32]).into(),
+                string_constant("%Y/%c/%e %H:%i:%s %W", 1),
+            ],
+            func: FormatDateTimeFunction::try_create("date_format", true),
+            expect: Series::new(vec!["2021/9/5 09:23:17 Sunday"]),
+            error: "",
+        },
+        FieldTest {
+            name: "date_format-unsupported-specifier",
+            fields: vec![datetime.clone(), string.clone()],
+            columns: vec![
+                Series::new(vec![1630833797u32]).into(),
+                string_constant("%D", 1),
+            ],
+            func: FormatDateTimeFunction::try_create("date_format", true),
+            expect: Series::new(vec![""]),
+            error: "Code: 6, displayText = Unsupported format specifier %D in \"%D\".",
+        },
+        FieldTest {
+            name: "parseDateTime-passed",
+            fields: vec![string.clone(), string.clone()],
+            columns: vec![
+                Series::new(vec!["2021-09-05 09:23:17", "2021-09-05", "abc"]).into(),
+                string_constant("%Y-%m-%d %H:%M:%S", 3),
+            ],
+            func: ParseDateTimeFunction::try_create("parseDateTime", false),
+            expect: Series::new(vec![Some(1630833797u32), None, None]),
+            error: "",
+        },
+        FieldTest {
+            name: "str_to_date-passed",
+            fields: vec![string.clone(), string],
+            columns: vec![
+                Series::new(vec!["2021-09-05", "05/09/2021"]).into(),
+                string_constant("%Y-%m-%d", 2),
+            ],
+            func: ParseDateTimeFunction::try_create("str_to_date", true),
+            expect: Series::new(vec![Some(1630800000u32), None]),
+            error: "",
+        },
+        FieldTest {
+            name: "parseDateTime-timezone-passed",
+            fields: vec![string.clone(), string.clone(), string.clone()],
+            columns: vec![
+                Series::new(vec!["2021-09-05 17:23:17"]).into(),
+                string_constant("%Y-%m-%d %H:%M:%S", 1),
+                string_constant("Asia/Shanghai", 1),
+            ],
+            func: ParseDateTimeFunction::try_create("parseDateTime", false),
+            expect: Series::new(vec![Some(1630833797u32)]),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        do_field_test(t)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_date_diff_function() -> Result<()> {
+    // 2021-09-05 00:00:00 --- 1630800000
+    // 2021-09-05 09:23:17 --- 1630833797
+    // 2021-10-01 00:00:00 --- 1633046400
+    let fields = vec![
+        DataField::new("unit", DataType::String, false),
+        DataField::new("a", DataType::DateTime32(None), false),
+        DataField::new("b", DataType::DateTime32(None), false),
+    ];
+
+    let mut tests = vec![];
+    for (unit, expect) in [
+        ("second", vec![33797i64, 2246400]),
+        ("hour", vec![9, 624]),
+        ("day", vec![0, 26]),
+        ("week", vec![0, 4]),
+        ("month", vec![0, 1]),
+        ("quarter", vec![0, 1]),
+        ("year", vec![0, 0]),
+    ] {
+        tests.push(FieldTest {
+            name: "dateDiff-passed",
+            fields: fields.clone(),
+            columns: vec![
+                string_constant(unit, 2),
+                DataColumn::Constant(DataValue::UInt32(Some(1630800000)), 2),
+                Series::new(vec![1630833797u32, 1633046400u32]).into(),
+            ],
+            func: DateDiffFunction::try_create("dateDiff"),
+            expect: Series::new(expect),
+            error: "",
+        });
+    }
+
+    tests.push(FieldTest {
+        name: "dateDiff-unknown-unit",
+        fields: fields.clone(),
+        columns: vec![
+            string_constant("century", 1),
+            Series::new(vec![1630800000u32]).into(),
+            Series::new(vec![1630833797u32]).into(),
+        ],
+        func: DateDiffFunction::try_create("dateDiff"),
+        expect: Series::new(vec![0i64]),
+        error: "Code: 6, displayText = Unknown unit \"century\" of function dateDiff.",
+    });
+
+    for t in tests {
+        do_field_test(t)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_datetime64_function() -> Result<()> {
+    // 2021-09-05 09:23:17.123 UTC --- 1630833797123
+    let utc = DataField::new("a", DataType::DateTime64(3, None), false);
+    let shanghai = DataField::new(
+        "a",
+        DataType::DateTime64(3, Some("Asia/Shanghai".to_string())),
+        false,
+    );
+    let integer = DataField::new("b", DataType::UInt8, false);
+    let datetimes = || -> DataColumn { Series::new(vec![1630833797123i64]).into() };
+
+    let tests = vec![
+        FieldTest {
+            name: "toHour-datetime64-shanghai-passed",
+            fields: vec![shanghai.clone()],
+            columns: vec![datetimes()],
+            func: ToHourFunction::try_create("toHour"),
+            expect: Series::new(vec![17u8]),
+            error: "",
+        },
+        FieldTest {
+            name: "toStartOfMinute-datetime64-passed",
+            fields: vec![utc.clone()],
+            columns: vec![datetimes()],
+            func: RoundFunction::try_create("toStartOfMinute", 60),
+            expect: Series::new(vec![1630833780u32]),
+            error: "",
+        },
+        FieldTest {
+            name: "addMonths-datetime64-passed",
+            fields: vec![utc.clone(), integer.clone()],
+            columns: vec![datetimes(), Series::new(vec![1u8]).into()],
+            func: MonthsArithmeticFunction::try_create(
+                "addMonths",
+                DataValueArithmeticOperator::Plus,
+                1,
+            ),
+            expect: Series::new(vec![1633425797123i64]),
+            error: "",
+        },
+        FieldTest {
+            name: "subtractSeconds-datetime64-passed",
+            fields: vec![utc.clone(), integer.clone()],
+            columns: vec![datetimes(), Series::new(vec![3u8]).into()],
+            func: SecondsArithmeticFunction::try_create(
+                "subtractSeconds",
+                DataValueArithmeticOperator::Minus,
+                1,
+            ),
+            expect: Series::new(vec![1630833794123i64]),
+            error: "",
+        },
+        FieldTest {
+            name: "formatDateTime-datetime64-passed",
+            fields: vec![utc.clone(), DataField::new("f", DataType::String, false)],
+            columns: vec![datetimes(), string_constant("%Y-%m-%d %H:%M:%S", 1)],
+            func: FormatDateTimeFunction::try_create("formatDateTime", false),
+            expect: Series::new(vec!["2021-09-05 09:23:17"]),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        do_field_test(t)?;
+    }
+    Ok(())
+}
+
+fn do_field_test(t: FieldTest) -> Result<()> {
+    let rows = t.columns[0].len();
+    let columns: Vec<DataColumnWithField> = t
+        .columns
+        .iter()
+        .zip(t.fields.iter())
+        .map(|(c, f)| DataColumnWithField::new(c.clone(), f.clone()))
+        .collect();
+
+    let func = t.func.unwrap();
+    match func.eval(&columns, rows) {
+        Ok(v) => assert_eq!(t.expect.to_values()?, v.to_values()?, "{}", t.name),
+        Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+    }
+    Ok(())
+}
+
 fn do_test(t: Test) -> Result<()> {
     let dummy = DataField::new("dummy", DataType::DateTime32(None), false);
     let rows = t.columns[0].len();
@@ -0,0 +1,281 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::chrono::format::Item;
+use common_datavalues::chrono::format::StrftimeItems;
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::number_function::get_timestamps;
+use super::number_function::get_timezone;
+use crate::scalars::Function;
+
+/// formatDateTime(t, format) and date_format(t, format).
+/// formatDateTime takes strftime patterns, date_format takes MySQL patterns such as '%Y-%m-%d %H:%i:%s'.
+/// The format must be a constant.
+#[derive(Clone)]
+pub struct FormatDateTimeFunction {
+    display_name: String,
+    mysql_dialect: bool,
+}
+
+impl FormatDateTimeFunction {
+    pub fn try_create(display_name: &str, mysql_dialect: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FormatDateTimeFunction {
+            display_name: display_name.to_string(),
+            mysql_dialect,
+        }))
+    }
+}
+
+impl Function for FormatDateTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !matches!(
+            args[0],
+            DataType::Date16
+                | DataType::Date32
+                | DataType::DateTime32(_)
+                | DataType::DateTime64(_, _)
+        ) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expect a date or a datetime",
+                args[0], self.display_name
+            )));
+        }
+
+        if args[1] != DataType::String {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of second argument of function {}, expect a string",
+                args[1], self.display_name
+            )));
+        }
+        Ok(DataType::String)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let format = get_format(&self.display_name, columns[1].column(), self.mysql_dialect)?;
+        let items = StrftimeItems::new(&format).collect::<Vec<_>>();
+
+        let data_type = columns[0].data_type();
+        let tz = get_timezone(data_type)?;
+        let series = columns[0].column().to_minimal_array()?;
+        let array = DFStringArray::new_from_opt_iter(
+            get_timestamps(&series, data_type)?.into_iter().map(|v| {
+                v.map(|v| {
+                    tz.timestamp(v, 0)
+                        .format_with_items(items.iter())
+                        .to_string()
+                })
+            }),
+        );
+
+        let column: DataColumn = array.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for FormatDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// parseDateTime(s, format[, timezone]) and str_to_date(s, format[, timezone]), the inverse of
+/// formatDateTime and date_format. The string is a local time of the timezone, UTC by default.
+/// Returns NULL when the string does not match the format, a pattern without time fields yields midnight.
+#[derive(Clone)]
+pub struct ParseDateTimeFunction {
+    display_name: String,
+    mysql_dialect: bool,
+}
+
+impl ParseDateTimeFunction {
+    pub fn try_create(display_name: &str, mysql_dialect: bool) -> Result<Box<dyn Function>> {
+        Ok(Box::new(ParseDateTimeFunction {
+            display_name: display_name.to_string(),
+            mysql_dialect,
+        }))
+    }
+
+    fn parse(value: &[u8], format: &str, tz: &Tz) -> Option<u32> {
+        let value = std::str::from_utf8(value).ok()?;
+        let date_time = NaiveDateTime::parse_from_str(value, format)
+            .or_else(|_| NaiveDate::parse_from_str(value, format).map(|d| d.and_hms(0, 0, 0)))
+            .ok()?;
+
+        let timestamp = tz.from_local_datetime(&date_time).earliest()?.timestamp();
+        match (0..=u32::MAX as i64).contains(&timestamp) {
+            true => Some(timestamp as u32),
+            false => None,
+        }
+    }
+}
+
+impl Function for ParseDateTimeFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((2, 3))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if args.iter().any(|arg| arg != &DataType::String) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal types {:?} of arguments of function {}, expect strings",
+                args, self.display_name
+            )));
+        }
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(true)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let format = get_format(&self.display_name, columns[1].column(), self.mysql_dialect)?;
+        let tz = match columns.get(2).map(|c| c.column()) {
+            None => Tz::UTC,
+            Some(DataColumn::Constant(DataValue::String(Some(tz)), _)) => {
+                let tz = String::from_utf8_lossy(tz);
+                tz.parse::<Tz>()
+                    .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz)))?
+            }
+            Some(_) => {
+                return Result::Err(ErrorCode::BadArguments(format!(
+                    "The third argument of function {} must be a constant string",
+                    self.display_name
+                )))
+            }
+        };
+
+        let series = columns[0].column().to_minimal_array()?;
+        let array = DFUInt32Array::new_from_opt_iter(
+            series
+                .string()?
+                .collect_values()
+                .into_iter()
+                .map(|v| v.and_then(|v| Self::parse(&v, &format, &tz))),
+        );
+
+        let column: DataColumn = array.into();
+        Ok(column.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for ParseDateTimeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// Extract the constant format argument and check it is a valid strftime pattern.
+fn get_format(display_name: &str, column: &DataColumn, mysql_dialect: bool) -> Result<String> {
+    let format = match column {
+        DataColumn::Constant(DataValue::String(Some(v)), _) => {
+            String::from_utf8_lossy(v).to_string()
+        }
+        _ => {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "The second argument of function {} must be a constant string",
+                display_name
+            )))
+        }
+    };
+
+    let format = match mysql_dialect {
+        true => translate_mysql_format(&format)?,
+        false => format,
+    };
+
+    if StrftimeItems::new(&format).any(|item| matches!(item, Item::Error)) {
+        return Result::Err(ErrorCode::BadArguments(format!(
+            "Invalid format {:?} of function {}",
+            format, display_name
+        )));
+    }
+    Ok(format)
+}
+
+/// Translate MySQL DATE_FORMAT specifiers into their strftime equivalents.
+fn translate_mysql_format(format: &str) -> Result<String> {
+    let mut result = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+
+        let specifier = match chars.next() {
+            Some(specifier) => specifier,
+            None => {
+                result.push_str("%%");
+                break;
+            }
+        };
+
+        match specifier {
+            'a' | 'b' | 'd' | 'H' | 'I' | 'j' | 'm' | 'p' | 'S' | 'U' | 'w' | 'Y' | 'y' => {
+                result.push('%');
+                result.push(specifier);
+            }
+            'c' => result.push_str("%-m"),
+            'e' => result.push_str("%-d"),
+            'f' => result.push_str("%6f"),
+            'h' => result.push_str("%I"),
+            'i' => result.push_str("%M"),
+            'k' => result.push_str("%-H"),
+            'l' => result.push_str("%-I"),
+            'M' => result.push_str("%B"),
+            'r' => result.push_str("%I:%M:%S %p"),
+            's' => result.push_str("%S"),
+            'T' => result.push_str("%H:%M:%S"),
+            'u' => result.push_str("%W"),
+            'v' => result.push_str("%V"),
+            'W' => result.push_str("%A"),
+            'x' => result.push_str("%G"),
+            '%' => result.push_str("%%"),
+            'D' | 'V' | 'X' => {
+                return Result::Err(ErrorCode::BadArguments(format!(
+                    "Unsupported format specifier %{} in {:?}",
+                    specifier, format
+                )))
+            }
+            // MySQL outputs the character itself for unknown specifiers.
+            other => result.push(other),
+        }
+    }
+    Ok(result)
+}
@@ -192,9 +192,10 @@ impl IntervalFunctionFactory {
         let mut date_datetime_opt = None;
         columns.iter().for_each(|column| match column.data_type() {
             DataType::Interval(_) => interval_opt = Some(column),
-            DataType::Date16 | DataType::Date32 | DataType::DateTime32(_) => {
-                date_datetime_opt = Some(column)
-            }
+            DataType::Date16
+            | DataType::Date32
+            | DataType::DateTime32(_)
+            | DataType::DateTime64(_, _) => date_datetime_opt = Some(column),
             _ => {}
         });
 
@@ -237,6 +238,8 @@ impl IntervalFunctionFactory {
     //   4. interval_month_plus_minus_date16 --------- Interval(YearMonth) +/-  Date16
     //   5. interval_month_plus_minus_date32 --------- Interval(YearMonth) +/-  Date32
     //   6. interval_month_plus_minus_datetime32 ----- Interval(YearMonth) +/-  DateTime32
+    //   7. interval_daytime_plus_minus_datetime64 --- Interval(DayTime)   +/-  DateTime64
+    //   8. interval_month_plus_minus_datetime64 ----- Interval(YearMonth) +/-  DateTime64
 
     fn get_interval_arithmetic_func(
         interval: &DataType,
@@ -247,12 +250,14 @@ impl IntervalFunctionFactory {
                 DataType::Date16 => Self::interval_month_plus_minus_date16,
                 DataType::Date32 => Self::interval_month_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_month_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_month_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             DataType::Interval(IntervalUnit::DayTime) => match date_datetime {
                 DataType::Date16 => Self::interval_daytime_plus_minus_date16,
                 DataType::Date32 => Self::interval_daytime_plus_minus_date32,
                 DataType::DateTime32(_) => Self::interval_daytime_plus_minus_datetime32,
+                DataType::DateTime64(_, _) => Self::interval_daytime_plus_minus_datetime64,
                 _ => unreachable!(),
             },
             _ => unreachable!(),
@@ -359,6 +364,42 @@ impl IntervalFunctionFactory {
         Self::month_i64_plus_minus_datetime32(op, interval, datetime, 1)
     }
 
+    fn interval_daytime_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        let ticks = Self::datetime64_ticks(datetime);
+        let res = Self::interval_operation(
+            interval.column().to_array()?.i64()?,
+            datetime.column().to_array()?.i64()?,
+            |ms: &i64, dt: &i64| {
+                // DayTime intervals are counted in milliseconds
+                let delta = match ticks {
+                    1 => *ms / 1000,
+                    _ => *ms * (ticks / 1000),
+                };
+                let r = match op {
+                    DataValueArithmeticOperator::Plus => *dt + delta,
+                    DataValueArithmeticOperator::Minus => *dt - delta,
+                    _ => unreachable!(),
+                };
+                Ok(r)
+            },
+        )?;
+        Ok(res.into())
+    }
+
+    fn interval_month_plus_minus_datetime64(
+        op: &DataValueArithmeticOperator,
+        a: &DataColumnWithField,
+        b: &DataColumnWithField,
+    ) -> Result<DataColumn> {
+        let (interval, datetime) = Self::validate_input(op, a, b)?;
+        Self::month_i64_plus_minus_datetime64(op, interval, datetime, 1)
+    }
+
     //  End of interval typed arithmetic functions.
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -405,6 +446,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::month_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::month_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::month_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::month_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::month_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::month_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::month_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::month_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::month_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -438,6 +490,15 @@ impl IntervalFunctionFactory {
     crate::define_month_plus_minus_datetime32!(month_u16_plus_minus_datetime32, u16);
     crate::define_month_plus_minus_datetime32!(month_u8_plus_minus_datetime32, u8);
 
+    crate::define_month_plus_minus_datetime64!(month_i64_plus_minus_datetime64, i64);
+    crate::define_month_plus_minus_datetime64!(month_i32_plus_minus_datetime64, i32);
+    crate::define_month_plus_minus_datetime64!(month_i16_plus_minus_datetime64, i16);
+    crate::define_month_plus_minus_datetime64!(month_i8_plus_minus_datetime64, i8);
+    crate::define_month_plus_minus_datetime64!(month_u64_plus_minus_datetime64, u64);
+    crate::define_month_plus_minus_datetime64!(month_u32_plus_minus_datetime64, u32);
+    crate::define_month_plus_minus_datetime64!(month_u16_plus_minus_datetime64, u16);
+    crate::define_month_plus_minus_datetime64!(month_u8_plus_minus_datetime64, u8);
+
     //  End of months integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
@@ -484,6 +545,17 @@ impl IntervalFunctionFactory {
                 DataType::Int64 => Self::time_secs_i64_plus_minus_datetime32,
                 _ => unreachable!(),
             },
+            DataType::DateTime64(_, _) => match integer {
+                DataType::UInt8 => Self::time_secs_u8_plus_minus_datetime64,
+                DataType::UInt16 => Self::time_secs_u16_plus_minus_datetime64,
+                DataType::UInt32 => Self::time_secs_u32_plus_minus_datetime64,
+                DataType::UInt64 => Self::time_secs_u64_plus_minus_datetime64,
+                DataType::Int8 => Self::time_secs_i8_plus_minus_datetime64,
+                DataType::Int16 => Self::time_secs_i16_plus_minus_datetime64,
+                DataType::Int32 => Self::time_secs_i32_plus_minus_datetime64,
+                DataType::Int64 => Self::time_secs_i64_plus_minus_datetime64,
+                _ => unreachable!(),
+            },
             _ => unreachable!(),
         }
     }
@@ -517,11 +589,20 @@ impl IntervalFunctionFactory {
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u16_plus_minus_datetime32, u16);
     crate::define_time_secs_plus_minus_datetime32!(time_secs_u8_plus_minus_datetime32, u8);
 
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i64_plus_minus_datetime64, i64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i32_plus_minus_datetime64, i32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i16_plus_minus_datetime64, i16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_i8_plus_minus_datetime64, i8);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u64_plus_minus_datetime64, u64);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u32_plus_minus_datetime64, u32);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u16_plus_minus_datetime64, u16);
+    crate::define_time_secs_plus_minus_datetime64!(time_secs_u8_plus_minus_datetime64, u8);
+
     // End of seconds integer arithmetic functions
     //////////////////////////////////////////////////////////////////////////////////
 
     // A private helper function for validate operator, returns a tuple of
-    // (interval|integer, date16|date32|datetime32)
+    // (interval|integer, date16|date32|datetime32|datetime64)
     fn validate_input<'a>(
         op: &DataValueArithmeticOperator,
         col0: &'a DataColumnWithField,
@@ -608,6 +689,14 @@ impl IntervalFunctionFactory {
         last_day_lookup[month as usize]
     }
 
+    // A private helper function to get the number of ticks per second of a datetime64 column
+    fn datetime64_ticks(datetime: &DataColumnWithField) -> i64 {
+        match datetime.data_type() {
+            DataType::DateTime64(precision, _) => datetime64_ticks_per_second(*precision),
+            _ => 1,
+        }
+    }
+
     // A private helper function to convert seconds (since Unix epoch) to chrono DateTime
     fn seconds_to_datetime(seconds: i64) -> Result<DateTime<Utc>> {
         let naive = NaiveDateTime::from_timestamp_opt(seconds, 0);
@@ -654,6 +743,40 @@ macro_rules! define_month_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_month_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            a: &DataColumnWithField,
+            b: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let (interval_months, datetime64) = Self::validate_input(op, a, b)?;
+            let ticks = Self::datetime64_ticks(datetime64);
+
+            let res = Self::interval_operation(
+                interval_months.column().to_array()?.$type()?,
+                datetime64.column().to_array()?.i64()?,
+                |months: &$type, value: &i64| {
+                    let dt = Self::seconds_to_datetime(value.div_euclid(ticks))?;
+                    let new_dt = match op {
+                        DataValueArithmeticOperator::Plus => {
+                            Self::datetime_plus_signed_months(&dt, (*months as i64) * mul)?
+                        }
+                        DataValueArithmeticOperator::Minus => {
+                            Self::datetime_plus_signed_months(&dt, -(*months as i64) * mul)?
+                        }
+                        _ => unreachable!(),
+                    };
+                    Ok(new_dt.timestamp() * ticks + value.rem_euclid(ticks))
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_month_plus_minus_date {
     ($fn_name:ident, $month_type:ident, $date_type:ident) => {
@@ -714,6 +837,33 @@ macro_rules! define_time_secs_plus_minus_datetime32 {
     };
 }
 
+#[macro_export]
+macro_rules! define_time_secs_plus_minus_datetime64 {
+    ($fn_name:ident, $type:ident) => {
+        fn $fn_name(
+            op: &DataValueArithmeticOperator,
+            interval: &DataColumnWithField,
+            datetime: &DataColumnWithField,
+            mul: i64,
+        ) -> Result<DataColumn> {
+            let ticks = Self::datetime64_ticks(datetime);
+            let res = Self::interval_operation(
+                interval.column().to_array()?.$type()?,
+                datetime.column().to_array()?.i64()?,
+                |secs: &$type, dt: &i64| {
+                    let r = match op {
+                        DataValueArithmeticOperator::Plus => *dt + *secs as i64 * mul * ticks,
+                        DataValueArithmeticOperator::Minus => *dt - *secs as i64 * mul * ticks,
+                        _ => unreachable!(),
+                    };
+                    Ok(r)
+                },
+            )?;
+            Ok(res.into())
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! define_time_secs_plus_minus_date {
     ($fn_name:ident, $seconds_type:ident, $date_type:ident) => {
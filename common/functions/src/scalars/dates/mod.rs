@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod date;
+mod date_diff;
 #[cfg(test)]
 mod date_function_test;
 #[cfg(test)]
@@ -21,22 +22,33 @@ mod date_test;
 #[cfg(test)]
 mod interval_function_test;
 
+mod format_function;
 mod interval_function;
 mod now;
 mod number_function;
 mod round_function;
 mod simple_date;
+mod unix_timestamp;
 mod week_date;
 
 pub use date::DateFunction;
+pub use date_diff::DateDiffFunction;
+pub use format_function::FormatDateTimeFunction;
+pub use format_function::ParseDateTimeFunction;
 pub use interval_function::IntervalArithmeticFunction;
 pub use interval_function::IntervalFunctionFactory;
 pub use interval_function::MonthsArithmeticFunction;
 pub use interval_function::SecondsArithmeticFunction;
+pub use number_function::ToDayOfWeekFunction;
+pub use number_function::ToHourFunction;
+pub use number_function::ToMinuteFunction;
+pub use number_function::ToQuarterFunction;
+pub use number_function::ToSecondFunction;
 pub use number_function::ToStartOfISOYearFunction;
 pub use number_function::ToStartOfMonthFunction;
 pub use number_function::ToStartOfQuarterFunction;
 pub use number_function::ToStartOfYearFunction;
+pub use number_function::ToUnixTimestampFunction;
 pub use number_function::ToYYYYMMDDFunction;
 pub use number_function::ToYYYYMMDDhhmmssFunction;
 pub use number_function::ToYYYYMMFunction;
@@ -44,4 +56,5 @@ pub use round_function::RoundFunction;
 pub use simple_date::TodayFunction;
 pub use simple_date::TomorrowFunction;
 pub use simple_date::YesterdayFunction;
+pub use unix_timestamp::FromUnixTimestampFunction;
 pub use week_date::ToStartOfWeekFunction;
@@ -51,6 +51,10 @@ impl Function for NowFunction {
         let value = DataValue::UInt32(Some((utc.timestamp_millis() / 1000) as u32));
         Ok(DataColumn::Constant(value, input_rows))
     }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
 }
 
 impl fmt::Display for NowFunction {
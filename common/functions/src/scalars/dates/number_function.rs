@@ -17,10 +17,12 @@ use std::marker::PhantomData;
 
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Timelike;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -35,8 +37,8 @@ pub struct NumberFunction<T, R> {
 
 pub trait NumberResultFunction<R> {
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -46,11 +48,11 @@ impl NumberResultFunction<u32> for ToYYYYMM {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 100 + value.month()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -62,11 +64,11 @@ impl NumberResultFunction<u32> for ToYYYYMMDD {
     fn return_type() -> Result<DataType> {
         Ok(DataType::UInt32)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         value.year() as u32 * 10000 + value.month() * 100 + value.day()
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt32(Some(Self::to_number(value)))
     }
 }
@@ -79,7 +81,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
         Ok(DataType::UInt64)
     }
 
-    fn to_number(value: DateTime<Utc>) -> u64 {
+    fn to_number(value: DateTime<Tz>) -> u64 {
         value.year() as u64 * 10000000000
             + value.month() as u64 * 100000000
             + value.day() as u64 * 1000000
@@ -88,7 +90,7 @@ impl NumberResultFunction<u64> for ToYYYYMMDDhhmmss {
             + value.second() as u64
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt64(Some(Self::to_number(value)))
     }
 }
@@ -100,12 +102,11 @@ impl NumberResultFunction<u32> for ToStartOfYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
-        let end: DateTime<Utc> = Utc.ymd(value.year(), 1, 1).and_hms(0, 0, 0);
-        get_day(end)
+    fn to_number(value: DateTime<Tz>) -> u32 {
+        get_day(NaiveDate::from_ymd(value.year(), 1, 1))
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -117,17 +118,15 @@ impl NumberResultFunction<u32> for ToStartOfISOYear {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         let week_day = value.weekday().num_days_from_monday();
         let iso_week = value.iso_week();
         let iso_week_num = iso_week.week();
         let sub_days = (iso_week_num - 1) * 7 + week_day;
-        let result = value.timestamp_millis() - sub_days as i64 * 24 * 3600 * 1000;
-        let end: DateTime<Utc> = Utc.timestamp_millis(result);
-        get_day(end)
+        get_day(value.date().naive_local() - Duration::days(sub_days as i64))
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -139,13 +138,12 @@ impl NumberResultFunction<u32> for ToStartOfQuarter {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
+    fn to_number(value: DateTime<Tz>) -> u32 {
         let new_month = value.month0() / 3 * 3 + 1;
-        let date = Utc.ymd(value.year(), new_month, 1).and_hms(0, 0, 0);
-        get_day(date)
+        get_day(NaiveDate::from_ymd(value.year(), new_month, 1))
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
@@ -157,16 +155,111 @@ impl NumberResultFunction<u32> for ToStartOfMonth {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>) -> u32 {
-        let date = Utc.ymd(value.year(), value.month(), 1).and_hms(0, 0, 0);
-        get_day(date)
+    fn to_number(value: DateTime<Tz>) -> u32 {
+        get_day(NaiveDate::from_ymd(value.year(), value.month(), 1))
     }
 
-    fn to_constant_value(value: DateTime<Utc>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value) as u16))
     }
 }
 
+#[derive(Clone)]
+pub struct ToQuarter;
+
+impl NumberResultFunction<u8> for ToQuarter {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Tz>) -> u8 {
+        (value.month0() / 3 + 1) as u8
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToDayOfWeek;
+
+impl NumberResultFunction<u8> for ToDayOfWeek {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Tz>) -> u8 {
+        value.weekday().number_from_monday() as u8
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToHour;
+
+impl NumberResultFunction<u8> for ToHour {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Tz>) -> u8 {
+        value.hour() as u8
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToMinute;
+
+impl NumberResultFunction<u8> for ToMinute {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Tz>) -> u8 {
+        value.minute() as u8
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToSecond;
+
+impl NumberResultFunction<u8> for ToSecond {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt8)
+    }
+    fn to_number(value: DateTime<Tz>) -> u8 {
+        value.second() as u8
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt8(Some(Self::to_number(value)))
+    }
+}
+
+#[derive(Clone)]
+pub struct ToUnixTimestamp;
+
+impl NumberResultFunction<u32> for ToUnixTimestamp {
+    fn return_type() -> Result<DataType> {
+        Ok(DataType::UInt32)
+    }
+    fn to_number(value: DateTime<Tz>) -> u32 {
+        value.timestamp() as u32
+    }
+
+    fn to_constant_value(value: DateTime<Tz>) -> DataValue {
+        DataValue::UInt32(Some(Self::to_number(value)))
+    }
+}
+
 impl<T, R> NumberFunction<T, R>
 where
     T: NumberResultFunction<R> + Clone + Sync + Send + 'static,
@@ -206,10 +299,11 @@ where
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
+        let tz = get_timezone(data_type)?;
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64().unwrap() as i64 * 24 * 3600, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64().unwrap() as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -217,7 +311,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = tz.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -226,7 +320,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64().unwrap() as i64 * 24 * 3600, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64().unwrap() as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -234,7 +328,7 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = tz.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -243,7 +337,7 @@ where
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64().unwrap() as i64, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64().unwrap() as i64, 0_u32);
                     let constant_result = T::to_constant_value(date_time);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -251,7 +345,25 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, _) => {
+                let ticks = datetime64_ticks_per_second(*precision);
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = tz.timestamp(v.as_i64().unwrap().div_euclid(ticks), 0_u32);
+                    let constant_result = T::to_constant_value(date_time);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = tz.timestamp(v.div_euclid(ticks), 0_u32);
                             T::to_number(date_time)
                         }
                         );
@@ -259,7 +371,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -273,12 +385,60 @@ impl<T, R> fmt::Display for NumberFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
+fn get_day(date: NaiveDate) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
     let duration = date.signed_duration_since(start);
     duration.num_days() as u32
 }
 
+/// Resolve the timezone a date/datetime column should be evaluated in.
+/// Dates and datetimes without an explicit timezone are treated as UTC.
+pub(crate) fn get_timezone(data_type: &DataType) -> Result<Tz> {
+    match data_type {
+        DataType::DateTime32(Some(tz)) | DataType::DateTime64(_, Some(tz)) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))),
+        _ => Ok(Tz::UTC),
+    }
+}
+
+/// Convert a date/datetime series to seconds since the epoch, dates map to their midnight.
+pub(crate) fn get_timestamps(series: &Series, data_type: &DataType) -> Result<Vec<Option<i64>>> {
+    match data_type {
+        DataType::Date16 => Ok(series
+            .u16()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64 * 24 * 3600))
+            .collect()),
+        DataType::Date32 => Ok(series
+            .u32()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64 * 24 * 3600))
+            .collect()),
+        DataType::DateTime32(_) => Ok(series
+            .u32()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64))
+            .collect()),
+        DataType::DateTime64(precision, _) => {
+            let ticks = datetime64_ticks_per_second(*precision);
+            Ok(series
+                .i64()?
+                .collect_values()
+                .into_iter()
+                .map(|v| v.map(|v| v.div_euclid(ticks)))
+                .collect())
+        }
+        other => Result::Err(ErrorCode::IllegalDataType(format!(
+            "Illegal type {:?}, should be a date16/data32 or a dateTime32/dateTime64",
+            other
+        ))),
+    }
+}
+
 pub type ToYYYYMMFunction = NumberFunction<ToYYYYMM, u32>;
 pub type ToYYYYMMDDFunction = NumberFunction<ToYYYYMMDD, u32>;
 pub type ToYYYYMMDDhhmmssFunction = NumberFunction<ToYYYYMMDDhhmmss, u64>;
@@ -286,3 +446,9 @@ pub type ToStartOfISOYearFunction = NumberFunction<ToStartOfISOYear, u32>;
 pub type ToStartOfYearFunction = NumberFunction<ToStartOfYear, u32>;
 pub type ToStartOfQuarterFunction = NumberFunction<ToStartOfQuarter, u32>;
 pub type ToStartOfMonthFunction = NumberFunction<ToStartOfMonth, u32>;
+pub type ToQuarterFunction = NumberFunction<ToQuarter, u8>;
+pub type ToDayOfWeekFunction = NumberFunction<ToDayOfWeek, u8>;
+pub type ToHourFunction = NumberFunction<ToHour, u8>;
+pub type ToMinuteFunction = NumberFunction<ToMinute, u8>;
+pub type ToSecondFunction = NumberFunction<ToSecond, u8>;
+pub type ToUnixTimestampFunction = NumberFunction<ToUnixTimestamp, u32>;
@@ -14,9 +14,14 @@
 
 use std::fmt;
 
+use common_datavalues::chrono::NaiveDateTime;
+use common_datavalues::chrono::Offset;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::Result;
 
+use super::number_function::get_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone)]
@@ -35,12 +40,24 @@ impl RoundFunction {
         Ok(Box::new(s))
     }
 
-    // TODO: (sundy-li)
-    // Consider about the timezones/offsets
-    // Currently: assuming timezone offset is a multiple of round.
+    /// Rounds the time down on the wall clock of the timezone.
     #[inline]
-    fn execute(&self, time: u32) -> u32 {
-        time / self.round * self.round
+    fn execute(&self, time: u32, tz: &Tz) -> u32 {
+        if *tz == Tz::UTC {
+            return time / self.round * self.round;
+        }
+
+        let utc = NaiveDateTime::from_timestamp(time as i64, 0);
+        let offset = tz.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64;
+        let local = (time as i64 + offset).div_euclid(self.round as i64) * self.round as i64;
+        // the rounded wall clock time may be skipped by a DST transition
+        match tz
+            .from_local_datetime(&NaiveDateTime::from_timestamp(local, 0))
+            .earliest()
+        {
+            Some(rounded) => rounded.timestamp() as u32,
+            None => (local - offset) as u32,
+        }
     }
 }
 
@@ -49,8 +66,13 @@ impl Function for RoundFunction {
         self.display_name.as_str()
     }
 
-    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
-        Ok(DataType::DateTime32(None))
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match &args[0] {
+            DataType::DateTime32(tz) | DataType::DateTime64(_, tz) => {
+                Ok(DataType::DateTime32(tz.clone()))
+            }
+            _ => Ok(DataType::DateTime32(None)),
+        }
     }
 
     fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
@@ -58,10 +80,34 @@ impl Function for RoundFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let tz = get_timezone(columns[0].data_type())?;
+        // DateTime64 is rounded on its whole seconds
+        if let DataType::DateTime64(precision, _) = columns[0].data_type() {
+            let ticks = datetime64_ticks_per_second(*precision);
+            return match columns[0].column() {
+                DataColumn::Array(array) => {
+                    let arr: DFUInt32Array = array
+                        .i64()?
+                        .apply_cast_numeric(|x| self.execute(x.div_euclid(ticks) as u32, &tz));
+                    Ok(DataColumn::Array(arr.into_series()))
+                }
+                DataColumn::Constant(v, rows) => {
+                    if v.is_null() {
+                        return Ok(DataColumn::Constant(DataValue::UInt32(None), *rows));
+                    }
+                    let value = v.as_i64()?.div_euclid(ticks);
+                    Ok(DataColumn::Constant(
+                        DataValue::UInt32(Some(self.execute(value as u32, &tz))),
+                        *rows,
+                    ))
+                }
+            };
+        }
+
         match columns[0].column() {
             DataColumn::Array(array) => {
                 let array = array.u32()?;
-                let arr = array.apply(|x| self.execute(x));
+                let arr = array.apply(|x| self.execute(x, &tz));
                 Ok(DataColumn::Array(arr.into_series()))
             }
             DataColumn::Constant(v, rows) => {
@@ -70,7 +116,7 @@ impl Function for RoundFunction {
                 }
                 let value = v.as_u64()?;
                 Ok(DataColumn::Constant(
-                    DataValue::UInt32(Some(self.execute(value as u32))),
+                    DataValue::UInt32(Some(self.execute(value as u32, &tz))),
                     *rows,
                 ))
             }
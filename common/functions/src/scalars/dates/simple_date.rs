@@ -106,6 +106,10 @@ where T: NoArgDateFunction + Clone + Sync + Send + 'static
             input_rows,
         ))
     }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
 }
 
 impl<T> fmt::Display for SimpleFunction<T> {
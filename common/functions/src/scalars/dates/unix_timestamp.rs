@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// fromUnixTimestamp(n), convert the seconds since the epoch into a DateTime.
+#[derive(Clone)]
+pub struct FromUnixTimestampFunction {
+    display_name: String,
+}
+
+impl FromUnixTimestampFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(FromUnixTimestampFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for FromUnixTimestampFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !is_integer(&args[0]) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expect an integer",
+                args[0], self.display_name
+            )));
+        }
+        Ok(DataType::DateTime32(None))
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        columns[0]
+            .column()
+            .cast_with_type(&DataType::UInt32)
+            .map(|c| c.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for FromUnixTimestampFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -19,12 +19,14 @@ use std::ops::Sub;
 use common_datavalues::chrono::DateTime;
 use common_datavalues::chrono::Datelike;
 use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::NaiveDate;
 use common_datavalues::chrono::TimeZone;
-use common_datavalues::chrono::Utc;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use super::number_function::get_timezone;
 use crate::scalars::Function;
 
 #[derive(Clone, Debug)]
@@ -36,8 +38,8 @@ pub struct WeekFunction<T, R> {
 
 pub trait WeekResultFunction<R> {
     fn return_type() -> Result<DataType>;
-    fn to_number(_value: DateTime<Utc>, mode: Option<u64>) -> R;
-    fn to_constant_value(_value: DateTime<Utc>, mode: Option<u64>) -> DataValue;
+    fn to_number(_value: DateTime<Tz>, mode: Option<u64>) -> R;
+    fn to_constant_value(_value: DateTime<Tz>, mode: Option<u64>) -> DataValue;
 }
 
 #[derive(Clone)]
@@ -47,7 +49,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
     fn return_type() -> Result<DataType> {
         Ok(DataType::Date16)
     }
-    fn to_number(value: DateTime<Utc>, mode: Option<u64>) -> u32 {
+    fn to_number(value: DateTime<Tz>, mode: Option<u64>) -> u32 {
         let week_mode = mode.unwrap_or(0);
         let mut weekday = value.weekday().number_from_sunday();
         if week_mode & 1 == 1 {
@@ -59,7 +61,7 @@ impl WeekResultFunction<u32> for ToStartOfWeek {
         get_day(result)
     }
 
-    fn to_constant_value(value: DateTime<Utc>, mode: Option<u64>) -> DataValue {
+    fn to_constant_value(value: DateTime<Tz>, mode: Option<u64>) -> DataValue {
         DataValue::UInt16(Some(Self::to_number(value, mode) as u16))
     }
 }
@@ -103,6 +105,7 @@ where
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
         let data_type = columns[0].data_type();
+        let tz = get_timezone(data_type)?;
         let mut mode: Option<u64> = None;
         if columns.len() == 2 && !columns[1].column().is_empty() {
             let week_mode = columns[1].column().to_values()?[0].clone().as_u64()?;
@@ -117,7 +120,7 @@ where
         let number_array: DataColumn = match data_type {
             DataType::Date16 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -125,7 +128,7 @@ where
                         .to_array()?
                         .u16()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = tz.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -134,7 +137,7 @@ where
             },
             DataType::Date32 => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64()? as i64 * 24 * 3600, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -142,7 +145,7 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64 * 24 * 3600, 0_u32);
+                            let date_time = tz.timestamp(v as i64 * 24 * 3600, 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -151,7 +154,7 @@ where
             },
             DataType::DateTime32(_) => {
                 if let DataColumn::Constant(v, _) = columns[0].column() {
-                    let date_time = Utc.timestamp(v.as_u64()? as i64, 0_u32);
+                    let date_time = tz.timestamp(v.as_u64()? as i64, 0_u32);
                     let constant_result = T::to_constant_value(date_time, mode);
                     Ok(DataColumn::Constant(constant_result, input_rows))
                 } else {
@@ -159,7 +162,25 @@ where
                         .to_array()?
                         .u32()?
                         .apply_cast_numeric(|v| {
-                            let date_time = Utc.timestamp(v as i64, 0_u32);
+                            let date_time = tz.timestamp(v as i64, 0_u32);
+                            T::to_number(date_time, mode)
+                        }
+                        );
+                    Ok(result.into())
+                }
+            },
+            DataType::DateTime64(precision, _) => {
+                let ticks = datetime64_ticks_per_second(*precision);
+                if let DataColumn::Constant(v, _) = columns[0].column() {
+                    let date_time = tz.timestamp(v.as_i64()?.div_euclid(ticks), 0_u32);
+                    let constant_result = T::to_constant_value(date_time, mode);
+                    Ok(DataColumn::Constant(constant_result, input_rows))
+                } else {
+                    let result = columns[0].column()
+                        .to_array()?
+                        .i64()?
+                        .apply_cast_numeric(|v| {
+                            let date_time = tz.timestamp(v.div_euclid(ticks), 0_u32);
                             T::to_number(date_time, mode)
                         }
                         );
@@ -167,7 +188,7 @@ where
                 }
             },
             other => Result::Err(ErrorCode::IllegalDataType(format!(
-                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32",
+                "Illegal type {:?} of argument of function {}.Should be a date16/data32 or a dateTime32/dateTime64",
                 other,
                 self.name()))),
         }?;
@@ -181,9 +202,9 @@ impl<T, R> fmt::Display for WeekFunction<T, R> {
     }
 }
 
-fn get_day(date: DateTime<Utc>) -> u32 {
-    let start: DateTime<Utc> = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
-    let duration = date.signed_duration_since(start);
+fn get_day(date: DateTime<Tz>) -> u32 {
+    let start = NaiveDate::from_ymd(1970, 1, 1);
+    let duration = date.naive_local().date().signed_duration_since(start);
     duration.num_days() as u32
 }
 
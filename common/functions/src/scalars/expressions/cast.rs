@@ -14,10 +14,12 @@
 
 use std::fmt;
 
+use common_datavalues::chrono::NaiveDate;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::columns::DataColumn;
-use common_datavalues::prelude::DataColumnsWithField;
-use common_datavalues::DataSchema;
-use common_datavalues::DataType;
+use common_datavalues::prelude::*;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::scalars::Function;
@@ -53,8 +55,18 @@ impl Function for CastFunction {
     }
 
     fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let from_type = columns[0].data_type();
         let series = columns[0].column().clone().to_minimal_array()?;
-        let column: DataColumn = series.cast_with_type(&self.cast_type)?.into();
+        let series = match (from_type, &self.cast_type) {
+            (_, DataType::DateTime64(precision, tz)) => {
+                cast_to_datetime64(&series, from_type, *precision, tz)?
+            }
+            (DataType::DateTime64(precision, tz), _) => {
+                cast_from_datetime64(&series, *precision, tz, &self.cast_type)?
+            }
+            _ => series.cast_with_type(&self.cast_type)?,
+        };
+        let column: DataColumn = series.into();
         Ok(column.resize_constant(input_rows))
     }
 
@@ -68,3 +80,161 @@ impl fmt::Display for CastFunction {
         write!(f, "CAST")
     }
 }
+
+fn parse_timezone(tz: &Option<String>) -> Result<Tz> {
+    match tz {
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))),
+        None => Ok(Tz::UTC),
+    }
+}
+
+/// Convert a date/datetime, string or number series to DateTime64(precision) ticks.
+/// Numbers are taken as seconds since the epoch, the result is truncated to `precision` digits.
+pub(crate) fn cast_to_datetime64(
+    series: &Series,
+    from_type: &DataType,
+    precision: u32,
+    tz: &Option<String>,
+) -> Result<Series> {
+    let ticks = datetime64_ticks_per_second(precision);
+    let step = (ticks / 10_i64.pow(precision.min(9))).max(1);
+    let seconds_per_day = 24 * 3600_i64;
+
+    let values: Vec<Option<i64>> = match from_type {
+        DataType::Null => return series.cast_with_type(&DataType::DateTime64(precision, None)),
+        DataType::Date16 => series
+            .u16()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64 * seconds_per_day * ticks))
+            .collect(),
+        DataType::Date32 => series
+            .u32()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64 * seconds_per_day * ticks))
+            .collect(),
+        DataType::DateTime32(_) => series
+            .u32()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v as i64 * ticks))
+            .collect(),
+        DataType::DateTime64(from_precision, _) => {
+            let from_ticks = datetime64_ticks_per_second(*from_precision);
+            series
+                .i64()?
+                .collect_values()
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| match from_ticks <= ticks {
+                        true => v * (ticks / from_ticks),
+                        false => v.div_euclid(from_ticks / ticks),
+                    })
+                })
+                .collect()
+        }
+        DataType::String => {
+            let tz = parse_timezone(tz)?;
+            series
+                .string()?
+                .collect_values()
+                .into_iter()
+                .map(|v| v.map(|v| parse_datetime64(&v, ticks, &tz)).transpose())
+                .collect::<Result<_>>()?
+        }
+        dt if is_floating(dt) => series
+            .cast_with_type(&DataType::Float64)?
+            .f64()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| (v * ticks as f64) as i64))
+            .collect(),
+        dt if is_integer(dt) => series
+            .cast_with_type(&DataType::Int64)?
+            .i64()?
+            .collect_values()
+            .into_iter()
+            .map(|v| v.map(|v| v * ticks))
+            .collect(),
+        other => {
+            return Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unsupported cast from {:?} to DateTime64({})",
+                other, precision
+            )))
+        }
+    };
+
+    let array = DFInt64Array::new_from_opt_iter(
+        values
+            .into_iter()
+            .map(|v| v.map(|v| v - v.rem_euclid(step))),
+    );
+    Ok(array.into_series())
+}
+
+/// Convert DateTime64(precision) ticks to dates, datetimes or strings.
+/// Other types get the raw ticks.
+pub(crate) fn cast_from_datetime64(
+    series: &Series,
+    precision: u32,
+    tz: &Option<String>,
+    to_type: &DataType,
+) -> Result<Series> {
+    let ticks = datetime64_ticks_per_second(precision);
+    let ticks_per_day = 24 * 3600 * ticks;
+    let array = series.i64()?;
+
+    match to_type {
+        DataType::Date16 => {
+            let array: DFUInt16Array =
+                array.apply_cast_numeric(|v| v.div_euclid(ticks_per_day) as u16);
+            Ok(array.into_series())
+        }
+        DataType::Date32 => {
+            let array: DFUInt32Array =
+                array.apply_cast_numeric(|v| v.div_euclid(ticks_per_day) as u32);
+            Ok(array.into_series())
+        }
+        DataType::DateTime32(_) => {
+            let array: DFUInt32Array = array.apply_cast_numeric(|v| v.div_euclid(ticks) as u32);
+            Ok(array.into_series())
+        }
+        DataType::String => {
+            let tz = parse_timezone(tz)?;
+            let array = DFStringArray::new_from_opt_iter(
+                array
+                    .collect_values()
+                    .into_iter()
+                    .map(|v| v.map(|v| format_date_time64(v, precision, &tz))),
+            );
+            Ok(array.into_series())
+        }
+        _ => series.cast_with_type(to_type),
+    }
+}
+
+fn parse_datetime64(value: &[u8], ticks: i64, tz: &Tz) -> Result<i64> {
+    let value = String::from_utf8_lossy(value);
+    let value = value.trim();
+    if let Ok(date_time) = tz.datetime_from_str(value, "%Y-%m-%d %H:%M:%S%.f") {
+        let fraction = date_time.timestamp_subsec_nanos() as i64 / (1_000_000_000 / ticks);
+        return Ok(date_time.timestamp() * ticks + fraction);
+    }
+
+    match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => match tz.from_local_datetime(&date.and_hms(0, 0, 0)).single() {
+            Some(date_time) => Ok(date_time.timestamp() * ticks),
+            None => Result::Err(ErrorCode::BadBytes(format!(
+                "Cannot parse value {:?} to DateTime64 type",
+                value
+            ))),
+        },
+        Err(_) => Result::Err(ErrorCode::BadBytes(format!(
+            "Cannot parse value {:?} to DateTime64 type",
+            value
+        ))),
+    }
+}
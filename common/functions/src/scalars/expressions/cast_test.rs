@@ -124,3 +124,106 @@ fn test_cast_function() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_cast_datetime64_function() -> Result<()> {
+    struct Test {
+        name: &'static str,
+        from_type: DataType,
+        to_type: DataType,
+        column: DataColumn,
+        expect: Series,
+        error: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "cast-string-to-datetime64-passed",
+            from_type: DataType::String,
+            to_type: DataType::DateTime64(3, None),
+            column: Series::new(vec!["2021-09-05 09:23:17.123456", "2021-09-05"]).into(),
+            expect: Series::new(vec![1630833797123i64, 1630800000000]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-datetime64-truncated-passed",
+            from_type: DataType::String,
+            to_type: DataType::DateTime64(2, None),
+            column: Series::new(vec!["2021-09-05 09:23:17.129"]).into(),
+            expect: Series::new(vec![1630833797120i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-string-to-datetime64-failed",
+            from_type: DataType::String,
+            to_type: DataType::DateTime64(3, None),
+            column: Series::new(vec!["2021-09-05 09:23"]).into(),
+            expect: Series::new(vec![0i64]),
+            error: "Code: 46, displayText = Cannot parse value \"2021-09-05 09:23\" to DateTime64 type.",
+        },
+        Test {
+            name: "cast-date16-to-datetime64-passed",
+            from_type: DataType::Date16,
+            to_type: DataType::DateTime64(0, None),
+            column: Series::new(vec![18875u16]).into(),
+            expect: Series::new(vec![1630800000i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime32-to-datetime64-passed",
+            from_type: DataType::DateTime32(None),
+            to_type: DataType::DateTime64(6, None),
+            column: Series::new(vec![1630833797u32]).into(),
+            expect: Series::new(vec![1630833797000000i64]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-datetime64-passed",
+            from_type: DataType::DateTime64(6, None),
+            to_type: DataType::DateTime64(3, None),
+            column: Series::new(vec![1630833797123456i64, -1]).into(),
+            expect: Series::new(vec![1630833797123i64, -1]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-datetime32-passed",
+            from_type: DataType::DateTime64(3, None),
+            to_type: DataType::DateTime32(None),
+            column: Series::new(vec![1630833797123i64]).into(),
+            expect: Series::new(vec![1630833797u32]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-date16-passed",
+            from_type: DataType::DateTime64(3, None),
+            to_type: DataType::Date16,
+            column: Series::new(vec![1630833797123i64]).into(),
+            expect: Series::new(vec![18875u16]),
+            error: "",
+        },
+        Test {
+            name: "cast-datetime64-to-string-passed",
+            from_type: DataType::DateTime64(3, None),
+            to_type: DataType::String,
+            column: Series::new(vec![1630833797123i64, -1]).into(),
+            expect: Series::new(vec!["2021-09-05 09:23:17.123", "1969-12-31 23:59:59.999"]),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let rows = t.column.len();
+        let field = DataField::new("dummy", t.from_type.clone(), false);
+        let columns = vec![DataColumnWithField::new(t.column.clone(), field)];
+
+        let func = CastFunction::create("cast".to_string(), t.to_type.clone())?;
+        match func.eval(&columns, rows) {
+            Ok(v) => {
+                let c: DataColumn = t.expect.into();
+                assert_eq!(&v, &c, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+    Ok(())
+}
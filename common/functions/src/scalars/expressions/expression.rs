@@ -63,6 +63,9 @@ impl ToCastFunction {
             map.insert("toDateTime32".into(), |display_name| {
                 CastFunction::create(display_name.to_string(), DataType::DateTime32(None))
             });
+            map.insert("toDateTime64".into(), |display_name| {
+                CastFunction::create(display_name.to_string(), DataType::DateTime64(3, None))
+            });
         }
 
         Ok(())
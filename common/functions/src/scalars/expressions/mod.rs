@@ -18,5 +18,6 @@ mod expression;
 
 mod cast;
 
+pub(crate) use cast::cast_to_datetime64;
 pub use cast::CastFunction;
 pub use expression::ToCastFunction;
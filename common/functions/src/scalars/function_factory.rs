@@ -22,12 +22,14 @@ use lazy_static::lazy_static;
 use unicase::UniCase;
 
 use crate::scalars::ArithmeticFunction;
+use crate::scalars::ArraysFunction;
 use crate::scalars::ComparisonFunction;
 use crate::scalars::ConditionalFunction;
 use crate::scalars::DateFunction;
 use crate::scalars::Function;
 use crate::scalars::HashesFunction;
 use crate::scalars::LogicFunction;
+use crate::scalars::MathsFunction;
 use crate::scalars::NullableFunction;
 use crate::scalars::StringFunction;
 use crate::scalars::ToCastFunction;
@@ -52,6 +54,8 @@ lazy_static! {
         ToCastFunction::register(map.clone()).unwrap();
         ConditionalFunction::register(map.clone()).unwrap();
         DateFunction::register(map.clone()).unwrap();
+        MathsFunction::register(map.clone()).unwrap();
+        ArraysFunction::register(map.clone()).unwrap();
 
         map
     };
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+#[derive(Clone)]
+pub struct AbsFunction {
+    display_name: String,
+}
+
+impl AbsFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(AbsFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for AbsFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        // From clickhouse: abs of a signed integer is the unsigned integer of the same size.
+        match &args[0] {
+            DataType::Int8 => Ok(DataType::UInt8),
+            DataType::Int16 => Ok(DataType::UInt16),
+            DataType::Int32 => Ok(DataType::UInt32),
+            DataType::Int64 => Ok(DataType::UInt64),
+            other if is_numeric(other) => Ok(other.clone()),
+            other => Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expect a number",
+                other, self.display_name
+            ))),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = columns[0].data_type();
+        let return_type = self.return_type(&[data_type.clone()])?;
+        let series = columns[0].column().to_minimal_array()?;
+
+        let result: DataColumn = if is_floating(data_type) {
+            let series = series.cast_with_type(&DataType::Float64)?;
+            series.f64()?.apply(f64::abs).into()
+        } else if is_signed_numeric(data_type) {
+            let series = series.cast_with_type(&DataType::Int64)?;
+            series
+                .i64()?
+                .apply_cast_numeric(|v| v.unsigned_abs())
+                .into()
+        } else {
+            series.into()
+        };
+
+        result
+            .cast_with_type(&return_type)
+            .map(|c| c.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for AbsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Math function with two numeric arguments and a Float64 result, such as pow and atan2.
+#[derive(Clone)]
+pub struct MathBinaryFunction {
+    display_name: String,
+    func: fn(f64, f64) -> f64,
+}
+
+impl MathBinaryFunction {
+    pub fn try_create(display_name: &str, func: fn(f64, f64) -> f64) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathBinaryFunction {
+            display_name: display_name.to_string(),
+            func,
+        }))
+    }
+}
+
+impl Function for MathBinaryFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_math_coercion(&args[0])?;
+        numerical_math_coercion(&args[1])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        binary_f64_eval(&columns[0], &columns[1], input_rows, self.func)
+    }
+}
+
+impl fmt::Display for MathBinaryFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+/// Returns the series of the two columns with the same length,
+/// constant columns are only expanded if the other one is an array.
+pub(crate) fn binary_arrays(
+    lhs: &DataColumnWithField,
+    rhs: &DataColumnWithField,
+    data_type: &DataType,
+) -> Result<(Series, Series, bool)> {
+    let all_constant = matches!(
+        (lhs.column(), rhs.column()),
+        (DataColumn::Constant(_, _), DataColumn::Constant(_, _))
+    );
+
+    let (lhs, rhs) = if all_constant {
+        (
+            lhs.column().to_minimal_array()?,
+            rhs.column().to_minimal_array()?,
+        )
+    } else {
+        (lhs.column().to_array()?, rhs.column().to_array()?)
+    };
+
+    Ok((
+        lhs.cast_with_type(data_type)?,
+        rhs.cast_with_type(data_type)?,
+        all_constant,
+    ))
+}
+
+pub(crate) fn binary_f64_eval(
+    lhs: &DataColumnWithField,
+    rhs: &DataColumnWithField,
+    input_rows: usize,
+    func: fn(f64, f64) -> f64,
+) -> Result<DataColumn> {
+    let (lhs, rhs, all_constant) = binary_arrays(lhs, rhs, &DataType::Float64)?;
+
+    let array: DFFloat64Array = lhs
+        .f64()?
+        .into_iter()
+        .zip(rhs.f64()?.into_iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(l), Some(r)) => Some(func(*l, *r)),
+            _ => None,
+        })
+        .collect();
+
+    let result: DataColumn = array.into();
+    match all_constant {
+        true => Ok(result.resize_constant(input_rows)),
+        false => Ok(result),
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::maths::binary::binary_arrays;
+use crate::scalars::Function;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitOperator {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+/// bitAnd, bitOr, bitXor, bitShiftLeft and bitShiftRight on integers.
+#[derive(Clone)]
+pub struct BitFunction {
+    display_name: String,
+    op: BitOperator,
+}
+
+impl BitFunction {
+    pub fn try_create(display_name: &str, op: BitOperator) -> Result<Box<dyn Function>> {
+        Ok(Box::new(BitFunction {
+            display_name: display_name.to_string(),
+            op,
+        }))
+    }
+}
+
+impl Function for BitFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        match self.op {
+            BitOperator::ShiftLeft | BitOperator::ShiftRight => {
+                numerical_bitwise_coercion(&args[0], &args[1])?;
+                // Shifts are computed in 64 bits, with the signedness of the shifted value.
+                match is_signed_numeric(&args[0]) {
+                    true => Ok(DataType::Int64),
+                    false => Ok(DataType::UInt64),
+                }
+            }
+            _ => numerical_bitwise_coercion(&args[0], &args[1]),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let return_type = self.return_type(&[
+            columns[0].data_type().clone(),
+            columns[1].data_type().clone(),
+        ])?;
+        let working_type = match is_signed_numeric(&return_type) {
+            true => DataType::Int64,
+            false => DataType::UInt64,
+        };
+
+        let (lhs, rhs, all_constant) = binary_arrays(&columns[0], &columns[1], &working_type)?;
+
+        let op = self.op;
+        let display_name = self.display_name.as_str();
+        macro_rules! bit_op {
+            ($lhs: expr, $rhs: expr) => {{
+                $lhs.into_iter()
+                    .zip($rhs.into_iter())
+                    .map(|(l, r)| match (l, r) {
+                        (Some(l), Some(r)) => match op {
+                            BitOperator::And => Ok(Some(*l & *r)),
+                            BitOperator::Or => Ok(Some(*l | *r)),
+                            BitOperator::Xor => Ok(Some(*l ^ *r)),
+                            BitOperator::ShiftLeft | BitOperator::ShiftRight => {
+                                let shift = match u32::try_from(*r) {
+                                    Ok(shift) if shift < 64 => shift,
+                                    _ => {
+                                        return Err(ErrorCode::BadArguments(format!(
+                                            "The shift amount of function {} must be in [0, 64), but got {}",
+                                            display_name, r
+                                        )))
+                                    }
+                                };
+                                match op {
+                                    BitOperator::ShiftLeft => Ok(Some(*l << shift)),
+                                    _ => Ok(Some(*l >> shift)),
+                                }
+                            }
+                        },
+                        _ => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .collect()
+            }};
+        }
+
+        let result: Series = match working_type {
+            DataType::Int64 => {
+                let array: DFInt64Array = bit_op!(lhs.i64()?, rhs.i64()?);
+                array.into_series()
+            }
+            _ => {
+                let array: DFUInt64Array = bit_op!(lhs.u64()?, rhs.u64()?);
+                array.into_series()
+            }
+        };
+
+        let result: DataColumn = result.cast_with_type(&return_type)?.into();
+        match all_constant {
+            true => Ok(result.resize_constant(input_rows)),
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for BitFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// greatest(a, b, ...) and least(a, b, ...), the arguments are converted to their common type.
+#[derive(Clone)]
+pub struct GreatestLeastFunction {
+    display_name: String,
+    op: DataValueComparisonOperator,
+}
+
+impl GreatestLeastFunction {
+    pub fn try_create_greatest(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GreatestLeastFunction {
+            display_name: display_name.to_string(),
+            op: DataValueComparisonOperator::Gt,
+        }))
+    }
+
+    pub fn try_create_least(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(GreatestLeastFunction {
+            display_name: display_name.to_string(),
+            op: DataValueComparisonOperator::Lt,
+        }))
+    }
+}
+
+impl Function for GreatestLeastFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, usize::MAX))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        aggregate_types(args)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, _input_rows: usize) -> Result<DataColumn> {
+        let args = columns
+            .iter()
+            .map(|c| c.data_type().clone())
+            .collect::<Vec<_>>();
+        let data_type = self.return_type(&args)?;
+
+        let mut result = columns[0].column().cast_with_type(&data_type)?;
+        for column in &columns[1..] {
+            let column = column.column().cast_with_type(&data_type)?;
+            let predicate = column.compare(self.op.clone(), &result)?;
+            result = predicate.if_then_else(&column, &result)?;
+        }
+        Ok(result)
+    }
+}
+
+impl fmt::Display for GreatestLeastFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::maths::binary::binary_arrays;
+use crate::scalars::Function;
+
+/// intDiv(a, b) divides a by b and rounds the result toward zero, an error is returned
+/// if b is zero or if the result overflows.
+#[derive(Clone)]
+pub struct IntDivFunction {
+    display_name: String,
+}
+
+impl IntDivFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(IntDivFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for IntDivFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        2
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_int_div_coercion(&args[0], &args[1])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let return_type = self.return_type(&[
+            columns[0].data_type().clone(),
+            columns[1].data_type().clone(),
+        ])?;
+
+        let has_float = is_floating(columns[0].data_type()) || is_floating(columns[1].data_type());
+        let working_type = match (has_float, is_signed_numeric(&return_type)) {
+            (true, _) => DataType::Float64,
+            (false, true) => DataType::Int64,
+            (false, false) => DataType::UInt64,
+        };
+
+        let (lhs, rhs, all_constant) = binary_arrays(&columns[0], &columns[1], &working_type)?;
+
+        let division_by_zero = || ErrorCode::BadArguments("Division by zero in function intDiv");
+        let overflow =
+            |l, r| ErrorCode::Overflow(format!("Overflow in function intDiv: {} / {}", l, r));
+        macro_rules! int_div {
+            ($lhs: expr, $rhs: expr, $div: expr) => {{
+                let div = $div;
+                $lhs.into_iter()
+                    .zip($rhs.into_iter())
+                    .map(|(l, r)| match (l, r) {
+                        (Some(l), Some(r)) => div(*l, *r).map(Some),
+                        _ => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .collect()
+            }};
+        }
+
+        let result: Series = match working_type {
+            DataType::Float64 => {
+                let array: DFInt64Array = int_div!(lhs.f64()?, rhs.f64()?, |l: f64, r: f64| {
+                    match r == 0.0 {
+                        true => Err(division_by_zero()),
+                        false => Ok((l / r).trunc() as i64),
+                    }
+                });
+                array.into_series()
+            }
+            DataType::Int64 => {
+                let array: DFInt64Array = int_div!(lhs.i64()?, rhs.i64()?, |l: i64, r: i64| {
+                    match r == 0 {
+                        true => Err(division_by_zero()),
+                        // i64::MIN / -1
+                        false => l.checked_div(r).ok_or_else(|| overflow(l, r)),
+                    }
+                });
+                array.into_series()
+            }
+            _ => {
+                let array: DFUInt64Array = int_div!(lhs.u64()?, rhs.u64()?, |l: u64, r: u64| {
+                    match r == 0 {
+                        true => Err(division_by_zero()),
+                        false => Ok(l / r),
+                    }
+                });
+                array.into_series()
+            }
+        };
+
+        let result: DataColumn = result.cast_with_type(&return_type)?.into();
+        match all_constant {
+            true => Ok(result.resize_constant(input_rows)),
+            false => Ok(result),
+        }
+    }
+}
+
+impl fmt::Display for IntDivFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
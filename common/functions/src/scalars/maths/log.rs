@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::maths::binary::binary_f64_eval;
+use crate::scalars::Function;
+
+/// log(x) is the natural logarithm of x, log(b, x) is the logarithm of x to the base b.
+#[derive(Clone)]
+pub struct LogFunction {
+    display_name: String,
+}
+
+impl LogFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(LogFunction {
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for LogFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        for arg in args {
+            numerical_math_coercion(arg)?;
+        }
+        Ok(DataType::Float64)
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        if columns.len() == 2 {
+            return binary_f64_eval(&columns[0], &columns[1], input_rows, |base, x| {
+                x.ln() / base.ln()
+            });
+        }
+
+        let series = columns[0]
+            .column()
+            .to_minimal_array()?
+            .cast_with_type(&DataType::Float64)?;
+
+        let result: DataColumn = series.f64()?.apply(f64::ln).into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for LogFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::scalars::AbsFunction;
+use crate::scalars::BitFunction;
+use crate::scalars::BitOperator;
+use crate::scalars::FactoryFuncRef;
+use crate::scalars::GreatestLeastFunction;
+use crate::scalars::IntDivFunction;
+use crate::scalars::LogFunction;
+use crate::scalars::MathBinaryFunction;
+use crate::scalars::MathConstantFunction;
+use crate::scalars::MathUnaryFunction;
+use crate::scalars::RandFunction;
+use crate::scalars::RoundingFunction;
+use crate::scalars::RoundingMode;
+use crate::scalars::SignFunction;
+
+#[derive(Clone)]
+pub struct MathsFunction;
+
+impl MathsFunction {
+    pub fn register(map: FactoryFuncRef) -> Result<()> {
+        let mut map = map.write();
+        map.insert("abs".into(), AbsFunction::try_create);
+        map.insert("sign".into(), SignFunction::try_create);
+        map.insert(
+            "greatest".into(),
+            GreatestLeastFunction::try_create_greatest,
+        );
+        map.insert("least".into(), GreatestLeastFunction::try_create_least);
+        map.insert("intDiv".into(), IntDivFunction::try_create);
+        map.insert("log".into(), LogFunction::try_create);
+        map.insert("rand".into(), RandFunction::try_create);
+        map.insert("randCanonical".into(), RandFunction::try_create_canonical);
+
+        // rounding
+        {
+            map.insert("round".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Round)
+            });
+            map.insert("floor".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Floor)
+            });
+            map.insert("ceil".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Ceil)
+            });
+            map.insert("ceiling".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Ceil)
+            });
+            map.insert("truncate".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Trunc)
+            });
+            map.insert("trunc".into(), |display_name| {
+                RoundingFunction::try_create(display_name, RoundingMode::Trunc)
+            });
+        }
+
+        // unary functions
+        {
+            map.insert("sqrt".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::sqrt)
+            });
+            map.insert("cbrt".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::cbrt)
+            });
+            map.insert("exp".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::exp)
+            });
+            map.insert("ln".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::ln)
+            });
+            map.insert("log2".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::log2)
+            });
+            map.insert("log10".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::log10)
+            });
+            map.insert("sin".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::sin)
+            });
+            map.insert("cos".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::cos)
+            });
+            map.insert("tan".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::tan)
+            });
+            map.insert("asin".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::asin)
+            });
+            map.insert("acos".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::acos)
+            });
+            map.insert("atan".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::atan)
+            });
+            map.insert("degrees".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::to_degrees)
+            });
+            map.insert("radians".into(), |display_name| {
+                MathUnaryFunction::try_create(display_name, f64::to_radians)
+            });
+        }
+
+        // binary functions
+        {
+            map.insert("pow".into(), |display_name| {
+                MathBinaryFunction::try_create(display_name, f64::powf)
+            });
+            map.insert("power".into(), |display_name| {
+                MathBinaryFunction::try_create(display_name, f64::powf)
+            });
+            map.insert("atan2".into(), |display_name| {
+                MathBinaryFunction::try_create(display_name, f64::atan2)
+            });
+        }
+
+        // constants
+        {
+            map.insert("pi".into(), |display_name| {
+                MathConstantFunction::try_create(display_name, std::f64::consts::PI)
+            });
+            map.insert("e".into(), |display_name| {
+                MathConstantFunction::try_create(display_name, std::f64::consts::E)
+            });
+        }
+
+        // bit operations
+        {
+            map.insert("bitAnd".into(), |display_name| {
+                BitFunction::try_create(display_name, BitOperator::And)
+            });
+            map.insert("bitOr".into(), |display_name| {
+                BitFunction::try_create(display_name, BitOperator::Or)
+            });
+            map.insert("bitXor".into(), |display_name| {
+                BitFunction::try_create(display_name, BitOperator::Xor)
+            });
+            map.insert("bitShiftLeft".into(), |display_name| {
+                BitFunction::try_create(display_name, BitOperator::ShiftLeft)
+            });
+            map.insert("bitShiftRight".into(), |display_name| {
+                BitFunction::try_create(display_name, BitOperator::ShiftRight)
+            });
+        }
+
+        Ok(())
+    }
+}
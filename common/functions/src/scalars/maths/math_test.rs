@@ -0,0 +1,252 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::scalars::*;
+
+#[test]
+fn test_math_function() -> Result<()> {
+    #[allow(dead_code)]
+    struct Test {
+        name: &'static str,
+        display: &'static str,
+        arg_names: Vec<&'static str>,
+        columns: Vec<DataColumn>,
+        expect: DataColumn,
+        error: &'static str,
+        func: Box<dyn Function>,
+    }
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("i", DataType::Int64, false),
+        DataField::new("u", DataType::UInt8, false),
+        DataField::new("f", DataType::Float64, false),
+        DataField::new("s", DataType::String, false),
+    ]);
+
+    let tests = vec![
+        Test {
+            name: "abs-int64-passed",
+            display: "abs",
+            arg_names: vec!["i"],
+            func: AbsFunction::try_create("abs")?,
+            columns: vec![Series::new(vec![-4i64, 3, 0]).into()],
+            expect: Series::new(vec![4u64, 3, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "sign-float64-passed",
+            display: "sign",
+            arg_names: vec!["f"],
+            func: SignFunction::try_create("sign")?,
+            columns: vec![Series::new(vec![-4.5f64, 3.0, 0.0]).into()],
+            expect: Series::new(vec![-1i8, 1, 0]).into(),
+            error: "",
+        },
+        Test {
+            name: "sqrt-uint8-passed",
+            display: "sqrt",
+            arg_names: vec!["u"],
+            func: MathUnaryFunction::try_create("sqrt", f64::sqrt)?,
+            columns: vec![Series::new(vec![4u8, 9, 16]).into()],
+            expect: Series::new(vec![2.0f64, 3.0, 4.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "sqrt-string-failed",
+            display: "sqrt",
+            arg_names: vec!["s"],
+            func: MathUnaryFunction::try_create("sqrt", f64::sqrt)?,
+            columns: vec![Series::new(vec!["a"]).into()],
+            expect: Series::new(vec![0.0f64]).into(),
+            error:
+                "Code: 10, displayText = DataValue Error: Unsupported math function on (String).",
+        },
+        Test {
+            name: "pow-passed",
+            display: "pow",
+            arg_names: vec!["i", "f"],
+            func: MathBinaryFunction::try_create("pow", f64::powf)?,
+            columns: vec![
+                Series::new(vec![2i64, 3, 4]).into(),
+                Series::new(vec![2.0f64, 2.0, 0.5]).into(),
+            ],
+            expect: Series::new(vec![4.0f64, 9.0, 2.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "log-with-base-passed",
+            display: "log",
+            arg_names: vec!["u", "f"],
+            func: LogFunction::try_create("log")?,
+            columns: vec![
+                Series::new(vec![2u8, 10]).into(),
+                Series::new(vec![8.0f64, 100.0]).into(),
+            ],
+            expect: Series::new(vec![3.0f64, 2.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "round-float64-passed",
+            display: "round",
+            arg_names: vec!["f", "i"],
+            func: RoundingFunction::try_create("round", RoundingMode::Round)?,
+            columns: vec![
+                Series::new(vec![1.25f64, -2.5, 3.0]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(1)), 3),
+            ],
+            expect: Series::new(vec![1.3f64, -2.5, 3.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "floor-int64-negative-scale-passed",
+            display: "floor",
+            arg_names: vec!["i", "i"],
+            func: RoundingFunction::try_create("floor", RoundingMode::Floor)?,
+            columns: vec![
+                Series::new(vec![123i64, 456, -789]).into(),
+                DataColumn::Constant(DataValue::Int64(Some(-2)), 3),
+            ],
+            expect: Series::new(vec![100i64, 400, -800]).into(),
+            error: "",
+        },
+        Test {
+            name: "int-div-passed",
+            display: "intDiv",
+            arg_names: vec!["i", "u"],
+            func: IntDivFunction::try_create("intDiv")?,
+            columns: vec![
+                Series::new(vec![7i64, -7, 9]).into(),
+                Series::new(vec![2u8, 2, 3]).into(),
+            ],
+            expect: Series::new(vec![3i64, -3, 3]).into(),
+            error: "",
+        },
+        Test {
+            name: "int-div-by-zero-failed",
+            display: "intDiv",
+            arg_names: vec!["i", "u"],
+            func: IntDivFunction::try_create("intDiv")?,
+            columns: vec![
+                Series::new(vec![7i64]).into(),
+                Series::new(vec![0u8]).into(),
+            ],
+            expect: Series::new(vec![0i64]).into(),
+            error: "Code: 6, displayText = Division by zero in function intDiv.",
+        },
+        Test {
+            name: "int-div-overflow-failed",
+            display: "intDiv",
+            arg_names: vec!["i", "i"],
+            func: IntDivFunction::try_create("intDiv")?,
+            columns: vec![
+                Series::new(vec![i64::MIN]).into(),
+                Series::new(vec![-1i64]).into(),
+            ],
+            expect: Series::new(vec![0i64]).into(),
+            error:
+                "Code: 49, displayText = Overflow in function intDiv: -9223372036854775808 / -1.",
+        },
+        Test {
+            name: "bit-and-passed",
+            display: "bitAnd",
+            arg_names: vec!["i", "u"],
+            func: BitFunction::try_create("bitAnd", BitOperator::And)?,
+            columns: vec![
+                Series::new(vec![12i64, 7]).into(),
+                Series::new(vec![10u8, 2]).into(),
+            ],
+            expect: Series::new(vec![8i64, 2]).into(),
+            error: "",
+        },
+        Test {
+            name: "bit-shift-left-passed",
+            display: "bitShiftLeft",
+            arg_names: vec!["u", "u"],
+            func: BitFunction::try_create("bitShiftLeft", BitOperator::ShiftLeft)?,
+            columns: vec![
+                Series::new(vec![1u8, 3]).into(),
+                Series::new(vec![10u8, 2]).into(),
+            ],
+            expect: Series::new(vec![1024u64, 12]).into(),
+            error: "",
+        },
+        Test {
+            name: "greatest-passed",
+            display: "greatest",
+            arg_names: vec!["u", "f"],
+            func: GreatestLeastFunction::try_create_greatest("greatest")?,
+            columns: vec![
+                Series::new(vec![2u8, 4, 0]).into(),
+                Series::new(vec![0.5f64, 4.5, -1.0]).into(),
+            ],
+            expect: Series::new(vec![2.0f64, 4.5, 0.0]).into(),
+            error: "",
+        },
+        Test {
+            name: "least-passed",
+            display: "least",
+            arg_names: vec!["i", "u"],
+            func: GreatestLeastFunction::try_create_least("least")?,
+            columns: vec![
+                Series::new(vec![1i64, 5, -3]).into(),
+                Series::new(vec![2u8, 4, 0]).into(),
+            ],
+            expect: Series::new(vec![1i64, 4, -3]).into(),
+            error: "",
+        },
+    ];
+
+    for t in tests {
+        let func = t.func;
+        let rows = t.columns[0].len();
+
+        let mut args = vec![];
+        let mut fields = vec![];
+        for name in t.arg_names {
+            args.push(schema.field_with_name(name)?.data_type().clone());
+            fields.push(schema.field_with_name(name)?.clone());
+        }
+
+        let columns: Vec<DataColumnWithField> = t
+            .columns
+            .iter()
+            .zip(fields.iter())
+            .map(|(c, f)| DataColumnWithField::new(c.clone(), f.clone()))
+            .collect();
+
+        // Display check.
+        let expect_display = t.display.to_string();
+        let actual_display = format!("{}", func);
+        assert_eq!(expect_display, actual_display, "{}", t.name);
+
+        let result = func
+            .return_type(&args)
+            .and_then(|expect_type| Ok((expect_type, func.eval(&columns, rows)?)));
+        match result {
+            Ok((expect_type, v)) => {
+                assert_eq!(t.error, "", "{}", t.name);
+                // Type check.
+                assert_eq!(expect_type, v.data_type(), "{}", t.name);
+                assert_eq!(&v, &t.expect, "{}", t.name);
+            }
+            Err(e) => assert_eq!(t.error, e.to_string(), "{}", t.name),
+        }
+    }
+
+    Ok(())
+}
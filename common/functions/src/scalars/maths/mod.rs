@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(test)]
+mod math_test;
+
+mod abs;
+mod binary;
+mod bit;
+mod constant;
+mod greatest;
+mod int_div;
+mod log;
+mod math;
+mod random;
+mod rounding;
+mod sign;
+mod unary;
+
+pub use abs::AbsFunction;
+pub use binary::MathBinaryFunction;
+pub use bit::BitFunction;
+pub use bit::BitOperator;
+pub use constant::MathConstantFunction;
+pub use greatest::GreatestLeastFunction;
+pub use int_div::IntDivFunction;
+pub use log::LogFunction;
+pub use math::MathsFunction;
+pub use random::RandFunction;
+pub use rounding::RoundingFunction;
+pub use rounding::RoundingMode;
+pub use sign::SignFunction;
+pub use unary::MathUnaryFunction;
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use rand::Rng;
+
+use crate::scalars::Function;
+
+/// rand() returns a random UInt32 for each row, randCanonical() returns a Float64 in [0, 1).
+/// The optional argument is ignored, it only prevents identical calls from being merged.
+#[derive(Clone)]
+pub struct RandFunction {
+    display_name: String,
+    canonical: bool,
+}
+
+impl RandFunction {
+    pub fn try_create(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RandFunction {
+            display_name: display_name.to_string(),
+            canonical: false,
+        }))
+    }
+
+    pub fn try_create_canonical(display_name: &str) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RandFunction {
+            display_name: display_name.to_string(),
+            canonical: true,
+        }))
+    }
+}
+
+impl Function for RandFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((0, 1))
+    }
+
+    fn return_type(&self, _args: &[DataType]) -> Result<DataType> {
+        match self.canonical {
+            true => Ok(DataType::Float64),
+            false => Ok(DataType::UInt32),
+        }
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, _columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let mut rng = rand::thread_rng();
+        match self.canonical {
+            true => {
+                let array: DFFloat64Array =
+                    (0..input_rows).map(|_| Some(rng.gen::<f64>())).collect();
+                Ok(array.into())
+            }
+            false => {
+                let array: DFUInt32Array =
+                    (0..input_rows).map(|_| Some(rng.gen::<u32>())).collect();
+                Ok(array.into())
+            }
+        }
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for RandFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}()", self.display_name)
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RoundingMode {
+    Round,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+/// round(x[, n]), floor(x[, n]), ceil(x[, n]) and truncate(x[, n]),
+/// n is the number of decimal places to keep, it can be negative and must be a constant.
+#[derive(Clone)]
+pub struct RoundingFunction {
+    display_name: String,
+    mode: RoundingMode,
+}
+
+impl RoundingFunction {
+    pub fn try_create(display_name: &str, mode: RoundingMode) -> Result<Box<dyn Function>> {
+        Ok(Box::new(RoundingFunction {
+            display_name: display_name.to_string(),
+            mode,
+        }))
+    }
+
+    #[inline]
+    fn execute(mode: RoundingMode, value: f64) -> f64 {
+        match mode {
+            RoundingMode::Round => value.round(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Trunc => value.trunc(),
+        }
+    }
+}
+
+impl Function for RoundingFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    // round(x)
+    // round(x, n)
+    fn variadic_arguments(&self) -> Option<(usize, usize)> {
+        Some((1, 2))
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        if !is_numeric(&args[0]) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of argument of function {}, expect a number",
+                args[0], self.display_name
+            )));
+        }
+
+        if args.len() == 2 && !is_integer(&args[1]) {
+            return Result::Err(ErrorCode::IllegalDataType(format!(
+                "Illegal type {:?} of second argument of function {}, expect an integer",
+                args[1], self.display_name
+            )));
+        }
+
+        Ok(args[0].clone())
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let data_type = columns[0].data_type();
+
+        let scale = match columns.len() {
+            2 => match columns[1].column() {
+                DataColumn::Constant(v, _) => v.as_i64()?,
+                _ => {
+                    return Result::Err(ErrorCode::BadArguments(format!(
+                        "The second argument of function {} must be a constant",
+                        self.display_name
+                    )))
+                }
+            },
+            _ => 0,
+        };
+
+        // Rounding an integer to a non-negative scale is a no-op.
+        if is_integer(data_type) && scale >= 0 {
+            return Ok(columns[0].column().clone());
+        }
+
+        let mode = self.mode;
+        let factor = 10_f64.powi(scale.abs() as i32);
+        let series = columns[0]
+            .column()
+            .to_minimal_array()?
+            .cast_with_type(&DataType::Float64)?;
+
+        let array = match scale {
+            0 => series.f64()?.apply(|v| Self::execute(mode, v)),
+            s if s > 0 => series
+                .f64()?
+                .apply(|v| Self::execute(mode, v * factor) / factor),
+            _ => series
+                .f64()?
+                .apply(|v| Self::execute(mode, v / factor) * factor),
+        };
+
+        let result: DataColumn = array.into();
+        result
+            .cast_with_type(data_type)
+            .map(|c| c.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for RoundingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
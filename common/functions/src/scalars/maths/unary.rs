@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::scalars::Function;
+
+/// Math function with one numeric argument and a Float64 result,
+/// such as sqrt, exp, ln, sin and so on.
+#[derive(Clone)]
+pub struct MathUnaryFunction {
+    display_name: String,
+    func: fn(f64) -> f64,
+}
+
+impl MathUnaryFunction {
+    pub fn try_create(display_name: &str, func: fn(f64) -> f64) -> Result<Box<dyn Function>> {
+        Ok(Box::new(MathUnaryFunction {
+            display_name: display_name.to_string(),
+            func,
+        }))
+    }
+}
+
+impl Function for MathUnaryFunction {
+    fn name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    fn num_arguments(&self) -> usize {
+        1
+    }
+
+    fn return_type(&self, args: &[DataType]) -> Result<DataType> {
+        numerical_math_coercion(&args[0])
+    }
+
+    fn nullable(&self, _input_schema: &DataSchema) -> Result<bool> {
+        Ok(false)
+    }
+
+    fn eval(&self, columns: &DataColumnsWithField, input_rows: usize) -> Result<DataColumn> {
+        let series = columns[0]
+            .column()
+            .to_minimal_array()?
+            .cast_with_type(&DataType::Float64)?;
+
+        let func = self.func;
+        let result: DataColumn = series.f64()?.apply(func).into();
+        Ok(result.resize_constant(input_rows))
+    }
+}
+
+impl fmt::Display for MathUnaryFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
@@ -16,6 +16,7 @@
 mod function_column_test;
 
 mod arithmetics;
+mod arrays;
 mod comparisons;
 mod conditionals;
 mod dates;
@@ -27,11 +28,13 @@ mod function_factory;
 mod function_literal;
 mod hashes;
 mod logics;
+mod maths;
 mod nullables;
 mod strings;
 mod udfs;
 
 pub use arithmetics::*;
+pub use arrays::*;
 pub use comparisons::*;
 pub use conditionals::*;
 pub use dates::*;
@@ -44,6 +47,7 @@ pub use function_factory::FunctionFactory;
 pub use function_literal::LiteralFunction;
 pub use hashes::*;
 pub use logics::*;
+pub use maths::*;
 pub use nullables::*;
 pub use strings::*;
 pub use udfs::*;
@@ -14,11 +14,15 @@
 //
 
 mod namespace;
+mod resource_group;
 mod user;
 
 pub use namespace::NamespaceApi;
 pub use namespace::NamespaceMgr;
 pub use namespace::NodeInfo;
+pub use resource_group::ResourceGroupApi;
+pub use resource_group::ResourceGroupMeta;
+pub use resource_group::ResourceGroupMgr;
 pub use user::user_api::AuthType;
 pub use user::user_api::UserInfo;
 pub use user::user_api::UserMgrApi;
@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::Result;
+
+/// A resource group created by CREATE RESOURCE GROUP, with the options of the statement.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ResourceGroupMeta {
+    pub name: String,
+    pub options: BTreeMap<String, String>,
+}
+
+impl ResourceGroupMeta {
+    pub fn create(name: String, options: BTreeMap<String, String>) -> ResourceGroupMeta {
+        ResourceGroupMeta { name, options }
+    }
+}
+
+pub trait ResourceGroupApi: Sync + Send {
+    // Add a new resource group to /tenant/group-name, fails if it exists.
+    fn add_resource_group(&self, group: ResourceGroupMeta) -> Result<u64>;
+
+    // Get all the resource groups of the tenant.
+    fn get_resource_groups(&self) -> Result<Vec<ResourceGroupMeta>>;
+
+    // Drop the resource group by name, fails if it does not exist.
+    fn drop_resource_group(&self, name: &str) -> Result<()>;
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_kv_api::KVApi;
+use common_kv_api::SyncKVApi;
+use common_metatypes::MatchSeq;
+
+use crate::resource_group::ResourceGroupApi;
+use crate::resource_group::ResourceGroupMeta;
+
+pub static RESOURCE_GROUP_API_KEY_PREFIX: &str = "__fd_resource_groups";
+
+pub struct ResourceGroupMgr {
+    kv_api: Arc<dyn KVApi>,
+    resource_group_prefix: String,
+}
+
+impl ResourceGroupMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        ResourceGroupMgr {
+            kv_api,
+            resource_group_prefix: format!("{}/{}", RESOURCE_GROUP_API_KEY_PREFIX, tenant),
+        }
+    }
+}
+
+impl ResourceGroupApi for ResourceGroupMgr {
+    fn add_resource_group(&self, group: ResourceGroupMeta) -> Result<u64> {
+        // Only when there are no record, i.e. seq=0
+        let match_seq = MatchSeq::Exact(0);
+        let key = format!("{}/{}", self.resource_group_prefix, group.name);
+        let value = serde_json::to_vec(&group)?;
+
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, match_seq, Some(value), None)?;
+
+        match (res.prev, res.result) {
+            (None, Some((s, _))) => Ok(s),
+            (Some((s, _)), None) => Err(ErrorCode::ResourceGroupAlreadyExists(format!(
+                "Resource group {} already exists, seq [{}]",
+                group.name, s
+            ))),
+            r @ (_, _) => Err(ErrorCode::UnknownException(format!(
+                "upsert result not expected (using version 0, got {:?})",
+                r
+            ))),
+        }
+    }
+
+    fn get_resource_groups(&self) -> Result<Vec<ResourceGroupMeta>> {
+        let values = self
+            .kv_api
+            .sync_prefix_list_kv(&self.resource_group_prefix)?;
+
+        let mut groups = Vec::with_capacity(values.len());
+        for (_key, (_, value)) in values {
+            groups.push(serde_json::from_slice::<ResourceGroupMeta>(&value.value)?);
+        }
+
+        Ok(groups)
+    }
+
+    fn drop_resource_group(&self, name: &str) -> Result<()> {
+        let key = format!("{}/{}", self.resource_group_prefix, name);
+        let res = self
+            .kv_api
+            .sync_upsert_kv(&key, MatchSeq::Any, None, None)?;
+        match (res.prev, res.result) {
+            (Some(_), None) => Ok(()),
+            _ => Err(ErrorCode::UnknownResourceGroup(format!(
+                "Unknown resource group {}",
+                name
+            ))),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_kv::KV;
+use common_kv_api::KVApi;
+
+use super::*;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_add_resource_group() -> Result<()> {
+    let (kv_api, resource_group_api) = new_resource_group_api().await?;
+
+    let group = create_test_resource_group("etl");
+    resource_group_api.add_resource_group(group.clone())?;
+
+    let value = kv_api.get_kv("__fd_resource_groups/test/etl").await?;
+    assert_eq!(value.result.unwrap().1.value, serde_json::to_vec(&group)?);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_resource_group() -> Result<()> {
+    let (_, resource_group_api) = new_resource_group_api().await?;
+
+    let group = create_test_resource_group("etl");
+    resource_group_api.add_resource_group(group.clone())?;
+
+    match resource_group_api.add_resource_group(group) {
+        Ok(_) => assert!(
+            false,
+            "Already exists add resource group must be return Err."
+        ),
+        Err(cause) => assert_eq!(cause.code(), 59),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_get_resource_groups() -> Result<()> {
+    let (_, resource_group_api) = new_resource_group_api().await?;
+
+    let groups = resource_group_api.get_resource_groups()?;
+    assert_eq!(groups, vec![]);
+
+    let etl = create_test_resource_group("etl");
+    let adhoc = create_test_resource_group("adhoc");
+    resource_group_api.add_resource_group(etl.clone())?;
+    resource_group_api.add_resource_group(adhoc.clone())?;
+
+    let groups = resource_group_api.get_resource_groups()?;
+    assert_eq!(groups, vec![adhoc, etl]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_resource_group() -> Result<()> {
+    let (_, resource_group_api) = new_resource_group_api().await?;
+
+    let group = create_test_resource_group("etl");
+    resource_group_api.add_resource_group(group)?;
+    resource_group_api.drop_resource_group("etl")?;
+
+    let groups = resource_group_api.get_resource_groups()?;
+    assert_eq!(groups, vec![]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_drop_resource_group() -> Result<()> {
+    let (_, resource_group_api) = new_resource_group_api().await?;
+
+    match resource_group_api.drop_resource_group("UNKNOWN_GROUP") {
+        Ok(_) => assert!(false, "Unknown resource group drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 58),
+    }
+
+    Ok(())
+}
+
+fn create_test_resource_group(name: &str) -> ResourceGroupMeta {
+    let mut options = BTreeMap::new();
+    options.insert("max_concurrent_queries".to_string(), "8".to_string());
+    options.insert("users".to_string(), "etl".to_string());
+    ResourceGroupMeta::create(name.to_string(), options)
+}
+
+async fn new_resource_group_api() -> Result<(Arc<KV>, ResourceGroupMgr)> {
+    let test_api = Arc::new(KV::new_temp().await?);
+    let resource_group_manager = ResourceGroupMgr::new(test_api.clone(), "test");
+    Ok((test_api, resource_group_manager))
+}
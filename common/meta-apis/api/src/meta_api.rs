@@ -16,6 +16,8 @@
 use common_meta_api_vo::*;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_metatypes::TableChange;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
@@ -45,6 +47,17 @@ pub trait MetaApi: Send + Sync {
         plan: DropTablePlan,
     ) -> common_exception::Result<DropTableActionResult>;
 
+    async fn alter_table(
+        &self,
+        plan: AlterTablePlan,
+    ) -> common_exception::Result<AlterTableActionResult>;
+
+    /// Apply the changes of the tables in one meta operation, all of them or none of them.
+    async fn update_tables(
+        &self,
+        changes: Vec<TableChange>,
+    ) -> common_exception::Result<UpdateTablesActionResult>;
+
     async fn get_table(
         &self,
         db: String,
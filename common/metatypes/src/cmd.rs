@@ -24,6 +24,7 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::Table;
+use crate::TableChange;
 
 /// A Cmd describes what a user want to do to raft state machine
 /// and is the essential part of a raft log.
@@ -76,6 +77,17 @@ pub enum Cmd {
         if_exists: bool,
     },
 
+    /// Replace the schema of a table, and rename it if the new name differs.
+    /// The table keeps its id, thus its data parts.
+    AlterTable {
+        db_name: String,
+        table_name: String,
+        new_db_name: String,
+        new_table_name: String,
+        /// serialized schema
+        schema: Vec<u8>,
+    },
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -95,6 +107,9 @@ pub enum Cmd {
 
     /// Truncate Table
     TruncateTable { db_name: String, table_name: String },
+
+    /// Apply the changes of the tables, all of them or none of them.
+    UpdateTables { changes: Vec<TableChange> },
 }
 
 impl fmt::Display for Cmd {
@@ -149,6 +164,19 @@ impl fmt::Display for Cmd {
                     db_name, table_name, if_exists
                 )
             }
+            Cmd::AlterTable {
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+                ..
+            } => {
+                write!(
+                    f,
+                    "alter_table:{}-{}, new_name:{}-{}",
+                    db_name, table_name, new_db_name, new_table_name
+                )
+            }
             Cmd::UpsertKV {
                 key,
                 seq,
@@ -167,6 +195,13 @@ impl fmt::Display for Cmd {
             } => {
                 write!(f, "truncate table:{}-{}", db_name, table_name)
             }
+            Cmd::UpdateTables { changes } => {
+                let changes = changes
+                    .iter()
+                    .map(|change| change.to_string())
+                    .collect::<Vec<_>>();
+                write!(f, "update_tables:[{}]", changes.join(", "))
+            }
         }
     }
 }
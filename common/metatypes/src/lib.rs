@@ -37,6 +37,8 @@ pub use raft_types::NodeId;
 pub use raft_types::Term;
 use serde::Deserialize;
 use serde::Serialize;
+pub use table_change::TableChange;
+pub use table_change::TableChangeError;
 
 mod errors;
 mod match_seq;
@@ -46,6 +48,7 @@ mod cmd;
 mod log_entry;
 mod raft_txid;
 mod raft_types;
+mod table_change;
 
 #[cfg(test)]
 mod match_seq_test;
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Table;
+
+/// A change of a table, the changes of a `Cmd::UpdateTables` are applied all or nothing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TableChange {
+    /// Create a table, it must be absent
+    Create {
+        db_name: String,
+        table_name: String,
+        table: Table,
+    },
+
+    /// Drop a table, it must be present
+    Drop { db_name: String, table_name: String },
+
+    /// Set an option of a table, or remove it if the value is None
+    SetOption {
+        db_name: String,
+        table_name: String,
+        key: String,
+        value: Option<String>,
+    },
+
+    /// Set an option of a table if its current value is `expected`, or fail with a `Conflict`
+    CompareAndSetOption {
+        db_name: String,
+        table_name: String,
+        key: String,
+        expected: Option<String>,
+        value: String,
+    },
+}
+
+impl TableChange {
+    /// The database and the name of the changed table.
+    pub fn table(&self) -> (&str, &str) {
+        match self {
+            TableChange::Create {
+                db_name,
+                table_name,
+                ..
+            }
+            | TableChange::Drop {
+                db_name,
+                table_name,
+            }
+            | TableChange::SetOption {
+                db_name,
+                table_name,
+                ..
+            }
+            | TableChange::CompareAndSetOption {
+                db_name,
+                table_name,
+                ..
+            } => (db_name, table_name),
+        }
+    }
+}
+
+impl fmt::Display for TableChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableChange::Create {
+                db_name,
+                table_name,
+                table,
+            } => write!(f, "create_table:{}-{}={}", db_name, table_name, table),
+            TableChange::Drop {
+                db_name,
+                table_name,
+            } => write!(f, "drop_table:{}-{}", db_name, table_name),
+            TableChange::SetOption {
+                db_name,
+                table_name,
+                key,
+                value,
+            } => write!(
+                f,
+                "set_table_option:{}-{}, {}={:?}",
+                db_name, table_name, key, value
+            ),
+            TableChange::CompareAndSetOption {
+                db_name,
+                table_name,
+                key,
+                expected,
+                value,
+            } => write!(
+                f,
+                "compare_and_set_table_option:{}-{}, {}={:?}->{}",
+                db_name, table_name, key, expected, value
+            ),
+        }
+    }
+}
+
+/// Why none of the changes of a `Cmd::UpdateTables` is applied, with the name of the table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TableChangeError {
+    UnknownDatabase(String),
+    UnknownTable(String),
+    TableAlreadyExists(String),
+    /// An option does not have the value expected by a `CompareAndSetOption`
+    Conflict(String),
+}
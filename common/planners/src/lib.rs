@@ -45,11 +45,15 @@ mod test;
 
 mod plan_aggregator_final;
 mod plan_aggregator_partial;
+mod plan_array_join;
 mod plan_broadcast;
 mod plan_builder;
 mod plan_builder_scan;
+mod plan_copy_into;
+mod plan_cte_scan;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_delete;
 mod plan_describe_table;
 mod plan_display;
 mod plan_display_indent;
@@ -61,6 +65,7 @@ mod plan_expression_chain;
 mod plan_expression_column;
 mod plan_expression_common;
 mod plan_expression_function;
+mod plan_expression_lambda;
 mod plan_expression_literal;
 mod plan_expression_rewriter;
 mod plan_expression_sort;
@@ -68,6 +73,7 @@ mod plan_expression_validator;
 mod plan_expression_visitor;
 mod plan_extras;
 mod plan_filter;
+mod plan_grouping_sets;
 mod plan_having;
 mod plan_insert_into;
 mod plan_kill;
@@ -77,30 +83,45 @@ mod plan_node;
 mod plan_partition;
 mod plan_projection;
 mod plan_read_datasource;
+mod plan_read_files;
 mod plan_remote;
+mod plan_resource_group;
 mod plan_rewriter;
 mod plan_scan;
 mod plan_select;
 mod plan_setting;
 mod plan_show_table_create;
+mod plan_sink;
 mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
 mod plan_subqueries_set;
+mod plan_table_alter;
 mod plan_table_create;
 mod plan_table_drop;
+mod plan_transaction;
 mod plan_truncate_table;
+mod plan_union_all;
+mod plan_update;
 mod plan_use_database;
+mod plan_view_create;
 mod plan_visitor;
 
 pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
+pub use plan_array_join::ArrayJoinPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
 pub use plan_builder_scan::TableScanInfo;
+pub use plan_copy_into::CopiedFiles;
+pub use plan_copy_into::CopyIntoPlan;
+pub use plan_copy_into::FileFormatOptions;
+pub use plan_cte_scan::CteScanPlan;
+pub use plan_cte_scan::CteSource;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
 pub use plan_explain::ExplainPlan;
@@ -116,7 +137,10 @@ pub use plan_expression_common::expand_wildcard;
 pub use plan_expression_common::expr_as_column_expr;
 pub use plan_expression_common::extract_aliases;
 pub use plan_expression_common::find_aggregate_exprs;
+pub use plan_expression_common::find_array_join_exprs;
 pub use plan_expression_common::find_columns_not_satisfy_exprs;
+pub use plan_expression_common::find_grouping_exprs;
+pub use plan_expression_common::find_subquery_exprs;
 pub use plan_expression_common::rebase_expr;
 pub use plan_expression_common::rebase_expr_from_input;
 pub use plan_expression_common::resolve_aliases_to_exprs;
@@ -127,6 +151,8 @@ pub use plan_expression_function::avg;
 pub use plan_expression_function::modular;
 pub use plan_expression_function::not;
 pub use plan_expression_function::sum;
+pub use plan_expression_lambda::is_array_join_function;
+pub use plan_expression_lambda::is_higher_order_function;
 pub use plan_expression_literal::lit;
 pub use plan_expression_rewriter::ExprRewriter;
 pub use plan_expression_sort::sort;
@@ -135,7 +161,15 @@ pub use plan_expression_visitor::ExpressionVisitor;
 pub use plan_expression_visitor::Recursion;
 pub use plan_extras::Extras;
 pub use plan_filter::FilterPlan;
+pub use plan_grouping_sets::grouping_id;
+pub use plan_grouping_sets::grouping_key_column;
+pub use plan_grouping_sets::grouping_key_fields;
+pub use plan_grouping_sets::grouping_to_expr;
+pub use plan_grouping_sets::is_grouping_function;
+pub use plan_grouping_sets::GROUPING_ID_COLUMN;
+pub use plan_grouping_sets::MAX_GROUPING_EXPRS;
 pub use plan_having::HavingPlan;
+pub use plan_insert_into::InsertInputStream;
 pub use plan_insert_into::InsertIntoPlan;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
@@ -145,7 +179,10 @@ pub use plan_partition::Part;
 pub use plan_partition::Partitions;
 pub use plan_projection::ProjectionPlan;
 pub use plan_read_datasource::ReadDataSourcePlan;
+pub use plan_read_files::ReadFilesPlan;
 pub use plan_remote::RemotePlan;
+pub use plan_resource_group::CreateResourceGroupPlan;
+pub use plan_resource_group::DropResourceGroupPlan;
 pub use plan_rewriter::PlanRewriter;
 pub use plan_rewriter::RewriteHelper;
 pub use plan_scan::ScanPlan;
@@ -153,14 +190,23 @@ pub use plan_select::SelectPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
 pub use plan_show_table_create::ShowCreateTablePlan;
+pub use plan_sink::SinkPlan;
 pub use plan_sort::SortPlan;
 pub use plan_stage::StageKind;
 pub use plan_stage::StagePlan;
 pub use plan_statistics::Statistics;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_alter::AlterTableAction;
+pub use plan_table_alter::AlterTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
+pub use plan_transaction::TransactionKind;
+pub use plan_transaction::TransactionPlan;
 pub use plan_truncate_table::TruncateTablePlan;
+pub use plan_union_all::UnionAllPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
+pub use plan_view_create::CreateViewPlan;
+pub use plan_view_create::MaterializedViewInfo;
 pub use plan_visitor::PlanVisitor;
@@ -23,6 +23,8 @@ use crate::PlanNode;
 pub struct AggregatorFinalPlan {
     pub aggr_expr: Vec<Expression>,
     pub group_expr: Vec<Expression>,
+    /// The grouping sets as positions in `group_expr`, empty for a plain `GROUP BY`
+    pub grouping_sets: Vec<Vec<usize>>,
     pub schema: DataSchemaRef,
     pub schema_before_group_by: DataSchemaRef,
     pub input: Arc<PlanNode>,
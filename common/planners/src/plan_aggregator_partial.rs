@@ -22,6 +22,9 @@ use crate::PlanNode;
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct AggregatorPartialPlan {
     pub group_expr: Vec<Expression>,
+    /// The grouping sets of `GROUPING SETS`, `ROLLUP` and `CUBE` as positions in `group_expr`,
+    /// empty for a plain `GROUP BY`
+    pub grouping_sets: Vec<Vec<usize>>,
     pub aggr_expr: Vec<Expression>,
     pub schema: DataSchemaRef,
     pub input: Arc<PlanNode>,
@@ -23,10 +23,12 @@ use common_datavalues::DataType;
 use common_exception::Result;
 
 use crate::col;
+use crate::grouping_key_fields;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::validate_expression;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::ArrayJoinPlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
 use crate::ExplainType;
@@ -41,6 +43,7 @@ use crate::ProjectionPlan;
 use crate::RewriteHelper;
 use crate::SelectPlan;
 use crate::SortPlan;
+use crate::GROUPING_ID_COLUMN;
 
 pub enum AggregateMode {
     Partial,
@@ -123,6 +126,7 @@ impl PlanBuilder {
         schema_before_groupby: DataSchemaRef,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         Ok(match mode {
             AggregateMode::Partial => {
@@ -137,10 +141,21 @@ impl PlanBuilder {
                     // Fields. [aggrs,  key]
                     // aggrs: aggr_len aggregate states
                     // key: Varint by hash method
-
-                    let group_cols: Vec<String> =
-                        group_expr.iter().map(|expr| expr.column_name()).collect();
-                    let sample_block = DataBlock::empty_with_schema(schema_before_groupby);
+                    let (sample_block, group_cols): (_, Vec<String>) = match grouping_sets
+                        .is_empty()
+                    {
+                        true => (
+                            DataBlock::empty_with_schema(schema_before_groupby),
+                            group_expr.iter().map(|expr| expr.column_name()).collect(),
+                        ),
+                        // The key of grouping sets is made of the key columns and the grouping id
+                        false => {
+                            let fields = grouping_key_fields(group_expr, &schema_before_groupby)?;
+                            let group_cols = fields.iter().map(|f| f.name().clone()).collect();
+                            let schema = DataSchemaRefExt::create(fields);
+                            (DataBlock::empty_with_schema(schema), group_cols)
+                        }
+                    };
                     let method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
                     partial_fields.push(DataField::new("_group_by_key", method.data_type(), false));
                 }
@@ -149,19 +164,37 @@ impl PlanBuilder {
                     input: Arc::new(self.plan.clone()),
                     aggr_expr: aggr_expr.to_vec(),
                     group_expr: group_expr.to_vec(),
+                    grouping_sets: grouping_sets.to_vec(),
                     schema: DataSchemaRefExt::create(partial_fields),
                 }))
             }
             AggregateMode::Final => {
-                let mut final_exprs = aggr_expr.to_owned();
-                final_exprs.extend_from_slice(group_expr);
-                let final_fields =
-                    RewriteHelper::exprs_to_fields(&final_exprs, &schema_before_groupby)?;
+                let mut final_fields =
+                    RewriteHelper::exprs_to_fields(aggr_expr, &schema_before_groupby)?;
+                let group_fields =
+                    RewriteHelper::exprs_to_fields(group_expr, &schema_before_groupby)?;
+                match grouping_sets.is_empty() {
+                    true => final_fields.extend(group_fields),
+                    // The group by columns are NULL in the rows of the sets without them
+                    false => {
+                        final_fields.extend(
+                            group_fields
+                                .iter()
+                                .map(|f| DataField::new(f.name(), f.data_type().clone(), true)),
+                        );
+                        final_fields.push(DataField::new(
+                            GROUPING_ID_COLUMN,
+                            DataType::UInt64,
+                            false,
+                        ));
+                    }
+                }
 
                 Self::from(&PlanNode::AggregatorFinal(AggregatorFinalPlan {
                     input: Arc::new(self.plan.clone()),
                     aggr_expr: aggr_expr.to_vec(),
                     group_expr: group_expr.to_vec(),
+                    grouping_sets: grouping_sets.to_vec(),
                     schema: DataSchemaRefExt::create(final_fields),
                     schema_before_group_by: schema_before_groupby,
                 }))
@@ -174,12 +207,24 @@ impl PlanBuilder {
         &self,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+    ) -> Result<Self> {
+        self.aggregate_partial_with_grouping_sets(aggr_expr, group_expr, &[])
+    }
+
+    /// Apply a partial aggregator plan of `GROUPING SETS`, every input row is aggregated
+    /// once for each grouping set.
+    pub fn aggregate_partial_with_grouping_sets(
+        &self,
+        aggr_expr: &[Expression],
+        group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         self.aggregate(
             AggregateMode::Partial,
             self.plan.schema(),
             aggr_expr,
             group_expr,
+            grouping_sets,
         )
     }
 
@@ -189,12 +234,25 @@ impl PlanBuilder {
         schema_before_group_by: DataSchemaRef,
         aggr_expr: &[Expression],
         group_expr: &[Expression],
+    ) -> Result<Self> {
+        self.aggregate_final_with_grouping_sets(schema_before_group_by, aggr_expr, group_expr, &[])
+    }
+
+    /// Apply a final aggregator plan of `GROUPING SETS`, the grouping id is kept in the
+    /// `GROUPING_ID_COLUMN` column.
+    pub fn aggregate_final_with_grouping_sets(
+        &self,
+        schema_before_group_by: DataSchemaRef,
+        aggr_expr: &[Expression],
+        group_expr: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<Self> {
         self.aggregate(
             AggregateMode::Final,
             schema_before_group_by,
             aggr_expr,
             group_expr,
+            grouping_sets,
         )
     }
 
@@ -252,6 +310,27 @@ impl PlanBuilder {
         })))
     }
 
+    /// Unfold the arrays of `arrayJoin(arr)` exprs into rows, the arrays must be computed by the input.
+    pub fn array_join(&self, exprs: &[Expression]) -> Result<Self> {
+        for expr in exprs.iter() {
+            validate_expression(expr)?;
+        }
+
+        let input_schema = self.plan.schema();
+        let mut fields = input_schema.fields().clone();
+        for field in RewriteHelper::exprs_to_fields(exprs, &input_schema)? {
+            if !fields.iter().any(|x| x.name() == field.name()) {
+                fields.push(field);
+            }
+        }
+
+        Ok(Self::from(&PlanNode::ArrayJoin(ArrayJoinPlan {
+            exprs: exprs.to_vec(),
+            schema: DataSchemaRefExt::create(fields),
+            input: Arc::new(self.plan.clone()),
+        })))
+    }
+
     pub fn select(&self) -> Result<Self> {
         Ok(Self::from(&PlanNode::Select(SelectPlan {
             input: Arc::new(self.plan.clone()),
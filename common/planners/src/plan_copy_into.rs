@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataSchemaRef;
+use common_metatypes::MetaId;
+
+/// The options of FILE_FORMAT, the keys are lowercase, e.g. `type`, `field_delimiter`.
+pub type FileFormatOptions = HashMap<String, String>;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CopyIntoPlan {
+    pub db_name: String,
+    pub tbl_name: String,
+    pub tbl_id: MetaId,
+    /// The columns to load, the files have the same columns
+    pub schema: DataSchemaRef,
+    /// `s3://bucket/path/` or `file:///path/`
+    pub location: String,
+    /// Only load the files whose path matches the regex
+    pub pattern: Option<String>,
+    pub file_format: FileFormatOptions,
+    /// Parse the files and return the errors, without loading them
+    pub validation_mode: bool,
+    /// Load the files even if they have been loaded
+    pub force: bool,
+}
+
+/// The files loaded by COPY INTO, the table records them in the same commit as the data,
+/// so a file is loaded only once unless `force` is set.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct CopiedFiles {
+    /// The locations of the files, with the scheme and the bucket
+    pub files: Vec<String>,
+    pub force: bool,
+}
+
+impl CopyIntoPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+
+use crate::PlanNode;
+
+/// Where the rows of a materialized CTE come from.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub enum CteSource {
+    /// The query runs once and its result is shared by all the references.
+    Query(Arc<PlanNode>),
+    /// The anchor runs once, then the recursive part runs on the rows produced by
+    /// the previous iteration until it produces no rows.
+    Recursive {
+        anchor: Arc<PlanNode>,
+        recursive: Arc<PlanNode>,
+    },
+    /// The rows produced by the previous iteration of the recursive CTE.
+    WorkingTable,
+}
+
+/// Reads the materialized result of a CTE, shared by name within the query.
+/// The CTE queries are executed locally, so they are not inputs of the plan.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct CteScanPlan {
+    /// The unique name of the materialized result in the query
+    pub name: String,
+    pub schema: DataSchemaRef,
+    pub source: CteSource,
+}
+
+impl CteScanPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+}
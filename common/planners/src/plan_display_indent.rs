@@ -18,8 +18,15 @@ use std::fmt::Formatter;
 use crate::plan_broadcast::BroadcastPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTableAction;
+use crate::AlterTablePlan;
+use crate::ArrayJoinPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
+use crate::CreateViewPlan;
+use crate::CteScanPlan;
+use crate::CteSource;
+use crate::DeletePlan;
 use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
@@ -28,9 +35,11 @@ use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::ReadFilesPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::SubQueriesSetPlan;
+use crate::UpdatePlan;
 
 pub struct PlanNodeIndentFormatDisplay<'a> {
     indent: usize,
@@ -65,12 +74,21 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
+            PlanNode::ArrayJoin(plan) => Self::format_array_join(f, plan),
+            PlanNode::Sink(plan) => write!(f, "Sink: {}.{}", plan.db_name, plan.tbl_name),
+            PlanNode::CteScan(plan) => Self::format_cte_scan(f, plan),
+            PlanNode::UnionAll(_) => write!(f, "UnionAll"),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
             PlanNode::ReadSource(plan) => Self::format_read_source(f, plan),
+            PlanNode::ReadFiles(plan) => Self::format_read_files(f, plan),
             PlanNode::CreateDatabase(plan) => Self::format_create_database(f, plan),
             PlanNode::DropDatabase(plan) => Self::format_drop_database(f, plan),
             PlanNode::CreateTable(plan) => Self::format_create_table(f, plan),
             PlanNode::DropTable(plan) => Self::format_drop_table(f, plan),
+            PlanNode::AlterTable(plan) => Self::format_alter_table(f, plan),
+            PlanNode::CreateView(plan) => Self::format_create_view(f, plan),
+            PlanNode::Delete(plan) => Self::format_delete(f, plan),
+            PlanNode::Update(plan) => Self::format_update(f, plan),
             _ => {
                 let mut printed = true;
 
@@ -154,7 +172,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             f,
             "AggregatorPartial: groupBy=[{:?}], aggr=[{:?}]",
             plan.group_expr, plan.aggr_expr
-        )
+        )?;
+        if !plan.grouping_sets.is_empty() {
+            write!(f, ", groupingSets=[{:?}]", plan.grouping_sets)?;
+        }
+        Ok(())
     }
 
     fn format_aggregator_final(f: &mut Formatter, plan: &AggregatorFinalPlan) -> fmt::Result {
@@ -162,7 +184,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
             f,
             "AggregatorFinal: groupBy=[{:?}], aggr=[{:?}]",
             plan.group_expr, plan.aggr_expr
-        )
+        )?;
+        if !plan.grouping_sets.is_empty() {
+            write!(f, ", groupingSets=[{:?}]", plan.grouping_sets)?;
+        }
+        Ok(())
     }
 
     fn format_sort(f: &mut Formatter, plan: &SortPlan) -> fmt::Result {
@@ -191,6 +217,18 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         }
     }
 
+    fn format_array_join(f: &mut Formatter, plan: &ArrayJoinPlan) -> fmt::Result {
+        write!(f, "ArrayJoin: ")?;
+        for i in 0..plan.exprs.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", plan.exprs[i])?;
+        }
+
+        fmt::Result::Ok(())
+    }
+
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
         let mut names = Vec::with_capacity(plan.expressions.len());
         for expression in &plan.expressions {
@@ -203,6 +241,31 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Create sub queries sets: [{}]", names.join(", "))
     }
 
+    fn format_cte_scan(f: &mut Formatter, plan: &CteScanPlan) -> fmt::Result {
+        let source = match plan.source {
+            CteSource::Query(_) => "materialized",
+            CteSource::Recursive { .. } => "recursive",
+            CteSource::WorkingTable => "working table",
+        };
+        write!(
+            f,
+            "CteScan: {} [{}], schema: {}",
+            plan.name,
+            source,
+            PlanNode::display_schema(plan.schema.as_ref())
+        )
+    }
+
+    fn format_read_files(f: &mut Formatter, plan: &ReadFilesPlan) -> fmt::Result {
+        write!(
+            f,
+            "ReadFiles: {} [{} files], schema: {}",
+            plan.location,
+            plan.parts.len(),
+            PlanNode::display_schema(plan.schema.as_ref())
+        )
+    }
+
     fn format_read_source(f: &mut Formatter, plan: &ReadDataSourcePlan) -> fmt::Result {
         write!(
             f,
@@ -218,7 +281,11 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Create database {:},", plan.db)?;
         write!(f, " engine: {},", plan.engine.to_string())?;
         write!(f, " if_not_exists:{:},", plan.if_not_exists)?;
-        write!(f, " option: {:?}", plan.options)
+        write!(f, " option: {:?}", plan.options)?;
+        if plan.as_select.is_some() {
+            write!(f, ", as select")?;
+        }
+        Ok(())
     }
 
     fn format_drop_database(f: &mut Formatter, plan: &DropDatabasePlan) -> fmt::Result {
@@ -239,4 +306,64 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         write!(f, "Drop table {:}.{:},", plan.db, plan.table)?;
         write!(f, " if_exists:{:}", plan.if_exists)
     }
+
+    fn format_alter_table(f: &mut Formatter, plan: &AlterTablePlan) -> fmt::Result {
+        write!(f, "Alter table {:}.{:}", plan.db, plan.table)?;
+        match &plan.action {
+            AlterTableAction::AddColumn { field, default } => {
+                write!(f, " add column {}:{:?}", field.name(), field.data_type())?;
+                if let Some(default) = default {
+                    write!(f, " default {:?}", default)?;
+                }
+                Ok(())
+            }
+            AlterTableAction::DropColumn { name } => write!(f, " drop column {}", name),
+            AlterTableAction::RenameColumn { name, new_name } => {
+                write!(f, " rename column {} to {}", name, new_name)
+            }
+            AlterTableAction::ModifyColumn { name, data_type } => {
+                write!(f, " modify column {}:{:?}", name, data_type)
+            }
+            AlterTableAction::RenameTable { new_db, new_table } => {
+                write!(f, " rename to {}.{}", new_db, new_table)
+            }
+        }
+    }
+
+    fn format_create_view(f: &mut Formatter, plan: &CreateViewPlan) -> fmt::Result {
+        match &plan.materialized {
+            None => write!(f, "Create view {:}.{:},", plan.db, plan.view)?,
+            Some(mv) => {
+                write!(f, "Create materialized view {:}.{:},", plan.db, plan.view)?;
+                write!(f, " to: {:}.{:},", mv.target_db, mv.target_table)?;
+                if let Some(engine) = &mv.target_engine {
+                    write!(f, " engine: {},", engine)?;
+                }
+            }
+        }
+        write!(f, " if_not_exists:{:},", plan.if_not_exists)?;
+        write!(f, " as: {}", plan.query)
+    }
+
+    fn format_delete(f: &mut Formatter, plan: &DeletePlan) -> fmt::Result {
+        write!(f, "Delete from {:}.{:}", plan.db_name, plan.tbl_name)?;
+        if let Some(selection) = &plan.selection {
+            write!(f, ", where: {:?}", selection)?;
+        }
+        Ok(())
+    }
+
+    fn format_update(f: &mut Formatter, plan: &UpdatePlan) -> fmt::Result {
+        write!(f, "Update {:}.{:} set ", plan.db_name, plan.tbl_name)?;
+        for (i, (column, expr)) in plan.update_list.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} = {:?}", column, expr)?;
+        }
+        if let Some(selection) = &plan.selection {
+            write!(f, ", where: {:?}", selection)?;
+        }
+        Ok(())
+    }
 }
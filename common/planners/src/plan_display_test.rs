@@ -38,6 +38,7 @@ fn test_plan_display_indent() -> Result<()> {
         schema,
         engine: "JSON".to_string(),
         options,
+        as_select: None,
     });
 
     assert_eq!(
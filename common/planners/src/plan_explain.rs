@@ -26,6 +26,7 @@ pub enum ExplainType {
     Syntax,
     Graph,
     Pipeline,
+    Analyze,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
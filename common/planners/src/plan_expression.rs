@@ -27,6 +27,7 @@ use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::FunctionFactory;
 use lazy_static::lazy_static;
 
+use crate::is_higher_order_function;
 use crate::PlanNode;
 
 lazy_static! {
@@ -116,6 +117,12 @@ pub enum Expression {
         name: String,
         query_plan: Arc<PlanNode>,
     },
+    /// A lambda such as `x -> x + 1`, only valid as the first argument of higher-order functions
+    /// like `arrayMap(x -> x + 1, arr)`. The body can only refer to the lambda parameters.
+    Lambda {
+        params: Vec<String>,
+        body: Box<Expression>,
+    },
 }
 
 impl Expression {
@@ -201,6 +208,10 @@ impl Expression {
             }
             Expression::Subquery { name, .. } => name.clone(),
             Expression::ScalarSubquery { name, .. } => name.clone(),
+            Expression::Lambda { params, body } => match params.len() {
+                1 => format!("{} -> {}", params[0], body.column_name()),
+                _ => format!("({}) -> {}", params.join(", "), body.column_name()),
+            },
             _ => format!("{:?}", self),
         }
     }
@@ -273,6 +284,9 @@ impl Expression {
                 func.return_type(&arg_types)
             }
 
+            Expression::ScalarFunction { op, args } if is_higher_order_function(op) => {
+                Self::to_higher_order_type(op, args, input_schema)
+            }
             Expression::ScalarFunction { op, args } => {
                 let mut arg_types = Vec::with_capacity(args.len());
                 for arg in args {
@@ -290,6 +304,9 @@ impl Expression {
             )),
             Expression::Cast { data_type, .. } => Ok(data_type.clone()),
             Expression::Sort { expr, .. } => expr.to_data_type(input_schema),
+            Expression::Lambda { .. } => Result::Err(ErrorCode::IllegalDataType(
+                "Lambda expressions are not valid to get return type",
+            )),
         }
     }
 
@@ -393,6 +410,10 @@ impl fmt::Debug for Expression {
             Expression::Cast { expr, data_type } => {
                 write!(f, "cast({:?} as {:?})", expr, data_type)
             }
+            Expression::Lambda { params, body } => match params.len() {
+                1 => write!(f, "{} -> {:?}", params[0], body),
+                _ => write!(f, "({}) -> {:?}", params.join(", "), body),
+            },
         }
     }
 }
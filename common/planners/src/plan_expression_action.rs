@@ -23,6 +23,8 @@ use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 
+use crate::Expression;
+
 #[derive(Debug, Clone)]
 pub enum ExpressionAction {
     /// Column which must be in input.
@@ -31,6 +33,7 @@ pub enum ExpressionAction {
     Constant(ActionConstant),
     Alias(ActionAlias),
     Function(ActionFunction),
+    Lambda(ActionLambda),
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +73,18 @@ pub struct ActionFunction {
     pub arg_fields: Vec<DataField>,
 }
 
+/// Higher-order function whose lambda body is evaluated on the items of the array arguments.
+#[derive(Debug, Clone)]
+pub struct ActionLambda {
+    pub name: String,
+    pub func_name: String,
+    pub return_type: DataType,
+    pub params: Vec<String>,
+    pub body: Expression,
+    pub arg_names: Vec<String>,
+    pub arg_types: Vec<DataType>,
+}
+
 impl ExpressionAction {
     pub fn column_name(&self) -> &str {
         match self {
@@ -77,6 +92,7 @@ impl ExpressionAction {
             ExpressionAction::Constant(c) => &c.name,
             ExpressionAction::Alias(a) => &a.name,
             ExpressionAction::Function(f) => &f.name,
+            ExpressionAction::Lambda(l) => &l.name,
         }
     }
 }
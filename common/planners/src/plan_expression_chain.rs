@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
 
+use crate::is_higher_order_function;
 use crate::ActionAlias;
 use crate::ActionConstant;
 use crate::ActionFunction;
 use crate::ActionInput;
+use crate::ActionLambda;
 use crate::Expression;
 use crate::ExpressionAction;
 
@@ -140,6 +143,38 @@ impl ExpressionChain {
                 self.actions.push(ExpressionAction::Function(function));
             }
 
+            Expression::ScalarFunction { op, args } if is_higher_order_function(op) => {
+                let (params, body) = match args.first() {
+                    Some(Expression::Lambda { params, body }) => (params.clone(), body),
+                    _ => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "The first argument of function {} must be a lambda",
+                            op
+                        )))
+                    }
+                };
+
+                let arrays = &args[1..];
+                for expr in arrays.iter() {
+                    self.add_expr(expr)?;
+                }
+
+                let lambda = ActionLambda {
+                    name: expr.column_name(),
+                    func_name: op.clone(),
+                    return_type: expr.to_data_type(&self.schema)?,
+                    params,
+                    body: body.as_ref().clone(),
+                    arg_names: arrays.iter().map(|arg| arg.column_name()).collect(),
+                    arg_types: arrays
+                        .iter()
+                        .map(|arg| arg.to_data_type(&self.schema))
+                        .collect::<Result<Vec<_>>>()?,
+                };
+
+                self.actions.push(ExpressionAction::Lambda(lambda));
+            }
+
             Expression::ScalarFunction { op, args } => {
                 for expr in args.iter() {
                     self.add_expr(expr)?;
@@ -193,7 +228,7 @@ impl ExpressionChain {
                 self.add_expr(expr)?;
             }
 
-            Expression::Wildcard => {}
+            Expression::Wildcard | Expression::Lambda { .. } => {}
             Expression::Cast {
                 expr: sub_expr,
                 data_type,
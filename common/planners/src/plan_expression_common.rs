@@ -18,6 +18,8 @@ use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::is_array_join_function;
+use crate::is_grouping_function;
 use crate::Expression;
 use crate::ExpressionVisitor;
 use crate::Recursion;
@@ -43,6 +45,34 @@ pub fn find_aggregate_exprs(exprs: &[Expression]) -> Vec<Expression> {
     })
 }
 
+/// Collect all deeply nested `arrayJoin`/`unnest` function calls. They are returned
+/// in order of occurrence (depth first), with duplicates omitted.
+pub fn find_array_join_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => is_array_join_function(op),
+        _ => false,
+    })
+}
+
+/// Collect all deeply nested `grouping` function calls. They are returned in order of
+/// occurrence (depth first), with duplicates omitted.
+pub fn find_grouping_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| match nest_exprs {
+        Expression::ScalarFunction { op, .. } => is_grouping_function(op),
+        _ => false,
+    })
+}
+
+/// Collect all deeply nested `Expression::Subquery` and `Expression::ScalarSubquery`.
+pub fn find_subquery_exprs(exprs: &[Expression]) -> Vec<Expression> {
+    find_exprs_in_exprs(exprs, &|nest_exprs| {
+        matches!(
+            nest_exprs,
+            Expression::Subquery { .. } | Expression::ScalarSubquery { .. }
+        )
+    })
+}
+
 /// Collect all arguments from aggregation function and append to this exprs
 /// [ColumnExpr(b), Aggr(sum(a, b))] ---> [ColumnExpr(b), ColumnExpr(a)]
 
@@ -318,7 +348,8 @@ where F: Fn(&Expression) -> Result<Option<Expression>> {
             Expression::Column(_)
             | Expression::Literal { .. }
             | Expression::Subquery { .. }
-            | Expression::ScalarSubquery { .. } => Ok(expr.clone()),
+            | Expression::ScalarSubquery { .. }
+            | Expression::Lambda { .. } => Ok(expr.clone()),
         },
     }
 }
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::list_data_type;
+use common_functions::scalars::list_item_type;
+
+use crate::Expression;
+
+/// Higher-order functions take a lambda as the first argument, followed by the arrays
+/// whose items are bound to the lambda parameters.
+pub fn is_higher_order_function(op: &str) -> bool {
+    matches!(op.to_lowercase().as_str(), "arraymap" | "arrayfilter")
+}
+
+/// `arrayJoin(arr)` (alias `unnest`) unfolds an array into rows, so it is planned as an
+/// `ArrayJoinPlan` instead of being evaluated as a scalar function.
+pub fn is_array_join_function(op: &str) -> bool {
+    matches!(op.to_lowercase().as_str(), "arrayjoin" | "unnest")
+}
+
+impl Expression {
+    /// The schema the lambda body is evaluated with, one field per parameter typed as the array items.
+    pub fn to_lambda_schema(
+        op: &str,
+        params: &[String],
+        arg_types: &[DataType],
+    ) -> Result<DataSchemaRef> {
+        if params.len() != arg_types.len() {
+            return Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Lambda of function {} expect to have {} parameters, but got {}",
+                op,
+                arg_types.len(),
+                params.len()
+            )));
+        }
+
+        let fields = params
+            .iter()
+            .zip(arg_types.iter())
+            .map(|(param, arg_type)| {
+                list_item_type(op, arg_type).map(|item_type| DataField::new(param, item_type, true))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataSchemaRefExt::create(fields))
+    }
+
+    /// The result type of `op(lambda, arr1, ..., arrn)`:
+    /// arrayMap returns the array of the lambda results, arrayFilter returns the first array type.
+    pub(crate) fn to_higher_order_type(
+        op: &str,
+        args: &[Expression],
+        input_schema: &DataSchemaRef,
+    ) -> Result<DataType> {
+        if args.len() < 2 {
+            return Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have at least 2 arguments, but got {}",
+                op,
+                args.len()
+            )));
+        }
+
+        let (params, body) = match &args[0] {
+            Expression::Lambda { params, body } => (params, body),
+            _ => {
+                return Result::Err(ErrorCode::BadArguments(format!(
+                    "The first argument of function {} must be a lambda",
+                    op
+                )))
+            }
+        };
+
+        let arg_types = args[1..]
+            .iter()
+            .map(|arg| arg.to_data_type(input_schema))
+            .collect::<Result<Vec<_>>>()?;
+        let lambda_schema = Self::to_lambda_schema(op, params, &arg_types)?;
+        let body_type = body.to_data_type(&lambda_schema)?;
+
+        match op.to_lowercase().as_str() {
+            "arraymap" => list_data_type(body_type),
+            _ => match body_type {
+                DataType::Boolean => list_data_type(list_item_type(op, &arg_types[0])?),
+                other => Result::Err(ErrorCode::IllegalDataType(format!(
+                    "Lambda of function {} must return Boolean, but got {:?}",
+                    op, other
+                ))),
+            },
+        }
+    }
+}
@@ -17,6 +17,8 @@ use common_exception::Result;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 
+use crate::is_higher_order_function;
+use crate::plan_expression_common::find_column_exprs;
 use crate::Expression;
 use crate::ExpressionVisitor;
 use crate::Recursion;
@@ -86,13 +88,64 @@ fn validate_function_arg(func: Box<dyn Function>, args: &[Expression]) -> Result
     }
 }
 
+fn validate_higher_order_function(op: &str, args: &[Expression]) -> Result<()> {
+    if args.len() < 2 {
+        return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at least 2 arguments, but got {}",
+            op,
+            args.len()
+        )));
+    }
+
+    match &args[0] {
+        Expression::Lambda { params, .. } if params.len() == args.len() - 1 => Ok(()),
+        Expression::Lambda { params, .. } => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "Lambda of function {} expect to have {} parameters, but got {}",
+            op,
+            args.len() - 1,
+            params.len()
+        ))),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "The first argument of function {} must be a lambda",
+            op
+        ))),
+    }
+}
+
+// The lambda body is evaluated on the array items only, so it can't refer to other columns.
+fn validate_lambda(params: &[String], body: &Expression) -> Result<()> {
+    for column in find_column_exprs(&[body.clone()]) {
+        let name = column.column_name();
+        if !params.contains(&name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Lambda can only refer to its parameters, but got column {}",
+                name
+            )));
+        }
+    }
+    validate_expression(body)
+}
+
 // Can works before expression,filter,having in PlanBuilder
 pub fn validate_expression(expr: &Expression) -> Result<()> {
     let validator = ExpressionValidator::new(&|expr: &Expression| match expr {
+        Expression::ScalarFunction { op, args } if is_higher_order_function(op) => {
+            validate_higher_order_function(op, args)
+        }
         Expression::ScalarFunction { op, args } => {
+            if args
+                .iter()
+                .any(|arg| matches!(arg, Expression::Lambda { .. }))
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Function {} does not accept lambda arguments",
+                    op
+                )));
+            }
             let func = FunctionFactory::get(op)?;
             validate_function_arg(func, args)
         }
+        Expression::Lambda { params, body } => validate_lambda(params, body),
 
         // Currently no need to check  UnaryExpression and BinaryExpression
         // todo: AggregateFunction validation after generic AggregateFunctions
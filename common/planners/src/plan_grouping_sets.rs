@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::col;
+use crate::lit;
+use crate::Expression;
+
+/// The column added by the partial aggregator when grouping by `GROUPING SETS`, `ROLLUP`
+/// or `CUBE`. Bit `i` is set if the i-th group by expression is not in the grouping set
+/// of the row, so the rows of the different sets never share a group.
+pub const GROUPING_ID_COLUMN: &str = "_grouping_id";
+
+/// A grouping id is a 64 bits mask of the group by expressions.
+pub const MAX_GROUPING_EXPRS: usize = 64;
+
+/// `grouping(a, b)` tells which of its arguments are aggregated in the row.
+pub fn is_grouping_function(op: &str) -> bool {
+    op.eq_ignore_ascii_case("grouping")
+}
+
+/// The key column of the i-th group by expression, the expression itself or NULL if it is
+/// not in the grouping set. The aggregate functions keep reading the original columns.
+pub fn grouping_key_column(index: usize) -> String {
+    format!("_grouping_key_{}", index)
+}
+
+/// The grouping id of a grouping set, see `GROUPING_ID_COLUMN`.
+pub fn grouping_id(grouping_set: &[usize], group_len: usize) -> u64 {
+    (0..group_len)
+        .filter(|index| !grouping_set.contains(index))
+        .fold(0, |id, index| id | (1 << index))
+}
+
+/// The fields of the group by key columns, followed by the grouping id.
+pub fn grouping_key_fields(
+    group_expr: &[Expression],
+    schema_before_group_by: &DataSchemaRef,
+) -> Result<Vec<DataField>> {
+    let mut fields = group_expr
+        .iter()
+        .enumerate()
+        .map(|(index, expr)| {
+            let data_type = expr.to_data_type(schema_before_group_by)?;
+            Ok(DataField::new(&grouping_key_column(index), data_type, true))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    fields.push(DataField::new(GROUPING_ID_COLUMN, DataType::UInt64, false));
+    Ok(fields)
+}
+
+/// `grouping(a, b)` is computed from the grouping id with the positions of its arguments in
+/// the group by expressions, the bit of the last argument is the lowest:
+/// `(id >> pos_a & 1) << 1 | (id >> pos_b & 1)`.
+pub fn grouping_to_expr(grouping: &Expression, group_expr: &[Expression]) -> Result<Expression> {
+    let args = match grouping {
+        Expression::ScalarFunction { op, args } if is_grouping_function(op) => args,
+        other => {
+            return Result::Err(ErrorCode::LogicalError(format!(
+                "Expected grouping function, but got: {:?}",
+                other
+            )))
+        }
+    };
+    if args.is_empty() {
+        return Result::Err(ErrorCode::NumberArgumentsNotMatch(
+            "Function grouping expect at least 1 argument",
+        ));
+    }
+
+    let mut result: Option<Expression> = None;
+    for (index, arg) in args.iter().enumerate() {
+        let position = group_expr
+            .iter()
+            .position(|expr| expr == arg)
+            .ok_or_else(|| {
+                ErrorCode::IllegalAggregateExp(format!(
+                    "Argument `{:?}` of function grouping is not in GROUP BY",
+                    arg
+                ))
+            })?;
+
+        let bit = Expression::ScalarFunction {
+            op: "bitAnd".to_string(),
+            args: vec![
+                Expression::ScalarFunction {
+                    op: "bitShiftRight".to_string(),
+                    args: vec![col(GROUPING_ID_COLUMN), lit(position as u64)],
+                },
+                lit(1u64),
+            ],
+        };
+        let bit = match args.len() - 1 - index {
+            0 => bit,
+            shift => Expression::ScalarFunction {
+                op: "bitShiftLeft".to_string(),
+                args: vec![bit, lit(shift as u64)],
+            },
+        };
+        result = Some(match result {
+            None => bit,
+            Some(result) => Expression::ScalarFunction {
+                op: "bitOr".to_string(),
+                args: vec![result, bit],
+            },
+        });
+    }
+
+    Ok(result.unwrap())
+}
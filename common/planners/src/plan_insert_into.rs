@@ -16,11 +16,17 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_exception::Result;
 use common_infallible::Mutex;
 use common_metatypes::MetaId;
 
-type BlockStream =
-    std::pin::Pin<Box<dyn futures::stream::Stream<Item = DataBlock> + Sync + Send + 'static>>;
+use crate::CopiedFiles;
+use crate::PlanNode;
+
+/// The blocks to insert, an error aborts the insert, nothing of it is committed.
+pub type InsertInputStream = std::pin::Pin<
+    Box<dyn futures::stream::Stream<Item = Result<DataBlock>> + Sync + Send + 'static>,
+>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub struct InsertIntoPlan {
@@ -28,9 +34,15 @@ pub struct InsertIntoPlan {
     pub tbl_name: String,
     pub tbl_id: MetaId,
     pub schema: DataSchemaRef,
+    /// The source query of `INSERT INTO ... SELECT`, its output has been cast to the schema
+    pub select_plan: Option<Box<PlanNode>>,
+    /// The format of `INSERT INTO ... FORMAT`, the data is streamed by the client
+    pub format: Option<String>,
+    /// The files of COPY INTO, recorded with the data
+    pub copied_files: Option<CopiedFiles>,
 
     #[serde(skip, default = "InsertIntoPlan::empty_stream")]
-    pub input_stream: Arc<Mutex<Option<BlockStream>>>,
+    pub input_stream: Arc<Mutex<Option<InsertInputStream>>>,
 }
 
 impl PartialEq for InsertIntoPlan {
@@ -38,17 +50,20 @@ impl PartialEq for InsertIntoPlan {
         self.db_name == other.db_name
             && self.tbl_name == other.tbl_name
             && self.schema == other.schema
+            && self.select_plan == other.select_plan
+            && self.format == other.format
+            && self.copied_files == other.copied_files
     }
 }
 
 impl InsertIntoPlan {
-    pub fn empty_stream() -> Arc<Mutex<Option<BlockStream>>> {
+    pub fn empty_stream() -> Arc<Mutex<Option<InsertInputStream>>> {
         Arc::new(Mutex::new(None))
     }
     pub fn schema(&self) -> DataSchemaRef {
         self.schema.clone()
     }
-    pub fn set_input_stream(&self, input_stream: BlockStream) {
+    pub fn set_input_stream(&self, input_stream: InsertInputStream) {
         let mut writer = self.input_stream.lock();
         *writer = Some(input_stream);
     }
@@ -22,10 +22,18 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
+use crate::ArrayJoinPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateResourceGroupPlan;
 use crate::CreateTablePlan;
+use crate::CreateViewPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropResourceGroupPlan;
 use crate::DropTablePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
@@ -38,14 +46,19 @@ use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::ReadFilesPlan;
 use crate::RemotePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
+use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -63,8 +76,13 @@ pub enum PlanNode {
     Sort(SortPlan),
     Limit(LimitPlan),
     LimitBy(LimitByPlan),
+    ArrayJoin(ArrayJoinPlan),
+    Sink(SinkPlan),
+    CteScan(CteScanPlan),
+    UnionAll(UnionAllPlan),
     Scan(ScanPlan),
     ReadSource(ReadDataSourcePlan),
+    ReadFiles(ReadFilesPlan),
     Select(SelectPlan),
     Explain(ExplainPlan),
     CreateDatabase(CreateDatabasePlan),
@@ -72,13 +90,21 @@ pub enum PlanNode {
     CreateTable(CreateTablePlan),
     DescribeTable(DescribeTablePlan),
     DropTable(DropTablePlan),
+    AlterTable(AlterTablePlan),
+    CreateView(CreateViewPlan),
     TruncateTable(TruncateTablePlan),
+    Delete(DeletePlan),
+    Update(UpdatePlan),
     UseDatabase(UseDatabasePlan),
     SetVariable(SettingPlan),
     InsertInto(InsertIntoPlan),
+    CopyInto(CopyIntoPlan),
     ShowCreateTable(ShowCreateTablePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Kill(KillPlan),
+    Transaction(TransactionPlan),
+    CreateResourceGroup(CreateResourceGroupPlan),
+    DropResourceGroup(DropResourceGroupPlan),
 }
 
 impl PlanNode {
@@ -98,7 +124,12 @@ impl PlanNode {
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
+            PlanNode::ArrayJoin(v) => v.schema(),
+            PlanNode::Sink(v) => v.schema(),
+            PlanNode::CteScan(v) => v.schema(),
+            PlanNode::UnionAll(v) => v.schema(),
             PlanNode::ReadSource(v) => v.schema(),
+            PlanNode::ReadFiles(v) => v.schema(),
             PlanNode::Select(v) => v.schema(),
             PlanNode::Explain(v) => v.schema(),
             PlanNode::CreateDatabase(v) => v.schema(),
@@ -106,14 +137,22 @@ impl PlanNode {
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
+            PlanNode::CreateView(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
             PlanNode::InsertInto(v) => v.schema(),
+            PlanNode::CopyInto(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Kill(v) => v.schema(),
+            PlanNode::Transaction(v) => v.schema(),
+            PlanNode::CreateResourceGroup(v) => v.schema(),
+            PlanNode::DropResourceGroup(v) => v.schema(),
         }
     }
 
@@ -132,7 +171,12 @@ impl PlanNode {
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
+            PlanNode::ArrayJoin(_) => "ArrayJoinPlan",
+            PlanNode::Sink(_) => "SinkPlan",
+            PlanNode::CteScan(_) => "CteScanPlan",
+            PlanNode::UnionAll(_) => "UnionAllPlan",
             PlanNode::ReadSource(_) => "ReadSourcePlan",
+            PlanNode::ReadFiles(_) => "ReadFilesPlan",
             PlanNode::Select(_) => "SelectPlan",
             PlanNode::Explain(_) => "ExplainPlan",
             PlanNode::CreateDatabase(_) => "CreateDatabasePlan",
@@ -140,14 +184,22 @@ impl PlanNode {
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
+            PlanNode::CreateView(_) => "CreateViewPlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
+            PlanNode::Delete(_) => "DeletePlan",
+            PlanNode::Update(_) => "UpdatePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
             PlanNode::InsertInto(_) => "InsertIntoPlan",
+            PlanNode::CopyInto(_) => "CopyIntoPlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Kill(_) => "KillQuery",
+            PlanNode::Transaction(_) => "TransactionPlan",
+            PlanNode::CreateResourceGroup(_) => "CreateResourceGroupPlan",
+            PlanNode::DropResourceGroup(_) => "DropResourceGroupPlan",
         }
     }
 
@@ -162,6 +214,9 @@ impl PlanNode {
             PlanNode::Filter(v) => vec![v.input.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
+            PlanNode::ArrayJoin(v) => vec![v.input.clone()],
+            PlanNode::Sink(v) => vec![v.input.clone()],
+            PlanNode::UnionAll(v) => v.inputs.clone(),
             PlanNode::Explain(v) => vec![v.input.clone()],
             PlanNode::Select(v) => vec![v.input.clone()],
             PlanNode::Sort(v) => vec![v.input.clone()],
@@ -190,6 +245,9 @@ impl PlanNode {
             PlanNode::Filter(v) => v.set_input(inputs[0]),
             PlanNode::Having(v) => v.set_input(inputs[0]),
             PlanNode::Limit(v) => v.set_input(inputs[0]),
+            PlanNode::ArrayJoin(v) => v.set_input(inputs[0]),
+            PlanNode::Sink(v) => v.set_input(inputs[0]),
+            PlanNode::UnionAll(v) => v.set_inputs(inputs),
             PlanNode::Explain(v) => v.set_input(inputs[0]),
             PlanNode::Select(v) => v.set_input(inputs[0]),
             PlanNode::Sort(v) => v.set_input(inputs[0]),
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataSchemaRef;
+
+use crate::FileFormatOptions;
+use crate::Partitions;
+
+/// Read the files of a location, each part is the path of a file.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ReadFilesPlan {
+    pub location: String,
+    pub file_format: FileFormatOptions,
+    pub schema: DataSchemaRef,
+    pub parts: Partitions,
+}
+
+impl ReadFilesPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    /// The files of a local location can only be read by the current node.
+    pub fn is_local(&self) -> bool {
+        !self.location.starts_with("s3://")
+    }
+
+    /// The location of a file of the parts, with the scheme and the bucket.
+    pub fn file_location(&self, file: &str) -> String {
+        match self.location.strip_prefix("s3://") {
+            Some(bucket_path) => {
+                let bucket = bucket_path.split('/').next().unwrap_or_default();
+                format!("s3://{}/{}", bucket, file)
+            }
+            None => format!("file:///{}", file),
+        }
+    }
+}
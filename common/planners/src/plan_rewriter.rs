@@ -25,10 +25,18 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
+use crate::ArrayJoinPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateResourceGroupPlan;
 use crate::CreateTablePlan;
+use crate::CreateViewPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropResourceGroupPlan;
 use crate::DropTablePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
@@ -45,14 +53,19 @@ use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::ReadFilesPlan;
 use crate::RemotePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
+use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanRewriter` is a visitor that can help to rewrite `PlanNode`
@@ -85,8 +98,13 @@ pub trait PlanRewriter {
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
+            PlanNode::ArrayJoin(plan) => self.rewrite_array_join(plan),
+            PlanNode::Sink(plan) => self.rewrite_sink(plan),
+            PlanNode::CteScan(plan) => self.rewrite_cte_scan(plan),
+            PlanNode::UnionAll(plan) => self.rewrite_union_all(plan),
             PlanNode::Scan(plan) => self.rewrite_scan(plan),
             PlanNode::ReadSource(plan) => self.rewrite_read_data_source(plan),
+            PlanNode::ReadFiles(plan) => self.rewrite_read_files(plan),
             PlanNode::Select(plan) => self.rewrite_select(plan),
             PlanNode::Explain(plan) => self.rewrite_explain(plan),
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
@@ -102,10 +120,18 @@ pub trait PlanRewriter {
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
             PlanNode::InsertInto(plan) => self.rewrite_insert_into(plan),
+            PlanNode::CopyInto(plan) => self.rewrite_copy_into(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
+            PlanNode::CreateView(plan) => self.rewrite_create_view(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
             PlanNode::Kill(plan) => self.rewrite_kill(plan),
+            PlanNode::Transaction(plan) => self.rewrite_transaction(plan),
+            PlanNode::CreateResourceGroup(plan) => self.rewrite_create_resource_group(plan),
+            PlanNode::DropResourceGroup(plan) => self.rewrite_drop_resource_group(plan),
         }
     }
 
@@ -182,6 +208,7 @@ pub trait PlanRewriter {
                     query_plan: Arc::new(new_subquery),
                 })
             }
+            Expression::Lambda { .. } => Ok(expr.clone()),
         }
     }
 
@@ -276,6 +303,36 @@ pub trait PlanRewriter {
             .build()
     }
 
+    fn rewrite_array_join(&mut self, plan: &ArrayJoinPlan) -> Result<PlanNode> {
+        let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
+        PlanBuilder::from(&new_input)
+            .array_join(&plan.exprs)?
+            .build()
+    }
+
+    fn rewrite_sink(&mut self, plan: &SinkPlan) -> Result<PlanNode> {
+        let mut new_plan = plan.clone();
+        new_plan.set_input(&self.rewrite_plan_node(plan.input.as_ref())?);
+        Ok(PlanNode::Sink(new_plan))
+    }
+
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CteScan(plan.clone()))
+    }
+
+    fn rewrite_union_all(&mut self, plan: &UnionAllPlan) -> Result<PlanNode> {
+        let inputs = plan
+            .inputs
+            .iter()
+            .map(|input| self.rewrite_plan_node(input.as_ref()).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PlanNode::UnionAll(UnionAllPlan {
+            schema: plan.schema.clone(),
+            inputs,
+        }))
+    }
+
     fn rewrite_scan(&mut self, plan: &ScanPlan) -> Result<PlanNode> {
         Ok(PlanNode::Scan(plan.clone()))
     }
@@ -295,6 +352,10 @@ pub trait PlanRewriter {
         }
     }
 
+    fn rewrite_read_files(&mut self, plan: &ReadFilesPlan) -> Result<PlanNode> {
+        Ok(PlanNode::ReadFiles(plan.clone()))
+    }
+
     fn rewrite_select(&mut self, plan: &SelectPlan) -> Result<PlanNode> {
         Ok(PlanNode::Select(SelectPlan {
             input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
@@ -344,13 +405,48 @@ pub trait PlanRewriter {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
 
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
+    fn rewrite_create_view(&mut self, plan: &CreateViewPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CreateView(plan.clone()))
+    }
+
     fn rewrite_truncate_table(&mut self, plan: &TruncateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::TruncateTable(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
+    fn rewrite_copy_into(&mut self, plan: &CopyIntoPlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyInto(plan.clone()))
+    }
+
     fn rewrite_kill(&mut self, plan: &KillPlan) -> Result<PlanNode> {
         Ok(PlanNode::Kill(plan.clone()))
     }
+
+    fn rewrite_transaction(&mut self, plan: &TransactionPlan) -> Result<PlanNode> {
+        Ok(PlanNode::Transaction(plan.clone()))
+    }
+
+    fn rewrite_create_resource_group(
+        &mut self,
+        plan: &CreateResourceGroupPlan,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateResourceGroup(plan.clone()))
+    }
+
+    fn rewrite_drop_resource_group(&mut self, plan: &DropResourceGroupPlan) -> Result<PlanNode> {
+        Ok(PlanNode::DropResourceGroup(plan.clone()))
+    }
 }
 
 pub struct RewriteHelper {}
@@ -523,6 +619,7 @@ impl RewriteHelper {
             | Expression::Literal { .. }
             | Expression::Subquery { .. }
             | Expression::ScalarSubquery { .. }
+            | Expression::Lambda { .. }
             | Expression::Sort { .. } => Ok(expr.clone()),
         }
     }
@@ -587,6 +684,7 @@ impl RewriteHelper {
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => vec![expr.as_ref().clone()],
             Expression::Cast { expr, .. } => vec![expr.as_ref().clone()],
+            Expression::Lambda { .. } => vec![],
         })
     }
 
@@ -624,6 +722,7 @@ impl RewriteHelper {
             Expression::Wildcard => vec![],
             Expression::Sort { expr, .. } => Self::expression_plan_columns(expr)?,
             Expression::Cast { expr, .. } => Self::expression_plan_columns(expr)?,
+            Expression::Lambda { .. } => vec![],
         })
     }
 
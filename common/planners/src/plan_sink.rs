@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_metatypes::MetaId;
+
+use crate::CopiedFiles;
+use crate::PlanNode;
+
+/// Writes the blocks of the input plan into the table, the output is empty.
+/// In cluster mode every node writes its own partial input.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct SinkPlan {
+    pub db_name: String,
+    pub tbl_name: String,
+    pub tbl_id: MetaId,
+    /// The columns to write, the input has been cast to them
+    pub schema: DataSchemaRef,
+    /// The logical plan
+    pub input: Arc<PlanNode>,
+    /// The files of COPY INTO, in cluster mode each node records the files it reads
+    pub copied_files: Option<CopiedFiles>,
+}
+
+impl SinkPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_input(&mut self, node: &PlanNode) {
+        self.input = Arc::new(node.clone());
+    }
+}
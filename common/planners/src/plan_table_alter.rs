@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum AlterTableAction {
+    /// The default is already cast to the column type, None for NULL
+    AddColumn {
+        field: DataField,
+        default: Option<DataValue>,
+    },
+    DropColumn {
+        name: String,
+    },
+    RenameColumn {
+        name: String,
+        new_name: String,
+    },
+    /// Only widening of the type is allowed
+    ModifyColumn {
+        name: String,
+        data_type: DataType,
+    },
+    /// Rename the table, or move it to another database
+    RenameTable {
+        new_db: String,
+        new_table: String,
+    },
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AlterTablePlan {
+    pub db: String,
+    /// The table name
+    pub table: String,
+    pub action: AlterTableAction,
+    /// The schema of the table after altered
+    pub table_schema: DataSchemaRef,
+}
+
+impl AlterTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+
+    /// The database and name of the table after altered.
+    pub fn new_name(&self) -> (String, String) {
+        match &self.action {
+            AlterTableAction::RenameTable { new_db, new_table } => {
+                (new_db.clone(), new_table.clone())
+            }
+            _ => (self.db.clone(), self.table.clone()),
+        }
+    }
+}
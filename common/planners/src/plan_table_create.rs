@@ -16,6 +16,8 @@ use std::collections::HashMap;
 
 use common_datavalues::DataSchemaRef;
 
+use crate::PlanNode;
+
 pub type TableOptions = HashMap<String, String>;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -29,6 +31,9 @@ pub struct CreateTablePlan {
     /// The file type of physical file
     pub engine: String,
     pub options: TableOptions,
+    /// The query of `CREATE TABLE ... AS SELECT`, its rows are inserted into the new table
+    #[serde(skip)]
+    pub as_select: Option<Box<PlanNode>>,
 }
 
 impl CreateTablePlan {
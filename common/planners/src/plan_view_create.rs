@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+
+/// The table a materialized view reads the inserted blocks from, and the table it writes to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MaterializedViewInfo {
+    pub source_db: String,
+    pub source_table: String,
+    pub target_db: String,
+    pub target_table: String,
+    /// The engine of the target table created with the view, None if the target table exists
+    pub target_engine: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct CreateViewPlan {
+    pub if_not_exists: bool,
+    pub db: String,
+    /// The view name
+    pub view: String,
+    /// The SQL text of the query
+    pub query: String,
+    /// The output schema of the query
+    pub view_schema: DataSchemaRef,
+    /// None for the views expanded at plan time
+    pub materialized: Option<MaterializedViewInfo>,
+}
+
+impl CreateViewPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
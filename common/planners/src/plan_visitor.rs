@@ -18,10 +18,18 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
+use crate::ArrayJoinPlan;
+use crate::CopyIntoPlan;
 use crate::CreateDatabasePlan;
+use crate::CreateResourceGroupPlan;
 use crate::CreateTablePlan;
+use crate::CreateViewPlan;
+use crate::CteScanPlan;
+use crate::DeletePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
+use crate::DropResourceGroupPlan;
 use crate::DropTablePlan;
 use crate::EmptyPlan;
 use crate::ExplainPlan;
@@ -36,14 +44,19 @@ use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
+use crate::ReadFilesPlan;
 use crate::RemotePlan;
 use crate::ScanPlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
 use crate::ShowCreateTablePlan;
+use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
+use crate::TransactionPlan;
 use crate::TruncateTablePlan;
+use crate::UnionAllPlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 
 /// `PlanVisitor` implements visitor pattern(reference [syn](https://docs.rs/syn/1.0.72/syn/visit/trait.Visit.html)) for `PlanNode`.
@@ -98,8 +111,13 @@ pub trait PlanVisitor {
             PlanNode::Sort(plan) => self.visit_sort(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
+            PlanNode::ArrayJoin(plan) => self.visit_array_join(plan),
+            PlanNode::Sink(plan) => self.visit_sink(plan),
+            PlanNode::CteScan(plan) => self.visit_cte_scan(plan),
+            PlanNode::UnionAll(plan) => self.visit_union_all(plan),
             PlanNode::Scan(plan) => self.visit_scan(plan),
             PlanNode::ReadSource(plan) => self.visit_read_data_source(plan),
+            PlanNode::ReadFiles(plan) => self.visit_read_files(plan),
             PlanNode::Select(plan) => self.visit_select(plan),
             PlanNode::Explain(plan) => self.visit_explain(plan),
             PlanNode::CreateDatabase(plan) => self.visit_create_database(plan),
@@ -107,7 +125,11 @@ pub trait PlanVisitor {
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
+            PlanNode::CreateView(plan) => self.visit_create_view(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::SetVariable(plan) => self.visit_set_variable(plan),
             PlanNode::Stage(plan) => self.visit_stage(plan),
@@ -116,9 +138,13 @@ pub trait PlanVisitor {
             PlanNode::Having(plan) => self.visit_having(plan),
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::InsertInto(plan) => self.visit_insert_into(plan),
+            PlanNode::CopyInto(plan) => self.visit_copy_into(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
+            PlanNode::Transaction(plan) => self.visit_transaction(plan),
+            PlanNode::CreateResourceGroup(plan) => self.visit_create_resource_group(plan),
+            PlanNode::DropResourceGroup(plan) => self.visit_drop_resource_group(plan),
         }
     }
 
@@ -214,6 +240,26 @@ pub trait PlanVisitor {
         self.visit_plan_node(plan.input.as_ref())
     }
 
+    fn visit_array_join(&mut self, plan: &ArrayJoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())?;
+        self.visit_exprs(&plan.exprs)
+    }
+
+    fn visit_sink(&mut self, plan: &SinkPlan) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref())
+    }
+
+    fn visit_cte_scan(&mut self, _: &CteScanPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan) -> Result<()> {
+        for input in &plan.inputs {
+            self.visit_plan_node(input.as_ref())?;
+        }
+        Ok(())
+    }
+
     fn visit_scan(&mut self, _: &ScanPlan) -> Result<()> {
         Ok(())
     }
@@ -222,6 +268,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_read_files(&mut self, _: &ReadFilesPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_select(&mut self, plan: &SelectPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())
     }
@@ -250,6 +300,14 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_view(&mut self, _: &CreateViewPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
@@ -262,6 +320,10 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_copy_into(&mut self, _: &CopyIntoPlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_show_create_table(&mut self, _: &ShowCreateTablePlan) -> Result<()> {
         Ok(())
     }
@@ -270,7 +332,27 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_kill_query(&mut self, _: &KillPlan) -> Result<()> {
         Ok(())
     }
+
+    fn visit_transaction(&mut self, _: &TransactionPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_create_resource_group(&mut self, _: &CreateResourceGroupPlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_drop_resource_group(&mut self, _: &DropResourceGroupPlan) -> Result<()> {
+        Ok(())
+    }
 }
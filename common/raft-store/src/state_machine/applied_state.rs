@@ -19,6 +19,7 @@ use common_metatypes::KVValue;
 use common_metatypes::Node;
 use common_metatypes::SeqValue;
 use common_metatypes::Table;
+use common_metatypes::TableChangeError;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -67,6 +68,11 @@ pub enum AppliedState {
         result: Option<usize>,
     },
 
+    /// The changes of the tables are applied, unless there is an error.
+    TableChanges {
+        error: Option<TableChangeError>,
+    },
+
     None,
 }
 
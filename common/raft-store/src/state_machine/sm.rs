@@ -37,6 +37,8 @@ use common_metatypes::Operation;
 use common_metatypes::SeqValue;
 use common_metatypes::Slot;
 use common_metatypes::Table;
+use common_metatypes::TableChange;
+use common_metatypes::TableChangeError;
 use common_planners::Part;
 use common_planners::Statistics;
 use common_sled_store::get_sled_db;
@@ -517,6 +519,57 @@ impl StateMachine {
                 }
             }
 
+            Cmd::AlterTable {
+                ref db_name,
+                ref table_name,
+                ref new_db_name,
+                ref new_table_name,
+                ref schema,
+            } => {
+                let tbl_id = self
+                    .databases
+                    .get(db_name)
+                    .and_then(|db| db.tables.get(table_name))
+                    .cloned();
+                let tbl_id = match tbl_id {
+                    Some(tbl_id) => tbl_id,
+                    None => return Ok((None::<Table>, None::<Table>).into()),
+                };
+                let prev = self.tables.get(&tbl_id).cloned();
+
+                if db_name != new_db_name || table_name != new_table_name {
+                    // - If the new database is absent or the new name is taken, nothing changes.
+                    let renamable = match self.databases.get(new_db_name) {
+                        Some(db) => !db.tables.contains_key(new_table_name),
+                        None => false,
+                    };
+                    if !renamable {
+                        return Ok((prev, None).into());
+                    }
+
+                    if let Some(db) = self.databases.get_mut(db_name) {
+                        db.tables.remove(table_name);
+                    }
+                    if let Some(db) = self.databases.get_mut(new_db_name) {
+                        db.tables.insert(new_table_name.clone(), tbl_id);
+                    }
+                }
+
+                let mut table = prev.clone().unwrap_or_default();
+                table.schema = schema.clone();
+                self.tables.insert(tbl_id, table.clone());
+                self.incr_seq(SEQ_DATABASE_META_ID).await?;
+                tracing::debug!(
+                    "applied AlterTable: {}.{} to {}.{}",
+                    db_name,
+                    table_name,
+                    new_db_name,
+                    new_table_name
+                );
+
+                Ok((prev, Some(table)).into())
+            }
+
             Cmd::UpsertKV {
                 ref key,
                 ref seq,
@@ -591,7 +644,140 @@ impl StateMachine {
                     Ok((None::<usize>, None::<usize>).into())
                 }
             }
+
+            Cmd::UpdateTables { ref changes } => {
+                // all the changes are checked before any of them is applied
+                if let Err(error) = self.check_table_changes(changes) {
+                    return Ok(AppliedState::TableChanges { error: Some(error) });
+                }
+                for change in changes {
+                    self.apply_table_change(change).await?;
+                }
+                self.incr_seq(SEQ_DATABASE_META_ID).await?;
+                tracing::debug!("applied UpdateTables: {:?}", changes);
+
+                Ok(AppliedState::TableChanges { error: None })
+            }
+        }
+    }
+
+    /// Check that every change of the batch can be applied, after the previous ones.
+    fn check_table_changes(&self, changes: &[TableChange]) -> Result<(), TableChangeError> {
+        // the options of the tables if they exist, after the previous changes of the batch
+        let mut tables = HashMap::new();
+        for change in changes {
+            let (db_name, table_name) = change.table();
+            let db = self
+                .databases
+                .get(db_name)
+                .ok_or_else(|| TableChangeError::UnknownDatabase(db_name.to_string()))?;
+            let name = format!("{}.{}", db_name, table_name);
+            let table = tables.entry(name.clone()).or_insert_with(|| {
+                let table = db.tables.get(table_name).and_then(|id| self.tables.get(id));
+                table.map(|table| table.table_options.clone())
+            });
+
+            let exist = table.is_some();
+            match change {
+                TableChange::Create { .. } if exist => {
+                    return Err(TableChangeError::TableAlreadyExists(name));
+                }
+                TableChange::Create { table: created, .. } => {
+                    *table = Some(created.table_options.clone());
+                }
+                _ if !exist => return Err(TableChangeError::UnknownTable(name)),
+                TableChange::Drop { .. } => *table = None,
+                TableChange::SetOption { key, value, .. } => {
+                    if let Some(options) = table {
+                        match value {
+                            Some(value) => options.insert(key.clone(), value.clone()),
+                            None => options.remove(key),
+                        };
+                    }
+                }
+                TableChange::CompareAndSetOption {
+                    key,
+                    expected,
+                    value,
+                    ..
+                } => {
+                    if let Some(options) = table {
+                        if options.get(key) != expected.as_ref() {
+                            return Err(TableChangeError::Conflict(name));
+                        }
+                        options.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_table_change(&mut self, change: &TableChange) -> common_exception::Result<()> {
+        match change {
+            TableChange::Create {
+                db_name,
+                table_name,
+                table,
+            } => {
+                let table = Table {
+                    table_id: self.incr_seq(SEQ_TABLE_ID).await?,
+                    ..table.clone()
+                };
+                if let Some(db) = self.databases.get_mut(db_name) {
+                    db.tables.insert(table_name.clone(), table.table_id);
+                }
+                self.tables.insert(table.table_id, table);
+            }
+            TableChange::Drop {
+                db_name,
+                table_name,
+            } => {
+                self.remove_table_data_parts(db_name, table_name);
+                let tbl_id = self
+                    .databases
+                    .get_mut(db_name)
+                    .and_then(|db| db.tables.remove(table_name));
+                if let Some(tbl_id) = tbl_id {
+                    self.tables.remove(&tbl_id);
+                }
+            }
+            TableChange::SetOption {
+                db_name,
+                table_name,
+                key,
+                value,
+            } => {
+                let tbl_id = self
+                    .databases
+                    .get(db_name)
+                    .and_then(|db| db.tables.get(table_name))
+                    .copied();
+                if let Some(table) = tbl_id.and_then(|tbl_id| self.tables.get_mut(&tbl_id)) {
+                    match value {
+                        Some(value) => table.table_options.insert(key.clone(), value.clone()),
+                        None => table.table_options.remove(key),
+                    };
+                }
+            }
+            TableChange::CompareAndSetOption {
+                db_name,
+                table_name,
+                key,
+                value,
+                ..
+            } => {
+                let tbl_id = self
+                    .databases
+                    .get(db_name)
+                    .and_then(|db| db.tables.get(table_name))
+                    .copied();
+                if let Some(table) = tbl_id.and_then(|tbl_id| self.tables.get_mut(&tbl_id)) {
+                    table.table_options.insert(key.clone(), value.clone());
+                }
+            }
         }
+        Ok(())
     }
 
     /// Update a generic-kv record, without seq checking
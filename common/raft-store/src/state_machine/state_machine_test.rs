@@ -31,6 +31,8 @@ use common_metatypes::Node;
 use common_metatypes::Operation;
 use common_metatypes::SeqValue;
 use common_metatypes::Slot;
+use common_metatypes::TableChange;
+use common_metatypes::TableChangeError;
 use common_tracing::tracing;
 use maplit::btreeset;
 use pretty_assertions::assert_eq;
@@ -284,6 +286,228 @@ async fn test_state_machine_apply_add_database() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_alter_table() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let mut m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    for db_name in ["foo", "bar"] {
+        m.apply_cmd(&Cmd::CreateDatabase {
+            name: db_name.to_string(),
+            if_not_exists: true,
+            db: Default::default(),
+        })
+        .await?;
+    }
+    for table_name in ["t1", "t2"] {
+        m.apply_cmd(&Cmd::CreateTable {
+            db_name: "foo".to_string(),
+            table_name: table_name.to_string(),
+            if_not_exists: true,
+            table: Default::default(),
+        })
+        .await?;
+    }
+
+    let alter = |db_name: &str, table_name: &str, new_db_name: &str, new_table_name: &str| {
+        Cmd::AlterTable {
+            db_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            new_db_name: new_db_name.to_string(),
+            new_table_name: new_table_name.to_string(),
+            schema: vec![1, 2, 3],
+        }
+    };
+
+    // alter schema
+    let resp = m.apply_cmd(&alter("foo", "t1", "foo", "t1")).await?;
+    let tbl_id = match resp {
+        AppliedState::Table {
+            prev: Some(prev),
+            result: Some(result),
+        } => {
+            assert_eq!(prev.table_id, result.table_id);
+            assert_eq!(vec![1, 2, 3], result.schema);
+            result.table_id
+        }
+        _ => panic!("expect table result, got: {:?}", resp),
+    };
+
+    // the new name is taken
+    let resp = m.apply_cmd(&alter("foo", "t1", "foo", "t2")).await?;
+    assert!(matches!(resp, AppliedState::Table {
+        prev: Some(_),
+        result: None
+    }));
+
+    // the new database is absent
+    let resp = m.apply_cmd(&alter("foo", "t1", "wow", "t1")).await?;
+    assert!(matches!(resp, AppliedState::Table {
+        prev: Some(_),
+        result: None
+    }));
+
+    // move to another database
+    let resp = m.apply_cmd(&alter("foo", "t1", "bar", "t3")).await?;
+    assert!(matches!(resp, AppliedState::Table {
+        prev: Some(_),
+        result: Some(_)
+    }));
+    let foo = m.get_database("foo").unwrap();
+    let bar = m.get_database("bar").unwrap();
+    assert!(!foo.tables.contains_key("t1"));
+    assert_eq!(Some(&tbl_id), bar.tables.get("t3"));
+
+    // unknown table
+    let resp = m.apply_cmd(&alter("foo", "t1", "foo", "t4")).await?;
+    assert_eq!(
+        AppliedState::Table {
+            prev: None,
+            result: None
+        },
+        resp
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_state_machine_apply_update_tables() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+
+    let tc = new_raft_test_context();
+    let mut m = StateMachine::open(&tc.raft_config, 1).await?;
+
+    m.apply_cmd(&Cmd::CreateDatabase {
+        name: "foo".to_string(),
+        if_not_exists: true,
+        db: Default::default(),
+    })
+    .await?;
+    m.apply_cmd(&Cmd::CreateTable {
+        db_name: "foo".to_string(),
+        table_name: "t1".to_string(),
+        if_not_exists: true,
+        table: Default::default(),
+    })
+    .await?;
+
+    let create = |table_name: &str| TableChange::Create {
+        db_name: "foo".to_string(),
+        table_name: table_name.to_string(),
+        table: Default::default(),
+    };
+    let drop_table = |table_name: &str| TableChange::Drop {
+        db_name: "foo".to_string(),
+        table_name: table_name.to_string(),
+    };
+    let set_option = |table_name: &str, value: Option<&str>| TableChange::SetOption {
+        db_name: "foo".to_string(),
+        table_name: table_name.to_string(),
+        key: "k".to_string(),
+        value: value.map(|v| v.to_string()),
+    };
+    let compare_and_set =
+        |table_name: &str, expected: Option<&str>, value: &str| TableChange::CompareAndSetOption {
+            db_name: "foo".to_string(),
+            table_name: table_name.to_string(),
+            key: "k".to_string(),
+            expected: expected.map(|v| v.to_string()),
+            value: value.to_string(),
+        };
+    let option_of = |m: &StateMachine, table_name: &str| {
+        let tbl_id = m.get_database("foo").unwrap().tables[table_name];
+        m.tables[&tbl_id].table_options.get("k").cloned()
+    };
+
+    // all the changes are applied
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![
+                create("t2"),
+                set_option("t1", Some("v")),
+                set_option("t2", Some("w")),
+            ],
+        })
+        .await?;
+    assert_eq!(AppliedState::TableChanges { error: None }, resp);
+    assert_eq!(Some("v".to_string()), option_of(&m, "t1"));
+    assert_eq!(Some("w".to_string()), option_of(&m, "t2"));
+
+    // a change fails, none of them is applied
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![set_option("t1", None), drop_table("t2"), create("t1")],
+        })
+        .await?;
+    assert_eq!(
+        AppliedState::TableChanges {
+            error: Some(TableChangeError::TableAlreadyExists("foo.t1".to_string()))
+        },
+        resp
+    );
+    assert_eq!(Some("v".to_string()), option_of(&m, "t1"));
+    assert!(m.get_database("foo").unwrap().tables.contains_key("t2"));
+
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![drop_table("t2"), set_option("t2", None)],
+        })
+        .await?;
+    assert_eq!(
+        AppliedState::TableChanges {
+            error: Some(TableChangeError::UnknownTable("foo.t2".to_string()))
+        },
+        resp
+    );
+
+    // the changes see the previous changes of the batch
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![drop_table("t2"), create("t2"), set_option("t1", None)],
+        })
+        .await?;
+    assert_eq!(AppliedState::TableChanges { error: None }, resp);
+    assert_eq!(None, option_of(&m, "t1"));
+    assert_eq!(None, option_of(&m, "t2"));
+
+    // the options are set only if all of them have the expected values
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![
+                compare_and_set("t1", None, "a"),
+                compare_and_set("t2", None, "b"),
+            ],
+        })
+        .await?;
+    assert_eq!(AppliedState::TableChanges { error: None }, resp);
+    assert_eq!(Some("a".to_string()), option_of(&m, "t1"));
+    assert_eq!(Some("b".to_string()), option_of(&m, "t2"));
+
+    let resp = m
+        .apply_cmd(&Cmd::UpdateTables {
+            changes: vec![
+                compare_and_set("t1", Some("a"), "c"),
+                compare_and_set("t2", None, "d"),
+            ],
+        })
+        .await?;
+    assert_eq!(
+        AppliedState::TableChanges {
+            error: Some(TableChangeError::Conflict("foo.t2".to_string()))
+        },
+        resp
+    );
+    assert_eq!(Some("a".to_string()), option_of(&m, "t1"));
+    assert_eq!(Some("b".to_string()), option_of(&m, "t2"));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_state_machine_apply_non_dup_generic_kv_upsert_get() -> anyhow::Result<()> {
     let (_log_guards, ut_span) = init_raft_store_ut!();
@@ -18,6 +18,8 @@ use common_meta_api::MetaApi;
 use common_meta_api_vo::*;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_metatypes::TableChange;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
@@ -67,6 +69,22 @@ impl MetaApi for StoreClient {
         self.do_action(DropTableAction { plan }).await
     }
 
+    /// Alter table call.
+    async fn alter_table(
+        &self,
+        plan: AlterTablePlan,
+    ) -> common_exception::Result<AlterTableActionResult> {
+        self.do_action(AlterTableAction { plan }).await
+    }
+
+    /// Update tables call.
+    async fn update_tables(
+        &self,
+        changes: Vec<TableChange>,
+    ) -> common_exception::Result<UpdateTablesActionResult> {
+        self.do_action(UpdateTablesAction { changes }).await
+    }
+
     /// Get table.
     async fn get_table(
         &self,
@@ -158,6 +176,28 @@ action_declare!(
     StoreDoAction::DropTable
 );
 
+// - alter table
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct AlterTableAction {
+    pub plan: AlterTablePlan,
+}
+action_declare!(
+    AlterTableAction,
+    AlterTableActionResult,
+    StoreDoAction::AlterTable
+);
+
+// - update tables
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct UpdateTablesAction {
+    pub changes: Vec<TableChange>,
+}
+action_declare!(
+    UpdateTablesAction,
+    UpdateTablesActionResult,
+    StoreDoAction::UpdateTables
+);
+
 // - get table
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct GetTableAction {
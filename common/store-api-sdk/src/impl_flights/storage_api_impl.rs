@@ -87,7 +87,8 @@ impl StorageApi for StoreClient {
         read_action: &ReadAction,
     ) -> common_exception::Result<SendableDataBlockStream> {
         let cmd = StoreDoGet::Read(read_action.clone());
-        let mut req = tonic::Request::<Ticket>::from(&cmd);
+        let req = tonic::Request::<Ticket>::from(&cmd);
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
         req.set_timeout(self.timeout);
         let res = self.client.clone().do_get(req).await?.into_inner();
         let mut arrow_schema: ArrowSchemaRef = Arc::new(schema.to_arrow());
@@ -149,7 +150,7 @@ impl StorageApi for StoreClient {
             }
         });
 
-        let mut req = Request::new(flight_stream);
+        let mut req = common_tracing::inject_span_to_tonic_request(Request::new(flight_stream));
         let meta = req.metadata_mut();
         storage_api_impl_utils::put_meta(meta, &db_name, &tbl_name);
 
@@ -126,12 +126,13 @@ impl StoreClient {
         let mut payload = vec![];
         auth.encode(&mut payload)?;
 
-        let mut req = Request::new(stream::once(async {
+        let req = Request::new(stream::once(async {
             HandshakeRequest {
                 payload,
                 ..HandshakeRequest::default()
             }
         }));
+        let mut req = common_tracing::inject_span_to_tonic_request(req);
         req.set_timeout(timeout);
 
         let rx = client.handshake(req).await?;
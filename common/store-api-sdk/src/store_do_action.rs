@@ -25,6 +25,7 @@ use crate::impl_flights::kv_api_impl::KVMetaAction;
 use crate::impl_flights::kv_api_impl::MGetKVAction;
 use crate::impl_flights::kv_api_impl::PrefixListReq;
 use crate::impl_flights::kv_api_impl::UpsertKVAction;
+use crate::impl_flights::meta_api_impl::AlterTableAction;
 use crate::impl_flights::meta_api_impl::CreateDatabaseAction;
 use crate::impl_flights::meta_api_impl::CreateTableAction;
 use crate::impl_flights::meta_api_impl::DropDatabaseAction;
@@ -32,6 +33,7 @@ use crate::impl_flights::meta_api_impl::DropTableAction;
 use crate::impl_flights::meta_api_impl::GetDatabaseAction;
 use crate::impl_flights::meta_api_impl::GetDatabaseMetaAction;
 use crate::impl_flights::meta_api_impl::GetTableAction;
+use crate::impl_flights::meta_api_impl::UpdateTablesAction;
 use crate::impl_flights::storage_api_impl::ReadPlanAction;
 use crate::impl_flights::storage_api_impl::TruncateTableAction;
 use crate::meta_api_impl::GetTableExtReq;
@@ -65,6 +67,8 @@ pub enum StoreDoAction {
     DropDatabase(DropDatabaseAction),
     CreateTable(CreateTableAction),
     DropTable(DropTableAction),
+    AlterTable(AlterTableAction),
+    UpdateTables(UpdateTablesAction),
     GetTable(GetTableAction),
     GetTableExt(GetTableExtReq),
     GetDatabaseMeta(GetDatabaseMetaAction),
@@ -14,6 +14,10 @@
 
 mod source;
 mod source_csv;
+mod source_factory;
+mod source_json_each_row;
+mod source_parquet;
+mod source_tsv;
 mod source_values;
 
 #[cfg(test)]
@@ -22,4 +26,8 @@ mod source_test;
 pub use source::FormatSettings;
 pub use source::Source;
 pub use source_csv::CsvSource;
+pub use source_factory::SourceFactory;
+pub use source_json_each_row::JsonEachRowSource;
+pub use source_parquet::ParquetSource;
+pub use source_tsv::TsvSource;
 pub use source_values::ValueSource;
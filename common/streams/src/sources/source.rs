@@ -19,8 +19,22 @@ pub trait Source: Sync + Send {
     fn read(&mut self) -> Result<Option<DataBlock>>;
 }
 
-#[allow(dead_code)]
+/// The options of the text formats, a field equal to `null_bytes` is read as NULL.
+#[derive(Debug, Clone)]
 pub struct FormatSettings {
-    delimiter: u8,
-    quote: u8,
+    pub field_delimiter: u8,
+    pub quote: u8,
+    pub skip_header: bool,
+    pub null_bytes: Vec<u8>,
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        FormatSettings {
+            field_delimiter: b',',
+            quote: b'"',
+            skip_header: false,
+            null_bytes: b"\\N".to_vec(),
+        }
+    }
 }
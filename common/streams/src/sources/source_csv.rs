@@ -23,11 +23,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 
+use crate::FormatSettings;
 use crate::Source;
 
 pub struct CsvSource<R> {
     reader: Reader<R>,
     schema: DataSchemaRef,
+    null_bytes: Vec<u8>,
     block_size: usize,
     rows: usize,
 }
@@ -36,12 +38,26 @@ impl<R> CsvSource<R>
 where R: io::Read + Sync + Send
 {
     pub fn new(reader: R, schema: DataSchemaRef, block_size: usize) -> Self {
-        let reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
+        Self::with_settings(reader, schema, FormatSettings::default(), block_size)
+    }
+
+    pub fn with_settings(
+        reader: R,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+        block_size: usize,
+    ) -> Self {
+        let reader = ReaderBuilder::new()
+            .has_headers(settings.skip_header)
+            .delimiter(settings.field_delimiter)
+            .quote(settings.quote)
+            .from_reader(reader);
 
         Self {
             reader,
             block_size,
             schema,
+            null_bytes: settings.null_bytes,
             rows: 0,
         }
     }
@@ -64,7 +80,7 @@ where R: io::Read + Sync + Send
                 .reader
                 .read_byte_record(&mut record)
                 .map_err_to_code(ErrorCode::BadBytes, || {
-                    format!("Parse csv error at line {}", self.rows)
+                    format!("Parse csv error at row {}", self.rows + 1)
                 })?;
 
             if !v {
@@ -73,13 +89,22 @@ where R: io::Read + Sync + Send
                 }
                 break;
             }
-            desers
-                .iter_mut()
-                .enumerate()
-                .for_each(|(col, deser)| match record.get(col) {
-                    Some(bytes) => deser.de_text(bytes).unwrap(),
-                    None => deser.de_null(),
-                });
+
+            for (col, deser) in desers.iter_mut().enumerate() {
+                match record.get(col) {
+                    Some(bytes) if bytes != self.null_bytes.as_slice() => {
+                        deser.de_text(bytes).map_err(|cause| {
+                            ErrorCode::BadBytes(format!(
+                                "Parse csv error at row {}, column {}: {}",
+                                self.rows + 1,
+                                col + 1,
+                                cause.message()
+                            ))
+                        })?
+                    }
+                    _ => deser.de_null(),
+                }
+            }
 
             self.rows += 1;
         }
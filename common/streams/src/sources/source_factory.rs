@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::CsvSource;
+use crate::FormatSettings;
+use crate::JsonEachRowSource;
+use crate::ParquetSource;
+use crate::Source;
+use crate::TsvSource;
+use crate::ValueSource;
+
+pub struct SourceFactory;
+
+impl SourceFactory {
+    /// Check the format name of `INSERT INTO ... FORMAT`, case insensitive.
+    pub fn check_format(format: &str) -> Result<()> {
+        match format.to_lowercase().as_str() {
+            "csv" | "tsv" | "tabseparated" | "jsoneachrow" | "parquet" | "values" => Ok(()),
+            _ => Err(ErrorCode::UnknownFormat(format!(
+                "Unknown format: {}, expect one of CSV, TSV, JSONEachRow, Parquet, Values",
+                format
+            ))),
+        }
+    }
+
+    pub fn try_get<R>(
+        format: &str,
+        reader: R,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+        block_size: usize,
+    ) -> Result<Box<dyn Source>>
+    where
+        R: io::Read + Send + Sync + 'static,
+    {
+        Self::check_format(format)?;
+
+        Ok(match format.to_lowercase().as_str() {
+            "csv" => Box::new(CsvSource::with_settings(
+                reader, schema, settings, block_size,
+            )),
+            "tsv" | "tabseparated" => Box::new(TsvSource::with_settings(
+                reader, schema, settings, block_size,
+            )),
+            "jsoneachrow" => Box::new(JsonEachRowSource::new(reader, schema, block_size)),
+            "parquet" => Box::new(ParquetSource::new(reader, schema)),
+            _ => Box::new(ValueSource::new(reader, schema, block_size)),
+        })
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde_json::Value as JsonValue;
+
+use crate::Source;
+
+/// One JSON object per line, the columns are matched by name and a missing key is NULL.
+pub struct JsonEachRowSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    rows: usize,
+}
+
+impl<R> JsonEachRowSource<R>
+where R: io::Read + Send + Sync
+{
+    pub fn new(reader: R, schema: DataSchemaRef, block_size: usize) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            schema,
+            block_size,
+            rows: 0,
+        }
+    }
+}
+
+impl<R> Source for JsonEachRowSource<R>
+where R: io::Read + Send + Sync
+{
+    fn read(&mut self) -> Result<Option<DataBlock>> {
+        let mut line = Vec::new();
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_serializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        while rows < self.block_size {
+            line.clear();
+            if self.reader.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+
+            let row = self.rows + rows + 1;
+            let object = match serde_json::from_slice::<JsonValue>(&line) {
+                Ok(JsonValue::Object(object)) => object,
+                Ok(other) => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Parse JSONEachRow error at row {}: expected an object, but found {}",
+                        row, other
+                    )))
+                }
+                Err(cause) => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Parse JSONEachRow error at row {}: {}",
+                        row, cause
+                    )))
+                }
+            };
+
+            for (field, deser) in self.schema.fields().iter().zip(desers.iter_mut()) {
+                let res = match object.get(field.name()) {
+                    None | Some(JsonValue::Null) => {
+                        deser.de_null();
+                        Ok(())
+                    }
+                    Some(JsonValue::String(v)) => deser.de_text(v.as_bytes()),
+                    Some(other) => deser.de_text(other.to_string().as_bytes()),
+                };
+                res.map_err(|cause| {
+                    ErrorCode::BadBytes(format!(
+                        "Parse JSONEachRow error at row {}, column {}: {}",
+                        row,
+                        field.name(),
+                        cause.message()
+                    ))
+                })?;
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+        self.rows += rows;
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
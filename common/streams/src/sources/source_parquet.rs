@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read;
+use common_arrow::parquet::metadata::RowGroupMetaData;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Source;
+
+/// The metadata of a parquet file is at its end, so the data is spooled to a temporary file
+/// before the first block is read, then the row groups are read one by one. The columns are
+/// matched by name and cast to the schema types.
+pub struct ParquetSource<R> {
+    reader: Option<R>,
+    schema: DataSchemaRef,
+    file: Option<File>,
+    row_groups: usize,
+    next_row_group: usize,
+    blocks: VecDeque<DataBlock>,
+}
+
+impl<R> ParquetSource<R>
+where R: io::Read + Send + Sync
+{
+    pub fn new(reader: R, schema: DataSchemaRef) -> Self {
+        Self {
+            reader: Some(reader),
+            schema,
+            file: None,
+            row_groups: 0,
+            next_row_group: 0,
+            blocks: VecDeque::new(),
+        }
+    }
+
+    fn spool(&mut self, mut reader: R) -> Result<()> {
+        let mut file = tempfile::tempfile()?;
+        io::copy(&mut reader, &mut file)?;
+
+        let metadata =
+            read::read_metadata(&mut file).map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        self.row_groups = metadata.row_groups.len();
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn read_row_group(&mut self, file: &File) -> Result<()> {
+        let row_group = self.next_row_group;
+        self.next_row_group += 1;
+
+        let filter = Arc::new(move |index: usize, _: &RowGroupMetaData| index == row_group);
+        let batches =
+            read::RecordReader::try_new(file.try_clone()?, None, None, Some(filter), None)?;
+        for batch in batches {
+            let block = DataBlock::try_from(batch?)?;
+            let columns = self
+                .schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    block
+                        .try_column_by_name(field.name())?
+                        .cast_with_type(field.data_type())
+                })
+                .collect::<Result<Vec<_>>>()
+                .map_err(|cause| {
+                    ErrorCode::BadBytes(format!(
+                        "Parse parquet error at row group {}: {}",
+                        row_group + 1,
+                        cause.message()
+                    ))
+                })?;
+            self.blocks
+                .push_back(DataBlock::create(self.schema.clone(), columns));
+        }
+        Ok(())
+    }
+}
+
+impl<R> Source for ParquetSource<R>
+where R: io::Read + Send + Sync
+{
+    fn read(&mut self) -> Result<Option<DataBlock>> {
+        if let Some(reader) = self.reader.take() {
+            self.spool(reader)?;
+        }
+
+        while self.blocks.is_empty() && self.next_row_group < self.row_groups {
+            let file = match self.file.take() {
+                Some(file) => file,
+                None => break,
+            };
+            let result = self.read_row_group(&file);
+            self.file = Some(file);
+            result?;
+        }
+        Ok(self.blocks.pop_front())
+    }
+}
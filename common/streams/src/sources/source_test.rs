@@ -12,13 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::convert::TryFrom;
+
+use common_arrow::arrow::io::parquet::write::*;
+use common_arrow::arrow::record_batch::RecordBatch;
 use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
 
 use crate::CsvSource;
+use crate::FormatSettings;
+use crate::JsonEachRowSource;
+use crate::ParquetSource;
 use crate::Source;
+use crate::SourceFactory;
+use crate::TsvSource;
 use crate::ValueSource;
 
 #[test]
@@ -78,3 +91,185 @@ fn test_parse_csvs() {
     let block = values_source.read().unwrap();
     assert!(block.is_none());
 }
+
+#[test]
+fn test_parse_csvs_with_settings() {
+    let buffer = "a|b|c\n1|\\N|1.11\n2|'2|2'|2\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, false),
+    ]);
+    let settings = FormatSettings {
+        field_delimiter: b'|',
+        quote: b'\'',
+        skip_header: true,
+        ..Default::default()
+    };
+    let mut csv_source = CsvSource::with_settings(buffer.as_bytes(), schema, settings, 10);
+    let block = csv_source.read().unwrap().unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+------+",
+            "| a | b    | c    |",
+            "+---+------+------+",
+            "| 1 | NULL | 1.11 |",
+            "| 2 | 2|2  | 2    |",
+            "+---+------+------+",
+        ],
+        &[block],
+    );
+
+    let block = csv_source.read().unwrap();
+    assert!(block.is_none());
+}
+
+#[test]
+fn test_parse_csvs_bad_row() {
+    let buffer = "1,1\n2,x\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::Int8, false),
+    ]);
+    let mut csv_source = CsvSource::new(buffer.as_bytes(), schema, 10);
+    let result = csv_source.read();
+    assert!(result.is_err());
+    let message = result.unwrap_err().message();
+    assert!(message.starts_with("Parse csv error at row 2, column 2"));
+}
+
+#[test]
+fn test_parse_tsvs() {
+    let buffer = "1\ta\\tb\t1.1\n2\t\\N\t2\r\n\n3\tc\\\\d\t3\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, false),
+    ]);
+    let mut tsv_source =
+        TsvSource::with_settings(buffer.as_bytes(), schema, FormatSettings::default(), 10);
+    let block = tsv_source.read().unwrap().unwrap();
+    assert_eq!(block.num_rows(), 3);
+    assert_eq!(
+        block.column(1).try_get(0).unwrap(),
+        DataValue::String(Some(b"a\tb".to_vec()))
+    );
+    assert_eq!(block.column(1).try_get(1).unwrap(), DataValue::String(None));
+    assert_eq!(
+        block.column(1).try_get(2).unwrap(),
+        DataValue::String(Some(b"c\\d".to_vec()))
+    );
+
+    let block = tsv_source.read().unwrap();
+    assert!(block.is_none());
+}
+
+#[test]
+fn test_parse_json_each_row() {
+    let buffer = "{\"a\": 1, \"b\": \"x\", \"c\": 1.5}\n\n{\"c\": 2, \"a\": 2}\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, false),
+    ]);
+    let mut json_source = JsonEachRowSource::new(buffer.as_bytes(), schema.clone(), 10);
+    let block = json_source.read().unwrap().unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+------+-----+",
+            "| a | b    | c   |",
+            "+---+------+-----+",
+            "| 1 | x    | 1.5 |",
+            "| 2 | NULL | 2   |",
+            "+---+------+-----+",
+        ],
+        &[block],
+    );
+
+    let block = json_source.read().unwrap();
+    assert!(block.is_none());
+
+    let mut json_source = JsonEachRowSource::new("{\"a\": 1}\n[1]\n".as_bytes(), schema, 10);
+    let result = json_source.read();
+    assert!(result.is_err());
+    let message = result.unwrap_err().message();
+    assert!(message.starts_with("Parse JSONEachRow error at row 2"));
+}
+
+#[test]
+fn test_parse_parquet() {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int64, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+
+    // two row groups
+    let batches = vec![
+        DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![1i64, 2]),
+            Series::new(vec!["x", "y"]),
+        ]),
+        DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![3i64]),
+            Series::new(vec!["z"]),
+        ]),
+    ]
+    .into_iter()
+    .map(|block| Ok(RecordBatch::try_from(block).unwrap()));
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let row_groups = RowGroupIterator::try_new(batches, &schema.to_arrow(), options, vec![
+        Encoding::Plain,
+        Encoding::Plain,
+    ])
+    .unwrap();
+    let parquet_schema = row_groups.parquet_schema().clone();
+    let mut buffer = vec![];
+    common_arrow::parquet::write::write_file(
+        &mut buffer,
+        row_groups,
+        parquet_schema,
+        options,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // the columns are matched by name and cast
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("b", DataType::String, false),
+        DataField::new("a", DataType::UInt8, false),
+    ]);
+    let mut source = ParquetSource::new(buffer.as_slice(), schema);
+    let first = source.read().unwrap().unwrap();
+    let second = source.read().unwrap().unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+---+",
+            "| b | a |",
+            "+---+---+",
+            "| x | 1 |",
+            "| y | 2 |",
+            "| z | 3 |",
+            "+---+---+",
+        ],
+        &[first, second],
+    );
+    assert!(source.read().unwrap().is_none());
+}
+
+#[test]
+fn test_source_factory_unknown_format() {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int8, false)]);
+    let result =
+        SourceFactory::try_get("xml", "".as_bytes(), schema, FormatSettings::default(), 10);
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().code(), 56);
+}
@@ -0,0 +1,163 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::FormatSettings;
+use crate::Source;
+
+/// Tab separated values, one row per line. The fields are not quoted, tab, newline and
+/// backslash in a field are escaped as `\t`, `\n` and `\\`.
+pub struct TsvSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    settings: FormatSettings,
+    block_size: usize,
+    rows: usize,
+    header_skipped: bool,
+}
+
+impl<R> TsvSource<R>
+where R: io::Read + Send + Sync
+{
+    pub fn with_settings(
+        reader: R,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+        block_size: usize,
+    ) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            schema,
+            header_skipped: !settings.skip_header,
+            settings,
+            block_size,
+            rows: 0,
+        }
+    }
+
+    fn read_line(&mut self, line: &mut Vec<u8>) -> Result<bool> {
+        line.clear();
+        if self.reader.read_until(b'\n', line)? == 0 {
+            return Ok(false);
+        }
+
+        if line.ends_with(b"\n") {
+            line.pop();
+        }
+        if line.ends_with(b"\r") {
+            line.pop();
+        }
+        Ok(true)
+    }
+
+    fn unescape(field: &[u8], buf: &mut Vec<u8>) {
+        buf.clear();
+        let mut bytes = field.iter();
+        while let Some(byte) = bytes.next() {
+            match (*byte, bytes.as_slice().first()) {
+                (b'\\', Some(escaped)) => {
+                    buf.push(match escaped {
+                        b't' => b'\t',
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b'0' => b'\0',
+                        other => *other,
+                    });
+                    bytes.next();
+                }
+                (byte, _) => buf.push(byte),
+            }
+        }
+    }
+}
+
+impl<R> Source for TsvSource<R>
+where R: io::Read + Send + Sync
+{
+    fn read(&mut self) -> Result<Option<DataBlock>> {
+        let mut line = Vec::new();
+        let mut field = Vec::new();
+        if !self.header_skipped {
+            self.read_line(&mut line)?;
+            self.header_skipped = true;
+        }
+
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_serializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        while rows < self.block_size && self.read_line(&mut line)? {
+            // skip the empty lines, a table with one String column takes them as empty strings
+            if line.is_empty() && desers.len() != 1 {
+                continue;
+            }
+
+            let row = self.rows + rows + 1;
+            let fields = line.split(|b| *b == b'\t').collect::<Vec<_>>();
+            if fields.len() != desers.len() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Parse tsv error at row {}: expected {} columns, but found {}",
+                    row,
+                    desers.len(),
+                    fields.len()
+                )));
+            }
+
+            for (col, (deser, bytes)) in desers.iter_mut().zip(fields.iter()).enumerate() {
+                if *bytes == self.settings.null_bytes.as_slice() {
+                    deser.de_null();
+                    continue;
+                }
+
+                Self::unescape(bytes, &mut field);
+                deser.de_text(&field).map_err(|cause| {
+                    ErrorCode::BadBytes(format!(
+                        "Parse tsv error at row {}, column {}: {}",
+                        row,
+                        col + 1,
+                        cause.message()
+                    ))
+                })?;
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+        self.rows += rows;
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod query_logs_test;
+#[cfg(test)]
+mod tracing_to_otlp_test;
+
 mod logging;
 mod panic_hook;
+mod query_logs;
 mod tracing_to_jaeger;
+mod tracing_to_otlp;
 
 pub use logging::init_default_tracing;
 pub use logging::init_default_ut_tracing;
@@ -22,9 +29,14 @@ pub use logging::init_global_tracing;
 pub use logging::init_tracing;
 pub use logging::init_tracing_with_file;
 pub use panic_hook::set_panic_hook;
+pub use query_logs::QueryLogEvent;
+pub use query_logs::QueryLogLayer;
+pub use query_logs::QueryLogs;
+pub use query_logs::QUERY_ID_FIELD;
 pub use tracing;
 pub use tracing_to_jaeger::extract_remote_span_as_parent;
 pub use tracing_to_jaeger::inject_span_to_tonic_request;
+pub use tracing_to_otlp::new_otlp_tracer;
 
 #[macro_export]
 macro_rules! func_name {
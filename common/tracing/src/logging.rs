@@ -34,6 +34,8 @@ use tracing_subscriber::prelude::*;
 use tracing_subscriber::registry::Registry;
 use tracing_subscriber::EnvFilter;
 
+use crate::new_otlp_tracer;
+use crate::query_logs::QueryLogLayer;
 use crate::tracing::subscriber::DefaultGuard;
 
 /// Write logs to stdout.
@@ -72,6 +74,10 @@ lazy_static! {
 /// To adjust batch sending delay, use `OTEL_BSP_SCHEDULE_DELAY`:
 ///   DATABEND_JAEGER=on RUST_LOG=trace OTEL_BSP_SCHEDULE_DELAY=1 cargo test
 ///
+/// To export tracing data to an OTLP collector over gRPC, set env var `DATABEND_OTLP_ENDPOINT`
+/// to the address of the collector, e.g.:
+///   DATABEND_OTLP_ENDPOINT=http://127.0.0.1:4317 RUST_LOG=trace cargo test
+///
 // TODO(xp): use DATABEND_JAEGER to assign jaeger server address.
 fn init_tracing_stdout() {
     let fmt_layer = Layer::default()
@@ -84,7 +90,9 @@ fn init_tracing_stdout() {
     let subscriber = Registry::default()
         .with(EnvFilter::from_default_env())
         .with(fmt_layer)
-        .with(jaeger_layer());
+        .with(QueryLogLayer)
+        .with(jaeger_layer())
+        .with(otlp_layer("databend"));
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("error setting global tracing subscriber");
@@ -110,6 +118,26 @@ fn jaeger_layer<
     }
 }
 
+fn otlp_layer<
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+>(
+    service_name: &str,
+) -> Option<impl tracing_subscriber::layer::Layer<S>> {
+    let endpoint = env::var("DATABEND_OTLP_ENDPOINT").unwrap_or_else(|_| "".to_string());
+
+    if !endpoint.is_empty() {
+        // Spans are propagated to the other nodes with the W3C trace context.
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let tracer = new_otlp_tracer(&endpoint, service_name).expect("install");
+
+        let ot_layer = tracing_opentelemetry::layer().with_tracer(tracer);
+        Some(ot_layer)
+    } else {
+        None
+    }
+}
+
 /// Write logs to file and rotation by HOUR.
 pub fn init_tracing_with_file(app_name: &str, dir: &str, level: &str) -> Vec<WorkerGuard> {
     let mut guards = vec![];
@@ -128,7 +156,9 @@ pub fn init_tracing_with_file(app_name: &str, dir: &str, level: &str) -> Vec<Wor
         .with(stdout_logging_layer)
         .with(JsonStorageLayer)
         .with(file_logging_layer)
-        .with(jaeger_layer());
+        .with(QueryLogLayer)
+        .with(jaeger_layer())
+        .with(otlp_layer(app_name));
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("error setting global tracing subscriber");
@@ -160,7 +190,8 @@ pub fn init_global_tracing(app_name: &str, dir: &str) -> WorkerGuard {
 /// Create a file based tracing/logging subscriber.
 /// A guard must be held during using the logging.
 /// The format layer logging span/event in plain text, without color, one event per line.
-/// Optionally it adds a layer to send to opentelemetry if env var `DATABEND_JAEGER` is present.
+/// Optionally it adds a layer to send to opentelemetry if env var `DATABEND_JAEGER` or
+/// `DATABEND_OTLP_ENDPOINT` is present.
 pub fn init_file_subscriber(app_name: &str, dir: &str) -> (WorkerGuard, impl Subscriber) {
     let path_str = dir.to_string() + "/" + app_name;
     let path: &Path = path_str.as_ref();
@@ -194,7 +225,9 @@ pub fn init_file_subscriber(app_name: &str, dir: &str) -> (WorkerGuard, impl Sub
     let subscriber = Registry::default()
         .with(EnvFilter::from_default_env())
         .with(f_layer)
-        .with(jaeger_layer());
+        .with(QueryLogLayer)
+        .with(jaeger_layer())
+        .with(otlp_layer(app_name));
 
     (writer_guard, subscriber)
 }
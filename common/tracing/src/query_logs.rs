@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use lazy_static::lazy_static;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::Event;
+use tracing::Id;
+use tracing::Level;
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// The span field which marks the events inside the span as the events of a query.
+pub const QUERY_ID_FIELD: &str = "query_id";
+
+/// An event emitted during a query, see [`QueryLogs`].
+#[derive(Clone, Debug)]
+pub struct QueryLogEvent {
+    pub time: SystemTime,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    pub thread_id: u64,
+}
+
+struct QueryLogSink {
+    level: Level,
+    events: Arc<Mutex<Vec<QueryLogEvent>>>,
+}
+
+lazy_static! {
+    static ref QUERY_LOG_SINKS: RwLock<HashMap<String, QueryLogSink>> = RwLock::new(HashMap::new());
+    static ref NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+thread_local! {
+    static THREAD_ID: u64 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Collects the events of a query until it is dropped, e.g. to send them back to the client.
+///
+/// An event belongs to the query if it is emitted inside a span whose `query_id` field is the
+/// subscribed query id. Events more verbose than `level` are dropped, as well as the events
+/// filtered out by the global subscriber.
+pub struct QueryLogs {
+    query_id: String,
+    events: Arc<Mutex<Vec<QueryLogEvent>>>,
+}
+
+impl QueryLogs {
+    pub fn subscribe(query_id: impl Into<String>, level: Level) -> QueryLogs {
+        let query_id = query_id.into();
+        let events = Arc::new(Mutex::new(vec![]));
+        let sink = QueryLogSink {
+            level,
+            events: events.clone(),
+        };
+
+        QUERY_LOG_SINKS
+            .write()
+            .unwrap()
+            .insert(query_id.clone(), sink);
+        QueryLogs { query_id, events }
+    }
+
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+
+    /// Takes the events collected since the last call.
+    pub fn take(&self) -> Vec<QueryLogEvent> {
+        std::mem::take(&mut *self.events.lock().unwrap())
+    }
+}
+
+impl Drop for QueryLogs {
+    fn drop(&mut self) {
+        QUERY_LOG_SINKS.write().unwrap().remove(&self.query_id);
+    }
+}
+
+/// The layer dispatching the events to [`QueryLogs`].
+pub struct QueryLogLayer;
+
+struct QueryId(String);
+
+impl<S> Layer<S> for QueryLogLayer
+where S: Subscriber + for<'span> LookupSpan<'span>
+{
+    fn new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = QueryIdVisitor(None);
+        attrs.record(&mut visitor);
+
+        if let (Some(query_id), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(QueryId(query_id));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let sinks = QUERY_LOG_SINKS.read().unwrap();
+        if sinks.is_empty() {
+            return;
+        }
+
+        let mut current = ctx.lookup_current();
+        while let Some(span) = current {
+            let query_id = span.extensions().get::<QueryId>().map(|id| id.0.clone());
+
+            if let Some(query_id) = query_id {
+                let level = event.metadata().level();
+                if let Some(sink) = sinks.get(&query_id).filter(|sink| *level <= sink.level) {
+                    let mut visitor = MessageVisitor(String::new());
+                    event.record(&mut visitor);
+
+                    sink.events.lock().unwrap().push(QueryLogEvent {
+                        time: SystemTime::now(),
+                        level: *level,
+                        target: event.metadata().target().to_string(),
+                        message: visitor.0,
+                        thread_id: THREAD_ID.with(|id| *id),
+                    });
+                }
+                return;
+            }
+
+            current = span.parent();
+        }
+    }
+}
+
+struct QueryIdVisitor(Option<String>);
+
+impl Visit for QueryIdVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == QUERY_ID_FIELD {
+            self.0 = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == QUERY_ID_FIELD {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
+
+// Formats the message followed by the other fields as `name=value`.
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+
+        let _ = match field.name() {
+            "message" => write!(self.0, "{:?}", value),
+            name => write!(self.0, "{}={:?}", name, value),
+        };
+    }
+}
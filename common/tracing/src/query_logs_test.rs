@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use tracing::Level;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::Registry;
+
+use crate::QueryLogLayer;
+use crate::QueryLogs;
+
+#[test]
+fn test_query_logs() {
+    let subscriber = Registry::default().with(QueryLogLayer);
+
+    tracing::subscriber::with_default(subscriber, || {
+        let logs = QueryLogs::subscribe("query-1", Level::INFO);
+
+        tracing::info!("before the query");
+        let span = tracing::info_span!("query", query_id = "query-1");
+        {
+            let _entered = span.enter();
+            let inner = tracing::info_span!("interpreter");
+            let _inner_entered = inner.enter();
+            tracing::info!(rows = 3, "read rows");
+            tracing::debug!("filtered by level");
+        }
+
+        let other = tracing::info_span!("query", query_id = "query-2");
+        other.in_scope(|| tracing::warn!("other query"));
+
+        let events = logs.take();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].level, Level::INFO);
+        assert_eq!(events[0].message, "read rows rows=3");
+        assert!(logs.take().is_empty());
+
+        drop(logs);
+        span.in_scope(|| tracing::info!("after unsubscribe"));
+    });
+}
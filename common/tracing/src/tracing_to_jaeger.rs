@@ -58,7 +58,8 @@ impl<'a> Extractor for MetadataMapExtractor<'a> {
 ///
 /// A tonic client should call this function just before sending out the request.
 ///
-/// The global propagater must be installed, e.g. by calling: TODO
+/// The global propagater must be installed, e.g. by setting env var `DATABEND_JAEGER` or
+/// `DATABEND_OTLP_ENDPOINT` before initializing the tracing.
 pub fn inject_span_to_tonic_request<T>(mes: impl tonic::IntoRequest<T>) -> tonic::Request<T> {
     let curr = tracing::Span::current();
     let cx = curr.context();
@@ -78,7 +79,8 @@ pub fn inject_span_to_tonic_request<T>(mes: impl tonic::IntoRequest<T>) -> tonic
 ///
 /// A tonic request handler should call this before doing anything else.
 ///
-/// The global propagater must be installed, e.g. by calling: TODO
+/// The global propagater must be installed, e.g. by setting env var `DATABEND_JAEGER` or
+/// `DATABEND_OTLP_ENDPOINT` before initializing the tracing.
 pub fn extract_remote_span_as_parent<T>(request: &tonic::Request<T>) {
     let parent_cx = global::get_text_map_propagator(|prop| {
         prop.extract(&MetadataMapExtractor(request.metadata()))
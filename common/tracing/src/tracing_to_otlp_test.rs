@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+
+use bytes::Buf;
+use bytes::BufMut;
+use opentelemetry::global;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::trace::TraceContextExt;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::body::BoxBody;
+use tonic::codec::Codec;
+use tonic::codec::DecodeBuf;
+use tonic::codec::Decoder;
+use tonic::codec::EncodeBuf;
+use tonic::codec::Encoder;
+use tonic::codegen::http;
+use tonic::codegen::Body;
+use tonic::codegen::BoxFuture;
+use tonic::codegen::Service;
+use tonic::codegen::StdError;
+use tonic::server::Grpc;
+use tonic::server::UnaryService;
+use tonic::transport::NamedService;
+use tonic::Status;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::Registry;
+
+use crate::extract_remote_span_as_parent;
+use crate::inject_span_to_tonic_request;
+use crate::new_otlp_tracer;
+
+/// Passes the protobuf messages through as bytes.
+#[derive(Clone, Default)]
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        RawCodec
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        RawCodec
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut EncodeBuf<'_>) -> Result<(), Status> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Vec<u8>>, Status> {
+        let mut item = vec![0; src.remaining()];
+        src.copy_to_slice(&mut item);
+        Ok(Some(item))
+    }
+}
+
+/// An in-process OTLP trace receiver, keeps the encoded export requests.
+/// An empty message is a valid `ExportTraceServiceResponse`.
+#[derive(Clone, Default)]
+struct OtlpReceiver {
+    requests: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl NamedService for OtlpReceiver {
+    const NAME: &'static str = "opentelemetry.proto.collector.trace.v1.TraceService";
+}
+
+impl UnaryService<Vec<u8>> for OtlpReceiver {
+    type Response = Vec<u8>;
+    type Future = BoxFuture<tonic::Response<Vec<u8>>, Status>;
+
+    fn call(&mut self, request: tonic::Request<Vec<u8>>) -> Self::Future {
+        self.requests.lock().unwrap().push(request.into_inner());
+        Box::pin(async { Ok(tonic::Response::new(vec![])) })
+    }
+}
+
+impl<B> Service<http::Request<B>> for OtlpReceiver
+where
+    B: Body + Send + Sync + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let receiver = self.clone();
+        Box::pin(async move {
+            let mut grpc = Grpc::new(RawCodec);
+            Ok(grpc.unary(receiver, request).await)
+        })
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_otlp_export_with_remote_parent() -> anyhow::Result<()> {
+    let receiver = OtlpReceiver::default();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let endpoint = format!("http://{}", listener.local_addr()?);
+    let server = tonic::transport::Server::builder()
+        .add_service(receiver.clone())
+        .serve_with_incoming(TcpListenerStream::new(listener));
+    tokio::spawn(server);
+
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let tracer = new_otlp_tracer(&endpoint, "otlp-test")?;
+    let provider = tracer.provider().unwrap();
+    let subscriber = Registry::default().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+    let (traceparent, remote_trace_id) = tracing::subscriber::with_default(subscriber, || {
+        // The client injects its span into the request.
+        let client_span = tracing::info_span!("otlp_client_span");
+        let request = client_span.in_scope(|| inject_span_to_tonic_request(()));
+        let traceparent = request.metadata().get("traceparent").cloned();
+
+        // The server continues the trace of the client.
+        let server_span = tracing::info_span!(parent: None, "otlp_server_span");
+        server_span.in_scope(|| extract_remote_span_as_parent(&request));
+        let remote_trace_id = server_span.context().span().span_context().trace_id();
+        (traceparent, remote_trace_id)
+    });
+
+    // traceparent: {version}-{trace_id}-{parent_id}-{flags}
+    let traceparent = traceparent.unwrap();
+    let traceparent = traceparent.to_str()?.split('-').collect::<Vec<_>>();
+    assert_eq!(traceparent.len(), 4);
+    assert_eq!(traceparent[1], remote_trace_id.to_hex());
+
+    for result in tokio::task::spawn_blocking(move || provider.force_flush()).await? {
+        result?;
+    }
+
+    let requests = receiver.requests.lock().unwrap().concat();
+    let contains = |s: &str| requests.windows(s.len()).any(|w| w == s.as_bytes());
+    assert!(contains("otlp-test"));
+    assert!(contains("otlp_client_span"));
+    assert!(contains("otlp_server_span"));
+
+    Ok(())
+}
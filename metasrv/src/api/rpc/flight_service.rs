@@ -87,10 +87,13 @@ impl MetaFlightImpl {
 #[async_trait::async_trait]
 impl FlightService for MetaFlightImpl {
     type HandshakeStream = FlightStream<HandshakeResponse>;
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn handshake(
         &self,
         request: Request<Streaming<HandshakeRequest>>,
     ) -> Result<Response<Self::HandshakeStream>, Status> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let req = request
             .into_inner()
             .next()
@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::io;
+
+use axum::body::Bytes;
+use axum::body::Full;
+use axum::extract::BodyStream;
+use axum::extract::Extension;
+use axum::extract::Query;
+use axum::http::Response;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use common_base::tokio;
+use common_base::tokio::sync::mpsc::channel;
+use common_base::tokio::sync::mpsc::Receiver;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::PlanNode;
+use common_streams::FormatSettings;
+use common_streams::SourceFactory;
+use futures::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::SessionManagerRef;
+use crate::sql::PlanParser;
+
+pub struct InsertTemplate {
+    result: Result<()>,
+}
+
+impl IntoResponse for InsertTemplate {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        match self.result {
+            Ok(_) => Response::builder()
+                .status(StatusCode::OK)
+                .body(Full::from(""))
+                .unwrap(),
+            Err(cause) => Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Full::from(format!("Failed to insert. cause: {}", cause)))
+                .unwrap(),
+        }
+    }
+}
+
+/// `POST /v1/insert?query=INSERT INTO t FORMAT CSV`, the request body is the data. The other
+/// parameters of the query string are settings of the insert, e.g. `format_skip_header=1`.
+pub async fn insert_handler(
+    sessions: Extension<SessionManagerRef>,
+    params: Query<HashMap<String, String>>,
+    body: BodyStream,
+) -> InsertTemplate {
+    let sessions = sessions.0;
+    InsertTemplate {
+        result: insert(sessions, params.0, body).await,
+    }
+}
+
+async fn insert(
+    sessions: SessionManagerRef,
+    mut params: HashMap<String, String>,
+    body: BodyStream,
+) -> Result<()> {
+    let query = params
+        .remove("query")
+        .ok_or_else(|| ErrorCode::BadArguments("Missing the query parameter"))?;
+
+    let session = sessions.create_session("HTTPInsert")?;
+    let ctx = session.create_context().await?;
+    for (name, value) in params {
+        ctx.get_settings().update_settings(&name, value)?;
+    }
+
+    ctx.attach_query_str(&query);
+    let plan = match PlanParser::create(ctx.clone()).build_from_sql(&query)? {
+        PlanNode::InsertInto(plan) if plan.format.is_some() => plan,
+        _ => {
+            return Err(ErrorCode::BadArguments(format!(
+                "Expect INSERT INTO ... FORMAT, but got: {}",
+                query
+            )))
+        }
+    };
+
+    // The body is forwarded to a blocking task, which parses it incrementally into blocks.
+    let format = plan.format.clone().unwrap_or_default();
+    let format_settings = format_settings(&ctx)?;
+    let block_size = ctx.get_settings().get_max_block_size()? as usize;
+    let mut source = SourceFactory::try_get(
+        &format,
+        BodyReader::create(forward_body(&ctx, body)?),
+        plan.schema(),
+        format_settings,
+        block_size,
+    )?;
+
+    // A parse error is sent to the table as well, which aborts the insert.
+    let (sender, receiver) = channel::<Result<DataBlock>>(2);
+    tokio::task::spawn_blocking(move || loop {
+        let block = match source.read() {
+            Ok(None) => break,
+            Ok(Some(block)) => Ok(block),
+            Err(cause) => Err(cause),
+        };
+        let failed = block.is_err();
+        if sender.blocking_send(block).is_err() || failed {
+            break;
+        }
+    });
+
+    plan.set_input_stream(Box::pin(ReceiverStream::new(receiver)));
+    let interpreter = InterpreterFactory::get(ctx.clone(), PlanNode::InsertInto(plan))?;
+    let mut stream = interpreter.execute().await?;
+    while let Some(block) = stream.next().await {
+        block?;
+    }
+    Ok(())
+}
+
+fn format_settings(ctx: &DatabendQueryContextRef) -> Result<FormatSettings> {
+    let settings = ctx.get_settings();
+    let field_delimiter = settings.get_format_field_delimiter()?;
+    if field_delimiter.len() != 1 {
+        return Err(ErrorCode::BadArguments(format!(
+            "format_field_delimiter must be a single byte, but got: {:?}",
+            field_delimiter
+        )));
+    }
+
+    Ok(FormatSettings {
+        field_delimiter: field_delimiter.as_bytes()[0],
+        skip_header: settings.get_format_skip_header()? != 0,
+        null_bytes: settings.get_format_null()?.into_bytes(),
+        ..Default::default()
+    })
+}
+
+fn forward_body(
+    ctx: &DatabendQueryContextRef,
+    mut body: BodyStream,
+) -> Result<Receiver<Result<Bytes>>> {
+    let (sender, receiver) = channel(2);
+    ctx.execute_task(async move {
+        while let Some(chunk) = body.next().await {
+            let chunk = chunk.map_err(|cause| {
+                ErrorCode::BadBytes(format!("Cannot read the request body, cause: {}", cause))
+            });
+            let failed = chunk.is_err();
+            if sender.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    })?;
+    Ok(receiver)
+}
+
+/// Blocking reader of the request body chunks, for the sources of the formats.
+struct BodyReader {
+    receiver: Receiver<Result<Bytes>>,
+    chunk: Bytes,
+}
+
+impl BodyReader {
+    fn create(receiver: Receiver<Result<Bytes>>) -> Self {
+        BodyReader {
+            receiver,
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+impl io::Read for BodyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            match self.receiver.blocking_recv() {
+                Some(Ok(chunk)) => self.chunk = chunk,
+                Some(Err(cause)) => {
+                    return Err(io::Error::new(io::ErrorKind::Other, cause.message()))
+                }
+                None => return Ok(0),
+            }
+        }
+
+        let size = buf.len().min(self.chunk.len());
+        buf[..size].copy_from_slice(&self.chunk[..size]);
+        self.chunk = self.chunk.slice(size..);
+        Ok(size)
+    }
+}
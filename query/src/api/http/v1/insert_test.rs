@@ -0,0 +1,127 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use axum::body::Body;
+use axum::handler::post;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::http::{self};
+use axum::AddExtensionLayer;
+use axum::Router;
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::PlanNode;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+use tower::ServiceExt;
+
+use crate::api::http::v1::insert::insert_handler;
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::SessionManagerRef;
+use crate::sql::PlanParser;
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_insert_format() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    execute(
+        &sessions,
+        "create table default.a(a Int64, b String) Engine = Memory",
+    )
+    .await?;
+
+    // CSV with a header line.
+    let status = post_insert(
+        &sessions,
+        "/v1/insert?query=insert%20into%20default.a%20format%20CSV&format_skip_header=1",
+        "a,b\n1,x\n2,\"y,z\"\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // JSONEachRow.
+    let status = post_insert(
+        &sessions,
+        "/v1/insert?query=insert%20into%20default.a%20format%20JSONEachRow",
+        "{\"a\": 3, \"b\": \"w\"}\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Bad row.
+    let status = post_insert(
+        &sessions,
+        "/v1/insert?query=insert%20into%20default.a%20format%20TSV",
+        "4\tv\nfour\tv\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Bad row after several blocks, none of them is inserted.
+    let status = post_insert(
+        &sessions,
+        "/v1/insert?query=insert%20into%20default.a%20format%20TSV&max_block_size=1",
+        "5\tv\n6\tv\n7\tv\nseven\tv\n",
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Not an INSERT INTO ... FORMAT.
+    let status = post_insert(&sessions, "/v1/insert?query=select%201", "").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let result = execute(&sessions, "select * from default.a").await?;
+    let expected = vec![
+        "+---+-----+",
+        "| a | b   |",
+        "+---+-----+",
+        "| 1 | x   |",
+        "| 2 | y,z |",
+        "| 3 | w   |",
+        "+---+-----+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+async fn post_insert(sessions: &SessionManagerRef, uri: &str, body: &'static str) -> StatusCode {
+    let test_router = Router::new()
+        .route("/v1/insert", post(insert_handler))
+        .layer(AddExtensionLayer::new(sessions.clone()));
+
+    let response = test_router
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(http::Method::POST)
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    response.status()
+}
+
+async fn execute(
+    sessions: &SessionManagerRef,
+    query: &str,
+) -> Result<Vec<common_datablocks::DataBlock>> {
+    let session = sessions.create_session("Test")?;
+    let ctx = session.create_context().await?;
+    let plan: PlanNode = PlanParser::create(ctx.clone()).build_from_sql(query)?;
+    let interpreter = InterpreterFactory::get(ctx, plan)?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
@@ -19,9 +19,12 @@ mod config_test;
 #[cfg(test)]
 mod health_test;
 #[cfg(test)]
+mod insert_test;
+#[cfg(test)]
 mod logs_test;
 
 pub mod cluster;
 pub mod config;
 pub mod health;
+pub mod insert;
 pub mod logs;
@@ -20,6 +20,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use axum::handler::get;
+use axum::handler::post;
 use axum::routing::BoxRoute;
 use axum::AddExtensionLayer;
 use axum::Router;
@@ -129,6 +130,7 @@ impl HttpService {
             .route("/v1/health", get(super::http::v1::health::health_handler))
             .route("/v1/config", get(super::http::v1::config::config_handler))
             .route("/v1/logs", get(super::http::v1::logs::logs_handler))
+            .route("/v1/insert", post(super::http::v1::insert::insert_handler))
             .route(
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
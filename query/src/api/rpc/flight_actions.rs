@@ -28,6 +28,8 @@ pub struct ShuffleAction {
     pub plan: PlanNode,
     pub sinks: Vec<String>,
     pub scatters_expression: Expression,
+    /// Profile the processors, the profiles are fetched by `FetchQueryProfileAction`.
+    pub profile: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -36,6 +38,7 @@ pub struct BroadcastAction {
     pub stage_id: String,
     pub plan: PlanNode,
     pub sinks: Vec<String>,
+    pub profile: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -43,6 +46,13 @@ pub struct CancelAction {
     pub query_id: String,
 }
 
+/// Fetch the profiles of the stages of the query executed on the node, the response body is
+/// the serialized `Vec<QueryProfileElement>`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct FetchQueryProfileAction {
+    pub query_id: String,
+}
+
 impl TryInto<ShuffleAction> for Vec<u8> {
     type Error = Status;
 
@@ -115,11 +125,36 @@ impl TryInto<Vec<u8>> for CancelAction {
     }
 }
 
+impl TryInto<FetchQueryProfileAction> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<FetchQueryProfileAction, Self::Error> {
+        match std::str::from_utf8(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(utf8_body) => match serde_json::from_str::<FetchQueryProfileAction>(utf8_body) {
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+                Ok(action) => Ok(action),
+            },
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for FetchQueryProfileAction {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
+            "Logical error: cannot serialize FetchQueryProfileAction."
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     PrepareShuffleAction(ShuffleAction),
     BroadcastAction(BroadcastAction),
     CancelAction(CancelAction),
+    FetchQueryProfileAction(FetchQueryProfileAction),
 }
 
 impl FlightAction {
@@ -155,6 +190,14 @@ impl FlightAction {
         }
     }
 
+    pub fn get_profile(&self) -> bool {
+        match self {
+            FlightAction::BroadcastAction(action) => action.profile,
+            FlightAction::PrepareShuffleAction(action) => action.profile,
+            _ => unimplemented!(),
+        }
+    }
+
     pub fn get_scatter_expression(&self) -> Option<Expression> {
         match self {
             FlightAction::BroadcastAction(_) => None,
@@ -172,6 +215,9 @@ impl TryInto<FlightAction> for Action {
             "PrepareShuffleAction" => Ok(FlightAction::PrepareShuffleAction(self.body.try_into()?)),
             "BroadcastAction" => Ok(FlightAction::BroadcastAction(self.body.try_into()?)),
             "CancelAction" => Ok(FlightAction::CancelAction(self.body.try_into()?)),
+            "FetchQueryProfileAction" => {
+                Ok(FlightAction::FetchQueryProfileAction(self.body.try_into()?))
+            }
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -197,6 +243,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("CancelAction"),
                 body: cancel_action.try_into()?,
             }),
+            FlightAction::FetchQueryProfileAction(fetch_action) => Ok(Action {
+                r#type: String::from("FetchQueryProfileAction"),
+                body: fetch_action.try_into()?,
+            }),
         }
     }
 }
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_planners::Expression;
 
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::FetchQueryProfileAction;
 use crate::api::ShuffleAction;
 use crate::tests::parse_query;
 
@@ -32,6 +33,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
         plan: parse_query("SELECT number FROM numbers(5)")?,
         sinks: vec![String::from("stream_id")],
         scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+        profile: false,
     };
 
     let from_action = FlightAction::PrepareShuffleAction(shuffle_action);
@@ -40,6 +42,7 @@ async fn test_shuffle_action_try_into() -> Result<()> {
     match from_action {
         FlightAction::CancelAction(_) => assert!(false),
         FlightAction::BroadcastAction(_) => assert!(false),
+        FlightAction::FetchQueryProfileAction(_) => assert!(false),
         FlightAction::PrepareShuffleAction(action) => {
             assert_eq!(action.query_id, "query_id");
             assert_eq!(action.stage_id, "stage_id");
@@ -54,3 +57,22 @@ async fn test_shuffle_action_try_into() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_fetch_query_profile_action_try_into() -> Result<()> {
+    let fetch_action = FetchQueryProfileAction {
+        query_id: String::from("query_id"),
+    };
+
+    let from_action = FlightAction::FetchQueryProfileAction(fetch_action);
+    let to_action: Action = from_action.try_into()?;
+    assert_eq!(to_action.r#type, "FetchQueryProfileAction");
+
+    let from_action: FlightAction = to_action.try_into()?;
+    match from_action {
+        FlightAction::FetchQueryProfileAction(action) => assert_eq!(action.query_id, "query_id"),
+        _ => assert!(false),
+    }
+
+    Ok(())
+}
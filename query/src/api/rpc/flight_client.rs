@@ -22,14 +22,18 @@ use common_base::tokio::time::Duration;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::ToErrorCode;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 use tonic::transport::channel::Channel;
 use tonic::Request;
 use tonic::Streaming;
 
+use crate::api::rpc::flight_actions::FetchQueryProfileAction;
 use crate::api::rpc::flight_actions::FlightAction;
 use crate::api::rpc::flight_client_stream::FlightDataStream;
 use crate::api::rpc::flight_tickets::FlightTicket;
+use crate::sessions::QueryProfileElement;
 
 pub struct FlightClient {
     inner: FlightServiceClient<Channel>,
@@ -57,9 +61,23 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Fetch the profiles of the stages of the query executed on the node.
+    pub async fn fetch_query_profile(
+        &mut self,
+        query_id: String,
+        timeout: u64,
+    ) -> Result<Vec<QueryProfileElement>> {
+        let action = FlightAction::FetchQueryProfileAction(FetchQueryProfileAction { query_id });
+        let body = self.do_action(action, timeout).await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::LogicalError, || {
+            "Logical error: cannot deserialize query profiles."
+        })
+    }
+
     // Execute do_get.
+    #[tracing::instrument(level = "debug", skip(self, ticket))]
     async fn do_get(&mut self, ticket: Ticket, timeout: u64) -> Result<Streaming<FlightData>> {
-        let mut request = Request::new(ticket);
+        let mut request = common_tracing::inject_span_to_tonic_request(Request::new(ticket));
         request.set_timeout(Duration::from_secs(timeout));
 
         let response = self.inner.do_get(request).await?;
@@ -67,10 +85,11 @@ impl FlightClient {
     }
 
     // Execute do_action.
+    #[tracing::instrument(level = "debug", skip(self, action))]
     async fn do_action(&mut self, action: FlightAction, timeout: u64) -> Result<Vec<u8>> {
         let action: Action = action.try_into()?;
         let action_type = action.r#type.clone();
-        let mut request = Request::new(action);
+        let mut request = common_tracing::inject_span_to_tonic_request(Request::new(action));
         request.set_timeout(Duration::from_secs(timeout));
 
         let response = self.inner.do_action(request).await?;
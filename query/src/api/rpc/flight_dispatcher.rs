@@ -32,8 +32,10 @@ use crate::api::rpc::flight_scatter_broadcast::BroadcastFlightScatter;
 use crate::api::rpc::flight_scatter_hash::HashFlightScatter;
 use crate::api::rpc::flight_tickets::StreamTicket;
 use crate::api::FlightAction;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::DatabendQueryContext;
+use crate::sessions::QueryLog;
 use crate::sessions::SessionRef;
 
 struct StreamInfo {
@@ -123,6 +125,9 @@ impl DatabendQueryFlightDispatcher {
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
+        if action.get_profile() {
+            action_context.enable_query_profile();
+        }
         let mut pipeline = pipeline_builder.build(&query_plan)?;
 
         let action_sinks = action.get_sinks();
@@ -137,6 +142,9 @@ impl DatabendQueryFlightDispatcher {
         let tx_ref = self.streams.read().get(&stream_name).map(|x| x.tx.clone());
         let tx = tx_ref.ok_or_else(|| ErrorCode::NotFoundStream("Not found stream"))?;
 
+        let query_log = query_context.get_sessions_manager().get_query_log();
+        let profile_ids = (action_query_id.clone(), action_stage_id.clone());
+
         query_context.execute_task(async move {
             let _session = session;
             wait_start(stage_name, stages_notify).await;
@@ -157,6 +165,9 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             };
+
+            // Before the sink is closed, so the profiles are ready when the stream is finished.
+            save_stage_profiles(&pipeline, &query_log, profile_ids);
         })?;
         Ok(())
     }
@@ -175,6 +186,9 @@ impl DatabendQueryFlightDispatcher {
 
         let query_plan = action.get_plan();
         action_context.attach_query_plan(&query_plan);
+        if action.get_profile() {
+            action_context.enable_query_profile();
+        }
         let mut pipeline = pipeline_builder.build(&query_plan)?;
 
         let action_query_id = action.get_query_id();
@@ -211,13 +225,17 @@ impl DatabendQueryFlightDispatcher {
             action.get_sinks().len(),
         )?;
 
+        let query_log = query_context.get_sessions_manager().get_query_log();
+        let profile_ids = (action_query_id.clone(), action_stage_id.clone());
+
         query_context.execute_task(async move {
             let _session = session;
             wait_start(stage_name, stages_notify).await;
 
             let sinks_tx_ref = &sinks_tx;
+            let pipeline_ref = &mut pipeline;
             let forward_blocks = async move {
-                let mut abortable_stream = pipeline.execute().await?;
+                let mut abortable_stream = pipeline_ref.execute().await?;
                 while let Some(item) = abortable_stream.next().await {
                     let forward_blocks = flight_scatter.execute(&item?)?;
 
@@ -244,6 +262,9 @@ impl DatabendQueryFlightDispatcher {
                     }
                 }
             }
+
+            // Before the sinks are closed, so the profiles are ready when the streams are finished.
+            save_stage_profiles(&pipeline, &query_log, profile_ids);
         })?;
 
         Ok(())
@@ -286,3 +307,11 @@ async fn wait_start(stage_name: String, stages_notify: Arc<RwLock<HashMap<String
         notify.notified().await;
     }
 }
+
+/// Keep the profiles of the stage on this node, they are fetched by the query node.
+fn save_stage_profiles(pipeline: &Pipeline, query_log: &QueryLog, ids: (String, String)) {
+    let (query_id, stage_id) = ids;
+    let profiles = pipeline.profiles();
+    let profiles = profiles.iter().map(|v| v.to_element(&query_id, &stage_id));
+    query_log.append_profiles(profiles.collect());
+}
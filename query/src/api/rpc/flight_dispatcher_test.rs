@@ -65,6 +65,7 @@ async fn test_run_shuffle_action_with_no_scatters() -> Result<()> {
                     plan: parse_query("SELECT number FROM numbers(5)")?,
                     sinks: vec![stream_id.clone()],
                     scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+                    profile: false,
                 }),
             )
             .await?;
@@ -109,6 +110,7 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
                     plan: parse_query("SELECT number FROM numbers(5)")?,
                     sinks: vec!["stream_1".to_string(), "stream_2".to_string()],
                     scatters_expression: Expression::Column("number".to_string()),
+                    profile: false,
                 }),
             )
             .await?;
@@ -150,6 +152,48 @@ async fn test_run_shuffle_action_with_scatter() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_run_shuffle_action_with_profile() -> Result<()> {
+    if let (Some(query_id), Some(stage_id), Some(stream_id)) = generate_uuids(3) {
+        let flight_dispatcher = DatabendQueryFlightDispatcher::create();
+
+        let sessions = SessionManagerBuilder::create().build()?;
+        let rpc_session = sessions.create_rpc_session(query_id.clone(), false)?;
+
+        flight_dispatcher
+            .shuffle_action(
+                rpc_session,
+                FlightAction::PrepareShuffleAction(ShuffleAction {
+                    query_id: query_id.clone(),
+                    stage_id: stage_id.clone(),
+                    plan: parse_query("SELECT number FROM numbers(5)")?,
+                    sinks: vec![stream_id.clone()],
+                    scatters_expression: Expression::create_literal(DataValue::UInt64(Some(1))),
+                    profile: true,
+                }),
+            )
+            .await?;
+
+        let stream = stream_ticket(&query_id, &stage_id, &stream_id);
+        let receiver = flight_dispatcher.get_stream(&stream)?;
+        let receiver_stream = ReceiverStream::new(receiver);
+        receiver_stream.collect::<Result<Vec<_>>>().await?;
+
+        let profiles = sessions.get_query_log().get_query_profiles(&query_id);
+        assert!(!profiles.is_empty());
+        assert!(profiles.iter().all(|profile| profile.stage == stage_id));
+
+        let source_rows: u64 = profiles
+            .iter()
+            .filter(|profile| profile.pipe == 0)
+            .map(|profile| profile.output_rows)
+            .sum();
+        assert_eq!(source_rows, 5);
+    }
+
+    Ok(())
+}
+
 fn stream_ticket(query_id: &str, stage_id: &str, stream: &str) -> StreamTicket {
     StreamTicket {
         query_id: query_id.to_string(),
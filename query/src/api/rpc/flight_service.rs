@@ -30,6 +30,9 @@ use common_arrow::arrow_flight::PutResult;
 use common_arrow::arrow_flight::Result as FlightResult;
 use common_arrow::arrow_flight::SchemaResult;
 use common_arrow::arrow_flight::Ticket;
+use common_exception::ErrorCode;
+use common_exception::ToErrorCode;
+use common_tracing::tracing;
 use tokio_stream::Stream;
 use tonic::Request;
 use tonic::Response as RawResponse;
@@ -98,7 +101,10 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoGetStream = FlightStream<FlightData>;
 
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let ticket: FlightTicket = request.into_inner().try_into()?;
 
         match ticket {
@@ -130,7 +136,11 @@ impl FlightService for DatabendQueryFlightService {
 
     type DoActionStream = FlightStream<FlightResult>;
 
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
+        // The stages of the action are executed in this span, as a part of the remote trace.
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let action = request.into_inner();
         let flight_action: FlightAction = action.try_into()?;
 
@@ -145,6 +155,15 @@ impl FlightService for DatabendQueryFlightService {
 
                 FlightResult { body: vec![] }
             }
+            FlightAction::FetchQueryProfileAction(action) => {
+                let query_log = self.sessions.get_query_log();
+                let profiles = query_log.get_query_profiles(&action.query_id);
+                let body = serde_json::to_vec(&profiles)
+                    .map_err_to_code(ErrorCode::LogicalError, || {
+                        "Logical error: cannot serialize query profiles."
+                    })?;
+                FlightResult { body }
+            }
             FlightAction::BroadcastAction(action) => {
                 let session_id = action.query_id.clone();
                 let is_aborted = self.dispatcher.is_aborted();
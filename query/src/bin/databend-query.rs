@@ -97,6 +97,23 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
+    // ClickHouse secure handler.
+    if conf.tls_handler_enabled() {
+        let hostname = conf.query.clickhouse_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.clickhouse_handler_secure_port);
+
+        let mut srv = ClickHouseHandler::create_secure(session_manager.clone());
+        let listening = srv.start(listening.parse()?).await?;
+        shutdown_handle.add_service(srv);
+
+        info!(
+            "ClickHouse secure handler listening on {}, Usage: clickhouse-client --secure --host {} --port {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // Metric API service.
     {
         let address = conf.query.metric_api_address.clone();
@@ -19,7 +19,10 @@ use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
 use common_planners::CreateDatabasePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::TableOptions;
 
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::Database;
 use crate::catalogs::TableFunctionMeta;
 use crate::catalogs::TableMeta;
@@ -53,6 +56,8 @@ pub trait Catalog {
         table_id: MetaId,
         table_version: Option<MetaVersion>,
     ) -> Result<Arc<TableMeta>>;
+    // Get the latest options of the table kept in the meta store, instead of the cached instance.
+    fn get_table_options(&self, db_name: &str, table_name: &str) -> Result<TableOptions>;
     // Get function by name.
     fn get_table_function(&self, func_name: &str) -> Result<Arc<TableFunctionMeta>>;
 
@@ -60,6 +65,12 @@ pub trait Catalog {
     fn create_database(&self, plan: CreateDatabasePlan) -> Result<()>;
     fn drop_database(&self, plan: DropDatabasePlan) -> Result<()>;
 
+    // Apply the changes of the tables atomically.
+    fn update_tables(&self, changes: Vec<TableChange>) -> Result<()>;
+
+    // Commit the new snapshots of the tables atomically.
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> Result<()>;
+
     // Get all db engines.
     fn get_db_engines(&self) -> Result<Vec<EngineDescription>>;
 }
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
@@ -50,4 +51,5 @@ pub trait Database: Sync + Send {
     /// DDL
     fn create_table(&self, plan: CreateTablePlan) -> Result<()>;
     fn drop_table(&self, plan: DropTablePlan) -> Result<()>;
+    fn alter_table(&self, plan: AlterTablePlan) -> Result<()>;
 }
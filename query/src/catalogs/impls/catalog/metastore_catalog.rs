@@ -23,12 +23,15 @@ use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
 use common_planners::CreateDatabasePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::TableOptions;
 
 use crate::catalogs::catalog::Catalog;
 use crate::catalogs::impls::meta_backends::EmbeddedMetaBackend;
 use crate::catalogs::impls::meta_backends::RemoteMeteStoreClient;
 use crate::catalogs::meta_backend::DatabaseInfo;
 use crate::catalogs::meta_backend::MetaBackend;
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::Database;
 use crate::catalogs::TableFunctionMeta;
 use crate::catalogs::TableMeta;
@@ -178,6 +181,15 @@ impl Catalog for MetaStoreCatalog {
         db.get_table_by_id(table_id, table_version)
     }
 
+    fn get_table_options(&self, db_name: &str, table_name: &str) -> Result<TableOptions> {
+        match self.meta_backend.get_table(db_name, table_name) {
+            Ok(table_info) => Ok(table_info.table_option.clone()),
+            // The tables of other database engines are not kept in the meta store
+            Err(e) if e.code() == ErrorCode::UnknownTable("").code() => Ok(TableOptions::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     fn get_table_function(&self, func_name: &str) -> Result<Arc<TableFunctionMeta>> {
         let databases = self.get_databases()?;
         for database in databases {
@@ -214,6 +226,14 @@ impl Catalog for MetaStoreCatalog {
         Ok(())
     }
 
+    fn update_tables(&self, changes: Vec<TableChange>) -> Result<()> {
+        self.meta_backend.update_tables(changes)
+    }
+
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> Result<()> {
+        self.meta_backend.commit_tables(commits)
+    }
+
     fn get_db_engines(&self) -> Result<Vec<EngineDescription>> {
         let descriptions = self.db_engine_registry.descriptions();
         Ok(descriptions)
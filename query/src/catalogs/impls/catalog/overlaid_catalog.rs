@@ -20,7 +20,10 @@ use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
 use common_planners::CreateDatabasePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::TableOptions;
 
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::Catalog;
 use crate::catalogs::Database;
 use crate::catalogs::TableFunctionMeta;
@@ -115,6 +118,18 @@ impl Catalog for OverlaidCatalog {
             })
     }
 
+    fn get_table_options(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> common_exception::Result<TableOptions> {
+        if self.read_only.exists_database(db_name)? {
+            self.read_only.get_table_options(db_name, table_name)
+        } else {
+            self.bottom.get_table_options(db_name, table_name)
+        }
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
@@ -134,6 +149,16 @@ impl Catalog for OverlaidCatalog {
         self.bottom.drop_database(plan)
     }
 
+    fn update_tables(&self, changes: Vec<TableChange>) -> common_exception::Result<()> {
+        // tables of the READ_ONLY layer are never changed
+        self.bottom.update_tables(changes)
+    }
+
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> common_exception::Result<()> {
+        // tables of the READ_ONLY layer are never committed
+        self.bottom.commit_tables(commits)
+    }
+
     fn get_db_engines(&self) -> common_exception::Result<Vec<EngineDescription>> {
         let mut dbs = self.read_only.get_db_engines()?;
         let mut other = self.bottom.get_db_engines()?;
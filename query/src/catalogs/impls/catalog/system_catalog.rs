@@ -22,12 +22,16 @@ use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
 use common_planners::CreateDatabasePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::TableOptions;
 
 use crate::catalogs::catalog::Catalog;
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::Database;
 use crate::catalogs::TableFunctionMeta;
 use crate::catalogs::TableMeta;
 use crate::configs::Config;
+use crate::datasources::database::information_schema::InformationSchemaDatabase;
 use crate::datasources::database::system::SystemDatabase;
 use crate::datasources::database_engine::DatabaseEngine;
 use crate::datasources::database_engine_registry::EngineDescription;
@@ -37,13 +41,15 @@ pub const SYS_TBL_ID_BEGIN: u64 = 1 << 62;
 // max id for system tables (exclusive)
 pub const SYS_TBL_ID_END: u64 = SYS_TBL_ID_BEGIN + 10000;
 
+// min id for information_schema tables (inclusive), the tables of the system db are below it
+pub const INFORMATION_SCHEMA_TBL_ID_BEGIN: u64 = SYS_TBL_ID_BEGIN + 5000;
+
 // min id for system tables (inclusive)
 // max id for local tables is u64:MAX
 pub const LOCAL_TBL_ID_BEGIN: u64 = SYS_TBL_ID_END;
 
 /// System Catalog contains ... all the system databases (no surprise :)
-/// Currently, this is only one database here, the "system" db.
-/// "information_schema" db is supposed to held here
+/// Currently, they are the "system" db and the "information_schema" db.
 pub struct SystemCatalog {
     dbs: HashMap<String, Arc<dyn Database>>,
 }
@@ -53,6 +59,9 @@ impl SystemCatalog {
         let mut dbs = HashMap::new();
         let sys_db = Arc::new(SystemDatabase::create()) as Arc<dyn Database>;
         dbs.insert("system".to_owned(), sys_db);
+        let information_schema_db =
+            Arc::new(InformationSchemaDatabase::create()) as Arc<dyn Database>;
+        dbs.insert("information_schema".to_owned(), information_schema_db);
         Ok(Self { dbs })
     }
 }
@@ -104,6 +113,12 @@ impl Catalog for SystemCatalog {
         db.get_table_by_id(table_id, table_version)
     }
 
+    fn get_table_options(&self, db_name: &str, table_name: &str) -> Result<TableOptions> {
+        // The system tables have no options
+        self.get_table(db_name, table_name)?;
+        Ok(TableOptions::new())
+    }
+
     fn get_table_function(&self, func_name: &str) -> Result<Arc<TableFunctionMeta>> {
         let databases = self.get_databases()?;
         for database in databases {
@@ -128,6 +143,18 @@ impl Catalog for SystemCatalog {
         Err(ErrorCode::UnImplement("Cannot drop system database"))
     }
 
+    fn update_tables(&self, _changes: Vec<TableChange>) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Cannot update tables of system database",
+        ))
+    }
+
+    fn commit_tables(&self, _commits: Vec<TableCommit>) -> Result<()> {
+        Err(ErrorCode::UnImplement(
+            "Cannot commit tables of system database",
+        ))
+    }
+
     fn get_db_engines(&self) -> Result<Vec<EngineDescription>> {
         // system catalog is special treated, no implicit database engine provided for it.
         let desc = EngineDescription {
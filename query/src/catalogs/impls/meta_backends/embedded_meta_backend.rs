@@ -16,10 +16,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_infallible::RwLock;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
@@ -28,8 +30,13 @@ use common_planners::DropTablePlan;
 use crate::catalogs::impls::LOCAL_TBL_ID_BEGIN;
 use crate::catalogs::meta_backend::DatabaseInfo;
 use crate::catalogs::meta_backend::MetaBackend;
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::meta_backend::TableInfo;
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
+use crate::datasources::common::alter_schema;
 
+#[derive(Clone)]
 struct InMemoryTableInfo {
     pub(crate) name2meta: HashMap<String, Arc<TableInfo>>,
     pub(crate) id2meta: HashMap<MetaId, Arc<TableInfo>>,
@@ -53,6 +60,19 @@ impl InMemoryTableInfo {
 
 type Databases = Arc<RwLock<HashMap<String, (Arc<DatabaseInfo>, InMemoryTableInfo)>>>;
 
+fn tables_mut<'a>(
+    databases: &'a mut HashMap<String, (Arc<DatabaseInfo>, InMemoryTableInfo)>,
+    db_name: &str,
+) -> common_exception::Result<&'a mut InMemoryTableInfo> {
+    match databases.get_mut(db_name) {
+        None => Err(ErrorCode::UnknownDatabase(format!(
+            "Unknown database: {}",
+            db_name
+        ))),
+        Some((_, metas)) => Ok(metas),
+    }
+}
+
 pub struct EmbeddedMetaBackend {
     databases: Databases,
     tbl_id_seq: Arc<RwLock<u64>>,
@@ -264,6 +284,62 @@ impl MetaBackend for EmbeddedMetaBackend {
         Ok(())
     }
 
+    fn alter_table(&self, plan: AlterTablePlan) -> common_exception::Result<()> {
+        let (new_db, new_table) = plan.new_name();
+
+        let mut lock = self.databases.write();
+        let old = match lock.get(&plan.db) {
+            None => {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "Unknown database: {}",
+                    plan.db
+                )))
+            }
+            Some((_, metas)) => metas.name2meta.get(&plan.table).cloned().ok_or_else(|| {
+                ErrorCode::UnknownTable(format!("Unknown table: '{}.{}'", plan.db, plan.table))
+            })?,
+        };
+
+        let renamed = new_db != plan.db || new_table != plan.table;
+        match lock.get(&new_db) {
+            None => {
+                return Err(ErrorCode::UnknownDatabase(format!(
+                    "Unknown database: {}",
+                    new_db
+                )))
+            }
+            Some((_, metas)) if renamed && metas.name2meta.contains_key(&new_table) => {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table: '{}.{}' already exists.",
+                    new_db, new_table,
+                )));
+            }
+            Some(_) => {}
+        }
+
+        // applied to the current schema, the one of the plan may miss a snapshot committed since
+        let schema = Arc::new(alter_schema(&old.schema, &plan.action)?);
+        if renamed {
+            if let Some((_, metas)) = lock.get_mut(&plan.db) {
+                metas.name2meta.remove(&plan.table);
+                metas.id2meta.remove(&old.table_id);
+            }
+        }
+
+        if let Some((_, metas)) = lock.get_mut(&new_db) {
+            metas.insert(TableInfo {
+                db: new_db.clone(),
+                table_id: old.table_id,
+                name: new_table.clone(),
+                schema,
+                engine: old.engine.clone(),
+                table_option: old.table_option.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn create_database(&self, plan: CreateDatabasePlan) -> common_exception::Result<()> {
         let db_name = plan.db.as_str();
 
@@ -308,6 +384,134 @@ impl MetaBackend for EmbeddedMetaBackend {
         Ok(())
     }
 
+    fn update_tables(&self, changes: Vec<TableChange>) -> common_exception::Result<()> {
+        let mut lock = self.databases.write();
+
+        // the changes are applied to a copy, which replaces the databases if all of them succeed
+        let mut databases = (*lock).clone();
+        for change in changes {
+            match change {
+                TableChange::Create(plan) => {
+                    let metas = tables_mut(&mut databases, &plan.db)?;
+                    if metas.name2meta.contains_key(&plan.table) {
+                        if plan.if_not_exists {
+                            continue;
+                        }
+                        return Err(ErrorCode::TableAlreadyExists(format!(
+                            "Table: '{}.{}' already exists.",
+                            plan.db, plan.table,
+                        )));
+                    }
+                    metas.insert(TableInfo {
+                        db: plan.db,
+                        table_id: self.next_db_id(),
+                        name: plan.table,
+                        schema: plan.schema,
+                        engine: plan.engine,
+                        table_option: plan.options,
+                    });
+                }
+                TableChange::Drop(plan) => {
+                    let metas = tables_mut(&mut databases, &plan.db)?;
+                    match metas.name2meta.remove(&plan.table) {
+                        Some(table) => {
+                            metas.id2meta.remove(&table.table_id);
+                        }
+                        None if plan.if_exists => {}
+                        None => {
+                            return Err(ErrorCode::UnknownTable(format!(
+                                "Unknown table: '{}.{}'",
+                                plan.db, plan.table
+                            )))
+                        }
+                    }
+                }
+                TableChange::SetOption {
+                    db,
+                    table,
+                    key,
+                    value,
+                } => {
+                    let metas = tables_mut(&mut databases, &db)?;
+                    let old = metas.name2meta.get(&table).cloned().ok_or_else(|| {
+                        ErrorCode::UnknownTable(format!("Unknown table: '{}.{}'", db, table))
+                    })?;
+                    let mut table_option = old.table_option.clone();
+                    match value {
+                        Some(value) => table_option.insert(key, value),
+                        None => table_option.remove(&key),
+                    };
+                    metas.insert(TableInfo {
+                        db: old.db.clone(),
+                        table_id: old.table_id,
+                        name: old.name.clone(),
+                        schema: old.schema.clone(),
+                        engine: old.engine.clone(),
+                        table_option,
+                    });
+                }
+            }
+        }
+
+        *lock = databases;
+        Ok(())
+    }
+
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> common_exception::Result<()> {
+        let mut lock = self.databases.write();
+
+        // all the tables are checked before any of them is updated
+        let mut committed = Vec::with_capacity(commits.len());
+        for commit in commits {
+            let table = match lock.get(&commit.db) {
+                None => {
+                    return Err(ErrorCode::UnknownDatabase(format!(
+                        "Unknown database: {}",
+                        commit.db
+                    )))
+                }
+                Some((_, metas)) => {
+                    metas
+                        .id2meta
+                        .get(&commit.table_id)
+                        .cloned()
+                        .ok_or_else(|| {
+                            ErrorCode::UnknownTable(format!(
+                                "Unknown table id: '{}'",
+                                commit.table_id
+                            ))
+                        })?
+                }
+            };
+
+            let mut meta = table.schema.meta().clone();
+            if meta.get(META_SNAPSHOT_LOCATION) != commit.prev_snapshot.as_ref() {
+                return Err(ErrorCode::CommitTableConflict(format!(
+                    "Table '{}.{}' has been changed by another commit",
+                    table.db, table.name
+                )));
+            }
+
+            meta.insert(META_SNAPSHOT_LOCATION.to_string(), commit.new_snapshot);
+            committed.push(TableInfo {
+                db: table.db.clone(),
+                table_id: table.table_id,
+                name: table.name.clone(),
+                schema: Arc::new(DataSchema::new_from(table.schema.fields().clone(), meta)),
+                engine: table.engine.clone(),
+                table_option: table.table_option.clone(),
+            });
+        }
+
+        for table_info in committed {
+            if let Some((_, metas)) = lock.get_mut(&table_info.db) {
+                metas.insert(table_info);
+            }
+        }
+
+        Ok(())
+    }
+
     fn name(&self) -> String {
         "embedded metastore backend".to_owned()
     }
@@ -19,27 +19,36 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::ipc::write::common::IpcWriteOptions;
+use common_arrow::arrow_flight::utils::flight_data_from_arrow_schema;
 use common_arrow::arrow_flight::FlightData;
 use common_base::Runtime;
 use common_cache::Cache;
 use common_cache::LruCache;
 use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
 use common_planners::DropTablePlan;
+use common_planners::TableOptions;
 
 use crate::catalogs::meta_backend::DatabaseInfo;
 use crate::catalogs::meta_backend::MetaBackend;
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::meta_backend::TableCommit;
 use crate::catalogs::meta_backend::TableInfo;
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
 use crate::common::StoreApiProvider;
 
 type CatalogTable = common_metatypes::Table;
+type MetaTableChange = common_metatypes::TableChange;
 type TableMetaCache = LruCache<(MetaId, MetaVersion), Arc<TableInfo>>;
 
 #[derive(Clone)]
@@ -77,19 +86,49 @@ impl RemoteMeteStoreClient {
             ..Default::default()
         })?;
         let schema = DataSchema::from(arrow_schema);
+        let (schema, options) = snapshot_into_schema(Arc::new(schema), tbl.table_options.clone());
 
         let info = TableInfo {
             db: db_name.to_owned(),
             table_id: t_id,
             name: t_name.to_owned(),
-            schema: Arc::new(schema),
-            table_option: tbl.table_options.clone(),
+            schema,
+            table_option: options,
             engine: tbl.table_engine.clone(),
         };
         Ok(info)
     }
 }
 
+/// The meta service keeps the snapshot location of a table in its options, so that the
+/// snapshots of several tables are committed at once by `CompareAndSetOption`. The tables see
+/// it in the schema meta, as with the embedded meta backend.
+fn snapshot_into_schema(
+    schema: DataSchemaRef,
+    mut options: TableOptions,
+) -> (DataSchemaRef, TableOptions) {
+    match options.remove(META_SNAPSHOT_LOCATION) {
+        None => (schema, options),
+        Some(location) => {
+            let mut meta = schema.meta().clone();
+            meta.insert(META_SNAPSHOT_LOCATION.to_string(), location);
+            let fields = schema.fields().clone();
+            (Arc::new(DataSchema::new_from(fields, meta)), options)
+        }
+    }
+}
+
+/// Moves the snapshot location of the table to be created from its schema meta into its options.
+fn snapshot_into_options(mut plan: CreateTablePlan) -> CreateTablePlan {
+    let mut meta = plan.schema.meta().clone();
+    if let Some(location) = meta.remove(META_SNAPSHOT_LOCATION) {
+        plan.options
+            .insert(META_SNAPSHOT_LOCATION.to_string(), location);
+        plan.schema = Arc::new(DataSchema::new_from(plan.schema.fields().clone(), meta));
+    }
+    plan
+}
+
 impl MetaBackend for RemoteMeteStoreClient {
     fn get_table(&self, db_name: &str, table_name: &str) -> Result<Arc<TableInfo>> {
         let cli_provider = self.store_api_provider.clone();
@@ -105,13 +144,14 @@ impl MetaBackend for RemoteMeteStoreClient {
             )??
         };
 
+        let (schema, options) = snapshot_into_schema(reply.schema, reply.options);
         let table_info = TableInfo {
             db: reply.db,
             table_id: reply.table_id,
             name: reply.name.clone(),
-            schema: reply.schema,
+            schema,
             engine: reply.engine,
-            table_option: reply.options,
+            table_option: options,
         };
         Ok(Arc::new(table_info))
     }
@@ -148,13 +188,14 @@ impl MetaBackend for RemoteMeteStoreClient {
             self.rpc_time_out,
         )??;
 
+        let (schema, options) = snapshot_into_schema(reply.schema, reply.options);
         let res = TableInfo {
             db: db_name.to_owned(),
             table_id: reply.table_id,
             name: reply.name.clone(),
-            schema: reply.schema.clone(),
-            engine: reply.engine.clone(),
-            table_option: reply.options.clone(),
+            schema,
+            engine: reply.engine,
+            table_option: options,
         };
 
         let mut cache = self.table_meta_cache.lock();
@@ -260,6 +301,7 @@ impl MetaBackend for RemoteMeteStoreClient {
 
     fn create_table(&self, plan: CreateTablePlan) -> Result<()> {
         // TODO validate plan by table engine first
+        let plan = snapshot_into_options(plan);
         let cli = self.store_api_provider.clone();
         let _r = self.rt.block_on(
             async move {
@@ -283,6 +325,18 @@ impl MetaBackend for RemoteMeteStoreClient {
         Ok(())
     }
 
+    fn alter_table(&self, plan: AlterTablePlan) -> Result<()> {
+        let cli = self.store_api_provider.clone();
+        let _r = self.rt.block_on(
+            async move {
+                let client = cli.try_get_meta_client().await?;
+                client.alter_table(plan).await
+            },
+            self.rpc_time_out,
+        )??;
+        Ok(())
+    }
+
     fn create_database(&self, plan: CreateDatabasePlan) -> Result<()> {
         let cli_provider = self.store_api_provider.clone();
         let _r = self.rt.block_on(
@@ -307,6 +361,91 @@ impl MetaBackend for RemoteMeteStoreClient {
         Ok(())
     }
 
+    fn update_tables(&self, changes: Vec<TableChange>) -> Result<()> {
+        // the meta service requires a created table to be absent and a dropped one to be present
+        let mut meta_changes = Vec::with_capacity(changes.len());
+        for change in changes {
+            let meta_change = match change {
+                TableChange::Create(plan) => {
+                    if plan.if_not_exists && self.get_table(&plan.db, &plan.table).is_ok() {
+                        continue;
+                    }
+                    let plan = snapshot_into_options(plan);
+                    let options = IpcWriteOptions::default();
+                    let flight_data =
+                        flight_data_from_arrow_schema(&plan.schema.to_arrow(), &options);
+                    MetaTableChange::Create {
+                        db_name: plan.db,
+                        table_name: plan.table,
+                        table: CatalogTable {
+                            table_id: 0,
+                            schema: flight_data.data_header,
+                            table_engine: plan.engine,
+                            table_options: plan.options,
+                            parts: Default::default(),
+                        },
+                    }
+                }
+                TableChange::Drop(plan) => {
+                    if plan.if_exists && self.get_table(&plan.db, &plan.table).is_err() {
+                        continue;
+                    }
+                    MetaTableChange::Drop {
+                        db_name: plan.db,
+                        table_name: plan.table,
+                    }
+                }
+                TableChange::SetOption {
+                    db,
+                    table,
+                    key,
+                    value,
+                } => MetaTableChange::SetOption {
+                    db_name: db,
+                    table_name: table,
+                    key,
+                    value,
+                },
+            };
+            meta_changes.push(meta_change);
+        }
+
+        let cli = self.store_api_provider.clone();
+        let _r = self.rt.block_on(
+            async move {
+                let client = cli.try_get_meta_client().await?;
+                client.update_tables(meta_changes).await
+            },
+            self.rpc_time_out,
+        )??;
+        Ok(())
+    }
+
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> Result<()> {
+        // the snapshot locations are compared and set by one command of the meta service
+        let mut meta_changes = Vec::with_capacity(commits.len());
+        for commit in commits {
+            let table = self.get_table_by_id(&commit.db, commit.table_id, None)?;
+            meta_changes.push(MetaTableChange::CompareAndSetOption {
+                db_name: commit.db,
+                table_name: table.name.clone(),
+                key: META_SNAPSHOT_LOCATION.to_string(),
+                expected: commit.prev_snapshot,
+                value: commit.new_snapshot,
+            });
+        }
+
+        let cli = self.store_api_provider.clone();
+        let _r = self.rt.block_on(
+            async move {
+                let client = cli.try_get_meta_client().await?;
+                client.update_tables(meta_changes).await
+            },
+            self.rpc_time_out,
+        )??;
+        Ok(())
+    }
+
     fn name(&self) -> String {
         "remote metastore backend".to_owned()
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 //
 
+pub use catalog::system_catalog::INFORMATION_SCHEMA_TBL_ID_BEGIN;
 pub use catalog::system_catalog::LOCAL_TBL_ID_BEGIN;
 pub use catalog::system_catalog::SYS_TBL_ID_BEGIN;
 pub use catalog::system_catalog::SYS_TBL_ID_END;
@@ -19,6 +19,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
@@ -35,6 +36,32 @@ pub struct TableInfo {
     pub table_option: TableOptions,
 }
 
+/// The key of the table schema meta, which points to the current snapshot of the table.
+pub const META_SNAPSHOT_LOCATION: &str = "META_SNAPSHOT_LOCATION";
+
+/// Replaces the current snapshot of the table, if it is still the `prev_snapshot`.
+#[derive(Clone, Debug)]
+pub struct TableCommit {
+    pub db: String,
+    pub table_id: MetaId,
+    pub prev_snapshot: Option<String>,
+    pub new_snapshot: String,
+}
+
+/// A change of a table, the changes passed to `update_tables` are applied all or nothing.
+#[derive(Clone, Debug)]
+pub enum TableChange {
+    Create(CreateTablePlan),
+    Drop(DropTablePlan),
+    /// Set an option of the table, or remove it if the value is None.
+    SetOption {
+        db: String,
+        table: String,
+        key: String,
+        value: Option<String>,
+    },
+}
+
 #[derive(Clone)]
 pub struct DatabaseInfo {
     pub name: String,
@@ -63,8 +90,18 @@ pub trait MetaBackend: Send + Sync {
 
     fn drop_table(&self, plan: DropTablePlan) -> Result<()>;
 
+    /// Alter the schema of the table, or rename it, possibly into another database.
+    fn alter_table(&self, plan: AlterTablePlan) -> Result<()>;
+
     fn create_database(&self, plan: CreateDatabasePlan) -> Result<()>;
 
     fn drop_database(&self, plan: DropDatabasePlan) -> Result<()>;
+
+    /// Apply the changes of the tables, all of them or none of them.
+    fn update_tables(&self, changes: Vec<TableChange>) -> Result<()>;
+
+    /// Commit the new snapshots of the tables, all of them or none of them.
+    fn commit_tables(&self, commits: Vec<TableCommit>) -> Result<()>;
+
     fn name(&self) -> String;
 }
@@ -13,18 +13,24 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::DeletePlan;
 use common_planners::InsertIntoPlan;
 use common_planners::ReadDataSourcePlan;
 use common_planners::ScanPlan;
+use common_planners::TableOptions;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 
+use crate::catalogs::meta_backend::TableCommit;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::StagedTable;
 
 #[async_trait::async_trait]
 pub trait Table: Sync + Send {
@@ -40,6 +46,17 @@ pub trait Table: Sync + Send {
     fn is_stateful(&self) -> bool {
         false
     }
+
+    // Whether the appends inside a transaction are staged until COMMIT, the appends to the
+    // other tables are rejected, since they would take effect at once.
+    fn support_transaction(&self) -> bool {
+        false
+    }
+
+    // The options the table is created with, they are cloned by CREATE TABLE ... LIKE.
+    fn options(&self) -> TableOptions {
+        TableOptions::new()
+    }
     // Get the read source plan.
     fn read_plan(
         &self,
@@ -76,6 +93,44 @@ pub trait Table: Sync + Send {
             self.name()
         )))
     }
+
+    async fn delete(&self, _ctx: DatabendQueryContextRef, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} of engine {} is not implemented",
+            self.name(),
+            self.engine()
+        )))
+    }
+
+    async fn update(&self, _ctx: DatabendQueryContextRef, _update_plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} of engine {} is not implemented",
+            self.name(),
+            self.engine()
+        )))
+    }
+
+    // The files loaded by COPY INTO, they are recorded by append_data with the data.
+    async fn copied_files(&self, _ctx: DatabendQueryContextRef) -> Result<HashSet<String>> {
+        Err(ErrorCode::UnImplement(format!(
+            "COPY INTO table {} of engine {} is not implemented",
+            self.name(),
+            self.engine()
+        )))
+    }
+
+    // Turn the writes staged by a transaction into the commit of a new snapshot.
+    async fn prepare_commit(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _staged: &StagedTable,
+    ) -> Result<TableCommit> {
+        Err(ErrorCode::UnImplement(format!(
+            "transaction for table {} of engine {} is not implemented",
+            self.name(),
+            self.engine()
+        )))
+    }
 }
 
 pub type TablePtr = Arc<dyn Table>;
@@ -82,6 +82,13 @@ impl<Key: HashTableKeyable, Entity: HashTableEntity<Key>> HashTable<Key, Entity>
         self.size == 0
     }
 
+    /// The bytes of the entities allocated by the table, whether they are filled or not.
+    #[inline(always)]
+    pub fn allocated_bytes(&self) -> usize {
+        let zero_entity = self.zero_entity_raw.map_or(0, |_| mem::size_of::<Entity>());
+        (self.grower.max_size() as usize) * mem::size_of::<Entity>() + zero_entity
+    }
+
     #[inline(always)]
     pub fn iter(&self) -> HashTableIter<Key, Entity> {
         HashTableIter::create(self.grower.max_size(), self.entities, self.zero_entity)
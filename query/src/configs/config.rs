@@ -66,6 +66,18 @@ const QUERY_MYSQL_HANDLER_PORT: &str = "QUERY_MYSQL_HANDLER_PORT";
 const QUERY_MAX_ACTIVE_SESSIONS: &str = "QUERY_MAX_ACTIVE_SESSIONS";
 const QUERY_CLICKHOUSE_HANDLER_HOST: &str = "QUERY_CLICKHOUSE_HANDLER_HOST";
 const QUERY_CLICKHOUSE_HANDLER_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_PORT";
+const QUERY_CLICKHOUSE_HANDLER_SECURE_PORT: &str = "QUERY_CLICKHOUSE_HANDLER_SECURE_PORT";
+const QUERY_HANDLER_TLS_SERVER_CERT: &str = "QUERY_HANDLER_TLS_SERVER_CERT";
+const QUERY_HANDLER_TLS_SERVER_KEY: &str = "QUERY_HANDLER_TLS_SERVER_KEY";
+const QUERY_HANDLER_TLS_SERVER_ROOT_CA_CERT: &str = "QUERY_HANDLER_TLS_SERVER_ROOT_CA_CERT";
+const QUERY_QUERY_LOG_SIZE: &str = "QUERY_QUERY_LOG_SIZE";
+const QUERY_QUERY_LOG_TABLE: &str = "QUERY_QUERY_LOG_TABLE";
+const QUERY_QUERY_LOG_FLUSH_INTERVAL_MS: &str = "QUERY_QUERY_LOG_FLUSH_INTERVAL_MS";
+const QUERY_RESULT_CACHE_SIZE: &str = "QUERY_RESULT_CACHE_SIZE";
+const QUERY_RESULT_CACHE_DIR: &str = "QUERY_RESULT_CACHE_DIR";
+const QUERY_RESOURCE_GROUPS: &str = "QUERY_RESOURCE_GROUPS";
+const QUERY_RESOURCE_GROUP_ADMINS: &str = "QUERY_RESOURCE_GROUP_ADMINS";
+const QUERY_LOCAL_STAGE_DIR: &str = "QUERY_LOCAL_STAGE_DIR";
 const QUERY_FLIGHT_API_ADDRESS: &str = "QUERY_FLIGHT_API_ADDRESS";
 const QUERY_HTTP_API_ADDRESS: &str = "QUERY_HTTP_API_ADDRESS";
 const QUERY_METRICS_API_ADDRESS: &str = "QUERY_METRIC_API_ADDRESS";
@@ -286,6 +298,114 @@ pub struct QueryConfig {
     #[serde(default)]
     pub clickhouse_handler_port: u16,
 
+    #[structopt(
+    long,
+    env = QUERY_CLICKHOUSE_HANDLER_SECURE_PORT,
+    default_value = "9440",
+    help = "The ClickHouse handler port of TLS connections, listened if the handler TLS is set"
+    )]
+    #[serde(default)]
+    pub clickhouse_handler_secure_port: u16,
+
+    #[structopt(
+        long,
+        env = QUERY_HANDLER_TLS_SERVER_CERT,
+        default_value = "",
+        help = "Server certificate of the MySQL and ClickHouse handlers"
+    )]
+    #[serde(default)]
+    pub handler_tls_server_cert: String,
+
+    #[structopt(
+        long,
+        env = QUERY_HANDLER_TLS_SERVER_KEY,
+        default_value = "",
+        help = "Key of the server certificate of the MySQL and ClickHouse handlers"
+    )]
+    #[serde(default)]
+    pub handler_tls_server_key: String,
+
+    #[structopt(
+        long,
+        env = QUERY_HANDLER_TLS_SERVER_ROOT_CA_CERT,
+        default_value = "",
+        help = "CA certificate to verify the client certificates of the MySQL and ClickHouse handlers"
+    )]
+    #[serde(default)]
+    pub handler_tls_server_root_ca_cert: String,
+
+    #[structopt(
+        long,
+        env = QUERY_QUERY_LOG_SIZE,
+        default_value = "10000",
+        help = "Maximum number of queries kept in memory by system.query_log and system.query_profile"
+    )]
+    #[serde(default)]
+    pub query_log_size: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_QUERY_LOG_TABLE,
+        default_value = "",
+        help = "Fuse table to persist system.query_log into, as 'database.table'"
+    )]
+    #[serde(default)]
+    pub query_log_table: String,
+
+    #[structopt(
+        long,
+        env = QUERY_QUERY_LOG_FLUSH_INTERVAL_MS,
+        default_value = "1000",
+        help = "Interval in milliseconds of writing the buffered query logs into the query_log_table"
+    )]
+    #[serde(default)]
+    pub query_log_flush_interval_ms: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_RESULT_CACHE_SIZE,
+        default_value = "1073741824",
+        help = "Maximum bytes of the query results kept by the result cache"
+    )]
+    #[serde(default)]
+    pub result_cache_size: u64,
+
+    #[structopt(
+        long,
+        env = QUERY_RESULT_CACHE_DIR,
+        default_value = "",
+        help = "Directory of the result cache on disk, the results are kept in memory if empty"
+    )]
+    #[serde(default)]
+    pub result_cache_dir: String,
+
+    #[structopt(
+        long,
+        env = QUERY_RESOURCE_GROUPS,
+        default_value = "",
+        help = "Resource groups of the node separated by semicolons, e.g. \"etl WITH (max_concurrent_queries = 8, users = 'etl')\""
+    )]
+    #[serde(default)]
+    pub resource_groups: String,
+
+    #[structopt(
+        long,
+        env = QUERY_RESOURCE_GROUP_ADMINS,
+        default_value = "root",
+        help = "Users allowed to create and drop resource groups, separated by commas"
+    )]
+    #[serde(default)]
+    pub resource_group_admins: String,
+
+    #[structopt(
+        long,
+        env = QUERY_LOCAL_STAGE_DIR,
+        default_value = "",
+        help = "Directory of the local files of COPY INTO, file:///path is relative to it, local files can't be loaded if empty"
+    )]
+    #[serde(default)]
+    pub local_stage_dir: String,
+
     #[structopt(
     long,
     env = QUERY_FLIGHT_API_ADDRESS,
@@ -372,6 +492,18 @@ impl QueryConfig {
             max_active_sessions: 256,
             clickhouse_handler_host: "127.0.0.1".to_string(),
             clickhouse_handler_port: 9000,
+            clickhouse_handler_secure_port: 9440,
+            handler_tls_server_cert: "".to_string(),
+            handler_tls_server_key: "".to_string(),
+            handler_tls_server_root_ca_cert: "".to_string(),
+            query_log_size: 10000,
+            query_log_table: "".to_string(),
+            query_log_flush_interval_ms: 1000,
+            result_cache_size: 1073741824,
+            result_cache_dir: "".to_string(),
+            resource_groups: "".to_string(),
+            resource_group_admins: "root".to_string(),
+            local_stage_dir: "".to_string(),
             flight_api_address: "127.0.0.1:9090".to_string(),
             http_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
@@ -567,6 +699,86 @@ impl Config {
             QUERY_DISABLE_LOCAL_DATABASE_ENGINE
         );
 
+        // for mysql and clickhouse handlers
+        env_helper!(
+            mut_config,
+            query,
+            clickhouse_handler_secure_port,
+            u16,
+            QUERY_CLICKHOUSE_HANDLER_SECURE_PORT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            handler_tls_server_cert,
+            String,
+            QUERY_HANDLER_TLS_SERVER_CERT
+        );
+        env_helper!(
+            mut_config,
+            query,
+            handler_tls_server_key,
+            String,
+            QUERY_HANDLER_TLS_SERVER_KEY
+        );
+        env_helper!(
+            mut_config,
+            query,
+            handler_tls_server_root_ca_cert,
+            String,
+            QUERY_HANDLER_TLS_SERVER_ROOT_CA_CERT
+        );
+        env_helper!(mut_config, query, query_log_size, u64, QUERY_QUERY_LOG_SIZE);
+        env_helper!(
+            mut_config,
+            query,
+            query_log_table,
+            String,
+            QUERY_QUERY_LOG_TABLE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            query_log_flush_interval_ms,
+            u64,
+            QUERY_QUERY_LOG_FLUSH_INTERVAL_MS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            result_cache_size,
+            u64,
+            QUERY_RESULT_CACHE_SIZE
+        );
+        env_helper!(
+            mut_config,
+            query,
+            result_cache_dir,
+            String,
+            QUERY_RESULT_CACHE_DIR
+        );
+        env_helper!(
+            mut_config,
+            query,
+            resource_groups,
+            String,
+            QUERY_RESOURCE_GROUPS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            resource_group_admins,
+            String,
+            QUERY_RESOURCE_GROUP_ADMINS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            local_stage_dir,
+            String,
+            QUERY_LOCAL_STAGE_DIR
+        );
+
         // for api http service
         env_helper!(
             mut_config,
@@ -649,6 +861,11 @@ impl Config {
             && !self.meta.rpc_tls_meta_service_domain_name.is_empty()
     }
 
+    pub fn tls_handler_enabled(&self) -> bool {
+        !self.query.handler_tls_server_key.is_empty()
+            && !self.query.handler_tls_server_cert.is_empty()
+    }
+
     pub fn tls_rpc_server_enabled(&self) -> bool {
         !self.query.rpc_tls_server_key.is_empty() && !self.query.rpc_tls_server_cert.is_empty()
     }
@@ -47,6 +47,19 @@ fn test_env_config() -> Result<()> {
     std::env::set_var("QUERY_MAX_ACTIVE_SESSIONS", "255");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_HOST", "1.2.3.4");
     std::env::set_var("QUERY_CLICKHOUSE_HANDLER_PORT", "9000");
+    std::env::set_var("QUERY_CLICKHOUSE_HANDLER_SECURE_PORT", "9441");
+    std::env::set_var("QUERY_HANDLER_TLS_SERVER_CERT", "server.pem");
+    std::env::set_var("QUERY_QUERY_LOG_SIZE", "100");
+    std::env::set_var("QUERY_QUERY_LOG_TABLE", "history.query_log");
+    std::env::set_var("QUERY_QUERY_LOG_FLUSH_INTERVAL_MS", "500");
+    std::env::set_var("QUERY_RESULT_CACHE_SIZE", "1024");
+    std::env::set_var("QUERY_RESULT_CACHE_DIR", "_cache/results");
+    std::env::set_var(
+        "QUERY_RESOURCE_GROUPS",
+        "etl WITH (max_concurrent_queries = 8)",
+    );
+    std::env::set_var("QUERY_RESOURCE_GROUP_ADMINS", "root, admin");
+    std::env::set_var("QUERY_LOCAL_STAGE_DIR", "/data/stage");
     std::env::set_var("QUERY_FLIGHT_API_ADDRESS", "1.2.3.4:9091");
     std::env::set_var("QUERY_HTTP_API_ADDRESS", "1.2.3.4:8081");
     std::env::set_var("QUERY_METRIC_API_ADDRESS", "1.2.3.4:7071");
@@ -67,6 +80,19 @@ fn test_env_config() -> Result<()> {
     assert_eq!(255, configured.query.max_active_sessions);
     assert_eq!("1.2.3.4", configured.query.clickhouse_handler_host);
     assert_eq!(9000, configured.query.clickhouse_handler_port);
+    assert_eq!(9441, configured.query.clickhouse_handler_secure_port);
+    assert_eq!("server.pem", configured.query.handler_tls_server_cert);
+    assert_eq!(100, configured.query.query_log_size);
+    assert_eq!("history.query_log", configured.query.query_log_table);
+    assert_eq!(500, configured.query.query_log_flush_interval_ms);
+    assert_eq!(1024, configured.query.result_cache_size);
+    assert_eq!("_cache/results", configured.query.result_cache_dir);
+    assert_eq!(
+        "etl WITH (max_concurrent_queries = 8)",
+        configured.query.resource_groups
+    );
+    assert_eq!("root, admin", configured.query.resource_group_admins);
+    assert_eq!("/data/stage", configured.query.local_stage_dir);
 
     assert_eq!("1.2.3.4:9091", configured.query.flight_api_address);
     assert_eq!("1.2.3.4:8081", configured.query.http_api_address);
@@ -86,6 +112,16 @@ fn test_env_config() -> Result<()> {
     std::env::remove_var("QUERY_MAX_ACTIVE_SESSIONS");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_HOST");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_PORT");
+    std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_SECURE_PORT");
+    std::env::remove_var("QUERY_HANDLER_TLS_SERVER_CERT");
+    std::env::remove_var("QUERY_QUERY_LOG_SIZE");
+    std::env::remove_var("QUERY_QUERY_LOG_TABLE");
+    std::env::remove_var("QUERY_QUERY_LOG_FLUSH_INTERVAL_MS");
+    std::env::remove_var("QUERY_RESULT_CACHE_SIZE");
+    std::env::remove_var("QUERY_RESULT_CACHE_DIR");
+    std::env::remove_var("QUERY_RESOURCE_GROUPS");
+    std::env::remove_var("QUERY_RESOURCE_GROUP_ADMINS");
+    std::env::remove_var("QUERY_LOCAL_STAGE_DIR");
     std::env::remove_var("QUERY_CLICKHOUSE_HANDLER_THREAD_NUM");
     std::env::remove_var("QUERY_FLIGHT_API_ADDRESS");
     std::env::remove_var("QUERY_HTTP_API_ADDRESS");
@@ -0,0 +1,39 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CopiedFiles;
+
+/// Adds the files of a COPY INTO to the files recorded by the table, unless one of them
+/// has been recorded by another COPY in the meantime and FORCE is not set.
+pub fn record_copied_files(
+    recorded: &mut HashSet<String>,
+    copied: &CopiedFiles,
+    db: &str,
+    tbl: &str,
+) -> Result<()> {
+    if !copied.force {
+        if let Some(file) = copied.files.iter().find(|file| recorded.contains(*file)) {
+            return Err(ErrorCode::CommitTableConflict(format!(
+                "File {} has been loaded into table {}.{} by another COPY",
+                file, db, tbl
+            )));
+        }
+    }
+    recorded.extend(copied.files.iter().cloned());
+    Ok(())
+}
@@ -17,9 +17,18 @@
 mod line_test;
 #[cfg(test)]
 mod part_test;
+#[cfg(test)]
+mod schema_evolution_test;
 
+mod copied_files;
 mod line;
 mod part;
+mod schema_evolution;
 
+pub use copied_files::record_copied_files;
 pub use line::count_lines;
 pub use part::generate_parts;
+pub use schema_evolution::alter_schema;
+pub use schema_evolution::column_default;
+pub use schema_evolution::column_ids;
+pub use schema_evolution::is_widening;
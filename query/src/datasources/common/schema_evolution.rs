@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::columns::DataColumn;
+use common_datavalues::is_numeric;
+use common_datavalues::numerical_coercion;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableAction;
+
+// The schema metadata keys used to keep track of the columns of a table across `ALTER TABLE`.
+// Columns are identified by ids which are never reused, so that the data files written before
+// a column is dropped, renamed or modified can still be mapped to the current schema.
+const COLUMN_IDS: &str = "COLUMN_IDS";
+const NEXT_COLUMN_ID: &str = "NEXT_COLUMN_ID";
+const COLUMN_DEFAULT_PREFIX: &str = "COLUMN_DEFAULT:";
+
+/// Returns the id of each field of the schema.
+/// A schema which has never been altered uses the position of the field as its id.
+pub fn column_ids(schema: &DataSchema) -> Vec<u32> {
+    let ids = schema.meta().get(COLUMN_IDS).and_then(|ids| {
+        ids.split(',')
+            .filter(|id| !id.is_empty())
+            .map(|id| id.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()
+    });
+
+    match ids {
+        Some(ids) if ids.len() == schema.fields().len() => ids,
+        _ => (0..schema.fields().len() as u32).collect(),
+    }
+}
+
+/// Returns the value of the field at `index` for the rows written before the column was added,
+/// which is the NULL of the field type if the column has no default.
+pub fn column_default(schema: &DataSchema, index: usize) -> Result<DataValue> {
+    let id = column_ids(schema)[index];
+    match schema.meta().get(&default_key(id)) {
+        Some(v) => Ok(serde_json::from_str(v)?),
+        None => Ok(DataValue::from(schema.field(index).data_type())),
+    }
+}
+
+/// Whether the values of type `from` can be converted to `to` without loss.
+pub fn is_widening(from: &DataType, to: &DataType) -> bool {
+    if from == to {
+        return true;
+    }
+
+    match (from, to) {
+        (DataType::Date16, DataType::Date32) => true,
+        _ if is_numeric(from) && is_numeric(to) => {
+            matches!(numerical_coercion(from, to, false), Ok(dt) if &dt == to)
+        }
+        _ => false,
+    }
+}
+
+/// Applies the action to the schema of a table, returns the schema after altered.
+pub fn alter_schema(schema: &DataSchema, action: &AlterTableAction) -> Result<DataSchema> {
+    let mut fields = schema.fields().clone();
+    let mut ids = column_ids(schema);
+    let mut next_id = match schema.meta().get(NEXT_COLUMN_ID) {
+        Some(id) => id.parse::<u32>().map_err(|e| {
+            ErrorCode::IllegalSchema(format!("Invalid {} {}: {}", NEXT_COLUMN_ID, id, e))
+        })?,
+        None => ids.iter().max().map(|id| id + 1).unwrap_or(0),
+    };
+    let mut meta = schema
+        .meta()
+        .iter()
+        .filter(|(k, _)| k.as_str() != COLUMN_IDS && k.as_str() != NEXT_COLUMN_ID)
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let index_of = |fields: &[DataField], name: &str| {
+        fields
+            .iter()
+            .position(|f| f.name() == name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Unknown column {}", name)))
+    };
+    let check_absent =
+        |fields: &[DataField], name: &str| match fields.iter().any(|f| f.name() == name) {
+            true => Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists",
+                name
+            ))),
+            false => Ok(()),
+        };
+
+    match action {
+        AlterTableAction::AddColumn { field, default } => {
+            check_absent(&fields, field.name())?;
+            if let Some(default) = default {
+                meta.insert(default_key(next_id), serde_json::to_string(default)?);
+            }
+            fields.push(field.clone());
+            ids.push(next_id);
+            next_id += 1;
+        }
+        AlterTableAction::DropColumn { name } => {
+            let index = index_of(&fields, name)?;
+            if fields.len() == 1 {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Can not drop the only column {}",
+                    name
+                )));
+            }
+            fields.remove(index);
+            meta.remove(&default_key(ids.remove(index)));
+        }
+        AlterTableAction::RenameColumn { name, new_name } => {
+            let index = index_of(&fields, name)?;
+            check_absent(&fields, new_name)?;
+            let field = &fields[index];
+            fields[index] =
+                DataField::new(new_name, field.data_type().clone(), field.is_nullable());
+        }
+        AlterTableAction::ModifyColumn { name, data_type } => {
+            let index = index_of(&fields, name)?;
+            let field = &fields[index];
+            if !is_widening(field.data_type(), data_type) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Can not modify column {} from {} to {}, only widening of the type is supported",
+                    name,
+                    field.data_type(),
+                    data_type
+                )));
+            }
+
+            let key = default_key(ids[index]);
+            if let Some(v) = meta.get(&key) {
+                let default: DataValue = serde_json::from_str(v)?;
+                let default = DataColumn::Constant(default, 1)
+                    .cast_with_type(data_type)?
+                    .try_get(0)?;
+                meta.insert(key, serde_json::to_string(&default)?);
+            }
+            fields[index] = DataField::new(name, data_type.clone(), field.is_nullable());
+        }
+        AlterTableAction::RenameTable { .. } => return Ok(schema.clone()),
+    }
+
+    let ids = ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    meta.insert(COLUMN_IDS.to_string(), ids.join(","));
+    meta.insert(NEXT_COLUMN_ID.to_string(), next_id.to_string());
+    Ok(DataSchema::new_from(fields, meta))
+}
+
+fn default_key(id: u32) -> String {
+    format!("{}{}", COLUMN_DEFAULT_PREFIX, id)
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_planners::AlterTableAction;
+use pretty_assertions::assert_eq;
+
+use crate::datasources::common::alter_schema;
+use crate::datasources::common::column_default;
+use crate::datasources::common::column_ids;
+use crate::datasources::common::is_widening;
+
+#[test]
+fn test_schema_evolution() -> Result<()> {
+    let schema = DataSchema::new(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, true),
+    ]);
+    assert_eq!(vec![0, 1], column_ids(&schema));
+
+    // add column with default
+    let schema = alter_schema(&schema, &AlterTableAction::AddColumn {
+        field: DataField::new("c", DataType::Int32, true),
+        default: Some(DataValue::Int32(Some(3))),
+    })?;
+    assert_eq!(vec![0, 1, 2], column_ids(&schema));
+    assert_eq!(DataValue::Int32(Some(3)), column_default(&schema, 2)?);
+    assert_eq!(DataValue::String(None), column_default(&schema, 1)?);
+
+    // drop column, the id is never reused
+    let schema = alter_schema(&schema, &AlterTableAction::DropColumn {
+        name: "b".to_string(),
+    })?;
+    let schema = alter_schema(&schema, &AlterTableAction::AddColumn {
+        field: DataField::new("b", DataType::UInt8, true),
+        default: None,
+    })?;
+    assert_eq!(vec![0, 2, 3], column_ids(&schema));
+    assert_eq!(DataValue::UInt8(None), column_default(&schema, 2)?);
+
+    // rename column keeps the id
+    let schema = alter_schema(&schema, &AlterTableAction::RenameColumn {
+        name: "a".to_string(),
+        new_name: "x".to_string(),
+    })?;
+    assert_eq!("x", schema.field(0).name());
+    assert_eq!(vec![0, 2, 3], column_ids(&schema));
+
+    // modify column casts the default
+    let schema = alter_schema(&schema, &AlterTableAction::ModifyColumn {
+        name: "c".to_string(),
+        data_type: DataType::Int64,
+    })?;
+    assert_eq!(&DataType::Int64, schema.field(1).data_type());
+    assert_eq!(DataValue::Int64(Some(3)), column_default(&schema, 1)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_evolution_errors() -> Result<()> {
+    let schema = DataSchema::new(vec![DataField::new("a", DataType::Int64, false)]);
+
+    struct Test {
+        name: &'static str,
+        action: AlterTableAction,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        Test {
+            name: "add-existing-column",
+            action: AlterTableAction::AddColumn {
+                field: DataField::new("a", DataType::Int32, true),
+                default: None,
+            },
+            expect: "Code: 6, displayText = Column a already exists.",
+        },
+        Test {
+            name: "drop-unknown-column",
+            action: AlterTableAction::DropColumn {
+                name: "b".to_string(),
+            },
+            expect: "Code: 6, displayText = Unknown column b.",
+        },
+        Test {
+            name: "drop-only-column",
+            action: AlterTableAction::DropColumn {
+                name: "a".to_string(),
+            },
+            expect: "Code: 6, displayText = Can not drop the only column a.",
+        },
+        Test {
+            name: "modify-column-narrowing",
+            action: AlterTableAction::ModifyColumn {
+                name: "a".to_string(),
+                data_type: DataType::Int32,
+            },
+            expect: "Code: 6, displayText = Can not modify column a from Int64 to Int32, only widening of the type is supported.",
+        },
+    ];
+
+    for t in tests {
+        let result = alter_schema(&schema, &t.action);
+        assert_eq!(t.expect, result.unwrap_err().to_string(), "{}", t.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_schema_evolution_widening() -> Result<()> {
+    assert!(is_widening(&DataType::Int32, &DataType::Int64));
+    assert!(is_widening(&DataType::UInt32, &DataType::Int64));
+    assert!(is_widening(&DataType::Float32, &DataType::Float64));
+    assert!(is_widening(&DataType::Date16, &DataType::Date32));
+    assert!(!is_widening(&DataType::Int64, &DataType::Int32));
+    assert!(!is_widening(&DataType::Int64, &DataType::String));
+    Ok(())
+}
@@ -43,6 +43,9 @@ pub trait DataAccessor: Send + Sync {
 
     async fn get(&self, path: &str) -> Result<Bytes>;
 
+    /// List the paths of the files under the prefix, recursively.
+    async fn list(&self, prefix: &str) -> Result<Vec<String>>;
+
     async fn put(&self, path: &str, content: Vec<u8>) -> Result<()>;
 
     async fn put_stream(
@@ -53,4 +56,7 @@ pub trait DataAccessor: Send + Sync {
         >,
         stream_len: usize,
     ) -> Result<()>;
+
+    /// Remove the file at the path.
+    async fn remove(&self, path: &str) -> Result<()>;
 }
@@ -21,7 +21,9 @@ use futures::Stream;
 use futures::StreamExt;
 use rusoto_core::ByteStream;
 use rusoto_core::Region;
+use rusoto_s3::DeleteObjectRequest;
 use rusoto_s3::GetObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
@@ -38,7 +40,6 @@ pub struct S3 {
 }
 
 impl S3 {
-    #[allow(dead_code)]
     pub fn new(region: Region, bucket: String) -> Self {
         let client = S3Client::new(region);
         S3 { client, bucket }
@@ -115,6 +116,34 @@ impl DataAccessor for S3 {
         }
     }
 
+    async fn list(&self, prefix: &str) -> common_exception::Result<Vec<String>> {
+        let mut keys = vec![];
+        let mut continuation_token = None;
+        loop {
+            let req = ListObjectsV2Request {
+                bucket: self.bucket.to_string(),
+                prefix: Some(prefix.to_string()),
+                continuation_token,
+                ..Default::default()
+            };
+            let output = self
+                .client
+                .list_objects_v2(req)
+                .await
+                .map_err(|e| ErrorCode::DALTransportError(e.to_string()))?;
+
+            let objects = output.contents.unwrap_or_default();
+            keys.extend(objects.into_iter().filter_map(|object| object.key));
+
+            match output.next_continuation_token {
+                Some(token) if output.is_truncated == Some(true) => {
+                    continuation_token = Some(token)
+                }
+                _ => return Ok(keys),
+            }
+        }
+    }
+
     async fn put(&self, path: &str, content: Vec<u8>) -> common_exception::Result<()> {
         self.put_byte_stream(path, ByteStream::from(content)).await
     }
@@ -131,4 +160,17 @@ impl DataAccessor for S3 {
         self.put_byte_stream(path, ByteStream::new_with_size(s, stream_len))
             .await
     }
+
+    async fn remove(&self, path: &str) -> common_exception::Result<()> {
+        let req = DeleteObjectRequest {
+            key: path.to_string(),
+            bucket: self.bucket.to_string(),
+            ..Default::default()
+        };
+        self.client
+            .delete_object(req)
+            .await
+            .map_err(|e| ErrorCode::DALTransportError(e.to_string()))?;
+        Ok(())
+    }
 }
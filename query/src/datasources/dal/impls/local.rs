@@ -26,6 +26,7 @@ use futures::Stream;
 use futures::StreamExt;
 use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
+use walkdir::WalkDir;
 
 use crate::datasources::dal::Bytes;
 use crate::datasources::dal::DataAccessor;
@@ -62,10 +63,18 @@ impl Local {
 #[async_trait::async_trait]
 impl DataAccessor for Local {
     fn get_reader(&self, path: &str, _len: Option<u64>) -> Result<Box<dyn SeekableReader>> {
+        let path = self.prefix_with_root(path)?;
         Ok(Box::new(std::fs::File::open(path)?))
     }
 
     fn get_writer(&self, path: &str) -> common_exception::Result<Box<dyn Write>> {
+        // the file does not exist yet, so the parent is checked instead
+        let path = self.root.join(path);
+        let parent = path
+            .parent()
+            .ok_or_else(|| ErrorCode::UnknownException(""))?; // TODO customized error code
+        std::fs::create_dir_all(parent)?;
+        self.prefix_with_root(&parent.to_string_lossy())?;
         Ok(Box::new(std::fs::File::create(path)?))
     }
 
@@ -82,6 +91,25 @@ impl DataAccessor for Local {
         Ok(contents)
     }
 
+    async fn list(&self, prefix: &str) -> Result<Vec<String>> {
+        let path = self.prefix_with_root(prefix)?;
+        let mut files = vec![];
+        for entry in WalkDir::new(path) {
+            let entry = entry.map_err(|e| ErrorCode::from(Error::from(e)))?;
+            if entry.file_type().is_file() {
+                let file = entry.path().strip_prefix(&self.root).map_err(|e| {
+                    ErrorCode::UnknownException(format!(
+                        "Unexpected path {:?}: {}",
+                        entry.path(),
+                        e
+                    ))
+                })?;
+                files.push(file.to_string_lossy().to_string());
+            }
+        }
+        Ok(files)
+    }
+
     // not "atomic", for test purpose only
     async fn put(&self, path: &str, content: Vec<u8>) -> common_exception::Result<()> {
         let path = self.prefix_with_root(path)?;
@@ -115,4 +143,10 @@ impl DataAccessor for Local {
         }
         Ok(())
     }
+
+    async fn remove(&self, path: &str) -> Result<()> {
+        let path = self.prefix_with_root(path)?;
+        tokio::fs::remove_file(path).await?;
+        Ok(())
+    }
 }
@@ -19,6 +19,7 @@ use common_exception::ErrorCode;
 use common_infallible::RwLock;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
@@ -153,4 +154,16 @@ impl Database for DefaultDatabase {
     fn drop_table(&self, plan: DropTablePlan) -> common_exception::Result<()> {
         self.meta_store_client.drop_table(plan)
     }
+
+    fn alter_table(&self, plan: AlterTablePlan) -> common_exception::Result<()> {
+        // The data of the stateful tables is kept by the cached instance, in the old schema
+        if let Some(meta) = self.stateful_table_cache.read().get_by_name(&plan.table) {
+            return Err(ErrorCode::UnImplement(format!(
+                "alter table for table {} of engine {} is not implemented",
+                plan.table,
+                meta.raw().engine()
+            )));
+        }
+        self.meta_store_client.alter_table(plan)
+    }
 }
@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
@@ -122,4 +123,8 @@ impl Database for ExampleDatabase {
     fn drop_table(&self, plan: DropTablePlan) -> Result<()> {
         self.meta_store_client.drop_table(plan)
     }
+
+    fn alter_table(&self, plan: AlterTablePlan) -> Result<()> {
+        self.meta_store_client.alter_table(plan)
+    }
 }
@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metatypes::MetaId;
+use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
+use common_planners::CreateTablePlan;
+use common_planners::DropTablePlan;
+
+use crate::catalogs::impls::util::in_memory_metas::InMemoryMetas;
+use crate::catalogs::impls::INFORMATION_SCHEMA_TBL_ID_BEGIN;
+use crate::catalogs::impls::SYS_TBL_ID_END;
+use crate::catalogs::Database;
+use crate::catalogs::Table;
+use crate::catalogs::TableFunctionMeta;
+use crate::catalogs::TableMeta;
+use crate::datasources::database::information_schema::SessionVariablesTable;
+use crate::datasources::table::ViewTable;
+
+/// The MySQL information_schema, its tables are views of the system tables, so that the
+/// MySQL drivers and the BI tools are able to introspect the databases.
+/// The table names are case insensitive, as in MySQL.
+pub struct InformationSchemaDatabase {
+    tables: InMemoryMetas,
+}
+
+impl InformationSchemaDatabase {
+    pub fn create() -> Self {
+        let string = |name: &str| DataField::new(name, DataType::String, false);
+        let views = vec![
+            (
+                "schemata",
+                vec![
+                    string("CATALOG_NAME"),
+                    string("SCHEMA_NAME"),
+                    string("DEFAULT_CHARACTER_SET_NAME"),
+                    string("DEFAULT_COLLATION_NAME"),
+                ],
+                "SELECT 'def' AS CATALOG_NAME, name AS SCHEMA_NAME, \
+                'utf8mb4' AS DEFAULT_CHARACTER_SET_NAME, \
+                'utf8mb4_general_ci' AS DEFAULT_COLLATION_NAME \
+                FROM system.databases",
+            ),
+            (
+                "tables",
+                vec![
+                    string("TABLE_CATALOG"),
+                    string("TABLE_SCHEMA"),
+                    string("TABLE_NAME"),
+                    string("TABLE_TYPE"),
+                    string("ENGINE"),
+                    string("TABLE_COMMENT"),
+                ],
+                "SELECT 'def' AS TABLE_CATALOG, database AS TABLE_SCHEMA, name AS TABLE_NAME, \
+                if(engine = 'View', 'VIEW', 'BASE TABLE') AS TABLE_TYPE, engine AS ENGINE, \
+                '' AS TABLE_COMMENT \
+                FROM system.tables",
+            ),
+            (
+                "columns",
+                vec![
+                    string("TABLE_CATALOG"),
+                    string("TABLE_SCHEMA"),
+                    string("TABLE_NAME"),
+                    string("COLUMN_NAME"),
+                    DataField::new("ORDINAL_POSITION", DataType::UInt64, false),
+                    DataField::new("COLUMN_DEFAULT", DataType::Null, true),
+                    string("IS_NULLABLE"),
+                    string("DATA_TYPE"),
+                    string("COLUMN_TYPE"),
+                    string("COLUMN_KEY"),
+                    string("EXTRA"),
+                    string("COLUMN_COMMENT"),
+                ],
+                "SELECT 'def' AS TABLE_CATALOG, database AS TABLE_SCHEMA, \"table\" AS TABLE_NAME, \
+                name AS COLUMN_NAME, position AS ORDINAL_POSITION, NULL AS COLUMN_DEFAULT, \
+                is_nullable AS IS_NULLABLE, type AS DATA_TYPE, type AS COLUMN_TYPE, \
+                '' AS COLUMN_KEY, '' AS EXTRA, '' AS COLUMN_COMMENT \
+                FROM system.columns",
+            ),
+            // There are no indexes
+            (
+                "statistics",
+                vec![
+                    string("TABLE_CATALOG"),
+                    string("TABLE_SCHEMA"),
+                    string("TABLE_NAME"),
+                    DataField::new("NON_UNIQUE", DataType::UInt8, false),
+                    string("INDEX_SCHEMA"),
+                    string("INDEX_NAME"),
+                    DataField::new("SEQ_IN_INDEX", DataType::UInt8, false),
+                    string("COLUMN_NAME"),
+                    string("INDEX_TYPE"),
+                    string("COMMENT"),
+                ],
+                "SELECT 'def' AS TABLE_CATALOG, database AS TABLE_SCHEMA, name AS TABLE_NAME, \
+                0 AS NON_UNIQUE, database AS INDEX_SCHEMA, '' AS INDEX_NAME, 0 AS SEQ_IN_INDEX, \
+                '' AS COLUMN_NAME, '' AS INDEX_TYPE, '' AS COMMENT \
+                FROM system.tables WHERE 1 = 0",
+            ),
+        ];
+
+        let mut table_list: Vec<Arc<dyn Table>> = vec![Arc::new(SessionVariablesTable::create())];
+        for (name, fields, query) in views {
+            let view = ViewTable::try_create(
+                "information_schema".to_string(),
+                name.to_string(),
+                DataSchemaRefExt::create(fields),
+                ViewTable::view_options(query, None),
+            )
+            .expect("information_schema view miss-assemblied");
+            table_list.push(Arc::from(view));
+        }
+
+        let mut tables = InMemoryMetas::create();
+        for (id, table) in (INFORMATION_SCHEMA_TBL_ID_BEGIN..SYS_TBL_ID_END).zip(table_list) {
+            tables.insert(TableMeta::create(table, id));
+        }
+        InformationSchemaDatabase { tables }
+    }
+}
+
+impl Database for InformationSchemaDatabase {
+    fn name(&self) -> &str {
+        "information_schema"
+    }
+
+    fn engine(&self) -> &str {
+        "local"
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn get_table(&self, table_name: &str) -> Result<Arc<TableMeta>> {
+        let table = self
+            .tables
+            .name2meta
+            .get(&table_name.to_lowercase())
+            .ok_or_else(|| ErrorCode::UnknownTable(format!("Unknown table: '{}'", table_name)))?;
+        Ok(table.clone())
+    }
+
+    fn exists_table(&self, table_name: &str) -> Result<bool> {
+        Ok(self
+            .tables
+            .name2meta
+            .get(&table_name.to_lowercase())
+            .is_some())
+    }
+
+    fn get_table_by_id(
+        &self,
+        table_id: MetaId,
+        _table_version: Option<MetaVersion>,
+    ) -> Result<Arc<TableMeta>> {
+        let table =
+            self.tables.id2meta.get(&table_id).ok_or_else(|| {
+                ErrorCode::UnknownTable(format!("Unknown table id: '{}'", table_id))
+            })?;
+        Ok(table.clone())
+    }
+
+    fn get_tables(&self) -> Result<Vec<Arc<TableMeta>>> {
+        Ok(self.tables.name2meta.values().cloned().collect())
+    }
+
+    fn get_table_functions(&self) -> Result<Vec<Arc<TableFunctionMeta>>> {
+        Ok(vec![])
+    }
+
+    fn create_table(&self, _plan: CreateTablePlan) -> Result<()> {
+        Result::Err(ErrorCode::UnImplement(
+            "Cannot create table for information_schema database",
+        ))
+    }
+
+    fn drop_table(&self, _plan: DropTablePlan) -> Result<()> {
+        Result::Err(ErrorCode::UnImplement(
+            "Cannot drop table for information_schema database",
+        ))
+    }
+
+    fn alter_table(&self, _plan: AlterTablePlan) -> Result<()> {
+        Result::Err(ErrorCode::UnImplement(
+            "Cannot alter table for information_schema database",
+        ))
+    }
+}
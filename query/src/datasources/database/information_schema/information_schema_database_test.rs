@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_information_schema_database() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    let tests = vec![
+        (
+            "SELECT SCHEMA_NAME, DEFAULT_CHARACTER_SET_NAME FROM information_schema.SCHEMATA",
+            vec![
+                "+--------------------+----------------------------+",
+                "| SCHEMA_NAME        | DEFAULT_CHARACTER_SET_NAME |",
+                "+--------------------+----------------------------+",
+                "| default            | utf8mb4                    |",
+                "| information_schema | utf8mb4                    |",
+                "| system             | utf8mb4                    |",
+                "+--------------------+----------------------------+",
+            ],
+        ),
+        (
+            "SELECT TABLE_NAME, TABLE_TYPE FROM information_schema.tables WHERE TABLE_SCHEMA = 'information_schema'",
+            vec![
+                "+-------------------+------------+",
+                "| TABLE_NAME        | TABLE_TYPE |",
+                "+-------------------+------------+",
+                "| columns           | VIEW       |",
+                "| schemata          | VIEW       |",
+                "| session_variables | BASE TABLE |",
+                "| statistics        | VIEW       |",
+                "| tables            | VIEW       |",
+                "+-------------------+------------+",
+            ],
+        ),
+        (
+            "SELECT COLUMN_NAME, ORDINAL_POSITION, DATA_TYPE FROM information_schema.columns \
+            WHERE TABLE_SCHEMA = 'system' AND TABLE_NAME = 'databases'",
+            vec![
+                "+-------------+------------------+-----------+",
+                "| COLUMN_NAME | ORDINAL_POSITION | DATA_TYPE |",
+                "+-------------+------------------+-----------+",
+                "| name        | 1                | String    |",
+                "+-------------+------------------+-----------+",
+            ],
+        ),
+        (
+            "SELECT VARIABLE_VALUE FROM information_schema.session_variables \
+            WHERE VARIABLE_NAME = 'autocommit' OR VARIABLE_NAME = 'character_set_client'",
+            vec![
+                "+----------------+",
+                "| VARIABLE_VALUE |",
+                "+----------------+",
+                "| 1              |",
+                "| utf8mb4        |",
+                "+----------------+",
+            ],
+        ),
+    ];
+
+    for (query, expected) in tests {
+        if let PlanNode::Select(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
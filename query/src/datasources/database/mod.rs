@@ -18,5 +18,6 @@ mod tests;
 
 pub(crate) mod default;
 pub(crate) mod example;
+pub(crate) mod information_schema;
 pub(crate) mod prelude;
 pub(crate) mod system;
@@ -0,0 +1,143 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct ColumnsTable {
+    schema: DataSchemaRef,
+}
+
+impl ColumnsTable {
+    pub fn create() -> Self {
+        ColumnsTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("database", DataType::String, false),
+                DataField::new("table", DataType::String, false),
+                DataField::new("name", DataType::String, false),
+                DataField::new("type", DataType::String, false),
+                DataField::new("position", DataType::UInt64, false),
+                DataField::new("is_nullable", DataType::String, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ColumnsTable {
+    fn name(&self) -> &str {
+        "columns"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemColumns"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: scan.table_id,
+            table_version: scan.table_version,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.columns table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+            remote: false,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let mut databases: Vec<String> = vec![];
+        let mut tables: Vec<String> = vec![];
+        let mut names: Vec<String> = vec![];
+        let mut types: Vec<String> = vec![];
+        let mut positions: Vec<u64> = vec![];
+        let mut nulls: Vec<String> = vec![];
+        for database in ctx.get_catalog().get_databases()? {
+            for table in database.get_tables()? {
+                let schema = table.raw().schema()?;
+                for (index, field) in schema.fields().iter().enumerate() {
+                    databases.push(database.name().to_string());
+                    tables.push(table.raw().name().to_string());
+                    names.push(field.name().to_string());
+                    types.push(format!("{:?}", field.data_type()));
+                    positions.push(index as u64 + 1);
+                    nulls.push(match field.is_nullable() {
+                        true => "YES".to_string(),
+                        false => "NO".to_string(),
+                    });
+                }
+            }
+        }
+
+        let databases: Vec<&[u8]> = databases.iter().map(|x| x.as_bytes()).collect();
+        let tables: Vec<&[u8]> = tables.iter().map(|x| x.as_bytes()).collect();
+        let names: Vec<&[u8]> = names.iter().map(|x| x.as_bytes()).collect();
+        let types: Vec<&[u8]> = types.iter().map(|x| x.as_bytes()).collect();
+        let nulls: Vec<&[u8]> = nulls.iter().map(|x| x.as_bytes()).collect();
+        let block = DataBlock::create_by_array(self.schema.clone(), vec![
+            Series::new(databases),
+            Series::new(tables),
+            Series::new(names),
+            Series::new(types),
+            Series::new(positions),
+            Series::new(nulls),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            vec![block],
+        )))
+    }
+}
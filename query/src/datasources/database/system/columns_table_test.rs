@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+
+use crate::catalogs::Table;
+use crate::datasources::database::system::ColumnsTable;
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::sql::PlanParser;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let table = ColumnsTable::create();
+    let source_plan = table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_settings().get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 6);
+
+    if let PlanNode::Select(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+        "select * from system.columns where database = 'system' and \"table\" = 'tables'",
+    )? {
+        let executor = SelectInterpreter::try_create(ctx.clone(), plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+--------+----------+--------+----------+-------------+",
+            "| database | table  | name     | type   | position | is_nullable |",
+            "+----------+--------+----------+--------+----------+-------------+",
+            "| system   | tables | database | String | 1        | NO          |",
+            "| system   | tables | engine   | String | 3        | NO          |",
+            "| system   | tables | name     | String | 2        | NO          |",
+            "+----------+--------+----------+--------+----------+-------------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
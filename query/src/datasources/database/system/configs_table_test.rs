@@ -40,7 +40,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 31);
+    assert_eq!(block.num_rows(), 43);
 
     let expected = vec![
         "+-----------------------------------+----------------+-------+-------------+",
@@ -51,9 +51,14 @@ async fn test_configs_table() -> Result<()> {
         "| api_tls_server_root_ca_cert       |                | query |             |",
         "| clickhouse_handler_host           | 127.0.0.1      | query |             |",
         "| clickhouse_handler_port           | 9000           | query |             |",
+        "| clickhouse_handler_secure_port    | 9440           | query |             |",
         "| disable_local_database_engine     | 0              | query |             |",
         "| flight_api_address                | 127.0.0.1:9090 | query |             |",
+        "| handler_tls_server_cert           |                | query |             |",
+        "| handler_tls_server_key            |                | query |             |",
+        "| handler_tls_server_root_ca_cert   |                | query |             |",
         "| http_api_address                  | 127.0.0.1:8080 | query |             |",
+        "| local_stage_dir                   |                | query |             |",
         "| log_dir                           | ./_logs        | log   |             |",
         "| log_level                         | INFO           | log   |             |",
         "| max_active_sessions               | 256            | query |             |",
@@ -65,6 +70,13 @@ async fn test_configs_table() -> Result<()> {
         "| mysql_handler_port                | 3307           | query |             |",
         "| namespace                         |                | query |             |",
         "| num_cpus                          | 8              | query |             |",
+        "| query_log_flush_interval_ms       | 1000           | query |             |",
+        "| query_log_size                    | 10000          | query |             |",
+        "| query_log_table                   |                | query |             |",
+        "| resource_group_admins             | root           | query |             |",
+        "| resource_groups                   |                | query |             |",
+        "| result_cache_dir                  |                | query |             |",
+        "| result_cache_size                 | 1073741824     | query |             |",
         "| rpc_tls_meta_server_root_ca_cert  |                | meta  |             |",
         "| rpc_tls_meta_service_domain_name  | localhost      | meta  |             |",
         "| rpc_tls_query_server_root_ca_cert |                | query |             |",
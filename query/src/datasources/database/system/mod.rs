@@ -15,6 +15,8 @@
 #[cfg(test)]
 mod clusters_table_test;
 #[cfg(test)]
+mod columns_table_test;
+#[cfg(test)]
 mod configs_table_test;
 #[cfg(test)]
 mod contributors_table_test;
@@ -29,6 +31,10 @@ mod functions_table_test;
 #[cfg(test)]
 mod numbers_table_test;
 #[cfg(test)]
+mod query_log_table_test;
+#[cfg(test)]
+mod query_profile_table_test;
+#[cfg(test)]
 mod settings_table_test;
 #[cfg(test)]
 mod tables_table_test;
@@ -36,6 +42,7 @@ mod tables_table_test;
 mod tracing_table_test;
 
 mod clusters_table;
+mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
@@ -46,6 +53,8 @@ mod numbers_stream;
 mod numbers_table;
 mod one_table;
 mod processes_table;
+mod query_log_table;
+mod query_profile_table;
 mod settings_table;
 mod system_database;
 mod tables_table;
@@ -53,6 +62,7 @@ mod tracing_table;
 mod tracing_table_stream;
 
 pub use clusters_table::ClustersTable;
+pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
@@ -63,6 +73,8 @@ pub use numbers_stream::NumbersStream;
 pub use numbers_table::NumbersTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
+pub use query_log_table::QueryLogTable;
+pub use query_profile_table::QueryProfileTable;
 pub use settings_table::SettingsTable;
 pub use system_database::SystemDatabase;
 //pub use system_databases::SystemDatabases;
@@ -48,6 +48,8 @@ impl ProcessesTable {
                 DataField::new("state", DataType::String, false),
                 DataField::new("database", DataType::String, false),
                 DataField::new("extra_info", DataType::String, true),
+                DataField::new("resource_group", DataType::String, true),
+                DataField::new("memory_usage", DataType::UInt64, false),
             ]),
         }
     }
@@ -63,6 +65,11 @@ impl ProcessesTable {
             .clone()
             .map(|s| s.into_bytes())
     }
+
+    fn process_resource_group(process_info: &ProcessInfo) -> Option<Vec<u8>> {
+        let resource_group = process_info.resource_group.clone();
+        resource_group.map(|s| s.into_bytes())
+    }
 }
 
 #[async_trait::async_trait]
@@ -124,6 +131,8 @@ impl Table for ProcessesTable {
         let mut processes_state = Vec::with_capacity(processes_info.len());
         let mut processes_database = Vec::with_capacity(processes_info.len());
         let mut processes_extra_info = Vec::with_capacity(processes_info.len());
+        let mut processes_resource_group = Vec::with_capacity(processes_info.len());
+        let mut processes_memory_usage = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
@@ -132,6 +141,8 @@ impl Table for ProcessesTable {
             processes_database.push(process_info.database.clone().into_bytes());
             processes_host.push(ProcessesTable::process_host(process_info));
             processes_extra_info.push(ProcessesTable::process_extra_info(process_info));
+            processes_resource_group.push(ProcessesTable::process_resource_group(process_info));
+            processes_memory_usage.push(process_info.memory_usage);
         }
 
         let schema = self.schema.clone();
@@ -142,6 +153,8 @@ impl Table for ProcessesTable {
             Series::new(processes_state),
             Series::new(processes_database),
             Series::new(processes_extra_info),
+            Series::new(processes_resource_group),
+            Series::new(processes_memory_usage),
         ]);
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -0,0 +1,191 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::QueryLogElement;
+
+pub struct QueryLogTable {
+    schema: DataSchemaRef,
+}
+
+impl QueryLogTable {
+    pub fn create() -> Self {
+        QueryLogTable {
+            schema: QueryLogTable::log_schema(),
+        }
+    }
+
+    /// The schema of system.query_log, also expected by the table the logs are persisted to.
+    pub fn log_schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("type", DataType::String, false),
+            DataField::new("event_time", DataType::DateTime32(None), false),
+            DataField::new("query_start_time", DataType::DateTime32(None), false),
+            DataField::new("query_duration_ms", DataType::UInt64, false),
+            DataField::new("query_id", DataType::String, false),
+            DataField::new("query_kind", DataType::String, false),
+            DataField::new("query", DataType::String, false),
+            DataField::new("session_id", DataType::String, false),
+            DataField::new("session_type", DataType::String, false),
+            DataField::new("user", DataType::String, false),
+            DataField::new("client_address", DataType::String, true),
+            DataField::new("current_database", DataType::String, false),
+            DataField::new("read_rows", DataType::UInt64, false),
+            DataField::new("read_bytes", DataType::UInt64, false),
+            DataField::new("result_rows", DataType::UInt64, false),
+            DataField::new("result_bytes", DataType::UInt64, false),
+            DataField::new("exception_code", DataType::Int32, false),
+            DataField::new("exception", DataType::String, false),
+        ])
+    }
+
+    pub fn logs_to_block(schema: DataSchemaRef, logs: &[QueryLogElement]) -> DataBlock {
+        let mut types = Vec::with_capacity(logs.len());
+        let mut event_times = Vec::with_capacity(logs.len());
+        let mut start_times = Vec::with_capacity(logs.len());
+        let mut durations = Vec::with_capacity(logs.len());
+        let mut query_ids = Vec::with_capacity(logs.len());
+        let mut query_kinds = Vec::with_capacity(logs.len());
+        let mut queries = Vec::with_capacity(logs.len());
+        let mut session_ids = Vec::with_capacity(logs.len());
+        let mut session_types = Vec::with_capacity(logs.len());
+        let mut users = Vec::with_capacity(logs.len());
+        let mut client_addresses = Vec::with_capacity(logs.len());
+        let mut databases = Vec::with_capacity(logs.len());
+        let mut read_rows = Vec::with_capacity(logs.len());
+        let mut read_bytes = Vec::with_capacity(logs.len());
+        let mut result_rows = Vec::with_capacity(logs.len());
+        let mut result_bytes = Vec::with_capacity(logs.len());
+        let mut exception_codes = Vec::with_capacity(logs.len());
+        let mut exceptions = Vec::with_capacity(logs.len());
+
+        for log in logs {
+            types.push(log.log_type.as_str().as_bytes().to_vec());
+            event_times.push(log.event_time);
+            start_times.push(log.query_start_time);
+            durations.push(log.query_duration_ms);
+            query_ids.push(log.query_id.clone().into_bytes());
+            query_kinds.push(log.query_kind.clone().into_bytes());
+            queries.push(log.query.clone().into_bytes());
+            session_ids.push(log.session_id.clone().into_bytes());
+            session_types.push(log.session_type.clone().into_bytes());
+            users.push(log.user.clone().into_bytes());
+            client_addresses.push(log.client_address.clone().map(|s| s.into_bytes()));
+            databases.push(log.current_database.clone().into_bytes());
+            read_rows.push(log.read_rows);
+            read_bytes.push(log.read_bytes);
+            result_rows.push(log.result_rows);
+            result_bytes.push(log.result_bytes);
+            exception_codes.push(log.exception_code);
+            exceptions.push(log.exception.clone().into_bytes());
+        }
+
+        DataBlock::create_by_array(schema, vec![
+            Series::new(types),
+            Series::new(event_times),
+            Series::new(start_times),
+            Series::new(durations),
+            Series::new(query_ids),
+            Series::new(query_kinds),
+            Series::new(queries),
+            Series::new(session_ids),
+            Series::new(session_types),
+            Series::new(users),
+            Series::new(client_addresses),
+            Series::new(databases),
+            Series::new(read_rows),
+            Series::new(read_bytes),
+            Series::new(result_rows),
+            Series::new(result_bytes),
+            Series::new(exception_codes),
+            Series::new(exceptions),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryLogTable {
+    fn name(&self) -> &str {
+        "query_log"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemQueryLog"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: scan.table_id,
+            table_version: scan.table_version,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.query_log table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+            remote: false,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let logs = ctx.get_sessions_manager().get_query_log().get_logs();
+
+        let schema = self.schema.clone();
+        let block = QueryLogTable::logs_to_block(schema.clone(), &logs);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
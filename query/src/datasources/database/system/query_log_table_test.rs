@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+
+use crate::catalogs::Table;
+use crate::datasources::database::system::QueryLogTable;
+use crate::sessions::QueryLogElement;
+use crate::sessions::QueryLogType;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let query_log = ctx.get_sessions_manager().get_query_log();
+    for log_type in [QueryLogType::QueryStart, QueryLogType::QueryFinish] {
+        query_log.append_log(QueryLogElement {
+            log_type,
+            event_time: 1630000000,
+            query_start_time: 1630000000,
+            query_duration_ms: 0,
+            query_id: ctx.get_id(),
+            query_kind: "Select".to_string(),
+            query: "select 1".to_string(),
+            session_id: "session".to_string(),
+            session_type: "MySQL".to_string(),
+            user: "root".to_string(),
+            client_address: None,
+            current_database: "default".to_string(),
+            read_rows: 1,
+            read_bytes: 1,
+            result_rows: 1,
+            result_bytes: 1,
+            exception_code: 0,
+            exception: "".to_string(),
+        });
+    }
+
+    let table = QueryLogTable::create();
+    let source_plan = table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_settings().get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 18);
+    assert_eq!(block.num_rows(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::series::Series;
+use common_datavalues::series::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_planners::Part;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::Statistics;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct QueryProfileTable {
+    schema: DataSchemaRef,
+}
+
+impl QueryProfileTable {
+    pub fn create() -> Self {
+        QueryProfileTable {
+            schema: DataSchemaRefExt::create(vec![
+                DataField::new("query_id", DataType::String, false),
+                DataField::new("stage", DataType::String, false),
+                DataField::new("pipe", DataType::UInt64, false),
+                DataField::new("processor", DataType::String, false),
+                DataField::new("output_rows", DataType::UInt64, false),
+                DataField::new("output_bytes", DataType::UInt64, false),
+                DataField::new("elapsed_us", DataType::UInt64, false),
+                DataField::new("cpu_us", DataType::UInt64, false),
+            ]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryProfileTable {
+    fn name(&self) -> &str {
+        "query_profile"
+    }
+
+    fn engine(&self) -> &str {
+        "SystemQueryProfile"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Ok(ReadDataSourcePlan {
+            db: "system".to_string(),
+            table: self.name().to_string(),
+            table_id: scan.table_id,
+            table_version: scan.table_version,
+            schema: self.schema.clone(),
+            parts: vec![Part {
+                name: "".to_string(),
+                version: 0,
+            }],
+            statistics: Statistics::default(),
+            description: "(Read from system.query_profile table)".to_string(),
+            scan_plan: Arc::new(scan.clone()),
+            remote: false,
+        })
+    }
+
+    async fn read(
+        &self,
+        ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let profiles = ctx.get_sessions_manager().get_query_log().get_profiles();
+
+        let mut query_ids = Vec::with_capacity(profiles.len());
+        let mut stages = Vec::with_capacity(profiles.len());
+        let mut pipes = Vec::with_capacity(profiles.len());
+        let mut processors = Vec::with_capacity(profiles.len());
+        let mut output_rows = Vec::with_capacity(profiles.len());
+        let mut output_bytes = Vec::with_capacity(profiles.len());
+        let mut elapsed = Vec::with_capacity(profiles.len());
+        let mut cpu = Vec::with_capacity(profiles.len());
+
+        for profile in &profiles {
+            query_ids.push(profile.query_id.clone().into_bytes());
+            stages.push(profile.stage.clone().into_bytes());
+            pipes.push(profile.pipe);
+            processors.push(profile.processor.clone().into_bytes());
+            output_rows.push(profile.output_rows);
+            output_bytes.push(profile.output_bytes);
+            elapsed.push(profile.elapsed_us);
+            cpu.push(profile.cpu_us);
+        }
+
+        let schema = self.schema.clone();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(query_ids),
+            Series::new(stages),
+            Series::new(pipes),
+            Series::new(processors),
+            Series::new(output_rows),
+            Series::new(output_bytes),
+            Series::new(elapsed),
+            Series::new(cpu),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+
+use crate::catalogs::Table;
+use crate::datasources::database::system::QueryProfileTable;
+use crate::sessions::ProcessorProfile;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_profile_table() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let query_log = ctx.get_sessions_manager().get_query_log();
+    query_log.append_profiles(vec![
+        ProcessorProfile::create(0, "SourceTransform").to_element(&ctx.get_id(), ""),
+        ProcessorProfile::create(1, "ProjectionTransform").to_element(&ctx.get_id(), ""),
+    ]);
+
+    let table = QueryProfileTable::create();
+    let source_plan = table.read_plan(
+        ctx.clone(),
+        &ScanPlan::empty(),
+        ctx.get_settings().get_max_threads()? as usize,
+    )?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 8);
+    assert_eq!(block.num_rows(), 2);
+
+    Ok(())
+}
@@ -19,12 +19,13 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_metatypes::MetaId;
 use common_metatypes::MetaVersion;
+use common_planners::AlterTablePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropTablePlan;
 
 use crate::catalogs::impls::util::in_memory_metas::InMemoryMetas;
+use crate::catalogs::impls::INFORMATION_SCHEMA_TBL_ID_BEGIN;
 use crate::catalogs::impls::SYS_TBL_ID_BEGIN;
-use crate::catalogs::impls::SYS_TBL_ID_END;
 use crate::catalogs::Database;
 use crate::catalogs::Table;
 use crate::catalogs::TableFunction;
@@ -41,8 +42,8 @@ impl SystemDatabase {
     pub fn create() -> Self {
         let mut id = SYS_TBL_ID_BEGIN;
         let mut next_id = || -> u64 {
-            // 10000 table ids reserved for system tables
-            if id >= SYS_TBL_ID_END {
+            // 5000 table ids reserved for system tables, the others for information_schema
+            if id >= INFORMATION_SCHEMA_TBL_ID_BEGIN {
                 // Fatal error, gives up
                 panic!("system table id used up")
             } else {
@@ -63,11 +64,14 @@ impl SystemDatabase {
             Arc::new(system::NumbersTable::create("numbers_mt")),
             Arc::new(system::NumbersTable::create("numbers_local")),
             Arc::new(system::TablesTable::create()),
+            Arc::new(system::ColumnsTable::create()),
             Arc::new(system::ClustersTable::create()),
             Arc::new(system::DatabasesTable::create()),
             Arc::new(system::TracingTable::create()),
             Arc::new(system::ProcessesTable::create()),
             Arc::new(system::ConfigsTable::create()),
+            Arc::new(system::QueryLogTable::create()),
+            Arc::new(system::QueryProfileTable::create()),
         ];
         let tbl_meta_list = table_list
             .iter()
@@ -162,4 +166,10 @@ impl Database for SystemDatabase {
             "Cannot drop table for system database",
         ))
     }
+
+    fn alter_table(&self, _plan: AlterTablePlan) -> Result<()> {
+        Result::Err(ErrorCode::UnImplement(
+            "Cannot alter table for system database",
+        ))
+    }
 }
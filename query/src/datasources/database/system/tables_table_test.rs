@@ -36,25 +36,33 @@ async fn test_tables_table() -> Result<()> {
     assert_eq!(block.num_columns(), 3);
 
     let expected = vec![
-        "+----------+---------------+--------------------+",
-        "| database | name          | engine             |",
-        "+----------+---------------+--------------------+",
-        "| system   | clusters      | SystemClusters     |",
-        "| system   | configs       | SystemConfigs      |",
-        "| system   | contributors  | SystemContributors |",
-        "| system   | credits       | SystemCredits      |",
-        "| system   | databases     | SystemDatabases    |",
-        "| system   | engines       | SystemEngines      |",
-        "| system   | functions     | SystemFunctions    |",
-        "| system   | numbers       | SystemNumbers      |",
-        "| system   | numbers_local | SystemNumbersLocal |",
-        "| system   | numbers_mt    | SystemNumbersMt    |",
-        "| system   | one           | SystemOne          |",
-        "| system   | processes     | SystemProcesses    |",
-        "| system   | settings      | SystemSettings     |",
-        "| system   | tables        | SystemTables       |",
-        "| system   | tracing       | SystemTracing      |",
-        "+----------+---------------+--------------------+",
+        "+--------------------+-------------------+--------------------+",
+        "| database           | name              | engine             |",
+        "+--------------------+-------------------+--------------------+",
+        "| information_schema | columns           | View               |",
+        "| information_schema | schemata          | View               |",
+        "| information_schema | session_variables | SessionVariables   |",
+        "| information_schema | statistics        | View               |",
+        "| information_schema | tables            | View               |",
+        "| system             | clusters          | SystemClusters     |",
+        "| system             | columns           | SystemColumns      |",
+        "| system             | configs           | SystemConfigs      |",
+        "| system             | contributors      | SystemContributors |",
+        "| system             | credits           | SystemCredits      |",
+        "| system             | databases         | SystemDatabases    |",
+        "| system             | engines           | SystemEngines      |",
+        "| system             | functions         | SystemFunctions    |",
+        "| system             | numbers           | SystemNumbers      |",
+        "| system             | numbers_local     | SystemNumbersLocal |",
+        "| system             | numbers_mt        | SystemNumbersMt    |",
+        "| system             | one               | SystemOne          |",
+        "| system             | processes         | SystemProcesses    |",
+        "| system             | query_log         | SystemQueryLog     |",
+        "| system             | query_profile     | SystemQueryProfile |",
+        "| system             | settings          | SystemSettings     |",
+        "| system             | tables            | SystemTables       |",
+        "| system             | tracing           | SystemTracing      |",
+        "+--------------------+-------------------+--------------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
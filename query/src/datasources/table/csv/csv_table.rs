@@ -37,6 +37,7 @@ pub struct CsvTable {
     name: String,
     schema: DataSchemaRef,
     file: String,
+    options: TableOptions,
     has_header: bool,
 }
 
@@ -63,6 +64,7 @@ impl CsvTable {
             schema,
             file,
             has_header,
+            options,
         }))
     }
 }
@@ -89,6 +91,10 @@ impl Table for CsvTable {
         true
     }
 
+    fn options(&self) -> TableOptions {
+        self.options.clone()
+    }
+
     fn read_plan(
         &self,
         ctx: DatabendQueryContextRef,
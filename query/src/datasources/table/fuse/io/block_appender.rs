@@ -15,19 +15,21 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::arrow::io::parquet::write::*;
 use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::parquet::metadata::KeyValue;
 use common_datablocks::DataBlock;
 use common_datavalues::columns::DataColumn;
+use common_datavalues::DataSchema;
 use common_datavalues::DataType;
-use common_dfs_api_vo::BlockStream;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::InsertInputStream;
 use futures::StreamExt;
 use uuid::Uuid;
 
+use crate::datasources::common::column_ids;
 use crate::datasources::dal::DataAccessor;
 use crate::datasources::table::fuse::block_location;
 use crate::datasources::table::fuse::column_stats_reduce;
@@ -44,7 +46,7 @@ impl FuseTable {
     pub async fn append_blocks(
         &self,
         ctx: DatabendQueryContextRef,
-        mut stream: BlockStream,
+        mut stream: InsertInputStream,
     ) -> Result<SegmentInfo> {
         let mut block_metas = vec![];
         let mut blocks_stats = vec![];
@@ -53,9 +55,13 @@ impl FuseTable {
         let mut summary_uncompressed_byte_size = 0u64;
         let mut summary_compressed_byte_size = 0u64;
 
+        let column_ids = column_ids(&self.schema);
         while let Some(block) = stream.next().await {
-            let schema = block.schema().to_arrow();
-            let blk_stats = block_stats(&block)?;
+            let block = block?;
+            // the data schema of the block is kept in the file, with the column ids of the table
+            let schema =
+                DataSchema::new_from(block.schema().fields().clone(), self.schema.meta().clone());
+            let blk_stats = block_stats(&block, &column_ids)?;
 
             let row_count = block.num_rows() as u64;
             let block_in_memory_size = block.memory_size() as u64;
@@ -82,6 +88,7 @@ impl FuseTable {
                 },
                 row_count,
                 block_size: block_in_memory_size,
+                file_size,
                 col_stats,
             };
 
@@ -109,12 +116,13 @@ impl FuseTable {
     }
 }
 
-pub fn block_stats(data_block: &DataBlock) -> Result<HashMap<ColumnId, (DataType, ColStats)>> {
-    // TODO column id is FAKED, this is OK as long as table schema is NOT changed, which is not realistic
-    // we should extend DataField with column_id ...
-    (0..).into_iter().zip(data_block.columns().iter()).try_fold(
+pub fn block_stats(
+    data_block: &DataBlock,
+    column_ids: &[ColumnId],
+) -> Result<HashMap<ColumnId, (DataType, ColStats)>> {
+    column_ids.iter().zip(data_block.columns().iter()).try_fold(
         HashMap::new(),
-        |mut res, (idx, col)| {
+        |mut res, (id, col)| {
             let data_type = col.data_type();
             let min = match col {
                 DataColumn::Array(s) => s.min(),
@@ -141,14 +149,17 @@ pub fn block_stats(data_block: &DataBlock) -> Result<HashMap<ColumnId, (DataType
                 max,
                 null_count,
             };
-            res.insert(idx, (data_type, col_stats));
+            res.insert(*id, (data_type, col_stats));
             Ok(res)
         },
     )
 }
 
+/// The key of the parquet file metadata, which keeps the data schema of the block.
+pub const DATA_SCHEMA_KEY: &str = "DATA_SCHEMA";
+
 pub(crate) fn save_block(
-    arrow_schema: &ArrowSchema,
+    data_schema: &DataSchema,
     block: DataBlock,
     data_accessor: Arc<dyn DataAccessor>,
     location: &str,
@@ -164,12 +175,18 @@ pub(crate) fn save_block(
 
     let batch = RecordBatch::try_from(block)?;
     let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
+    let arrow_schema = data_schema.to_arrow();
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &arrow_schema, options, encodings)?;
     let parquet_schema = row_groups.parquet_schema().clone();
     let mut writer = data_accessor.get_writer(location)?;
 
     // arrow2 convert schema to metadata, is it required?
     // -- let key_value_metadata = Some(vec![schema_to_metadata_key(schema)]);
+    let key_value_metadata = Some(vec![KeyValue {
+        key: DATA_SCHEMA_KEY.to_string(),
+        value: Some(serde_json::to_string(data_schema)?),
+    }]);
 
     let len = common_arrow::parquet::write::write_file(
         &mut writer,
@@ -177,7 +194,7 @@ pub(crate) fn save_block(
         parquet_schema,
         options,
         None,
-        None,
+        key_value_metadata,
     )
     .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
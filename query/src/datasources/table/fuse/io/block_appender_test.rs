@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::Local;
+use crate::datasources::table::fuse::read_block;
+use crate::datasources::table::fuse::save_block;
+
+#[tokio::test]
+async fn test_block_datetime64_round_trip() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let data_accessor: Arc<dyn DataAccessor> = Arc::new(Local::new(&dir.path().to_string_lossy()));
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::DateTime64(3, None), false),
+        DataField::new(
+            "b",
+            DataType::DateTime64(6, Some("Asia/Shanghai".to_string())),
+            false,
+        ),
+    ]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec![1630833797123i64, 1630833798000]),
+        Series::new(vec![1630833797123456i64, 1630833798000000]),
+    ]);
+
+    let location = "_b/datetime64.parquet";
+    save_block(&schema, block, data_accessor.clone(), location)?;
+    let block = read_block(data_accessor, location, &schema, &[0, 1]).await?;
+
+    assert_eq!(block.schema(), &schema);
+    assert_eq!(block.column(0).to_values()?, vec![
+        DataValue::Int64(Some(1630833797123)),
+        DataValue::Int64(Some(1630833798000)),
+    ]);
+    assert_eq!(block.column(1).to_values()?, vec![
+        DataValue::Int64(Some(1630833797123456)),
+        DataValue::Int64(Some(1630833798000000)),
+    ]);
+    Ok(())
+}
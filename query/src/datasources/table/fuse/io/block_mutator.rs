@@ -0,0 +1,299 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::DataArrayFilter;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_metatypes::MetaId;
+use common_planners::Expression;
+use uuid::Uuid;
+
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::table::fuse::block_may_match;
+use crate::datasources::table::fuse::merge_stats;
+use crate::datasources::table::fuse::read_block;
+use crate::datasources::table::fuse::read_segment_async;
+use crate::datasources::table::fuse::reduce_block_metas;
+use crate::datasources::table::fuse::segment_info_location;
+use crate::datasources::table::fuse::snapshot_location;
+use crate::datasources::table::fuse::BlockMeta;
+use crate::datasources::table::fuse::FuseTable;
+use crate::datasources::table::fuse::SegmentInfo;
+use crate::datasources::table::fuse::TableSnapshot;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::DatabendQueryContextRef;
+
+pub enum BlockMutation {
+    Delete,
+    /// Assignments of the columns, the values are already cast to the column types
+    Update(Vec<(String, Expression)>),
+}
+
+impl FuseTable {
+    /// Copy-on-write mutation of the table.
+    ///
+    /// Segments and blocks are pruned by the min/max statistics of the selection, only the
+    /// blocks which have matched rows are rewritten, and a new snapshot is committed.
+    pub(crate) async fn mutate(
+        &self,
+        ctx: DatabendQueryContextRef,
+        table_id: MetaId,
+        selection: Option<Expression>,
+        mutation: BlockMutation,
+    ) -> Result<()> {
+        // rewriting the staged blocks is not supported
+        if ctx.get_transaction().is_some() {
+            return Err(ErrorCode::TransactionError(format!(
+                "DELETE and UPDATE of table {}.{} are not supported in a transaction",
+                self.db, self.name
+            )));
+        }
+
+        let snapshot = match self.committed_snapshot(&ctx)? {
+            Some(snapshot) => snapshot,
+            None => return Ok(()),
+        };
+
+        let data_accessor = self.data_accessor(&ctx)?;
+        let executor = self.mutation_executor(&selection, &mutation)?;
+
+        let mut segments = Vec::with_capacity(snapshot.segments.len());
+        let mut segment_stats = Vec::with_capacity(snapshot.segments.len());
+        let mut mutated = false;
+
+        for seg_loc in &snapshot.segments {
+            let segment = read_segment_async(data_accessor.clone(), seg_loc).await?;
+            if !block_may_match(&self.schema, &segment.summary.col_stats, selection.as_ref()) {
+                segments.push(seg_loc.clone());
+                segment_stats.push(segment.summary);
+                continue;
+            }
+
+            let mut changed = false;
+            let mut block_metas = Vec::with_capacity(segment.blocks.len());
+            let mut new_blocks = vec![];
+            for block_meta in segment.blocks {
+                if !block_may_match(&self.schema, &block_meta.col_stats, selection.as_ref()) {
+                    block_metas.push(block_meta);
+                    continue;
+                }
+
+                let block = self
+                    .mutate_block(data_accessor.clone(), &block_meta, &executor, &mutation)
+                    .await?;
+                match block {
+                    // nothing matched, keep the block as it is
+                    None => block_metas.push(block_meta),
+                    Some(block) => {
+                        changed = true;
+                        if !block.is_empty() {
+                            new_blocks.push(block);
+                        }
+                    }
+                }
+            }
+
+            if !changed {
+                segments.push(seg_loc.clone());
+                segment_stats.push(segment.summary);
+                continue;
+            }
+
+            mutated = true;
+            if !new_blocks.is_empty() {
+                let stream = Box::pin(futures::stream::iter(new_blocks.into_iter().map(Ok)));
+                let new_segment = self.append_blocks(ctx.clone(), stream).await?;
+                block_metas.extend(new_segment.blocks);
+            }
+
+            // all the rows of the segment are deleted
+            if block_metas.is_empty() {
+                continue;
+            }
+
+            let summary = reduce_block_metas(&self.schema, &block_metas)?;
+            let new_seg_loc = {
+                let uuid = Uuid::new_v4().to_simple().to_string();
+                segment_info_location(&uuid)
+            };
+            let segment_info = SegmentInfo {
+                blocks: block_metas,
+                summary: summary.clone(),
+            };
+            self.save_segment(&new_seg_loc, &data_accessor, segment_info)
+                .await?;
+            segments.push(new_seg_loc);
+            segment_stats.push(summary);
+        }
+
+        if !mutated {
+            return Ok(());
+        }
+
+        let new_snapshot = TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            summary: merge_stats(&self.schema, &segment_stats)?,
+            schema: snapshot.schema,
+            segments,
+            copied_files: snapshot.copied_files,
+        };
+        let snapshot_loc = {
+            let uuid = Uuid::new_v4().to_simple().to_string();
+            snapshot_location(&uuid)
+        };
+        self.save_snapshot(&snapshot_loc, &data_accessor, new_snapshot)
+            .await?;
+
+        self.commit_snapshot(&ctx, table_id, snapshot_loc)
+    }
+
+    /// The executor evaluates the selection (if any) followed by the new values of the updated columns.
+    fn mutation_executor(
+        &self,
+        selection: &Option<Expression>,
+        mutation: &BlockMutation,
+    ) -> Result<Option<ExpressionExecutor>> {
+        let mut exprs = vec![];
+        if let Some(selection) = selection {
+            exprs.push(selection.clone());
+        }
+
+        if let BlockMutation::Update(update_list) = mutation {
+            for (column, value) in update_list {
+                let expr = match selection {
+                    None => value.clone(),
+                    Some(selection) => Expression::ScalarFunction {
+                        op: "if".to_string(),
+                        args: vec![
+                            selection.clone(),
+                            value.clone(),
+                            Expression::Column(column.clone()),
+                        ],
+                    },
+                };
+                exprs.push(expr);
+            }
+        }
+
+        if exprs.is_empty() {
+            return Ok(None);
+        }
+
+        let fields = exprs
+            .iter()
+            .map(|expr| expr.to_data_field(&self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        let executor = ExpressionExecutor::try_create(
+            "mutation executor",
+            self.schema.clone(),
+            DataSchemaRefExt::create(fields),
+            exprs,
+            false,
+        )?;
+        executor.validate()?;
+        Ok(Some(executor))
+    }
+
+    /// Returns None if no rows of the block are matched, otherwise the mutated block.
+    async fn mutate_block(
+        &self,
+        data_accessor: Arc<dyn DataAccessor>,
+        block_meta: &BlockMeta,
+        executor: &Option<ExpressionExecutor>,
+        mutation: &BlockMutation,
+    ) -> Result<Option<DataBlock>> {
+        let executor = match executor {
+            Some(executor) => executor,
+            // DELETE without selection, all the rows are deleted
+            None => return Ok(Some(DataBlock::empty())),
+        };
+
+        let projection = (0..self.schema.fields().len()).collect::<Vec<_>>();
+        let block = read_block(
+            data_accessor,
+            &block_meta.location.location,
+            &self.schema,
+            &projection,
+        )
+        .await?;
+        let evaluated = executor.execute(&block)?;
+
+        let matched = match executor_selection(&evaluated, mutation)? {
+            None => None,
+            Some(matched) => {
+                if !matched.iter().any(|v| *v == Some(true)) {
+                    return Ok(None);
+                }
+                Some(matched)
+            }
+        };
+
+        match mutation {
+            BlockMutation::Delete => {
+                // nulls are not matched
+                let retained = matched
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|v| *v != Some(true))
+                    .collect::<DFBooleanArray>();
+                let arrays = block
+                    .columns()
+                    .iter()
+                    .map(|column| column.to_array())
+                    .collect::<Result<Vec<_>>>()?;
+                let arrays = DataArrayFilter::filter_batch_array(arrays, &retained)?;
+                Ok(Some(DataBlock::create_by_array(
+                    self.schema.clone(),
+                    arrays,
+                )))
+            }
+            BlockMutation::Update(update_list) => {
+                let offset = evaluated.num_columns() - update_list.len();
+                let mut columns = Vec::with_capacity(self.schema.fields().len());
+                for field in self.schema.fields() {
+                    let updated = update_list.iter().position(|(c, _)| c == field.name());
+                    let column = match updated {
+                        Some(idx) => evaluated.column(offset + idx).clone(),
+                        None => block.try_column_by_name(field.name())?.clone(),
+                    };
+                    columns.push(column);
+                }
+                Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+            }
+        }
+    }
+}
+
+/// The selection is the first evaluated column if there is one.
+fn executor_selection(
+    evaluated: &DataBlock,
+    mutation: &BlockMutation,
+) -> Result<Option<Vec<Option<bool>>>> {
+    let assignments = match mutation {
+        BlockMutation::Delete => 0,
+        BlockMutation::Update(update_list) => update_list.len(),
+    };
+    if evaluated.num_columns() == assignments {
+        return Ok(None);
+    }
+
+    let selection = evaluated.column(0).to_array()?;
+    let selection = selection.cast_with_type(&DataType::Boolean)?;
+    Ok(Some(selection.bool()?.collect_values()))
+}
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::decompress;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
@@ -32,8 +33,10 @@ use common_infallible::Mutex;
 use common_planners::Part;
 use futures::StreamExt;
 
+use crate::datasources::common::column_default;
+use crate::datasources::common::column_ids;
 use crate::datasources::dal::DataAccessor;
-use crate::datasources::table::fuse::block_location;
+use crate::datasources::table::fuse::DATA_SCHEMA_KEY;
 
 #[derive(PartialEq, Eq, Hash)]
 pub struct BlockMetaCacheKey {
@@ -70,54 +73,78 @@ impl BlockReader {
     }
 }
 
-pub(crate) async fn read_part(
-    part: Part,
+/// Read the projected columns of the block at the location, in the current schema of the table.
+///
+/// The columns are mapped by column id to the schema the block was written in, columns added
+/// after the block was written are filled with their defaults, modified columns are cast.
+pub(crate) async fn read_block(
     data_accessor: Arc<dyn DataAccessor>,
-    projection: Vec<usize>,
-    sender: Sender<Result<DataBlock>>,
-    arrow_schema: &ArrowSchema,
-) -> Result<()> {
-    let loc = block_location(&part.name);
+    loc: &str,
+    table_schema: &DataSchema,
+    projection: &[usize],
+) -> Result<DataBlock> {
     // TODO pass in parquet file len
-    let mut reader = data_accessor.get_input_stream(&loc, None).await?;
+    let mut reader = data_accessor.get_input_stream(loc, None).await?;
     let metadata = read_metadata_async(&mut reader)
         .await
         .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
+    // blocks written before the data schema is kept in the file are in the table schema
+    let data_schema = match metadata
+        .key_value_metadata
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == DATA_SCHEMA_KEY)
+        .and_then(|kv| kv.value.as_ref())
+    {
+        Some(v) => serde_json::from_str::<DataSchema>(v)?,
+        None => table_schema.clone(),
+    };
+    let data_column_ids = column_ids(&data_schema);
+    let table_column_ids = column_ids(table_schema);
+
     // only onw page in the the parquet
     let row_group = 0;
-    let cols = projection
-        .iter()
-        .map(|idx| (metadata.row_groups[row_group].column(*idx), *idx));
+    let num_rows = metadata.num_rows as usize;
+    let mut fields = Vec::with_capacity(projection.len());
+    let mut columns = Vec::with_capacity(projection.len());
+    for idx in projection {
+        let field = table_schema.field(*idx);
+        let data_idx = data_column_ids
+            .iter()
+            .position(|id| *id == table_column_ids[*idx]);
 
-    let fields = arrow_schema.fields();
-    let mut arrays: Vec<Arc<dyn common_arrow::arrow::array::Array>> = vec![];
-    for (col_meta, idx) in cols {
-        // NOTE: here the page filter is !Send
-        let pages = get_page_stream(col_meta, &mut reader, vec![], Arc::new(|_, _| true))
-            .await
-            .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
-        let pages = pages.map(|compressed_page| decompress(compressed_page?, &mut vec![]));
-        // QUOTE(from arrow2): deserialize the pages. This is CPU bounded and SHOULD be done in a dedicated thread pool (e.g. Rayon)
-        let array = page_stream_to_array(
-            pages,
-            &metadata.row_groups[0].columns()[idx],
-            fields[idx].data_type.clone(),
-        )
-        .await?;
-        arrays.push(array.into());
+        let column = match data_idx {
+            None => DataColumn::Constant(column_default(table_schema, *idx)?, num_rows),
+            Some(data_idx) => {
+                let col_meta = metadata.row_groups[row_group].column(data_idx);
+                // NOTE: here the page filter is !Send
+                let pages = get_page_stream(col_meta, &mut reader, vec![], Arc::new(|_, _| true))
+                    .await
+                    .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+                let pages = pages.map(|compressed_page| decompress(compressed_page?, &mut vec![]));
+                // QUOTE(from arrow2): deserialize the pages. This is CPU bounded and SHOULD be done in a dedicated thread pool (e.g. Rayon)
+                let data_field = data_schema.field(data_idx);
+                let array = page_stream_to_array(
+                    pages,
+                    col_meta,
+                    data_field.to_arrow().data_type().clone(),
+                )
+                .await?;
+                let column = DataColumn::Array(Arc::<dyn Array>::from(array).into_series());
+                if data_field.data_type() != field.data_type() {
+                    column.cast_with_type(field.data_type())?
+                } else {
+                    column
+                }
+            }
+        };
+        fields.push(field.clone());
+        columns.push(column);
     }
 
-    let ser = arrays
-        .into_iter()
-        .map(|a| DataColumn::Array(a.into_series()))
-        .collect::<Vec<_>>();
-
-    let block = DataBlock::create(Arc::new(DataSchema::from(arrow_schema)), ser);
-    sender
-        .send(Ok(block))
-        .await
-        .map_err(|e| ErrorCode::BrokenChannel(e.to_string()))?;
-
-    Ok(())
+    Ok(DataBlock::create(
+        Arc::new(DataSchema::new(fields)),
+        columns,
+    ))
 }
@@ -19,10 +19,14 @@ mod snapshot_reader;
 // end
 
 mod block_appender;
+#[cfg(test)]
+mod block_appender_test;
+mod block_mutator;
 mod block_reader;
 mod reader_util;
 
 pub use block_appender::*;
+pub use block_mutator::*;
 pub use block_reader::*;
 pub use reader_util::*;
 pub use segment_reader::*;
@@ -54,7 +54,6 @@ impl MetaInfoReader {
         }
         Ok(res)
     }
-    #[allow(dead_code)]
     pub fn read_segment_info(&self, location: &str) -> Result<SegmentInfo> {
         read_segment(self.da.clone(), &self.ctx, location)
     }
@@ -14,6 +14,7 @@
 //
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use common_arrow::parquet::statistics::Statistics;
 use common_datavalues::DataSchema;
@@ -37,12 +38,9 @@ pub struct TableSnapshot {
     /// We rely on background merge tasks to keep merging segments, so that
     /// this the size of this vector could be kept reasonable
     pub segments: Vec<Location>,
-}
-
-impl TableSnapshot {
-    pub fn new() -> Self {
-        todo!()
-    }
+    /// The files loaded by COPY INTO, committed with their data
+    #[serde(default)]
+    pub copied_files: HashSet<String>,
 }
 
 /// A segment comprised of one or more blocks
@@ -52,7 +50,7 @@ pub struct SegmentInfo {
     pub summary: Stats,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Stats {
     pub row_count: u64,
     pub block_count: u64,
@@ -62,16 +60,18 @@ pub struct Stats {
 }
 
 /// Meta information of a block (currently, the parquet file)
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct BlockMeta {
     /// Pointer of the data Block
     pub row_count: u64,
     pub block_size: u64,
+    /// Size of the parquet file
+    pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColStats>,
     pub location: BlockLocation,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct BlockLocation {
     pub location: Location,
     // for parquet, this filed can be used to fetch the meta data without seeking around
@@ -14,38 +14,54 @@
 //
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_metatypes::MetaId;
+use common_planners::DeletePlan;
+use common_planners::InsertInputStream;
 use common_planners::InsertIntoPlan;
+use common_planners::Part;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::ScanPlan;
 use common_planners::Statistics;
 use common_planners::TableOptions;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
-use tokio_stream::wrappers::ReceiverStream;
+use futures::StreamExt;
 use uuid::Uuid;
 
+use crate::catalogs::meta_backend::TableCommit;
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
+use crate::catalogs::Catalog;
 use crate::catalogs::Table;
+use crate::datasources::common::record_copied_files;
 use crate::datasources::dal::DataAccessor;
-//use crate::datasources::table::fuse::parse_storage_scheme;
+use crate::datasources::table::fuse::merge_stats;
+use crate::datasources::table::fuse::parse_storage_scheme;
 use crate::datasources::table::fuse::project_col_idx;
 use crate::datasources::table::fuse::range_filter;
-use crate::datasources::table::fuse::read_part;
+use crate::datasources::table::fuse::read_block;
+use crate::datasources::table::fuse::read_segment;
+use crate::datasources::table::fuse::read_segment_async;
 use crate::datasources::table::fuse::read_table_snapshot;
 use crate::datasources::table::fuse::segment_info_location;
 use crate::datasources::table::fuse::snapshot_location;
-use crate::datasources::table::fuse::BlockLocation;
+use crate::datasources::table::fuse::BlockMeta;
+use crate::datasources::table::fuse::BlockMutation;
 use crate::datasources::table::fuse::MetaInfoReader;
 use crate::datasources::table::fuse::SegmentInfo;
+use crate::datasources::table::fuse::Stats;
 use crate::datasources::table::fuse::TableSnapshot;
 use crate::datasources::table::fuse::TableStorageScheme;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::StagedTable;
 
 pub struct FuseTable {
     pub db: String,
@@ -76,43 +92,112 @@ impl FuseTable {
         let bytes = serde_json::to_vec(&snapshot)?;
         data_accessor.put(location, bytes).await
     }
-    pub(crate) fn merge_seg(&self, new_seg: String, mut prev: TableSnapshot) -> TableSnapshot {
-        prev.segments.push(new_seg);
-        let new_id = Uuid::new_v4();
-        prev.snapshot_id = new_id;
-        prev
+
+    /// The new snapshot which appends the segments to the previous one, if any.
+    pub(crate) fn merge_segments(
+        &self,
+        prev: Option<TableSnapshot>,
+        new_segments: Vec<(String, Stats)>,
+    ) -> Result<TableSnapshot> {
+        let (prev_snapshot_id, schema, mut segments, mut stats, copied_files) = match prev {
+            Some(prev) => (
+                Some(prev.snapshot_id),
+                prev.schema,
+                prev.segments,
+                vec![prev.summary],
+                prev.copied_files,
+            ),
+            None => (
+                None,
+                self.schema.as_ref().clone(),
+                vec![],
+                vec![],
+                HashSet::new(),
+            ),
+        };
+
+        for (location, summary) in new_segments {
+            segments.push(location);
+            stats.push(summary);
+        }
+
+        Ok(TableSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id,
+            summary: merge_stats(&self.schema, &stats)?,
+            schema,
+            segments,
+            copied_files,
+        })
+    }
+
+    /// Commit the new snapshot, if the table is still at the snapshot it is loaded with.
+    pub(crate) fn commit_snapshot(
+        &self,
+        ctx: &DatabendQueryContextRef,
+        table_id: MetaId,
+        new_snapshot: String,
+    ) -> Result<()> {
+        ctx.get_catalog().commit_tables(vec![TableCommit {
+            db: self.db.clone(),
+            table_id,
+            prev_snapshot: self.snapshot_location().cloned(),
+            new_snapshot,
+        }])?;
+
+        let result_cache = ctx.get_sessions_manager().get_result_cache();
+        result_cache.invalidate_tables(&[table_id]);
+        Ok(())
+    }
+
+    /// Writes the blocks of a table which is not created yet, and returns the location of its
+    /// first snapshot, which the table is created with in its schema meta.
+    pub async fn write_first_snapshot(
+        &self,
+        ctx: DatabendQueryContextRef,
+        block_stream: InsertInputStream,
+    ) -> Result<String> {
+        let data_accessor = self.data_accessor(&ctx)?;
+        let segment_info = self.append_blocks(ctx.clone(), block_stream).await?;
+        let seg_loc = {
+            let uuid = Uuid::new_v4().to_simple().to_string();
+            segment_info_location(&uuid)
+        };
+        let summary = segment_info.summary.clone();
+        self.save_segment(&seg_loc, &data_accessor, segment_info)
+            .await?;
+
+        let snapshot = self.merge_segments(None, vec![(seg_loc, summary)])?;
+        let snapshot_loc = {
+            let uuid = Uuid::new_v4().to_simple().to_string();
+            snapshot_location(&uuid)
+        };
+        self.save_snapshot(&snapshot_loc, &data_accessor, snapshot)
+            .await?;
+        Ok(snapshot_loc)
     }
 }
 
 impl FuseTable {
     pub fn try_create(
-        _db: String,
-        _name: String,
-        _schema: DataSchemaRef,
-        _options: TableOptions,
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        options: TableOptions,
     ) -> Result<Box<dyn Table>> {
-        todo!()
+        // the data is kept in the local file system, unless another scheme is given
+        let storage_scheme = match options.get("storage_scheme") {
+            None => TableStorageScheme::LocalFs,
+            value => parse_storage_scheme(value)?,
+        };
+        Ok(Box::new(FuseTable {
+            db,
+            name,
+            schema,
+            storage_scheme,
+            local: true,
+        }))
     }
-
-    //    pub fn with_meta_client(
-    //        db: String,
-    //        name: String,
-    //        schema: DataSchemaRef,
-    //        options: TableOptions,
-    //        meta_client: T,
-    //    ) -> Result<Box<dyn Table>> {
-    //        let storage_scheme = parse_storage_scheme(options.get("STORAGE_SCHEME"))?;
-    //        let res = FuseTable {
-    //            db,
-    //            name,
-    //            schema,
-    //            storage_scheme,
-    //            meta_client,
-    //            local: true,
-    //        };
-    //
-    //        Ok(Box::new(res))
-    //    }
 }
 
 #[async_trait::async_trait]
@@ -137,6 +222,10 @@ impl Table for FuseTable {
         self.local
     }
 
+    fn support_transaction(&self) -> bool {
+        true
+    }
+
     fn read_plan(
         &self,
         ctx: DatabendQueryContextRef,
@@ -148,8 +237,8 @@ impl Table for FuseTable {
         if let Some(snapshot) = tbl_snapshot {
             let da = self.data_accessor(&ctx)?;
             let meta_reader = MetaInfoReader::new(da, ctx.clone());
-            let block_locations = range_filter(&snapshot, &scan.push_downs, meta_reader)?;
-            let (statistics, parts) = self.to_partitions(&block_locations);
+            let block_metas = range_filter(&self.schema, &snapshot, &scan.push_downs, meta_reader)?;
+            let (statistics, parts) = self.to_partitions(&block_metas);
             let plan = ReadDataSourcePlan {
                 db: scan.schema_name.clone(),
                 table: self.name().to_string(),
@@ -178,10 +267,8 @@ impl Table for FuseTable {
             &source_plan.scan_plan.projected_schema,
         )?;
 
-        let (tx, rx) = common_base::tokio::sync::mpsc::channel(1024);
-
         let bite_size = 1; // TODO config
-        let mut iter = {
+        let iter = {
             let ctx = ctx.clone();
             std::iter::from_fn(move || match ctx.clone().try_get_partitions(bite_size) {
                 Err(_) => None,
@@ -191,25 +278,17 @@ impl Table for FuseTable {
             .flatten()
         };
         let da = self.data_accessor(&ctx)?;
-        let arrow_schema = source_plan.scan_plan.table_schema.to_arrow();
-        let _h = common_base::tokio::task::spawn_local(async move {
-            // TODO error handling is buggy
-            for part in &mut iter {
-                read_part(
-                    part,
-                    da.clone(),
-                    projection.clone(),
-                    tx.clone(),
-                    &arrow_schema,
-                )
-                .await?;
-            }
-            Ok::<(), ErrorCode>(())
+        let table_schema = source_plan.scan_plan.table_schema.clone();
+        // the blocks are read one by one, an error of a block is passed down the stream
+        let stream = futures::stream::iter(iter).then(move |part| {
+            let da = da.clone();
+            let table_schema = table_schema.clone();
+            let projection = projection.clone();
+            async move { read_block(da, &part.name, &table_schema, &projection).await }
         });
 
         let progress_callback = ctx.progress_callback()?;
-        let receiver = ReceiverStream::new(rx);
-        let stream = ProgressStream::try_create(Box::pin(receiver), progress_callback)?;
+        let stream = ProgressStream::try_create(Box::pin(stream), progress_callback)?;
         Ok(Box::pin(stream))
     }
 
@@ -218,6 +297,14 @@ impl Table for FuseTable {
         ctx: DatabendQueryContextRef,
         insert_plan: InsertIntoPlan,
     ) -> Result<()> {
+        // checked before any block is written, the files would be left behind otherwise
+        let transaction = ctx.get_transaction();
+        if transaction.is_some() && insert_plan.copied_files.is_some() {
+            return Err(ErrorCode::TransactionError(
+                "COPY INTO is not supported inside a transaction",
+            ));
+        }
+
         // 1. take out input stream from plan
         //    Assumes that, insert_interpreter has already split data into blocks properly
         let block_stream = {
@@ -235,17 +322,37 @@ impl Table for FuseTable {
             let uuid = Uuid::new_v4().to_simple().to_string();
             segment_info_location(&uuid)
         };
+        let summary = segment_info.summary.clone();
+        let mut files = segment_info
+            .blocks
+            .iter()
+            .map(|block_meta| block_meta.location.location.clone())
+            .collect::<Vec<_>>();
         self.save_segment(&seg_loc, &data_accessor, segment_info)
             .await?;
 
-        // 3. new snapshot
-        let tbl_snapshot = self
-            .table_snapshot(&ctx)?
-            .unwrap_or_else(TableSnapshot::new);
-        let _snapshot_id = tbl_snapshot.snapshot_id;
-        let new_snapshot: TableSnapshot = self.merge_seg(seg_loc, tbl_snapshot);
-        let _new_snapshot_id = new_snapshot.snapshot_id;
+        // inside a transaction, the segment is staged until COMMIT
+        if let Some(transaction) = transaction {
+            files.push(seg_loc.clone());
+            transaction.stage(
+                &self.db,
+                &self.name,
+                insert_plan.tbl_id,
+                seg_loc,
+                files,
+                data_accessor,
+            );
+            return Ok(());
+        }
 
+        // 3. new snapshot
+        let prev_snapshot = self.committed_snapshot(&ctx)?;
+        let mut new_snapshot = self.merge_segments(prev_snapshot, vec![(seg_loc, summary)])?;
+        // the loaded files are recorded in the same snapshot as their data
+        if let Some(copied) = &insert_plan.copied_files {
+            let copied_files = &mut new_snapshot.copied_files;
+            record_copied_files(copied_files, copied, &self.db, &self.name)?;
+        }
         let snapshot_loc = {
             let uuid = Uuid::new_v4().to_simple().to_string();
             snapshot_location(&uuid)
@@ -255,16 +362,15 @@ impl Table for FuseTable {
             .await?;
 
         // 4. commit
-        let _table_id = insert_plan.tbl_id;
         // TODO simple retry strategy
-        // self.meta_client
-        //     .commit_table(
-        //         table_id,
-        //         snapshot_id.to_simple().to_string(),
-        //         new_snapshot_id.to_simple().to_string(),
-        //     )
-        //     .await?;
-        Ok(())
+        self.commit_snapshot(&ctx, insert_plan.tbl_id, snapshot_loc)
+    }
+
+    async fn copied_files(&self, ctx: DatabendQueryContextRef) -> Result<HashSet<String>> {
+        Ok(match self.committed_snapshot(&ctx)? {
+            Some(snapshot) => snapshot.copied_files,
+            None => HashSet::new(),
+        })
     }
 
     async fn truncate(
@@ -274,12 +380,81 @@ impl Table for FuseTable {
     ) -> Result<()> {
         todo!()
     }
+
+    async fn delete(&self, ctx: DatabendQueryContextRef, delete_plan: DeletePlan) -> Result<()> {
+        let table_id = delete_plan.tbl_id;
+        self.mutate(ctx, table_id, delete_plan.selection, BlockMutation::Delete)
+            .await
+    }
+
+    async fn update(&self, ctx: DatabendQueryContextRef, update_plan: UpdatePlan) -> Result<()> {
+        let table_id = update_plan.tbl_id;
+        let mutation = BlockMutation::Update(update_plan.update_list);
+        self.mutate(ctx, table_id, update_plan.selection, mutation)
+            .await
+    }
+
+    async fn prepare_commit(
+        &self,
+        ctx: DatabendQueryContextRef,
+        staged: &StagedTable,
+    ) -> Result<TableCommit> {
+        let data_accessor = self.data_accessor(&ctx)?;
+        let mut segments = Vec::with_capacity(staged.segments.len());
+        for seg_loc in &staged.segments {
+            let segment = read_segment_async(data_accessor.clone(), seg_loc).await?;
+            segments.push((seg_loc.clone(), segment.summary));
+        }
+
+        let prev_snapshot = self.committed_snapshot(&ctx)?;
+        let new_snapshot = self.merge_segments(prev_snapshot, segments)?;
+        let snapshot_loc = {
+            let uuid = Uuid::new_v4().to_simple().to_string();
+            snapshot_location(&uuid)
+        };
+        self.save_snapshot(&snapshot_loc, &data_accessor, new_snapshot)
+            .await?;
+
+        Ok(TableCommit {
+            db: self.db.clone(),
+            table_id: staged.table_id,
+            prev_snapshot: self.snapshot_location().cloned(),
+            new_snapshot: snapshot_loc,
+        })
+    }
 }
 
 impl FuseTable {
-    fn table_snapshot(&self, ctx: &DatabendQueryContextRef) -> Result<Option<TableSnapshot>> {
-        let schema = self.schema()?;
-        if let Some(loc) = schema.meta().get("META_SNAPSHOT_LOCATION") {
+    /// The snapshot seen by the query, including the segments staged by its transaction.
+    pub(crate) fn table_snapshot(
+        &self,
+        ctx: &DatabendQueryContextRef,
+    ) -> Result<Option<TableSnapshot>> {
+        let snapshot = self.committed_snapshot(ctx)?;
+        let staged = match ctx.get_transaction() {
+            Some(transaction) => transaction.staged_segments(&self.db, &self.name),
+            None => vec![],
+        };
+        if staged.is_empty() {
+            return Ok(snapshot);
+        }
+
+        let da = self.data_accessor(ctx)?;
+        let segments = staged
+            .into_iter()
+            .map(|seg_loc| {
+                let segment = read_segment(da.clone(), ctx, &seg_loc)?;
+                Ok((seg_loc, segment.summary))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(self.merge_segments(snapshot, segments)?))
+    }
+
+    pub(crate) fn committed_snapshot(
+        &self,
+        ctx: &DatabendQueryContextRef,
+    ) -> Result<Option<TableSnapshot>> {
+        if let Some(loc) = self.snapshot_location() {
             let r = read_table_snapshot(self.data_accessor(ctx)?, ctx, loc)?;
             Ok(Some(r))
         } else {
@@ -287,6 +462,10 @@ impl FuseTable {
         }
     }
 
+    pub(crate) fn snapshot_location(&self) -> Option<&String> {
+        self.schema.meta().get(META_SNAPSHOT_LOCATION)
+    }
+
     pub(crate) fn empty_read_source_plan(&self, scan: &ScanPlan) -> Result<ReadDataSourcePlan> {
         Ok(ReadDataSourcePlan {
             db: scan.schema_name.clone(),
@@ -302,8 +481,20 @@ impl FuseTable {
         })
     }
 
-    pub(crate) fn to_partitions(&self, _blocs: &[BlockLocation]) -> (Statistics, Partitions) {
-        todo!()
+    /// One partition for each block, named by its location.
+    pub(crate) fn to_partitions(&self, block_metas: &[BlockMeta]) -> (Statistics, Partitions) {
+        let (rows, bytes) = block_metas.iter().fold((0, 0), |(rows, bytes), meta| {
+            (rows + meta.row_count, bytes + meta.block_size)
+        });
+        let parts = block_metas
+            .iter()
+            .map(|meta| Part {
+                name: meta.location.location.clone(),
+                version: 0,
+            })
+            .collect();
+        let statistics = Statistics::new_exact(rows as usize, bytes as usize);
+        (statistics, parts)
     }
 
     pub(crate) fn data_accessor(
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataValue;
+use common_planners::Expression;
+
+use crate::datasources::common::column_ids;
+use crate::datasources::table::fuse::ColStats;
+use crate::datasources::table::fuse::ColumnId;
+
+/// Checks whether the rows of a block (or segment) might satisfy the predicate,
+/// according to the min/max statistics of its columns.
+///
+/// Returns false only if none of the rows could match; unknown cases are kept.
+pub fn block_may_match(
+    schema: &DataSchema,
+    col_stats: &HashMap<ColumnId, ColStats>,
+    predicate: Option<&Expression>,
+) -> bool {
+    match predicate {
+        None => true,
+        Some(expr) => may_match(schema, col_stats, expr),
+    }
+}
+
+fn may_match(
+    schema: &DataSchema,
+    col_stats: &HashMap<ColumnId, ColStats>,
+    expr: &Expression,
+) -> bool {
+    match expr {
+        Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
+            "and" => may_match(schema, col_stats, left) && may_match(schema, col_stats, right),
+            "or" => may_match(schema, col_stats, left) || may_match(schema, col_stats, right),
+            op => match (left.as_ref(), right.as_ref()) {
+                (Expression::Column(name), Expression::Literal { value, .. }) => {
+                    column_may_match(schema, col_stats, name, op, value)
+                }
+                (Expression::Literal { value, .. }, Expression::Column(name)) => match flip(op) {
+                    Some(op) => column_may_match(schema, col_stats, name, op, value),
+                    None => true,
+                },
+                _ => true,
+            },
+        },
+        _ => true,
+    }
+}
+
+fn column_may_match(
+    schema: &DataSchema,
+    col_stats: &HashMap<ColumnId, ColStats>,
+    name: &str,
+    op: &str,
+    value: &DataValue,
+) -> bool {
+    let stats = match schema.index_of(name) {
+        Ok(idx) => col_stats.get(&column_ids(schema)[idx]),
+        Err(_) => None,
+    };
+    let stats = match stats {
+        Some(stats) => stats,
+        None => return true,
+    };
+
+    let (min, max) = match (compare(&stats.min, value), compare(&stats.max, value)) {
+        (Some(min), Some(max)) => (min, max),
+        _ => return true,
+    };
+
+    match op {
+        "=" => min != Ordering::Greater && max != Ordering::Less,
+        "<" => min == Ordering::Less,
+        "<=" => min != Ordering::Greater,
+        ">" => max == Ordering::Greater,
+        ">=" => max != Ordering::Less,
+        _ => true,
+    }
+}
+
+fn flip(op: &str) -> Option<&'static str> {
+    match op {
+        "=" => Some("="),
+        "<" => Some(">"),
+        "<=" => Some(">="),
+        ">" => Some("<"),
+        ">=" => Some("<="),
+        _ => None,
+    }
+}
+
+/// Compares two values of the same kind, None if they are null or not comparable.
+fn compare(lhs: &DataValue, rhs: &DataValue) -> Option<Ordering> {
+    if lhs.is_null() || rhs.is_null() {
+        return None;
+    }
+
+    match (lhs, rhs) {
+        (DataValue::String(Some(lhs)), DataValue::String(Some(rhs))) => Some(lhs.cmp(rhs)),
+        (DataValue::String(_), _) | (_, DataValue::String(_)) => None,
+        _ => match (as_i128(lhs), as_i128(rhs)) {
+            (Some(lhs), Some(rhs)) => Some(lhs.cmp(&rhs)),
+            _ => match (lhs.as_f64(), rhs.as_f64()) {
+                (Ok(lhs), Ok(rhs)) => lhs.partial_cmp(&rhs),
+                _ => None,
+            },
+        },
+    }
+}
+
+fn as_i128(value: &DataValue) -> Option<i128> {
+    match value {
+        DataValue::UInt8(_)
+        | DataValue::UInt16(_)
+        | DataValue::UInt32(_)
+        | DataValue::UInt64(_) => value.as_u64().ok().map(|v| v as i128),
+        DataValue::Int8(_) | DataValue::Int16(_) | DataValue::Int32(_) | DataValue::Int64(_) => {
+            value.as_i64().ok().map(|v| v as i128)
+        }
+        _ => None,
+    }
+}
@@ -13,58 +13,46 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+
+use common_datavalues::DataSchema;
 use common_exception::Result;
 use common_planners::Extras;
 
-use crate::datasources::table::fuse::BlockLocation;
+use crate::datasources::table::fuse::block_may_match;
+use crate::datasources::table::fuse::BlockMeta;
+use crate::datasources::table::fuse::ColStats;
+use crate::datasources::table::fuse::ColumnId;
 use crate::datasources::table::fuse::MetaInfoReader;
 use crate::datasources::table::fuse::TableSnapshot;
 
-struct TableSparseIndex {}
-struct CacheMgr;
-
-// non-distributed indexing
-impl TableSparseIndex {
-    pub fn load(
-        _table_snapshot: &TableSnapshot,
-        _meta_reader: &MetaInfoReader,
-        _cache_mgr: &CacheMgr,
-    ) -> Result<Self> {
-        // load index, which may be cached (or partially cached)
-        todo!()
-    }
-
-    // Returns an iterator or stream would be better
-    // let's begin with
-    pub fn apply(&self, _expression: &Extras) -> Result<Vec<BlockLocation>> {
-        // prunes blocks
-        todo!()
-    }
-}
-
+/// The blocks of the snapshot which might have rows matching the filters of the push down,
+/// segments and blocks are pruned by the min/max statistics of their columns.
 pub fn range_filter(
+    schema: &DataSchema,
     table_snapshot: &TableSnapshot,
     push_down: &Extras,
     // MetaInfoReader takes care of caching itself
     meta_reader: MetaInfoReader,
-) -> Result<Vec<BlockLocation>> {
-    let cache_mgr = CacheMgr; // TODO passed in from context
-    let range_index = TableSparseIndex::load(table_snapshot, &meta_reader, &cache_mgr)?;
-    range_index.apply(push_down)
-
-    /*
+) -> Result<Vec<BlockMeta>> {
+    let may_match = |col_stats: &HashMap<ColumnId, ColStats>| {
+        push_down
+            .filters
+            .iter()
+            .all(|filter| block_may_match(schema, col_stats, Some(filter)))
+    };
+
+    let mut res = vec![];
     for seg_loc in &table_snapshot.segments {
-        // TODO filter by seg.summary
         let seg = meta_reader.read_segment_info(seg_loc)?;
-        let _seg_summary = &seg.summary;
-        for x in seg.blocks {
-            // filter by block_meta's ColStats if necessary
-            let _col_stats = &x.col_stats;
-            // keep the block we are fond of
-            res.push(x);
+        if !may_match(&seg.summary.col_stats) {
+            continue;
+        }
+        for block_meta in seg.blocks {
+            if may_match(&block_meta.col_stats) {
+                res.push(block_meta);
+            }
         }
     }
-
     Ok(res)
-    */
 }
@@ -13,14 +13,18 @@
 //  limitations under the License.
 //
 
+mod block_pruner;
 mod index_helpers;
 mod location_gen;
 mod projection_helper;
 mod statistic_helper;
 mod storage_scheme_helper;
 
+pub use block_pruner::block_may_match;
 pub use index_helpers::range_filter;
 pub use location_gen::*;
 pub use projection_helper::project_col_idx;
 pub use statistic_helper::column_stats_reduce;
+pub use statistic_helper::merge_stats;
+pub use statistic_helper::reduce_block_metas;
 pub use storage_scheme_helper::*;
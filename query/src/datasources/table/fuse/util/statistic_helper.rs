@@ -16,11 +16,17 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
+use common_datavalues::columns::DataColumn;
+use common_datavalues::DataSchema;
 use common_datavalues::DataType;
+use common_datavalues::DataValue;
 use common_exception::Result;
 
+use crate::datasources::common::column_ids;
+use crate::datasources::table::fuse::BlockMeta;
 use crate::datasources::table::fuse::ColStats;
 use crate::datasources::table::fuse::ColumnId;
+use crate::datasources::table::fuse::Stats;
 
 pub fn column_stats_reduce(
     stats: Vec<HashMap<ColumnId, (DataType, ColStats)>>,
@@ -63,7 +69,7 @@ pub fn column_stats_reduce(
                     .min()?;
 
             let max =
-                common_datavalues::DataValue::try_into_data_array(min_stats.as_slice(), data_type)?
+                common_datavalues::DataValue::try_into_data_array(max_stats.as_slice(), data_type)?
                     .max()?;
 
             acc.insert(*id, ColStats {
@@ -75,3 +81,67 @@ pub fn column_stats_reduce(
         },
     )
 }
+
+/// Merges the statistics of segments (or blocks) into one, the column types are taken from the schema.
+pub fn merge_stats(schema: &DataSchema, stats: &[Stats]) -> Result<Stats> {
+    let mut row_count = 0u64;
+    let mut block_count = 0u64;
+    let mut uncompressed_byte_size = 0u64;
+    let mut compressed_byte_size = 0u64;
+    let mut col_stats = Vec::with_capacity(stats.len());
+    let column_ids = column_ids(schema);
+
+    for item in stats {
+        row_count += item.row_count;
+        block_count += item.block_count;
+        uncompressed_byte_size += item.uncompressed_byte_size;
+        compressed_byte_size += item.compressed_byte_size;
+
+        // the stats of the dropped columns are discarded, the modified columns are cast
+        let mut typed_stats = HashMap::with_capacity(item.col_stats.len());
+        for (id, col_stats) in &item.col_stats {
+            if let Some(idx) = column_ids.iter().position(|v| v == id) {
+                let data_type = schema.field(idx).data_type();
+                let col_stats = ColStats {
+                    min: cast_value(&col_stats.min, data_type)?,
+                    max: cast_value(&col_stats.max, data_type)?,
+                    null_count: col_stats.null_count,
+                };
+                typed_stats.insert(*id, (data_type.clone(), col_stats));
+            }
+        }
+        col_stats.push(typed_stats);
+    }
+
+    Ok(Stats {
+        row_count,
+        block_count,
+        uncompressed_byte_size,
+        compressed_byte_size,
+        col_stats: column_stats_reduce(col_stats)?,
+    })
+}
+
+/// Statistics of the given blocks, used as the summary of a segment.
+pub fn reduce_block_metas(schema: &DataSchema, block_metas: &[BlockMeta]) -> Result<Stats> {
+    let stats = block_metas
+        .iter()
+        .map(|meta| Stats {
+            row_count: meta.row_count,
+            block_count: 1,
+            uncompressed_byte_size: meta.block_size,
+            compressed_byte_size: meta.file_size,
+            col_stats: meta.col_stats.clone(),
+        })
+        .collect::<Vec<_>>();
+    merge_stats(schema, &stats)
+}
+
+fn cast_value(value: &DataValue, data_type: &DataType) -> Result<DataValue> {
+    if &value.data_type() == data_type {
+        return Ok(value.clone());
+    }
+    DataColumn::Constant(value.clone(), 1)
+        .cast_with_type(data_type)?
+        .try_get(0)
+}
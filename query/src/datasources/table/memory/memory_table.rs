@@ -14,6 +14,7 @@
 //
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
@@ -31,6 +32,7 @@ use futures::stream::StreamExt;
 
 use crate::catalogs::Table;
 use crate::datasources::common::generate_parts;
+use crate::datasources::common::record_copied_files;
 use crate::datasources::table::memory::memory_table_stream::MemoryTableStream;
 use crate::sessions::DatabendQueryContextRef;
 
@@ -39,6 +41,8 @@ pub struct MemoryTable {
     name: String,
     schema: DataSchemaRef,
     blocks: Arc<RwLock<Vec<DataBlock>>>,
+    // The files loaded by COPY INTO, updated under the lock of the blocks.
+    copied_files: Arc<RwLock<HashSet<String>>>,
 }
 
 impl MemoryTable {
@@ -53,6 +57,7 @@ impl MemoryTable {
             name,
             schema,
             blocks: Arc::new(RwLock::new(vec![])),
+            copied_files: Arc::new(RwLock::new(HashSet::new())),
         };
         Ok(Box::new(table))
     }
@@ -140,13 +145,25 @@ impl Table for MemoryTable {
             return Err(ErrorCode::BadArguments("DataBlock schema mismatch"));
         }
 
+        // the blocks are added once all of them are read, so a failed insert adds nothing
+        let mut new_blocks = vec![];
         while let Some(block) = s.next().await {
-            let mut blocks = self.blocks.write();
-            blocks.push(block);
+            new_blocks.push(block?);
         }
+
+        let mut blocks = self.blocks.write();
+        if let Some(copied) = &insert_plan.copied_files {
+            let mut copied_files = self.copied_files.write();
+            record_copied_files(&mut copied_files, copied, &self.db, &self.name)?;
+        }
+        blocks.extend(new_blocks);
         Ok(())
     }
 
+    async fn copied_files(&self, _ctx: DatabendQueryContextRef) -> Result<HashSet<String>> {
+        Ok(self.copied_files.read().clone())
+    }
+
     async fn truncate(
         &self,
         _ctx: DatabendQueryContextRef,
@@ -154,6 +171,7 @@ impl Table for MemoryTable {
     ) -> Result<()> {
         let mut blocks = self.blocks.write();
         blocks.clear();
+        self.copied_files.write().clear();
         Ok(())
     }
 }
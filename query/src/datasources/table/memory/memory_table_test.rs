@@ -19,6 +19,7 @@ use common_base::tokio;
 use common_datablocks::assert_blocks_sorted_eq;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use common_planners::*;
@@ -52,12 +53,15 @@ async fn test_memorytable() -> Result<()> {
         ]);
         let blocks = vec![block, block2];
 
-        let input_stream = futures::stream::iter::<Vec<DataBlock>>(blocks.clone());
+        let input_stream = futures::stream::iter(blocks.clone().into_iter().map(Ok));
         let insert_plan = InsertIntoPlan {
             db_name: "default".to_string(),
             tbl_name: "a".to_string(),
             tbl_id: 0,
             schema,
+            select_plan: None,
+            format: None,
+            copied_files: None,
             input_stream: Arc::new(Mutex::new(Some(Box::pin(input_stream)))),
         };
         table.append_data(ctx.clone(), insert_plan).await.unwrap();
@@ -90,6 +94,42 @@ async fn test_memorytable() -> Result<()> {
         );
     }
 
+    // append a stream which fails partway, nothing is appended.
+    {
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(vec![5u64]),
+            Series::new(vec![55u64]),
+        ]);
+        let input_stream =
+            futures::stream::iter(vec![Ok(block), Err(ErrorCode::BadBytes("bad block"))]);
+        let insert_plan = InsertIntoPlan {
+            db_name: "default".to_string(),
+            tbl_name: "a".to_string(),
+            tbl_id: 0,
+            schema: schema.clone(),
+            select_plan: None,
+            format: None,
+            copied_files: None,
+            input_stream: Arc::new(Mutex::new(Some(Box::pin(input_stream)))),
+        };
+        let result = table.append_data(ctx.clone(), insert_plan).await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Code: 46, displayText = bad block."
+        );
+
+        let source_plan = table.read_plan(
+            ctx.clone(),
+            &ScanPlan::empty(),
+            ctx.get_settings().get_max_threads()? as usize,
+        )?;
+        ctx.try_set_partitions(source_plan.parts.clone())?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+        assert_eq!(rows, 4);
+    }
+
     // truncate.
     {
         let truncate_plan = TruncateTablePlan {
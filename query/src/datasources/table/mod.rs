@@ -22,5 +22,11 @@ mod null;
 mod parquet;
 // deprecating
 mod remote;
+mod view;
 
 pub use prelude::register_prelude_tbl_engines;
+pub use view::materialized_view::append_data_with_views;
+pub use view::view_table::ViewTable;
+pub use view::view_table::MATERIALIZED_VIEW_ENGINE;
+pub use view::view_table::OPT_KEY_MATERIALIZED_VIEW_PREFIX;
+pub use view::view_table::VIEW_ENGINE;
@@ -122,7 +122,7 @@ impl Table for NullTable {
         .ok_or_else(|| ErrorCode::EmptyData("input stream consumed"))?;
 
         while let Some(block) = s.next().await {
-            info!("Ignore one block rows: {}", block.num_rows())
+            info!("Ignore one block rows: {}", block?.num_rows())
         }
         Ok(())
     }
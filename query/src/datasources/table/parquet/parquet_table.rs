@@ -42,6 +42,7 @@ pub struct ParquetTable {
     name: String,
     schema: DataSchemaRef,
     file: String,
+    options: TableOptions,
 }
 
 impl ParquetTable {
@@ -51,7 +52,7 @@ impl ParquetTable {
         schema: DataSchemaRef,
         options: TableOptions,
     ) -> Result<Box<dyn Table>> {
-        let file = options.get("location");
+        let file = options.get("location").cloned();
         return match file {
             Some(file) => {
                 let table = ParquetTable {
@@ -59,6 +60,7 @@ impl ParquetTable {
                     name,
                     schema,
                     file: file.trim_matches(|s| s == '\'' || s == '"').to_string(),
+                    options,
                 };
                 Ok(Box::new(table))
             }
@@ -121,6 +123,10 @@ impl Table for ParquetTable {
         true
     }
 
+    fn options(&self) -> TableOptions {
+        self.options.clone()
+    }
+
     fn read_plan(
         &self,
         _ctx: DatabendQueryContextRef,
@@ -21,6 +21,9 @@ use crate::datasources::table::memory::memory_table::MemoryTable;
 use crate::datasources::table::null::null_table::NullTable;
 use crate::datasources::table::parquet::parquet_table::ParquetTable;
 use crate::datasources::table::remote::remote_table::RemoteTableFactory;
+use crate::datasources::table::view::view_table::ViewTable;
+use crate::datasources::table::view::view_table::MATERIALIZED_VIEW_ENGINE;
+use crate::datasources::table::view::view_table::VIEW_ENGINE;
 use crate::datasources::table_engine_registry::TableEngineRegistry;
 
 pub fn register_prelude_tbl_engines(registry: &TableEngineRegistry) -> Result<()> {
@@ -30,5 +33,10 @@ pub fn register_prelude_tbl_engines(registry: &TableEngineRegistry) -> Result<()
     registry.register("MEMORY", std::sync::Arc::new(MemoryTable::try_create))?;
     registry.register("FUSE", std::sync::Arc::new(FuseTable::try_create))?;
     registry.register("REMOTE", std::sync::Arc::new(RemoteTableFactory {}))?;
+    registry.register(VIEW_ENGINE, std::sync::Arc::new(ViewTable::try_create))?;
+    registry.register(
+        MATERIALIZED_VIEW_ENGINE,
+        std::sync::Arc::new(ViewTable::try_create_materialized),
+    )?;
     Ok(())
 }
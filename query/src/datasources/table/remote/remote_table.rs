@@ -29,6 +29,7 @@ use common_planners::Statistics;
 use common_planners::TableOptions;
 use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
 
 use crate::catalogs::Table;
 use crate::common::StoreApiProvider;
@@ -116,6 +117,9 @@ impl Table for RemoteTable {
         {
             let block_stream =
                 opt_stream.ok_or_else(|| ErrorCode::EmptyData("input stream consumed"))?;
+            // the store keeps what it receives, the blocks are read first so that
+            // a failed insert sends nothing
+            let blocks = block_stream.try_collect::<Vec<_>>().await?;
 
             let client = self.store_api_provider.try_get_storage_client().await?;
 
@@ -124,7 +128,7 @@ impl Table for RemoteTable {
                     plan.db_name.clone(),
                     plan.tbl_name.clone(),
                     (&plan).schema().clone(),
-                    block_stream,
+                    Box::pin(futures::stream::iter(blocks)),
                 )
                 .await?;
         }
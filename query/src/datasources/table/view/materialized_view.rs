@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_base::tokio::sync::mpsc::channel;
+use common_base::tokio::sync::mpsc::Sender;
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::CteScanPlan;
+use common_planners::CteSource;
+use common_planners::InsertIntoPlan;
+use common_planners::MaterializedViewInfo;
+use common_planners::PlanNode;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::catalogs::Catalog;
+use crate::catalogs::TableMeta;
+use crate::datasources::table::ViewTable;
+use crate::optimizers::Optimizers;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::PlanParser;
+
+/// A materialized view fed with the inserted blocks, as (working table, query plan, sender to the
+/// target table).
+type ViewFeed = (String, PlanNode, Sender<Result<DataBlock>>);
+
+/// Only the tables supporting transaction can be appended inside a transaction, so that nothing
+/// is written before COMMIT.
+fn check_transaction(ctx: &DatabendQueryContextRef, table: &TableMeta) -> Result<()> {
+    let table = table.raw();
+    match ctx.get_transaction().is_some() && !table.support_transaction() {
+        false => Ok(()),
+        true => Err(ErrorCode::TransactionError(format!(
+            "INSERT into table {} of engine {} is not supported in a transaction",
+            table.name(),
+            table.engine()
+        ))),
+    }
+}
+
+/// Appends the inserted blocks to the table, and the results of the queries of the materialized
+/// views reading from the table to their target tables.
+///
+/// The blocks are streamed: the queries of the views are applied to each block as it arrives, and
+/// the table and the target tables are appended concurrently. An error of the input or of a query
+/// aborts all the appends.
+pub fn append_data_with_views(
+    ctx: DatabendQueryContextRef,
+    table: Arc<TableMeta>,
+    insert_plan: InsertIntoPlan,
+) -> BoxFuture<'static, Result<()>> {
+    async move {
+        check_transaction(&ctx, &table)?;
+        let views = materialized_views_of(&ctx, &insert_plan.db_name, &insert_plan.tbl_name)?;
+        if views.is_empty() {
+            return table.raw().append_data(ctx.clone(), insert_plan).await;
+        }
+
+        let mut appends = vec![];
+        let mut feeds = vec![];
+        for (view_db, query, info) in views {
+            let target = ctx.get_table(&info.target_db, &info.target_table)?;
+            check_transaction(&ctx, &target)?;
+            let target_schema = target.raw().schema()?;
+            let working_table = ctx.get_cte_name();
+            let scan = PlanNode::CteScan(CteScanPlan {
+                name: working_table.clone(),
+                schema: insert_plan.schema(),
+                source: CteSource::WorkingTable,
+            });
+            let plan = PlanParser::create(ctx.clone())
+                .with_database(view_db)
+                .with_table_override(info.source_db, info.source_table, scan)
+                .build_materialized_view_plan(&query, &target_schema)?;
+
+            let (sender, receiver) = channel(1);
+            let target_plan = InsertIntoPlan {
+                db_name: info.target_db,
+                tbl_name: info.target_table,
+                tbl_id: target.meta_id(),
+                schema: target_schema,
+                select_plan: None,
+                format: None,
+                copied_files: None,
+                input_stream: Arc::new(Mutex::new(Some(Box::pin(ReceiverStream::new(receiver))))),
+            };
+            appends.push(append_data_with_views(ctx.clone(), target, target_plan));
+            feeds.push((working_table, plan, sender));
+        }
+
+        let input_stream = insert_plan.input_stream.lock().take();
+        let (sender, receiver) = channel(1);
+        insert_plan.set_input_stream(Box::pin(ReceiverStream::new(receiver)));
+
+        let feeder_ctx = ctx.clone();
+        ctx.execute_task(async move {
+            let mut input_stream = match input_stream {
+                None => return,
+                Some(input_stream) => input_stream,
+            };
+            while let Some(block) = input_stream.next().await {
+                if let Err(cause) = feed_block(&feeder_ctx, &feeds, &sender, block).await {
+                    // The appends abort with the error instead of committing the blocks sent
+                    for (_, _, view_sender) in &feeds {
+                        let _ = view_sender.send(Err(cause.clone())).await;
+                    }
+                    let _ = sender.send(Err(cause)).await;
+                    break;
+                }
+            }
+        })?;
+
+        let append = table.raw().append_data(ctx.clone(), insert_plan);
+        appends.push(append.boxed());
+        futures::future::try_join_all(appends).await?;
+        Ok(())
+    }
+    .boxed()
+}
+
+/// Sends the results of the queries of the views over the block to their target tables, then
+/// the block to the table.
+async fn feed_block(
+    ctx: &DatabendQueryContextRef,
+    feeds: &[ViewFeed],
+    sender: &Sender<Result<DataBlock>>,
+    block: Result<DataBlock>,
+) -> Result<()> {
+    let block = block?;
+    for (working_table, plan, view_sender) in feeds {
+        ctx.set_cte_working_table(working_table, Arc::new(vec![block.clone()]));
+        for result in execute_query(ctx.clone(), plan).await? {
+            send(view_sender, result).await?;
+        }
+    }
+    send(sender, block).await
+}
+
+async fn send(sender: &Sender<Result<DataBlock>>, block: DataBlock) -> Result<()> {
+    sender
+        .send(Ok(block))
+        .await
+        .map_err(|_| ErrorCode::AbortedQuery("The insert into the materialized view is aborted"))
+}
+
+/// The materialized views reading from the table, as (database, query, info).
+///
+/// The views are indexed by the options of their source table, see `ViewTable::source_table_option`.
+fn materialized_views_of(
+    ctx: &DatabendQueryContextRef,
+    db: &str,
+    table: &str,
+) -> Result<Vec<(String, String, MaterializedViewInfo)>> {
+    let options = ctx.get_catalog().get_table_options(db, table)?;
+    let mut views = vec![];
+    for (view_db, view_name) in ViewTable::materialized_views_of(&options) {
+        let table_meta = match ctx.get_table(&view_db, &view_name) {
+            Ok(table_meta) => table_meta,
+            // The view is dropped with its database, which leaves the index behind
+            Err(e) if e.code() == ErrorCode::UnknownDatabase("").code() => continue,
+            Err(e) => return Err(e),
+        };
+        if let Some(view) = table_meta.raw().as_any().downcast_ref::<ViewTable>() {
+            if let Some(info) = view.materialized()? {
+                views.push((view_db, view.query()?.to_string(), info));
+            }
+        }
+    }
+    Ok(views)
+}
+
+async fn execute_query(ctx: DatabendQueryContextRef, plan: &PlanNode) -> Result<Vec<DataBlock>> {
+    let query_ctx = DatabendQueryContext::new(ctx);
+    let optimized_plan = Optimizers::without_scatters(query_ctx.clone()).optimize(plan)?;
+    let mut pipeline = PipelineBuilder::create(query_ctx).build(&optimized_plan)?;
+    let stream = pipeline.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::MaterializedViewInfo;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ScanPlan;
+use common_planners::TableOptions;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Table;
+use crate::sessions::DatabendQueryContextRef;
+
+pub const VIEW_ENGINE: &str = "VIEW";
+pub const MATERIALIZED_VIEW_ENGINE: &str = "MATERIALIZED_VIEW";
+
+// The table options of the views.
+pub const OPT_KEY_QUERY: &str = "query";
+pub const OPT_KEY_SOURCE_DB: &str = "source_db";
+pub const OPT_KEY_SOURCE_TABLE: &str = "source_table";
+pub const OPT_KEY_TARGET_DB: &str = "target_db";
+pub const OPT_KEY_TARGET_TABLE: &str = "target_table";
+
+// The table options of the source tables, one for each materialized view reading from the table.
+pub const OPT_KEY_MATERIALIZED_VIEW_PREFIX: &str = "materialized_view.";
+
+/// The views are kept in the meta service as tables, the query is kept in the table options.
+///
+/// A view is expanded by the `PlanParser` where it is referenced. A materialized view reads from
+/// its target table, and its query is applied to the blocks inserted into its source table.
+pub struct ViewTable {
+    db: String,
+    name: String,
+    schema: DataSchemaRef,
+    options: TableOptions,
+    materialized: bool,
+}
+
+impl ViewTable {
+    pub fn try_create(
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        options: TableOptions,
+    ) -> Result<Box<dyn Table>> {
+        Self::create(db, name, schema, options, false)
+    }
+
+    pub fn try_create_materialized(
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        options: TableOptions,
+    ) -> Result<Box<dyn Table>> {
+        Self::create(db, name, schema, options, true)
+    }
+
+    fn create(
+        db: String,
+        name: String,
+        schema: DataSchemaRef,
+        options: TableOptions,
+        materialized: bool,
+    ) -> Result<Box<dyn Table>> {
+        let table = Self {
+            db,
+            name,
+            schema,
+            options,
+            materialized,
+        };
+        table.option(OPT_KEY_QUERY)?;
+        if materialized {
+            table.materialized_view_info()?;
+        }
+        Ok(Box::new(table))
+    }
+
+    /// The database the view is defined in, unqualified table names of the query are in it.
+    pub fn database(&self) -> &str {
+        &self.db
+    }
+
+    /// The SQL text of the query.
+    pub fn query(&self) -> Result<&str> {
+        self.option(OPT_KEY_QUERY)
+    }
+
+    /// None if the view is not materialized.
+    pub fn materialized(&self) -> Result<Option<MaterializedViewInfo>> {
+        match self.materialized {
+            false => Ok(None),
+            true => self.materialized_view_info().map(Some),
+        }
+    }
+
+    /// The table options of the view to be kept in the meta service.
+    pub fn view_options(query: &str, materialized: Option<&MaterializedViewInfo>) -> TableOptions {
+        let mut options = TableOptions::new();
+        options.insert(OPT_KEY_QUERY.to_string(), query.to_string());
+        if let Some(mv) = materialized {
+            options.insert(OPT_KEY_SOURCE_DB.to_string(), mv.source_db.clone());
+            options.insert(OPT_KEY_SOURCE_TABLE.to_string(), mv.source_table.clone());
+            options.insert(OPT_KEY_TARGET_DB.to_string(), mv.target_db.clone());
+            options.insert(OPT_KEY_TARGET_TABLE.to_string(), mv.target_table.clone());
+        }
+        options
+    }
+
+    /// The option of the source table indexing the materialized view `db.view`, as (key, value).
+    pub fn source_table_option(db: &str, view: &str) -> (String, String) {
+        let key = format!("{}{}.{}", OPT_KEY_MATERIALIZED_VIEW_PREFIX, db, view);
+        (key, db.to_string())
+    }
+
+    /// The materialized views indexed by the options of their source table, as (database, view).
+    pub fn materialized_views_of(options: &TableOptions) -> Vec<(String, String)> {
+        options
+            .iter()
+            .filter_map(|(key, db)| {
+                let name = key.strip_prefix(OPT_KEY_MATERIALIZED_VIEW_PREFIX)?;
+                let view = name.strip_prefix(db.as_str())?.strip_prefix('.')?;
+                Some((db.clone(), view.to_string()))
+            })
+            .collect()
+    }
+
+    fn materialized_view_info(&self) -> Result<MaterializedViewInfo> {
+        Ok(MaterializedViewInfo {
+            source_db: self.option(OPT_KEY_SOURCE_DB)?.to_string(),
+            source_table: self.option(OPT_KEY_SOURCE_TABLE)?.to_string(),
+            target_db: self.option(OPT_KEY_TARGET_DB)?.to_string(),
+            target_table: self.option(OPT_KEY_TARGET_TABLE)?.to_string(),
+            target_engine: None,
+        })
+    }
+
+    fn option(&self, key: &str) -> Result<&str> {
+        self.options.get(key).map(|v| v.as_str()).ok_or_else(|| {
+            ErrorCode::BadOption(format!(
+                "View {}.{} is missing the option {}",
+                self.db, self.name, key
+            ))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ViewTable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn engine(&self) -> &str {
+        match self.materialized {
+            false => "View",
+            true => "MaterializedView",
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn read_plan(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _scan: &ScanPlan,
+        _partitions: usize,
+    ) -> Result<ReadDataSourcePlan> {
+        Err(ErrorCode::LogicalError(format!(
+            "View {}.{} should be expanded by the PlanParser",
+            self.db, self.name
+        )))
+    }
+
+    async fn read(
+        &self,
+        _ctx: DatabendQueryContextRef,
+        _source_plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Err(ErrorCode::LogicalError(format!(
+            "View {}.{} should be expanded by the PlanParser",
+            self.db, self.name
+        )))
+    }
+}
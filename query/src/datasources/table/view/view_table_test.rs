@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+
+use crate::datasources::table::view::view_table::ViewTable;
+
+#[test]
+fn test_view_table() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+
+    // view
+    {
+        let options = ViewTable::view_options("SELECT a FROM t", None);
+        let table = ViewTable::try_create("db".into(), "v".into(), schema.clone(), options)?;
+        let view = table.as_any().downcast_ref::<ViewTable>().unwrap();
+        assert_eq!("View", table.engine());
+        assert_eq!("db", view.database());
+        assert_eq!("SELECT a FROM t", view.query()?);
+        assert_eq!(None, view.materialized()?);
+    }
+
+    // materialized view
+    {
+        let mv = MaterializedViewInfo {
+            source_db: "db".to_string(),
+            source_table: "t".to_string(),
+            target_db: "db".to_string(),
+            target_table: "t2".to_string(),
+            target_engine: None,
+        };
+        let options = ViewTable::view_options("SELECT a FROM t", Some(&mv));
+        let table =
+            ViewTable::try_create_materialized("db".into(), "mv".into(), schema.clone(), options)?;
+        let view = table.as_any().downcast_ref::<ViewTable>().unwrap();
+        assert_eq!("MaterializedView", table.engine());
+        assert_eq!(Some(mv), view.materialized()?);
+    }
+
+    // materialized views indexed by the source table
+    {
+        let mut options = TableOptions::new();
+        options.insert("comment".to_string(), "x".to_string());
+        for (db, view) in [("db", "mv"), ("db.x", "mv.y")] {
+            let (key, value) = ViewTable::source_table_option(db, view);
+            options.insert(key, value);
+        }
+        let mut views = ViewTable::materialized_views_of(&options);
+        views.sort();
+        let expect = vec![
+            ("db".to_string(), "mv".to_string()),
+            ("db.x".to_string(), "mv.y".to_string()),
+        ];
+        assert_eq!(expect, views);
+    }
+
+    // missing query
+    {
+        let result = ViewTable::try_create("db".into(), "v".into(), schema, TableOptions::new());
+        let expect = "Code: 22, displayText = View db.v is missing the option query.";
+        assert_eq!(expect, result.err().unwrap().to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_datavalues::series::Series;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CopiedFiles;
+use common_planners::CopyIntoPlan;
+use common_planners::Part;
+use common_planners::PlanNode;
+use common_planners::ReadFilesPlan;
+use common_planners::SelectPlan;
+use common_planners::SinkPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+use regex::Regex;
+
+use crate::datasources::dal::DataAccessor;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::transforms::ReadFilesTransform;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct CopyIntoInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: CopyIntoPlan,
+}
+
+impl CopyIntoInterpreter {
+    pub fn try_create(ctx: DatabendQueryContextRef, plan: CopyIntoPlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        // Check the options before listing the files.
+        ReadFilesTransform::format_settings(&self.plan.file_format)?;
+
+        let (accessor, prefix) = self.ctx.get_location_accessor(&self.plan.location)?;
+        let files = self.list_files(accessor.as_ref(), &prefix).await?;
+
+        match self.plan.validation_mode {
+            true => self.validate_files(accessor, files).await,
+            false => self.copy_files(files).await,
+        }
+    }
+}
+
+impl CopyIntoInterpreter {
+    /// The files under the location whose path matches the whole pattern, sorted by path.
+    async fn list_files(&self, accessor: &dyn DataAccessor, prefix: &str) -> Result<Vec<String>> {
+        let mut files = accessor.list(prefix).await?;
+        if let Some(pattern) = &self.plan.pattern {
+            let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+                ErrorCode::BadArguments(format!("Invalid PATTERN {}: {}", pattern, e))
+            })?;
+            files.retain(|file| regex.is_match(file));
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    // The location of the listed file, with the scheme and the bucket.
+    fn file_location(&self, file: &str) -> String {
+        self.read_files_plan(vec![]).file_location(file)
+    }
+
+    /// VALIDATION_MODE = RETURN_ERRORS, parse the files on this node and return the
+    /// first error of each file, nothing is loaded.
+    async fn validate_files(
+        &self,
+        accessor: Arc<dyn DataAccessor>,
+        files: Vec<String>,
+    ) -> Result<SendableDataBlockStream> {
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+
+        let mut error_files = vec![];
+        let mut errors = vec![];
+        for file in files {
+            let stream = ReadFilesTransform::read_file(
+                accessor.clone(),
+                file.clone(),
+                &self.plan.file_format,
+                self.plan.schema(),
+                block_size,
+            );
+
+            let error = match stream.await {
+                Err(cause) => Some(cause),
+                Ok(mut stream) => loop {
+                    match stream.next().await {
+                        None => break None,
+                        Some(Ok(_)) => continue,
+                        Some(Err(cause)) => break Some(cause),
+                    }
+                },
+            };
+
+            if let Some(cause) = error {
+                error_files.push(self.file_location(&file));
+                errors.push(cause.message());
+            }
+        }
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("error", DataType::String, false),
+        ]);
+        Self::result_stream(schema, error_files, errors)
+    }
+
+    /// Load the files which have not been loaded into the table, or all of them with
+    /// FORCE = TRUE, the files are read in parallel by the cluster in case of S3.
+    /// The table records the loaded files in the same commit as their data, a file
+    /// loaded by a concurrent COPY fails the commit.
+    async fn copy_files(&self, files: Vec<String>) -> Result<SendableDataBlockStream> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.db_name, &self.plan.tbl_name)?;
+        let copied_files = table.raw().copied_files(self.ctx.clone())?;
+
+        let mut loaded = vec![];
+        let mut locations = vec![];
+        let mut status = vec![];
+        for file in files {
+            let location = self.file_location(&file);
+            match !self.plan.force && copied_files.contains(&location) {
+                true => status.push("SKIPPED".to_string()),
+                false => {
+                    status.push("LOADED".to_string());
+                    loaded.push(file);
+                }
+            }
+            locations.push(location);
+        }
+
+        if !loaded.is_empty() {
+            self.load_files(loaded).await?;
+        }
+
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("status", DataType::String, false),
+        ]);
+        Self::result_stream(schema, locations, status)
+    }
+
+    // Run `Select(Sink(ReadFiles))`, like `INSERT INTO ... SELECT`.
+    async fn load_files(&self, files: Vec<String>) -> Result<()> {
+        let copied_files = CopiedFiles {
+            files: files.iter().map(|file| self.file_location(file)).collect(),
+            force: self.plan.force,
+        };
+        let read_files = self.read_files_plan(files);
+
+        let sink = PlanNode::Sink(SinkPlan {
+            db_name: self.plan.db_name.clone(),
+            tbl_name: self.plan.tbl_name.clone(),
+            tbl_id: self.plan.tbl_id,
+            schema: self.plan.schema(),
+            input: Arc::new(PlanNode::ReadFiles(read_files)),
+            copied_files: Some(copied_files),
+        });
+        let select = SelectPlan {
+            input: Arc::new(sink),
+        };
+
+        let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select)?;
+        let mut stream = interpreter.execute().await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    fn read_files_plan(&self, files: Vec<String>) -> ReadFilesPlan {
+        ReadFilesPlan {
+            location: self.plan.location.clone(),
+            file_format: self.plan.file_format.clone(),
+            schema: self.plan.schema(),
+            parts: files
+                .into_iter()
+                .map(|name| Part { name, version: 0 })
+                .collect(),
+        }
+    }
+
+    fn result_stream(
+        schema: DataSchemaRef,
+        files: Vec<String>,
+        values: Vec<String>,
+    ) -> Result<SendableDataBlockStream> {
+        let files: Vec<&[u8]> = files.iter().map(|x| x.as_bytes()).collect();
+        let values: Vec<&[u8]> = values.iter().map(|x| x.as_bytes()).collect();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(files),
+            Series::new(values),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
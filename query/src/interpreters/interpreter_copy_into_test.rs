@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fs;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::configs::Config;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+fn string_values(block: &DataBlock, column: usize) -> Result<Vec<DataValue>> {
+    block.column(column).to_values()
+}
+
+fn string_value(value: &str) -> DataValue {
+    DataValue::String(Some(value.as_bytes().to_vec()))
+}
+
+// The context reads the local files from the stage directory.
+fn try_create_stage_context(stage_dir: &tempfile::TempDir) -> Result<DatabendQueryContextRef> {
+    let mut config = Config::default();
+    config.query.local_stage_dir = stage_dir.path().to_str().unwrap().to_string();
+    crate::tests::try_create_context_with_config(config)
+}
+
+#[tokio::test]
+async fn test_copy_into_interpreter() -> Result<()> {
+    for engine in ["Memory", "Fuse"] {
+        let dir = tempfile::tempdir()?;
+        let ctx = try_create_stage_context(&dir)?;
+        fs::create_dir(dir.path().join("data"))?;
+        fs::write(dir.path().join("data/a.csv"), "1,2\n3,4\n")?;
+        fs::write(dir.path().join("data/b.csv"), "5,6\n")?;
+        fs::write(dir.path().join("data/c.txt"), "7,8\n")?;
+
+        let sql = format!(
+            "create table default.a(a Int64, b UInt64) Engine = {}",
+            engine
+        );
+        execute_sql(&ctx, &sql).await?;
+
+        // Load the csv files only.
+        {
+            let sql = "copy into default.a from 'file:///data/' pattern = '.*\\.csv' file_format = (type = CSV)";
+            let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+            let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+            assert_eq!(executor.name(), "CopyIntoInterpreter");
+
+            let result = executor.execute().await?.try_collect::<Vec<_>>().await?;
+            assert_eq!(string_values(&result[0], 0)?, vec![
+                string_value("file:///data/a.csv"),
+                string_value("file:///data/b.csv"),
+            ]);
+            assert_eq!(string_values(&result[0], 1)?, vec![
+                string_value("LOADED"),
+                string_value("LOADED"),
+            ]);
+
+            let result = execute_sql(&ctx, "select * from default.a").await?;
+            let expected = vec![
+                "+---+---+",
+                "| a | b |",
+                "+---+---+",
+                "| 1 | 2 |",
+                "| 3 | 4 |",
+                "| 5 | 6 |",
+                "+---+---+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        }
+
+        // The loaded files are recorded by the table and skipped, unless FORCE = TRUE.
+        {
+            fs::write(dir.path().join("data/d.csv"), "9,10\n")?;
+            let sql = "copy into default.a from 'file:///data/' pattern = '.*\\.csv'";
+            let result = execute_sql(&ctx, sql).await?;
+            assert_eq!(string_values(&result[0], 1)?, vec![
+                string_value("SKIPPED"),
+                string_value("SKIPPED"),
+                string_value("LOADED"),
+            ]);
+
+            let table = ctx.get_table("default", "a")?;
+            let mut copied_files = table
+                .raw()
+                .copied_files(ctx.clone())
+                .await?
+                .into_iter()
+                .collect::<Vec<_>>();
+            copied_files.sort();
+            assert_eq!(copied_files, vec![
+                "file:///data/a.csv",
+                "file:///data/b.csv",
+                "file:///data/d.csv",
+            ]);
+
+            let sql = "copy into default.a from 'file:///data/' pattern = '.*b\\.csv' force = true";
+            let result = execute_sql(&ctx, sql).await?;
+            assert_eq!(string_values(&result[0], 1)?, vec![string_value("LOADED")]);
+
+            let result = execute_sql(&ctx, "select count(*) as c from default.a").await?;
+            let expected = vec!["+---+", "| c |", "+---+", "| 5 |", "+---+"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        }
+
+        // Validation mode returns the errors without loading.
+        {
+            fs::write(dir.path().join("data/e.csv"), "11,x\n")?;
+            let sql = "copy into default.a from 'file:///data/' pattern = '.*\\.csv' validation_mode = return_errors";
+            let result = execute_sql(&ctx, sql).await?;
+            assert_eq!(string_values(&result[0], 0)?, vec![string_value(
+                "file:///data/e.csv"
+            )]);
+
+            let result = execute_sql(&ctx, "select count(*) as c from default.a").await?;
+            let expected = vec!["+---+", "| c |", "+---+", "| 5 |", "+---+"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        }
+
+        execute_sql(&ctx, "drop table default.a").await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_copy_into_conflict() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let ctx = try_create_stage_context(&dir)?;
+    fs::write(dir.path().join("a.csv"), "1\n2\n")?;
+
+    execute_sql(&ctx, "create table default.t(a UInt64) Engine = Fuse").await?;
+    execute_sql(&ctx, "copy into default.t from 'file:///'").await?;
+
+    // Another COPY commits a file which has been recorded in the meantime.
+    let table = ctx.get_table("default", "t")?;
+    let schema = table.raw().schema()?;
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![3u64])]);
+    let insert_plan = InsertIntoPlan {
+        db_name: "default".to_string(),
+        tbl_name: "t".to_string(),
+        tbl_id: table.meta_id(),
+        schema,
+        select_plan: None,
+        format: None,
+        copied_files: Some(CopiedFiles {
+            files: vec!["file:///a.csv".to_string()],
+            force: false,
+        }),
+        input_stream: Arc::new(Mutex::new(Some(Box::pin(futures::stream::iter(vec![Ok(
+            block,
+        )]))))),
+    };
+    let result = table.raw().append_data(ctx.clone(), insert_plan).await;
+    let error = result.unwrap_err();
+    assert_eq!(error.code(), ErrorCode::CommitTableConflict("").code());
+    assert_eq!(
+        error.message(),
+        "File file:///a.csv has been loaded into table default.t by another COPY"
+    );
+
+    let result = execute_sql(&ctx, "select * from default.t").await?;
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_copy_into_local_location() -> Result<()> {
+    // Local files can't be loaded without a stage directory.
+    {
+        let ctx = crate::tests::try_create_context()?;
+        execute_sql(&ctx, "create table default.a(a Int64) Engine = Memory").await?;
+        let result = execute_sql(&ctx, "copy into default.a from 'file:///etc/'").await;
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Can't load local files from file:///etc/, local_stage_dir is not configured"
+        );
+
+        let result = execute_sql(&ctx, "copy into default.a from '/etc/'").await;
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Unsupported location: /etc/, expect s3://bucket/path or file:///path"
+        );
+
+        let result = execute_sql(&ctx, "copy into default.a from 'hdfs://data/'").await;
+        assert_eq!(
+            result.unwrap_err().message(),
+            "Unsupported location: hdfs://data/, expect s3://bucket/path or file:///path"
+        );
+    }
+
+    // The files out of the stage directory can't be read.
+    {
+        let dir = tempfile::tempdir()?;
+        let ctx = try_create_stage_context(&dir)?;
+        execute_sql(&ctx, "create table default.a(a Int64) Engine = Memory").await?;
+        let result = execute_sql(&ctx, "copy into default.a from 'file:///../'").await;
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_sorted_eq;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::datasources::table::fuse::read_segment_async;
+use crate::datasources::table::fuse::FuseTable;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+// The locations of the blocks of the committed snapshot, in the order of the segments.
+async fn block_locations(ctx: &DatabendQueryContextRef) -> Result<Vec<String>> {
+    let table = ctx.get_table("default", "a")?;
+    let table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+    let da = table.data_accessor(ctx)?;
+
+    let mut locations = vec![];
+    if let Some(snapshot) = table.committed_snapshot(ctx)? {
+        for seg_loc in &snapshot.segments {
+            let segment = read_segment_async(da.clone(), seg_loc).await?;
+            locations.extend(segment.blocks.into_iter().map(|b| b.location.location));
+        }
+    }
+    Ok(locations)
+}
+
+// One block for each insert.
+async fn create_table(ctx: &DatabendQueryContextRef) -> Result<()> {
+    execute_sql(
+        ctx,
+        "create table default.a(a Int64, b UInt64) Engine = Fuse",
+    )
+    .await?;
+    execute_sql(ctx, "insert into default.a values(1, 10), (2, 20)").await?;
+    execute_sql(ctx, "insert into default.a values(3, 30), (4, 40)").await?;
+    execute_sql(ctx, "insert into default.a values(5, 50)").await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_delete_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    create_table(&ctx).await?;
+    let blocks = block_locations(&ctx).await?;
+    assert_eq!(blocks.len(), 3);
+
+    // Only the block of the deleted row is rewritten.
+    {
+        let plan =
+            PlanParser::create(ctx.clone()).build_from_sql("delete from default.a where a = 3")?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "DeleteInterpreter");
+        executor.execute().await?;
+
+        let result = execute_sql(&ctx, "select * from default.a").await?;
+        let expected = vec![
+            "+---+----+",
+            "| a | b  |",
+            "+---+----+",
+            "| 1 | 10 |",
+            "| 2 | 20 |",
+            "| 4 | 40 |",
+            "| 5 | 50 |",
+            "+---+----+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let new_blocks = block_locations(&ctx).await?;
+        assert_eq!(new_blocks.len(), 3);
+        assert!(new_blocks.contains(&blocks[0]));
+        assert!(!new_blocks.contains(&blocks[1]));
+        assert!(new_blocks.contains(&blocks[2]));
+    }
+
+    // The block whose rows are all deleted is removed from the table.
+    {
+        execute_sql(&ctx, "delete from default.a where a >= 5").await?;
+
+        let result = execute_sql(&ctx, "select a from default.a").await?;
+        let expected = vec![
+            "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 4 |", "+---+",
+        ];
+        assert_blocks_sorted_eq(expected, result.as_slice());
+
+        let new_blocks = block_locations(&ctx).await?;
+        assert_eq!(new_blocks.len(), 2);
+        assert!(new_blocks.contains(&blocks[0]));
+        assert!(!new_blocks.contains(&blocks[2]));
+    }
+
+    // The blocks which can't match the predicate are not read: the first block is
+    // removed from the storage, and the delete of another block still succeeds.
+    {
+        let table = ctx.get_table("default", "a")?;
+        let table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+        table.data_accessor(&ctx)?.remove(&blocks[0]).await?;
+
+        execute_sql(&ctx, "delete from default.a where a = 4").await?;
+        assert_eq!(block_locations(&ctx).await?, vec![blocks[0].clone()]);
+
+        let result = execute_sql(&ctx, "delete from default.a where a = 1").await;
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
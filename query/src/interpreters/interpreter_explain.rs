@@ -12,20 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::ExplainPlan;
 use common_planners::ExplainType;
+use common_planners::PlanNode;
+use common_planners::SelectPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
+use crate::interpreters::interpreter_select::Scheduled;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
 use crate::optimizers::Optimizers;
 use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::StageProfilesDisplay;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct ExplainInterpreter {
@@ -46,6 +54,7 @@ impl Interpreter for ExplainInterpreter {
             ExplainType::Graph => self.explain_graph(),
             ExplainType::Syntax => self.explain_syntax(),
             ExplainType::Pipeline => self.explain_pipeline(),
+            ExplainType::Analyze => self.explain_analyze().await,
         }?;
 
         Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
@@ -101,4 +110,85 @@ impl ExplainInterpreter {
         );
         Ok(DataBlock::create_by_array(schema, vec![formatted_pipeline]))
     }
+
+    async fn explain_analyze(&self) -> Result<DataBlock> {
+        let select = match self.explain.input.as_ref() {
+            PlanNode::Select(select) => select,
+            _ => {
+                return Err(ErrorCode::UnImplement(
+                    "EXPLAIN ANALYZE only supports SELECT",
+                ))
+            }
+        };
+
+        // Profile the local pipeline and the remote stages of the query.
+        self.ctx.enable_query_profile();
+
+        let mut scheduled = Scheduled::new();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        match self.analyze_query(select, &mut scheduled, timeout).await {
+            Ok(lines) => {
+                let schema = self.schema();
+                let formatted = Series::new(lines.iter().map(|s| s.as_bytes()).collect::<Vec<_>>());
+                Ok(DataBlock::create_by_array(schema, vec![formatted]))
+            }
+            Err(error) => {
+                SelectInterpreter::error_handler(scheduled, &self.ctx, timeout).await;
+                Err(error)
+            }
+        }
+    }
+
+    async fn analyze_query(
+        &self,
+        select: &SelectPlan,
+        scheduled: &mut Scheduled,
+        timeout: u64,
+    ) -> Result<Vec<String>> {
+        let plan = Optimizers::create(self.ctx.clone()).optimize(&select.input)?;
+        let mut pipeline = SelectInterpreter::schedule_plan(&self.ctx, &plan, scheduled).await?;
+
+        // Run the query to the end, the results are discarded.
+        let mut stream = pipeline.execute().await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+
+        let mut lines = format!("{}", pipeline.display_analyze())
+            .lines()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>();
+
+        // The remote stages have saved their profiles before finishing their streams.
+        let query_id = self.ctx.get_id();
+        let config = self.ctx.get_config();
+        let cluster = self.ctx.get_cluster();
+        let mut nodes = scheduled.values().collect::<Vec<_>>();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        for node in nodes {
+            let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
+            let profiles = flight_client
+                .fetch_query_profile(query_id.clone(), timeout)
+                .await?;
+
+            let mut stages = BTreeMap::new();
+            for profile in profiles {
+                stages
+                    .entry(profile.stage.clone())
+                    .or_insert_with(Vec::new)
+                    .push(profile);
+            }
+
+            for (stage, profiles) in stages {
+                let display = StageProfilesDisplay {
+                    stage,
+                    node: node.id.clone(),
+                    profiles,
+                };
+                lines.extend(format!("{}", display).lines().map(|s| s.to_string()));
+            }
+        }
+
+        Ok(lines)
+    }
 }
@@ -54,3 +54,48 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    if let PlanNode::Explain(plan) = PlanParser::create(ctx.clone())
+        .build_from_sql("explain analyze select number from numbers_mt(10) where number > 4")?
+    {
+        let executor = ExplainInterpreter::try_create(ctx.clone(), plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let block = &result[0];
+        assert_eq!(block.num_columns(), 1);
+
+        let lines = block
+            .column(0)
+            .to_array()?
+            .string()?
+            .into_no_null_iter()
+            .map(|s| String::from_utf8_lossy(s).to_string())
+            .collect::<Vec<_>>();
+
+        assert!(lines[0].contains("rows out: 5,"));
+        assert!(lines.iter().all(|line| line.contains("rows out")));
+        assert!(lines
+            .last()
+            .unwrap()
+            .trim_start()
+            .starts_with("SourceTransform"));
+        assert!(lines.last().unwrap().contains("rows out: 10,"));
+    } else {
+        assert!(false)
+    }
+
+    // Only SELECT can be analyzed.
+    if let PlanNode::Explain(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("explain analyze show tables")?
+    {
+        let executor = ExplainInterpreter::try_create(ctx, plan)?;
+        let result = executor.execute().await;
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
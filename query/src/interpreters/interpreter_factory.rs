@@ -16,21 +16,32 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::ExplainType;
 use common_planners::PlanNode;
 
 use crate::interpreters::interpreter_kill::KillInterpreter;
+use crate::interpreters::AlterTableInterpreter;
+use crate::interpreters::CopyIntoInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateResourceGroupInterpreter;
 use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::CreateViewInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
+use crate::interpreters::DropResourceGroupInterpreter;
 use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::ExplainInterpreter;
 use crate::interpreters::InsertIntoInterpreter;
+use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterQueryLog;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
 use crate::interpreters::ShowCreateTableInterpreter;
+use crate::interpreters::TransactionInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
 use crate::sessions::DatabendQueryContextRef;
 
@@ -38,6 +49,28 @@ pub struct InterpreterFactory;
 
 impl InterpreterFactory {
     pub fn get(ctx: DatabendQueryContextRef, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
+        let query_log = InterpreterQueryLog::create(ctx.clone(), &plan);
+        let admission = InterpreterFactory::need_admission(&plan);
+        let inner = InterpreterFactory::create(ctx.clone(), plan)?;
+        Ok(InterceptorInterpreter::create(
+            ctx, inner, query_log, admission,
+        ))
+    }
+
+    // The queries which read or write the tables wait for their resource group to run.
+    fn need_admission(plan: &PlanNode) -> bool {
+        match plan {
+            PlanNode::Select(_)
+            | PlanNode::InsertInto(_)
+            | PlanNode::CopyInto(_)
+            | PlanNode::Delete(_)
+            | PlanNode::Update(_) => true,
+            PlanNode::Explain(v) => v.typ == ExplainType::Analyze,
+            _ => false,
+        }
+    }
+
+    fn create(ctx: DatabendQueryContextRef, plan: PlanNode) -> Result<Arc<dyn Interpreter>> {
         match plan {
             PlanNode::Select(v) => SelectInterpreter::try_create(ctx, v),
             PlanNode::Explain(v) => ExplainInterpreter::try_create(ctx, v),
@@ -45,13 +78,21 @@ impl InterpreterFactory {
             PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx, v),
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx, v),
+            PlanNode::AlterTable(v) => AlterTableInterpreter::try_create(ctx, v),
+            PlanNode::CreateView(v) => CreateViewInterpreter::try_create(ctx, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx, v),
             PlanNode::InsertInto(v) => InsertIntoInterpreter::try_create(ctx, v),
+            PlanNode::CopyInto(v) => CopyIntoInterpreter::try_create(ctx, v),
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx, v),
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx, v),
+            PlanNode::Transaction(v) => TransactionInterpreter::try_create(ctx, v),
+            PlanNode::CreateResourceGroup(v) => CreateResourceGroupInterpreter::try_create(ctx, v),
+            PlanNode::DropResourceGroup(v) => DropResourceGroupInterpreter::try_create(ctx, v),
             _ => Result::Err(ErrorCode::UnknownTypeOfQuery(format!(
                 "Can't get the interpreter by plan:{}",
                 plan.name()
@@ -14,14 +14,21 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::InsertIntoPlan;
+use common_planners::PlanNode;
+use common_planners::SelectPlan;
+use common_planners::SinkPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
 
 use crate::catalogs::Catalog;
+use crate::datasources::table::append_data_with_views;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct InsertIntoInterpreter {
@@ -45,13 +52,59 @@ impl Interpreter for InsertIntoInterpreter {
     }
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
+        if let Some(select_plan) = &self.plan.select_plan {
+            return self.execute_select(select_plan).await;
+        }
+
+        if let Some(format) = &self.plan.format {
+            if self.plan.input_stream.lock().is_none() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "INSERT INTO ... FORMAT {} expects the data to be streamed by the client, \
+                    use the ClickHouse or the HTTP handler",
+                    format
+                )));
+            }
+        }
+
         let datasource = self.ctx.get_catalog();
         let database = datasource.get_database(self.plan.db_name.as_str())?;
         let table = database.get_table_by_id(self.plan.tbl_id, None)?;
-        table
-            .raw()
-            .append_data(self.ctx.clone(), self.plan.clone())
-            .await?;
+        append_data_with_views(self.ctx.clone(), table, self.plan.clone()).await?;
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
+
+impl InsertIntoInterpreter {
+    /// `INSERT INTO ... SELECT` runs the query with a sink on top, so the blocks are written
+    /// as they come out of the pipeline, and by every node in cluster mode.
+    async fn execute_select(&self, select_plan: &PlanNode) -> Result<SendableDataBlockStream> {
+        let input = match select_plan {
+            PlanNode::Select(select) => select.input.clone(),
+            other => Arc::new(other.clone()),
+        };
+
+        let sink = PlanNode::Sink(SinkPlan {
+            db_name: self.plan.db_name.clone(),
+            tbl_name: self.plan.tbl_name.clone(),
+            tbl_id: self.plan.tbl_id,
+            schema: self.plan.schema(),
+            input,
+            copied_files: None,
+        });
+        let select = SelectPlan {
+            input: Arc::new(sink),
+        };
+
+        let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select)?;
+        let mut stream = interpreter.execute().await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
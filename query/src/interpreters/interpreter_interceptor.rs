@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterQueryLog;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::ResourceGroupTicket;
+
+/// Wraps the interpreter created by `InterpreterFactory` to log the start and the end of the
+/// query into system.query_log, and to admit the query by its resource group.
+pub struct InterceptorInterpreter {
+    ctx: DatabendQueryContextRef,
+    inner: InterpreterPtr,
+    query_log: Arc<InterpreterQueryLog>,
+    admission: bool,
+}
+
+impl InterceptorInterpreter {
+    pub fn create(
+        ctx: DatabendQueryContextRef,
+        inner: InterpreterPtr,
+        query_log: InterpreterQueryLog,
+        admission: bool,
+    ) -> InterpreterPtr {
+        Arc::new(InterceptorInterpreter {
+            ctx,
+            inner,
+            query_log: Arc::new(query_log),
+            admission,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for InterceptorInterpreter {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        self.query_log.log_start();
+
+        let ticket = match self.admission {
+            false => None,
+            true => match self.ctx.admit_query().await {
+                Ok(ticket) => ticket,
+                Err(cause) => {
+                    self.query_log.log_finish(0, 0, Some(&cause));
+                    return Err(cause);
+                }
+            },
+        };
+
+        match self.inner.execute().await {
+            Ok(input) => Ok(Box::pin(QueryLogStream {
+                input,
+                ticket,
+                query_log: self.query_log.clone(),
+                result_rows: 0,
+                result_bytes: 0,
+                logged: false,
+            })),
+            Err(cause) => {
+                self.query_log.log_finish(0, 0, Some(&cause));
+                Err(cause)
+            }
+        }
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.inner.schema()
+    }
+}
+
+/// Counts the result of the query, and logs the end of the query once the input is exhausted
+/// or failed.
+struct QueryLogStream {
+    input: SendableDataBlockStream,
+    // The slot of the query in its resource group, released once the query finishes.
+    ticket: Option<ResourceGroupTicket>,
+    query_log: Arc<InterpreterQueryLog>,
+    result_rows: u64,
+    result_bytes: u64,
+    logged: bool,
+}
+
+impl Stream for QueryLogStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.logged {
+            return Poll::Ready(None);
+        }
+
+        let item = futures::ready!(this.input.poll_next_unpin(ctx));
+        match &item {
+            Some(Ok(block)) => {
+                this.result_rows += block.num_rows() as u64;
+                this.result_bytes += block.memory_size() as u64;
+                return Poll::Ready(item);
+            }
+            Some(Err(cause)) => {
+                this.query_log
+                    .log_finish(this.result_rows, this.result_bytes, Some(cause))
+            }
+            None => this
+                .query_log
+                .log_finish(this.result_rows, this.result_bytes, None),
+        };
+
+        this.logged = true;
+        this.ticket.take();
+        Poll::Ready(item)
+    }
+}
+
+impl Drop for QueryLogStream {
+    fn drop(&mut self) {
+        // The result is not fully consumed.
+        if !self.logged {
+            self.query_log
+                .log_finish(self.result_rows, self.result_bytes, None);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::datasources::table::fuse::FuseTable;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::QueryLogElement;
+use crate::sessions::QueryLogType;
+use crate::sql::*;
+use crate::tests::SessionManagerBuilder;
+
+fn query_logs(ctx: &crate::sessions::DatabendQueryContextRef) -> Vec<QueryLogElement> {
+    let query_log = ctx.get_sessions_manager().get_query_log();
+    let logs = query_log.get_logs().into_iter();
+    logs.filter(|log| log.query_id == ctx.get_id()).collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interceptor_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let query = "select number from numbers_mt(3)";
+    ctx.attach_query_str(query);
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(query)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    assert_eq!(executor.name(), "SelectInterpreter");
+
+    let stream = executor.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 3);
+
+    let logs = query_logs(&ctx);
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].log_type, QueryLogType::QueryStart);
+    assert_eq!(logs[1].log_type, QueryLogType::QueryFinish);
+    assert_eq!(logs[1].query_kind, "Select");
+    assert_eq!(logs[1].query, query);
+    assert_eq!(logs[1].result_rows, 3);
+    assert_eq!(logs[1].exception_code, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interceptor_interpreter_error() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    let plan = PlanParser::create(ctx.clone()).build_from_sql("use xx")?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let error = executor.execute().await.err().unwrap();
+
+    let logs = query_logs(&ctx);
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[1].log_type, QueryLogType::ExceptionWhileProcessing);
+    assert_eq!(logs[1].query_kind, "UseDatabase");
+    assert_eq!(logs[1].exception_code, error.code() as i32);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_exception_before_start() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.attach_query_str("selec 1");
+
+    let error = PlanParser::create(ctx.clone())
+        .build_from_sql("selec 1")
+        .err()
+        .unwrap();
+    InterpreterQueryLog::log_exception_before_start(ctx.clone(), &error);
+
+    let logs = query_logs(&ctx);
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].log_type, QueryLogType::ExceptionBeforeStart);
+    assert_eq!(logs[0].exception_code, error.code() as i32);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_interceptor_interpreter_query_log_table() -> Result<()> {
+    // The logs are only written into the table by the flush of the test.
+    let sessions = SessionManagerBuilder::create()
+        .query_log_table("default.query_log")
+        .query_log_flush_interval_ms(3600 * 1000)
+        .build()?;
+    let session = sessions.create_session("TestSession")?;
+    let ctx = session.create_context().await?;
+
+    execute_sql(
+        &ctx,
+        "create table default.query_log like system.query_log Engine = Fuse",
+    )
+    .await?;
+    execute_sql(&ctx, "select number from numbers_mt(3)").await?;
+    let table = ctx.get_table("default", "query_log")?;
+    let fuse_table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+    assert!(fuse_table.committed_snapshot(&ctx)?.is_none());
+
+    // The logs of both queries are committed at once.
+    InterpreterQueryLog::flush(sessions.clone()).await?;
+    assert!(sessions.get_query_log().take_unflushed().is_empty());
+
+    let table = ctx.get_table("default", "query_log")?;
+    let fuse_table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+    let snapshot = fuse_table.committed_snapshot(&ctx)?.unwrap();
+    assert_eq!(snapshot.prev_snapshot_id, None);
+    assert_eq!(snapshot.summary.row_count, 4);
+
+    let result = execute_sql(
+        &ctx,
+        "select type, query from default.query_log where query_kind = 'Select'",
+    )
+    .await?;
+    let expected = vec![
+        "+-------------+----------------------------------+",
+        "| type        | query                            |",
+        "+-------------+----------------------------------+",
+        "| QueryFinish | select number from numbers_mt(3) |",
+        "| QueryStart  | select number from numbers_mt(3) |",
+        "+-------------+----------------------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    ctx.attach_query_str(sql);
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await
+}
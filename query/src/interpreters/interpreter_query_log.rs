@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::InsertIntoPlan;
+use common_planners::PlanNode;
+
+use crate::datasources::database::system::QueryLogTable;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::QueryLogElement;
+use crate::sessions::QueryLogType;
+use crate::sessions::Session;
+use crate::sessions::SessionManagerRef;
+
+/// Writes the logs of a query into system.query_log, and buffers them for the `query_log_table`
+/// if it is configured, see `InterpreterQueryLog::flush`.
+pub struct InterpreterQueryLog {
+    ctx: DatabendQueryContextRef,
+    query_kind: String,
+}
+
+impl InterpreterQueryLog {
+    pub fn create(ctx: DatabendQueryContextRef, plan: &PlanNode) -> InterpreterQueryLog {
+        InterpreterQueryLog {
+            ctx,
+            query_kind: plan.name().trim_end_matches("Plan").to_string(),
+        }
+    }
+
+    /// Log the query that failed before it is executed, such as a query with a syntax error.
+    pub fn log_exception_before_start(ctx: DatabendQueryContextRef, error: &ErrorCode) {
+        let query_log = InterpreterQueryLog {
+            ctx,
+            query_kind: "".to_string(),
+        };
+
+        let log_type = QueryLogType::ExceptionBeforeStart;
+        query_log.log(log_type, 0, 0, Some(error))
+    }
+
+    pub fn log_start(&self) {
+        self.log(QueryLogType::QueryStart, 0, 0, None)
+    }
+
+    /// Log the end of the query and its profiles.
+    pub fn log_finish(&self, result_rows: u64, result_bytes: u64, error: Option<&ErrorCode>) {
+        let query_id = self.ctx.get_id();
+        let profiles = self.ctx.get_processor_profiles();
+        let query_log = self.ctx.get_sessions_manager().get_query_log();
+        query_log.append_profiles(
+            profiles
+                .iter()
+                .map(|profile| profile.to_element(&query_id, ""))
+                .collect(),
+        );
+
+        let log_type = match error {
+            None => QueryLogType::QueryFinish,
+            Some(_) => QueryLogType::ExceptionWhileProcessing,
+        };
+        self.log(log_type, result_rows, result_bytes, error)
+    }
+
+    fn log(
+        &self,
+        log_type: QueryLogType,
+        result_rows: u64,
+        result_bytes: u64,
+        error: Option<&ErrorCode>,
+    ) {
+        let element = self.create_element(log_type, result_rows, result_bytes, error);
+        let sessions = self.ctx.get_sessions_manager();
+        let query_log = sessions.get_query_log();
+        if !sessions.get_conf().query.query_log_table.is_empty() {
+            query_log.append_unflushed(element.clone());
+        }
+
+        query_log.append_log(element);
+    }
+
+    /// Write the log entries buffered since the last flush into the `query_log_table` as one
+    /// block, so the table gets a single commit for all of them. It is called periodically by
+    /// the `SessionManager`, every `query_log_flush_interval_ms`.
+    pub async fn flush(sessions: SessionManagerRef) -> Result<()> {
+        let logs = sessions.get_query_log().take_unflushed();
+        if logs.is_empty() {
+            return Ok(());
+        }
+
+        let conf = sessions.get_conf().clone();
+        let table = conf.query.query_log_table.clone();
+        let session = Session::try_create(
+            conf,
+            uuid::Uuid::new_v4().to_string(),
+            String::from("QueryLogFlusher"),
+            sessions,
+        )?;
+        let ctx = session.create_context().await?;
+        Self::persist(ctx, &table, logs).await
+    }
+
+    fn create_element(
+        &self,
+        log_type: QueryLogType,
+        result_rows: u64,
+        result_bytes: u64,
+        error: Option<&ErrorCode>,
+    ) -> QueryLogElement {
+        let now = SystemTime::now();
+        let start = self.ctx.get_created_time();
+        let unix_seconds = |time: SystemTime| match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs() as u32,
+            Err(_) => 0,
+        };
+
+        let process_info = self.ctx.get_process_info();
+        let progress = self.ctx.get_total_progress_value();
+        QueryLogElement {
+            log_type,
+            event_time: unix_seconds(now),
+            query_start_time: unix_seconds(start),
+            query_duration_ms: match now.duration_since(start) {
+                Ok(duration) => duration.as_millis() as u64,
+                Err(_) => 0,
+            },
+            query_id: self.ctx.get_id(),
+            query_kind: self.query_kind.clone(),
+            query: self.ctx.get_query_str(),
+            session_id: process_info.id,
+            session_type: process_info.typ,
+            user: process_info.user.unwrap_or_default(),
+            client_address: process_info.client_address.map(|s| s.to_string()),
+            current_database: process_info.database,
+            read_rows: progress.read_rows as u64,
+            read_bytes: progress.read_bytes as u64,
+            result_rows,
+            result_bytes,
+            exception_code: error.map(|e| e.code() as i32).unwrap_or(0),
+            exception: error.map(|e| e.message()).unwrap_or_default(),
+        }
+    }
+
+    async fn persist(
+        ctx: DatabendQueryContextRef,
+        table: &str,
+        logs: Vec<QueryLogElement>,
+    ) -> Result<()> {
+        let (db_name, tbl_name) = table.split_once('.').ok_or_else(|| {
+            ErrorCode::BadOption(format!(
+                "Invalid query_log_table: {}, expect database.table",
+                table
+            ))
+        })?;
+
+        let table = ctx.get_table(db_name, tbl_name)?;
+        let schema = table.raw().schema()?;
+        let log_schema = QueryLogTable::log_schema();
+        let fields = schema.fields().iter().map(|field| field.name());
+        if !fields.eq(log_schema.fields().iter().map(|field| field.name())) {
+            return Err(ErrorCode::BadOption(format!(
+                "The columns of {}.{} mismatch with system.query_log",
+                db_name, tbl_name
+            )));
+        }
+
+        let block = QueryLogTable::logs_to_block(schema.clone(), &logs);
+        let insert_plan = InsertIntoPlan {
+            db_name: db_name.to_string(),
+            tbl_name: tbl_name.to_string(),
+            tbl_id: table.meta_id(),
+            schema,
+            select_plan: None,
+            format: None,
+            copied_files: None,
+            input_stream: Arc::new(Mutex::new(Some(Box::pin(futures::stream::iter(vec![Ok(
+                block,
+            )]))))),
+        };
+        table.raw().append_data(ctx, insert_plan).await
+    }
+}
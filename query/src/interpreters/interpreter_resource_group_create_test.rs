@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::stream::StreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_resource_group_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+
+    let sessions = SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user("root".to_string());
+    let ctx = session.create_context().await?;
+    let query = "create resource group etl with (max_concurrent_queries = 2, users = 'etl')";
+
+    if let PlanNode::CreateResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql(query)?
+    {
+        let executor = CreateResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "CreateResourceGroupInterpreter");
+        let mut stream = executor.execute().await?;
+        while let Some(_block) = stream.next().await {}
+
+        let groups = ctx
+            .get_sessions_manager()
+            .get_resource_groups()
+            .get_groups();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].get_info().name, "etl");
+        assert_eq!(groups[0].get_info().max_concurrent_queries, 2);
+        assert_eq!(groups[0].get_info().users, vec!["etl".to_string()]);
+
+        // Create again.
+        let executor = CreateResourceGroupInterpreter::try_create(ctx.clone(), plan)?;
+        match executor.execute().await {
+            Ok(_) => assert!(false, "Create resource group must fail if it exists"),
+            Err(cause) => assert_eq!(
+                cause.to_string(),
+                "Code: 59, displayText = Resource group etl already exists."
+            ),
+        }
+    } else {
+        assert!(false)
+    }
+
+    let query = "create resource group adhoc with (max_memory = 1)";
+    if let PlanNode::CreateResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql(query)?
+    {
+        let executor = CreateResourceGroupInterpreter::try_create(ctx, plan)?;
+        match executor.execute().await {
+            Ok(_) => assert!(false, "Unknown option must be rejected"),
+            Err(cause) => assert_eq!(
+                cause.to_string(),
+                "Code: 22, displayText = Unknown resource group option: max_memory."
+            ),
+        }
+    } else {
+        assert!(false)
+    }
+
+    // Only the users of the resource_group_admins config may create groups.
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user("etl".to_string());
+    let ctx = session.create_context().await?;
+    let query = "create resource group adhoc with (max_threads = 2)";
+    if let PlanNode::CreateResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql(query)?
+    {
+        let executor = CreateResourceGroupInterpreter::try_create(ctx, plan)?;
+        match executor.execute().await {
+            Ok(_) => assert!(false, "Create resource group must be denied"),
+            Err(cause) => assert_eq!(
+                cause.to_string(),
+                "Code: 62, displayText = User etl is not allowed to create or drop resource groups."
+            ),
+        }
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::DropResourceGroupPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::ResourceGroups;
+
+#[derive(Debug)]
+pub struct DropResourceGroupInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: DropResourceGroupPlan,
+}
+
+impl DropResourceGroupInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: DropResourceGroupPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(DropResourceGroupInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropResourceGroupInterpreter {
+    fn name(&self) -> &str {
+        "DropResourceGroupInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let admins = self.ctx.get_config().query.resource_group_admins;
+        ResourceGroups::check_admin(&admins, self.ctx.get_current_user().as_deref())?;
+
+        let resource_groups = self.ctx.get_sessions_manager().get_resource_groups();
+        resource_groups.drop_group(&self.plan.name, self.plan.if_exists)?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sql::*;
+use crate::tests::SessionManagerBuilder;
+
+#[tokio::test]
+async fn test_drop_resource_group_interpreter() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let resource_groups = sessions.get_resource_groups();
+    resource_groups.create("etl", &Default::default(), false)?;
+
+    // Only the users of the resource_group_admins config may drop groups.
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user("etl".to_string());
+    let ctx = session.create_context().await?;
+    if let PlanNode::DropResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("drop resource group etl")?
+    {
+        let executor = DropResourceGroupInterpreter::try_create(ctx, plan)?;
+        match executor.execute().await {
+            Ok(_) => assert!(false, "Drop resource group must be denied"),
+            Err(cause) => assert_eq!(
+                cause.to_string(),
+                "Code: 62, displayText = User etl is not allowed to create or drop resource groups."
+            ),
+        }
+    } else {
+        assert!(false)
+    }
+    assert_eq!(resource_groups.get_groups().len(), 1);
+
+    let session = sessions.create_session("TestSession")?;
+    session.set_current_user("root".to_string());
+    let ctx = session.create_context().await?;
+
+    if let PlanNode::DropResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("drop resource group etl")?
+    {
+        let executor = DropResourceGroupInterpreter::try_create(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "DropResourceGroupInterpreter");
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec!["++", "++"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        assert!(resource_groups.get_groups().is_empty());
+
+        // Drop again.
+        let executor = DropResourceGroupInterpreter::try_create(ctx.clone(), plan)?;
+        match executor.execute().await {
+            Ok(_) => assert!(false, "Drop resource group must fail if it does not exist"),
+            Err(cause) => assert_eq!(
+                cause.to_string(),
+                "Code: 58, displayText = Unknown resource group etl."
+            ),
+        }
+    } else {
+        assert!(false)
+    }
+
+    if let PlanNode::DropResourceGroup(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("drop resource group if exists etl")?
+    {
+        let executor = DropResourceGroupInterpreter::try_create(ctx, plan)?;
+        executor.execute().await?;
+    } else {
+        assert!(false)
+    }
+
+    Ok(())
+}
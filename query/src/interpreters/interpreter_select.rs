@@ -17,6 +17,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::task::Context;
+use std::time::Duration;
 
 use common_base::tokio::macros::support::Pin;
 use common_base::tokio::macros::support::Poll;
@@ -24,7 +25,9 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_management::NodeInfo;
+use common_planners::PlanNode;
 use common_planners::SelectPlan;
+use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::Stream;
@@ -36,8 +39,12 @@ use crate::interpreters::plan_scheduler::PlanScheduler;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::optimizers::Optimizers;
+use crate::pipelines::processors::Pipeline;
 use crate::pipelines::processors::PipelineBuilder;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::MemoryTracker;
+use crate::sessions::QueryResultCache;
+use crate::sessions::ResultCacheKey;
 
 pub struct SelectInterpreter {
     ctx: DatabendQueryContextRef,
@@ -58,11 +65,34 @@ impl Interpreter for SelectInterpreter {
 
     #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let optimized_plan = Optimizers::create(self.ctx.clone()).optimize(&self.select.input)?;
+
+        let cache_key = self.result_cache_key(&optimized_plan)?;
+        let cache = self.ctx.get_sessions_manager().get_result_cache();
+        if let Some(key) = &cache_key {
+            let ttl = self.ctx.get_settings().get_query_result_cache_ttl()?;
+            if let Some((schema, blocks)) = cache.get(key, Duration::from_secs(ttl)) {
+                return Ok(Box::pin(DataBlockStream::create(schema, None, blocks)));
+            }
+        }
+
         // TODO: maybe panic?
         let mut scheduled = Scheduled::new();
         let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
-        match self.schedule_query(&mut scheduled).await {
-            Ok(stream) => Ok(ScheduledStream::create(scheduled, stream, self.ctx.clone())),
+        match self.schedule_query(&optimized_plan, &mut scheduled).await {
+            Ok(stream) => {
+                let stream = ScheduledStream::create(scheduled, stream, self.ctx.clone());
+                match cache_key {
+                    None => Ok(stream),
+                    Some(key) => Ok(ResultCacheStream::create(
+                        key,
+                        self.schema(),
+                        cache,
+                        self.ctx.get_memory_tracker(),
+                        stream,
+                    )),
+                }
+            }
             Err(error) => {
                 Self::error_handler(scheduled, &self.ctx, timeout).await;
                 Err(error)
@@ -75,19 +105,46 @@ impl Interpreter for SelectInterpreter {
     }
 }
 
-type Scheduled = HashMap<String, Arc<NodeInfo>>;
+pub(crate) type Scheduled = HashMap<String, Arc<NodeInfo>>;
 
 impl SelectInterpreter {
-    async fn schedule_query(&self, scheduled: &mut Scheduled) -> Result<SendableDataBlockStream> {
-        let optimized_plan = Optimizers::create(self.ctx.clone()).optimize(&self.select.input)?;
+    async fn schedule_query(
+        &self,
+        optimized_plan: &PlanNode,
+        scheduled: &mut Scheduled,
+    ) -> Result<SendableDataBlockStream> {
+        let mut in_local_pipeline =
+            Self::schedule_plan(&self.ctx, optimized_plan, scheduled).await?;
+        in_local_pipeline.execute().await
+    }
+
+    /// The key of the query in the result cache, None if the cache is disabled or bypassed.
+    fn result_cache_key(&self, optimized_plan: &PlanNode) -> Result<Option<ResultCacheKey>> {
+        if self.ctx.get_settings().get_enable_query_result_cache()? == 0 {
+            return Ok(None);
+        }
+
+        // The snapshots of the tables don't include the writes staged by the transaction.
+        if self.ctx.get_transaction().is_some() {
+            return Ok(None);
+        }
+
+        ResultCacheKey::try_create(optimized_plan)
+    }
 
-        let scheduler = PlanScheduler::try_create(self.ctx.clone())?;
-        let scheduled_tasks = scheduler.reschedule(&optimized_plan)?;
+    /// Send the remote stages of the optimized plan to the nodes, returns the local pipeline.
+    pub(crate) async fn schedule_plan(
+        ctx: &DatabendQueryContextRef,
+        optimized_plan: &PlanNode,
+        scheduled: &mut Scheduled,
+    ) -> Result<Pipeline> {
+        let scheduler = PlanScheduler::try_create(ctx.clone())?;
+        let scheduled_tasks = scheduler.reschedule(optimized_plan)?;
         let remote_stage_actions = scheduled_tasks.get_tasks()?;
 
-        let config = self.ctx.get_config();
-        let cluster = self.ctx.get_cluster();
-        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let config = ctx.get_config();
+        let cluster = ctx.get_cluster();
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
         for (node, action) in remote_stage_actions {
             let mut flight_client = cluster.create_node_conn(&node.id, &config).await?;
             let executing_action = flight_client.execute_action(action.clone(), timeout);
@@ -96,12 +153,15 @@ impl SelectInterpreter {
             scheduled.insert(node.id.clone(), node.clone());
         }
 
-        let pipeline_builder = PipelineBuilder::create(self.ctx.clone());
-        let mut in_local_pipeline = pipeline_builder.build(&scheduled_tasks.get_local_task())?;
-        in_local_pipeline.execute().await
+        let pipeline_builder = PipelineBuilder::create(ctx.clone());
+        pipeline_builder.build(&scheduled_tasks.get_local_task())
     }
 
-    async fn error_handler(scheduled: Scheduled, context: &DatabendQueryContextRef, timeout: u64) {
+    pub(crate) async fn error_handler(
+        scheduled: Scheduled,
+        context: &DatabendQueryContextRef,
+        timeout: u64,
+    ) {
         let query_id = context.get_id();
         let config = context.get_config();
         let cluster = context.get_cluster();
@@ -188,3 +248,88 @@ impl Stream for ScheduledStream {
         })
     }
 }
+
+/// Collects the result of the query, put into the result cache once the query succeeds.
+/// The collected blocks are charged to the memory tracker of the query until then.
+struct ResultCacheStream {
+    key: ResultCacheKey,
+    schema: DataSchemaRef,
+    cache: Arc<QueryResultCache>,
+    memory_tracker: Arc<MemoryTracker>,
+    // None once the query fails, or the result exceeds the capacity of the cache or the
+    // memory limit of the query.
+    blocks: Option<Vec<DataBlock>>,
+    bytes: usize,
+    inner: SendableDataBlockStream,
+}
+
+impl ResultCacheStream {
+    pub fn create(
+        key: ResultCacheKey,
+        schema: DataSchemaRef,
+        cache: Arc<QueryResultCache>,
+        memory_tracker: Arc<MemoryTracker>,
+        inner: SendableDataBlockStream,
+    ) -> SendableDataBlockStream {
+        Box::pin(ResultCacheStream {
+            key,
+            schema,
+            cache,
+            memory_tracker,
+            blocks: Some(vec![]),
+            bytes: 0,
+            inner,
+        })
+    }
+
+    fn collect(&mut self, block: &Result<DataBlock>) {
+        let block = match (block, &self.blocks) {
+            (Ok(block), Some(_)) => block,
+            _ => return self.release(),
+        };
+
+        let size = block.memory_size();
+        let exceeded = (self.bytes + size) as u64 > self.cache.capacity();
+        // The result is not cached rather than failing the query.
+        if exceeded || self.memory_tracker.alloc(size).is_err() {
+            return self.release();
+        }
+
+        self.bytes += size;
+        if let Some(blocks) = &mut self.blocks {
+            blocks.push(block.clone());
+        }
+    }
+
+    // Stop collecting the result and release the collected blocks.
+    fn release(&mut self) {
+        self.blocks = None;
+        self.memory_tracker.free(self.bytes);
+        self.bytes = 0;
+    }
+}
+
+impl Drop for ResultCacheStream {
+    fn drop(&mut self) {
+        self.memory_tracker.free(self.bytes);
+    }
+}
+
+impl Stream for ResultCacheStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx).map(|x| {
+            match &x {
+                None => {
+                    if let Some(blocks) = self.blocks.take() {
+                        self.cache.put(&self.key, self.schema.clone(), blocks);
+                    }
+                    self.release();
+                }
+                Some(block) => self.collect(block),
+            }
+            x
+        })
+    }
+}
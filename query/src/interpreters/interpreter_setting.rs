@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_datavalues::Tz;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::SettingPlan;
 use common_streams::DataBlockStream;
@@ -24,6 +26,7 @@ use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::sessions::is_mysql_system_variable;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct SettingInterpreter {
@@ -47,12 +50,19 @@ impl Interpreter for SettingInterpreter {
         let plan = self.set.clone();
         for var in plan.vars {
             match var.variable.to_lowercase().as_str() {
-                // To be compatible with some drivers
-                "sql_mode" | "autocommit" => {}
+                // To be compatible with the MySQL drivers, the MySQL system variables are ignored
+                name if is_mysql_system_variable(name) => {}
                 "max_threads" => {
                     let threads: u64 = var.value.parse()?;
                     self.ctx.get_settings().set_max_threads(threads)?;
                 }
+                "timezone" => {
+                    let tz = var.value.trim_matches(|c| c == '\'' || c == '\"');
+                    tz.parse::<Tz>().map_err(|_| {
+                        ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))
+                    })?;
+                    self.ctx.get_settings().set_timezone(tz.to_string())?;
+                }
                 _ => {
                     self.ctx
                         .get_settings()
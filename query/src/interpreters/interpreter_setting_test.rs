@@ -58,3 +58,61 @@ async fn test_setting_interpreter_error() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_timezone_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    if let PlanNode::SetVariable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("set timezone='Asia/Shanghai'")?
+    {
+        let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+        let mut stream = executor.execute().await?;
+        while let Some(_block) = stream.next().await {}
+        assert_eq!(ctx.get_settings().get_timezone()?, "Asia/Shanghai");
+    } else {
+        assert!(false)
+    }
+
+    if let PlanNode::SetVariable(plan) =
+        PlanParser::create(ctx.clone()).build_from_sql("set timezone='Mars/Olympus'")?
+    {
+        let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+        if let Err(e) = executor.execute().await {
+            let expect = "Code: 6, displayText = Unknown timezone: \"Mars/Olympus\".";
+            assert_eq!(expect, format!("{}", e));
+        } else {
+            assert!(false);
+        }
+        assert_eq!(ctx.get_settings().get_timezone()?, "Asia/Shanghai");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_setting_mysql_variables_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    let queries = vec![
+        "SET NAMES utf8mb4",
+        "SET NAMES 'utf8mb4' COLLATE 'utf8mb4_general_ci'",
+        "SET SESSION sql_mode = 'STRICT_TRANS_TABLES'",
+        "SET GLOBAL autocommit = 1",
+        "SET @@session.max_block_size = 100",
+    ];
+    for query in queries {
+        if let PlanNode::SetVariable(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql(query)?
+        {
+            let executor = SettingInterpreter::try_create(ctx.clone(), plan)?;
+            let mut stream = executor.execute().await?;
+            while let Some(_block) = stream.next().await {}
+        } else {
+            assert!(false)
+        }
+    }
+    assert_eq!(ctx.get_settings().get_max_block_size()?, 100);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+#[tokio::test]
+async fn test_alter_table_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // Create table.
+    {
+        if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+            "create table default.a(a bigint, b int, c varchar(255)) Engine = Null",
+        )? {
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute().await?;
+        }
+    }
+
+    // Alter table.
+    let queries = [
+        "alter table a add column d smallint default 1",
+        "alter table a add e Date",
+        "alter table a drop column c",
+        "alter table a rename column a to x",
+        "alter table a modify column b bigint",
+        "rename table a to b",
+    ];
+    for query in queries {
+        if let PlanNode::AlterTable(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+            let executor = AlterTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            assert_eq!(executor.name(), "AlterTableInterpreter");
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec!["++", "++"];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Describe the renamed table.
+    {
+        let plan = PlanParser::create(ctx.clone()).build_from_sql("describe b")?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+-------+--------+------+",
+            "| Field | Type   | Null |",
+            "+-------+--------+------+",
+            "| b     | Int64  | NO   |",
+            "| d     | Int16  | NO   |",
+            "| e     | Date16 | YES  |",
+            "| x     | Int64  | NO   |",
+            "+-------+--------+------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The table is renamed.
+    {
+        let result = PlanParser::create(ctx.clone()).build_from_sql("alter table a drop column b");
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_alter_table_after_concurrent_insert() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(&ctx, "create table default.a(a Int64) Engine = Fuse").await?;
+    execute_sql(&ctx, "insert into default.a values(1)").await?;
+
+    // The plan is built before the insert commits a new snapshot.
+    let plan =
+        PlanParser::create(ctx.clone()).build_from_sql("alter table a add column b Int64")?;
+    execute_sql(&ctx, "insert into default.a values(2)").await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await?;
+
+    let result = execute_sql(&ctx, "select a from default.a").await?;
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await
+}
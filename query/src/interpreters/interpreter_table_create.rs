@@ -14,14 +14,28 @@
 
 use std::sync::Arc;
 
+use common_base::tokio::sync::mpsc::channel;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::CreateTablePlan;
+use common_planners::DropTablePlan;
+use common_planners::InsertIntoPlan;
+use common_planners::PlanNode;
+use common_planners::SelectPlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use futures::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
 
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
 use crate::catalogs::Catalog;
+use crate::catalogs::Database;
+use crate::datasources::table::fuse::FuseTable;
+use crate::interpreters::InsertIntoInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct CreateTableInterpreter {
@@ -47,7 +61,27 @@ impl Interpreter for CreateTableInterpreter {
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let datasource = self.ctx.get_catalog();
         let database = datasource.get_database(self.plan.db.as_str())?;
-        database.create_table(self.plan.clone())?;
+
+        match &self.plan.as_select {
+            None => database.create_table(self.plan.clone())?,
+            Some(select) => {
+                if self.plan.if_not_exists && database.exists_table(self.plan.table.as_str())? {
+                    return Ok(Box::pin(DataBlockStream::create(
+                        self.plan.schema.clone(),
+                        None,
+                        vec![],
+                    )));
+                }
+
+                if self.plan.engine.eq_ignore_ascii_case("FUSE") {
+                    self.create_fuse_table_as_select(database.as_ref(), select)
+                        .await?;
+                } else {
+                    self.create_table_as_select(database.as_ref(), select)
+                        .await?;
+                }
+            }
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema.clone(),
@@ -56,3 +90,106 @@ impl Interpreter for CreateTableInterpreter {
         )))
     }
 }
+
+impl CreateTableInterpreter {
+    /// The rows of the query are written before the table is created, and the table is created
+    /// with their snapshot in its schema meta, so that it appears with all of its rows or not at all.
+    async fn create_fuse_table_as_select(
+        &self,
+        database: &dyn Database,
+        select: &PlanNode,
+    ) -> Result<()> {
+        let table = FuseTable::try_create(
+            self.plan.db.clone(),
+            self.plan.table.clone(),
+            self.plan.schema.clone(),
+            self.plan.options.clone(),
+        )?;
+        let fuse_table = table
+            .as_any()
+            .downcast_ref::<FuseTable>()
+            .ok_or_else(|| ErrorCode::LogicalError("The table should be a Fuse table"))?;
+
+        // The table consumes a Sync stream of blocks, forward the rows through a channel.
+        let select = match select {
+            PlanNode::Select(select) => select.clone(),
+            other => SelectPlan {
+                input: Arc::new(other.clone()),
+            },
+        };
+        let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select)?;
+        let mut input_stream = interpreter.execute().await?;
+        let (sender, receiver) = channel(2);
+        self.ctx.execute_task(async move {
+            while let Some(block) = input_stream.next().await {
+                let failed = block.is_err();
+                if sender.send(block).await.is_err() || failed {
+                    break;
+                }
+            }
+        })?;
+
+        let snapshot_loc = fuse_table
+            .write_first_snapshot(self.ctx.clone(), Box::pin(ReceiverStream::new(receiver)))
+            .await?;
+
+        let mut meta = self.plan.schema.meta().clone();
+        meta.insert(META_SNAPSHOT_LOCATION.to_string(), snapshot_loc);
+        let fields = self.plan.schema.fields().clone();
+        database.create_table(CreateTablePlan {
+            if_not_exists: false,
+            schema: Arc::new(DataSchema::new_from(fields, meta)),
+            as_select: None,
+            ..self.plan.clone()
+        })
+    }
+
+    /// The table is dropped if the rows of the query fail to be inserted, the tables of the
+    /// other engines don't keep their rows in a snapshot to be created with.
+    async fn create_table_as_select(
+        &self,
+        database: &dyn Database,
+        select: &PlanNode,
+    ) -> Result<()> {
+        database.create_table(CreateTablePlan {
+            as_select: None,
+            ..self.plan.clone()
+        })?;
+
+        if let Err(cause) = self.insert_select(database, select).await {
+            let dropped = database.drop_table(DropTablePlan {
+                if_exists: true,
+                db: self.plan.db.clone(),
+                table: self.plan.table.clone(),
+            });
+            if let Err(e) = dropped {
+                log::warn!(
+                    "Cannot drop the table {}.{} of a failed CREATE TABLE ... AS SELECT: {}",
+                    self.plan.db,
+                    self.plan.table,
+                    e
+                );
+            }
+            return Err(cause);
+        }
+        Ok(())
+    }
+
+    async fn insert_select(&self, database: &dyn Database, select: &PlanNode) -> Result<()> {
+        let table = database.get_table(self.plan.table.as_str())?;
+        let insert = InsertIntoPlan {
+            db_name: self.plan.db.clone(),
+            tbl_name: self.plan.table.clone(),
+            tbl_id: table.meta_id(),
+            schema: self.plan.schema.clone(),
+            select_plan: Some(Box::new(select.clone())),
+            format: None,
+            copied_files: None,
+            input_stream: InsertIntoPlan::empty_stream(),
+        };
+
+        let interpreter = InsertIntoInterpreter::try_create(self.ctx.clone(), insert)?;
+        interpreter.execute().await?;
+        Ok(())
+    }
+}
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_datablocks::DataBlock;
 use common_datavalues::DataType;
 use common_exception::Result;
 use common_planners::*;
 use futures::stream::StreamExt;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
 use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
 use crate::sql::*;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -46,3 +50,137 @@ async fn test_create_table_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_create_table_as_select_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // Create table as select.
+    {
+        if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone()).build_from_sql(
+            "create table default.a Engine = Memory as select number as a, number + 1 as b from numbers(3)",
+        )? {
+            assert_eq!(plan.schema().field_with_name("a")?.data_type(), &DataType::UInt64);
+            assert_eq!(plan.schema().field_with_name("b")?.data_type(), &DataType::UInt64);
+
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            assert_eq!(result.len(), 0);
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Select.
+    {
+        if let PlanNode::Select(plan) =
+            PlanParser::create(ctx.clone()).build_from_sql("select * from default.a")?
+        {
+            let executor = SelectInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let stream = executor.execute().await?;
+            let result = stream.try_collect::<Vec<_>>().await?;
+            let expected = vec![
+                "+---+---+",
+                "| a | b |",
+                "+---+---+",
+                "| 0 | 1 |",
+                "| 1 | 2 |",
+                "| 2 | 3 |",
+                "+---+---+",
+            ];
+            common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        } else {
+            assert!(false)
+        }
+    }
+
+    // Create table like.
+    {
+        if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("create table default.b like default.a")?
+        {
+            assert_eq!(plan.engine, "Memory");
+            assert_eq!(
+                plan.schema().field_with_name("a")?.data_type(),
+                &DataType::UInt64
+            );
+            assert_eq!(
+                plan.schema().field_with_name("b")?.data_type(),
+                &DataType::UInt64
+            );
+
+            let executor = CreateTableInterpreter::try_create(ctx.clone(), plan.clone())?;
+            let _ = executor.execute().await?;
+            assert!(ctx.get_table("default", "b").is_ok());
+        } else {
+            assert!(false)
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_create_fuse_table_as_select_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // The table is created with the snapshot of the rows of the query.
+    {
+        execute_sql(
+            &ctx,
+            "create table default.c as select number as a from numbers(3)",
+        )
+        .await?;
+        let table = ctx.get_table("default", "c")?;
+        assert_eq!(table.raw().engine(), "fuse");
+        let schema = table.raw().schema()?;
+        assert!(schema.meta().contains_key(META_SNAPSHOT_LOCATION));
+
+        let result = execute_sql(&ctx, "select * from default.c").await?;
+        let expected = vec![
+            "+---+", "| a |", "+---+", "| 0 |", "| 1 |", "| 2 |", "+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The table created like it has none of its rows.
+    {
+        if let PlanNode::CreateTable(plan) = PlanParser::create(ctx.clone())
+            .build_from_sql("create table default.d like default.c")?
+        {
+            assert!(plan.schema().meta().is_empty());
+        } else {
+            assert!(false)
+        }
+
+        execute_sql(&ctx, "create table default.d like default.c").await?;
+        execute_sql(&ctx, "insert into default.d values(10)").await?;
+        let result = execute_sql(&ctx, "select * from default.d").await?;
+        let expected = vec!["+----+", "| a  |", "+----+", "| 10 |", "+----+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // Nothing is created if the table exists.
+    {
+        let result = execute_sql(&ctx, "create table default.c as select 1 as a").await;
+        assert!(result.is_err());
+        let result = execute_sql(&ctx, "select count() from default.c").await?;
+        let expected = vec![
+            "+---------+",
+            "| count() |",
+            "+---------+",
+            "| 3       |",
+            "+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await
+}
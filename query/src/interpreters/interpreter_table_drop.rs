@@ -16,10 +16,13 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_planners::DropTablePlan;
+use common_planners::MaterializedViewInfo;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
 
+use crate::catalogs::meta_backend::TableChange;
 use crate::catalogs::Catalog;
+use crate::datasources::table::ViewTable;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::DatabendQueryContextRef;
@@ -43,8 +46,39 @@ impl Interpreter for DropTableInterpreter {
 
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let datasource = self.ctx.get_catalog();
-        let database = datasource.get_database(self.plan.db.as_str())?;
-        database.drop_table(self.plan.clone())?;
+        match self.materialized_view()? {
+            None => {
+                let database = datasource.get_database(self.plan.db.as_str())?;
+                database.drop_table(self.plan.clone())?;
+            }
+            Some(info) => {
+                // The view, its inner table and its index in the options of the source table
+                // are dropped at once
+                let mut changes = vec![TableChange::Drop(self.plan.clone())];
+                let inner_table = format!(".inner.{}", self.plan.table);
+                if info.target_db == self.plan.db && info.target_table == inner_table {
+                    changes.push(TableChange::Drop(DropTablePlan {
+                        if_exists: true,
+                        db: self.plan.db.clone(),
+                        table: inner_table,
+                    }));
+                }
+                let (key, _) = ViewTable::source_table_option(&self.plan.db, &self.plan.table);
+                let indexed = datasource
+                    .get_table_options(&info.source_db, &info.source_table)
+                    .map(|options| options.contains_key(&key))
+                    .unwrap_or(false);
+                if indexed {
+                    changes.push(TableChange::SetOption {
+                        db: info.source_db,
+                        table: info.source_table,
+                        key,
+                        value: None,
+                    });
+                }
+                datasource.update_tables(changes)?;
+            }
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -53,3 +87,17 @@ impl Interpreter for DropTableInterpreter {
         )))
     }
 }
+
+impl DropTableInterpreter {
+    fn materialized_view(&self) -> Result<Option<MaterializedViewInfo>> {
+        let table = match self.ctx.get_table(&self.plan.db, &self.plan.table) {
+            Ok(table) => table,
+            // Let the database report the missing table
+            Err(_) => return Ok(None),
+        };
+        match table.raw().as_any().downcast_ref::<ViewTable>() {
+            Some(view) => view.materialized(),
+            None => Ok(None),
+        }
+    }
+}
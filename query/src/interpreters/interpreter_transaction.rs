@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::TransactionKind;
+use common_planners::TransactionPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct TransactionInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: TransactionPlan,
+}
+
+impl TransactionInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: TransactionPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(TransactionInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for TransactionInterpreter {
+    fn name(&self) -> &str {
+        "TransactionInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        match self.plan.kind {
+            TransactionKind::Begin => self.ctx.begin_transaction()?,
+            // COMMIT and ROLLBACK without a transaction in progress do nothing, as MySQL does
+            TransactionKind::Commit => {
+                if let Some(transaction) = self.ctx.take_transaction() {
+                    transaction.commit(&self.ctx).await?;
+                }
+            }
+            TransactionKind::Rollback => {
+                if let Some(transaction) = self.ctx.take_transaction() {
+                    transaction.rollback().await;
+                }
+            }
+        }
+
+        let schema = self.plan.schema();
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use futures::stream::StreamExt;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::catalogs::meta_backend::TableCommit;
+use crate::catalogs::Catalog;
+use crate::datasources::table::fuse::read_segment_async;
+use crate::datasources::table::fuse::FuseTable;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+use crate::tests::SessionManagerBuilder;
+
+async fn execute_transaction(ctx: &DatabendQueryContextRef, query: &str) -> Result<()> {
+    if let PlanNode::Transaction(plan) = PlanParser::create(ctx.clone()).build_from_sql(query)? {
+        let executor = TransactionInterpreter::try_create(ctx.clone(), plan)?;
+        assert_eq!(executor.name(), "TransactionInterpreter");
+
+        let mut stream = executor.execute().await?;
+        while let Some(_block) = stream.next().await {}
+    } else {
+        assert!(false)
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    // COMMIT and ROLLBACK without transaction
+    execute_transaction(&ctx, "COMMIT").await?;
+    execute_transaction(&ctx, "ROLLBACK").await?;
+    assert!(ctx.get_transaction().is_none());
+
+    execute_transaction(&ctx, "BEGIN").await?;
+    assert!(ctx.get_transaction().is_some());
+    execute_transaction(&ctx, "COMMIT").await?;
+    assert!(ctx.get_transaction().is_none());
+
+    execute_transaction(&ctx, "START TRANSACTION").await?;
+    assert!(ctx.get_transaction().is_some());
+    execute_transaction(&ctx, "ROLLBACK").await?;
+    assert!(ctx.get_transaction().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_interpreter_error() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    execute_transaction(&ctx, "BEGIN").await?;
+    match execute_transaction(&ctx, "BEGIN").await {
+        Err(e) => {
+            let expect = "Code: 57, displayText = There is already a transaction in progress.";
+            assert_eq!(expect, format!("{}", e));
+        }
+        Ok(_) => assert!(false),
+    }
+
+    // the transaction in progress is kept
+    assert!(ctx.get_transaction().is_some());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_insert_fuse_table() -> Result<()> {
+    let (ctx, other) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.t(a UInt64) Engine = Fuse").await?;
+    execute_sql(&ctx, "insert into default.t values(1)").await?;
+
+    execute_sql(&ctx, "begin").await?;
+    execute_sql(&ctx, "insert into default.t values(2)").await?;
+    execute_sql(&ctx, "insert into default.t values(3)").await?;
+
+    // The transaction reads its own writes, the other sessions don't see them before COMMIT.
+    let result = execute_sql(&ctx, "select * from default.t").await?;
+    let expected = vec![
+        "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    let result = execute_sql(&other, "select * from default.t").await?;
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    execute_sql(&ctx, "commit").await?;
+    let result = execute_sql(&other, "select * from default.t").await?;
+    let expected = vec![
+        "+---+", "| a |", "+---+", "| 1 |", "| 2 |", "| 3 |", "+---+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_rollback() -> Result<()> {
+    let (ctx, _) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.t(a UInt64) Engine = Fuse").await?;
+
+    execute_sql(&ctx, "begin").await?;
+    execute_sql(&ctx, "insert into default.t values(1)").await?;
+
+    let table = ctx.get_table("default", "t")?;
+    let fuse_table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+    let data_accessor = fuse_table.data_accessor(&ctx)?;
+    let transaction = ctx.get_transaction().unwrap();
+    let mut files = transaction.staged_segments("default", "t");
+    assert_eq!(files.len(), 1);
+    let segment = read_segment_async(data_accessor.clone(), &files[0]).await?;
+    files.extend(segment.blocks.iter().map(|b| b.location.location.clone()));
+
+    // The staged segment and blocks are removed.
+    execute_sql(&ctx, "rollback").await?;
+    for file in &files {
+        assert!(data_accessor.get(file).await.is_err());
+    }
+
+    let result = execute_sql(&ctx, "select * from default.t").await?;
+    let expected = vec!["++", "++"];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_commit_tables() -> Result<()> {
+    let (ctx, other) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.t1(a UInt64) Engine = Fuse").await?;
+    execute_sql(&ctx, "create table default.t2(a UInt64) Engine = Fuse").await?;
+
+    // Both tables are committed.
+    {
+        execute_sql(&ctx, "begin").await?;
+        execute_sql(&ctx, "insert into default.t1 values(1)").await?;
+        execute_sql(&ctx, "insert into default.t2 values(2)").await?;
+        execute_sql(&ctx, "commit").await?;
+
+        let result = execute_sql(&other, "select * from default.t1").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+        let result = execute_sql(&other, "select * from default.t2").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 2 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // None of the tables is committed if one of them fails.
+    {
+        execute_sql(&ctx, "begin").await?;
+        execute_sql(&ctx, "insert into default.t1 values(3)").await?;
+        execute_sql(&ctx, "insert into default.t2 values(4)").await?;
+        execute_sql(&other, "drop table default.t2").await?;
+
+        let result = execute_sql(&ctx, "commit").await;
+        assert_eq!(
+            ErrorCode::UnknownTable("").code(),
+            result.unwrap_err().code()
+        );
+        assert!(ctx.get_transaction().is_none());
+
+        let result = execute_sql(&other, "select * from default.t1").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_concurrent_commit() -> Result<()> {
+    let (ctx, other) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.t1(a UInt64) Engine = Fuse").await?;
+    execute_sql(&ctx, "create table default.t2(a UInt64) Engine = Fuse").await?;
+
+    // The staged rows are appended to the snapshot committed by the other session.
+    {
+        execute_sql(&ctx, "begin").await?;
+        execute_sql(&ctx, "insert into default.t1 values(1)").await?;
+        execute_sql(&other, "insert into default.t1 values(2)").await?;
+        execute_sql(&ctx, "commit").await?;
+
+        let result = execute_sql(&other, "select * from default.t1").await?;
+        let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The commit based on a snapshot replaced in the meantime fails, for all the tables.
+    {
+        let snapshot_of = |table: &str| -> Result<(u64, Option<String>)> {
+            let table = ctx.get_table("default", table)?;
+            let fuse_table = table.raw().as_any().downcast_ref::<FuseTable>().unwrap();
+            Ok((table.meta_id(), fuse_table.snapshot_location().cloned()))
+        };
+        let (t1_id, t1_snapshot) = snapshot_of("t1")?;
+        let (t2_id, t2_snapshot) = snapshot_of("t2")?;
+        execute_sql(&other, "insert into default.t2 values(3)").await?;
+
+        let result = ctx.get_catalog().commit_tables(vec![
+            TableCommit {
+                db: "default".to_string(),
+                table_id: t1_id,
+                prev_snapshot: t1_snapshot.clone(),
+                new_snapshot: "_ss/t1".to_string(),
+            },
+            TableCommit {
+                db: "default".to_string(),
+                table_id: t2_id,
+                prev_snapshot: t2_snapshot,
+                new_snapshot: "_ss/t2".to_string(),
+            },
+        ]);
+        assert_eq!(
+            ErrorCode::CommitTableConflict("").code(),
+            result.unwrap_err().code()
+        );
+        assert_eq!(snapshot_of("t1")?.1, t1_snapshot);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_insert_memory_table() -> Result<()> {
+    let (ctx, _) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.m(a UInt64) Engine = Memory").await?;
+
+    execute_sql(&ctx, "begin").await?;
+    match execute_sql(&ctx, "insert into default.m values(1)").await {
+        Err(e) => {
+            let expect = "Code: 57, displayText = INSERT into table m of engine Memory is not supported in a transaction.";
+            assert_eq!(expect, format!("{}", e));
+        }
+        Ok(_) => assert!(false),
+    }
+    execute_sql(&ctx, "rollback").await?;
+
+    let result = execute_sql(&ctx, "select * from default.m").await?;
+    let expected = vec!["++", "++"];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_transaction_copy_into() -> Result<()> {
+    let (ctx, _) = create_session_contexts().await?;
+    execute_sql(&ctx, "create table default.t(a UInt64) Engine = Fuse").await?;
+
+    let table = ctx.get_table("default", "t")?;
+    let schema = table.raw().schema()?;
+    let plan = InsertIntoPlan {
+        db_name: "default".to_string(),
+        tbl_name: "t".to_string(),
+        tbl_id: table.meta_id(),
+        schema: schema.clone(),
+        select_plan: None,
+        format: None,
+        copied_files: Some(CopiedFiles::default()),
+        input_stream: InsertIntoPlan::empty_stream(),
+    };
+    let block = DataBlock::create_by_array(schema, vec![Series::new(vec![1u64])]);
+    plan.set_input_stream(Box::pin(futures::stream::iter(vec![Ok(block)])));
+
+    execute_sql(&ctx, "begin").await?;
+    match table.raw().append_data(ctx.clone(), plan.clone()).await {
+        Err(e) => {
+            let expect = "Code: 57, displayText = COPY INTO is not supported inside a transaction.";
+            assert_eq!(expect, format!("{}", e));
+        }
+        Ok(_) => assert!(false),
+    }
+
+    // Rejected before any block is written.
+    assert!(plan.input_stream.lock().is_some());
+    assert!(ctx
+        .get_transaction()
+        .unwrap()
+        .staged_segments("default", "t")
+        .is_empty());
+    execute_sql(&ctx, "rollback").await?;
+
+    Ok(())
+}
+
+/// The contexts of two sessions of the same server.
+async fn create_session_contexts() -> Result<(DatabendQueryContextRef, DatabendQueryContextRef)> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let ctx = sessions
+        .create_session("TestSession")?
+        .create_context()
+        .await?;
+    let other = sessions
+        .create_session("TestSession")?
+        .create_context()
+        .await?;
+    Ok((ctx, other))
+}
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    executor.execute().await?.try_collect::<Vec<_>>().await
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::CreateTablePlan;
+use common_planners::CreateViewPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::meta_backend::TableChange;
+use crate::catalogs::Catalog;
+use crate::datasources::table::ViewTable;
+use crate::datasources::table::MATERIALIZED_VIEW_ENGINE;
+use crate::datasources::table::VIEW_ENGINE;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::DatabendQueryContextRef;
+
+pub struct CreateViewInterpreter {
+    ctx: DatabendQueryContextRef,
+    plan: CreateViewPlan,
+}
+
+impl CreateViewInterpreter {
+    pub fn try_create(
+        ctx: DatabendQueryContextRef,
+        plan: CreateViewPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CreateViewInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateViewInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        let database = self.ctx.get_catalog().get_database(plan.db.as_str())?;
+        if plan.if_not_exists && database.exists_table(plan.view.as_str())? {
+            return Ok(Box::pin(DataBlockStream::create(
+                plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
+        let view = |engine: &str, if_not_exists: bool| CreateTablePlan {
+            if_not_exists,
+            db: plan.db.clone(),
+            table: plan.view.clone(),
+            schema: plan.view_schema.clone(),
+            engine: engine.to_string(),
+            options: ViewTable::view_options(&plan.query, plan.materialized.as_ref()),
+            as_select: None,
+        };
+
+        match &plan.materialized {
+            None => database.create_table(view(VIEW_ENGINE, plan.if_not_exists))?,
+            Some(mv) => {
+                // The inner table, the view and its index in the options of the source table
+                // are created at once
+                let mut changes = vec![];
+                if let Some(target_engine) = &mv.target_engine {
+                    changes.push(TableChange::Create(CreateTablePlan {
+                        if_not_exists: false,
+                        db: mv.target_db.clone(),
+                        table: mv.target_table.clone(),
+                        schema: plan.view_schema.clone(),
+                        engine: target_engine.clone(),
+                        options: Default::default(),
+                        as_select: None,
+                    }));
+                }
+                let (key, value) = ViewTable::source_table_option(&plan.db, &plan.view);
+                changes.push(TableChange::SetOption {
+                    db: mv.source_db.clone(),
+                    table: mv.source_table.clone(),
+                    key,
+                    value: Some(value),
+                });
+                changes.push(TableChange::Create(view(MATERIALIZED_VIEW_ENGINE, false)));
+                self.ctx.get_catalog().update_tables(changes)?;
+            }
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::catalogs::Catalog;
+use crate::datasources::table::ViewTable;
+use crate::interpreters::*;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+async fn execute(ctx: &DatabendQueryContextRef, query: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(query)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+#[tokio::test]
+async fn test_create_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    execute(
+        &ctx,
+        "create table default.t(a bigint, b int) Engine = Memory",
+    )
+    .await?;
+    execute(&ctx, "insert into t values(1, 10), (2, 20), (3, 30)").await?;
+
+    if let PlanNode::CreateView(plan) = PlanParser::create(ctx.clone())
+        .build_from_sql("create view v as select a, b * 2 as c from t where a > 1")?
+    {
+        let executor = CreateViewInterpreter::try_create(ctx.clone(), plan.clone())?;
+        assert_eq!(executor.name(), "CreateViewInterpreter");
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        common_datablocks::assert_blocks_sorted_eq(vec!["++", "++"], result.as_slice());
+    } else {
+        assert!(false)
+    }
+
+    // The view is expanded where it is referenced.
+    {
+        let result = execute(&ctx, "select * from v").await?;
+        let expected = vec![
+            "+---+----+",
+            "| a | c  |",
+            "+---+----+",
+            "| 2 | 40 |",
+            "| 3 | 60 |",
+            "+---+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The view exists.
+    {
+        execute(&ctx, "create view if not exists v as select a from t").await?;
+        let result = execute(&ctx, "create view v as select a from t").await;
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_materialized_view_interpreter() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+
+    execute(
+        &ctx,
+        "create table default.src(k bigint, v bigint) Engine = Memory",
+    )
+    .await?;
+    execute(
+        &ctx,
+        "create materialized view mv Engine = Memory as select k, sum(v) as s from src group by k",
+    )
+    .await?;
+
+    // The query of the view is applied to the inserted blocks.
+    execute(&ctx, "insert into src values(1, 10), (2, 20), (1, 30)").await?;
+    {
+        let result = execute(&ctx, "select * from mv").await?;
+        let expected = vec![
+            "+---+----+",
+            "| k | s  |",
+            "+---+----+",
+            "| 1 | 40 |",
+            "| 2 | 20 |",
+            "+---+----+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The view reads from its target table.
+    {
+        execute(
+            &ctx,
+            "create table default.dst(k bigint, s bigint) Engine = Memory",
+        )
+        .await?;
+        execute(
+            &ctx,
+            "create materialized view mv2 to dst as select k, v * 2 from src",
+        )
+        .await?;
+        execute(&ctx, "insert into src values(3, 1)").await?;
+        let result = execute(&ctx, "select * from dst").await?;
+        let expected = vec![
+            "+---+---+",
+            "| k | s |",
+            "+---+---+",
+            "| 3 | 2 |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The views are indexed by the options of the source table.
+    {
+        let options = ctx.get_catalog().get_table_options("default", "src")?;
+        let mut views = ViewTable::materialized_views_of(&options);
+        views.sort();
+        let expected = vec![
+            ("default".to_string(), "mv".to_string()),
+            ("default".to_string(), "mv2".to_string()),
+        ];
+        assert_eq!(expected, views);
+    }
+
+    // The blocks of an insert are streamed to the views.
+    {
+        execute(&ctx, "set max_block_size = 1").await?;
+        execute(&ctx, "insert into src select number + 4, 1 from numbers(3)").await?;
+        execute(&ctx, "set max_block_size = 10000").await?;
+        let result = execute(&ctx, "select * from dst where k > 3").await?;
+        let expected = vec![
+            "+---+---+",
+            "| k | s |",
+            "+---+---+",
+            "| 4 | 2 |",
+            "| 5 | 2 |",
+            "| 6 | 2 |",
+            "+---+---+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // The inner table and the index are dropped with the view.
+    {
+        execute(&ctx, "drop view mv").await?;
+        assert!(ctx.get_table("default", ".inner.mv").is_err());
+        let options = ctx.get_catalog().get_table_options("default", "src")?;
+        let views = ViewTable::materialized_views_of(&options);
+        assert_eq!(vec![("default".to_string(), "mv2".to_string())], views);
+
+        execute(
+            &ctx,
+            "create materialized view mv Engine = Memory as select k from src",
+        )
+        .await?;
+    }
+
+    // The inner table isn't created if the view can't be created.
+    {
+        let result = execute(
+            &ctx,
+            "create materialized view mv2 Engine = Memory as select k from src",
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(ctx.get_table("default", ".inner.mv2").is_err());
+        let options = ctx.get_catalog().get_table_options("default", "src")?;
+        assert_eq!(2, ViewTable::materialized_views_of(&options).len());
+    }
+
+    Ok(())
+}
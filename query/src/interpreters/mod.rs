@@ -12,61 +12,101 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(test)]
+mod interpreter_copy_into_test;
 #[cfg(test)]
 mod interpreter_database_create_test;
 #[cfg(test)]
 mod interpreter_database_drop_test;
 #[cfg(test)]
+mod interpreter_delete_test;
+#[cfg(test)]
 mod interpreter_describe_table_test;
 #[cfg(test)]
 mod interpreter_explain_test;
 #[cfg(test)]
+mod interpreter_insert_into_test;
+#[cfg(test)]
+mod interpreter_interceptor_test;
+#[cfg(test)]
+mod interpreter_resource_group_create_test;
+#[cfg(test)]
+mod interpreter_resource_group_drop_test;
+#[cfg(test)]
 mod interpreter_select_test;
 #[cfg(test)]
 mod interpreter_setting_test;
 #[cfg(test)]
 mod interpreter_show_create_table_test;
 #[cfg(test)]
+mod interpreter_table_alter_test;
+#[cfg(test)]
 mod interpreter_table_create_test;
 #[cfg(test)]
 mod interpreter_table_drop_test;
 #[cfg(test)]
+mod interpreter_transaction_test;
+#[cfg(test)]
 mod interpreter_truncate_table_test;
 #[cfg(test)]
+mod interpreter_update_test;
+#[cfg(test)]
 mod interpreter_use_database_test;
 #[cfg(test)]
+mod interpreter_view_create_test;
+#[cfg(test)]
 mod plan_scheduler_test;
 
 mod interpreter;
+mod interpreter_copy_into;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_delete;
 mod interpreter_describe_table;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_insert_into;
+mod interpreter_interceptor;
 mod interpreter_kill;
+mod interpreter_query_log;
+mod interpreter_resource_group_create;
+mod interpreter_resource_group_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_show_create_table;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_drop;
+mod interpreter_transaction;
 mod interpreter_truncate_table;
+mod interpreter_update;
 mod interpreter_use_database;
+mod interpreter_view_create;
 #[allow(clippy::needless_range_loop)]
 mod plan_scheduler;
 
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_copy_into::CopyIntoInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert_into::InsertIntoInterpreter;
+pub use interpreter_interceptor::InterceptorInterpreter;
+pub use interpreter_query_log::InterpreterQueryLog;
+pub use interpreter_resource_group_create::CreateResourceGroupInterpreter;
+pub use interpreter_resource_group_drop::DropResourceGroupInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_show_create_table::ShowCreateTableInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_transaction::TransactionInterpreter;
 pub use interpreter_truncate_table::TruncateTableInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
+pub use interpreter_view_create::CreateViewInterpreter;
@@ -22,7 +22,9 @@ use common_exception::Result;
 use common_management::NodeInfo;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
+use common_planners::ArrayJoinPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::EmptyPlan;
 use common_planners::Expression;
 use common_planners::ExpressionPlan;
@@ -35,13 +37,16 @@ use common_planners::Partitions;
 use common_planners::PlanNode;
 use common_planners::ProjectionPlan;
 use common_planners::ReadDataSourcePlan;
+use common_planners::ReadFilesPlan;
 use common_planners::RemotePlan;
 use common_planners::ScanPlan;
 use common_planners::SelectPlan;
+use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionAllPlan;
 use common_tracing::tracing;
 
 use crate::api::BroadcastAction;
@@ -71,6 +76,8 @@ pub struct PlanScheduler {
     running_mode: RunningMode,
     query_context: DatabendQueryContextRef,
     subqueries_expressions: Vec<Expressions>,
+    // Profile the processors of the remote stages, for EXPLAIN ANALYZE.
+    profile: bool,
 }
 
 impl PlanScheduler {
@@ -90,9 +97,11 @@ impl PlanScheduler {
             cluster_nodes_name.push(cluster_nodes[index].id.clone());
         }
 
+        let profile = context.is_query_profile_enabled()?;
         Ok(PlanScheduler {
             local_pos,
             nodes_plan,
+            profile,
             stage_id: uuid::Uuid::new_v4().to_string(),
             query_context: context,
             subqueries_expressions: vec![],
@@ -172,6 +181,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.profile,
         }
     }
 
@@ -212,6 +222,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.profile,
         }
     }
 
@@ -255,6 +266,7 @@ impl PlanScheduler {
             plan: input.clone(),
             sinks: vec![self.cluster_nodes[self.local_pos].clone()],
             scatters_expression: stage.scatters_expr.clone(),
+            profile: self.profile,
         }
     }
 
@@ -303,7 +315,12 @@ impl PlanScheduler {
             PlanNode::Sort(plan) => self.visit_sort(plan, tasks),
             PlanNode::Limit(plan) => self.visit_limit(plan, tasks),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan, tasks),
+            PlanNode::ArrayJoin(plan) => self.visit_array_join(plan, tasks),
+            PlanNode::Sink(plan) => self.visit_sink(plan, tasks),
+            PlanNode::CteScan(plan) => self.visit_cte_scan(plan, tasks),
+            PlanNode::UnionAll(plan) => self.visit_union_all(plan, tasks),
             PlanNode::ReadSource(plan) => self.visit_data_source(plan, tasks),
+            PlanNode::ReadFiles(plan) => self.visit_read_files(plan, tasks),
             PlanNode::Select(plan) => self.visit_select(plan, tasks),
             PlanNode::Stage(plan) => self.visit_stage(plan, tasks),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan, tasks),
@@ -328,6 +345,7 @@ impl PlanScheduler {
             schema: plan.schema(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
         });
     }
@@ -338,6 +356,7 @@ impl PlanScheduler {
                 schema: plan.schema(),
                 aggr_expr: plan.aggr_expr.clone(),
                 group_expr: plan.group_expr.clone(),
+                grouping_sets: plan.grouping_sets.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
             });
         }
@@ -358,6 +377,7 @@ impl PlanScheduler {
             schema: plan.schema.clone(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             schema_before_group_by: plan.schema_before_group_by.clone(),
             input: Arc::new(self.nodes_plan[self.local_pos].clone()),
         })
@@ -369,6 +389,7 @@ impl PlanScheduler {
                 schema: plan.schema.clone(),
                 aggr_expr: plan.aggr_expr.clone(),
                 group_expr: plan.group_expr.clone(),
+                grouping_sets: plan.grouping_sets.clone(),
                 schema_before_group_by: plan.schema_before_group_by.clone(),
                 input: Arc::new(self.nodes_plan[index].clone()),
             })
@@ -435,6 +456,7 @@ impl PlanScheduler {
             query_id: self.query_context.get_id(),
             plan: input.clone(),
             sinks: self.cluster_nodes.clone(),
+            profile: self.profile,
         }
     }
 
@@ -780,6 +802,124 @@ impl PlanScheduler {
         }
     }
 
+    fn visit_array_join(&mut self, plan: &ArrayJoinPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_array_join(plan),
+            RunningMode::Standalone => self.visit_local_array_join(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_array_join(&mut self, plan: &ArrayJoinPlan) {
+        self.nodes_plan[self.local_pos] = PlanNode::ArrayJoin(ArrayJoinPlan {
+            exprs: plan.exprs.clone(),
+            schema: plan.schema.clone(),
+            input: Arc::new(self.nodes_plan[self.local_pos].clone()),
+        });
+    }
+
+    fn visit_cluster_array_join(&mut self, plan: &ArrayJoinPlan) {
+        for index in 0..self.nodes_plan.len() {
+            self.nodes_plan[index] = PlanNode::ArrayJoin(ArrayJoinPlan {
+                exprs: plan.exprs.clone(),
+                schema: plan.schema.clone(),
+                input: Arc::new(self.nodes_plan[index].clone()),
+            });
+        }
+    }
+
+    fn visit_sink(&mut self, plan: &SinkPlan, tasks: &mut Tasks) -> Result<()> {
+        self.visit_plan_node(plan.input.as_ref(), tasks)?;
+        match self.running_mode {
+            RunningMode::Cluster => self.visit_cluster_sink(plan),
+            RunningMode::Standalone => self.visit_local_sink(plan),
+        };
+        Ok(())
+    }
+
+    fn visit_local_sink(&mut self, plan: &SinkPlan) {
+        let mut sink = plan.clone();
+        sink.set_input(&self.nodes_plan[self.local_pos]);
+        self.nodes_plan[self.local_pos] = PlanNode::Sink(sink);
+    }
+
+    fn visit_cluster_sink(&mut self, plan: &SinkPlan) {
+        // Every node writes its own partial input, and records the files it reads
+        for index in 0..self.nodes_plan.len() {
+            let mut sink = plan.clone();
+            if let (Some(copied), PlanNode::ReadFiles(read)) =
+                (&mut sink.copied_files, &self.nodes_plan[index])
+            {
+                copied.files = read
+                    .parts
+                    .iter()
+                    .map(|part| read.file_location(&part.name))
+                    .collect();
+            }
+            sink.set_input(&self.nodes_plan[index]);
+            self.nodes_plan[index] = PlanNode::Sink(sink);
+        }
+    }
+
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan, _: &mut Tasks) -> Result<()> {
+        // The CTE queries are always executed on the local node
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::CteScan(plan.clone());
+        Ok(())
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan, tasks: &mut Tasks) -> Result<()> {
+        // Every input is scheduled on its own, so the inputs run in parallel in the cluster
+        let mut inputs_scheduler = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let input_context = DatabendQueryContext::new(self.query_context.clone());
+            let mut input_scheduler = PlanScheduler::try_create(input_context)?;
+            input_scheduler.visit_plan_node(input.as_ref(), tasks)?;
+            inputs_scheduler.push(input_scheduler);
+        }
+
+        let in_cluster = inputs_scheduler
+            .iter()
+            .any(|scheduler| matches!(scheduler.running_mode, RunningMode::Cluster));
+
+        match in_cluster {
+            true => self.visit_cluster_union_all(plan, &inputs_scheduler),
+            false => self.visit_local_union_all(plan, &inputs_scheduler),
+        };
+        Ok(())
+    }
+
+    fn visit_local_union_all(&mut self, plan: &UnionAllPlan, inputs: &[PlanScheduler]) {
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::UnionAll(UnionAllPlan {
+            schema: plan.schema.clone(),
+            inputs: inputs
+                .iter()
+                .map(|input| Arc::new(input.nodes_plan[input.local_pos].clone()))
+                .collect(),
+        });
+    }
+
+    fn visit_cluster_union_all(&mut self, plan: &UnionAllPlan, inputs: &[PlanScheduler]) {
+        self.running_mode = RunningMode::Cluster;
+        for index in 0..self.nodes_plan.len() {
+            // The standalone inputs only run on the local node
+            let node_inputs = inputs
+                .iter()
+                .filter(|input| {
+                    matches!(input.running_mode, RunningMode::Cluster) || index == self.local_pos
+                })
+                .map(|input| Arc::new(input.nodes_plan[index].clone()))
+                .collect();
+
+            self.nodes_plan[index] = PlanNode::UnionAll(UnionAllPlan {
+                schema: plan.schema.clone(),
+                inputs: node_inputs,
+            });
+        }
+    }
+
     fn visit_data_source(&mut self, plan: &ReadDataSourcePlan, _: &mut Tasks) -> Result<()> {
         let table_meta = self.query_context.get_table(&plan.db, &plan.table)?;
         let table = table_meta.raw();
@@ -801,7 +941,7 @@ impl PlanScheduler {
 
     fn visit_cluster_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
         self.running_mode = RunningMode::Cluster;
-        let nodes_parts = self.repartition(plan);
+        let nodes_parts = self.repartition(&plan.parts);
 
         for index in 0..self.nodes_plan.len() {
             let mut read_plan = plan.clone();
@@ -812,6 +952,32 @@ impl PlanScheduler {
         Ok(())
     }
 
+    fn visit_read_files(&mut self, plan: &ReadFilesPlan, _: &mut Tasks) -> Result<()> {
+        match plan.is_local() {
+            true => self.visit_local_read_files(plan),
+            false => self.visit_cluster_read_files(plan),
+        }
+    }
+
+    fn visit_local_read_files(&mut self, plan: &ReadFilesPlan) -> Result<()> {
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::ReadFiles(plan.clone());
+        Ok(())
+    }
+
+    fn visit_cluster_read_files(&mut self, plan: &ReadFilesPlan) -> Result<()> {
+        self.running_mode = RunningMode::Cluster;
+        let nodes_parts = self.repartition(&plan.parts);
+
+        for index in 0..self.nodes_plan.len() {
+            let mut read_plan = plan.clone();
+            read_plan.parts = nodes_parts[index].clone();
+            self.nodes_plan[index] = PlanNode::ReadFiles(read_plan);
+        }
+
+        Ok(())
+    }
+
     fn visit_select(&mut self, plan: &SelectPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
@@ -845,10 +1011,9 @@ impl PlanScheduler {
         table.read_plan(context, node, max_threads * nodes.len())
     }
 
-    fn repartition(&mut self, cluster_source: &ReadDataSourcePlan) -> Vec<Partitions> {
+    fn repartition(&mut self, cluster_parts: &Partitions) -> Vec<Partitions> {
         // We always put adjacent partitions in the same node
         let nodes = self.cluster_nodes.clone();
-        let cluster_parts = &cluster_source.parts;
         let parts_pre_node = cluster_parts.len() / nodes.len();
 
         let mut nodes_parts = Vec::with_capacity(nodes.len());
@@ -19,6 +19,7 @@ use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
+use common_planners::is_higher_order_function;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::Expression;
@@ -50,6 +51,11 @@ impl ConstantFoldingImpl {
 
     fn rewrite_function<F>(op: &str, args: Expressions, name: String, f: F) -> Result<Expression>
     where F: Fn(&str, Expressions) -> Expression {
+        // Higher-order functions are evaluated with their lambda, never folded.
+        if is_higher_order_function(op) {
+            return Ok(f(op, args));
+        }
+
         let function = FunctionFactory::get(op)?;
 
         if function.is_deterministic() && ConstantFoldingImpl::constants_arguments(&args) {
@@ -218,7 +224,11 @@ impl PlanRewriter for ConstantFoldingImpl {
                 let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
                 let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
                 PlanBuilder::from(&new_input)
-                    .aggregate_partial(&new_aggr_expr, &new_group_expr)?
+                    .aggregate_partial_with_grouping_sets(
+                        &new_aggr_expr,
+                        &new_group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
@@ -235,7 +245,12 @@ impl PlanRewriter for ConstantFoldingImpl {
                 let new_aggr_expr = self.rewrite_exprs(&new_input.schema(), &plan.aggr_expr)?;
                 let new_group_expr = self.rewrite_exprs(&new_input.schema(), &plan.group_expr)?;
                 PlanBuilder::from(&new_input)
-                    .aggregate_final(schema_before_group_by, &new_aggr_expr, &new_group_expr)?
+                    .aggregate_final_with_grouping_sets(
+                        schema_before_group_by,
+                        &new_aggr_expr,
+                        &new_group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
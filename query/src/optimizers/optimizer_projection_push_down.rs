@@ -59,7 +59,11 @@ impl PlanRewriter for ProjectionPushDownImpl {
             None => {
                 self.before_group_by_schema = Some(new_input.schema());
                 PlanBuilder::from(&new_input)
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_partial_with_grouping_sets(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()
             }
         }
@@ -75,7 +79,12 @@ impl PlanRewriter for ProjectionPushDownImpl {
                 "Logical error: before group by schema must be Some",
             )),
             Some(schema_before_group_by) => PlanBuilder::from(&new_input)
-                .aggregate_final(schema_before_group_by, &plan.aggr_expr, &plan.group_expr)?
+                .aggregate_final_with_grouping_sets(
+                    schema_before_group_by,
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
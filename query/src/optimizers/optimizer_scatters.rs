@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::Expression;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
@@ -28,9 +29,11 @@ use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::PlanRewriter;
 use common_planners::ReadDataSourcePlan;
+use common_planners::ReadFilesPlan;
 use common_planners::SortPlan;
 use common_planners::StageKind;
 use common_planners::StagePlan;
+use common_planners::UnionAllPlan;
 
 use crate::optimizers::Optimizer;
 use crate::sessions::DatabendQueryContext;
@@ -73,7 +76,11 @@ impl ScattersOptimizerImpl {
             None => Err(ErrorCode::LogicalError("Cluster aggr input is None")),
             Some(input) => Self::convergent_shuffle_stage(
                 PlanBuilder::from(input.as_ref())
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_partial_with_grouping_sets(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()?,
             ),
         }
@@ -88,7 +95,11 @@ impl ScattersOptimizerImpl {
             Some(input) => Self::normal_shuffle_stage(
                 "_group_by_key",
                 PlanBuilder::from(input.as_ref())
-                    .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                    .aggregate_partial_with_grouping_sets(
+                        &plan.aggr_expr,
+                        &plan.group_expr,
+                        &plan.grouping_sets,
+                    )?
                     .build()?,
             ),
         }
@@ -105,7 +116,11 @@ impl ScattersOptimizerImpl {
         match self.input.take() {
             None => Err(ErrorCode::LogicalError("Standalone aggr input is None")),
             Some(input) => PlanBuilder::from(input.as_ref())
-                .aggregate_partial(&plan.aggr_expr, &plan.group_expr)?
+                .aggregate_partial_with_grouping_sets(
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
@@ -247,7 +262,12 @@ impl PlanRewriter for ScattersOptimizerImpl {
         match self.before_group_by_schema.take() {
             None => Ok(PlanNode::AggregatorFinal(plan.clone())),
             Some(schema_before_group_by) => PlanBuilder::from(&new_input)
-                .aggregate_final(schema_before_group_by, &plan.aggr_expr, &plan.group_expr)?
+                .aggregate_final_with_grouping_sets(
+                    schema_before_group_by,
+                    &plan.aggr_expr,
+                    &plan.group_expr,
+                    &plan.grouping_sets,
+                )?
                 .build(),
         }
     }
@@ -291,6 +311,41 @@ impl PlanRewriter for ScattersOptimizerImpl {
 
         Ok(PlanNode::ReadSource(plan.clone()))
     }
+
+    fn rewrite_read_files(&mut self, plan: &ReadFilesPlan) -> Result<PlanNode> {
+        match plan.is_local() {
+            false => self.running_mode = RunningMode::Cluster,
+            true => self.running_mode = RunningMode::Standalone,
+        }
+
+        Ok(PlanNode::ReadFiles(plan.clone()))
+    }
+
+    fn rewrite_union_all(&mut self, plan: &UnionAllPlan) -> Result<PlanNode> {
+        // The union runs in the cluster if any of its inputs runs in the cluster
+        let mut running_mode = RunningMode::Standalone;
+        let mut inputs = Vec::with_capacity(plan.inputs.len());
+        for input in &plan.inputs {
+            let mut input_optimizer = ScattersOptimizerImpl::create(self.ctx.clone());
+            inputs.push(Arc::new(input_optimizer.rewrite_plan_node(input.as_ref())?));
+
+            if let RunningMode::Cluster = input_optimizer.running_mode {
+                running_mode = RunningMode::Cluster;
+            }
+        }
+
+        self.running_mode = running_mode;
+        Ok(PlanNode::UnionAll(UnionAllPlan {
+            schema: plan.schema.clone(),
+            inputs,
+        }))
+    }
+
+    fn rewrite_cte_scan(&mut self, plan: &CteScanPlan) -> Result<PlanNode> {
+        // The CTE queries are always executed on the local node
+        self.running_mode = RunningMode::Standalone;
+        Ok(PlanNode::CteScan(plan.clone()))
+    }
 }
 
 impl ScattersOptimizer {
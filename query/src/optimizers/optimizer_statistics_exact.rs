@@ -114,6 +114,7 @@ impl PlanRewriter for StatisticsExactImpl<'_> {
             schema_before_group_by: plan.schema_before_group_by.clone(),
             aggr_expr: plan.aggr_expr.clone(),
             group_expr: plan.group_expr.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
             input: Arc::new(self.rewrite_plan_node(plan.input.as_ref())?),
         }))
     }
@@ -26,6 +26,8 @@ mod processor_empty_test;
 mod processor_merge_test;
 #[cfg(test)]
 mod processor_mixed_test;
+#[cfg(test)]
+mod processor_profiling_test;
 
 mod pipe;
 mod pipeline;
@@ -36,12 +38,15 @@ mod processor;
 mod processor_empty;
 mod processor_merge;
 mod processor_mixed;
+mod processor_profiling;
 
 pub use pipe::Pipe;
 pub use pipeline::Pipeline;
 pub use pipeline_builder::PipelineBuilder;
+pub use pipeline_display::StageProfilesDisplay;
 pub use processor::FormatterSettings;
 pub use processor::Processor;
 pub use processor_empty::EmptyProcessor;
 pub use processor_merge::MergeProcessor;
 pub use processor_mixed::MixedProcessor;
+pub use processor_profiling::ProfilingProcessor;
@@ -22,21 +22,29 @@ use super::MixedProcessor;
 use crate::pipelines::processors::MergeProcessor;
 use crate::pipelines::processors::Pipe;
 use crate::pipelines::processors::Processor;
+use crate::pipelines::processors::ProfilingProcessor;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::ProcessorProfile;
 
 pub struct Pipeline {
     ctx: DatabendQueryContextRef,
     pipes: Vec<Pipe>,
+    profiles: Vec<Arc<ProcessorProfile>>,
 }
 
 impl Pipeline {
     pub fn create(ctx: DatabendQueryContextRef) -> Self {
-        Pipeline { ctx, pipes: vec![] }
+        Pipeline {
+            ctx,
+            pipes: vec![],
+            profiles: vec![],
+        }
     }
 
     /// Reset the pipeline.
     pub fn reset(&mut self) {
         self.pipes.clear();
+        self.profiles.clear();
     }
 
     /// The number of pipes.
@@ -62,7 +70,38 @@ impl Pipeline {
             .ok_or_else(|| ErrorCode::IllegalPipelineState("Pipeline last pipe can not be none"))
     }
 
+    /// The profiles of the processors, empty if the query is not profiled.
+    pub fn profiles(&self) -> Vec<Arc<ProcessorProfile>> {
+        self.profiles.clone()
+    }
+
+    /// Wrap the processor to profile it if the query is profiled.
+    fn profile(
+        &mut self,
+        pipe: usize,
+        processor: Arc<dyn Processor>,
+    ) -> Result<Arc<dyn Processor>> {
+        if !self.ctx.is_query_profile_enabled()? {
+            return Ok(processor);
+        }
+
+        let profile = Arc::new(ProcessorProfile::create(pipe, processor.name()));
+        self.ctx.add_processor_profile(profile.clone());
+        self.profiles.push(profile.clone());
+        Ok(Arc::new(ProfilingProcessor::create(processor, profile)))
+    }
+
+    fn add_pipe(&mut self, processors: Vec<Arc<dyn Processor>>) -> Result<()> {
+        let mut new_pipe = Pipe::create();
+        for processor in processors {
+            new_pipe.add(self.profile(self.pipes.len(), processor)?);
+        }
+        self.pipes.push(new_pipe);
+        Ok(())
+    }
+
     pub fn add_source(&mut self, source: Arc<dyn Processor>) -> Result<()> {
+        let source = self.profile(0, source)?;
         if self.pipes.first().is_none() {
             let mut first = Pipe::create();
             first.add(source);
@@ -86,14 +125,13 @@ impl Pipeline {
         f: impl Fn() -> Result<Box<dyn Processor>>,
     ) -> Result<()> {
         let last_pipe = self.last_pipe()?;
-        let mut new_pipe = Pipe::create();
+        let mut processors = vec![];
         for x in last_pipe.processors() {
             let mut p = f()?;
             p.connect_to(x.clone())?;
-            new_pipe.add(Arc::from(p));
+            processors.push(Arc::from(p));
         }
-        self.pipes.push(new_pipe);
-        Ok(())
+        self.add_pipe(processors)
     }
 
     /// Merge many(or one)-ways processors into one-way.
@@ -111,9 +149,8 @@ impl Pipeline {
             for x in last_pipe.processors() {
                 merge.connect_to(x.clone())?;
             }
-            let mut new_pipe = Pipe::create();
-            new_pipe.add(Arc::from(merge));
-            self.pipes.push(new_pipe);
+            let merge: Arc<dyn Processor> = Arc::new(merge);
+            self.add_pipe(vec![merge])?;
         }
         Ok(())
     }
@@ -142,15 +179,13 @@ impl Pipeline {
             processor.connect_to(x)?;
         }
 
-        let mut new_pipe = Pipe::create();
+        let mut processors: Vec<Arc<dyn Processor>> = vec![];
         for _i in 0..n - 1 {
             let processor = processor.share()?;
-            new_pipe.add(Arc::from(processor));
+            processors.push(Arc::new(processor));
         }
-        new_pipe.add(Arc::from(processor));
-        self.pipes.push(new_pipe);
-
-        Ok(())
+        processors.push(Arc::new(processor));
+        self.add_pipe(processors)
     }
 
     pub async fn execute(&mut self) -> Result<SendableDataBlockStream> {
@@ -17,7 +17,9 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
+use common_planners::ArrayJoinPlan;
 use common_planners::BroadcastPlan;
+use common_planners::CteScanPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
@@ -26,18 +28,23 @@ use common_planners::LimitPlan;
 use common_planners::PlanNode;
 use common_planners::ProjectionPlan;
 use common_planners::ReadDataSourcePlan;
+use common_planners::ReadFilesPlan;
 use common_planners::RemotePlan;
 use common_planners::SelectPlan;
+use common_planners::SinkPlan;
 use common_planners::SortPlan;
 use common_planners::StagePlan;
 use common_planners::SubQueriesSetPlan;
+use common_planners::UnionAllPlan;
 use common_tracing::tracing;
 
 use crate::api::FlightTicket;
 use crate::pipelines::processors::Pipeline;
 use crate::pipelines::transforms::AggregatorFinalTransform;
 use crate::pipelines::transforms::AggregatorPartialTransform;
+use crate::pipelines::transforms::ArrayJoinTransform;
 use crate::pipelines::transforms::CreateSetsTransform;
+use crate::pipelines::transforms::CteScanTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::FilterTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
@@ -45,11 +52,14 @@ use crate::pipelines::transforms::GroupByPartialTransform;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
 use crate::pipelines::transforms::ProjectionTransform;
+use crate::pipelines::transforms::ReadFilesTransform;
 use crate::pipelines::transforms::RemoteTransform;
+use crate::pipelines::transforms::SinkTransform;
 use crate::pipelines::transforms::SortMergeTransform;
 use crate::pipelines::transforms::SortPartialTransform;
 use crate::pipelines::transforms::SourceTransform;
 use crate::pipelines::transforms::SubQueriesPuller;
+use crate::sessions::DatabendQueryContext;
 use crate::sessions::DatabendQueryContextRef;
 
 pub struct PipelineBuilder {
@@ -86,7 +96,12 @@ impl PipelineBuilder {
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
+            PlanNode::ArrayJoin(node) => self.visit_array_join(node),
+            PlanNode::Sink(node) => self.visit_sink(node),
+            PlanNode::CteScan(node) => self.visit_cte_scan(node),
+            PlanNode::UnionAll(node) => self.visit_union_all(node),
             PlanNode::ReadSource(node) => self.visit_read_data_source(node),
+            PlanNode::ReadFiles(node) => self.visit_read_files(node),
             PlanNode::SubQueryExpression(node) => self.visit_create_sets(node),
             other => Result::Err(ErrorCode::UnknownPlan(format!(
                 "Build pipeline from the plan node unsupported:{:?}",
@@ -166,12 +181,16 @@ impl PipelineBuilder {
             })?;
         } else {
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(GroupByPartialTransform::create(
-                    node.schema(),
-                    node.input.schema(),
-                    node.aggr_expr.clone(),
-                    node.group_expr.clone(),
-                )))
+                Ok(Box::new(
+                    GroupByPartialTransform::create(
+                        node.schema(),
+                        node.input.schema(),
+                        node.aggr_expr.clone(),
+                        node.group_expr.clone(),
+                    )
+                    .with_grouping_sets(node.grouping_sets.clone())
+                    .with_memory_tracker(self.ctx.get_memory_tracker()),
+                ))
             })?;
         }
         Ok(pipeline)
@@ -192,15 +211,19 @@ impl PipelineBuilder {
         } else {
             let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(GroupByFinalTransform::create(
-                    node.schema(),
-                    max_block_size,
-                    node.schema_before_group_by.clone(),
-                    node.aggr_expr.clone(),
-                    node.group_expr.clone(),
-                )))
+                Ok(Box::new(
+                    GroupByFinalTransform::create(
+                        node.schema(),
+                        max_block_size,
+                        node.schema_before_group_by.clone(),
+                        node.aggr_expr.clone(),
+                        node.group_expr.clone(),
+                    )
+                    .with_grouping_sets(node.grouping_sets.clone())
+                    .with_memory_tracker(self.ctx.get_memory_tracker()),
+                ))
             })?;
-            pipeline.mixed_processor(self.ctx.get_settings().get_max_threads()? as usize)?;
+            pipeline.mixed_processor(self.ctx.get_max_threads()?)?;
         }
         Ok(pipeline)
     }
@@ -247,11 +270,10 @@ impl PipelineBuilder {
         // processor 2: [sorted blocks ...] ---> merge to one sorted block
         // processor 3: [sorted blocks ...] ---> merge to one sorted block
         pipeline.add_simple_transform(|| {
-            Ok(Box::new(SortMergeTransform::try_create(
-                plan.schema(),
-                plan.order_by.clone(),
-                self.limit,
-            )?))
+            Ok(Box::new(
+                SortMergeTransform::try_create(plan.schema(), plan.order_by.clone(), self.limit)?
+                    .with_memory_tracker(self.ctx.get_memory_tracker()),
+            ))
         })?;
 
         // processor1 sorted block --
@@ -262,11 +284,14 @@ impl PipelineBuilder {
         if pipeline.last_pipe()?.nums() > 1 {
             pipeline.merge_processor()?;
             pipeline.add_simple_transform(|| {
-                Ok(Box::new(SortMergeTransform::try_create(
-                    plan.schema(),
-                    plan.order_by.clone(),
-                    self.limit,
-                )?))
+                Ok(Box::new(
+                    SortMergeTransform::try_create(
+                        plan.schema(),
+                        plan.order_by.clone(),
+                        self.limit,
+                    )?
+                    .with_memory_tracker(self.ctx.get_memory_tracker()),
+                ))
             })?;
         }
         Ok(pipeline)
@@ -295,12 +320,57 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_array_join(&mut self, node: &ArrayJoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ArrayJoinTransform::try_create(
+                node.schema(),
+                node.exprs.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
+    fn visit_sink(&mut self, node: &SinkPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*node.input)?;
+        pipeline.merge_processor()?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(SinkTransform::create(
+                self.ctx.clone(),
+                node.clone(),
+            )))
+        })?;
+        Ok(pipeline)
+    }
+
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan) -> Result<Pipeline> {
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        let source = CteScanTransform::create(self.ctx.clone(), plan.clone());
+        pipeline.add_source(Arc::new(source))?;
+        Ok(pipeline)
+    }
+
+    fn visit_union_all(&mut self, plan: &UnionAllPlan) -> Result<Pipeline> {
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        for input in &plan.inputs {
+            // Each input binds its own partitions, so it needs its own context.
+            let input_ctx = DatabendQueryContext::new(self.ctx.clone());
+            let input_pipeline = PipelineBuilder::create(input_ctx).build(input.as_ref())?;
+
+            // The outputs of all the inputs run in parallel as the sources of the union.
+            for processor in input_pipeline.last_pipe()?.processors() {
+                pipeline.add_source(processor)?;
+            }
+        }
+        Ok(pipeline)
+    }
+
     fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<Pipeline> {
         // Bind plan partitions to context.
         self.ctx.try_set_partitions(plan.parts.clone())?;
 
         let mut pipeline = Pipeline::create(self.ctx.clone());
-        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = self.ctx.get_max_threads()?;
         let max_threads = std::cmp::min(max_threads, plan.parts.len());
         let workers = std::cmp::max(max_threads, 1);
 
@@ -311,6 +381,22 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_read_files(&mut self, plan: &ReadFilesPlan) -> Result<Pipeline> {
+        // Bind the files to context, the workers read them one by one.
+        self.ctx.try_set_partitions(plan.parts.clone())?;
+
+        let mut pipeline = Pipeline::create(self.ctx.clone());
+        let max_threads = self.ctx.get_max_threads()?;
+        let max_threads = std::cmp::min(max_threads, plan.parts.len());
+        let workers = std::cmp::max(max_threads, 1);
+
+        for _i in 0..workers {
+            let source = ReadFilesTransform::try_create(self.ctx.clone(), plan.clone())?;
+            pipeline.add_source(Arc::new(source))?;
+        }
+        Ok(pipeline)
+    }
+
     fn visit_create_sets(&mut self, plan: &SubQueriesSetPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
         let schema = plan.schema();
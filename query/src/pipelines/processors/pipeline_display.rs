@@ -15,7 +15,10 @@
 use std::fmt;
 use std::fmt::Display;
 
+use common_io::prelude::convert_byte_size;
+
 use crate::pipelines::processors::Pipeline;
+use crate::sessions::QueryProfileElement;
 
 impl Pipeline {
     pub fn display_indent(&self) -> impl fmt::Display + '_ {
@@ -131,6 +134,34 @@ impl Pipeline {
         Wrapper(self)
     }
 
+    /// The indented pipeline with the runtime statistics of each pipe, for EXPLAIN ANALYZE.
+    pub fn display_analyze(&self) -> impl fmt::Display + '_ {
+        struct Wrapper<'a>(&'a Pipeline);
+        impl<'a> fmt::Display for Wrapper<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let profiles = self
+                    .0
+                    .profiles()
+                    .iter()
+                    .map(|profile| profile.to_element("", ""))
+                    .collect::<Vec<_>>();
+
+                // The pipes are displayed from the last one to the first one.
+                let pipes = self.0.pipes().len();
+                let display = format!("{}", self.0.display_indent());
+                for (index, line) in display.lines().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", line)?;
+                    write_pipe_stats(f, &profiles, pipes - 1 - index)?;
+                }
+                Ok(())
+            }
+        }
+        Wrapper(self)
+    }
+
     pub fn display_graphviz(&self) -> impl fmt::Display + '_ {
         struct Wrapper<'a>(&'a Pipeline);
         impl<'a> fmt::Display for Wrapper<'a> {
@@ -155,3 +186,75 @@ impl fmt::Debug for Pipeline {
         self.display_indent().fmt(f)
     }
 }
+
+/// The profiles of a remote stage of the query, displayed as the pipeline of EXPLAIN ANALYZE.
+pub struct StageProfilesDisplay {
+    pub stage: String,
+    pub node: String,
+    pub profiles: Vec<QueryProfileElement>,
+}
+
+impl fmt::Display for StageProfilesDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RemoteStage {} on {}", self.stage, self.node)?;
+
+        let last_pipe = self.profiles.iter().map(|p| p.pipe).max().unwrap_or(0);
+        for pipe in (0..=last_pipe as usize).rev() {
+            let processors = self
+                .profiles
+                .iter()
+                .filter(|p| p.pipe as usize == pipe)
+                .collect::<Vec<_>>();
+
+            if let Some(processor) = processors.first() {
+                writeln!(f)?;
+                for _ in 0..(last_pipe as usize - pipe + 1) {
+                    write!(f, "  ")?;
+                }
+                write!(
+                    f,
+                    "{} × {} {}",
+                    processor.processor,
+                    processors.len(),
+                    if processors.len() == 1 {
+                        "processor"
+                    } else {
+                        "processors"
+                    },
+                )?;
+                write_pipe_stats(f, &self.profiles, pipe)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_pipe_stats(
+    f: &mut fmt::Formatter,
+    profiles: &[QueryProfileElement],
+    pipe: usize,
+) -> fmt::Result {
+    let pipe_profiles = |pipe: usize| profiles.iter().filter(move |p| p.pipe as usize == pipe);
+    if pipe_profiles(pipe).next().is_none() {
+        return Ok(());
+    }
+
+    write!(f, " (")?;
+    if pipe > 0 {
+        let rows_in: u64 = pipe_profiles(pipe - 1).map(|p| p.output_rows).sum();
+        write!(f, "rows in: {}, ", rows_in)?;
+    }
+
+    let rows_out: u64 = pipe_profiles(pipe).map(|p| p.output_rows).sum();
+    let bytes_out: u64 = pipe_profiles(pipe).map(|p| p.output_bytes).sum();
+    let elapsed_us: u64 = pipe_profiles(pipe).map(|p| p.elapsed_us).sum();
+    let cpu_us: u64 = pipe_profiles(pipe).map(|p| p.cpu_us).sum();
+    write!(
+        f,
+        "rows out: {}, bytes out: {}, elapsed: {:.3} ms, cpu: {:.3} ms)",
+        rows_out,
+        convert_byte_size(bytes_out as f64),
+        elapsed_us as f64 / 1000.0,
+        cpu_us as f64 / 1000.0,
+    )
+}
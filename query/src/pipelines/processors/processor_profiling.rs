@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::cell::Cell;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_streams::SendableDataBlockStream;
+use futures::Stream;
+use futures::StreamExt;
+
+use crate::pipelines::processors::Processor;
+use crate::sessions::ProcessorProfile;
+
+thread_local! {
+    // The (wall, cpu) nanoseconds spent by the profiled inputs polled by the current processor.
+    static INPUTS_ELAPSED: Cell<(u64, u64)> = Cell::new((0, 0));
+}
+
+/// Wraps a processor to record its output and elapsed time into a `ProcessorProfile`,
+/// used when the query is profiled.
+pub struct ProfilingProcessor {
+    inner: Arc<dyn Processor>,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl ProfilingProcessor {
+    pub fn create(inner: Arc<dyn Processor>, profile: Arc<ProcessorProfile>) -> Self {
+        ProfilingProcessor { inner, profile }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ProfilingProcessor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        match Arc::get_mut(&mut self.inner) {
+            Some(inner) => inner.connect_to(input),
+            None => Err(ErrorCode::IllegalPipelineState(
+                "Cannot connect to a shared processor",
+            )),
+        }
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        self.inner.inputs()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self.inner.as_any()
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let input = self.inner.execute().await?;
+        Ok(Box::pin(ProfilingStream {
+            input,
+            profile: self.profile.clone(),
+        }))
+    }
+}
+
+struct ProfilingStream {
+    input: SendableDataBlockStream,
+    profile: Arc<ProcessorProfile>,
+}
+
+impl Stream for ProfilingStream {
+    type Item = Result<DataBlock>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // The inputs polled on this thread are profiled by their own streams, their time is
+        // subtracted, the inputs running in other tasks are not polled here.
+        let outer_inputs = INPUTS_ELAPSED.with(|v| v.replace((0, 0)));
+        let start = Instant::now();
+        let start_cpu = thread_cpu_time();
+        let poll = self.input.poll_next_unpin(ctx);
+        let elapsed = start.elapsed().as_nanos() as u64;
+        let cpu = thread_cpu_time().saturating_sub(start_cpu);
+        let (inputs_elapsed, inputs_cpu) = INPUTS_ELAPSED.with(|v| v.get());
+        INPUTS_ELAPSED.with(|v| v.set((outer_inputs.0 + elapsed, outer_inputs.1 + cpu)));
+
+        let profile = &self.profile;
+        let elapsed = elapsed.saturating_sub(inputs_elapsed);
+        profile.elapsed_ns.fetch_add(elapsed, Ordering::Relaxed);
+        profile
+            .cpu_ns
+            .fetch_add(cpu.saturating_sub(inputs_cpu), Ordering::Relaxed);
+        if let Poll::Ready(Some(Ok(block))) = &poll {
+            let rows = block.num_rows() as u64;
+            let bytes = block.memory_size() as u64;
+            profile.output_rows.fetch_add(rows, Ordering::Relaxed);
+            profile.output_bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+        poll
+    }
+}
+
+/// The CPU time of the current thread in nanoseconds.
+fn thread_cpu_time() -> u64 {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+
+    match unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) } {
+        0 => time.tv_sec as u64 * 1_000_000_000 + time.tv_nsec as u64,
+        _ => 0,
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::Result;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::pipelines::processors::*;
+use crate::tests;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processor_profiling() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    ctx.get_settings().set_enable_query_profile(1)?;
+    let test_source = tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+
+    let source = test_source.number_source_transform_for_test(6)?;
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.mixed_processor(2)?;
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let rows: usize = result.iter().map(|block| block.num_rows()).sum();
+    assert_eq!(rows, 6);
+
+    // The source, the two mixed processors and the final merge.
+    let profiles = ctx.get_processor_profiles();
+    assert_eq!(profiles.len(), 4);
+
+    let output_rows = |pipe: usize| -> u64 {
+        profiles
+            .iter()
+            .filter(|profile| profile.pipe == pipe)
+            .map(|profile| profile.output_rows.load(Ordering::Relaxed))
+            .sum()
+    };
+    assert_eq!(output_rows(0), 6);
+    assert_eq!(output_rows(1), 6);
+    assert_eq!(output_rows(2), 6);
+    assert_eq!(profiles[0].processor, "SourceTransform");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processor_profiling_disabled() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+    let source = test_source.number_source_transform_for_test(6)?;
+    pipeline.add_source(Arc::new(source))?;
+
+    let stream = pipeline.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    assert!(ctx.get_processor_profiles().is_empty());
+
+    Ok(())
+}
@@ -34,6 +34,7 @@ use crate::pipelines::transforms::group_by::aggregator_params::AggregatorParamsR
 use crate::pipelines::transforms::group_by::aggregator_state::AggregatorState;
 use crate::pipelines::transforms::group_by::aggregator_state_entity::StateEntity;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+use crate::sessions::MemoryTracker;
 
 pub struct Aggregator<Method: HashMethod> {
     method: Method,
@@ -47,11 +48,15 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
     // If we set it to inline(performance degradation).
     // Because it will make other internal functions to no inline
+    /// The growth of the state is charged to the memory tracker after each block, `charged`
+    /// is the bytes to release from it once the state is dropped.
     #[inline(never)]
     pub async fn aggregate(
         &self,
         group_cols: Vec<String>,
         mut stream: SendableDataBlockStream,
+        memory_tracker: Option<&MemoryTracker>,
+        charged: &mut usize,
     ) -> Result<Method::State> {
         // This may be confusing
         // It will help us improve performance ~10% when we declare local references for them.
@@ -69,6 +74,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
                     let group_columns = Self::group_columns(&group_cols, &block)?;
                     let group_keys = hash_method.build_keys(&group_columns, block.num_rows())?;
                     self.lookup_key(group_keys, &mut state);
+                    Self::charge(&state, memory_tracker, charged)?;
                 }
             }
             false => {
@@ -81,6 +87,7 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
 
                     let places = self.lookup_state(group_keys, &mut state);
                     Self::execute(aggregator_params, &block, &places)?;
+                    Self::charge(&state, memory_tracker, charged)?;
                 }
             }
         }
@@ -88,6 +95,22 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method>> Aggregator<Method> {
         Ok(state)
     }
 
+    #[inline(always)]
+    fn charge(
+        state: &Method::State,
+        memory_tracker: Option<&MemoryTracker>,
+        charged: &mut usize,
+    ) -> Result<()> {
+        if let Some(tracker) = memory_tracker {
+            let bytes = state.allocated_bytes();
+            if bytes > *charged {
+                tracker.alloc(bytes - *charged)?;
+                *charged = bytes;
+            }
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     #[allow(clippy::ptr_arg)] // &[StateAddr] slower than &StateAddrs ~20%
     fn execute(params: &AggregatorParams, block: &DataBlock, places: &StateAddrs) -> Result<()> {
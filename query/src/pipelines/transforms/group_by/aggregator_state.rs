@@ -52,6 +52,10 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
     fn alloc_layout(&self, params: &AggregatorParams) -> StateAddr;
 
     fn entity(&mut self, key: &Method::HashKey, inserted: &mut bool) -> *mut Self::Entity;
+
+    /// The bytes held by the keys and the aggregate function states, charged to the memory
+    /// tracker of the query.
+    fn allocated_bytes(&self) -> usize;
 }
 
 /// The fixed length array is used as the data structure to locate the key by subscript
@@ -153,6 +157,12 @@ where
             value
         }
     }
+
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        let entity_size = std::mem::size_of::<ShortFixedKeysStateEntity<T>>();
+        self.max_size * entity_size + self.area.allocated_bytes()
+    }
 }
 
 pub struct LongerFixedKeysAggregatorState<T: HashTableKeyable> {
@@ -207,6 +217,11 @@ where
     fn entity(&mut self, key: &Self::Key, inserted: &mut bool) -> *mut Self::Entity {
         self.data.insert_key(key, inserted)
     }
+
+    #[inline(always)]
+    fn allocated_bytes(&self) -> usize {
+        self.data.allocated_bytes() + self.area.allocated_bytes()
+    }
 }
 
 pub struct SerializedKeysAggregatorState {
@@ -268,4 +283,10 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
 
         state_entity
     }
+
+    fn allocated_bytes(&self) -> usize {
+        let keys_bytes = self.keys_area.allocated_bytes();
+        let states_bytes = self.state_area.allocated_bytes();
+        self.data_state_map.allocated_bytes() + keys_bytes + states_bytes
+    }
 }
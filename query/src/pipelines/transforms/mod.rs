@@ -14,8 +14,10 @@
 
 pub use transform_aggregator_final::AggregatorFinalTransform;
 pub use transform_aggregator_partial::AggregatorPartialTransform;
+pub use transform_array_join::ArrayJoinTransform;
 pub use transform_create_sets::CreateSetsTransform;
 pub use transform_create_sets::SubQueriesPuller;
+pub use transform_cte_scan::CteScanTransform;
 pub use transform_expression::ExpressionTransform;
 pub use transform_expression_executor::ExpressionExecutor;
 pub use transform_filter::FilterTransform;
@@ -24,7 +26,9 @@ pub use transform_group_by_partial::GroupByPartialTransform;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
+pub use transform_read_files::ReadFilesTransform;
 pub use transform_remote::RemoteTransform;
+pub use transform_sink::SinkTransform;
 pub use transform_sort_merge::SortMergeTransform;
 pub use transform_sort_partial::SortPartialTransform;
 pub use transform_source::SourceTransform;
@@ -34,6 +38,8 @@ mod transform_aggregator_final_test;
 #[cfg(test)]
 mod transform_aggregator_partial_test;
 #[cfg(test)]
+mod transform_array_join_test;
+#[cfg(test)]
 mod transform_expression_test;
 #[cfg(test)]
 mod transform_filter_test;
@@ -48,13 +54,17 @@ mod transform_limit_test;
 #[cfg(test)]
 mod transform_projection_test;
 #[cfg(test)]
+mod transform_sink_test;
+#[cfg(test)]
 mod transform_sort_test;
 #[cfg(test)]
 mod transform_source_test;
 
 mod transform_aggregator_final;
 mod transform_aggregator_partial;
+mod transform_array_join;
 mod transform_create_sets;
+mod transform_cte_scan;
 mod transform_expression;
 mod transform_expression_executor;
 mod transform_filter;
@@ -63,7 +73,9 @@ mod transform_group_by_partial;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
+mod transform_read_files;
 mod transform_remote;
+mod transform_sink;
 mod transform_sort_merge;
 mod transform_sort_partial;
 mod transform_source;
@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_streams::CorrectWithSchemaStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use tokio_stream::StreamExt;
+
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+
+/// Unfold the array argument of every `arrayJoin(arr)` into rows, the other columns are
+/// replicated for every item. Empty and null arrays produce no rows, several arrayJoin
+/// produce the cartesian product of their items.
+pub struct ArrayJoinTransform {
+    schema: DataSchemaRef,
+    input: Arc<dyn Processor>,
+    exprs: Vec<Expression>,
+}
+
+impl ArrayJoinTransform {
+    pub fn try_create(schema: DataSchemaRef, exprs: Vec<Expression>) -> Result<Self> {
+        for expr in exprs.iter() {
+            match expr {
+                Expression::ScalarFunction { args, .. } if args.len() == 1 => {}
+                _ => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "ArrayJoinTransform expect arrayJoin(arr) expressions, but got {:?}",
+                        expr
+                    )))
+                }
+            }
+        }
+
+        Ok(ArrayJoinTransform {
+            schema,
+            input: Arc::new(EmptyProcessor::create()),
+            exprs,
+        })
+    }
+
+    fn array_join(
+        schema: &DataSchemaRef,
+        exprs: &[Expression],
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let mut block = block;
+        for expr in exprs {
+            let array_name = match expr {
+                Expression::ScalarFunction { args, .. } => args[0].column_name(),
+                _ => unreachable!(),
+            };
+            let item_field = schema.field_with_name(&expr.column_name())?.clone();
+            block = Self::unfold(&block, &array_name, item_field)?;
+        }
+        Ok(block)
+    }
+
+    fn unfold(block: &DataBlock, array_name: &str, item_field: DataField) -> Result<DataBlock> {
+        let array = block.try_column_by_name(array_name)?.to_array()?;
+
+        let mut indices = vec![];
+        let mut items = vec![];
+        for (row, row_items) in array.list()?.into_iter().enumerate() {
+            if let Some(row_items) = row_items {
+                indices.extend(std::iter::repeat(row).take(row_items.len()));
+                if !row_items.is_empty() {
+                    items.push(DataColumn::Array(row_items));
+                }
+            }
+        }
+
+        let mut fields = block.schema().fields().clone();
+        let mut columns = Vec::with_capacity(fields.len() + 1);
+        for column in block.columns() {
+            let column = column.to_array()?.take_iter(&mut indices.iter().copied())?;
+            columns.push(DataColumn::Array(column));
+        }
+
+        columns.push(match items.is_empty() {
+            true => DataColumn::Array(DataValue::try_into_data_array(&[], item_field.data_type())?),
+            false => DataColumnCommon::concat(&items)?.cast_with_type(item_field.data_type())?,
+        });
+        fields.push(item_field);
+
+        Ok(DataBlock::create(DataSchemaRefExt::create(fields), columns))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ArrayJoinTransform {
+    fn name(&self) -> &str {
+        "ArrayJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let input_stream = self.input.execute().await?;
+        let schema = self.schema.clone();
+        let exprs = self.exprs.clone();
+        let stream = input_stream.filter_map(move |v| {
+            match v.and_then(|block| Self::array_join(&schema, &exprs, block)) {
+                Err(error) => Some(Err(error)),
+                Ok(data_block) if data_block.is_empty() => None,
+                Ok(data_block) => Some(Ok(data_block)),
+            }
+        });
+
+        Ok(Box::pin(CorrectWithSchemaStream::new(
+            Box::pin(stream),
+            self.schema.clone(),
+        )))
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::Result;
+use common_planners::*;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::pipelines::processors::*;
+use crate::pipelines::transforms::*;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_array_join() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let mut pipeline = Pipeline::create(ctx.clone());
+
+    let source = test_source.number_source_transform_for_test(3)?;
+    pipeline.add_source(Arc::new(source))?;
+    pipeline.merge_processor()?;
+
+    let array = Expression::ScalarFunction {
+        op: "array".to_string(),
+        args: vec![col("number"), add(col("number"), lit(1u64))],
+    };
+    let array_join = Expression::ScalarFunction {
+        op: "arrayJoin".to_string(),
+        args: vec![array.clone()],
+    };
+
+    let plan = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .expression(&[array], "Before ArrayJoin")?
+        .array_join(&[array_join.clone()])?
+        .build()?;
+
+    if let PlanNode::ArrayJoin(join_plan) = plan {
+        if let PlanNode::Expression(expression_plan) = join_plan.input.as_ref() {
+            pipeline.add_simple_transform(|| {
+                Ok(Box::new(ExpressionTransform::try_create(
+                    expression_plan.input.schema(),
+                    expression_plan.schema.clone(),
+                    expression_plan.exprs.clone(),
+                )?))
+            })?;
+        }
+
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ArrayJoinTransform::try_create(
+                join_plan.schema(),
+                join_plan.exprs.clone(),
+            )?))
+        })?;
+
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(ProjectionTransform::try_create(
+                join_plan.schema(),
+                DataSchemaRefExt::create(vec![array_join.to_data_field(&join_plan.schema())?]),
+                vec![array_join.clone()],
+            )?))
+        })?;
+    }
+
+    let stream = pipeline.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 1);
+
+    let expected = vec![
+        "+----------------------------------------+",
+        "| arrayJoin(array(number, (number + 1))) |",
+        "+----------------------------------------+",
+        "| 0                                      |",
+        "| 1                                      |",
+        "| 1                                      |",
+        "| 2                                      |",
+        "| 2                                      |",
+        "| 3                                      |",
+        "+----------------------------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
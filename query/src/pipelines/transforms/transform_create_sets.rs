@@ -38,6 +38,7 @@ use crate::pipelines::processors::PipelineBuilder;
 use crate::pipelines::processors::Processor;
 use crate::sessions::DatabendQueryContext;
 use crate::sessions::DatabendQueryContextRef;
+use crate::sessions::MemoryTracker;
 
 pub struct CreateSetsTransform {
     ctx: DatabendQueryContextRef,
@@ -173,9 +174,11 @@ impl<'a> SubQueriesPuller<'a> {
             match query_expression {
                 Expression::Subquery { query_plan, .. } => {
                     let plan = query_plan.as_ref().clone();
+                    let memory_tracker = subquery_ctx.get_memory_tracker();
                     let builder = PipelineBuilder::create(subquery_ctx);
                     let pipeline = builder.build(&plan)?;
-                    let shared_future = Self::receive_subquery_res(plan.schema(), pipeline);
+                    let shared_future =
+                        Self::receive_subquery_res(plan.schema(), pipeline, memory_tracker);
                     self.sub_queries.push(shared_future);
                 }
                 Expression::ScalarSubquery { query_plan, .. } => {
@@ -196,7 +199,12 @@ impl<'a> SubQueriesPuller<'a> {
         Ok(())
     }
 
-    fn receive_subquery_res(schema: DataSchemaRef, mut pipeline: Pipeline) -> SharedFuture<'a> {
+    // The set of the subquery is kept, and charged, until the query finishes.
+    fn receive_subquery_res(
+        schema: DataSchemaRef,
+        mut pipeline: Pipeline,
+        memory_tracker: Arc<MemoryTracker>,
+    ) -> SharedFuture<'a> {
         let subquery_future = async move {
             let mut stream = pipeline.execute().await?;
             let mut columns = Vec::with_capacity(schema.fields().len());
@@ -208,6 +216,7 @@ impl<'a> SubQueriesPuller<'a> {
 
             while let Some(data_block) = stream.next().await {
                 let data_block = data_block?;
+                memory_tracker.alloc(data_block.memory_size())?;
 
                 #[allow(clippy::needless_range_loop)]
                 for column_index in 0..data_block.num_columns() {
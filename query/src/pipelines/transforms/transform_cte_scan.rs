@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::CteScanPlan;
+use common_planners::CteSource;
+use common_planners::PlanNode;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::FutureExt;
+use futures::TryStreamExt;
+
+use crate::optimizers::Optimizers;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::sessions::DatabendQueryContext;
+use crate::sessions::DatabendQueryContextRef;
+
+/// Reads the materialized result of a CTE.
+/// The first reference executes the CTE query, the others wait for it and share the result.
+pub struct CteScanTransform {
+    ctx: DatabendQueryContextRef,
+    plan: CteScanPlan,
+}
+
+impl CteScanTransform {
+    pub fn create(ctx: DatabendQueryContextRef, plan: CteScanPlan) -> Self {
+        CteScanTransform { ctx, plan }
+    }
+
+    async fn execute_query(
+        ctx: DatabendQueryContextRef,
+        plan: &PlanNode,
+    ) -> Result<Vec<DataBlock>> {
+        let query_ctx = DatabendQueryContext::new(ctx);
+        let optimized_plan = Optimizers::without_scatters(query_ctx.clone()).optimize(plan)?;
+        let memory_tracker = query_ctx.get_memory_tracker();
+        let mut pipeline = PipelineBuilder::create(query_ctx).build(&optimized_plan)?;
+        let stream = pipeline.execute().await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        // The materialized blocks are kept, and charged, until the query finishes.
+        let bytes = blocks.iter().map(|block| block.memory_size()).sum();
+        memory_tracker.alloc(bytes)?;
+        Ok(blocks)
+    }
+
+    async fn execute_recursive(
+        ctx: DatabendQueryContextRef,
+        name: String,
+        anchor: Arc<PlanNode>,
+        recursive: Arc<PlanNode>,
+    ) -> Result<Vec<DataBlock>> {
+        let max_iterations = ctx.get_settings().get_max_recursive_cte_iterations()?;
+
+        let mut working_table = Self::execute_query(ctx.clone(), &anchor).await?;
+        let mut result = working_table.clone();
+        let mut iterations = 0;
+
+        while working_table.iter().any(|block| block.num_rows() > 0) {
+            if iterations >= max_iterations {
+                return Err(ErrorCode::TooManyRecursiveIterations(format!(
+                    "Recursive CTE exceeded the maximum number of iterations: {}, see setting max_recursive_cte_iterations",
+                    max_iterations
+                )));
+            }
+
+            iterations += 1;
+            tracing::debug!("recursive CTE {} iteration {}", name, iterations);
+            ctx.set_cte_working_table(&name, Arc::new(working_table));
+            working_table = Self::execute_query(ctx.clone(), &recursive).await?;
+            result.extend(working_table.iter().cloned());
+        }
+
+        Ok(result)
+    }
+
+    async fn materialize(&self) -> Result<Arc<Vec<DataBlock>>> {
+        let ctx = self.ctx.clone();
+        let name = &self.plan.name;
+
+        let set_future = match &self.plan.source {
+            CteSource::WorkingTable => return self.ctx.get_cte_working_table(name),
+            CteSource::Query(query) => {
+                let query = query.clone();
+                self.ctx.get_or_create_cte_set(name, move || {
+                    async move { Ok(Arc::new(Self::execute_query(ctx, &query).await?)) }.boxed()
+                })
+            }
+            CteSource::Recursive { anchor, recursive } => {
+                let anchor = anchor.clone();
+                let recursive = recursive.clone();
+                let set_name = name.clone();
+                self.ctx.get_or_create_cte_set(name, move || {
+                    async move {
+                        let blocks =
+                            Self::execute_recursive(ctx, set_name, anchor, recursive).await?;
+                        Ok(Arc::new(blocks))
+                    }
+                    .boxed()
+                })
+            }
+        };
+
+        set_future.await
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for CteScanTransform {
+    fn name(&self) -> &str {
+        "CteScanTransform"
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::LogicalError(
+            "Cannot call CteScanTransform connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![Arc::new(EmptyProcessor::create())]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute, cte:{:#} ...", self.plan.name);
+
+        let blocks = self.materialize().await?;
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema.clone(),
+            None,
+            blocks.as_ref().clone(),
+        )))
+    }
+}
@@ -17,11 +17,14 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::columns::DataColumn;
-use common_datavalues::prelude::DataColumnWithField;
+use common_datavalues::prelude::*;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::ActionLambda;
 use common_planners::Expression;
 use common_planners::ExpressionAction;
 use common_planners::ExpressionChain;
@@ -128,6 +131,25 @@ impl ExpressionExecutor {
 
                     column_map.insert(f.name.clone(), column);
                 }
+                ExpressionAction::Lambda(lambda) => {
+                    let mut arrays = Vec::with_capacity(lambda.arg_names.len());
+                    for arg in lambda.arg_names.iter() {
+                        let column = column_map.get(arg).ok_or_else(|| {
+                            ErrorCode::LogicalError(
+                                "Arguments must be prepared before lambda transform",
+                            )
+                        })?;
+                        arrays.push(column.column().to_array()?);
+                    }
+
+                    let column = Self::execute_lambda(lambda, &arrays)?;
+                    let column = DataColumnWithField::new(
+                        column,
+                        DataField::new(&lambda.name, lambda.return_type.clone(), false),
+                    );
+
+                    column_map.insert(lambda.name.clone(), column);
+                }
                 ExpressionAction::Constant(constant) => {
                     let column = DataColumn::Constant(constant.value.clone(), rows);
 
@@ -175,4 +197,96 @@ impl ExpressionExecutor {
             project_columns,
         ))
     }
+
+    /// Unfolds the array items into one column per lambda parameter, evaluates the lambda body
+    /// on them and folds the results back into one array per row.
+    fn execute_lambda(lambda: &ActionLambda, arrays: &[Series]) -> Result<DataColumn> {
+        let lambda_schema =
+            Expression::to_lambda_schema(&lambda.func_name, &lambda.params, &lambda.arg_types)?;
+
+        // The size of every row, None if the first array is null.
+        let mut sizes: Vec<Option<usize>> = vec![];
+        let mut param_columns = Vec::with_capacity(arrays.len());
+        for (i, array) in arrays.iter().enumerate() {
+            let mut items = vec![];
+            for (row, row_items) in array.list()?.into_iter().enumerate() {
+                let size = row_items.as_ref().map(|v| v.len());
+                if i == 0 {
+                    sizes.push(size);
+                } else if sizes[row].unwrap_or(0) != size.unwrap_or(0) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Arrays passed to {} must have equal size",
+                        lambda.func_name
+                    )));
+                }
+                if let Some(row_items) = row_items {
+                    items.push(DataColumn::Array(row_items));
+                }
+            }
+
+            let item_type = lambda_schema.field(i).data_type();
+            param_columns.push(match items.is_empty() {
+                true => DataColumn::Array(DataValue::try_into_data_array(&[], item_type)?),
+                false => DataColumnCommon::concat(&items)?,
+            });
+        }
+
+        let body_field = lambda.body.to_data_field(&lambda_schema)?;
+        let executor = ExpressionExecutor::try_create(
+            "Lambda executor",
+            lambda_schema.clone(),
+            DataSchemaRefExt::create(vec![body_field]),
+            vec![lambda.body.clone()],
+            false,
+        )?;
+        let block = DataBlock::create(lambda_schema, param_columns.clone());
+        let result = executor.execute(&block)?.column(0).to_array()?;
+
+        match lambda.func_name.to_lowercase().as_str() {
+            "arraymap" => Self::build_lists(&result, &sizes),
+            _ => {
+                let mut indices = vec![];
+                let mut filtered_sizes = Vec::with_capacity(sizes.len());
+                let mut mask = result.bool()?.into_iter();
+                let mut offset = 0;
+                for size in sizes.iter() {
+                    let size = size.unwrap_or(0);
+                    let before = indices.len();
+                    for index in offset..offset + size {
+                        if let Some(Some(true)) = mask.next() {
+                            indices.push(index);
+                        }
+                    }
+                    offset += size;
+                    filtered_sizes.push(Some(indices.len() - before));
+                }
+
+                for (filtered_size, size) in filtered_sizes.iter_mut().zip(sizes.iter()) {
+                    if size.is_none() {
+                        *filtered_size = None;
+                    }
+                }
+
+                let items = param_columns[0]
+                    .to_array()?
+                    .take_iter(&mut indices.into_iter())?;
+                Self::build_lists(&items, &filtered_sizes)
+            }
+        }
+    }
+
+    fn build_lists(items: &Series, sizes: &[Option<usize>]) -> Result<DataColumn> {
+        let mut builder = get_list_builder(items.data_type(), items.len(), sizes.len());
+        let mut offset = 0;
+        for size in sizes {
+            match size {
+                Some(size) => {
+                    builder.append_series(&items.slice(offset, *size));
+                    offset += size;
+                }
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series().into())
+    }
 }
@@ -25,6 +25,7 @@ use common_exception::Result;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::StateAddr;
 use common_infallible::RwLock;
+use common_planners::grouping_key_fields;
 use common_planners::Expression;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -33,14 +34,17 @@ use futures::stream::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
+use crate::sessions::MemoryTracker;
 
 pub struct GroupByFinalTransform {
     max_block_size: usize,
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
+    grouping_sets: Vec<Vec<usize>>,
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
     input: Arc<dyn Processor>,
+    memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
 impl GroupByFinalTransform {
@@ -55,11 +59,50 @@ impl GroupByFinalTransform {
             max_block_size,
             aggr_exprs,
             group_exprs,
+            grouping_sets: vec![],
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
+            memory_tracker: None,
         }
     }
+
+    pub fn with_grouping_sets(mut self, grouping_sets: Vec<Vec<usize>>) -> Self {
+        self.grouping_sets = grouping_sets;
+        self
+    }
+
+    /// Charge the hash table of the merged groups to the memory tracker of the query.
+    pub fn with_memory_tracker(mut self, memory_tracker: Arc<MemoryTracker>) -> Self {
+        self.memory_tracker = Some(memory_tracker);
+        self
+    }
+
+    /// The key columns of grouping sets are followed by the grouping id, a key column is NULL
+    /// in the rows of the sets without it.
+    fn null_grouped_out_columns(group_columns: Vec<Series>) -> Result<Vec<Series>> {
+        let (grouping_id, key_columns) = match group_columns.split_last() {
+            Some((grouping_id, key_columns)) => (grouping_id.u64()?, key_columns),
+            None => return Ok(group_columns),
+        };
+
+        let mut columns = Vec::with_capacity(group_columns.len());
+        for (index, column) in key_columns.iter().enumerate() {
+            let data_type = column.data_type();
+            let values = column
+                .to_values()?
+                .into_iter()
+                .zip(grouping_id.into_no_null_iter())
+                .map(|(value, id)| match (id >> index) & 1 {
+                    0 => value,
+                    _ => DataValue::from(data_type),
+                })
+                .collect::<Vec<_>>();
+            columns.push(DataValue::try_into_data_array(&values, data_type)?);
+        }
+        columns.push(group_columns[key_columns.len()].clone());
+        Ok(columns)
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,23 +135,38 @@ impl Processor for GroupByFinalTransform {
         let aggr_funcs_len = funcs.len();
         let group_expr_len = self.group_exprs.len();
 
-        let group_cols = self
-            .group_exprs
-            .iter()
-            .map(|x| x.column_name())
-            .collect::<Vec<_>>();
-
-        let group_fields = self
-            .group_exprs
-            .iter()
-            .map(|c| c.to_data_field(&self.schema_before_group_by))
-            .collect::<Result<Vec<_>>>()?;
+        let (group_cols, group_fields, sample_block) = match self.grouping_sets.is_empty() {
+            true => {
+                let group_cols = self
+                    .group_exprs
+                    .iter()
+                    .map(|x| x.column_name())
+                    .collect::<Vec<_>>();
+
+                let group_fields = self
+                    .group_exprs
+                    .iter()
+                    .map(|c| c.to_data_field(&self.schema_before_group_by))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let sample_block =
+                    DataBlock::empty_with_schema(self.schema_before_group_by.clone());
+                (group_cols, group_fields, sample_block)
+            }
+            // The keys of grouping sets are made of the key columns and the grouping id
+            false => {
+                let key_fields =
+                    grouping_key_fields(&self.group_exprs, &self.schema_before_group_by)?;
+                let group_cols = key_fields.iter().map(|f| f.name().clone()).collect();
+                let schema = DataSchemaRefExt::create(key_fields.clone());
+                (group_cols, key_fields, DataBlock::empty_with_schema(schema))
+            }
+        };
 
         let start = Instant::now();
         let arena = Bump::new();
 
         let mut stream = self.input.execute().await?;
-        let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
         let method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
 
         let (layout, offsets_aggregate_states) = unsafe { get_layout_offsets(&funcs) };
@@ -118,6 +176,13 @@ impl Processor for GroupByFinalTransform {
                 type GroupFuncTable = $group_func_table;
                 let groups_locker = GroupFuncTable::default();
 
+                // The hash table is charged until the output blocks are built.
+                let mut charged = ChargedMemory {
+                    tracker: self.memory_tracker.as_deref(),
+                    bytes: 0,
+                };
+                let mut keys_bytes = 0;
+
                 while let Some(block) = stream.next().await {
                     let mut groups = groups_locker.write();
                     let block = block?;
@@ -140,6 +205,7 @@ impl Processor for GroupByFinalTransform {
                         let group_key = $hash_method.get_key(&key_array, row);
                         match groups.get(&group_key) {
                             None => {
+                                keys_bytes += group_key.heap_size();
                                 if aggr_funcs_len == 0 {
                                     groups.insert(group_key, 0usize);
                                 } else {
@@ -171,6 +237,9 @@ impl Processor for GroupByFinalTransform {
                             }
                         };
                     }
+
+                    let table_bytes = hash_table_bytes(&*groups) + keys_bytes;
+                    charged.resize(table_bytes + arena.allocated_bytes())?;
                 }
                 let delta = start.elapsed();
                 tracing::debug!("Group by final cost: {:?}", delta);
@@ -208,7 +277,10 @@ impl Processor for GroupByFinalTransform {
                 }
 
                 {
-                    let group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
+                    let mut group_columns = $hash_method.de_group_columns(keys, &group_fields)?;
+                    if !self.grouping_sets.is_empty() {
+                        group_columns = Self::null_grouped_out_columns(group_columns)?;
+                    }
                     columns.extend_from_slice(&group_columns);
                 }
 
@@ -251,3 +323,52 @@ impl Processor for GroupByFinalTransform {
         match_hash_method_and_apply! {method, apply}
     }
 }
+
+// Releases the bytes charged for the groups once the transform finishes or fails.
+struct ChargedMemory<'a> {
+    tracker: Option<&'a MemoryTracker>,
+    bytes: usize,
+}
+
+impl ChargedMemory<'_> {
+    fn resize(&mut self, bytes: usize) -> Result<()> {
+        if let Some(tracker) = self.tracker {
+            if bytes > self.bytes {
+                tracker.alloc(bytes - self.bytes)?;
+                self.bytes = bytes;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ChargedMemory<'_> {
+    fn drop(&mut self) {
+        if let Some(tracker) = self.tracker {
+            tracker.free(self.bytes);
+        }
+    }
+}
+
+// The buckets of the table, each one holds a key, a value and a control byte.
+fn hash_table_bytes<K, V, S>(table: &HashMap<K, V, S>) -> usize {
+    table.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+}
+
+// The bytes of a group key out of the hash table.
+trait HeapSize {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for u8 {}
+impl HeapSize for u16 {}
+impl HeapSize for u32 {}
+impl HeapSize for u64 {}
+
+impl HeapSize for Vec<u8> {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
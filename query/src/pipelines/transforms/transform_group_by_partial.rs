@@ -21,23 +21,29 @@ use common_datablocks::HashMethod;
 use common_datablocks::HashMethodKind;
 use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_planners::grouping_id;
+use common_planners::grouping_key_fields;
 use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
+use futures::StreamExt;
 
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::transforms::group_by::Aggregator;
 use crate::pipelines::transforms::group_by::AggregatorParams;
 use crate::pipelines::transforms::group_by::PolymorphicKeysHelper;
+use crate::sessions::MemoryTracker;
 
 pub struct GroupByPartialTransform {
     aggr_exprs: Vec<Expression>,
     group_exprs: Vec<Expression>,
+    grouping_sets: Vec<Vec<usize>>,
 
     schema: DataSchemaRef,
     schema_before_group_by: DataSchemaRef,
     input: Arc<dyn Processor>,
+    memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
 impl GroupByPartialTransform {
@@ -50,12 +56,25 @@ impl GroupByPartialTransform {
         Self {
             aggr_exprs,
             group_exprs,
+            grouping_sets: vec![],
             schema,
             schema_before_group_by,
             input: Arc::new(EmptyProcessor::create()),
+            memory_tracker: None,
         }
     }
 
+    pub fn with_grouping_sets(mut self, grouping_sets: Vec<Vec<usize>>) -> Self {
+        self.grouping_sets = grouping_sets;
+        self
+    }
+
+    /// Charge the hash table of the groups to the memory tracker of the query.
+    pub fn with_memory_tracker(mut self, memory_tracker: Arc<MemoryTracker>) -> Self {
+        self.memory_tracker = Some(memory_tracker);
+        self
+    }
+
     fn extract_group_columns(&self) -> Vec<String> {
         self.group_exprs
             .iter()
@@ -63,6 +82,49 @@ impl GroupByPartialTransform {
             .collect::<Vec<_>>()
     }
 
+    /// Fan each block out to one block per grouping set, with the key columns of the set and
+    /// its grouping id, so all the sets are aggregated within one pass over the input.
+    fn fan_out_grouping_sets(
+        &self,
+        stream: SendableDataBlockStream,
+    ) -> Result<SendableDataBlockStream> {
+        let group_cols = self.extract_group_columns();
+        let key_fields = grouping_key_fields(&self.group_exprs, &self.schema_before_group_by)?;
+        let grouping_sets = self.grouping_sets.clone();
+
+        let fan_out = move |block: DataBlock| -> Result<Vec<DataBlock>> {
+            let rows = block.num_rows();
+            let mut blocks = Vec::with_capacity(grouping_sets.len());
+            for grouping_set in &grouping_sets {
+                let mut fields = block.schema().fields().clone();
+                let mut columns = block.columns().to_vec();
+                for (index, key_field) in key_fields.iter().take(group_cols.len()).enumerate() {
+                    let column = match grouping_set.contains(&index) {
+                        true => block.try_column_by_name(&group_cols[index])?.clone(),
+                        false => DataColumn::Constant(DataValue::from(key_field.data_type()), rows),
+                    };
+                    fields.push(key_field.clone());
+                    columns.push(column);
+                }
+
+                let id = grouping_id(grouping_set, group_cols.len());
+                fields.push(key_fields[group_cols.len()].clone());
+                columns.push(DataColumn::Constant(DataValue::UInt64(Some(id)), rows));
+                blocks.push(DataBlock::create(DataSchemaRefExt::create(fields), columns));
+            }
+            Ok(blocks)
+        };
+
+        let stream = stream.flat_map(move |block| {
+            let blocks = match block.and_then(&fan_out) {
+                Ok(blocks) => blocks.into_iter().map(Ok).collect(),
+                Err(cause) => vec![Err(cause)],
+            };
+            futures::stream::iter(blocks)
+        });
+        Ok(Box::pin(stream))
+    }
+
     #[inline]
     async fn aggregate<Method: HashMethod + PolymorphicKeysHelper<Method>>(
         &self,
@@ -71,19 +133,33 @@ impl GroupByPartialTransform {
     ) -> Result<SendableDataBlockStream> {
         let start = Instant::now();
 
-        let stream = self.input.execute().await?;
+        let mut stream = self.input.execute().await?;
+        if !self.grouping_sets.is_empty() {
+            stream = self.fan_out_grouping_sets(stream)?;
+        }
         let aggr_exprs = &self.aggr_exprs;
         let schema = self.schema_before_group_by.clone();
         let aggregator_params = AggregatorParams::try_create(schema, aggr_exprs)?;
 
         let aggregator = Aggregator::create(method, aggregator_params);
-        let state = aggregator.aggregate(group_cols, stream).await?;
+
+        // The hash table is charged until the states are serialized into the output block.
+        let mut charged = 0;
+        let memory_tracker = self.memory_tracker.as_deref();
+        let state = aggregator
+            .aggregate(group_cols, stream, memory_tracker, &mut charged)
+            .await;
 
         let delta = start.elapsed();
         tracing::debug!("Group by partial cost: {:?}", delta);
 
         let finalized_schema = self.schema.clone();
-        aggregator.aggregate_finalized(&state, finalized_schema)
+        let finalized =
+            state.and_then(|state| aggregator.aggregate_finalized(&state, finalized_schema));
+        if let Some(tracker) = memory_tracker {
+            tracker.free(charged);
+        }
+        finalized
     }
 }
 
@@ -127,8 +203,20 @@ impl Processor for GroupByPartialTransform {
     /// 1.2)  serialize the state to the output block
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
-        let group_cols = self.extract_group_columns();
-        let sample_block = DataBlock::empty_with_schema(self.schema_before_group_by.clone());
+        let (group_cols, sample_block) = match self.grouping_sets.is_empty() {
+            true => (
+                self.extract_group_columns(),
+                DataBlock::empty_with_schema(self.schema_before_group_by.clone()),
+            ),
+            // Grouping sets are grouped by the key columns and the grouping id
+            false => {
+                let key_fields =
+                    grouping_key_fields(&self.group_exprs, &self.schema_before_group_by)?;
+                let group_cols = key_fields.iter().map(|f| f.name().clone()).collect();
+                let schema = DataSchemaRefExt::create(key_fields);
+                (group_cols, DataBlock::empty_with_schema(schema))
+            }
+        };
         let hash_method = DataBlock::choose_hash_method(&sample_block, &group_cols)?;
 
         match hash_method {
@@ -23,6 +23,7 @@ use pretty_assertions::assert_eq;
 
 use crate::pipelines::processors::*;
 use crate::pipelines::transforms::*;
+use crate::sessions::MemoryTracker;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_transform_partial_group_by() -> Result<()> {
@@ -75,3 +76,51 @@ async fn test_transform_partial_group_by() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_transform_partial_group_by_memory_limit() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    let test_source = crate::tests::NumberTestData::create(ctx.clone());
+
+    let aggr_exprs = vec![sum(col("number"))];
+    let group_exprs = vec![col("number")];
+    let aggr_partial = PlanBuilder::create(test_source.number_schema_for_test()?)
+        .aggregate_partial(&aggr_exprs, &group_exprs)?
+        .build()?;
+
+    for limit in [0, 1] {
+        let memory_tracker = MemoryTracker::create(limit, None);
+        let mut pipeline = Pipeline::create(ctx.clone());
+        let source = test_source.number_source_transform_for_test(5)?;
+        let source_schema = test_source.number_schema_for_test()?;
+
+        pipeline.add_source(Arc::new(source))?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(
+                GroupByPartialTransform::create(
+                    aggr_partial.schema(),
+                    source_schema.clone(),
+                    aggr_exprs.clone(),
+                    group_exprs.clone(),
+                )
+                .with_memory_tracker(memory_tracker.clone()),
+            ))
+        })?;
+        pipeline.merge_processor()?;
+
+        let result = match pipeline.execute().await {
+            Ok(stream) => stream.try_collect::<Vec<_>>().await,
+            Err(cause) => Err(cause),
+        };
+        match (limit, result) {
+            (0, result) => assert_eq!(result?[0].num_rows(), 5),
+            (_, Ok(_)) => assert!(false, "The memory limit must be checked"),
+            (_, Err(cause)) => assert_eq!(cause.code(), 61),
+        }
+
+        // The hash table is released once the groups are serialized.
+        assert_eq!(memory_tracker.get_usage(), 0);
+    }
+
+    Ok(())
+}
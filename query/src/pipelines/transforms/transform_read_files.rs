@@ -0,0 +1,211 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::io;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_base::tokio::runtime::Handle;
+use common_base::tokio::sync::mpsc::channel;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::FileFormatOptions;
+use common_planners::ReadFilesPlan;
+use common_streams::FormatSettings;
+use common_streams::SendableDataBlockStream;
+use common_streams::SourceFactory;
+use common_tracing::tracing;
+use futures::AsyncReadExt;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+
+use crate::datasources::dal::DataAccessor;
+use crate::datasources::dal::InputStream;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::sessions::DatabendQueryContextRef;
+
+/// Reads the files of the parts bound to the context, one file at a time, until there
+/// are no parts left.
+pub struct ReadFilesTransform {
+    ctx: DatabendQueryContextRef,
+    plan: ReadFilesPlan,
+}
+
+impl ReadFilesTransform {
+    pub fn try_create(ctx: DatabendQueryContextRef, plan: ReadFilesPlan) -> Result<Self> {
+        Ok(ReadFilesTransform { ctx, plan })
+    }
+
+    /// The source format and its settings of the FILE_FORMAT options, CSV by default.
+    pub fn format_settings(options: &FileFormatOptions) -> Result<(String, FormatSettings)> {
+        let mut settings = FormatSettings::default();
+        for (key, value) in options {
+            match key.as_str() {
+                "type" => SourceFactory::check_format(value)?,
+                "field_delimiter" => settings.field_delimiter = Self::single_byte(key, value)?,
+                "quote" => settings.quote = Self::single_byte(key, value)?,
+                "skip_header" => {
+                    settings.skip_header = match value.to_lowercase().as_str() {
+                        "0" | "false" => false,
+                        "1" | "true" => true,
+                        _ => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "Unsupported skip_header: {}, expect 0 or 1",
+                                value
+                            )))
+                        }
+                    }
+                }
+                "null" => settings.null_bytes = value.as_bytes().to_vec(),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Unknown file format option: {}",
+                        key
+                    )))
+                }
+            }
+        }
+
+        let format = options.get("type").cloned();
+        Ok((format.unwrap_or_else(|| "CSV".to_string()), settings))
+    }
+
+    fn single_byte(key: &str, value: &str) -> Result<u8> {
+        match value {
+            "\\t" => Ok(b'\t'),
+            _ if value.len() == 1 => Ok(value.as_bytes()[0]),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The {} must be a single character, but got: {}",
+                key, value
+            ))),
+        }
+    }
+
+    /// Parse the file in a blocking thread, the errors are tagged with the file path.
+    pub async fn read_file(
+        accessor: Arc<dyn DataAccessor>,
+        path: String,
+        options: &FileFormatOptions,
+        schema: DataSchemaRef,
+        block_size: usize,
+    ) -> Result<SendableDataBlockStream> {
+        let (format, settings) = Self::format_settings(options)?;
+        let input = accessor.get_input_stream(&path, None).await?;
+        let reader = BlockingReader {
+            handle: Handle::current(),
+            input: Mutex::new(input),
+        };
+        let mut source = SourceFactory::try_get(&format, reader, schema, settings, block_size)?;
+
+        let (sender, receiver) = channel(2);
+        tokio::task::spawn_blocking(move || loop {
+            let block = match source.read() {
+                Ok(Some(block)) => Ok(block),
+                Ok(None) => break,
+                Err(cause) => Err(cause.add_message_back(format!(" (while reading {})", path))),
+            };
+            let is_err = block.is_err();
+            if sender.blocking_send(block).is_err() || is_err {
+                break;
+            }
+        });
+        Ok(Box::pin(ReceiverStream::new(receiver)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for ReadFilesTransform {
+    fn name(&self) -> &str {
+        "ReadFilesTransform"
+    }
+
+    fn connect_to(&mut self, _: Arc<dyn Processor>) -> Result<()> {
+        Result::Err(ErrorCode::LogicalError(
+            "Cannot call ReadFilesTransform connect_to",
+        ))
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![Arc::new(EmptyProcessor::create())]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute, location:{} ...", self.plan.location);
+
+        let ctx = self.ctx.clone();
+        let (accessor, _) = ctx.get_location_accessor(&self.plan.location)?;
+        let options = self.plan.file_format.clone();
+        let schema = self.plan.schema.clone();
+        let block_size = ctx.get_settings().get_max_block_size()? as usize;
+
+        let (sender, receiver) = channel::<Result<DataBlock>>(2);
+        let worker_ctx = ctx.clone();
+        ctx.execute_task(async move {
+            loop {
+                let part = match worker_ctx.try_get_partitions(1) {
+                    Ok(mut parts) if !parts.is_empty() => parts.remove(0),
+                    Ok(_) => break,
+                    Err(cause) => {
+                        let _ = sender.send(Err(cause)).await;
+                        break;
+                    }
+                };
+
+                let path = part.name;
+                let stream =
+                    Self::read_file(accessor.clone(), path, &options, schema.clone(), block_size);
+                let mut stream = match stream.await {
+                    Ok(stream) => stream,
+                    Err(cause) => {
+                        let _ = sender.send(Err(cause)).await;
+                        break;
+                    }
+                };
+
+                while let Some(block) = stream.next().await {
+                    let is_err = block.is_err();
+                    if sender.send(block).await.is_err() || is_err {
+                        return;
+                    }
+                }
+            }
+        })?;
+
+        Ok(Box::pin(ctx.try_create_abortable(Box::pin(
+            ReceiverStream::new(receiver),
+        ))?))
+    }
+}
+
+/// Blocking `Read` over the input stream of a data accessor, only for the blocking
+/// threads of the runtime.
+struct BlockingReader {
+    handle: Handle,
+    input: Mutex<InputStream>,
+}
+
+impl io::Read for BlockingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut input = self.input.lock();
+        self.handle.block_on(input.read(buf))
+    }
+}
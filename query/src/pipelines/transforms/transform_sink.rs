@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::tokio::sync::mpsc::channel;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::InsertIntoPlan;
+use common_planners::SinkPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+
+use crate::datasources::table::append_data_with_views;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::Processor;
+use crate::sessions::DatabendQueryContextRef;
+
+/// Appends the input blocks to the table as they arrive, the output stream is empty.
+pub struct SinkTransform {
+    ctx: DatabendQueryContextRef,
+    plan: SinkPlan,
+    input: Arc<dyn Processor>,
+}
+
+impl SinkTransform {
+    pub fn create(ctx: DatabendQueryContextRef, plan: SinkPlan) -> Self {
+        SinkTransform {
+            ctx,
+            plan,
+            input: Arc::new(EmptyProcessor::create()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for SinkTransform {
+    fn name(&self) -> &str {
+        "SinkTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        tracing::debug!("execute...");
+
+        let table = self
+            .ctx
+            .get_table(&self.plan.db_name, &self.plan.tbl_name)?;
+
+        // The table consumes a Sync stream of blocks, forward the input through a channel.
+        // An error of the input is forwarded too, the table aborts the insert with it.
+        let mut input_stream = self.input.execute().await?;
+        let (sender, receiver) = channel(2);
+        self.ctx.execute_task(async move {
+            while let Some(block) = input_stream.next().await {
+                let failed = block.is_err();
+                if sender.send(block).await.is_err() || failed {
+                    break;
+                }
+            }
+        })?;
+
+        let insert_plan = InsertIntoPlan {
+            db_name: self.plan.db_name.clone(),
+            tbl_name: self.plan.tbl_name.clone(),
+            tbl_id: self.plan.tbl_id,
+            schema: self.plan.schema(),
+            select_plan: None,
+            format: None,
+            copied_files: self.plan.copied_files.clone(),
+            input_stream: Arc::new(Mutex::new(Some(Box::pin(ReceiverStream::new(receiver))))),
+        };
+        append_data_with_views(self.ctx.clone(), table, insert_plan).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
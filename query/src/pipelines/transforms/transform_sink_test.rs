@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_sorted_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::*;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::interpreters::*;
+use crate::pipelines::processors::*;
+use crate::pipelines::transforms::SinkTransform;
+use crate::sessions::DatabendQueryContextRef;
+use crate::sql::*;
+
+async fn execute_sql(ctx: &DatabendQueryContextRef, sql: &str) -> Result<Vec<DataBlock>> {
+    let plan = PlanParser::create(ctx.clone()).build_from_sql(sql)?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = executor.execute().await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+/// Returns a block, then fails.
+struct FailingSource {
+    schema: DataSchemaRef,
+}
+
+#[async_trait::async_trait]
+impl Processor for FailingSource {
+    fn name(&self) -> &str {
+        "FailingSource"
+    }
+
+    fn connect_to(&mut self, _input: Arc<dyn Processor>) -> Result<()> {
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let block =
+            DataBlock::create_by_array(self.schema.clone(), vec![Series::new(vec![3u64, 4])]);
+        Ok(Box::pin(futures::stream::iter(vec![
+            Ok(block),
+            Err(ErrorCode::BadBytes("source failed")),
+        ])))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_sink_transform_input_error() -> Result<()> {
+    let ctx = crate::tests::try_create_context()?;
+    execute_sql(&ctx, "create table default.t(a UInt64) Engine = Fuse").await?;
+    execute_sql(&ctx, "insert into default.t values(1), (2)").await?;
+
+    let table = ctx.get_table("default", "t")?;
+    let schema = table.raw().schema()?;
+    let plan = SinkPlan {
+        db_name: "default".to_string(),
+        tbl_name: "t".to_string(),
+        tbl_id: table.meta_id(),
+        schema: schema.clone(),
+        input: Arc::new(PlanNode::Empty(EmptyPlan::create_with_schema(
+            schema.clone(),
+        ))),
+        copied_files: None,
+    };
+    let mut sink = SinkTransform::create(ctx.clone(), plan);
+    sink.connect_to(Arc::new(FailingSource { schema }))?;
+
+    let result = sink.execute().await;
+    assert_eq!(
+        result.err().unwrap().to_string(),
+        "Code: 46, displayText = source failed."
+    );
+
+    // the block read before the error is not committed
+    let result = execute_sql(&ctx, "select * from default.t").await?;
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
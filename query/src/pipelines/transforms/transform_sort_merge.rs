@@ -29,12 +29,14 @@ use futures::StreamExt;
 use crate::pipelines::processors::EmptyProcessor;
 use crate::pipelines::processors::Processor;
 use crate::pipelines::transforms::transform_sort_partial::get_sort_descriptions;
+use crate::sessions::MemoryTracker;
 
 pub struct SortMergeTransform {
     schema: DataSchemaRef,
     exprs: Vec<Expression>,
     limit: Option<usize>,
     input: Arc<dyn Processor>,
+    memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
 impl SortMergeTransform {
@@ -48,8 +50,39 @@ impl SortMergeTransform {
             exprs,
             limit,
             input: Arc::new(EmptyProcessor::create()),
+            memory_tracker: None,
         })
     }
+
+    /// Charge the blocks buffered before the merge to the memory tracker of the query.
+    pub fn with_memory_tracker(mut self, memory_tracker: Arc<MemoryTracker>) -> Self {
+        self.memory_tracker = Some(memory_tracker);
+        self
+    }
+
+    async fn merge_blocks(&self, buffered: &mut usize) -> Result<Vec<DataBlock>> {
+        let sort_columns_descriptions = get_sort_descriptions(&self.schema, &self.exprs)?;
+        let mut blocks = vec![];
+        let mut stream = self.input.execute().await?;
+
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            if let Some(tracker) = &self.memory_tracker {
+                tracker.alloc(block.memory_size())?;
+                *buffered += block.memory_size();
+            }
+            blocks.push(block);
+        }
+
+        match blocks.len() {
+            0 => Ok(vec![]),
+            _ => Ok(vec![DataBlock::merge_sort_blocks(
+                &blocks,
+                &sort_columns_descriptions,
+                self.limit,
+            )?]),
+        }
+    }
 }
 
 #[async_trait]
@@ -74,22 +107,13 @@ impl Processor for SortMergeTransform {
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         tracing::debug!("execute...");
 
-        let sort_columns_descriptions = get_sort_descriptions(&self.schema, &self.exprs)?;
-        let mut blocks = vec![];
-        let mut stream = self.input.execute().await?;
-
-        while let Some(block) = stream.next().await {
-            blocks.push(block?);
+        // The buffered bytes are charged until the blocks are merged.
+        let mut buffered = 0;
+        let results = self.merge_blocks(&mut buffered).await;
+        if let Some(tracker) = &self.memory_tracker {
+            tracker.free(buffered);
         }
-
-        let results = match blocks.len() {
-            0 => vec![],
-            _ => vec![DataBlock::merge_sort_blocks(
-                &blocks,
-                &sort_columns_descriptions,
-                self.limit,
-            )?],
-        };
+        let results = results?;
 
         Ok(Box::pin(CorrectWithSchemaStream::new(
             Box::pin(DataBlockStream::create(self.schema.clone(), None, results)),
@@ -26,10 +26,12 @@ use futures::future::AbortRegistration;
 use futures::stream::Abortable;
 use futures::Future;
 use futures::StreamExt;
+use tokio_rustls::TlsAcceptor;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::servers::clickhouse::clickhouse_session::ClickHouseConnection;
 use crate::servers::clickhouse::reject_connection::RejectCHConnection;
+use crate::servers::handler_tls_config;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
 use crate::sessions::SessionManager;
@@ -37,6 +39,7 @@ use crate::sessions::SessionManagerRef;
 
 pub struct ClickHouseHandler {
     sessions: SessionManagerRef,
+    secure: bool,
 
     abort_handle: AbortHandle,
     abort_registration: Option<AbortRegistration>,
@@ -45,9 +48,19 @@ pub struct ClickHouseHandler {
 
 impl ClickHouseHandler {
     pub fn create(sessions: SessionManagerRef) -> Box<dyn Server> {
+        Self::create_impl(sessions, false)
+    }
+
+    /// The handler of the secure native port, every connection starts with a TLS handshake.
+    pub fn create_secure(sessions: SessionManagerRef) -> Box<dyn Server> {
+        Self::create_impl(sessions, true)
+    }
+
+    fn create_impl(sessions: SessionManagerRef, secure: bool) -> Box<dyn Server> {
         let (abort_handle, registration) = AbortHandle::new_pair();
         Box::new(ClickHouseHandler {
             sessions,
+            secure,
             abort_handle,
             abort_registration: Some(registration),
             join_handle: None,
@@ -67,15 +80,21 @@ impl ClickHouseHandler {
         Ok((TcpListenerStream::new(listener), listener_addr))
     }
 
-    fn listen_loop(&self, stream: ListeningStream, r: Arc<Runtime>) -> impl Future<Output = ()> {
+    fn listen_loop(
+        &self,
+        stream: ListeningStream,
+        r: Arc<Runtime>,
+        tls: Option<TlsAcceptor>,
+    ) -> impl Future<Output = ()> {
         let sessions = self.sessions.clone();
         stream.for_each(move |accept_socket| {
             let executor = r.clone();
             let sessions = sessions.clone();
+            let tls = tls.clone();
             async move {
                 match accept_socket {
                     Err(error) => log::error!("Broken session connection: {}", error),
-                    Ok(socket) => ClickHouseHandler::accept_socket(sessions, executor, socket),
+                    Ok(socket) => ClickHouseHandler::accept_socket(sessions, executor, socket, tls),
                 };
             }
         })
@@ -92,12 +111,17 @@ impl ClickHouseHandler {
         });
     }
 
-    fn accept_socket(sessions: Arc<SessionManager>, executor: Arc<Runtime>, socket: TcpStream) {
+    fn accept_socket(
+        sessions: Arc<SessionManager>,
+        executor: Arc<Runtime>,
+        socket: TcpStream,
+        tls: Option<TlsAcceptor>,
+    ) {
         match sessions.create_session("ClickHouseSession") {
             Err(error) => Self::reject_connection(socket, executor, error),
             Ok(session) => {
                 log::info!("ClickHouse connection coming: {:?}", socket.peer_addr());
-                if let Err(error) = ClickHouseConnection::run_on_stream(session, socket) {
+                if let Err(error) = ClickHouseConnection::run_on_stream(session, socket, tls) {
                     log::error!("Unexpected error occurred during query: {:?}", error);
                 }
             }
@@ -126,10 +150,22 @@ impl Server for ClickHouseHandler {
                 "ClickHouseHandler already running.",
             )),
             Some(registration) => {
+                let tls = match self.secure {
+                    false => None,
+                    true => match handler_tls_config(self.sessions.get_conf())? {
+                        Some(tls_config) => Some(TlsAcceptor::from(tls_config)),
+                        None => {
+                            return Err(ErrorCode::TLSConfigurationFailure(
+                                "ClickHouse secure handler requires handler_tls_server_cert and handler_tls_server_key",
+                            ))
+                        }
+                    },
+                };
+
                 let rejected_rt = Arc::new(Runtime::with_worker_threads(1)?);
                 let (stream, listener) = Self::listener_tcp(listening).await?;
                 let stream = Abortable::new(stream, registration);
-                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt)));
+                self.join_handle = Some(tokio::spawn(self.listen_loop(stream, rejected_rt, tls)));
                 Ok(listener)
             }
         }
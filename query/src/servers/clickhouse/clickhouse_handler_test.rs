@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs::File;
+use std::io::BufReader;
 use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use clickhouse_rs::types::Complex;
@@ -20,10 +23,22 @@ use clickhouse_rs::Block;
 use clickhouse_rs::ClientHandle;
 use clickhouse_rs::Pool;
 use common_base::tokio;
+use common_clickhouse_srv::protocols::QuerySetting;
+use common_clickhouse_srv::CHContext;
+use common_clickhouse_srv::QueryState;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_tracing::tracing::Level;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::webpki::DNSNameRef;
+use tokio_rustls::TlsConnector;
 
+use crate::servers::clickhouse::interactive_worker_base::InteractiveWorkerBase;
 use crate::servers::ClickHouseHandler;
+use crate::tests::tls_constants::TEST_CA_CERT;
+use crate::tests::tls_constants::TEST_CN_NAME;
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
 use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -43,6 +58,85 @@ async fn test_clickhouse_handler_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_clickhouse_secure_handler() -> Result<()> {
+    let mut handler = ClickHouseHandler::create_secure(
+        SessionManagerBuilder::create()
+            .max_sessions(1)
+            .handler_tls_server_key(TEST_SERVER_KEY)
+            .handler_tls_server_cert(TEST_SERVER_CERT)
+            .build()?,
+    );
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    let mut client_config = ClientConfig::new();
+    let mut ca_cert = BufReader::new(File::open(TEST_CA_CERT)?);
+    assert!(client_config.root_store.add_pem_file(&mut ca_cert).is_ok());
+
+    let connector = TlsConnector::from(Arc::new(client_config));
+    let stream = tokio::net::TcpStream::connect(("127.0.0.1", listening.port())).await?;
+    let domain = DNSNameRef::try_from_ascii_str(TEST_CN_NAME).unwrap();
+    let tls_stream = connector.connect(domain, stream).await;
+    assert!(tls_stream.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_clickhouse_secure_handler_without_tls() -> Result<()> {
+    let mut handler =
+        ClickHouseHandler::create_secure(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    match handler.start(listening).await {
+        Ok(_) => panic!("Secure handler must not start without TLS config"),
+        Err(error) => assert_eq!(error.code(), 52),
+    };
+
+    Ok(())
+}
+
+#[test]
+fn test_clickhouse_client_settings() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session("ClickHouseSession")?;
+
+    let setting = |name: &str, value: &str, important: bool| QuerySetting {
+        name: name.to_string(),
+        value: value.to_string(),
+        important,
+    };
+
+    // Known settings are applied, unknown ones are ignored unless they are important
+    let mut ch_ctx = CHContext::new(QueryState::default());
+    ch_ctx.state.settings = vec![
+        setting("max_threads", "4", false),
+        setting("max_block_size", "1000", false),
+        setting("send_logs_level", "debug", false),
+        setting("output_format_pretty_color", "0", false),
+    ];
+    let send_logs_level = InteractiveWorkerBase::apply_settings(&ch_ctx, &session)?;
+    assert_eq!(send_logs_level, Some(Level::DEBUG));
+    assert_eq!(session.get_settings().get_max_threads()?, 4);
+    assert_eq!(session.get_settings().get_max_block_size()?, 1000);
+
+    ch_ctx.state.settings = vec![setting("output_format_pretty_color", "0", true)];
+    match InteractiveWorkerBase::apply_settings(&ch_ctx, &session) {
+        Ok(_) => panic!("Unknown important setting must be rejected"),
+        Err(error) => assert_eq!(error.code(), 20),
+    };
+
+    ch_ctx.state.settings = vec![setting("send_logs_level", "verbose", false)];
+    match InteractiveWorkerBase::apply_settings(&ch_ctx, &session) {
+        Ok(_) => panic!("Unknown send_logs_level must be rejected"),
+        Err(error) => assert_eq!(error.message(), "Unknown send_logs_level: \"verbose\""),
+    };
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_clickhouse_insert_data() -> Result<()> {
     let mut handler =
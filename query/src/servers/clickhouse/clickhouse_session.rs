@@ -20,6 +20,7 @@ use common_clickhouse_srv::ClickHouseServer;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
+use tokio_rustls::TlsAcceptor;
 
 use crate::servers::clickhouse::interactive_worker::InteractiveWorker;
 use crate::sessions::SessionRef;
@@ -27,7 +28,11 @@ use crate::sessions::SessionRef;
 pub struct ClickHouseConnection;
 
 impl ClickHouseConnection {
-    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+    pub fn run_on_stream(
+        session: SessionRef,
+        stream: TcpStream,
+        tls: Option<TlsAcceptor>,
+    ) -> Result<()> {
         let blocking_stream = Self::convert_stream(stream)?;
         ClickHouseConnection::attach_session(&session, &blocking_stream)?;
         let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
@@ -36,7 +41,21 @@ impl ClickHouseConnection {
         std::thread::spawn(move || {
             let join_handle = query_executor.spawn(async move {
                 let interactive_worker = InteractiveWorker::create(session);
-                ClickHouseServer::run_on_stream(interactive_worker, non_blocking_stream).await
+                match tls {
+                    None => {
+                        ClickHouseServer::run_on_stream(interactive_worker, non_blocking_stream)
+                            .await
+                    }
+                    Some(acceptor) => match acceptor.accept(non_blocking_stream).await {
+                        Ok(tls_stream) => {
+                            ClickHouseServer::run_on_stream(interactive_worker, tls_stream).await
+                        }
+                        Err(cause) => {
+                            log::error!("ClickHouse TLS handshake failed: {}", cause);
+                            Ok(())
+                        }
+                    },
+                }
             });
 
             let _ = futures::executor::block_on(join_handle);
@@ -18,6 +18,10 @@ use std::time::Instant;
 use common_clickhouse_srv::connection::Connection;
 use common_clickhouse_srv::CHContext;
 use common_clickhouse_srv::ClickHouseSession;
+use common_datavalues::Tz;
+use common_tracing::tracing;
+use common_tracing::tracing::Instrument;
+use common_tracing::QueryLogs;
 use metrics::histogram;
 
 use crate::servers::clickhouse::interactive_worker_base::InteractiveWorkerBase;
@@ -45,10 +49,26 @@ impl ClickHouseSession for InteractiveWorker {
     ) -> common_clickhouse_srv::errors::Result<()> {
         let start = Instant::now();
 
-        let mut query_writer = QueryWriter::create(ctx.client_revision, conn);
+        let timezone = self
+            .session
+            .get_settings()
+            .get_timezone()
+            .map_err(to_clickhouse_err)?;
+        let timezone = timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+        let send_logs_level =
+            InteractiveWorkerBase::apply_settings(ctx, &self.session).map_err(to_clickhouse_err)?;
+
+        let query_id = match ctx.state.query_id.is_empty() {
+            true => uuid::Uuid::new_v4().to_string(),
+            false => ctx.state.query_id.clone(),
+        };
+        let query_logs = send_logs_level.map(|level| QueryLogs::subscribe(&query_id, level));
+        let mut query_writer =
+            QueryWriter::create(ctx.client_revision, conn, timezone).with_logs(query_logs);
 
         let session = self.session.clone();
-        let get_query_result = InteractiveWorkerBase::do_query(ctx, session);
+        let span = tracing::info_span!("clickhouse_query", query_id = query_id.as_str());
+        let get_query_result = InteractiveWorkerBase::do_query(ctx, session).instrument(span);
         if let Err(cause) = query_writer.write(get_query_result.await).await {
             let new_error = cause.add_message(&ctx.state.query);
             return Err(to_clickhouse_err(new_error));
@@ -92,14 +112,18 @@ impl ClickHouseSession for InteractiveWorker {
     }
 
     // TODO: remove it
-    // the MIN_SERVER_REVISION for suggestions is 54406
+    // the settings are serialized as strings since 54429, the server logs are supported since 54406
     fn dbms_tcp_protocol_version(&self) -> u64 {
-        54405
+        54429
     }
 
     fn authenticate(&self, user: &str, password: &[u8]) -> bool {
         if let Ok(user) = get_mock_user(user) {
-            return user.authenticate_user(password);
+            let authenticated = user.authenticate_user(password);
+            if authenticated {
+                self.session.set_current_user(user.name.clone());
+            }
+            return authenticated;
         }
         false
     }
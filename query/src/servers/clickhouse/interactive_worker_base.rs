@@ -26,9 +26,11 @@ use common_clickhouse_srv::types::Block as ClickHouseBlock;
 use common_clickhouse_srv::CHContext;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::InsertIntoPlan;
 use common_planners::PlanNode;
+use common_tracing::tracing::Level;
 use futures::channel::mpsc;
 use futures::channel::mpsc::Receiver;
 use futures::SinkExt;
@@ -39,6 +41,7 @@ use tokio_stream::wrappers::ReceiverStream;
 
 use super::writers::from_clickhouse_block;
 use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
 use crate::sessions::DatabendQueryContextRef;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
@@ -63,10 +66,18 @@ impl InteractiveWorkerBase {
         let ctx = session.create_context().await?;
         ctx.attach_query_str(query);
 
-        let plan = PlanParser::create(ctx.clone()).build_from_sql(query)?;
+        let plan = match PlanParser::create(ctx.clone()).build_from_sql(query) {
+            Ok(plan) => plan,
+            Err(cause) => {
+                InterpreterQueryLog::log_exception_before_start(ctx.clone(), &cause);
+                return Err(cause);
+            }
+        };
 
         match plan {
-            PlanNode::InsertInto(insert) => Self::process_insert_query(insert, ch_ctx, ctx).await,
+            PlanNode::InsertInto(insert) if insert.select_plan.is_none() => {
+                Self::process_insert_query(insert, ch_ctx, ctx).await
+            }
             _ => {
                 let start = Instant::now();
                 let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
@@ -107,6 +118,47 @@ impl InteractiveWorkerBase {
         }
     }
 
+    /// Applies the settings sent by the client to the session settings and returns the level of
+    /// the logs to send back, if `send_logs_level` is set.
+    ///
+    /// Like ClickHouse, an unknown setting fails the query only if the client marks it important.
+    pub fn apply_settings(ch_ctx: &CHContext, session: &SessionRef) -> Result<Option<Level>> {
+        let settings = session.get_settings();
+        let mut send_logs_level = None;
+
+        for setting in &ch_ctx.state.settings {
+            match setting.name.as_str() {
+                "send_logs_level" => send_logs_level = Self::logs_level(&setting.value)?,
+                name => match settings.update_settings(name, setting.value.clone()) {
+                    Err(cause)
+                        if cause.code() == ErrorCode::UnknownVariable("").code()
+                            && !setting.important =>
+                    {
+                        log::debug!("Ignore unknown ClickHouse setting: {}", name);
+                    }
+                    res => res?,
+                },
+            }
+        }
+
+        Ok(send_logs_level)
+    }
+
+    fn logs_level(value: &str) -> Result<Option<Level>> {
+        match value.to_lowercase().as_str() {
+            "" | "none" => Ok(None),
+            "fatal" | "error" => Ok(Some(Level::ERROR)),
+            "warning" => Ok(Some(Level::WARN)),
+            "information" => Ok(Some(Level::INFO)),
+            "debug" => Ok(Some(Level::DEBUG)),
+            "trace" => Ok(Some(Level::TRACE)),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown send_logs_level: {:?}",
+                value
+            ))),
+        }
+    }
+
     pub async fn process_insert_query(
         insert: InsertIntoPlan,
         ch_ctx: &mut CHContext,
@@ -151,27 +203,15 @@ pub struct FromClickHouseBlockStream {
 }
 
 impl futures::stream::Stream for FromClickHouseBlockStream {
-    type Item = DataBlock;
+    type Item = Result<DataBlock>;
 
     fn poll_next(
         mut self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
-        self.input.poll_next_unpin(cx).map(|x| match x {
-            Some(v) => {
-                let block = from_clickhouse_block(self.schema.clone(), v);
-                match block {
-                    Ok(block) => Some(block),
-                    Err(e) => {
-                        log::error!(
-                            "failed to convert ClickHouseBlock to block , breaking out, {:?}",
-                            e
-                        );
-                        None
-                    }
-                }
-            }
-            _ => None,
-        })
+        // a block failed to convert aborts the insert
+        self.input
+            .poll_next_unpin(cx)
+            .map(|x| x.map(|v| from_clickhouse_block(self.schema.clone(), v)))
     }
 }
@@ -16,6 +16,7 @@ use std::borrow::Cow;
 
 use chrono::Date;
 use chrono::DateTime;
+use chrono::Utc;
 use chrono_tz::Tz;
 use common_base::ProgressValues;
 use common_clickhouse_srv::connection::Connection;
@@ -23,12 +24,16 @@ use common_clickhouse_srv::errors::Error as CHError;
 use common_clickhouse_srv::errors::Result as CHResult;
 use common_clickhouse_srv::errors::ServerError;
 use common_clickhouse_srv::types::Block;
+use common_clickhouse_srv::types::DateTime64Values;
 use common_clickhouse_srv::types::DateTimeType;
 use common_clickhouse_srv::types::SqlType;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_tracing::tracing::Level;
+use common_tracing::QueryLogEvent;
+use common_tracing::QueryLogs;
 use futures::channel::mpsc::Receiver;
 use futures::StreamExt;
 
@@ -37,26 +42,58 @@ use crate::servers::clickhouse::interactive_worker_base::BlockItem;
 pub struct QueryWriter<'a> {
     client_version: u64,
     conn: &'a mut Connection,
+    // Session timezone, used for DateTime columns without their own timezone.
+    timezone: Tz,
+    // Subscribed if the client sets send_logs_level.
+    logs: Option<QueryLogs>,
 }
 
 impl<'a> QueryWriter<'a> {
-    pub fn create(version: u64, conn: &'a mut Connection) -> QueryWriter {
+    pub fn create(version: u64, conn: &'a mut Connection, timezone: Tz) -> QueryWriter {
         QueryWriter {
             conn,
             client_version: version,
+            timezone,
+            logs: None,
         }
     }
 
+    pub fn with_logs(mut self, logs: Option<QueryLogs>) -> Self {
+        self.logs = logs;
+        self
+    }
+
     pub async fn write(&mut self, receiver: Result<Receiver<BlockItem>>) -> Result<()> {
         match receiver {
             Err(error) => self.write_error(error).await,
             Ok(receiver) => {
                 let write_data = self.write_data(receiver);
-                write_data.await
+                write_data.await?;
+                self.write_logs().await
             }
         }
     }
 
+    async fn write_logs(&mut self) -> Result<()> {
+        let (query_id, events) = match &self.logs {
+            None => return Ok(()),
+            Some(logs) => (logs.query_id().to_string(), logs.take()),
+        };
+
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let block = to_clickhouse_log_block(&query_id, events);
+        match self.conn.write_log_block(&block).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(ErrorCode::UnknownException(format!(
+                "Cannot send logs {:?}",
+                error
+            ))),
+        }
+    }
+
     async fn write_progress(&mut self, values: ProgressValues) -> Result<()> {
         let progress = common_clickhouse_srv::types::Progress {
             rows: values.read_rows as u64,
@@ -64,6 +101,7 @@ impl<'a> QueryWriter<'a> {
             total_rows: 0,
         };
 
+        self.write_logs().await?;
         let version = self.client_version;
         match self.conn.write_progress(progress, version).await {
             Ok(_) => Ok(()),
@@ -76,6 +114,8 @@ impl<'a> QueryWriter<'a> {
 
     async fn write_error(&mut self, error: ErrorCode) -> Result<()> {
         log::error!("OnQuery Error: {:?}", error);
+        // The client finishes the query on the exception, the logs must go first.
+        self.write_logs().await?;
         let clickhouse_err = to_clickhouse_err(error);
         match self.conn.write_error(&clickhouse_err).await {
             Ok(_) => Ok(()),
@@ -87,7 +127,7 @@ impl<'a> QueryWriter<'a> {
     }
 
     async fn write_block(&mut self, block: DataBlock) -> Result<()> {
-        let block = to_clickhouse_block(block)?;
+        let block = to_clickhouse_block(block, &self.timezone)?;
 
         match self.conn.write_block(&block).await {
             Ok(_) => Ok(()),
@@ -152,7 +192,44 @@ pub fn from_clickhouse_err(res: common_clickhouse_srv::errors::Error) -> ErrorCo
     ErrorCode::LogicalError(format!("clickhouse-srv expception: {:?}", res))
 }
 
-pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
+// The columns of the server logs block, same as the ClickHouse server.
+fn to_clickhouse_log_block(query_id: &str, events: Vec<QueryLogEvent>) -> Block {
+    let mut event_times = Vec::with_capacity(events.len());
+    let mut event_microseconds = Vec::with_capacity(events.len());
+    let mut thread_ids = Vec::with_capacity(events.len());
+    let mut priorities = Vec::with_capacity(events.len());
+    let mut sources = Vec::with_capacity(events.len());
+    let mut texts = Vec::with_capacity(events.len());
+
+    for event in events {
+        let event_time = DateTime::<Utc>::from(event.time);
+        event_microseconds.push(event_time.timestamp_subsec_micros());
+        event_times.push(event_time.with_timezone(&Tz::UTC));
+        thread_ids.push(event.thread_id);
+        priorities.push(match event.level {
+            Level::ERROR => 3i8,
+            Level::WARN => 4,
+            Level::INFO => 6,
+            Level::DEBUG => 7,
+            _ => 8,
+        });
+        sources.push(event.target);
+        texts.push(event.message);
+    }
+
+    let rows = texts.len();
+    Block::new()
+        .column("event_time", event_times)
+        .column("event_time_microseconds", event_microseconds)
+        .column("host_name", vec![String::new(); rows])
+        .column("query_id", vec![query_id.to_string(); rows])
+        .column("thread_id", thread_ids)
+        .column("priority", priorities)
+        .column("source", sources)
+        .column("text", texts)
+}
+
+pub fn to_clickhouse_block(block: DataBlock, session_tz: &Tz) -> Result<Block> {
     let mut result = Block::new();
     if block.num_columns() == 0 {
         return Ok(result);
@@ -191,9 +268,12 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                     result.column(name, c)
                 }
                 DataType::DateTime32(tz) => {
-                    let tz = tz.clone();
-                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                    let tz: Tz = tz.parse().unwrap();
+                    let tz = match tz {
+                        Some(tz) => tz.parse::<Tz>().map_err(|_| {
+                            ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))
+                        })?,
+                        None => *session_tz,
+                    };
 
                     let c: Vec<Option<DateTime<Tz>>> = column
                         .u32()?
@@ -203,6 +283,20 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
 
                     result.column(name, c)
                 }
+                DataType::DateTime64(precision, tz) => {
+                    let (scale, tz) = datetime64_column_params(*precision, tz, session_tz)?;
+                    let values: Vec<Option<i64>> = column
+                        .i64()?
+                        .into_iter()
+                        .map(|x| x.map(|v| *v / scale))
+                        .collect();
+
+                    result.column(name, DateTime64Values {
+                        values,
+                        precision: *precision,
+                        tz,
+                    })
+                }
                 DataType::UInt64 => result.column(name, column.u64()?.collect_values()),
                 DataType::Float32 => result.column(name, column.f32()?.collect_values()),
                 DataType::Float64 => result.column(name, column.f64()?.collect_values()),
@@ -266,9 +360,12 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                 }
 
                 DataType::DateTime32(tz) => {
-                    let tz = tz.clone();
-                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                    let tz: Tz = tz.parse().unwrap();
+                    let tz = match tz {
+                        Some(tz) => tz.parse::<Tz>().map_err(|_| {
+                            ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))
+                        })?,
+                        None => *session_tz,
+                    };
 
                     let c: Vec<DateTime<Tz>> = column
                         .u32()?
@@ -279,6 +376,21 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
                     result.column(name, c)
                 }
 
+                DataType::DateTime64(precision, tz) => {
+                    let (scale, tz) = datetime64_column_params(*precision, tz, session_tz)?;
+                    let values: Vec<i64> = column
+                        .i64()?
+                        .into_no_null_iter()
+                        .map(|v| *v / scale)
+                        .collect();
+
+                    result.column(name, DateTime64Values {
+                        values,
+                        precision: *precision,
+                        tz,
+                    })
+                }
+
                 DataType::UInt64 => {
                     result.column(name, column.u64()?.inner().values().as_slice().to_vec())
                 }
@@ -315,9 +427,36 @@ pub fn to_clickhouse_block(block: DataBlock) -> Result<Block> {
     Ok(result)
 }
 
+/// ClickHouse counts DateTime64 in 10^-precision seconds, returns the divisor
+/// from our ticks and the timezone of the column.
+fn datetime64_column_params(
+    precision: u32,
+    tz: &Option<String>,
+    session_tz: &Tz,
+) -> Result<(i64, Tz)> {
+    let tz = match tz {
+        Some(tz) => tz
+            .parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz)))?,
+        None => *session_tz,
+    };
+    let scale = datetime64_ticks_per_second(precision) / 10_i64.pow(precision.min(9));
+    Ok((scale, tz))
+}
+
+fn datetime64_ticks(date_time: &DateTime<Tz>, data_type: &DataType) -> i64 {
+    let ticks = match data_type {
+        DataType::DateTime64(precision, _) => datetime64_ticks_per_second(*precision),
+        _ => 1,
+    };
+    date_time.timestamp() * ticks
+        + date_time.timestamp_subsec_nanos() as i64 / (1_000_000_000 / ticks)
+}
+
 pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<DataBlock> {
     let get_series = |block: &Block, index: usize| -> CHResult<Series> {
         let col = &block.columns()[index];
+        let data_type = schema.field(index).data_type();
         match col.sql_type() {
             SqlType::UInt8 => {
                 Ok(DFUInt8Array::new_from_iter(col.iter::<u8>()?.copied()).into_series())
@@ -331,6 +470,11 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
             SqlType::UInt64 => {
                 Ok(DFUInt64Array::new_from_iter(col.iter::<u64>()?.copied()).into_series())
             }
+            SqlType::DateTime(DateTimeType::DateTime64(_, _)) => Ok(DFInt64Array::new_from_iter(
+                col.iter::<DateTime<Tz>>()?
+                    .map(|v| datetime64_ticks(&v, data_type)),
+            )
+            .into_series()),
             SqlType::Int8 => {
                 Ok(DFInt8Array::new_from_iter(col.iter::<i8>()?.copied()).into_series())
             }
@@ -367,6 +511,13 @@ pub fn from_clickhouse_block(schema: DataSchemaRef, block: Block) -> Result<Data
                 DFUInt32Array::new_from_opt_iter(col.iter::<Option<u32>>()?.map(|c| c.copied()))
                     .into_series(),
             ),
+            SqlType::Nullable(SqlType::DateTime(DateTimeType::DateTime64(_, _))) => {
+                Ok(DFInt64Array::new_from_opt_iter(
+                    col.iter::<Option<DateTime<Tz>>>()?
+                        .map(|v| v.map(|v| datetime64_ticks(&v, data_type))),
+                )
+                .into_series())
+            }
             SqlType::Nullable(SqlType::UInt64) => Ok(DFUInt64Array::new_from_opt_iter(
                 col.iter::<Option<u64>>()?.map(|c| c.copied()),
             )
@@ -17,6 +17,7 @@
 pub use clickhouse::ClickHouseHandler;
 pub use server::Server;
 pub use server::ShutdownHandle;
+pub use server_tls::handler_tls_config;
 
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLHandler;
@@ -24,3 +25,4 @@ pub use self::mysql::MySQLHandler;
 mod clickhouse;
 mod mysql;
 pub(crate) mod server;
+mod server_tls;
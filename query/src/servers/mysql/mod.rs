@@ -22,5 +22,6 @@ mod mysql_handler;
 mod mysql_interactive_worker;
 mod mysql_metrics;
 mod mysql_session;
+mod mysql_tls_stream;
 mod reject_connection;
 mod writers;
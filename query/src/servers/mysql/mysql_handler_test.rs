@@ -29,6 +29,12 @@ use mysql::FromRowError;
 use mysql::Row;
 
 use crate::servers::MySQLHandler;
+use crate::tests::tls_constants::TEST_CA_CERT;
+use crate::tests::tls_constants::TEST_SERVER_CERT;
+use crate::tests::tls_constants::TEST_SERVER_KEY;
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 use crate::tests::SessionManagerBuilder;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -48,6 +54,22 @@ async fn test_use_database_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_with_many_blocks() -> Result<()> {
+    let mut handler =
+        MySQLHandler::create(SessionManagerBuilder::create().max_sessions(1).build()?);
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port())?;
+    query::<EmptyRow>(&mut connection, "SET max_block_size = 100")?;
+    let received_data: Vec<u64> = query(&mut connection, "SELECT number FROM numbers(1000)")?;
+    assert_eq!(received_data.len(), 1000);
+    assert_eq!(received_data.iter().sum::<u64>(), 499500);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_rejected_session_with_sequence() -> Result<()> {
     let mut handler =
@@ -146,6 +168,54 @@ async fn test_rejected_session_with_parallel() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_with_tls() -> Result<()> {
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(2)
+            .handler_tls_server_key(TEST_SERVER_KEY)
+            .handler_tls_server_cert(TEST_SERVER_CERT)
+            .build()?,
+    );
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // Upgraded by SSLRequest
+    let mut connection = create_tls_connection(listening.port())?;
+    let received_data: Vec<u64> = query(&mut connection, "SELECT number FROM numbers(10)")?;
+    assert_eq!(received_data.iter().sum::<u64>(), 45);
+
+    // Plaintext is still allowed without client certificate verification
+    let mut connection = create_connection(listening.port())?;
+    let received_data: Vec<String> = query(&mut connection, "SELECT database()")?;
+    assert_eq!(received_data, vec!["default"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rejected_plaintext_with_client_ca() -> Result<()> {
+    let mut handler = MySQLHandler::create(
+        SessionManagerBuilder::create()
+            .max_sessions(1)
+            .handler_tls_server_key(TEST_TLS_SERVER_KEY)
+            .handler_tls_server_cert(TEST_TLS_SERVER_CERT)
+            .handler_tls_server_root_ca_cert(TEST_TLS_CA_CERT)
+            .build()?,
+    );
+
+    let listening = "0.0.0.0:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    match create_connection(listening.port()) {
+        Ok(_) => panic!("Plaintext connection must be rejected"),
+        Err(error) => assert_eq!(error.message(), "Reject connection"),
+    };
+
+    Ok(())
+}
+
 fn query<T: FromRow>(connection: &mut Conn, query: &str) -> Result<Vec<T>> {
     connection
         .query::<T, &str>(query)
@@ -158,6 +228,17 @@ fn create_connection(port: u16) -> Result<mysql::Conn> {
     mysql::Conn::new(opts).map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
 }
 
+fn create_tls_connection(port: u16) -> Result<mysql::Conn> {
+    let ssl_opts =
+        mysql::SslOpts::default().with_root_cert_path(Some(std::path::PathBuf::from(TEST_CA_CERT)));
+    let opts = mysql::OptsBuilder::new()
+        .ip_or_hostname(Some("localhost"))
+        .tcp_port(port)
+        .user(Some("default"))
+        .ssl_opts(Some(ssl_opts));
+    mysql::Conn::new(opts).map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
+}
+
 struct EmptyRow;
 
 impl FromRow for EmptyRow {
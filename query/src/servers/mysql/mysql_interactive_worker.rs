@@ -13,14 +13,19 @@
 // limitations under the License.
 
 use std::marker::PhantomData;
+use std::sync::Arc;
 use std::time::Instant;
 
 use common_base::tokio;
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::*;
 use common_planners::PlanNode;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
 use metrics::histogram;
 use msql_srv::ErrorKind;
 use msql_srv::InitWriter;
@@ -32,6 +37,7 @@ use rand::RngCore;
 use tokio_stream::StreamExt;
 
 use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::server::mock::get_mock_user;
@@ -107,7 +113,12 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
                 }
                 _ => auth_data.to_vec(),
             };
-            return user.authenticate_user(encode_password);
+
+            let authenticated = user.authenticate_user(encode_password);
+            if authenticated {
+                self.session.set_current_user(user.name.clone());
+            }
+            return authenticated;
         }
 
         false
@@ -164,14 +175,23 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
             ));
         }
 
-        let mut writer = DFQueryResultWriter::create(writer);
+        let timezone = self.session.get_settings().get_timezone()?;
+        let timezone = timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+        let mut writer = DFQueryResultWriter::create(writer, timezone);
 
         match InteractiveWorkerBase::<W>::build_runtime() {
             Ok(runtime) => {
                 let instant = Instant::now();
-                let blocks = runtime.block_on(self.base.do_query(query));
+                let query_result = runtime.block_on(self.base.do_query(query));
 
-                let mut write_result = writer.write(blocks);
+                // The blocks are pulled as the rows are written, a client reading slowly
+                // holds the query back instead of the result being buffered.
+                let mut write_result = writer.write(query_result.map(|(mut stream, context)| {
+                    let blocks = std::iter::from_fn(move || runtime.block_on(stream.next()));
+                    let extra_info =
+                        move || InteractiveWorkerBase::<W>::extra_info(&context, instant);
+                    (blocks, extra_info)
+                }));
 
                 if let Err(cause) = write_result {
                     let suffix = format!("(while in query {})", query);
@@ -185,7 +205,7 @@ impl<W: std::io::Write> MysqlShim<W> for InteractiveWorker<W> {
 
                 write_result
             }
-            Err(error) => writer.write(Err(error)),
+            Err(error) => writer.write_error(error),
         }
     }
 
@@ -229,7 +249,10 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
 
     fn do_close(&mut self, _: u32) {}
 
-    async fn do_query(&mut self, query: &str) -> Result<(Vec<DataBlock>, String)> {
+    async fn do_query(
+        &mut self,
+        query: &str,
+    ) -> Result<(SendableDataBlockStream, DatabendQueryContextRef)> {
         log::debug!("{}", query);
 
         let context = self.session.create_context().await?;
@@ -243,15 +266,22 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
             .find(|v| v.error_code.is_some())
             .and_then(|x| x.error_code)
         {
-            None => Self::exec_query(plan, &context).await,
-            Some(hint_error_code) => match Self::exec_query(plan, &context).await {
+            None => Self::exec_query(plan, &context)
+                .await
+                .map(|stream| (stream, context)),
+            // The query is run to the end to check the error it is expected to fail with.
+            Some(hint_error_code) => match Self::collect_query(plan, &context).await {
                 Ok(_) => Err(ErrorCode::UnexpectedError(format!(
                     "Expected server error code: {} but got: Ok.",
                     hint_error_code
                 ))),
                 Err(error_code) => {
                     if hint_error_code == error_code.code() {
-                        Ok((vec![DataBlock::empty()], String::from("")))
+                        let stream =
+                            DataBlockStream::create(Arc::new(DataSchema::empty()), None, vec![
+                                DataBlock::empty(),
+                            ]);
+                        Ok((Box::pin(stream), context))
                     } else {
                         let actual_code = error_code.code();
                         Err(error_code.add_message(format!(
@@ -267,19 +297,31 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     async fn exec_query(
         plan: Result<PlanNode>,
         context: &DatabendQueryContextRef,
-    ) -> Result<(Vec<DataBlock>, String)> {
+    ) -> Result<SendableDataBlockStream> {
         let instant = Instant::now();
 
-        let interpreter = InterpreterFactory::get(context.clone(), plan?)?;
+        let interpreter = match plan.and_then(|v| InterpreterFactory::get(context.clone(), v)) {
+            Ok(interpreter) => interpreter,
+            Err(cause) => {
+                InterpreterQueryLog::log_exception_before_start(context.clone(), &cause);
+                return Err(cause);
+            }
+        };
         let data_stream = interpreter.execute().await?;
         histogram!(
             super::mysql_metrics::METRIC_INTERPRETER_USEDTIME,
             instant.elapsed()
         );
 
-        let collector = data_stream.collect::<Result<Vec<DataBlock>>>();
-        let query_result = collector.await;
-        query_result.map(|data| (data, Self::extra_info(context, instant)))
+        Ok(data_stream)
+    }
+
+    async fn collect_query(
+        plan: Result<PlanNode>,
+        context: &DatabendQueryContextRef,
+    ) -> Result<Vec<DataBlock>> {
+        let data_stream = Self::exec_query(plan, context).await?;
+        data_stream.collect::<Result<Vec<DataBlock>>>().await
     }
 
     fn extra_info(context: &DatabendQueryContextRef, instant: Instant) -> String {
@@ -297,7 +339,10 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
 
     fn do_init(&mut self, database_name: &str) -> Result<()> {
         let init_query = format!("USE {};", database_name);
-        let do_query = self.do_query(&init_query);
+        let do_query = async move {
+            let (stream, _) = self.do_query(&init_query).await?;
+            stream.collect::<Result<Vec<DataBlock>>>().await
+        };
 
         match Self::build_runtime() {
             Err(error_code) => Err(error_code),
@@ -22,24 +22,45 @@ use common_exception::ToErrorCode;
 use msql_srv::MysqlIntermediary;
 
 use crate::servers::mysql::mysql_interactive_worker::InteractiveWorker;
+use crate::servers::mysql::mysql_tls_stream::MySQLTlsConfig;
+use crate::servers::mysql::mysql_tls_stream::MySQLTlsStream;
 use crate::sessions::SessionRef;
 
 pub struct MySQLConnection;
 
 impl MySQLConnection {
-    pub fn run_on_stream(session: SessionRef, stream: TcpStream) -> Result<()> {
+    pub fn run_on_stream(
+        session: SessionRef,
+        stream: TcpStream,
+        tls: Option<MySQLTlsConfig>,
+    ) -> Result<()> {
         let blocking_stream = Self::convert_stream(stream)?;
         MySQLConnection::attach_session(&session, &blocking_stream)?;
         std::thread::spawn(move || {
-            MySQLConnection::session_executor(session, blocking_stream);
+            MySQLConnection::session_executor(session, blocking_stream, tls);
         });
 
         Ok(())
     }
 
-    fn session_executor(session: SessionRef, blocking_stream: std::net::TcpStream) {
-        let interactive_worker = InteractiveWorker::create(session);
-        if let Err(error) = MysqlIntermediary::run_on_tcp(interactive_worker, blocking_stream) {
+    fn session_executor(
+        session: SessionRef,
+        blocking_stream: std::net::TcpStream,
+        tls: Option<MySQLTlsConfig>,
+    ) {
+        let result = match tls {
+            None => {
+                let interactive_worker = InteractiveWorker::create(session);
+                MysqlIntermediary::run_on_tcp(interactive_worker, blocking_stream)
+            }
+            Some(tls) => {
+                let interactive_worker = InteractiveWorker::create(session);
+                let stream = MySQLTlsStream::create(blocking_stream, tls);
+                MysqlIntermediary::run_on(interactive_worker, stream.clone(), stream)
+            }
+        };
+
+        if let Err(error) = result {
             if error.code() != ABORT_SESSION {
                 log::error!(
                     "Unexpected error occurred during query execution: {:?}",
@@ -0,0 +1,304 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_infallible::Mutex;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::ServerSession;
+use tokio_rustls::rustls::StreamOwned;
+
+use crate::configs::Config;
+use crate::servers::handler_tls_config;
+
+const CLIENT_SSL: u32 = 0x0000_0800;
+// SSLRequest payload: capability flags, max packet size, character set and 23 reserved bytes.
+const SSL_REQUEST_PAYLOAD_LEN: usize = 32;
+const PACKET_HEADER_LEN: usize = 4;
+
+#[derive(Clone)]
+pub struct MySQLTlsConfig {
+    server_config: Arc<ServerConfig>,
+    // Plaintext connections are rejected when the client certificates are verified.
+    required: bool,
+}
+
+impl MySQLTlsConfig {
+    pub fn try_create(config: &Config) -> Result<Option<MySQLTlsConfig>> {
+        Ok(
+            handler_tls_config(config)?.map(|server_config| MySQLTlsConfig {
+                server_config,
+                required: !config.query.handler_tls_server_root_ca_cert.is_empty(),
+            }),
+        )
+    }
+}
+
+#[derive(PartialEq)]
+enum Phase {
+    // The server greeting and the first client packet.
+    Greeting,
+    // Authentication over TLS, the SSLRequest packet shifts the sequence ids by one.
+    Authentication,
+    // Everything is passed through.
+    Command,
+}
+
+struct Inner {
+    tcp: TcpStream,
+    tls: Option<StreamOwned<ServerSession, TcpStream>>,
+    config: MySQLTlsConfig,
+    phase: Phase,
+    read_buffer: Vec<u8>,
+    read_pos: usize,
+    write_buffer: Vec<u8>,
+}
+
+/// Upgrades a MySQL connection to TLS when the client sends an SSLRequest.
+///
+/// msql_srv speaks the protocol over plain Read + Write halves, so the stream rewrites the few
+/// handshake packets it needs: it advertises CLIENT_SSL in the server greeting, performs the TLS
+/// handshake after the SSLRequest and hides the extra packet from msql_srv by adjusting the
+/// sequence ids until the authentication is done.
+#[derive(Clone)]
+pub struct MySQLTlsStream {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl MySQLTlsStream {
+    pub fn create(tcp: TcpStream, config: MySQLTlsConfig) -> MySQLTlsStream {
+        MySQLTlsStream {
+            inner: Arc::new(Mutex::new(Inner {
+                tcp,
+                tls: None,
+                config,
+                phase: Phase::Greeting,
+                read_buffer: vec![],
+                read_pos: 0,
+                write_buffer: vec![],
+            })),
+        }
+    }
+}
+
+impl Read for MySQLTlsStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.lock().read(buf)
+    }
+}
+
+impl Write for MySQLTlsStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.lock().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.lock().transport_flush()
+    }
+}
+
+impl Inner {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.read_pos < self.read_buffer.len() {
+            let size = std::cmp::min(buf.len(), self.read_buffer.len() - self.read_pos);
+            buf[..size].copy_from_slice(&self.read_buffer[self.read_pos..self.read_pos + size]);
+            self.read_pos += size;
+            return Ok(size);
+        }
+
+        match self.phase {
+            Phase::Command => self.transport_read(buf),
+            Phase::Greeting => {
+                let packet = self.read_packet()?;
+                self.on_first_packet(packet)?;
+                self.read(buf)
+            }
+            Phase::Authentication => {
+                let mut packet = self.read_packet()?;
+                packet[3] = packet[3].wrapping_sub(1);
+                self.fill_read_buffer(packet);
+                self.read(buf)
+            }
+        }
+    }
+
+    fn on_first_packet(&mut self, packet: Vec<u8>) -> io::Result<()> {
+        let payload = &packet[PACKET_HEADER_LEN..];
+        let ssl_request = payload.len() == SSL_REQUEST_PAYLOAD_LEN
+            && Self::capabilities(payload) & CLIENT_SSL != 0;
+
+        match ssl_request {
+            true => {
+                let session = ServerSession::new(&self.config.server_config);
+                self.tls = Some(StreamOwned::new(session, self.tcp.try_clone()?));
+                self.phase = Phase::Authentication;
+
+                // The handshake response follows the SSLRequest over TLS.
+                let mut packet = self.read_packet()?;
+                packet[3] = packet[3].wrapping_sub(1);
+                if packet.len() >= PACKET_HEADER_LEN + 4 {
+                    packet[PACKET_HEADER_LEN + 1] &= !((CLIENT_SSL >> 8) as u8);
+                }
+                self.fill_read_buffer(packet);
+                Ok(())
+            }
+            false if self.config.required => {
+                let message = "Connections using insecure transport are prohibited";
+                self.write_error_packet(packet[3].wrapping_add(1), message)?;
+                Err(io::Error::new(io::ErrorKind::PermissionDenied, message))
+            }
+            false => {
+                self.phase = Phase::Command;
+                self.fill_read_buffer(packet);
+                Ok(())
+            }
+        }
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.phase == Phase::Command && self.write_buffer.is_empty() {
+            return self.transport_write(buf);
+        }
+
+        self.write_buffer.extend_from_slice(buf);
+        while let Some(mut packet) = self.take_written_packet() {
+            match self.phase {
+                Phase::Greeting => {
+                    Self::advertise_ssl(&mut packet);
+                    self.transport_write_all(&packet)?;
+                }
+                Phase::Authentication => {
+                    packet[3] = packet[3].wrapping_add(1);
+                    self.transport_write_all(&packet)?;
+
+                    // OK or ERR packet ends the authentication.
+                    if matches!(packet.get(PACKET_HEADER_LEN), Some(0x00) | Some(0xFF)) {
+                        self.phase = Phase::Command;
+                    }
+                }
+                Phase::Command => self.transport_write_all(&packet)?,
+            }
+        }
+
+        if self.phase == Phase::Command && !self.write_buffer.is_empty() {
+            let remaining = std::mem::take(&mut self.write_buffer);
+            self.transport_write_all(&remaining)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn take_written_packet(&mut self) -> Option<Vec<u8>> {
+        if self.write_buffer.len() < PACKET_HEADER_LEN {
+            return None;
+        }
+
+        let packet_len = PACKET_HEADER_LEN + Self::payload_len(&self.write_buffer);
+        match self.write_buffer.len() < packet_len {
+            true => None,
+            false => Some(self.write_buffer.drain(..packet_len).collect()),
+        }
+    }
+
+    // Sets CLIENT_SSL in the lower capability flags of the protocol v10 greeting, which follow
+    // the server version, the connection id, the auth plugin data part 1 and a filler.
+    fn advertise_ssl(packet: &mut [u8]) {
+        let payload = &packet[PACKET_HEADER_LEN..];
+        if payload.first() != Some(&10) {
+            return;
+        }
+
+        if let Some(version_len) = payload[1..].iter().position(|b| *b == 0) {
+            let offset = PACKET_HEADER_LEN + 1 + version_len + 1 + 4 + 8 + 1;
+            if offset + 1 < packet.len() {
+                packet[offset + 1] |= (CLIENT_SSL >> 8) as u8;
+            }
+        }
+    }
+
+    fn write_error_packet(&mut self, seq: u8, message: &str) -> io::Result<()> {
+        // ER_SECURE_TRANSPORT_REQUIRED with SQLSTATE HY000
+        let mut payload = vec![0xFF];
+        payload.extend_from_slice(&3159u16.to_le_bytes());
+        payload.extend_from_slice(b"#HY000");
+        payload.extend_from_slice(message.as_bytes());
+
+        let mut packet = (payload.len() as u32).to_le_bytes()[..3].to_vec();
+        packet.push(seq);
+        packet.extend_from_slice(&payload);
+        self.transport_write_all(&packet)?;
+        self.transport_flush()
+    }
+
+    fn read_packet(&mut self) -> io::Result<Vec<u8>> {
+        let mut packet = vec![0; PACKET_HEADER_LEN];
+        self.transport_read_exact(&mut packet)?;
+        let payload_len = Self::payload_len(&packet);
+        packet.resize(PACKET_HEADER_LEN + payload_len, 0);
+        self.transport_read_exact(&mut packet[PACKET_HEADER_LEN..])?;
+        Ok(packet)
+    }
+
+    fn fill_read_buffer(&mut self, packet: Vec<u8>) {
+        self.read_buffer = packet;
+        self.read_pos = 0;
+    }
+
+    fn payload_len(header: &[u8]) -> usize {
+        u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize
+    }
+
+    fn capabilities(payload: &[u8]) -> u32 {
+        u32::from_le_bytes([payload[0], payload[1], payload[2], payload[3]])
+    }
+
+    fn transport_read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.tls {
+            None => self.tcp.read(buf),
+            Some(tls) => tls.read(buf),
+        }
+    }
+
+    fn transport_read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        match &mut self.tls {
+            None => self.tcp.read_exact(buf),
+            Some(tls) => tls.read_exact(buf),
+        }
+    }
+
+    fn transport_write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut self.tls {
+            None => self.tcp.write(buf),
+            Some(tls) => tls.write(buf),
+        }
+    }
+
+    fn transport_write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match &mut self.tls {
+            None => self.tcp.write_all(buf),
+            Some(tls) => tls.write_all(buf),
+        }
+    }
+
+    fn transport_flush(&mut self) -> io::Result<()> {
+        match &mut self.tls {
+            None => self.tcp.flush(),
+            Some(tls) => tls.flush(),
+        }
+    }
+}
@@ -14,6 +14,8 @@
 
 mod init_result_writer;
 mod query_result_writer;
+#[cfg(test)]
+mod query_result_writer_test;
 
 pub use self::init_result_writer::DFInitResultWriter;
 pub use self::query_result_writer::DFQueryResultWriter;
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
 use chrono_tz::Tz;
 use common_datablocks::DataBlock;
+use common_datavalues::format_date_time64;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
@@ -27,57 +30,96 @@ use msql_srv::*;
 
 pub struct DFQueryResultWriter<'a, W: std::io::Write> {
     inner: Option<QueryResultWriter<'a, W>>,
+    // Session timezone, used for DateTime columns without their own timezone.
+    timezone: Tz,
 }
 
 impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
-    pub fn create(inner: QueryResultWriter<'a, W>) -> DFQueryResultWriter<'a, W> {
-        DFQueryResultWriter::<'a, W> { inner: Some(inner) }
+    pub fn create(inner: QueryResultWriter<'a, W>, timezone: Tz) -> DFQueryResultWriter<'a, W> {
+        DFQueryResultWriter::<'a, W> {
+            inner: Some(inner),
+            timezone,
+        }
     }
 
-    pub fn write(&mut self, query_result: Result<(Vec<DataBlock>, String)>) -> Result<()> {
+    /// Write the result of a query, the blocks are pulled one by one and their rows are written
+    /// before pulling the next, so a slow client slows the query down instead of the result
+    /// being buffered. The extra info is taken after the last block.
+    pub fn write<I, E>(&mut self, query_result: Result<(I, E)>) -> Result<()>
+    where
+        I: Iterator<Item = Result<DataBlock>>,
+        E: FnOnce() -> String,
+    {
         if let Some(writer) = self.inner.take() {
             match query_result {
-                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, writer)?,
+                Ok((blocks, extra_info)) => Self::ok(blocks, extra_info, &self.timezone, writer)?,
                 Err(error) => Self::err(&error, writer)?,
             }
         }
         Ok(())
     }
 
-    fn ok(
-        blocks: Vec<DataBlock>,
-        extra_info: String,
+    pub fn write_error(&mut self, error: ErrorCode) -> Result<()> {
+        if let Some(writer) = self.inner.take() {
+            Self::err(&error, writer)?;
+        }
+        Ok(())
+    }
+
+    fn ok<I, E>(
+        mut blocks: I,
+        extra_info: E,
+        session_tz: &Tz,
         dataset_writer: QueryResultWriter<'a, W>,
-    ) -> Result<()> {
-        // XXX: num_columns == 0 may is error?
-        let default_response = OkResponse {
-            info: extra_info,
-            ..Default::default()
+    ) -> Result<()>
+    where
+        I: Iterator<Item = Result<DataBlock>>,
+        E: FnOnce() -> String,
+    {
+        let block = match blocks.next() {
+            None => None,
+            Some(Ok(block)) => Some(block),
+            Some(Err(error)) => return Self::err(&error, dataset_writer),
         };
 
-        if blocks.is_empty() || (blocks[0].num_columns() == 0) {
-            dataset_writer.completed(default_response)?;
-            return Ok(());
-        }
+        // XXX: num_columns == 0 may is error?
+        let block = match block {
+            Some(block) if block.num_columns() != 0 => block,
+            _ => {
+                for block in blocks {
+                    if let Err(error) = block {
+                        return Self::err(&error, dataset_writer);
+                    }
+                }
 
+                let default_response = OkResponse {
+                    info: extra_info(),
+                    ..Default::default()
+                };
+                dataset_writer.completed(default_response)?;
+                return Ok(());
+            }
+        };
+
+        // The column types of the MySQL protocol, the drivers and the BI tools map them into
+        // their own types, so that the integers keep their widths and signedness.
         fn convert_field_type(field: &DataField) -> Result<ColumnType> {
             match field.data_type() {
-                DataType::Int8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::Int64 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
-                DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
+                DataType::Int8 | DataType::UInt8 => Ok(ColumnType::MYSQL_TYPE_TINY),
+                DataType::Int16 | DataType::UInt16 => Ok(ColumnType::MYSQL_TYPE_SHORT),
+                DataType::Int32 | DataType::UInt32 => Ok(ColumnType::MYSQL_TYPE_LONG),
+                DataType::Int64 | DataType::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
                 DataType::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
-                DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+                DataType::Float64 => Ok(ColumnType::MYSQL_TYPE_DOUBLE),
                 DataType::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
-                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
+                DataType::List(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Boolean => Ok(ColumnType::MYSQL_TYPE_TINY),
                 DataType::Date16 | DataType::Date32 => Ok(ColumnType::MYSQL_TYPE_DATE),
-                DataType::DateTime32(_) => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::DateTime32(_) | DataType::DateTime64(_, _) => {
+                    Ok(ColumnType::MYSQL_TYPE_DATETIME)
+                }
                 DataType::Null => Ok(ColumnType::MYSQL_TYPE_NULL),
-                DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONG),
+                DataType::Interval(_) => Ok(ColumnType::MYSQL_TYPE_LONGLONG),
                 _ => Err(ErrorCode::UnImplement(format!(
                     "Unsupported column type:{:?}",
                     field.data_type()
@@ -85,12 +127,26 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             }
         }
 
+        fn convert_field_flags(field: &DataField) -> ColumnFlags {
+            let mut flags = ColumnFlags::empty();
+            if matches!(
+                field.data_type(),
+                DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64
+            ) {
+                flags |= ColumnFlags::UNSIGNED_FLAG;
+            }
+            if !field.is_nullable() {
+                flags |= ColumnFlags::NOT_NULL_FLAG;
+            }
+            flags
+        }
+
         fn make_column_from_field(field: &DataField) -> Result<Column> {
             convert_field_type(field).map(|column_type| Column {
                 table: "".to_string(),
                 column: field.name().to_string(),
                 coltype: column_type,
-                colflags: ColumnFlags::empty(),
+                colflags: convert_field_flags(field),
             })
         }
 
@@ -98,102 +154,169 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
             schema.fields().iter().map(make_column_from_field).collect()
         }
 
-        let block = blocks[0].clone();
-        let utc: Tz = "UTC".parse().unwrap();
         match convert_schema(block.schema()) {
             Err(error) => Self::err(&error, dataset_writer),
             Ok(columns) => {
-                let columns_size = block.num_columns();
                 let mut row_writer = dataset_writer.start(&columns)?;
 
-                for block in &blocks {
-                    let rows_size = block.column(0).len();
-                    for row_index in 0..rows_size {
-                        for col_index in 0..columns_size {
-                            let val = block.column(col_index).try_get(row_index)?;
-                            if val.is_null() {
-                                row_writer.write_col(None::<u8>)?;
-                                continue;
-                            }
-                            let data_type = block.schema().fields()[col_index].data_type();
-                            match (data_type, val) {
-                                (DataType::Boolean, DataValue::Boolean(Some(v))) => {
-                                    row_writer.write_col(v as i8)?
-                                }
-                                (DataType::Int8, DataValue::Int8(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int16, DataValue::Int16(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int32, DataValue::Int32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Int64, DataValue::Int64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt8, DataValue::UInt8(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt16, DataValue::UInt16(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt32, DataValue::UInt32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::UInt64, DataValue::UInt64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Float32, DataValue::Float32(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Float64, DataValue::Float64(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (DataType::Date16, DataValue::UInt16(Some(v))) => {
-                                    row_writer.write_col(v.to_date(&utc).naive_local())?
-                                }
-                                (DataType::Date32, DataValue::UInt32(Some(v))) => {
-                                    row_writer.write_col(v.to_date(&utc).naive_local())?
-                                }
-                                (DataType::DateTime32(tz), DataValue::UInt32(Some(v))) => {
-                                    let tz = tz.clone();
-                                    let tz = tz.unwrap_or_else(|| "UTC".to_string());
-                                    let tz: Tz = tz.parse().unwrap();
-                                    row_writer.write_col(v.to_date_time(&tz).naive_local())?
-                                }
-                                (DataType::String, DataValue::String(Some(v))) => {
-                                    row_writer.write_col(v)?
-                                }
-                                (_, v) => {
-                                    return Err(ErrorCode::BadDataValueType(format!(
-                                        "Unsupported column type:{:?}",
-                                        v.data_type()
-                                    )));
-                                }
-                            }
-                        }
-                        row_writer.end_row()?;
+                let mut block = Ok(block);
+                loop {
+                    // The column definitions have been sent, errors are reported by the error
+                    // packet that ends the result set.
+                    if let Err(error) = block
+                        .and_then(|block| Self::write_block(&mut row_writer, &block, session_tz))
+                    {
+                        let kind = Self::error_kind(&error);
+                        row_writer.finish_error(kind, format!("{}", error).as_bytes())?;
+                        return Ok(());
                     }
+
+                    block = match blocks.next() {
+                        None => break,
+                        Some(block) => block,
+                    };
                 }
-                row_writer.finish_with_info(&default_response.info)?;
 
+                row_writer.finish_with_info(&extra_info())?;
                 Ok(())
             }
         }
     }
 
-    fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
+    /// The values of the block are converted before any of its rows is written, an error ends
+    /// the result set after the rows of the previous blocks instead of after a partial row.
+    fn write_block(
+        row_writer: &mut RowWriter<'a, W>,
+        block: &DataBlock,
+        session_tz: &Tz,
+    ) -> Result<()> {
+        for row in block_rows(block, session_tz)? {
+            for value in row {
+                match value {
+                    RowValue::Null => row_writer.write_col(None::<u8>)?,
+                    RowValue::Int8(v) => row_writer.write_col(v)?,
+                    RowValue::Int16(v) => row_writer.write_col(v)?,
+                    RowValue::Int32(v) => row_writer.write_col(v)?,
+                    RowValue::Int64(v) => row_writer.write_col(v)?,
+                    RowValue::UInt8(v) => row_writer.write_col(v)?,
+                    RowValue::UInt16(v) => row_writer.write_col(v)?,
+                    RowValue::UInt32(v) => row_writer.write_col(v)?,
+                    RowValue::UInt64(v) => row_writer.write_col(v)?,
+                    RowValue::Float32(v) => row_writer.write_col(v)?,
+                    RowValue::Float64(v) => row_writer.write_col(v)?,
+                    RowValue::Date(v) => row_writer.write_col(v)?,
+                    RowValue::DateTime(v) => row_writer.write_col(v)?,
+                    RowValue::Bytes(v) => row_writer.write_col(v)?,
+                    RowValue::Text(v) => row_writer.write_col(v)?,
+                }
+            }
+            row_writer.end_row()?;
+        }
+
+        Ok(())
+    }
+
+    /// The kind of the error packet, the errors except the aborts are logged.
+    fn error_kind(error: &ErrorCode) -> ErrorKind {
         if error.code() != ABORT_QUERY && error.code() != ABORT_SESSION {
             log::error!("OnQuery Error: {:?}", error);
-            writer.error(ErrorKind::ER_UNKNOWN_ERROR, format!("{}", error).as_bytes())?;
+            ErrorKind::ER_UNKNOWN_ERROR
         } else {
-            writer.error(
-                ErrorKind::ER_ABORTING_CONNECTION,
-                format!("{}", error).as_bytes(),
-            )?;
+            ErrorKind::ER_ABORTING_CONNECTION
         }
+    }
 
+    fn err(error: &ErrorCode, writer: QueryResultWriter<'a, W>) -> Result<()> {
+        writer.error(Self::error_kind(error), format!("{}", error).as_bytes())?;
         Ok(())
     }
 }
+
+/// A value of a row, in the type it is written to the MySQL protocol.
+#[derive(Debug, PartialEq)]
+pub(crate) enum RowValue {
+    Null,
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    Bytes(Vec<u8>),
+    Text(String),
+}
+
+/// The rows of the block, or the error of the first value which can't be written.
+pub(crate) fn block_rows(block: &DataBlock, session_tz: &Tz) -> Result<Vec<Vec<RowValue>>> {
+    let utc: Tz = "UTC".parse().unwrap();
+    let fields = block.schema().fields();
+    let timezones = fields
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::DateTime32(Some(tz)) | DataType::DateTime64(_, Some(tz)) => tz
+                .parse::<Tz>()
+                .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz))),
+            _ => Ok(*session_tz),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let columns_size = block.num_columns();
+    let rows_size = block.column(0).len();
+    let mut rows = Vec::with_capacity(rows_size);
+    for row_index in 0..rows_size {
+        let mut row = Vec::with_capacity(columns_size);
+        for col_index in 0..columns_size {
+            let val = block.column(col_index).try_get(row_index)?;
+            if val.is_null() {
+                row.push(RowValue::Null);
+                continue;
+            }
+            let tz = &timezones[col_index];
+            let value = match (fields[col_index].data_type(), val) {
+                (DataType::Boolean, DataValue::Boolean(Some(v))) => RowValue::Int8(v as i8),
+                (DataType::Int8, DataValue::Int8(Some(v))) => RowValue::Int8(v),
+                (DataType::Int16, DataValue::Int16(Some(v))) => RowValue::Int16(v),
+                (DataType::Int32, DataValue::Int32(Some(v))) => RowValue::Int32(v),
+                (DataType::Int64, DataValue::Int64(Some(v))) => RowValue::Int64(v),
+                (DataType::UInt8, DataValue::UInt8(Some(v))) => RowValue::UInt8(v),
+                (DataType::UInt16, DataValue::UInt16(Some(v))) => RowValue::UInt16(v),
+                (DataType::UInt32, DataValue::UInt32(Some(v))) => RowValue::UInt32(v),
+                (DataType::UInt64, DataValue::UInt64(Some(v))) => RowValue::UInt64(v),
+                (DataType::Float32, DataValue::Float32(Some(v))) => RowValue::Float32(v),
+                (DataType::Float64, DataValue::Float64(Some(v))) => RowValue::Float64(v),
+                (DataType::Date16, DataValue::UInt16(Some(v))) => {
+                    RowValue::Date(v.to_date(&utc).naive_local())
+                }
+                (DataType::Date32, DataValue::UInt32(Some(v))) => {
+                    RowValue::Date(v.to_date(&utc).naive_local())
+                }
+                (DataType::DateTime32(_), DataValue::UInt32(Some(v))) => {
+                    RowValue::DateTime(v.to_date_time(tz).naive_local())
+                }
+                (DataType::DateTime64(precision, _), DataValue::Int64(Some(v))) => {
+                    // the text protocol keeps the fractional digits
+                    RowValue::Text(format_date_time64(v, *precision, tz))
+                }
+                (DataType::String, DataValue::String(Some(v))) => RowValue::Bytes(v),
+                (DataType::List(_), v @ DataValue::List(Some(_), _)) => {
+                    RowValue::Text(format!("{}", v))
+                }
+                (_, v) => {
+                    return Err(ErrorCode::BadDataValueType(format!(
+                        "Unsupported column type:{:?}",
+                        v.data_type()
+                    )));
+                }
+            };
+            row.push(value);
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use pretty_assertions::assert_eq;
+
+use crate::servers::mysql::writers::query_result_writer::block_rows;
+use crate::servers::mysql::writers::query_result_writer::RowValue;
+
+#[test]
+fn test_block_rows() -> Result<()> {
+    let tz: Tz = "UTC".parse().unwrap();
+
+    // The first block is converted into rows.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt64, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![1u64, 2]),
+        Series::new(vec!["x", "y"]),
+    ]);
+    let expected = vec![
+        vec![RowValue::UInt64(1), RowValue::Bytes(b"x".to_vec())],
+        vec![RowValue::UInt64(2), RowValue::Bytes(b"y".to_vec())],
+    ];
+    assert_eq!(expected, block_rows(&block, &tz)?);
+
+    // The second block fails in its second column, none of its rows is written.
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::UInt64, false),
+        DataField::new(
+            "t",
+            DataType::DateTime32(Some("Bad/Zone".to_string())),
+            false,
+        ),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![3u64]),
+        Series::new(vec![0u32]),
+    ]);
+    let result = block_rows(&block, &tz);
+    let expect = "Code: 6, displayText = Unknown timezone: \"Bad/Zone\".";
+    assert_eq!(expect, result.unwrap_err().to_string());
+
+    Ok(())
+}
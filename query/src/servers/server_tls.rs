@@ -0,0 +1,78 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tokio_rustls::rustls::internal::pemfile::certs;
+use tokio_rustls::rustls::internal::pemfile::pkcs8_private_keys;
+use tokio_rustls::rustls::AllowAnyAuthenticatedClient;
+use tokio_rustls::rustls::NoClientAuth;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::rustls::ServerConfig;
+
+use crate::configs::Config;
+
+/// The TLS config of the MySQL and ClickHouse handlers, None if the server certificate or its key
+/// is not set. The client certificates are verified if the root CA certificate is set.
+pub fn handler_tls_config(config: &Config) -> Result<Option<Arc<ServerConfig>>> {
+    if !config.tls_handler_enabled() {
+        return Ok(None);
+    }
+
+    let query = &config.query;
+    let certs = certs(&mut BufReader::new(File::open(
+        &query.handler_tls_server_cert,
+    )?))
+    .map_err(|_| ErrorCode::TLSConfigurationFailure("invalid handler cert"))?;
+    // currently only PKCS8 key supports for TLS setup
+    let mut keys = pkcs8_private_keys(&mut BufReader::new(File::open(
+        &query.handler_tls_server_key,
+    )?))
+    .map_err(|_| ErrorCode::TLSConfigurationFailure("invalid handler key"))?;
+    if keys.is_empty() {
+        return Err(ErrorCode::TLSConfigurationFailure(
+            "no PKCS8 key in handler key file",
+        ));
+    }
+
+    let verifier = match query.handler_tls_server_root_ca_cert.is_empty() {
+        true => NoClientAuth::new(),
+        false => {
+            log::info!("Client Authentication for MySQL and ClickHouse handlers.");
+            let pem_file = File::open(&query.handler_tls_server_root_ca_cert)?;
+            let mut root_cert_store = RootCertStore::empty();
+            if root_cert_store
+                .add_pem_file(&mut BufReader::new(pem_file))
+                .is_err()
+            {
+                return Err(ErrorCode::TLSConfigurationFailure(
+                    "Cannot add client ca in for handlers",
+                ));
+            }
+            AllowAnyAuthenticatedClient::new(root_cert_store)
+        }
+    };
+
+    let mut tls_config = ServerConfig::new(verifier);
+    if let Err(cause) = tls_config.set_single_cert(certs, keys.remove(0)) {
+        return Err(ErrorCode::TLSConfigurationFailure(format!(
+            "Cannot build TLS config for handlers, cause {}",
+            cause
+        )));
+    }
+    Ok(Some(Arc::new(tls_config)))
+}
@@ -17,10 +17,12 @@ use std::future::Future;
 use std::sync::atomic::Ordering;
 use std::sync::atomic::Ordering::Acquire;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use common_base::tokio::task::JoinHandle;
 use common_base::ProgressCallback;
 use common_base::ProgressValues;
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
@@ -32,6 +34,11 @@ use common_planners::PlanNode;
 use common_planners::Statistics;
 use common_streams::AbortStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing::Instrument;
+use futures::future::BoxFuture;
+use futures::future::Shared;
+use futures::FutureExt;
+use rusoto_core::Region;
 
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::catalogs::Catalog;
@@ -44,8 +51,14 @@ use crate::datasources::dal::Local;
 use crate::datasources::dal::StorageScheme;
 use crate::datasources::dal::S3;
 use crate::sessions::context_shared::DatabendQueryContextShared;
+use crate::sessions::MemoryTracker;
+use crate::sessions::ProcessInfo;
+use crate::sessions::ProcessorProfile;
+use crate::sessions::ResourceGroup;
+use crate::sessions::ResourceGroupTicket;
 use crate::sessions::SessionManagerRef;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
 
 pub struct DatabendQueryContext {
     statistics: Arc<RwLock<Statistics>>,
@@ -56,6 +69,9 @@ pub struct DatabendQueryContext {
 
 pub type DatabendQueryContextRef = Arc<DatabendQueryContext>;
 
+/// The materialized result of a CTE, shared by all of its references in a query.
+pub type CteSetFuture = Shared<BoxFuture<'static, Result<Arc<Vec<DataBlock>>>>>;
+
 impl DatabendQueryContext {
     pub fn new(other: DatabendQueryContextRef) -> DatabendQueryContextRef {
         DatabendQueryContext::from_shared(other.shared.clone())
@@ -84,7 +100,8 @@ impl DatabendQueryContext {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        Ok(self.shared.try_get_runtime()?.spawn(task))
+        // The task inherits the span of the caller, e.g. to attribute its events to the query.
+        Ok(self.shared.try_get_runtime()?.spawn(task.in_current_span()))
     }
 
     /// Set progress callback to context.
@@ -92,8 +109,10 @@ impl DatabendQueryContext {
     /// Note that the callback can be called from different threads.
     pub fn progress_callback(&self) -> Result<ProgressCallback> {
         let current_progress = self.shared.progress.clone();
+        let total_progress = self.shared.total_progress.clone();
         Ok(Box::new(move |value: &ProgressValues| {
             current_progress.incr(value);
+            total_progress.incr(value);
         }))
     }
 
@@ -105,6 +124,11 @@ impl DatabendQueryContext {
         self.shared.progress.as_ref().get_and_reset()
     }
 
+    /// The progress of the whole query, not affected by `get_and_reset_progress_value`.
+    pub fn get_total_progress_value(&self) -> ProgressValues {
+        self.shared.total_progress.as_ref().get_values()
+    }
+
     // Some table can estimate the approx total rows, such as NumbersTable
     pub fn add_total_rows_approx(&self, total_rows: usize) {
         self.shared
@@ -154,6 +178,21 @@ impl DatabendQueryContext {
         self.shared.get_catalog()
     }
 
+    /// Start a transaction in the session, it fails if there is one in progress.
+    pub fn begin_transaction(&self) -> Result<()> {
+        self.shared.begin_transaction()
+    }
+
+    /// The transaction in progress of the session, if any.
+    pub fn get_transaction(&self) -> Option<Arc<Transaction>> {
+        self.shared.get_transaction()
+    }
+
+    /// Detach the transaction in progress from the session, to commit or discard it.
+    pub fn take_transaction(&self) -> Option<Arc<Transaction>> {
+        self.shared.take_transaction()
+    }
+
     pub fn get_table(&self, database: &str, table: &str) -> Result<Arc<TableMeta>> {
         self.get_catalog().get_table(database, table)
     }
@@ -217,6 +256,38 @@ impl DatabendQueryContext {
         format!("_subquery_{}", index)
     }
 
+    pub fn get_cte_name(&self) -> String {
+        let index = self.shared.subquery_index.fetch_add(1, Ordering::Relaxed);
+        format!("_cte_{}", index)
+    }
+
+    /// Get the materialized result of a CTE, only the first reference of the CTE
+    /// creates the future, so the CTE query runs once per query.
+    pub fn get_or_create_cte_set<F>(&self, name: &str, create: F) -> CteSetFuture
+    where F: FnOnce() -> BoxFuture<'static, Result<Arc<Vec<DataBlock>>>> {
+        let mut cte_sets = self.shared.cte_sets.write();
+        cte_sets
+            .entry(name.to_string())
+            .or_insert_with(|| create().shared())
+            .clone()
+    }
+
+    /// Set the rows produced by the last iteration of a recursive CTE.
+    pub fn set_cte_working_table(&self, name: &str, blocks: Arc<Vec<DataBlock>>) {
+        let mut working_tables = self.shared.cte_working_tables.write();
+        working_tables.insert(name.to_string(), blocks);
+    }
+
+    pub fn get_cte_working_table(&self, name: &str) -> Result<Arc<Vec<DataBlock>>> {
+        let working_tables = self.shared.cte_working_tables.read();
+        working_tables.get(name).cloned().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "Logical error: the working table of CTE {} is not set",
+                name
+            ))
+        })
+    }
+
     pub fn attach_query_str(&self, query: &str) {
         self.shared.attach_query_str(query);
     }
@@ -229,6 +300,84 @@ impl DatabendQueryContext {
         self.shared.session.get_sessions_manager()
     }
 
+    pub fn get_current_user(&self) -> Option<String> {
+        self.shared.session.get_current_user()
+    }
+
+    pub fn get_query_str(&self) -> String {
+        self.shared.get_query_str()
+    }
+
+    pub fn get_created_time(&self) -> SystemTime {
+        self.shared.created_time
+    }
+
+    pub fn get_process_info(&self) -> ProcessInfo {
+        self.shared.session.process_info()
+    }
+
+    /// Profile the processors of this query even if `enable_query_profile` is not set.
+    pub fn enable_query_profile(&self) {
+        self.shared
+            .force_query_profile
+            .store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_query_profile_enabled(&self) -> Result<bool> {
+        match self.shared.force_query_profile.load(Ordering::Relaxed) {
+            true => Ok(true),
+            false => Ok(self.get_settings().get_enable_query_profile()? != 0),
+        }
+    }
+
+    pub fn add_processor_profile(&self, profile: Arc<ProcessorProfile>) {
+        self.shared.add_processor_profile(profile);
+    }
+
+    pub fn get_processor_profiles(&self) -> Vec<Arc<ProcessorProfile>> {
+        self.shared.get_processor_profiles()
+    }
+
+    /// Wait in the queue of the resource group of the session until the query can run,
+    /// the query holds its slot until the ticket is dropped.
+    pub async fn admit_query(&self) -> Result<Option<ResourceGroupTicket>> {
+        let setting = self.get_settings().get_resource_group()?;
+        let user = self.shared.session.get_current_user();
+        let groups = self
+            .shared
+            .session
+            .get_sessions_manager()
+            .get_resource_groups();
+        let group = match groups.get_group(&setting, user.as_deref())? {
+            None => return Ok(None),
+            Some(group) => group,
+        };
+
+        *self.shared.resource_group.write() = Some(group.clone());
+        self.shared.queued.store(true, Ordering::Relaxed);
+        let priority = self.get_settings().get_query_priority()?;
+        let ticket = group.admit(priority).await;
+        self.shared.queued.store(false, Ordering::Relaxed);
+
+        let tracker = MemoryTracker::create(0, Some(group.get_memory_tracker()));
+        *self.shared.memory_tracker.write() = tracker;
+        ticket.map(Some)
+    }
+
+    pub fn get_resource_group(&self) -> Option<Arc<ResourceGroup>> {
+        self.shared.get_resource_group()
+    }
+
+    /// The memory tracker of the query, charging the resource group of the query if any.
+    pub fn get_memory_tracker(&self) -> Arc<MemoryTracker> {
+        self.shared.get_memory_tracker()
+    }
+
+    /// The `max_threads` setting, capped by the resource group of the query.
+    pub fn get_max_threads(&self) -> Result<usize> {
+        self.shared.get_max_threads()
+    }
+
     pub fn get_data_accessor(
         &self,
         storage_scheme: &StorageScheme,
@@ -239,6 +388,45 @@ impl DatabendQueryContext {
             _ => todo!(),
         }
     }
+
+    /// The data accessor of an external location and the path in it, the location is
+    /// `s3://bucket/path` or a local path `file:///path`.
+    pub fn get_location_accessor(&self, location: &str) -> Result<(Arc<dyn DataAccessor>, String)> {
+        if let Some(bucket_path) = location.strip_prefix("s3://") {
+            let (bucket, path) = bucket_path.split_once('/').unwrap_or((bucket_path, ""));
+            if bucket.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Missing bucket in location: {}",
+                    location
+                )));
+            }
+            let accessor = S3::new(Region::default(), bucket.to_string());
+            return Ok((Arc::new(accessor), path.to_string()));
+        }
+
+        match location.strip_prefix("file://") {
+            Some(path) if path.starts_with('/') => {
+                // local files are confined to the stage directory, the accessor
+                // rejects the paths which escape from it
+                let stage_dir = self.get_config().query.local_stage_dir;
+                if stage_dir.is_empty() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Can't load local files from {}, local_stage_dir is not configured",
+                        location
+                    )));
+                }
+                let root = std::fs::canonicalize(&stage_dir).map_err(|e| {
+                    ErrorCode::BadArguments(format!("Invalid local_stage_dir {}: {}", stage_dir, e))
+                })?;
+                let path = path.trim_start_matches('/').to_string();
+                Ok((Arc::new(Local::new(&root.to_string_lossy())), path))
+            }
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unsupported location: {}, expect s3://bucket/path or file:///path",
+                location
+            ))),
+        }
+    }
 }
 
 impl std::fmt::Debug for DatabendQueryContext {
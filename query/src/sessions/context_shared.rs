@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use common_base::Progress;
 use common_base::Runtime;
+use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_infallible::RwLock;
 use common_planners::PlanNode;
@@ -26,8 +31,13 @@ use uuid::Uuid;
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::clusters::ClusterRef;
 use crate::configs::Config;
+use crate::sessions::context::CteSetFuture;
+use crate::sessions::MemoryTracker;
+use crate::sessions::ProcessorProfile;
+use crate::sessions::ResourceGroup;
 use crate::sessions::Session;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
 
 /// Data that needs to be shared in a query context.
 /// This is very useful, for example, for queries:
@@ -41,6 +51,8 @@ use crate::sessions::Settings;
 pub struct DatabendQueryContextShared {
     pub(in crate::sessions) conf: Config,
     pub(in crate::sessions) progress: Arc<Progress>,
+    // Unlike `progress`, never reset by the handlers reporting the progress.
+    pub(in crate::sessions) total_progress: Arc<Progress>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
@@ -50,6 +62,16 @@ pub struct DatabendQueryContextShared {
     pub(in crate::sessions) subquery_index: Arc<AtomicUsize>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_plan: Arc<RwLock<Option<PlanNode>>>,
+    pub(in crate::sessions) cte_sets: Arc<RwLock<HashMap<String, CteSetFuture>>>,
+    pub(in crate::sessions) cte_working_tables: Arc<RwLock<HashMap<String, Arc<Vec<DataBlock>>>>>,
+    pub(in crate::sessions) created_time: SystemTime,
+    pub(in crate::sessions) processor_profiles: Arc<RwLock<Vec<Arc<ProcessorProfile>>>>,
+    // Profile the processors regardless of `enable_query_profile`, set by EXPLAIN ANALYZE.
+    pub(in crate::sessions) force_query_profile: Arc<AtomicBool>,
+    pub(in crate::sessions) resource_group: Arc<RwLock<Option<Arc<ResourceGroup>>>>,
+    // Set while the query waits in the queue of its resource group.
+    pub(in crate::sessions) queued: Arc<AtomicBool>,
+    pub(in crate::sessions) memory_tracker: Arc<RwLock<Arc<MemoryTracker>>>,
 }
 
 impl DatabendQueryContextShared {
@@ -62,6 +84,7 @@ impl DatabendQueryContextShared {
             conf,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             progress: Arc::new(Progress::create()),
+            total_progress: Arc::new(Progress::create()),
             session,
             cluster_cache,
             runtime: Arc::new(RwLock::new(None)),
@@ -70,6 +93,14 @@ impl DatabendQueryContextShared {
             subquery_index: Arc::new(AtomicUsize::new(1)),
             running_query: Arc::new(RwLock::new(None)),
             running_plan: Arc::new(RwLock::new(None)),
+            cte_sets: Arc::new(RwLock::new(HashMap::new())),
+            cte_working_tables: Arc::new(RwLock::new(HashMap::new())),
+            created_time: SystemTime::now(),
+            processor_profiles: Arc::new(RwLock::new(Vec::new())),
+            force_query_profile: Arc::new(AtomicBool::new(false)),
+            resource_group: Arc::new(RwLock::new(None)),
+            queued: Arc::new(AtomicBool::new(false)),
+            memory_tracker: Arc::new(RwLock::new(MemoryTracker::create(0, None))),
         })
     }
 
@@ -103,6 +134,18 @@ impl DatabendQueryContextShared {
         self.session.get_catalog()
     }
 
+    pub fn begin_transaction(&self) -> Result<()> {
+        self.session.begin_transaction()
+    }
+
+    pub fn get_transaction(&self) -> Option<Arc<Transaction>> {
+        self.session.get_transaction()
+    }
+
+    pub fn take_transaction(&self) -> Option<Arc<Transaction>> {
+        self.session.take_transaction()
+    }
+
     /// Init runtime when first get
     pub fn try_get_runtime(&self) -> Result<Arc<Runtime>> {
         let mut query_runtime = self.runtime.write();
@@ -110,8 +153,7 @@ impl DatabendQueryContextShared {
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
-                let settings = self.get_settings();
-                let max_threads = settings.get_max_threads()? as usize;
+                let max_threads = self.get_max_threads()?;
                 let runtime = Arc::new(Runtime::with_worker_threads(max_threads)?);
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
@@ -119,11 +161,38 @@ impl DatabendQueryContextShared {
         }
     }
 
+    /// The `max_threads` setting, capped by the resource group of the query.
+    pub fn get_max_threads(&self) -> Result<usize> {
+        let max_threads = self.get_settings().get_max_threads()?;
+        match &*self.resource_group.read() {
+            Some(group) if group.get_info().max_threads != 0 => {
+                Ok(std::cmp::min(max_threads, group.get_info().max_threads) as usize)
+            }
+            _ => Ok(max_threads as usize),
+        }
+    }
+
+    pub fn get_resource_group(&self) -> Option<Arc<ResourceGroup>> {
+        self.resource_group.read().clone()
+    }
+
+    pub fn is_queued(&self) -> bool {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    pub fn get_memory_tracker(&self) -> Arc<MemoryTracker> {
+        self.memory_tracker.read().clone()
+    }
+
     pub fn attach_query_str(&self, query: &str) {
         let mut running_query = self.running_query.write();
         *running_query = Some(query.to_string());
     }
 
+    pub fn get_query_str(&self) -> String {
+        self.running_query.read().clone().unwrap_or_default()
+    }
+
     pub fn attach_query_plan(&self, plan: &PlanNode) {
         let mut running_plan = self.running_plan.write();
         *running_plan = Some(plan.clone());
@@ -133,6 +202,14 @@ impl DatabendQueryContextShared {
         let mut sources_abort_handle = self.sources_abort_handle.write();
         sources_abort_handle.push(handle);
     }
+
+    pub fn add_processor_profile(&self, profile: Arc<ProcessorProfile>) {
+        self.processor_profiles.write().push(profile);
+    }
+
+    pub fn get_processor_profiles(&self) -> Vec<Arc<ProcessorProfile>> {
+        self.processor_profiles.read().clone()
+    }
 }
 
 impl Session {
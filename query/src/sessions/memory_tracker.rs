@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// Tracks the memory held by a query: the blocks buffered by sorts, materialized CTEs, the
+/// sets of subqueries and the result cache, and the hash tables of GROUP BY. The usage is
+/// checked against the limit of the resource group of the query.
+///
+/// The usage is also charged to the parent tracker, the remaining usage of a query is
+/// released from it once the tracker of the query is dropped.
+pub struct MemoryTracker {
+    // 0 means unlimited.
+    limit: u64,
+    usage: AtomicU64,
+    parent: Option<Arc<MemoryTracker>>,
+}
+
+impl MemoryTracker {
+    pub fn create(limit: u64, parent: Option<Arc<MemoryTracker>>) -> Arc<MemoryTracker> {
+        Arc::new(MemoryTracker {
+            limit,
+            usage: AtomicU64::new(0),
+            parent,
+        })
+    }
+
+    pub fn alloc(&self, bytes: usize) -> Result<()> {
+        let bytes = bytes as u64;
+        let usage = self.usage.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if self.limit != 0 && usage > self.limit {
+            self.usage.fetch_sub(bytes, Ordering::Relaxed);
+            return Err(ErrorCode::MemoryLimitExceeded(format!(
+                "Memory limit exceeded: would use {} bytes, maximum: {} bytes",
+                usage, self.limit
+            )));
+        }
+
+        if let Some(parent) = &self.parent {
+            if let Err(cause) = parent.alloc(bytes as usize) {
+                self.usage.fetch_sub(bytes, Ordering::Relaxed);
+                return Err(cause);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn free(&self, bytes: usize) {
+        self.usage.fetch_sub(bytes as u64, Ordering::Relaxed);
+        if let Some(parent) = &self.parent {
+            parent.free(bytes);
+        }
+    }
+
+    pub fn get_usage(&self) -> u64 {
+        self.usage.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for MemoryTracker {
+    fn drop(&mut self) {
+        if let Some(parent) = &self.parent {
+            parent.free(self.get_usage() as usize);
+        }
+    }
+}
@@ -17,7 +17,15 @@ mod macros;
 
 mod context;
 mod context_shared;
+mod memory_tracker;
 mod metrics;
+mod query_log;
+mod resource_group;
+#[cfg(test)]
+mod resource_group_test;
+mod result_cache;
+#[cfg(test)]
+mod result_cache_test;
 mod session;
 mod session_info;
 mod session_ref;
@@ -25,13 +33,32 @@ mod session_ref;
 mod sessions;
 mod sessions_info;
 mod settings;
+mod system_variables;
+mod transaction;
 
 pub use context::DatabendQueryContext;
 pub use context::DatabendQueryContextRef;
 pub use context_shared::DatabendQueryContextShared;
+pub use memory_tracker::MemoryTracker;
+pub use query_log::ProcessorProfile;
+pub use query_log::QueryLog;
+pub use query_log::QueryLogElement;
+pub use query_log::QueryLogType;
+pub use query_log::QueryProfileElement;
+pub use resource_group::ResourceGroup;
+pub use resource_group::ResourceGroupInfo;
+pub use resource_group::ResourceGroupTicket;
+pub use resource_group::ResourceGroups;
+pub use result_cache::QueryResultCache;
+pub use result_cache::ResultCacheKey;
 pub use session::Session;
 pub use session_info::ProcessInfo;
 pub use session_ref::SessionRef;
 pub use sessions::SessionManager;
 pub use sessions::SessionManagerRef;
 pub use settings::Settings;
+pub use system_variables::get_system_variable;
+pub use system_variables::get_system_variables;
+pub use system_variables::is_mysql_system_variable;
+pub use transaction::StagedTable;
+pub use transaction::Transaction;
@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use common_infallible::RwLock;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QueryLogType {
+    QueryStart,
+    QueryFinish,
+    ExceptionBeforeStart,
+    ExceptionWhileProcessing,
+}
+
+impl QueryLogType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryLogType::QueryStart => "QueryStart",
+            QueryLogType::QueryFinish => "QueryFinish",
+            QueryLogType::ExceptionBeforeStart => "ExceptionBeforeStart",
+            QueryLogType::ExceptionWhileProcessing => "ExceptionWhileProcessing",
+        }
+    }
+}
+
+/// A row of system.query_log.
+#[derive(Clone, Debug)]
+pub struct QueryLogElement {
+    pub log_type: QueryLogType,
+    // Seconds since the unix epoch.
+    pub event_time: u32,
+    pub query_start_time: u32,
+    pub query_duration_ms: u64,
+    pub query_id: String,
+    pub query_kind: String,
+    pub query: String,
+    pub session_id: String,
+    pub session_type: String,
+    pub user: String,
+    pub client_address: Option<String>,
+    pub current_database: String,
+    pub read_rows: u64,
+    pub read_bytes: u64,
+    pub result_rows: u64,
+    pub result_bytes: u64,
+    pub exception_code: i32,
+    pub exception: String,
+}
+
+/// A row of system.query_profile, also sent back by the remote stages for EXPLAIN ANALYZE.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct QueryProfileElement {
+    pub query_id: String,
+    // The stage id of a remote stage, empty for the pipeline of the query itself.
+    pub stage: String,
+    pub pipe: u64,
+    pub processor: String,
+    pub output_rows: u64,
+    pub output_bytes: u64,
+    pub elapsed_us: u64,
+    pub cpu_us: u64,
+}
+
+/// The counters of a processor, updated while the pipeline is running.
+pub struct ProcessorProfile {
+    pub pipe: usize,
+    pub processor: String,
+    pub output_rows: AtomicU64,
+    pub output_bytes: AtomicU64,
+    // Wall and CPU time spent in the processor, excluding the inputs polled by it.
+    pub elapsed_ns: AtomicU64,
+    pub cpu_ns: AtomicU64,
+}
+
+impl ProcessorProfile {
+    pub fn create(pipe: usize, processor: impl Into<String>) -> ProcessorProfile {
+        ProcessorProfile {
+            pipe,
+            processor: processor.into(),
+            output_rows: AtomicU64::new(0),
+            output_bytes: AtomicU64::new(0),
+            elapsed_ns: AtomicU64::new(0),
+            cpu_ns: AtomicU64::new(0),
+        }
+    }
+
+    pub fn to_element(&self, query_id: &str, stage: &str) -> QueryProfileElement {
+        QueryProfileElement {
+            query_id: query_id.to_string(),
+            stage: stage.to_string(),
+            pipe: self.pipe as u64,
+            processor: self.processor.clone(),
+            output_rows: self.output_rows.load(Ordering::Relaxed),
+            output_bytes: self.output_bytes.load(Ordering::Relaxed),
+            elapsed_us: self.elapsed_ns.load(Ordering::Relaxed) / 1000,
+            cpu_us: self.cpu_ns.load(Ordering::Relaxed) / 1000,
+        }
+    }
+}
+
+/// The recent query logs and profiles of this node, the oldest ones are dropped once `size` is
+/// reached: `size` log entries for system.query_log and `size` queries for system.query_profile.
+pub struct QueryLog {
+    size: usize,
+    logs: RwLock<VecDeque<QueryLogElement>>,
+    profiles: RwLock<VecDeque<Vec<QueryProfileElement>>>,
+    // The log entries not written into the `query_log_table` yet, also bounded by `size`.
+    unflushed: RwLock<VecDeque<QueryLogElement>>,
+}
+
+impl QueryLog {
+    pub fn create(size: usize) -> QueryLog {
+        QueryLog {
+            size,
+            logs: RwLock::new(VecDeque::new()),
+            profiles: RwLock::new(VecDeque::new()),
+            unflushed: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    pub fn append_log(&self, element: QueryLogElement) {
+        let mut logs = self.logs.write();
+        if logs.len() >= self.size {
+            logs.pop_front();
+        }
+
+        if self.size > 0 {
+            logs.push_back(element);
+        }
+    }
+
+    /// Buffer the log entry until the next flush writes it into the `query_log_table`.
+    pub fn append_unflushed(&self, element: QueryLogElement) {
+        let mut unflushed = self.unflushed.write();
+        if unflushed.len() >= self.size {
+            unflushed.pop_front();
+        }
+
+        if self.size > 0 {
+            unflushed.push_back(element);
+        }
+    }
+
+    pub fn take_unflushed(&self) -> Vec<QueryLogElement> {
+        self.unflushed.write().drain(..).collect()
+    }
+
+    pub fn append_profiles(&self, elements: Vec<QueryProfileElement>) {
+        let mut profiles = self.profiles.write();
+        if profiles.len() >= self.size {
+            profiles.pop_front();
+        }
+
+        if self.size > 0 && !elements.is_empty() {
+            profiles.push_back(elements);
+        }
+    }
+
+    pub fn get_logs(&self) -> Vec<QueryLogElement> {
+        self.logs.read().iter().cloned().collect()
+    }
+
+    pub fn get_profiles(&self) -> Vec<QueryProfileElement> {
+        self.profiles.read().iter().flatten().cloned().collect()
+    }
+
+    pub fn get_query_profiles(&self, query_id: &str) -> Vec<QueryProfileElement> {
+        let profiles = self.profiles.read();
+        let profiles = profiles.iter().flatten();
+        profiles
+            .filter(|v| v.query_id == query_id)
+            .cloned()
+            .collect()
+    }
+}
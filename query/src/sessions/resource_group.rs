@@ -0,0 +1,472 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio::sync::oneshot;
+use common_base::tokio::time::timeout;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use common_management::ResourceGroupApi;
+use common_management::ResourceGroupMeta;
+
+use crate::sessions::MemoryTracker;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+
+/// The resource group used by the sessions which are not mapped to any other group.
+pub const DEFAULT_RESOURCE_GROUP: &str = "default";
+
+/// The limits of a resource group, 0 means unlimited.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceGroupInfo {
+    pub name: String,
+    pub max_concurrent_queries: u64,
+    pub max_queued_queries: u64,
+    // Seconds a query waits in the queue before it fails.
+    pub queue_timeout: u64,
+    pub max_threads: u64,
+    // Bytes of memory used by the running queries of the group, see `MemoryTracker`.
+    pub max_memory_usage: u64,
+    // The highest `query_priority` of the queued queries, a higher one is lowered to it.
+    // 0 leaves the priorities as they are.
+    pub max_query_priority: u64,
+    // The users whose sessions are mapped to the group.
+    pub users: Vec<String>,
+}
+
+impl ResourceGroupInfo {
+    pub fn try_create(name: &str, options: &HashMap<String, String>) -> Result<ResourceGroupInfo> {
+        let mut info = ResourceGroupInfo {
+            name: name.to_string(),
+            max_concurrent_queries: 0,
+            max_queued_queries: 0,
+            queue_timeout: 0,
+            max_threads: 0,
+            max_memory_usage: 0,
+            max_query_priority: 0,
+            users: vec![],
+        };
+
+        for (key, value) in options {
+            let number = || {
+                value.parse::<u64>().map_err(|_| {
+                    ErrorCode::BadOption(format!(
+                        "Resource group option {} must be a number, but got: {}",
+                        key, value
+                    ))
+                })
+            };
+
+            match key.as_str() {
+                "max_concurrent_queries" => info.max_concurrent_queries = number()?,
+                "max_queued_queries" => info.max_queued_queries = number()?,
+                "queue_timeout" => info.queue_timeout = number()?,
+                "max_threads" => info.max_threads = number()?,
+                "max_memory_usage" => info.max_memory_usage = number()?,
+                "max_query_priority" => info.max_query_priority = number()?,
+                "users" => {
+                    let users = value.split(',').map(|user| user.trim().to_string());
+                    info.users = users.filter(|user| !user.is_empty()).collect();
+                }
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "Unknown resource group option: {}",
+                        key
+                    )))
+                }
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+// The queued queries are started by priority, then by arrival.
+type QueueKey = (Reverse<u64>, u64);
+
+struct QueueState {
+    running: u64,
+    next_seq: u64,
+    queued: BTreeMap<QueueKey, oneshot::Sender<()>>,
+}
+
+/// Admits the queries of a group: at most `max_concurrent_queries` run at the same time,
+/// the others wait in the queue until a running query finishes.
+pub struct ResourceGroup {
+    info: ResourceGroupInfo,
+    memory_tracker: Arc<MemoryTracker>,
+    state: Mutex<QueueState>,
+}
+
+impl ResourceGroup {
+    pub fn create(info: ResourceGroupInfo) -> Arc<ResourceGroup> {
+        Arc::new(ResourceGroup {
+            memory_tracker: MemoryTracker::create(info.max_memory_usage, None),
+            info,
+            state: Mutex::new(QueueState {
+                running: 0,
+                next_seq: 0,
+                queued: BTreeMap::new(),
+            }),
+        })
+    }
+
+    pub fn get_info(&self) -> &ResourceGroupInfo {
+        &self.info
+    }
+
+    pub fn get_memory_tracker(&self) -> Arc<MemoryTracker> {
+        self.memory_tracker.clone()
+    }
+
+    /// The number of the running and the queued queries.
+    pub fn get_queries(&self) -> (u64, u64) {
+        let state = self.state.lock();
+        (state.running, state.queued.len() as u64)
+    }
+
+    /// Everyone may use the default group, the other groups are used by their users only.
+    pub fn is_accessible(&self, user: Option<&str>) -> bool {
+        let users = &self.info.users;
+        self.info.name == DEFAULT_RESOURCE_GROUP
+            || user.map_or(false, |u| users.iter().any(|v| v == u))
+    }
+
+    /// Wait until the query can run, the query keeps its slot until the ticket is dropped.
+    pub async fn admit(self: &Arc<Self>, priority: u64) -> Result<ResourceGroupTicket> {
+        let priority = match self.info.max_query_priority {
+            0 => priority,
+            max_priority => priority.min(max_priority),
+        };
+        let (key, receiver) = {
+            let mut state = self.state.lock();
+            let max_running = self.info.max_concurrent_queries;
+            if max_running == 0 || state.running < max_running {
+                state.running += 1;
+                return Ok(ResourceGroupTicket {
+                    group: self.clone(),
+                });
+            }
+
+            let max_queued = self.info.max_queued_queries;
+            if max_queued != 0 && state.queued.len() as u64 >= max_queued {
+                return Err(ErrorCode::TooManyQueuedQueries(format!(
+                    "Too many queued queries in resource group {}, maximum: {}",
+                    self.info.name, max_queued
+                )));
+            }
+
+            let key = (Reverse(priority), state.next_seq);
+            let (sender, receiver) = oneshot::channel();
+            state.next_seq += 1;
+            state.queued.insert(key, sender);
+            (key, receiver)
+        };
+
+        // Leaves the queue even if the query is killed while it is waiting.
+        let mut queued = QueuedQuery {
+            group: self.clone(),
+            key,
+            left: false,
+        };
+
+        match self.info.queue_timeout {
+            0 => {
+                let _ = receiver.await;
+            }
+            seconds => {
+                let _ = timeout(Duration::from_secs(seconds), receiver).await;
+            }
+        }
+
+        match queued.leave() {
+            true => Ok(ResourceGroupTicket {
+                group: self.clone(),
+            }),
+            false => Err(ErrorCode::Timeout(format!(
+                "Query waited more than {} seconds in the queue of resource group {}",
+                self.info.queue_timeout, self.info.name
+            ))),
+        }
+    }
+
+    // Hand over the slot of a finished query to the first queued query.
+    fn release(&self) {
+        let mut state = self.state.lock();
+        let first = state.queued.keys().next().cloned();
+        match first.and_then(|key| state.queued.remove(&key)) {
+            Some(sender) => {
+                // The query is admitted once it is removed from the queue, even if it
+                // stopped waiting, see `QueuedQuery::leave`.
+                let _ = sender.send(());
+            }
+            None => state.running -= 1,
+        }
+    }
+}
+
+struct QueuedQuery {
+    group: Arc<ResourceGroup>,
+    key: QueueKey,
+    left: bool,
+}
+
+impl QueuedQuery {
+    // Returns true if a finished query handed over its slot to this query.
+    fn leave(&mut self) -> bool {
+        self.left = true;
+        let mut state = self.group.state.lock();
+        state.queued.remove(&self.key).is_none()
+    }
+}
+
+impl Drop for QueuedQuery {
+    fn drop(&mut self) {
+        if !self.left && self.leave() {
+            self.group.release();
+        }
+    }
+}
+
+/// The slot of a running query in its resource group.
+pub struct ResourceGroupTicket {
+    group: Arc<ResourceGroup>,
+}
+
+impl Drop for ResourceGroupTicket {
+    fn drop(&mut self) {
+        self.group.release();
+    }
+}
+
+/// The resource groups of this node, defined by the `resource_groups` config or by
+/// CREATE RESOURCE GROUP on any node of the cluster.
+pub struct ResourceGroups {
+    groups: RwLock<BTreeMap<String, Arc<ResourceGroup>>>,
+    // The groups of the config, they are not kept in the meta service.
+    config_groups: RwLock<BTreeSet<String>>,
+    // Keeps the groups created by CREATE RESOURCE GROUP in the meta service.
+    api: Option<Arc<dyn ResourceGroupApi>>,
+}
+
+impl ResourceGroups {
+    /// The config is a list of groups separated by semicolons, in the syntax of
+    /// CREATE RESOURCE GROUP, e.g. `etl WITH (max_concurrent_queries = 8, users = 'etl')`.
+    pub fn try_create(conf: &str) -> Result<ResourceGroups> {
+        let groups = ResourceGroups {
+            groups: RwLock::new(BTreeMap::new()),
+            config_groups: RwLock::new(BTreeSet::new()),
+            api: None,
+        };
+
+        for group in conf.split(';').map(str::trim).filter(|v| !v.is_empty()) {
+            let (statements, _) = DfParser::parse_sql(&format!("CREATE RESOURCE GROUP {}", group))?;
+            match statements.as_slice() {
+                [DfStatement::CreateResourceGroup(create)] => {
+                    let info = ResourceGroupInfo::try_create(&create.name.value, &create.options)?;
+                    groups.insert(info, false)?;
+                    groups
+                        .config_groups
+                        .write()
+                        .insert(create.name.value.clone());
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "Invalid resource group: {}",
+                        group
+                    )))
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// The groups of the config, then the groups created by CREATE RESOURCE GROUP and kept
+    /// in the meta service, a group of the config takes precedence over a created one.
+    pub fn try_create_with_api(
+        conf: &str,
+        api: Arc<dyn ResourceGroupApi>,
+    ) -> Result<ResourceGroups> {
+        let mut groups = Self::try_create(conf)?;
+        groups.api = Some(api);
+        groups.refresh()?;
+        Ok(groups)
+    }
+
+    /// Load the groups created and dropped by the other nodes from the meta service. The
+    /// running and the queued queries of a group dropped or replaced meanwhile keep their
+    /// slots in the old one.
+    fn refresh(&self) -> Result<()> {
+        let api = match &self.api {
+            None => return Ok(()),
+            Some(api) => api,
+        };
+
+        let mut persisted = BTreeMap::new();
+        for group in api.get_resource_groups()? {
+            let options = group.options.into_iter().collect::<HashMap<_, _>>();
+            let info = ResourceGroupInfo::try_create(&group.name, &options)?;
+            persisted.insert(group.name, info);
+        }
+
+        let config_groups = self.config_groups.read();
+        let mut groups = self.groups.write();
+        groups.retain(|name, _| config_groups.contains(name) || persisted.contains_key(name));
+        for (name, info) in persisted {
+            if config_groups.contains(&name) {
+                continue;
+            }
+            match groups.get(&name) {
+                Some(group) if group.info == info => {}
+                _ => {
+                    groups.insert(name, ResourceGroup::create(info));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn insert(&self, info: ResourceGroupInfo, if_not_exists: bool) -> Result<()> {
+        let mut groups = self.groups.write();
+        if groups.contains_key(&info.name) {
+            return match if_not_exists {
+                true => Ok(()),
+                false => Err(ErrorCode::ResourceGroupAlreadyExists(format!(
+                    "Resource group {} already exists",
+                    info.name
+                ))),
+            };
+        }
+
+        groups.insert(info.name.clone(), ResourceGroup::create(info));
+        Ok(())
+    }
+
+    /// Create the group and keep it in the meta service, so that it is loaded again when the
+    /// node restarts.
+    pub fn create(
+        &self,
+        name: &str,
+        options: &HashMap<String, String>,
+        if_not_exists: bool,
+    ) -> Result<()> {
+        let info = ResourceGroupInfo::try_create(name, options)?;
+        if self.groups.read().contains_key(name) {
+            return self.insert(info, if_not_exists);
+        }
+
+        if let Some(api) = &self.api {
+            let options = options.clone().into_iter().collect();
+            let meta = ResourceGroupMeta::create(name.to_string(), options);
+            match api.add_resource_group(meta) {
+                Ok(_) => {}
+                // Created by another node meanwhile.
+                Err(cause) if cause.code() == ErrorCode::ResourceGroupAlreadyExists("").code() => {
+                    return match if_not_exists {
+                        true => Ok(()),
+                        false => Err(cause),
+                    };
+                }
+                Err(cause) => return Err(cause),
+            }
+            // Loaded by a refresh meanwhile.
+            return self.insert(info, true);
+        }
+
+        self.insert(info, if_not_exists)
+    }
+
+    /// The running and the queued queries of the group keep their slots until they finish.
+    pub fn drop_group(&self, name: &str, if_exists: bool) -> Result<()> {
+        let unknown =
+            || ErrorCode::UnknownResourceGroup(format!("Unknown resource group {}", name));
+        self.config_groups.write().remove(name);
+        let dropped = self.groups.write().remove(name).is_some();
+
+        // The groups of the config are not kept in the meta service.
+        let persisted = match &self.api {
+            None => false,
+            Some(api) => match api.drop_resource_group(name) {
+                Ok(_) => true,
+                Err(cause) if cause.code() == unknown().code() => false,
+                Err(cause) => return Err(cause),
+            },
+        };
+
+        match dropped || persisted || if_exists {
+            true => Ok(()),
+            false => Err(unknown()),
+        }
+    }
+
+    /// Only the users of the `resource_group_admins` config may create and drop groups.
+    pub fn check_admin(admins: &str, user: Option<&str>) -> Result<()> {
+        let mut admins = admins.split(',').map(str::trim).filter(|v| !v.is_empty());
+        match user {
+            Some(user) if admins.any(|admin| admin == user) => Ok(()),
+            _ => Err(ErrorCode::PermissionDenied(format!(
+                "User {} is not allowed to create or drop resource groups",
+                user.unwrap_or("")
+            ))),
+        }
+    }
+
+    pub fn get_groups(&self) -> Vec<Arc<ResourceGroup>> {
+        self.groups.read().values().cloned().collect()
+    }
+
+    /// The group of the query: the group of the `resource_group` setting if it is set and
+    /// accessible by the user, otherwise the first group listing the user, otherwise the
+    /// default group if any. The groups are reloaded from the meta service first.
+    pub fn get_group(
+        &self,
+        setting: &str,
+        user: Option<&str>,
+    ) -> Result<Option<Arc<ResourceGroup>>> {
+        self.refresh()?;
+        let groups = self.groups.read();
+        if !setting.is_empty() {
+            return match groups.get(setting) {
+                Some(group) if group.is_accessible(user) => Ok(Some(group.clone())),
+                Some(_) => Err(ErrorCode::PermissionDenied(format!(
+                    "User {} is not allowed to use resource group {}",
+                    user.unwrap_or(""),
+                    setting
+                ))),
+                None => Err(ErrorCode::UnknownResourceGroup(format!(
+                    "Unknown resource group {}",
+                    setting
+                ))),
+            };
+        }
+
+        if let Some(user) = user {
+            let mut user_groups = groups.values();
+            if let Some(group) = user_groups.find(|g| g.info.users.iter().any(|u| u == user)) {
+                return Ok(Some(group.clone()));
+            }
+        }
+
+        Ok(groups.get(DEFAULT_RESOURCE_GROUP).cloned())
+    }
+}
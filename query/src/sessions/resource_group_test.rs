@@ -0,0 +1,325 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::Result;
+use common_management::ResourceGroupMgr;
+use pretty_assertions::assert_eq;
+
+use crate::common::StoreApiProvider;
+use crate::configs::Config;
+use crate::sessions::MemoryTracker;
+use crate::sessions::ResourceGroup;
+use crate::sessions::ResourceGroupInfo;
+use crate::sessions::ResourceGroups;
+
+fn create_group(options: &[(&str, &str)]) -> Result<Arc<ResourceGroup>> {
+    let options = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<HashMap<_, _>>();
+    Ok(ResourceGroup::create(ResourceGroupInfo::try_create(
+        "test", &options,
+    )?))
+}
+
+#[test]
+fn test_resource_group_info() -> Result<()> {
+    let group = create_group(&[
+        ("max_concurrent_queries", "8"),
+        ("max_queued_queries", "100"),
+        ("queue_timeout", "60"),
+        ("max_threads", "4"),
+        ("max_memory_usage", "1024"),
+        ("max_query_priority", "10"),
+        ("users", "etl, loader"),
+    ])?;
+    let info = group.get_info();
+    assert_eq!(info.max_concurrent_queries, 8);
+    assert_eq!(info.max_queued_queries, 100);
+    assert_eq!(info.queue_timeout, 60);
+    assert_eq!(info.max_threads, 4);
+    assert_eq!(info.max_memory_usage, 1024);
+    assert_eq!(info.max_query_priority, 10);
+    assert_eq!(info.users, vec!["etl".to_string(), "loader".to_string()]);
+
+    match create_group(&[("max_threads", "many")]) {
+        Ok(_) => assert!(false, "Option must be a number"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 22, displayText = Resource group option max_threads must be a number, but got: many."
+        ),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resource_group_queue() -> Result<()> {
+    let group = create_group(&[("max_concurrent_queries", "1")])?;
+    let running = group.admit(0).await?;
+    assert_eq!(group.get_queries(), (1, 0));
+
+    let low_group = group.clone();
+    let mut low = tokio::spawn(async move { low_group.admit(0).await });
+    while group.get_queries() != (1, 1) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let high_group = group.clone();
+    let high = tokio::spawn(async move { high_group.admit(5).await });
+    while group.get_queries() != (1, 2) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The query of the higher priority is started first.
+    drop(running);
+    let high_running = high.await.unwrap()?;
+    assert_eq!(group.get_queries(), (1, 1));
+    let waiting = tokio::time::timeout(Duration::from_millis(100), &mut low).await;
+    assert!(waiting.is_err());
+
+    drop(high_running);
+    let low_running = low.await.unwrap()?;
+    assert_eq!(group.get_queries(), (1, 0));
+
+    drop(low_running);
+    assert_eq!(group.get_queries(), (0, 0));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resource_group_query_priority() -> Result<()> {
+    let group = create_group(&[("max_concurrent_queries", "1"), ("max_query_priority", "1")])?;
+    let running = group.admit(0).await?;
+
+    let first_group = group.clone();
+    let first = tokio::spawn(async move { first_group.admit(1).await });
+    while group.get_queries() != (1, 1) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let second_group = group.clone();
+    let mut second = tokio::spawn(async move { second_group.admit(10).await });
+    while group.get_queries() != (1, 2) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    // The priority is capped by the group, so the queries are started by arrival.
+    drop(running);
+    let first_running = first.await.unwrap()?;
+    let waiting = tokio::time::timeout(Duration::from_millis(100), &mut second).await;
+    assert!(waiting.is_err());
+
+    drop(first_running);
+    let second_running = second.await.unwrap()?;
+    drop(second_running);
+    assert_eq!(group.get_queries(), (0, 0));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_resource_group_queue_limits() -> Result<()> {
+    let group = create_group(&[
+        ("max_concurrent_queries", "1"),
+        ("max_queued_queries", "1"),
+        ("queue_timeout", "1"),
+    ])?;
+    let running = group.admit(0).await?;
+
+    let queued_group = group.clone();
+    let queued = tokio::spawn(async move { queued_group.admit(0).await });
+    while group.get_queries() != (1, 1) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    match group.admit(0).await {
+        Ok(_) => assert!(false, "The queue of the group is full"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 60, displayText = Too many queued queries in resource group test, maximum: 1."
+        ),
+    }
+
+    match queued.await.unwrap() {
+        Ok(_) => assert!(false, "The queued query must time out"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 40, displayText = Query waited more than 1 seconds in the queue of resource group test."
+        ),
+    }
+    assert_eq!(group.get_queries(), (1, 0));
+
+    drop(running);
+    assert_eq!(group.get_queries(), (0, 0));
+    Ok(())
+}
+
+#[test]
+fn test_resource_groups() -> Result<()> {
+    let groups = ResourceGroups::try_create(
+        "etl WITH (max_concurrent_queries = 2, users = 'etl'); default WITH (max_threads = 4)",
+    )?;
+    assert_eq!(groups.get_groups().len(), 2);
+
+    let group = groups.get_group("", Some("etl"))?;
+    assert_eq!(
+        group.map(|g| g.get_info().name.clone()),
+        Some("etl".to_string())
+    );
+
+    let group = groups.get_group("", Some("root"))?;
+    assert_eq!(
+        group.map(|g| g.get_info().name.clone()),
+        Some("default".to_string())
+    );
+
+    let group = groups.get_group("etl", Some("etl"))?;
+    assert_eq!(
+        group.map(|g| g.get_info().name.clone()),
+        Some("etl".to_string())
+    );
+
+    let group = groups.get_group("default", None)?;
+    assert_eq!(
+        group.map(|g| g.get_info().name.clone()),
+        Some("default".to_string())
+    );
+
+    match groups.get_group("etl", Some("root")) {
+        Ok(_) => assert!(false, "The group of the setting must list the user"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 62, displayText = User root is not allowed to use resource group etl."
+        ),
+    }
+
+    match groups.get_group("adhoc", None) {
+        Ok(_) => assert!(false, "The group of the setting must exist"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 58, displayText = Unknown resource group adhoc."
+        ),
+    }
+
+    groups.drop_group("default", false)?;
+    assert!(groups.get_group("", Some("root"))?.is_none());
+
+    match ResourceGroups::try_create("etl WITH (max_threads = 4); etl") {
+        Ok(_) => assert!(false, "Resource group names must be unique"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 59, displayText = Resource group etl already exists."
+        ),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resource_groups_persisted() -> Result<()> {
+    let kv_api = StoreApiProvider::new(&Config::default())
+        .try_get_kv_client()
+        .await?;
+    let api = Arc::new(ResourceGroupMgr::new(kv_api, "test"));
+
+    let groups = ResourceGroups::try_create_with_api("", api.clone())?;
+    let options = vec![("max_threads".to_string(), "2".to_string())];
+    groups.create("etl", &options.into_iter().collect(), false)?;
+
+    // The created groups are loaded with the groups of the config.
+    let groups =
+        ResourceGroups::try_create_with_api("default WITH (max_threads = 4)", api.clone())?;
+    let names = groups
+        .get_groups()
+        .iter()
+        .map(|g| g.get_info().name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["default".to_string(), "etl".to_string()]);
+
+    match groups.create("etl", &HashMap::new(), false) {
+        Ok(_) => assert!(false, "Resource group names must be unique"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 59, displayText = Resource group etl already exists."
+        ),
+    }
+
+    groups.drop_group("etl", false)?;
+    groups.drop_group("default", false)?;
+    let groups = ResourceGroups::try_create_with_api("", api)?;
+    assert!(groups.get_groups().is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resource_groups_refreshed() -> Result<()> {
+    let kv_api = StoreApiProvider::new(&Config::default())
+        .try_get_kv_client()
+        .await?;
+    let api = Arc::new(ResourceGroupMgr::new(kv_api, "test_refreshed"));
+
+    // Two nodes of the same tenant.
+    let node1 = ResourceGroups::try_create_with_api("", api.clone())?;
+    let node2 = ResourceGroups::try_create_with_api("adhoc WITH (users = 'root')", api)?;
+
+    // The group created by the other node is used.
+    let options = vec![("users".to_string(), "etl".to_string())];
+    node1.create("etl", &options.into_iter().collect(), false)?;
+    let group = node2.get_group("etl", Some("etl"))?;
+    assert_eq!(group.unwrap().get_info().name, "etl");
+
+    // The group dropped by the other node is unknown, the groups of the config are kept.
+    node1.drop_group("etl", false)?;
+    match node2.get_group("etl", Some("etl")) {
+        Ok(_) => assert!(false, "The dropped group must be unknown"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 58, displayText = Unknown resource group etl."
+        ),
+    }
+    let group = node2.get_group("adhoc", Some("root"))?;
+    assert_eq!(group.unwrap().get_info().name, "adhoc");
+
+    Ok(())
+}
+
+#[test]
+fn test_memory_tracker() -> Result<()> {
+    let group = MemoryTracker::create(100, None);
+    let query = MemoryTracker::create(0, Some(group.clone()));
+
+    query.alloc(60)?;
+    assert_eq!(group.get_usage(), 60);
+
+    match query.alloc(60) {
+        Ok(_) => assert!(false, "The limit of the group must be checked"),
+        Err(cause) => assert_eq!(
+            cause.to_string(),
+            "Code: 61, displayText = Memory limit exceeded: would use 120 bytes, maximum: 100 bytes."
+        ),
+    }
+    assert_eq!(query.get_usage(), 60);
+
+    query.free(20);
+    assert_eq!(group.get_usage(), 40);
+
+    // The usage left by the query is released when the query finishes.
+    drop(query);
+    assert_eq!(group.get_usage(), 0);
+    Ok(())
+}
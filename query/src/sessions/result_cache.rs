@@ -0,0 +1,371 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_arrow::arrow::io::ipc::write::common::IpcWriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::arrow_flight::utils::flight_data_from_arrow_batch;
+use common_arrow::arrow_flight::utils::flight_data_to_arrow_batch;
+use common_arrow::arrow_flight::FlightData;
+use common_cache::Cache;
+use common_cache::DefaultHashBuilder;
+use common_cache::LruCache;
+use common_cache::LruDiskCache;
+use common_cache::Meter;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::scalars::FunctionFactory;
+use common_infallible::Mutex;
+use common_metatypes::MetaId;
+use common_metatypes::MetaVersion;
+use common_planners::is_higher_order_function;
+use common_planners::CteScanPlan;
+use common_planners::CteSource;
+use common_planners::Expression;
+use common_planners::ExpressionVisitor;
+use common_planners::PlanNode;
+use common_planners::PlanVisitor;
+use common_planners::ReadDataSourcePlan;
+use common_planners::ReadFilesPlan;
+use common_planners::Recursion;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
+
+/// The key of a cached result: the hash of the optimized plan and of the versions
+/// of the tables it reads, with the ids of these tables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResultCacheKey {
+    pub hash: String,
+    pub tables: Vec<MetaId>,
+}
+
+impl ResultCacheKey {
+    /// The key of the result of the plan, None if the result can't be cached: the plan
+    /// calls non-deterministic functions, or reads sources without a snapshot.
+    pub fn try_create(plan: &PlanNode) -> Result<Option<ResultCacheKey>> {
+        let mut visitor = CacheableVisitor {
+            cacheable: true,
+            sources: vec![],
+        };
+        visitor.visit_plan_node(plan)?;
+
+        if !visitor.cacheable {
+            return Ok(None);
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(serde_json::to_vec(plan)?);
+        for (table_id, table_version, snapshot) in &visitor.sources {
+            hasher.update(format!("{}:{:?}:{}", table_id, table_version, snapshot));
+        }
+
+        let mut tables = visitor
+            .sources
+            .iter()
+            .map(|(table_id, _, _)| *table_id)
+            .collect::<Vec<_>>();
+        tables.sort_unstable();
+        tables.dedup();
+
+        Ok(Some(ResultCacheKey {
+            hash: format!("{:x}", hasher.finalize()),
+            tables,
+        }))
+    }
+}
+
+struct CacheableVisitor {
+    cacheable: bool,
+    // (table id, table version, snapshot location) of each source.
+    sources: Vec<(MetaId, Option<MetaVersion>, String)>,
+}
+
+impl PlanVisitor for CacheableVisitor {
+    fn visit_expr(&mut self, expr: &Expression) -> Result<()> {
+        let visitor = expr.accept(DeterministicVisitor {
+            deterministic: true,
+            subqueries: vec![],
+        })?;
+
+        if !visitor.deterministic {
+            self.cacheable = false;
+        }
+
+        for subquery in &visitor.subqueries {
+            self.visit_subquery_plan(subquery)?;
+        }
+        Ok(())
+    }
+
+    fn visit_cte_scan(&mut self, plan: &CteScanPlan) -> Result<()> {
+        match &plan.source {
+            CteSource::Query(query) => self.visit_plan_node(query),
+            CteSource::Recursive { anchor, recursive } => {
+                self.visit_plan_node(anchor)?;
+                self.visit_plan_node(recursive)
+            }
+            CteSource::WorkingTable => Ok(()),
+        }
+    }
+
+    fn visit_read_data_source(&mut self, plan: &ReadDataSourcePlan) -> Result<()> {
+        // Only the tables with snapshots are immutable for a given version, the others
+        // (memory, system, files...) may change without a new version.
+        match plan.schema.meta().get(META_SNAPSHOT_LOCATION) {
+            None => self.cacheable = false,
+            Some(snapshot) => {
+                let source = (plan.table_id, plan.table_version, snapshot.clone());
+                self.sources.push(source);
+            }
+        }
+        Ok(())
+    }
+
+    fn visit_read_files(&mut self, _: &ReadFilesPlan) -> Result<()> {
+        self.cacheable = false;
+        Ok(())
+    }
+}
+
+struct DeterministicVisitor {
+    deterministic: bool,
+    subqueries: Vec<Arc<PlanNode>>,
+}
+
+impl DeterministicVisitor {
+    fn is_deterministic(op: &str) -> bool {
+        // The lambda of a higher-order function is visited as an expression.
+        if is_higher_order_function(op) {
+            return true;
+        }
+
+        match FunctionFactory::get(op) {
+            Ok(function) => function.is_deterministic(),
+            Err(_) => false,
+        }
+    }
+}
+
+impl ExpressionVisitor for DeterministicVisitor {
+    fn pre_visit(self, expr: &Expression) -> Result<Recursion<Self>> {
+        let mut visitor = self;
+        match expr {
+            Expression::ScalarFunction { op, .. }
+            | Expression::UnaryExpression { op, .. }
+            | Expression::BinaryExpression { op, .. } => {
+                if !Self::is_deterministic(op) {
+                    visitor.deterministic = false;
+                }
+            }
+            Expression::Lambda { body, .. } => {
+                visitor = body.accept(visitor)?;
+            }
+            Expression::Subquery { query_plan, .. }
+            | Expression::ScalarSubquery { query_plan, .. } => {
+                visitor.subqueries.push(query_plan.clone());
+            }
+            _ => {}
+        }
+        Ok(Recursion::Continue(visitor))
+    }
+}
+
+struct CachedResult {
+    created: Instant,
+    tables: Vec<MetaId>,
+    schema: DataSchemaRef,
+    bytes: usize,
+    // None if the blocks are kept in the disk cache.
+    blocks: Option<Vec<DataBlock>>,
+}
+
+struct ResultSize;
+
+impl Meter<String, CachedResult> for ResultSize {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &CachedResult) -> usize
+    where String: Borrow<Q> {
+        v.bytes
+    }
+}
+
+/// The results of the SELECT queries of this node, reused by the queries with the same
+/// key until the TTL of the reader expires or one of the tables commits a new snapshot.
+///
+/// The results are kept in memory, or in the disk cache if `result_cache_dir` is set, at
+/// most `result_cache_size` bytes, the least recently used results are evicted first.
+pub struct QueryResultCache {
+    results: Mutex<LruCache<String, CachedResult, DefaultHashBuilder, ResultSize>>,
+    disk: Option<Mutex<LruDiskCache>>,
+}
+
+impl QueryResultCache {
+    pub fn try_create(size: u64, dir: &str) -> Result<QueryResultCache> {
+        let disk = match dir.is_empty() {
+            true => None,
+            false => {
+                let cache = LruDiskCache::new(dir, size).map_err(ErrorCode::from_std_error)?;
+                Some(Mutex::new(cache))
+            }
+        };
+
+        Ok(QueryResultCache {
+            results: Mutex::new(LruCache::with_meter(size, ResultSize)),
+            disk,
+        })
+    }
+
+    /// Maximum bytes of the cached results.
+    pub fn capacity(&self) -> u64 {
+        self.results.lock().capacity()
+    }
+
+    /// The cached result of the key, None if missing or older than `ttl`.
+    pub fn get(
+        &self,
+        key: &ResultCacheKey,
+        ttl: Duration,
+    ) -> Option<(DataSchemaRef, Vec<DataBlock>)> {
+        let mut results = self.results.lock();
+        let (schema, blocks) = match results.get(&key.hash) {
+            Some(result) if result.created.elapsed() < ttl => {
+                (result.schema.clone(), result.blocks.clone())
+            }
+            _ => return None,
+        };
+
+        match blocks {
+            Some(blocks) => Some((schema, blocks)),
+            None => match self.read_blocks(&key.hash, &schema) {
+                Ok(blocks) => Some((schema, blocks)),
+                Err(cause) => {
+                    // The file may be evicted by the disk cache already.
+                    log::debug!("Cannot read the cached result {}: {}", key.hash, cause);
+                    results.pop(&key.hash);
+                    None
+                }
+            },
+        }
+    }
+
+    pub fn put(&self, key: &ResultCacheKey, schema: DataSchemaRef, blocks: Vec<DataBlock>) {
+        let bytes = blocks.iter().map(|block| block.memory_size()).sum();
+        let blocks = match &self.disk {
+            None => Some(blocks),
+            Some(_) => match self.write_blocks(&key.hash, blocks) {
+                Ok(_) => None,
+                Err(cause) => {
+                    log::warn!("Cannot write the cached result {}: {}", key.hash, cause);
+                    return;
+                }
+            },
+        };
+
+        self.results.lock().put(key.hash.clone(), CachedResult {
+            created: Instant::now(),
+            tables: key.tables.clone(),
+            schema,
+            bytes,
+            blocks,
+        });
+    }
+
+    /// Drop the results reading any of the tables, called once they commit new snapshots.
+    pub fn invalidate_tables(&self, tables: &[MetaId]) {
+        let mut results = self.results.lock();
+        let invalidated = results
+            .iter()
+            .filter(|(_, result)| result.tables.iter().any(|id| tables.contains(id)))
+            .map(|(hash, _)| hash.clone())
+            .collect::<Vec<_>>();
+
+        for hash in invalidated {
+            results.pop(&hash);
+            if let Some(disk) = &self.disk {
+                // The file may be evicted by the disk cache already.
+                let _ = disk.lock().remove(&hash);
+            }
+        }
+    }
+
+    // Each block is written as the length prefixed IPC header and body of its flight data.
+    fn write_blocks(&self, hash: &str, blocks: Vec<DataBlock>) -> Result<()> {
+        let options = IpcWriteOptions::default();
+        let mut bytes = vec![];
+        for block in blocks {
+            let batch = RecordBatch::try_from(block)?;
+            let (_, data) = flight_data_from_arrow_batch(&batch, &options);
+            for part in [&data.data_header, &data.data_body] {
+                bytes.extend_from_slice(&(part.len() as u64).to_le_bytes());
+                bytes.extend_from_slice(part);
+            }
+        }
+
+        if let Some(disk) = &self.disk {
+            let mut disk = disk.lock();
+            disk.insert_bytes(hash, &bytes)
+                .map_err(ErrorCode::from_std_error)?;
+        }
+        Ok(())
+    }
+
+    fn read_blocks(&self, hash: &str, schema: &DataSchemaRef) -> Result<Vec<DataBlock>> {
+        let mut bytes = vec![];
+        if let Some(disk) = &self.disk {
+            let mut reader = disk.lock().get(hash).map_err(ErrorCode::from_std_error)?;
+            reader.read_to_end(&mut bytes)?;
+        }
+
+        let mut parts = vec![];
+        let mut remaining = bytes.as_slice();
+        while !remaining.is_empty() {
+            if remaining.len() < 8 {
+                return Err(ErrorCode::BadBytes("Truncated cached result"));
+            }
+            let (len, rest) = remaining.split_at(8);
+            let len = u64::from_le_bytes(len.try_into().unwrap()) as usize;
+            if rest.len() < len {
+                return Err(ErrorCode::BadBytes("Truncated cached result"));
+            }
+            let (part, rest) = rest.split_at(len);
+            parts.push(part.to_vec());
+            remaining = rest;
+        }
+
+        let arrow_schema = Arc::new(schema.to_arrow());
+        let mut blocks = Vec::with_capacity(parts.len() / 2);
+        let mut parts = parts.into_iter();
+        while let (Some(data_header), Some(data_body)) = (parts.next(), parts.next()) {
+            let data = FlightData {
+                data_header,
+                data_body,
+                ..Default::default()
+            };
+            let batch = flight_data_to_arrow_batch(&data, arrow_schema.clone(), true, &[])?;
+            blocks.push(DataBlock::try_from(batch)?);
+        }
+        Ok(blocks)
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::*;
+use pretty_assertions::assert_eq;
+
+use crate::catalogs::meta_backend::META_SNAPSHOT_LOCATION;
+use crate::sessions::QueryResultCache;
+use crate::sessions::ResultCacheKey;
+
+fn source_plan(snapshot: Option<&str>) -> PlanNode {
+    let mut meta = HashMap::new();
+    if let Some(snapshot) = snapshot {
+        meta.insert(META_SNAPSHOT_LOCATION.to_string(), snapshot.to_string());
+    }
+
+    let fields = vec![DataField::new("a", DataType::UInt64, false)];
+    PlanNode::ReadSource(ReadDataSourcePlan {
+        db: "default".to_string(),
+        table: "t".to_string(),
+        table_id: 1,
+        table_version: None,
+        schema: Arc::new(DataSchema::new_from(fields, meta)),
+        parts: vec![],
+        statistics: Statistics::default(),
+        description: "".to_string(),
+        scan_plan: Arc::new(ScanPlan::empty()),
+        remote: true,
+    })
+}
+
+fn project(source: &PlanNode, expr: Expression) -> Result<PlanNode> {
+    PlanBuilder::from(source).project(&[expr])?.build()
+}
+
+#[test]
+fn test_result_cache_key() -> Result<()> {
+    let plan = project(&source_plan(Some("_ss/1")), col("a"))?;
+    let key = ResultCacheKey::try_create(&plan)?.unwrap();
+    assert_eq!(key.tables, vec![1]);
+    assert_eq!(Some(key.clone()), ResultCacheKey::try_create(&plan)?);
+
+    // a new snapshot of the table
+    let plan = project(&source_plan(Some("_ss/2")), col("a"))?;
+    let new_key = ResultCacheKey::try_create(&plan)?.unwrap();
+    assert!(key.hash != new_key.hash);
+
+    // the source without snapshot
+    let plan = project(&source_plan(None), col("a"))?;
+    assert_eq!(None, ResultCacheKey::try_create(&plan)?);
+
+    // non-deterministic functions
+    for op in ["rand", "now", "today"] {
+        let expr = Expression::ScalarFunction {
+            op: op.to_string(),
+            args: vec![],
+        };
+        let plan = project(&source_plan(Some("_ss/1")), expr)?;
+        assert_eq!(None, ResultCacheKey::try_create(&plan)?);
+    }
+
+    Ok(())
+}
+
+fn test_cache(cache: QueryResultCache) -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1u64, 2])]);
+    let key = ResultCacheKey {
+        hash: "a".to_string(),
+        tables: vec![1, 2],
+    };
+    let ttl = Duration::from_secs(60);
+
+    assert!(cache.get(&key, ttl).is_none());
+    cache.put(&key, schema, vec![block]);
+
+    let (_, blocks) = cache.get(&key, ttl).unwrap();
+    let expected = vec!["+---+", "| a |", "+---+", "| 1 |", "| 2 |", "+---+"];
+    assert_blocks_eq(expected, &blocks);
+
+    // expired
+    assert!(cache.get(&key, Duration::from_secs(0)).is_none());
+
+    // the results reading other tables are kept
+    cache.invalidate_tables(&[3]);
+    assert!(cache.get(&key, ttl).is_some());
+    cache.invalidate_tables(&[2]);
+    assert!(cache.get(&key, ttl).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_result_cache_in_memory() -> Result<()> {
+    test_cache(QueryResultCache::try_create(1024 * 1024, "")?)
+}
+
+#[test]
+fn test_result_cache_on_disk() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path().to_str().unwrap();
+    test_cache(QueryResultCache::try_create(1024 * 1024, dir)?)
+}
@@ -16,6 +16,7 @@ use std::net::SocketAddr;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::Mutex;
 use futures::channel::oneshot::Sender;
@@ -28,15 +29,18 @@ use crate::sessions::DatabendQueryContext;
 use crate::sessions::DatabendQueryContextRef;
 use crate::sessions::SessionManagerRef;
 use crate::sessions::Settings;
+use crate::sessions::Transaction;
 
 pub(in crate::sessions) struct MutableStatus {
     pub(in crate::sessions) abort: bool,
     pub(in crate::sessions) current_database: String,
+    pub(in crate::sessions) current_user: Option<String>,
     pub(in crate::sessions) session_settings: Arc<Settings>,
     #[allow(unused)]
     pub(in crate::sessions) client_host: Option<SocketAddr>,
     pub(in crate::sessions) io_shutdown_tx: Option<Sender<Sender<()>>>,
     pub(in crate::sessions) context_shared: Option<Arc<DatabendQueryContextShared>>,
+    pub(in crate::sessions) transaction: Option<Arc<Transaction>>,
 }
 
 #[derive(Clone)]
@@ -65,10 +69,12 @@ impl Session {
             mutable_state: Arc::new(Mutex::new(MutableStatus {
                 abort: false,
                 current_database: String::from("default"),
+                current_user: None,
                 session_settings: Settings::try_create()?,
                 client_host: None,
                 io_shutdown_tx: None,
                 context_shared: None,
+                transaction: None,
             })),
         }))
     }
@@ -170,6 +176,16 @@ impl Session {
         inner.current_database.clone()
     }
 
+    pub fn set_current_user(self: &Arc<Self>, user: String) {
+        let mut inner = self.mutable_state.lock();
+        inner.current_user = Some(user);
+    }
+
+    pub fn get_current_user(self: &Arc<Self>) -> Option<String> {
+        let inner = self.mutable_state.lock();
+        inner.current_user.clone()
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.mutable_state.lock().session_settings.clone()
     }
@@ -181,4 +197,24 @@ impl Session {
     pub fn get_catalog(self: &Arc<Self>) -> Arc<DatabaseCatalog> {
         self.sessions.get_catalog()
     }
+
+    pub fn begin_transaction(self: &Arc<Self>) -> Result<()> {
+        let mut inner = self.mutable_state.lock();
+        if inner.transaction.is_some() {
+            return Err(ErrorCode::TransactionError(
+                "There is already a transaction in progress",
+            ));
+        }
+        inner.transaction = Some(Transaction::create());
+        Ok(())
+    }
+
+    pub fn get_transaction(self: &Arc<Self>) -> Option<Arc<Transaction>> {
+        self.mutable_state.lock().transaction.clone()
+    }
+
+    /// End the transaction of the session, if there is one.
+    pub fn take_transaction(self: &Arc<Self>) -> Option<Arc<Transaction>> {
+        self.mutable_state.lock().transaction.take()
+    }
 }
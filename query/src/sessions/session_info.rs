@@ -24,10 +24,13 @@ pub struct ProcessInfo {
     pub typ: String,
     pub state: String,
     pub database: String,
+    pub user: Option<String>,
     #[allow(unused)]
     pub settings: Arc<Settings>,
     pub client_address: Option<SocketAddr>,
     pub session_extra_info: Option<String>,
+    pub resource_group: Option<String>,
+    pub memory_usage: u64,
 }
 
 impl Session {
@@ -42,20 +45,35 @@ impl Session {
             typ: self.typ.clone(),
             state: self.process_state(status),
             database: status.current_database.clone(),
+            user: status.current_user.clone(),
             settings: status.session_settings.clone(),
             client_address: status.client_host,
             session_extra_info: self.process_extra_info(status),
+            resource_group: Session::process_resource_group(status),
+            memory_usage: Session::process_memory_usage(status),
         }
     }
 
     fn process_state(self: &Arc<Self>, status: &MutableStatus) -> String {
-        match status.context_shared {
+        match &status.context_shared {
             _ if status.abort => String::from("Aborting"),
             None => String::from("Idle"),
+            Some(context_shared) if context_shared.is_queued() => String::from("Queued"),
             Some(_) => String::from("Query"),
         }
     }
 
+    fn process_resource_group(status: &MutableStatus) -> Option<String> {
+        let context_shared = status.context_shared.as_ref();
+        let resource_group = context_shared.and_then(|shared| shared.get_resource_group());
+        resource_group.map(|group| group.get_info().name.clone())
+    }
+
+    fn process_memory_usage(status: &MutableStatus) -> u64 {
+        let context_shared = status.context_shared.as_ref();
+        context_shared.map_or(0, |shared| shared.get_memory_tracker().get_usage())
+    }
+
     fn process_extra_info(self: &Arc<Self>, status: &MutableStatus) -> Option<String> {
         match self.typ.as_str() {
             "RPCSession" => Session::rpc_extra_info(status),
@@ -57,6 +57,16 @@ impl Session {
         if self.ref_count.fetch_sub(1, Ordering::Release) == 1 {
             std::sync::atomic::fence(Acquire);
             log::debug!("Destroy session {}", self.id);
+
+            // the uncommitted writes are discarded when the client goes away
+            if let Some(transaction) = self.take_transaction() {
+                match common_base::tokio::runtime::Handle::try_current() {
+                    Ok(handle) => {
+                        handle.spawn(async move { transaction.rollback().await });
+                    }
+                    Err(_) => log::warn!("Cannot discard the transaction of session {}", self.id),
+                }
+            }
             self.sessions.destroy_session(&self.id);
         }
     }
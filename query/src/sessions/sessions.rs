@@ -17,21 +17,29 @@ use std::collections::hash_map::Entry::Vacant;
 use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
 use common_base::tokio;
 use common_base::tokio::sync::mpsc::Receiver;
+use common_base::Runtime;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_infallible::RwLock;
+use common_management::ResourceGroupMgr;
 use futures::future::Either;
 use metrics::counter;
 
 use crate::catalogs::impls::DatabaseCatalog;
 use crate::catalogs::Catalog;
 use crate::clusters::ClusterDiscoveryRef;
+use crate::common::StoreApiProvider;
 use crate::configs::Config;
 use crate::datasources::database::example::ExampleDatabaseEngine;
+use crate::interpreters::InterpreterQueryLog;
+use crate::sessions::query_log::QueryLog;
+use crate::sessions::resource_group::ResourceGroups;
+use crate::sessions::result_cache::QueryResultCache;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
 
@@ -42,6 +50,11 @@ pub struct SessionManager {
 
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
+    pub(in crate::sessions) query_log: Arc<QueryLog>,
+    pub(in crate::sessions) result_cache: Arc<QueryResultCache>,
+    pub(in crate::sessions) resource_groups: Arc<ResourceGroups>,
+    // Runs the periodical flush of the query logs if the `query_log_table` is configured.
+    pub(in crate::sessions) query_log_flusher: Option<Runtime>,
 }
 
 pub type SessionManagerRef = Arc<SessionManager>;
@@ -53,13 +66,44 @@ impl SessionManager {
         catalog.register_db_engine("example", Arc::new(ExampleDatabaseEngine::create()))?;
 
         let max_active_sessions = conf.query.max_active_sessions as usize;
-        Ok(Arc::new(SessionManager {
+        let query_log = Arc::new(QueryLog::create(conf.query.query_log_size as usize));
+        let result_cache = Arc::new(QueryResultCache::try_create(
+            conf.query.result_cache_size,
+            &conf.query.result_cache_dir,
+        )?);
+        let kv_api = StoreApiProvider::new(&conf).sync_try_get_kv_client()?;
+        let resource_group_api = Arc::new(ResourceGroupMgr::new(kv_api, &conf.query.tenant));
+        let resource_groups = Arc::new(ResourceGroups::try_create_with_api(
+            &conf.query.resource_groups,
+            resource_group_api,
+        )?);
+        let query_log_flusher = match conf.query.query_log_table.is_empty() {
+            true => None,
+            false => Some(Runtime::with_worker_threads(1)?),
+        };
+        let flush_interval = Duration::from_millis(conf.query.query_log_flush_interval_ms.max(1));
+
+        let sessions = Arc::new(SessionManager {
             catalog,
             conf,
             discovery,
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
-        }))
+            query_log,
+            result_cache,
+            resource_groups,
+            query_log_flusher,
+        });
+
+        if let Some(runtime) = &sessions.query_log_flusher {
+            let weak_sessions = Arc::downgrade(&sessions);
+            runtime.spawn(Self::flush_query_log_periodically(
+                weak_sessions,
+                flush_interval,
+            ));
+        }
+
+        Ok(sessions)
     }
 
     pub fn get_conf(&self) -> &Config {
@@ -74,6 +118,18 @@ impl SessionManager {
         self.catalog.clone()
     }
 
+    pub fn get_query_log(&self) -> Arc<QueryLog> {
+        self.query_log.clone()
+    }
+
+    pub fn get_result_cache(&self) -> Arc<QueryResultCache> {
+        self.result_cache.clone()
+    }
+
+    pub fn get_resource_groups(&self) -> Arc<ResourceGroups> {
+        self.resource_groups.clone()
+    }
+
     pub fn create_session(self: &Arc<Self>, typ: impl Into<String>) -> Result<SessionRef> {
         counter!(super::metrics::METRIC_SESSION_CONNECT_NUMBERS, 1);
 
@@ -135,6 +191,7 @@ impl SessionManager {
     }
 
     pub fn shutdown(self: &Arc<Self>, signal: Option<Receiver<()>>) -> impl Future<Output = ()> {
+        let sessions = self.clone();
         let active_sessions = self.active_sessions.clone();
         async move {
             log::info!("Waiting for current connections to close.");
@@ -143,6 +200,7 @@ impl SessionManager {
 
                 for _index in 0..5 {
                     if SessionManager::destroy_idle_sessions(&active_sessions) {
+                        SessionManager::flush_query_log(sessions).await;
                         return;
                     }
 
@@ -160,6 +218,24 @@ impl SessionManager {
                 .read()
                 .values()
                 .for_each(Session::force_kill_session);
+            SessionManager::flush_query_log(sessions).await;
+        }
+    }
+
+    async fn flush_query_log_periodically(sessions: Weak<SessionManager>, interval: Duration) {
+        loop {
+            tokio::time::sleep(interval).await;
+            match sessions.upgrade() {
+                None => break,
+                Some(sessions) => SessionManager::flush_query_log(sessions).await,
+            }
+        }
+    }
+
+    async fn flush_query_log(sessions: SessionManagerRef) {
+        if let Err(cause) = InterpreterQueryLog::flush(sessions.clone()).await {
+            let table = &sessions.conf.query.query_log_table;
+            log::warn!("Cannot write the query logs into {}: {}", table, cause);
         }
     }
 
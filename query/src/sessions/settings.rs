@@ -31,7 +31,18 @@ impl Settings {
         ("max_threads", u64, 16, "The maximum number of threads to execute the request. By default, it is determined automatically."),
         ("flight_client_timeout", u64, 60, "Max duration the flight client request is allowed to take in seconds. By default, it is 60 seconds"),
         ("min_distributed_rows", u64, 100000000, "Minimum distributed read rows. In cluster mode, when read rows exceeds this value, the local table converted to distributed query."),
-        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query.")
+        ("min_distributed_bytes", u64, 500 * 1024 * 1024, "Minimum distributed read bytes. In cluster mode, when read bytes exceeds this value, the local table converted to distributed query."),
+        ("timezone", String, "UTC".to_string(), "Timezone used to render DateTime values, and to evaluate and parse them in the date and time functions. By default, it is UTC."),
+        ("enable_cte_materialization", u64, 0, "Materialize a non-recursive CTE once and share the result among its references, instead of inlining the CTE query into each reference. By default, it is 0 (inline)."),
+        ("max_recursive_cte_iterations", u64, 1000, "Maximum number of iterations to evaluate a recursive CTE. By default, it is 1000."),
+        ("enable_query_profile", u64, 0, "Record the output rows, bytes and elapsed time of each processor into system.query_profile. By default, it is 0."),
+        ("enable_query_result_cache", u64, 0, "Reuse the cached result of a previous SELECT with the same plan and the same table snapshots. By default, it is 0."),
+        ("query_result_cache_ttl", u64, 300, "Seconds the cached result of a SELECT may be reused for. By default, it is 300 seconds."),
+        ("resource_group", String, "".to_string(), "Resource group to run the queries of the session in, it must list the current user unless it is the 'default' group. By default, it is chosen by the current user, then the 'default' group if any."),
+        ("query_priority", u64, 0, "Priority of the queries of the session in the queue of the resource group, a higher priority is started first, up to the max_query_priority of the group. By default, it is 0."),
+        ("format_field_delimiter", String, ",".to_string(), "Field delimiter of the CSV format in INSERT INTO ... FORMAT. By default, it is ','."),
+        ("format_skip_header", u64, 0, "Skip the first line of the CSV and TSV formats in INSERT INTO ... FORMAT. By default, it is 0."),
+        ("format_null", String, "\\N".to_string(), "The representation of NULL in the CSV and TSV formats in INSERT INTO ... FORMAT. By default, it is \\N.")
     }
 
     pub fn try_create() -> Result<Arc<Settings>> {
@@ -215,12 +226,12 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_set_string(&self, key: &'static str, val: &str, desc: &str) -> Result<()> {
+    pub fn try_set_string(&self, key: &'static str, val: String, desc: &str) -> Result<()> {
         let mut settings = self.settings.write();
-        let default_value = val;
+        let default_value = val.clone();
         let setting_val = DataValue::Struct(vec![
-            DataValue::String(Some(val.as_bytes().to_vec())),
-            DataValue::String(Some(default_value.as_bytes().to_vec())),
+            DataValue::String(Some(val.into_bytes())),
+            DataValue::String(Some(default_value.into_bytes())),
             DataValue::String(Some(desc.as_bytes().to_vec())),
         ]);
         settings.insert(key, setting_val);
@@ -228,7 +239,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_update_string(&self, key: &'static str, val: &str) -> Result<()> {
+    pub fn try_update_string(&self, key: &'static str, val: String) -> Result<()> {
         let mut settings = self.settings.write();
         let setting_val = settings
             .get(key)
@@ -236,7 +247,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             let v = DataValue::Struct(vec![
-                DataValue::String(Some(val.as_bytes().to_vec())),
+                DataValue::String(Some(val.into_bytes())),
                 values[1].clone(),
                 values[2].clone(),
             ]);
@@ -246,7 +257,7 @@ impl SettingsBase {
     }
 
     #[allow(unused)]
-    pub fn try_get_string(&self, key: &str) -> Result<Vec<u8>> {
+    pub fn try_get_string(&self, key: &str) -> Result<String> {
         let settings = self.settings.read();
         let setting_val = settings
             .get(key)
@@ -254,7 +265,7 @@ impl SettingsBase {
 
         if let DataValue::Struct(values) = setting_val {
             if let DataValue::String(Some(result)) = values[0].clone() {
-                return Ok(result);
+                return Ok(String::from_utf8_lossy(&result).to_string());
             }
         }
 
@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::configs::config::DATABEND_COMMIT_VERSION;
+use crate::sessions::DatabendQueryContextRef;
+
+/// The MySQL system variables the drivers and the BI tools read on connect, by `SELECT @@name`
+/// and `SHOW VARIABLES`. They are read-only, `SET` of them is accepted and has no effect.
+const MYSQL_SYSTEM_VARIABLES: [&str; 26] = [
+    "auto_increment_increment",
+    "autocommit",
+    "character_set_client",
+    "character_set_connection",
+    "character_set_database",
+    "character_set_results",
+    "character_set_server",
+    "collation_connection",
+    "collation_database",
+    "collation_server",
+    "init_connect",
+    "interactive_timeout",
+    "license",
+    "lower_case_table_names",
+    "max_allowed_packet",
+    "net_buffer_length",
+    "net_write_timeout",
+    "sql_mode",
+    "system_time_zone",
+    "time_zone",
+    "transaction_isolation",
+    "transaction_read_only",
+    "tx_isolation",
+    "tx_read_only",
+    "version",
+    "version_comment",
+];
+
+pub fn is_mysql_system_variable(name: &str) -> bool {
+    MYSQL_SYSTEM_VARIABLES.contains(&name.to_lowercase().as_str())
+}
+
+/// The value of `@@name`, `@@session.name`, `@@global.name` or `@@local.name`, which is a MySQL
+/// system variable or a setting of the session.
+pub fn get_system_variable(ctx: &DatabendQueryContextRef, name: &str) -> Result<DataValue> {
+    let scoped_name = name.trim_start_matches('@').to_lowercase();
+    let variable = ["session.", "global.", "local."]
+        .iter()
+        .find_map(|scope| scoped_name.strip_prefix(scope))
+        .unwrap_or_else(|| scoped_name.as_str());
+
+    if let Some(value) = mysql_system_variable(ctx, variable)? {
+        return Ok(value);
+    }
+
+    setting_values(ctx)
+        .into_iter()
+        .find(|(setting, _)| setting == variable)
+        .map(|(_, value)| value)
+        .ok_or_else(|| ErrorCode::UnknownVariable(format!("Unknown system variable {}", name)))
+}
+
+/// The MySQL system variables followed by the settings of the session.
+pub fn get_system_variables(ctx: &DatabendQueryContextRef) -> Result<Vec<(String, DataValue)>> {
+    let mut variables = Vec::with_capacity(MYSQL_SYSTEM_VARIABLES.len());
+    for name in MYSQL_SYSTEM_VARIABLES {
+        if let Some(value) = mysql_system_variable(ctx, name)? {
+            variables.push((name.to_string(), value));
+        }
+    }
+    variables.extend(setting_values(ctx));
+    Ok(variables)
+}
+
+fn mysql_system_variable(ctx: &DatabendQueryContextRef, name: &str) -> Result<Option<DataValue>> {
+    let string = |value: &str| DataValue::String(Some(value.as_bytes().to_vec()));
+    let number = |value: u64| DataValue::UInt64(Some(value));
+
+    Ok(Some(match name {
+        "auto_increment_increment" => number(1),
+        "autocommit" => number(1),
+        "character_set_client"
+        | "character_set_connection"
+        | "character_set_database"
+        | "character_set_results"
+        | "character_set_server" => string("utf8mb4"),
+        "collation_connection" | "collation_database" | "collation_server" => {
+            string("utf8mb4_general_ci")
+        }
+        "init_connect" => string(""),
+        "interactive_timeout" => number(28800),
+        "license" => string("Apache License 2.0"),
+        "lower_case_table_names" => number(0),
+        "max_allowed_packet" => number(67108864),
+        "net_buffer_length" => number(16384),
+        "net_write_timeout" => number(60),
+        "sql_mode" => string(""),
+        "system_time_zone" => string("UTC"),
+        "time_zone" => string(&ctx.get_settings().get_timezone()?),
+        "transaction_isolation" | "tx_isolation" => string("REPEATABLE-READ"),
+        "transaction_read_only" | "tx_read_only" => number(0),
+        "version" => string(DATABEND_COMMIT_VERSION.as_str()),
+        "version_comment" => string("Databend Query Server"),
+        _ => return Ok(None),
+    }))
+}
+
+fn setting_values(ctx: &DatabendQueryContextRef) -> Vec<(String, DataValue)> {
+    ctx.get_settings()
+        .iter()
+        .filter_map(|setting| match setting {
+            DataValue::Struct(values) if values.len() > 1 => {
+                Some((format!("{:?}", values[0]), values[1].clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_metatypes::MetaId;
+
+use crate::catalogs::Catalog;
+use crate::datasources::dal::DataAccessor;
+use crate::sessions::DatabendQueryContextRef;
+
+/// The writes to a table staged by a transaction, they are invisible to other sessions until COMMIT.
+pub struct StagedTable {
+    pub db: String,
+    pub table: String,
+    pub table_id: MetaId,
+    /// Locations of the staged segments, in the order they are appended.
+    pub segments: Vec<String>,
+    /// All the files written for the staged segments, removed if the transaction is discarded.
+    pub files: Vec<String>,
+    pub data_accessor: Arc<dyn DataAccessor>,
+}
+
+/// Session-level transaction, started by BEGIN and ended by COMMIT or ROLLBACK.
+///
+/// Appends to the tables are staged here instead of being committed one by one,
+/// COMMIT turns them into new snapshots of the tables, which are committed with a single
+/// conditional update of the meta service.
+#[derive(Default)]
+pub struct Transaction {
+    tables: Mutex<Vec<StagedTable>>,
+}
+
+impl Transaction {
+    pub fn create() -> Arc<Transaction> {
+        Arc::new(Transaction::default())
+    }
+
+    /// Stage the segment appended to the table, and the files written for it.
+    pub fn stage(
+        &self,
+        db: &str,
+        table: &str,
+        table_id: MetaId,
+        segment: String,
+        files: Vec<String>,
+        data_accessor: Arc<dyn DataAccessor>,
+    ) {
+        let mut tables = self.tables.lock();
+        match tables.iter_mut().find(|v| v.db == db && v.table == table) {
+            Some(staged) => {
+                staged.segments.push(segment);
+                staged.files.extend(files);
+            }
+            None => tables.push(StagedTable {
+                db: db.to_string(),
+                table: table.to_string(),
+                table_id,
+                segments: vec![segment],
+                files,
+                data_accessor,
+            }),
+        }
+    }
+
+    /// The segments staged for the table, so that the transaction reads its own writes.
+    pub fn staged_segments(&self, db: &str, table: &str) -> Vec<String> {
+        let tables = self.tables.lock();
+        tables
+            .iter()
+            .find(|v| v.db == db && v.table == table)
+            .map(|v| v.segments.clone())
+            .unwrap_or_default()
+    }
+
+    /// Commit the staged writes of all the tables atomically, or none of them.
+    pub async fn commit(&self, ctx: &DatabendQueryContextRef) -> Result<()> {
+        let tables = std::mem::take(&mut *self.tables.lock());
+        if tables.is_empty() {
+            return Ok(());
+        }
+
+        let mut commits = Vec::with_capacity(tables.len());
+        let mut result = Ok(());
+        for staged in &tables {
+            let prepared = match ctx.get_table(&staged.db, &staged.table) {
+                Ok(table) => table.raw().prepare_commit(ctx.clone(), staged).await,
+                Err(cause) => Err(cause),
+            };
+
+            match prepared {
+                Ok(commit) => commits.push(commit),
+                Err(cause) => {
+                    result = Err(cause);
+                    break;
+                }
+            }
+        }
+
+        if result.is_ok() {
+            result = ctx.get_catalog().commit_tables(commits.clone());
+        }
+
+        if result.is_ok() {
+            let table_ids = commits.iter().map(|c| c.table_id).collect::<Vec<_>>();
+            let result_cache = ctx.get_sessions_manager().get_result_cache();
+            result_cache.invalidate_tables(&table_ids);
+        }
+
+        if result.is_err() {
+            // the new snapshots are never referenced, remove them with the staged files
+            for (staged, commit) in tables.iter().zip(commits.iter()) {
+                remove_file(&staged.data_accessor, &commit.new_snapshot).await;
+            }
+            Self::discard_tables(&tables).await;
+        }
+
+        result
+    }
+
+    /// Discard the staged writes of all the tables.
+    pub async fn rollback(&self) {
+        let tables = std::mem::take(&mut *self.tables.lock());
+        Self::discard_tables(&tables).await;
+    }
+
+    async fn discard_tables(tables: &[StagedTable]) {
+        for staged in tables {
+            for file in &staged.files {
+                remove_file(&staged.data_accessor, file).await;
+            }
+        }
+    }
+}
+
+// Files which fail to be removed are left as garbage, they are never referenced by any snapshot.
+async fn remove_file(data_accessor: &Arc<dyn DataAccessor>, path: &str) {
+    if let Err(cause) = data_accessor.remove(path).await {
+        log::warn!("Failed to remove the discarded file {}: {}", path, cause);
+    }
+}
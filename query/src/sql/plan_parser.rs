@@ -13,83 +13,189 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
-use common_infallible::Mutex;
+use common_functions::scalars::DateFunction;
+use common_infallible::RwLock;
 use common_planners::expand_aggregate_arg_exprs;
 use common_planners::expand_wildcard;
 use common_planners::expr_as_column_expr;
 use common_planners::extract_aliases;
 use common_planners::find_aggregate_exprs;
+use common_planners::find_array_join_exprs;
 use common_planners::find_columns_not_satisfy_exprs;
+use common_planners::find_grouping_exprs;
+use common_planners::find_subquery_exprs;
+use common_planners::grouping_to_expr;
+use common_planners::is_grouping_function;
+use common_planners::is_higher_order_function;
 use common_planners::rebase_expr;
 use common_planners::rebase_expr_from_input;
 use common_planners::resolve_aliases_to_exprs;
 use common_planners::sort_to_inner_expr;
 use common_planners::unwrap_alias_exprs;
+use common_planners::AlterTableAction;
+use common_planners::AlterTablePlan;
+use common_planners::CopyIntoPlan;
 use common_planners::CreateDatabasePlan;
+use common_planners::CreateResourceGroupPlan;
 use common_planners::CreateTablePlan;
+use common_planners::CreateViewPlan;
+use common_planners::CteScanPlan;
+use common_planners::CteSource;
+use common_planners::DeletePlan;
 use common_planners::DescribeTablePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::DropResourceGroupPlan;
 use common_planners::DropTablePlan;
 use common_planners::ExplainPlan;
 use common_planners::Expression;
 use common_planners::InsertIntoPlan;
 use common_planners::KillPlan;
+use common_planners::MaterializedViewInfo;
 use common_planners::PlanBuilder;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
 use common_planners::SettingPlan;
 use common_planners::ShowCreateTablePlan;
 use common_planners::TableScanInfo;
+use common_planners::TransactionPlan;
 use common_planners::TruncateTablePlan;
+use common_planners::UnionAllPlan;
+use common_planners::UpdatePlan;
 use common_planners::UseDatabasePlan;
 use common_planners::VarValue;
+use common_planners::MAX_GROUPING_EXPRS;
 use common_streams::Source;
+use common_streams::SourceFactory;
 use common_streams::ValueSource;
 use common_tracing::tracing;
 use nom::FindSubstring;
+use sqlparser::ast::Assignment;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::ColumnOption;
+use sqlparser::ast::Cte;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::Query;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::SetOperator;
 use sqlparser::ast::Statement;
+use sqlparser::ast::TableAlias;
 use sqlparser::ast::TableFactor;
 use sqlparser::ast::UnaryOperator;
+use sqlparser::ast::With;
 
 use crate::catalogs::Catalog;
+use crate::datasources::common::alter_schema;
+use crate::datasources::table::ViewTable;
+use crate::datasources::table::OPT_KEY_MATERIALIZED_VIEW_PREFIX;
 use crate::functions::ContextFunction;
+use crate::sessions::get_system_variable;
 use crate::sessions::DatabendQueryContextRef;
 use crate::sql::sql_statement::DfCreateTable;
 use crate::sql::sql_statement::DfDropDatabase;
 use crate::sql::sql_statement::DfUseDatabase;
+use crate::sql::DfAlterTable;
+use crate::sql::DfAlterTableAction;
+use crate::sql::DfCopy;
 use crate::sql::DfCreateDatabase;
+use crate::sql::DfCreateResourceGroup;
+use crate::sql::DfCreateView;
 use crate::sql::DfDescribeTable;
+use crate::sql::DfDropResourceGroup;
 use crate::sql::DfDropTable;
 use crate::sql::DfExplain;
 use crate::sql::DfHint;
+use crate::sql::DfInsertFormat;
 use crate::sql::DfKillStatement;
 use crate::sql::DfParser;
+use crate::sql::DfSetNames;
+use crate::sql::DfShowColumns;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
+use crate::sql::DfShowFilter;
+use crate::sql::DfShowFullTables;
+use crate::sql::DfShowIndex;
 use crate::sql::DfShowTables;
+use crate::sql::DfShowVariables;
 use crate::sql::DfStatement;
+use crate::sql::DfTransaction;
 use crate::sql::DfTruncateTable;
 use crate::sql::SQLCommon;
 
 pub struct PlanParser {
     ctx: DatabendQueryContextRef,
+    /// The CTEs visible to the query being planned, the innermost WITH clause is the last
+    cte_scopes: RwLock<Vec<HashMap<String, PlanNode>>>,
+    /// Whether the recursive part of a recursive CTE is being planned
+    in_recursive_term: AtomicBool,
+    /// Whether the recursive part references the CTE being defined
+    working_table_referenced: AtomicBool,
+    /// The database of the unqualified table names, the current database by default.
+    /// The query of a view is planned in the database the view is defined in
+    database: Option<String>,
+    /// The tables planned as the given plans instead, used to apply the query of a
+    /// materialized view to the blocks inserted into its source table
+    table_overrides: HashMap<(String, String), PlanNode>,
 }
 
 impl PlanParser {
     pub fn create(ctx: DatabendQueryContextRef) -> Self {
-        Self { ctx }
+        Self {
+            ctx,
+            cte_scopes: RwLock::new(vec![]),
+            in_recursive_term: AtomicBool::new(false),
+            working_table_referenced: AtomicBool::new(false),
+            database: None,
+            table_overrides: HashMap::new(),
+        }
+    }
+
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    pub fn with_table_override(
+        mut self,
+        database: impl Into<String>,
+        table: impl Into<String>,
+        plan: PlanNode,
+    ) -> Self {
+        self.table_overrides
+            .insert((database.into(), table.into()), plan);
+        self
+    }
+
+    /// The plan of the query of a materialized view, the output columns are cast to the
+    /// schema of the target table by position.
+    pub fn build_materialized_view_plan(
+        &self,
+        query: &str,
+        target_schema: &DataSchemaRef,
+    ) -> Result<PlanNode> {
+        let query = Self::parse_view_query(query)?;
+        let plan = self.query_to_plan(&query)?;
+        Self::check_view_target(&plan.schema(), target_schema)?;
+        Self::cast_to_schema(plan, target_schema)
+    }
+
+    fn current_database(&self) -> String {
+        match &self.database {
+            Some(database) => database.clone(),
+            None => self.ctx.get_current_database(),
+        }
     }
 
     pub fn build_from_sql(&self, query: &str) -> Result<PlanNode> {
@@ -127,9 +233,13 @@ impl PlanParser {
             DfStatement::CreateDatabase(v) => self.sql_create_database_to_plan(v),
             DfStatement::DropDatabase(v) => self.sql_drop_database_to_plan(v),
             DfStatement::CreateTable(v) => self.sql_create_table_to_plan(v),
+            DfStatement::CreateView(v) => self.sql_create_view_to_plan(v),
             DfStatement::DescribeTable(v) => self.sql_describe_table_to_plan(v),
             DfStatement::DropTable(v) => self.sql_drop_table_to_plan(v),
+            DfStatement::AlterTable(v) => self.sql_alter_table_to_plan(v),
             DfStatement::TruncateTable(v) => self.sql_truncate_table_to_plan(v),
+            DfStatement::InsertFormat(v) => self.sql_insert_format_to_plan(v),
+            DfStatement::Copy(v) => self.sql_copy_to_plan(v),
             DfStatement::UseDatabase(v) => self.sql_use_database_to_plan(v),
             DfStatement::ShowCreateTable(v) => self.sql_show_create_table_to_plan(v),
             DfStatement::ShowTables(df) => {
@@ -161,12 +271,20 @@ impl PlanParser {
                 };
                 self.build_from_sql(show_sql.as_str())
             }
+            DfStatement::ShowFullTables(v) => self.sql_show_full_tables_to_plan(v),
+            DfStatement::ShowColumns(v) => self.sql_show_columns_to_plan(v),
+            DfStatement::ShowIndex(v) => self.sql_show_index_to_plan(v),
             DfStatement::ShowSettings(_) => self.build_from_sql("SELECT name FROM system.settings"),
+            DfStatement::ShowVariables(v) => self.sql_show_variables_to_plan(v),
+            DfStatement::SetNames(v) => self.sql_set_names_to_plan(v),
             DfStatement::ShowProcessList(_) => {
                 self.build_from_sql("SELECT * FROM system.processes")
             }
             DfStatement::KillQuery(v) => self.sql_kill_query_to_plan(v),
             DfStatement::KillConn(v) => self.sql_kill_connection_to_plan(v),
+            DfStatement::Transaction(v) => self.sql_transaction_to_plan(v),
+            DfStatement::CreateResourceGroup(v) => self.sql_create_resource_group_to_plan(v),
+            DfStatement::DropResourceGroup(v) => self.sql_drop_resource_group_to_plan(v),
         }
     }
 
@@ -189,6 +307,17 @@ impl PlanParser {
                 self.insert_to_plan(table_name, columns, source, &format_sql)
             }
 
+            Statement::Delete {
+                table_name,
+                selection,
+            } => self.delete_to_plan(table_name, selection),
+
+            Statement::Update {
+                table_name,
+                assignments,
+                selection,
+            } => self.update_to_plan(table_name, assignments, selection),
+
             _ => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported statement {:?}",
                 statement
@@ -282,6 +411,33 @@ impl PlanParser {
         }))
     }
 
+    #[tracing::instrument(level = "info", skip(self, transaction), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_transaction_to_plan(&self, transaction: &DfTransaction) -> Result<PlanNode> {
+        Ok(PlanNode::Transaction(TransactionPlan {
+            kind: transaction.kind,
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_resource_group_to_plan(
+        &self,
+        create: &DfCreateResourceGroup,
+    ) -> Result<PlanNode> {
+        Ok(PlanNode::CreateResourceGroup(CreateResourceGroupPlan {
+            if_not_exists: create.if_not_exists,
+            name: create.name.value.clone(),
+            options: create.options.clone(),
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_drop_resource_group_to_plan(&self, drop: &DfDropResourceGroup) -> Result<PlanNode> {
+        Ok(PlanNode::DropResourceGroup(DropResourceGroupPlan {
+            if_exists: drop.if_exists,
+            name: drop.name.value.clone(),
+        }))
+    }
+
     #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn sql_create_table_to_plan(&self, create: &DfCreateTable) -> Result<PlanNode> {
         let mut db = self.ctx.get_current_database();
@@ -294,14 +450,33 @@ impl PlanParser {
             table = create.name.0[1].value.clone();
         }
 
-        let fields = create
-            .columns
-            .iter()
-            .map(|column| {
-                SQLCommon::make_data_type(&column.data_type)
-                    .map(|data_type| DataField::new(&column.name.value, data_type, false))
-            })
-            .collect::<Result<Vec<DataField>>>()?;
+        if let Some(like) = &create.like {
+            return self.create_table_like_to_plan(create, db, table, like);
+        }
+
+        let (schema, as_select) = match &create.query {
+            None => {
+                let fields = create
+                    .columns
+                    .iter()
+                    .map(|column| {
+                        SQLCommon::make_data_type(&column.data_type)
+                            .map(|data_type| DataField::new(&column.name.value, data_type, false))
+                    })
+                    .collect::<Result<Vec<DataField>>>()?;
+                (DataSchemaRefExt::create(fields), None)
+            }
+            Some(query) => {
+                if !create.columns.is_empty() {
+                    return Result::Err(ErrorCode::SyntaxException(
+                        "CREATE TABLE ... AS SELECT does not support column definitions, \
+                        the columns are inferred from the query",
+                    ));
+                }
+                let select = self.query_to_plan(query)?;
+                (select.schema(), Some(Box::new(select)))
+            }
+        };
 
         let mut options = HashMap::new();
         for p in create.options.iter() {
@@ -314,17 +489,154 @@ impl PlanParser {
             );
         }
 
-        let schema = DataSchemaRefExt::create(fields);
         Ok(PlanNode::CreateTable(CreateTablePlan {
             if_not_exists: create.if_not_exists,
             db,
             table,
             schema,
-            engine: create.engine.clone(),
+            // The rows of the query are kept by the default engine
+            engine: create
+                .engine
+                .clone()
+                .unwrap_or_else(|| match as_select.is_some() {
+                    true => "FUSE".to_string(),
+                    false => "NULL".to_string(),
+                }),
+            options,
+            as_select,
+        }))
+    }
+
+    /// `CREATE TABLE name LIKE other`, the engine of other is kept if ENGINE is not specified.
+    fn create_table_like_to_plan(
+        &self,
+        create: &DfCreateTable,
+        db: String,
+        table: String,
+        like: &ObjectName,
+    ) -> Result<PlanNode> {
+        let (like_db, like_table) = self.resolve_table_name(like);
+        let like_meta = self.ctx.get_table(&like_db, &like_table)?;
+        let like_raw = like_meta.raw();
+        if like_raw.as_any().is::<ViewTable>() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Cannot create table {}.{} like {}.{}, it is a view",
+                db, table, like_db, like_table
+            )));
+        }
+
+        // The schema meta and the index of the materialized views belong to the other table
+        let schema = DataSchemaRefExt::create(like_raw.schema()?.fields().clone());
+        let options = like_raw
+            .options()
+            .into_iter()
+            .filter(|(key, _)| !key.starts_with(OPT_KEY_MATERIALIZED_VIEW_PREFIX))
+            .collect();
+
+        Ok(PlanNode::CreateTable(CreateTablePlan {
+            if_not_exists: create.if_not_exists,
+            db,
+            table,
+            schema,
+            engine: create
+                .engine
+                .clone()
+                .unwrap_or_else(|| like_raw.engine().to_string()),
             options,
+            as_select: None,
+        }))
+    }
+
+    /// DfCreateView to plan, the query is planned in the database of the view to get its schema.
+    #[tracing::instrument(level = "info", skip(self, create), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_create_view_to_plan(&self, create: &DfCreateView) -> Result<PlanNode> {
+        if create.name.0.is_empty() {
+            return Result::Err(ErrorCode::SyntaxException("Create view name is empty"));
+        }
+        let (db, view) = self.resolve_table_name(&create.name);
+        let view_parser = PlanParser::create(self.ctx.clone()).with_database(db.clone());
+        let view_schema = view_parser.query_to_plan(&create.query)?.schema();
+
+        let materialized = match create.materialized {
+            false => None,
+            true => {
+                let source = Self::materialized_view_source(&create.query)?;
+                let (source_db, source_table) = view_parser.resolve_table_name(source);
+                let source_meta = self.ctx.get_table(&source_db, &source_table)?;
+                if source_meta.raw().as_any().is::<ViewTable>() {
+                    return Result::Err(ErrorCode::BadArguments(format!(
+                        "The source of a materialized view must be a table, but {}.{} is a view",
+                        source_db, source_table
+                    )));
+                }
+
+                let (target_db, target_table, target_engine) = match &create.to {
+                    Some(to) => {
+                        let (target_db, target_table) = self.resolve_table_name(to);
+                        let target = self.ctx.get_table(&target_db, &target_table)?;
+                        Self::check_view_target(&view_schema, &target.raw().schema()?)?;
+                        (target_db, target_table, None)
+                    }
+                    // The inner table is created with the view
+                    None => {
+                        let engine = create.engine.clone().unwrap_or_else(|| "FUSE".to_string());
+                        (db.clone(), format!(".inner.{}", view), Some(engine))
+                    }
+                };
+                if (&target_db, &target_table) == (&source_db, &source_table) {
+                    return Result::Err(ErrorCode::BadArguments(
+                        "The target of a materialized view can't be its source table",
+                    ));
+                }
+
+                Some(MaterializedViewInfo {
+                    source_db,
+                    source_table,
+                    target_db,
+                    target_table,
+                    target_engine,
+                })
+            }
+        };
+
+        Ok(PlanNode::CreateView(CreateViewPlan {
+            if_not_exists: create.if_not_exists,
+            db,
+            view,
+            query: create.query.to_string(),
+            view_schema,
+            materialized,
         }))
     }
 
+    /// The query of a materialized view reads from exactly one table, the source table.
+    fn materialized_view_source(query: &Query) -> Result<&ObjectName> {
+        if let SetExpr::Select(select) = &query.body {
+            if let [from] = select.from.as_slice() {
+                if let TableFactor::Table { name, args, .. } = &from.relation {
+                    if args.is_empty() && from.joins.is_empty() && query.with.is_none() {
+                        return Ok(name);
+                    }
+                }
+            }
+        }
+        Result::Err(ErrorCode::SyntaxException(format!(
+            "The query of a materialized view must read from exactly one table: {}",
+            query
+        )))
+    }
+
+    fn check_view_target(view_schema: &DataSchemaRef, target_schema: &DataSchemaRef) -> Result<()> {
+        if view_schema.fields().len() != target_schema.fields().len() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Number of columns doesn't match, the target table expect {} columns, but the query returns {}",
+                target_schema.fields().len(),
+                view_schema.fields().len()
+            )));
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, show_create), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn sql_show_create_table_to_plan(
         &self,
@@ -381,6 +693,118 @@ impl PlanParser {
         }))
     }
 
+    /// `SHOW VARIABLES` reads `information_schema.session_variables`.
+    pub fn sql_show_variables_to_plan(&self, show: &DfShowVariables) -> Result<PlanNode> {
+        let query = "SELECT VARIABLE_NAME AS Variable_name, VARIABLE_VALUE AS Value \
+            FROM information_schema.session_variables ORDER BY Variable_name";
+        self.show_filter_to_plan(query, &show.filter, "Variable_name")
+    }
+
+    /// `SHOW FULL TABLES` reads `information_schema.tables`.
+    pub fn sql_show_full_tables_to_plan(&self, show: &DfShowFullTables) -> Result<PlanNode> {
+        let db = match &show.database {
+            Some(database) => database.0[0].value.clone(),
+            None => self.current_database(),
+        };
+        let column = format!("Tables_in_{}", db);
+        let query = format!(
+            "SELECT TABLE_NAME AS \"{}\", TABLE_TYPE AS Table_type \
+            FROM information_schema.tables WHERE TABLE_SCHEMA = '{}' ORDER BY TABLE_NAME",
+            column, db
+        );
+        self.show_filter_to_plan(&query, &show.filter, &format!("\"{}\"", column))
+    }
+
+    /// `SHOW [FULL] COLUMNS` reads `information_schema.columns`.
+    pub fn sql_show_columns_to_plan(&self, show: &DfShowColumns) -> Result<PlanNode> {
+        let (db, table) = self.resolve_show_table(&show.table, &show.database)?;
+        let columns = match show.full {
+            true => {
+                "COLUMN_NAME AS Field, COLUMN_TYPE AS Type, NULL AS Collation, \
+                IS_NULLABLE AS \"Null\", COLUMN_KEY AS \"Key\", COLUMN_DEFAULT AS \"Default\", \
+                EXTRA AS Extra, 'select,insert' AS Privileges, COLUMN_COMMENT AS Comment"
+            }
+            false => {
+                "COLUMN_NAME AS Field, COLUMN_TYPE AS Type, IS_NULLABLE AS \"Null\", \
+                COLUMN_KEY AS \"Key\", COLUMN_DEFAULT AS \"Default\", EXTRA AS Extra"
+            }
+        };
+        let query = format!(
+            "SELECT {} FROM information_schema.columns \
+            WHERE TABLE_SCHEMA = '{}' AND TABLE_NAME = '{}' ORDER BY ORDINAL_POSITION",
+            columns, db, table
+        );
+        self.show_filter_to_plan(&query, &show.filter, "Field")
+    }
+
+    /// `SHOW INDEX` reads `information_schema.statistics`, there are no indexes.
+    pub fn sql_show_index_to_plan(&self, show: &DfShowIndex) -> Result<PlanNode> {
+        let (db, table) = self.resolve_show_table(&show.table, &show.database)?;
+        let query = format!(
+            "SELECT TABLE_NAME AS \"Table\", NON_UNIQUE AS Non_unique, INDEX_NAME AS Key_name, \
+            SEQ_IN_INDEX AS Seq_in_index, COLUMN_NAME AS Column_name, INDEX_TYPE AS Index_type, \
+            COMMENT AS Comment \
+            FROM information_schema.statistics WHERE TABLE_SCHEMA = '{}' AND TABLE_NAME = '{}'",
+            db, table
+        );
+        self.build_from_sql(&query)
+    }
+
+    /// `SET NAMES` sets the character sets of the connection, which are always utf8mb4.
+    pub fn sql_set_names_to_plan(&self, set: &DfSetNames) -> Result<PlanNode> {
+        let mut vars = vec![
+            "character_set_client",
+            "character_set_connection",
+            "character_set_results",
+        ]
+        .into_iter()
+        .map(|variable| VarValue {
+            variable: variable.to_string(),
+            value: set.charset.clone(),
+        })
+        .collect::<Vec<_>>();
+        if let Some(collation) = &set.collation {
+            vars.push(VarValue {
+                variable: "collation_connection".to_string(),
+                value: collation.clone(),
+            });
+        }
+        Ok(PlanNode::SetVariable(SettingPlan { vars }))
+    }
+
+    // The table of `SHOW COLUMNS` and `SHOW INDEX`, which must exist.
+    fn resolve_show_table(
+        &self,
+        table: &ObjectName,
+        database: &Option<ObjectName>,
+    ) -> Result<(String, String)> {
+        let (mut db, table) = self.resolve_table_name(table);
+        if let Some(database) = database {
+            db = database.0[0].value.clone();
+        }
+        self.ctx.get_table(&db, &table)?;
+        Ok((db, table))
+    }
+
+    // `LIKE 'pattern'` matches the first column, `WHERE expr` is on the columns of the result.
+    fn show_filter_to_plan(
+        &self,
+        query: &str,
+        filter: &Option<DfShowFilter>,
+        like_column: &str,
+    ) -> Result<PlanNode> {
+        match filter {
+            None => self.build_from_sql(query),
+            Some(DfShowFilter::Like(pattern)) => self.build_from_sql(&format!(
+                "SELECT * FROM ({}) AS t WHERE {} LIKE {}",
+                query, like_column, pattern
+            )),
+            Some(DfShowFilter::Where(expr)) => {
+                self.build_from_sql(&format!("SELECT * FROM ({}) AS t WHERE {}", query, expr))
+            }
+        }
+    }
+
     /// DfDropTable to plan.
     #[tracing::instrument(level = "info", skip(self, drop), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn sql_drop_table_to_plan(&self, drop: &DfDropTable) -> Result<PlanNode> {
@@ -400,6 +824,81 @@ impl PlanParser {
         }))
     }
 
+    /// DfAlterTable to plan, the schema of the table after altered is resolved here.
+    #[tracing::instrument(level = "info", skip(self, alter), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_alter_table_to_plan(&self, alter: &DfAlterTable) -> Result<PlanNode> {
+        if alter.name.0.is_empty() {
+            return Result::Err(ErrorCode::SyntaxException("Alter table name is empty"));
+        }
+        let (db, table) = self.resolve_table_name(&alter.name);
+        let schema = self
+            .ctx
+            .get_catalog()
+            .get_table(&db, &table)?
+            .raw()
+            .schema()?;
+
+        let action = match &alter.action {
+            DfAlterTableAction::AddColumn(column) => {
+                let data_type = SQLCommon::make_data_type(&column.data_type)?;
+                let default = self.column_default_to_value(column, &data_type)?;
+                let field = DataField::new(&column.name.value, data_type, default.is_none());
+                AlterTableAction::AddColumn { field, default }
+            }
+            DfAlterTableAction::DropColumn(name) => AlterTableAction::DropColumn {
+                name: name.value.clone(),
+            },
+            DfAlterTableAction::RenameColumn { old, new } => AlterTableAction::RenameColumn {
+                name: old.value.clone(),
+                new_name: new.value.clone(),
+            },
+            DfAlterTableAction::ModifyColumn(column) => AlterTableAction::ModifyColumn {
+                name: column.name.value.clone(),
+                data_type: SQLCommon::make_data_type(&column.data_type)?,
+            },
+            DfAlterTableAction::RenameTable(new_name) => {
+                if new_name.0.is_empty() {
+                    return Result::Err(ErrorCode::SyntaxException("Rename table name is empty"));
+                }
+                let (new_db, new_table) = self.resolve_table_name(new_name);
+                AlterTableAction::RenameTable { new_db, new_table }
+            }
+        };
+
+        let table_schema = Arc::new(alter_schema(&schema, &action)?);
+        Ok(PlanNode::AlterTable(AlterTablePlan {
+            db,
+            table,
+            action,
+            table_schema,
+        }))
+    }
+
+    /// The DEFAULT of the added column, which must be a constant, cast to the column type.
+    fn column_default_to_value(
+        &self,
+        column: &ColumnDef,
+        data_type: &DataType,
+    ) -> Result<Option<DataValue>> {
+        for option in &column.options {
+            if let ColumnOption::Default(expr) = &option.option {
+                return match self.sql_to_rex(expr, &DataSchema::empty(), None)? {
+                    Expression::Literal { value, .. } => {
+                        let value = DataColumn::Constant(value, 1)
+                            .cast_with_type(data_type)?
+                            .try_get(0)?;
+                        Ok(Some(value))
+                    }
+                    other => Result::Err(ErrorCode::BadArguments(format!(
+                        "DEFAULT of column {} must be a constant, but got: {:?}",
+                        column.name.value, other
+                    ))),
+                };
+            }
+        }
+        Ok(None)
+    }
+
     // DfTruncateTable to plan.
     #[tracing::instrument(level = "info", skip(self, truncate), fields(ctx.id = self.ctx.get_id().as_str()))]
     pub fn sql_truncate_table_to_plan(&self, truncate: &DfTruncateTable) -> Result<PlanNode> {
@@ -415,85 +914,691 @@ impl PlanParser {
             table = truncate.name.0[1].value.clone();
         }
 
-        Ok(PlanNode::TruncateTable(TruncateTablePlan { db, table }))
+        Ok(PlanNode::TruncateTable(TruncateTablePlan { db, table }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, table_name, columns, source), fields(ctx.id = self.ctx.get_id().as_str()))]
+    fn insert_to_plan(
+        &self,
+        table_name: &ObjectName,
+        columns: &[Ident],
+        source: &Option<Box<Query>>,
+        format_sql: &str,
+    ) -> Result<PlanNode> {
+        let mut plan = self.insert_into_table(table_name, columns)?;
+        let mut input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(vec![]);
+
+        if let Some(source) = source {
+            if let sqlparser::ast::SetExpr::Values(_vs) = &source.body {
+                tracing::debug!("{:?}", format_sql);
+                let index = format_sql.find_substring(" VALUES ").unwrap();
+                let values = &format_sql[index + " VALUES ".len()..];
+
+                let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+                let mut source = ValueSource::new(values.as_bytes(), plan.schema(), block_size);
+                let mut blocks = vec![];
+                loop {
+                    let block = source.read()?;
+                    match block {
+                        Some(b) => blocks.push(Ok(b)),
+                        None => break,
+                    }
+                }
+                input_stream = futures::stream::iter(blocks);
+            } else {
+                let select_plan = self.insert_select_to_plan(source, &plan.schema)?;
+                plan.select_plan = Some(Box::new(select_plan));
+            }
+        }
+
+        plan.set_input_stream(Box::pin(input_stream));
+        Ok(PlanNode::InsertInto(plan))
+    }
+
+    /// `INSERT INTO ... FORMAT`, the client sets the input stream of the plan
+    /// with the data parsed in the format.
+    #[tracing::instrument(level = "info", skip(self, insert), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_insert_format_to_plan(&self, insert: &DfInsertFormat) -> Result<PlanNode> {
+        SourceFactory::check_format(&insert.format)?;
+
+        let mut plan = self.insert_into_table(&insert.table_name, &insert.columns)?;
+        plan.format = Some(insert.format.clone());
+        Ok(PlanNode::InsertInto(plan))
+    }
+
+    /// `COPY INTO table FROM 'location'`, the files are listed and loaded by the interpreter.
+    #[tracing::instrument(level = "info", skip(self, copy), fields(ctx.id = self.ctx.get_id().as_str()))]
+    pub fn sql_copy_to_plan(&self, copy: &DfCopy) -> Result<PlanNode> {
+        let insert = self.insert_into_table(&copy.name, &copy.columns)?;
+
+        let mut file_format = copy.file_format.clone();
+        let format = file_format
+            .entry("type".to_string())
+            .or_insert_with(|| "CSV".to_string());
+        SourceFactory::check_format(format)?;
+
+        let validation_mode = match &copy.validation_mode {
+            None => false,
+            Some(mode) if mode.to_uppercase() == "RETURN_ERRORS" => true,
+            Some(mode) => {
+                return Result::Err(ErrorCode::SyntaxException(format!(
+                    "Unsupported VALIDATION_MODE: {}, expect RETURN_ERRORS",
+                    mode
+                )))
+            }
+        };
+
+        Ok(PlanNode::CopyInto(CopyIntoPlan {
+            db_name: insert.db_name,
+            tbl_name: insert.tbl_name,
+            tbl_id: insert.tbl_id,
+            schema: insert.schema,
+            location: copy.location.clone(),
+            pattern: copy.pattern.clone(),
+            file_format,
+            validation_mode,
+            force: copy.force,
+        }))
+    }
+
+    /// The insert plan of the table without input, the schema has the insert columns.
+    fn insert_into_table(
+        &self,
+        table_name: &ObjectName,
+        columns: &[Ident],
+    ) -> Result<InsertIntoPlan> {
+        let (db_name, tbl_name) = self.resolve_table_name(table_name);
+        let table = self.ctx.get_catalog().get_table(&db_name, &tbl_name)?;
+
+        let mut schema = table.raw().schema()?;
+        let tbl_id = table.meta_id();
+
+        if !columns.is_empty() {
+            let fields = columns
+                .iter()
+                .map(|ident| schema.field_with_name(&ident.value).map(|v| v.clone()))
+                .collect::<Result<Vec<_>>>()?;
+
+            schema = DataSchemaRefExt::create(fields);
+        }
+
+        Ok(InsertIntoPlan {
+            db_name,
+            tbl_name,
+            tbl_id,
+            schema,
+            select_plan: None,
+            format: None,
+            copied_files: None,
+            input_stream: InsertIntoPlan::empty_stream(),
+        })
+    }
+
+    /// The database and the name of the table, in the current database by default.
+    fn resolve_table_name(&self, table_name: &ObjectName) -> (String, String) {
+        let mut db_name = self.current_database();
+        let mut tbl_name = table_name.0[0].value.clone();
+
+        if table_name.0.len() > 1 {
+            db_name = tbl_name;
+            tbl_name = table_name.0[1].value.clone();
+        }
+        (db_name, tbl_name)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, table_name, selection), fields(ctx.id = self.ctx.get_id().as_str()))]
+    fn delete_to_plan(
+        &self,
+        table_name: &ObjectName,
+        selection: &Option<sqlparser::ast::Expr>,
+    ) -> Result<PlanNode> {
+        let (db_name, tbl_name) = self.resolve_table_name(table_name);
+        let table = self.ctx.get_catalog().get_table(&db_name, &tbl_name)?;
+        let schema = table.raw().schema()?;
+
+        Ok(PlanNode::Delete(DeletePlan {
+            db_name,
+            tbl_name,
+            tbl_id: table.meta_id(),
+            selection: self.mutation_selection_to_rex(selection, &schema)?,
+        }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self, table_name, assignments, selection), fields(ctx.id = self.ctx.get_id().as_str()))]
+    fn update_to_plan(
+        &self,
+        table_name: &ObjectName,
+        assignments: &[Assignment],
+        selection: &Option<sqlparser::ast::Expr>,
+    ) -> Result<PlanNode> {
+        let (db_name, tbl_name) = self.resolve_table_name(table_name);
+        let table = self.ctx.get_catalog().get_table(&db_name, &tbl_name)?;
+        let schema = table.raw().schema()?;
+
+        let mut update_list = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            let column = assignment.id.value.clone();
+            let field = schema.field_with_name(&column)?;
+            if update_list.iter().any(|(name, _)| name == &column) {
+                return Result::Err(ErrorCode::BadArguments(format!(
+                    "Column {} is updated more than once",
+                    column
+                )));
+            }
+
+            let value = self.sql_to_rex(&assignment.value, &schema, None)?;
+            Self::check_mutation_expr(&value, &schema)?;
+            let value = Expression::Cast {
+                expr: Box::new(value),
+                data_type: field.data_type().clone(),
+            };
+            update_list.push((column, value));
+        }
+
+        Ok(PlanNode::Update(UpdatePlan {
+            db_name,
+            tbl_name,
+            tbl_id: table.meta_id(),
+            update_list,
+            selection: self.mutation_selection_to_rex(selection, &schema)?,
+        }))
+    }
+
+    fn mutation_selection_to_rex(
+        &self,
+        selection: &Option<sqlparser::ast::Expr>,
+        schema: &DataSchemaRef,
+    ) -> Result<Option<Expression>> {
+        match selection {
+            None => Ok(None),
+            Some(selection) => {
+                let expr = self.sql_to_rex(selection, schema, None)?;
+                Self::check_mutation_expr(&expr, schema)?;
+                Ok(Some(expr))
+            }
+        }
+    }
+
+    // The expressions of DELETE and UPDATE are evaluated on each block of the table,
+    // they can only refer to the columns of the table.
+    fn check_mutation_expr(expr: &Expression, schema: &DataSchemaRef) -> Result<()> {
+        let exprs = [expr.clone()];
+        if !find_aggregate_exprs(&exprs).is_empty()
+            || !find_array_join_exprs(&exprs).is_empty()
+            || !find_subquery_exprs(&exprs).is_empty()
+        {
+            return Result::Err(ErrorCode::SyntaxException(format!(
+                "Aggregate functions, arrayJoin and subqueries are not allowed in DELETE and UPDATE: {:?}",
+                expr
+            )));
+        }
+
+        expr.to_data_field(schema)?;
+        Ok(())
+    }
+
+    /// The source query of `INSERT INTO ... SELECT`, the output columns are matched to the
+    /// insert columns by position and cast to their types.
+    fn insert_select_to_plan(&self, query: &Query, schema: &DataSchemaRef) -> Result<PlanNode> {
+        let plan = self.query_to_plan(query)?;
+        let input_schema = plan.schema();
+        if input_schema.fields().len() != schema.fields().len() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Number of columns doesn't match, insert expect {} columns, but the query returns {}",
+                schema.fields().len(),
+                input_schema.fields().len()
+            )));
+        }
+
+        Self::cast_to_schema(plan, schema)
+    }
+
+    /// Project the output columns of the plan to the schema by position, renaming and
+    /// casting the columns if necessary. The number of columns must be the same.
+    fn cast_to_schema(plan: PlanNode, schema: &DataSchemaRef) -> Result<PlanNode> {
+        let input_schema = plan.schema();
+        if &input_schema == schema {
+            return Ok(plan);
+        }
+
+        let exprs = schema
+            .fields()
+            .iter()
+            .zip(input_schema.fields().iter())
+            .map(|(field, input_field)| {
+                let column = Expression::Column(input_field.name().clone());
+                match (
+                    field.name() == input_field.name(),
+                    field.data_type() == input_field.data_type(),
+                ) {
+                    (true, true) => column,
+                    (false, true) => Expression::Alias(field.name().clone(), Box::new(column)),
+                    (_, false) => Expression::Alias(
+                        field.name().clone(),
+                        Box::new(Expression::Cast {
+                            expr: Box::new(column),
+                            data_type: field.data_type().clone(),
+                        }),
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match plan {
+            PlanNode::Select(select) => PlanBuilder::from(select.input.as_ref())
+                .project(&exprs)?
+                .select()?
+                .build(),
+            other => PlanBuilder::from(&other).project(&exprs)?.select()?.build(),
+        }
+    }
+
+    /// Generate a logic plan from an SQL query
+    pub fn query_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
+        let with = match &query.with {
+            None => return self.query_body_to_plan(query),
+            Some(with) => with,
+        };
+
+        self.cte_scopes.write().push(HashMap::new());
+        let plan = self
+            .with_to_scope(with)
+            .and_then(|_| self.query_body_to_plan(query));
+        self.cte_scopes.write().pop();
+        plan
+    }
+
+    fn query_body_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
+        match &query.body {
+            SetExpr::Select(s) => {
+                self.select_to_plan(s.as_ref(), &query.limit, &query.offset, &query.order_by)
+            }
+            SetExpr::SetOperation { .. } => {
+                // ORDER BY and LIMIT apply to the result of the set operation
+                let plan = self.set_expr_to_plan(&query.body)?;
+                self.set_operation_order_by_limit(plan, query)
+            }
+            _ => Result::Err(ErrorCode::UnImplement(format!(
+                "Query {} is not yet implemented",
+                query.body
+            ))),
+        }
+    }
+
+    fn set_expr_to_plan(&self, set_expr: &SetExpr) -> Result<PlanNode> {
+        match set_expr {
+            SetExpr::Select(s) => self.select_to_plan(s.as_ref(), &None, &None, &[]),
+            SetExpr::Query(query) => self.query_to_plan(query),
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => self.set_operation_to_plan(op, *all, left, right),
+            _ => Result::Err(ErrorCode::UnImplement(format!(
+                "Query {} is not yet implemented",
+                set_expr
+            ))),
+        }
+    }
+
+    /// Plan `UNION [ALL | DISTINCT]`, `INTERSECT` and `EXCEPT`, the output columns take the
+    /// names of the left side and the common types of both sides.
+    /// The distinct set operations group by all the columns with the group-by hash table.
+    fn set_operation_to_plan(
+        &self,
+        op: &SetOperator,
+        all: bool,
+        left: &SetExpr,
+        right: &SetExpr,
+    ) -> Result<PlanNode> {
+        let left = self.set_expr_to_plan(left)?;
+        let right = self.set_expr_to_plan(right)?;
+        let (left_schema, right_schema) = (left.schema(), right.schema());
+        if left_schema.fields().len() != right_schema.fields().len() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Number of columns doesn't match, the left side of {} returns {} columns, but the right side returns {}",
+                op,
+                left_schema.fields().len(),
+                right_schema.fields().len()
+            )));
+        }
+
+        let fields = left_schema
+            .fields()
+            .iter()
+            .zip(right_schema.fields().iter())
+            .map(|(left_field, right_field)| {
+                let (left_type, right_type) = (left_field.data_type(), right_field.data_type());
+                let nullable = left_field.is_nullable()
+                    || right_field.is_nullable()
+                    || left_type == &DataType::Null
+                    || right_type == &DataType::Null;
+                Ok(DataField::new(
+                    left_field.name(),
+                    merge_types(left_type, right_type)?,
+                    nullable,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let schema = DataSchemaRefExt::create(fields);
+
+        match (op, all) {
+            (SetOperator::Union, true) => Self::union_all(vec![left, right], &schema),
+            (SetOperator::Union, false) => {
+                let plan = Self::union_all(vec![left, right], &schema)?;
+                let columns = Self::schema_columns(&schema);
+                let plan = self.aggregate(&plan, &[], &columns)?;
+                self.project(&plan, &columns)
+            }
+            (SetOperator::Intersect, false) | (SetOperator::Except, false) => {
+                self.intersect_or_except(op, left, right, &schema)
+            }
+            (_, true) => Result::Err(ErrorCode::UnImplement(format!(
+                "{} ALL is not yet implemented",
+                op
+            ))),
+        }
+    }
+
+    fn union_all(inputs: Vec<PlanNode>, schema: &DataSchemaRef) -> Result<PlanNode> {
+        let inputs = inputs
+            .into_iter()
+            .map(|input| Self::cast_to_schema(input, schema).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(PlanNode::UnionAll(UnionAllPlan {
+            schema: schema.clone(),
+            inputs,
+        }))
+    }
+
+    /// Tag the rows of the left and the right side with `_set_side` 1 and 2, group by all
+    /// the columns, then keep the groups found on both sides for INTERSECT, or only on the
+    /// left side for EXCEPT.
+    fn intersect_or_except(
+        &self,
+        op: &SetOperator,
+        left: PlanNode,
+        right: PlanNode,
+        schema: &DataSchemaRef,
+    ) -> Result<PlanNode> {
+        let columns = Self::schema_columns(schema);
+        let side_column = "_set_side";
+
+        let mut fields = schema.fields().clone();
+        fields.push(DataField::new(side_column, DataType::UInt8, false));
+        let tagged_schema = DataSchemaRefExt::create(fields);
+
+        let tag_side = |input: PlanNode, side: u8| -> Result<PlanNode> {
+            let input = Self::cast_to_schema(input, schema)?;
+            let mut exprs = columns.clone();
+            exprs.push(Expression::Alias(
+                side_column.to_string(),
+                Box::new(Expression::create_literal(DataValue::UInt8(Some(side)))),
+            ));
+            self.project(&input, &exprs)
+        };
+        let plan = Self::union_all(
+            vec![tag_side(left, 1)?, tag_side(right, 2)?],
+            &tagged_schema,
+        )?;
+
+        let side_aggregate = |op: &str| Expression::AggregateFunction {
+            op: op.to_string(),
+            distinct: false,
+            params: vec![],
+            args: vec![Expression::Column(side_column.to_string())],
+        };
+        let (min_side, max_side) = (side_aggregate("min"), side_aggregate("max"));
+        let predicate = match op {
+            SetOperator::Intersect => Expression::BinaryExpression {
+                left: Box::new(min_side.clone()),
+                op: "<".to_string(),
+                right: Box::new(max_side.clone()),
+            },
+            _ => Expression::BinaryExpression {
+                left: Box::new(max_side.clone()),
+                op: "=".to_string(),
+                right: Box::new(Expression::create_literal(DataValue::UInt8(Some(1)))),
+            },
+        };
+
+        let plan = self.aggregate(&plan, &[min_side, max_side], &columns)?;
+        let plan = self.having(&plan, Some(predicate))?;
+        self.project(&plan, &columns)
+    }
+
+    fn schema_columns(schema: &DataSchemaRef) -> Vec<Expression> {
+        schema
+            .fields()
+            .iter()
+            .map(|field| Expression::Column(field.name().clone()))
+            .collect()
+    }
+
+    /// Apply the ORDER BY and LIMIT of a query whose body is a set operation,
+    /// they can only refer to the output columns.
+    fn set_operation_order_by_limit(&self, plan: PlanNode, query: &Query) -> Result<PlanNode> {
+        let schema = plan.schema();
+        let order_by_exprs = query
+            .order_by
+            .iter()
+            .map(|e| -> Result<Expression> {
+                Ok(Expression::Sort {
+                    expr: Box::new(self.sql_to_rex(&e.expr, &schema, None)?),
+                    asc: e.asc.unwrap_or(true),
+                    nulls_first: e.nulls_first.unwrap_or(true),
+                })
+            })
+            .collect::<Result<Vec<Expression>>>()?;
+
+        let plan = match plan {
+            PlanNode::Select(select) => select.input.as_ref().clone(),
+            other => other,
+        };
+        let plan = match order_by_exprs.is_empty() {
+            true => plan,
+            false => {
+                let columns = Self::schema_columns(&schema);
+                let mut exprs = columns.clone();
+                exprs.extend_from_slice(&order_by_exprs);
+
+                let plan = self.expression(&plan, &exprs, "Before OrderBy")?;
+                let plan = self.sort(&plan, &order_by_exprs)?;
+                self.project(&plan, &columns)?
+            }
+        };
+        let plan = self.limit(&plan, &query.limit, &query.offset, None)?;
+
+        Ok(PlanNode::Select(SelectPlan {
+            input: Arc::new(plan),
+        }))
+    }
+
+    /// Plan the CTEs of a WITH clause into the innermost scope,
+    /// a CTE can reference the CTEs defined before it.
+    fn with_to_scope(&self, with: &With) -> Result<()> {
+        for cte in &with.cte_tables {
+            let name = &cte.alias.name.value;
+            if let Some(scope) = self.cte_scopes.read().last() {
+                if scope.contains_key(name) {
+                    return Result::Err(ErrorCode::SyntaxException(format!(
+                        "Duplicate CTE name '{}'",
+                        name
+                    )));
+                }
+            }
+
+            let recursive_plan = match &cte.query.body {
+                SetExpr::SetOperation {
+                    op: SetOperator::Union,
+                    all,
+                    left,
+                    right,
+                } if with.recursive => self.recursive_cte_to_plan(cte, *all, left, right)?,
+                _ => None,
+            };
+
+            let plan = match recursive_plan {
+                Some(plan) => plan,
+                None => self.cte_to_plan(cte)?,
+            };
+
+            if let Some(scope) = self.cte_scopes.write().last_mut() {
+                scope.insert(name.clone(), plan);
+            }
+        }
+        Ok(())
+    }
+
+    /// A non-recursive CTE is inlined into each reference by default,
+    /// or materialized once if `enable_cte_materialization` is set.
+    fn cte_to_plan(&self, cte: &Cte) -> Result<PlanNode> {
+        let plan = self.query_to_plan(&cte.query)?;
+        let plan = Self::rename_cte_columns(&cte.alias, plan)?;
+
+        // The recursive part runs once per iteration, so its CTEs can't be shared
+        let materialize = self.ctx.get_settings().get_enable_cte_materialization()? != 0
+            && !self.in_recursive_term.load(Ordering::Relaxed);
+        match materialize {
+            false => Ok(plan),
+            true => Ok(PlanNode::CteScan(CteScanPlan {
+                name: self.ctx.get_cte_name(),
+                schema: plan.schema(),
+                source: CteSource::Query(Arc::new(plan)),
+            })),
+        }
     }
 
-    #[tracing::instrument(level = "info", skip(self, table_name, columns, source), fields(ctx.id = self.ctx.get_id().as_str()))]
-    fn insert_to_plan(
+    /// Plan `WITH RECURSIVE name AS (anchor UNION ALL recursive)`, the recursive part reads
+    /// the rows produced by the previous iteration through the CTE name.
+    /// Returns None if the recursive part doesn't reference the CTE.
+    fn recursive_cte_to_plan(
         &self,
-        table_name: &ObjectName,
-        columns: &[Ident],
-        source: &Option<Box<Query>>,
-        format_sql: &str,
-    ) -> Result<PlanNode> {
-        let mut db_name = self.ctx.get_current_database();
-        let mut tbl_name = table_name.0[0].value.clone();
-
-        if table_name.0.len() > 1 {
-            db_name = tbl_name;
-            tbl_name = table_name.0[1].value.clone();
+        cte: &Cte,
+        all: bool,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+    ) -> Result<Option<PlanNode>> {
+        let name = &cte.alias.name.value;
+        if self.in_recursive_term.load(Ordering::Relaxed) {
+            return Result::Err(ErrorCode::UnImplement(format!(
+                "Recursive CTE '{}' in the recursive part of another recursive CTE is not yet implemented",
+                name
+            )));
         }
-        let table = self.ctx.get_catalog().get_table(&db_name, &tbl_name)?;
 
-        let mut schema = table.raw().schema()?;
-        let tbl_id = table.meta_id();
+        let anchor = self.set_expr_to_plan(anchor)?;
+        let anchor = Self::rename_cte_columns(&cte.alias, anchor)?;
+        let schema = anchor.schema();
+        let cte_name = self.ctx.get_cte_name();
+
+        let working_table = PlanNode::CteScan(CteScanPlan {
+            name: cte_name.clone(),
+            schema: schema.clone(),
+            source: CteSource::WorkingTable,
+        });
+        if let Some(scope) = self.cte_scopes.write().last_mut() {
+            scope.insert(name.clone(), working_table);
+        }
 
-        if !columns.is_empty() {
-            let fields = columns
-                .iter()
-                .map(|ident| schema.field_with_name(&ident.value).map(|v| v.clone()))
-                .collect::<Result<Vec<_>>>()?;
+        self.in_recursive_term.store(true, Ordering::Relaxed);
+        self.working_table_referenced
+            .store(false, Ordering::Relaxed);
+        let recursive = self.set_expr_to_plan(recursive);
+        self.in_recursive_term.store(false, Ordering::Relaxed);
+        if let Some(scope) = self.cte_scopes.write().last_mut() {
+            scope.remove(name);
+        }
 
-            schema = DataSchemaRefExt::create(fields);
+        let recursive = recursive?;
+        if !self.working_table_referenced.load(Ordering::Relaxed) {
+            return Ok(None);
         }
 
-        let mut input_stream = futures::stream::iter::<Vec<DataBlock>>(vec![]);
+        if !all {
+            return Result::Err(ErrorCode::UnImplement(format!(
+                "Recursive CTE '{}' only supports UNION ALL",
+                name
+            )));
+        }
 
-        if let Some(source) = source {
-            if let sqlparser::ast::SetExpr::Values(_vs) = &source.body {
-                tracing::debug!("{:?}", format_sql);
-                let index = format_sql.find_substring(" VALUES ").unwrap();
-                let values = &format_sql[index + " VALUES ".len()..];
+        if !cte.query.order_by.is_empty() || cte.query.limit.is_some() {
+            return Result::Err(ErrorCode::UnImplement(format!(
+                "ORDER BY and LIMIT in recursive CTE '{}' are not yet implemented",
+                name
+            )));
+        }
 
-                let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
-                let mut source = ValueSource::new(values.as_bytes(), schema.clone(), block_size);
-                let mut blocks = vec![];
-                loop {
-                    let block = source.read()?;
-                    match block {
-                        Some(b) => blocks.push(b),
-                        None => break,
-                    }
-                }
-                input_stream = futures::stream::iter(blocks);
-            }
+        let recursive_schema = recursive.schema();
+        if recursive_schema.fields().len() != schema.fields().len() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Number of columns doesn't match, recursive CTE '{}' expect {} columns, but the recursive part returns {}",
+                name,
+                schema.fields().len(),
+                recursive_schema.fields().len()
+            )));
         }
 
-        let plan_node = InsertIntoPlan {
-            db_name,
-            tbl_name,
-            tbl_id,
+        let recursive = Self::cast_to_schema(recursive, &schema)?;
+        Ok(Some(PlanNode::CteScan(CteScanPlan {
+            name: cte_name,
             schema,
-            input_stream: Arc::new(Mutex::new(Some(Box::pin(input_stream)))),
-        };
-        Ok(PlanNode::InsertInto(plan_node))
+            source: CteSource::Recursive {
+                anchor: Arc::new(anchor),
+                recursive: Arc::new(recursive),
+            },
+        })))
     }
 
-    /// Generate a logic plan from an SQL query
-    pub fn query_to_plan(&self, query: &sqlparser::ast::Query) -> Result<PlanNode> {
-        if query.with.is_some() {
-            return Result::Err(ErrorCode::UnImplement("CTE is not yet implement"));
+    /// Rename the output columns to the column names of `WITH name(column, ...) AS`.
+    fn rename_cte_columns(alias: &TableAlias, plan: PlanNode) -> Result<PlanNode> {
+        if alias.columns.is_empty() {
+            return Ok(plan);
         }
 
-        match &query.body {
-            sqlparser::ast::SetExpr::Select(s) => {
-                self.select_to_plan(s.as_ref(), &query.limit, &query.offset, &query.order_by)
-            }
-            _ => Result::Err(ErrorCode::UnImplement(format!(
-                "Query {} is not yet implemented",
-                query.body
-            ))),
+        let schema = plan.schema();
+        if alias.columns.len() != schema.fields().len() {
+            return Result::Err(ErrorCode::BadArguments(format!(
+                "Number of columns doesn't match, CTE '{}' returns {} columns, but {} column names are specified",
+                alias.name.value,
+                schema.fields().len(),
+                alias.columns.len()
+            )));
+        }
+
+        let fields = schema
+            .fields()
+            .iter()
+            .zip(alias.columns.iter())
+            .map(|(field, column)| {
+                DataField::new(
+                    &column.value,
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                )
+            })
+            .collect::<Vec<_>>();
+        Self::cast_to_schema(plan, &DataSchemaRefExt::create(fields))
+    }
+
+    /// Find the CTE by name from the innermost scope to the outermost one.
+    fn find_cte(&self, name: &str) -> Option<PlanNode> {
+        let scopes = self.cte_scopes.read();
+        let plan = scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).cloned())?;
+
+        if let PlanNode::CteScan(CteScanPlan {
+            source: CteSource::WorkingTable,
+            ..
+        }) = &plan
+        {
+            self.working_table_referenced.store(true, Ordering::Relaxed);
         }
+        Some(plan)
     }
 
     /// Generate a logic plan from an SQL select
@@ -530,14 +1635,8 @@ impl PlanParser {
 
         // Group By expression after against aliases
         // In example: GroupBy=[(number % 3)]
-        let group_by_exprs = select
-            .group_by
-            .iter()
-            .map(|e| {
-                self.sql_to_rex(e, &plan.schema(), Some(select))
-                    .and_then(|expr| resolve_aliases_to_exprs(&expr, &aliases))
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let (group_by_exprs, grouping_sets) =
+            self.group_by_to_rex(select, &plan.schema(), &aliases)?;
 
         // Having Expression after against aliases
         // In example: Having=((number % 3) > 1)
@@ -583,6 +1682,23 @@ impl PlanParser {
         // In example: aggr=[[sum((number + 1))]]
         let aggr_exprs = find_aggregate_exprs(&expression_exprs);
 
+        // The grouping() calls are computed from the grouping id after aggregation, with a
+        // plain GROUP BY there is a single grouping set of all the group by expressions.
+        let grouping_exprs = find_grouping_exprs(&expression_exprs);
+        let grouping_sets = match (grouping_exprs.is_empty(), grouping_sets.is_empty()) {
+            (false, true) if !group_by_exprs.is_empty() => {
+                vec![(0..group_by_exprs.len()).collect()]
+            }
+            _ => grouping_sets,
+        };
+
+        // ArrayJoin unfolds the arrays into rows before aggregation.
+        // In example: ArrayJoin=[arrayJoin(arr)]
+        let mut array_join_source_exprs = expression_exprs.clone();
+        array_join_source_exprs.extend_from_slice(&group_by_exprs);
+        let array_join_exprs = find_array_join_exprs(&array_join_source_exprs);
+        let plan = self.array_join(&plan, &array_join_exprs)?;
+
         let has_aggr = aggr_exprs.len() + group_by_exprs.len() > 0;
         let (plan, having_expr_post_aggr_opt) = if has_aggr {
             let aggr_projection_exprs = group_by_exprs
@@ -598,11 +1714,21 @@ impl PlanParser {
             // inner expression=[(number + 1), (number % 3)]
             let plan = self
                 .expression(&plan, &before_aggr_exprs, "Before GroupBy")
-                .and_then(|input| self.aggregate(&input, &aggr_exprs, &group_by_exprs))?;
+                .and_then(|input| {
+                    self.aggregate(&input, &aggr_exprs, &group_by_exprs, &grouping_sets)
+                })
+                .and_then(|input| {
+                    self.grouping(&input, &grouping_exprs, &group_by_exprs, &aliases)
+                })?;
 
             // After aggregation, these are all of the columns that will be
             // available to next phases of planning.
-            let column_exprs_post_aggr = aggr_projection_exprs
+            let post_aggr_exprs = aggr_projection_exprs
+                .iter()
+                .chain(grouping_exprs.iter())
+                .cloned()
+                .collect::<Vec<_>>();
+            let column_exprs_post_aggr = post_aggr_exprs
                 .iter()
                 .map(|expr| expr_as_column_expr(expr))
                 .collect::<Result<Vec<_>>>()?;
@@ -611,7 +1737,7 @@ impl PlanParser {
             // In example:[col("number + 1"), col("number % 3")]
             let select_exprs_post_aggr = expression_exprs
                 .iter()
-                .map(|expr| rebase_expr(expr, &aggr_projection_exprs))
+                .map(|expr| rebase_expr(expr, &post_aggr_exprs))
                 .collect::<Result<Vec<_>>>()?;
 
             if let Ok(Some(expr)) =
@@ -626,7 +1752,7 @@ impl PlanParser {
             // Rewrite the HAVING expression to use the columns produced by the
             // aggregation.
             let having_expr_post_aggr_opt = if let Some(having_expr) = &having_expr_opt {
-                let having_expr_post_aggr = rebase_expr(having_expr, &aggr_projection_exprs)?;
+                let having_expr_post_aggr = rebase_expr(having_expr, &post_aggr_exprs)?;
                 if let Ok(Some(expr)) = find_columns_not_satisfy_exprs(&column_exprs_post_aggr, &[
                     having_expr_post_aggr.clone(),
                 ]) {
@@ -667,6 +1793,162 @@ impl PlanParser {
         }))
     }
 
+    /// The group by expressions, and the grouping sets of `GROUPING SETS`, `ROLLUP` and `CUBE`
+    /// as positions of the expressions. The grouping sets are empty for a plain `GROUP BY`, and
+    /// the items of GROUP BY are combined as the cross product of their grouping sets:
+    ///   `ROLLUP(a, b)`                    => (a, b), (a), ()
+    ///   `CUBE(a, b)`                      => (a, b), (a), (b), ()
+    ///   `GROUPING SETS ((a), (a, b), ())` => (a), (a, b), ()
+    fn group_by_to_rex(
+        &self,
+        select: &sqlparser::ast::Select,
+        schema: &DataSchemaRef,
+        aliases: &HashMap<String, Expression>,
+    ) -> Result<(Vec<Expression>, Vec<Vec<usize>>)> {
+        let to_rex = |expr: &sqlparser::ast::Expr| {
+            self.sql_to_rex(expr, schema, Some(select))
+                .and_then(|expr| resolve_aliases_to_exprs(&expr, aliases))
+        };
+
+        let has_grouping_sets = select.group_by.iter().any(
+            |expr| matches!(Self::grouping_sets_function(expr), Some((name, _)) if name != "tuple"),
+        );
+        if !has_grouping_sets {
+            let group_by_exprs = select
+                .group_by
+                .iter()
+                .map(to_rex)
+                .collect::<Result<Vec<_>>>()?;
+            return Ok((group_by_exprs, vec![]));
+        }
+
+        let mut group_by_exprs = vec![];
+        let mut position = |expr: &sqlparser::ast::Expr| -> Result<usize> {
+            let expr = to_rex(expr)?;
+            match group_by_exprs.iter().position(|e| e == &expr) {
+                Some(position) => Ok(position),
+                None => {
+                    group_by_exprs.push(expr);
+                    Ok(group_by_exprs.len() - 1)
+                }
+            }
+        };
+
+        let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+        for item in &select.group_by {
+            let item_sets = match Self::grouping_sets_function(item) {
+                Some((name, args)) if name == "rollup" => {
+                    let positions = args
+                        .iter()
+                        .map(|arg| position(*arg))
+                        .collect::<Result<Vec<_>>>()?;
+                    (0..=positions.len())
+                        .rev()
+                        .map(|len| positions[..len].to_vec())
+                        .collect()
+                }
+                Some((name, args)) if name == "cube" => {
+                    let positions = args
+                        .iter()
+                        .map(|arg| position(*arg))
+                        .collect::<Result<Vec<_>>>()?;
+                    if positions.len() > 12 {
+                        return Result::Err(ErrorCode::SyntaxException(
+                            "CUBE supports at most 12 expressions",
+                        ));
+                    }
+                    (0..1usize << positions.len())
+                        .rev()
+                        .map(|mask| {
+                            positions
+                                .iter()
+                                .enumerate()
+                                .filter(|(index, _)| {
+                                    (mask >> (positions.len() - 1 - index)) & 1 == 1
+                                })
+                                .map(|(_, position)| *position)
+                                .collect()
+                        })
+                        .collect()
+                }
+                Some((name, args)) if name == "groupingsets" => {
+                    let mut sets = vec![];
+                    for arg in args {
+                        let set = match Self::grouping_sets_function(arg) {
+                            Some((name, set_args)) if name == "tuple" => set_args
+                                .iter()
+                                .map(|arg| position(*arg))
+                                .collect::<Result<Vec<_>>>()?,
+                            _ => vec![position(arg)?],
+                        };
+                        sets.push(set);
+                    }
+                    sets
+                }
+                _ => vec![vec![position(item)?]],
+            };
+
+            let mut product = Vec::with_capacity(grouping_sets.len() * item_sets.len());
+            for set in &grouping_sets {
+                for item_set in &item_sets {
+                    let mut set = set.clone();
+                    for index in item_set {
+                        if !set.contains(index) {
+                            set.push(*index);
+                        }
+                    }
+                    product.push(set);
+                }
+            }
+            grouping_sets = product;
+            if grouping_sets.len() > 4096 {
+                return Result::Err(ErrorCode::SyntaxException(
+                    "Too many grouping sets, at most 4096 are supported",
+                ));
+            }
+        }
+
+        if group_by_exprs.len() > MAX_GROUPING_EXPRS {
+            return Result::Err(ErrorCode::SyntaxException(format!(
+                "Grouping sets support at most {} expressions, but got {}",
+                MAX_GROUPING_EXPRS,
+                group_by_exprs.len()
+            )));
+        }
+        // `GROUPING SETS (())` is a plain aggregation.
+        if group_by_exprs.is_empty() {
+            grouping_sets.clear();
+        }
+        Ok((group_by_exprs, grouping_sets))
+    }
+
+    /// The lower case name and the arguments of `ROLLUP`, `CUBE`, `GROUPING SETS`, and the tuples
+    /// of `GROUPING SETS`, see `TokenRewriter`.
+    fn grouping_sets_function(
+        expr: &sqlparser::ast::Expr,
+    ) -> Option<(String, Vec<&sqlparser::ast::Expr>)> {
+        match expr {
+            sqlparser::ast::Expr::Function(function) => {
+                let name = function.name.to_string().to_lowercase();
+                match name.as_str() {
+                    "rollup" | "cube" | "groupingsets" | "tuple" => {
+                        let args = function
+                            .args
+                            .iter()
+                            .map(|arg| match arg {
+                                FunctionArg::Named { arg, .. } => arg,
+                                FunctionArg::Unnamed(arg) => arg,
+                            })
+                            .collect();
+                        Some((name, args))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
     /// Generate a relational expression from a select SQL expression
     fn sql_select_to_rex(
         &self,
@@ -741,7 +2023,13 @@ impl PlanParser {
     fn create_relation(&self, relation: &sqlparser::ast::TableFactor) -> Result<PlanNode> {
         match relation {
             TableFactor::Table { name, args, .. } => {
-                let mut db_name = self.ctx.get_current_database();
+                if args.is_empty() && name.0.len() == 1 {
+                    if let Some(cte_plan) = self.find_cte(&name.0[0].value) {
+                        return Ok(cte_plan);
+                    }
+                }
+
+                let mut db_name = self.current_database();
                 let mut table_name = name.to_string();
                 if name.0.len() == 2 {
                     db_name = name.0[0].to_string();
@@ -777,7 +2065,15 @@ impl PlanParser {
                     table_name = table_function.name().to_string();
                     table = table_function.as_table();
                 } else {
+                    let key = (db_name.clone(), table_name.clone());
+                    if let Some(plan) = self.table_overrides.get(&key) {
+                        return Ok(plan.clone());
+                    }
+
                     let table_meta = self.ctx.get_table(&db_name, &table_name)?;
+                    if let Some(view) = table_meta.raw().as_any().downcast_ref::<ViewTable>() {
+                        return self.view_to_plan(view);
+                    }
                     meta_id = table_meta.meta_id();
                     meta_version = table_meta.meta_ver();
                     table = table_meta.raw().clone();
@@ -815,6 +2111,37 @@ impl PlanParser {
             }
         }
     }
+    /// A view is planned as a subquery, a materialized view reads from its target table.
+    fn view_to_plan(&self, view: &ViewTable) -> Result<PlanNode> {
+        match view.materialized()? {
+            None => {
+                let query = Self::parse_view_query(view.query()?)?;
+                PlanParser::create(self.ctx.clone())
+                    .with_database(view.database())
+                    .query_to_plan(&query)
+            }
+            Some(mv) => self.create_relation(&TableFactor::Table {
+                name: ObjectName(vec![Ident::new(mv.target_db), Ident::new(mv.target_table)]),
+                alias: None,
+                args: vec![],
+                with_hints: vec![],
+            }),
+        }
+    }
+
+    fn parse_view_query(sql: &str) -> Result<Box<Query>> {
+        let (mut statements, _) = DfParser::parse_sql(sql)?;
+        match statements.pop() {
+            Some(DfStatement::Statement(Statement::Query(query))) if statements.is_empty() => {
+                Ok(query)
+            }
+            _ => Result::Err(ErrorCode::SyntaxException(format!(
+                "The query of the view is not a single query: {}",
+                sql
+            ))),
+        }
+    }
+
     fn process_compound_ident(
         &self,
         ids: &[Ident],
@@ -986,6 +2313,9 @@ impl PlanParser {
     ) -> Result<Expression> {
         match expr {
             sqlparser::ast::Expr::Value(value) => Self::value_to_rex(value),
+            sqlparser::ast::Expr::Identifier(ref v) if v.value.starts_with("@@") => {
+                self.system_variable_to_rex(&v.value)
+            }
             sqlparser::ast::Expr::Identifier(ref v) => Ok(Expression::Column(v.clone().value)),
             sqlparser::ast::Expr::BinaryOp { left, op, right } => {
                 Ok(Expression::BinaryExpression {
@@ -1015,10 +2345,45 @@ impl PlanParser {
             }),
             sqlparser::ast::Expr::Subquery(q) => Ok(self.scalar_subquery_to_rex(q)?),
             sqlparser::ast::Expr::Nested(e) => self.sql_to_rex(e, schema, select),
+            sqlparser::ast::Expr::CompoundIdentifier(ids) if ids[0].value.starts_with("@@") => {
+                let name = ids
+                    .iter()
+                    .map(|id| id.value.as_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                self.system_variable_to_rex(&name)
+            }
             sqlparser::ast::Expr::CompoundIdentifier(ids) => {
                 self.process_compound_ident(ids.as_slice(), select)
             }
             sqlparser::ast::Expr::Function(e) => {
+                if e.name.to_string().to_lowercase() == "totimezone" {
+                    return self.to_time_zone_to_rex(e, schema, select);
+                }
+                if e.name.to_string().to_lowercase() == "todatetime64" && e.args.len() > 1 {
+                    return self.to_datetime64_to_rex(e, schema, select);
+                }
+                if e.name.to_string().to_lowercase() == "lambda" {
+                    return self.lambda_to_rex(e, schema, select);
+                }
+                if is_higher_order_function(&e.name.to_string())
+                    || is_grouping_function(&e.name.to_string())
+                {
+                    let args = e
+                        .args
+                        .iter()
+                        .map(|arg| match arg {
+                            FunctionArg::Named { arg, .. } => self.sql_to_rex(arg, schema, select),
+                            FunctionArg::Unnamed(arg) => self.sql_to_rex(arg, schema, select),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    return Ok(Expression::ScalarFunction {
+                        op: e.name.to_string(),
+                        args,
+                    });
+                }
+
                 let mut args = Vec::with_capacity(e.args.len());
 
                 // 1. Get the args from context by function name. such as SELECT database()
@@ -1080,6 +2445,7 @@ impl PlanParser {
                     });
                 }
 
+                let args = self.session_timezone_args(&op, args, schema)?;
                 Ok(Expression::ScalarFunction { op, args })
             }
             sqlparser::ast::Expr::Wildcard => Ok(Expression::Wildcard),
@@ -1145,6 +2511,189 @@ impl PlanParser {
         }
     }
 
+    /// toTimeZone(t, 'tz') only changes the timezone carried by the DateTime type,
+    /// the result type depends on the constant timezone, so it's planned as a cast.
+    fn to_time_zone_to_rex(
+        &self,
+        e: &sqlparser::ast::Function,
+        schema: &DataSchema,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<Expression> {
+        let args = e
+            .args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArg::Named { arg, .. } => arg,
+                FunctionArg::Unnamed(arg) => arg,
+            })
+            .collect::<Vec<_>>();
+
+        if args.len() != 2 {
+            return Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function toTimeZone expect to have 2 arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let tz = match args[1] {
+            sqlparser::ast::Expr::Value(sqlparser::ast::Value::SingleQuotedString(tz)) => tz,
+            _ => {
+                return Result::Err(ErrorCode::BadArguments(
+                    "The second argument of function toTimeZone must be a constant string",
+                ))
+            }
+        };
+        tz.parse::<Tz>()
+            .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz)))?;
+
+        Ok(Expression::Cast {
+            expr: Box::new(self.sql_to_rex(args[0], schema, select)?),
+            data_type: DataType::DateTime32(Some(tz.clone())),
+        })
+    }
+
+    /// toDateTime64(t, precision[, 'tz']) is a cast to DateTime64(precision[, tz]),
+    /// the single argument form is registered as a plain cast function.
+    fn to_datetime64_to_rex(
+        &self,
+        e: &sqlparser::ast::Function,
+        schema: &DataSchema,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<Expression> {
+        let args = e
+            .args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArg::Named { arg, .. } => arg,
+                FunctionArg::Unnamed(arg) => arg,
+            })
+            .collect::<Vec<_>>();
+
+        if args.len() > 3 {
+            return Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Function toDateTime64 expect to have 1 to 3 arguments, but got {}",
+                args.len()
+            )));
+        }
+
+        let precision = match args[1] {
+            sqlparser::ast::Expr::Value(sqlparser::ast::Value::Number(n, _)) => {
+                n.parse::<u32>().ok().filter(|p| *p <= 9)
+            }
+            _ => None,
+        };
+        let precision = precision.ok_or_else(|| {
+            ErrorCode::BadArguments(
+                "The second argument of function toDateTime64 must be a constant integer between 0 and 9",
+            )
+        })?;
+
+        let tz = match args.get(2) {
+            None => None,
+            Some(sqlparser::ast::Expr::Value(sqlparser::ast::Value::SingleQuotedString(tz))) => {
+                tz.parse::<Tz>()
+                    .map_err(|_| ErrorCode::BadArguments(format!("Unknown timezone: {:?}", tz)))?;
+                Some(tz.clone())
+            }
+            Some(_) => {
+                return Result::Err(ErrorCode::BadArguments(
+                    "The third argument of function toDateTime64 must be a constant string",
+                ))
+            }
+        };
+
+        Ok(Expression::Cast {
+            expr: Box::new(self.sql_to_rex(args[0], schema, select)?),
+            data_type: DataType::DateTime64(precision, tz),
+        })
+    }
+
+    /// `x -> body` and `(x, y) -> body` are rewritten by the DfParser as lambda(x, body)
+    /// and lambda(x, y, body), the parameters are plain identifiers.
+    fn lambda_to_rex(
+        &self,
+        e: &sqlparser::ast::Function,
+        schema: &DataSchema,
+        select: Option<&sqlparser::ast::Select>,
+    ) -> Result<Expression> {
+        let args = e
+            .args
+            .iter()
+            .map(|arg| match arg {
+                FunctionArg::Named { arg, .. } => arg,
+                FunctionArg::Unnamed(arg) => arg,
+            })
+            .collect::<Vec<_>>();
+
+        if args.len() < 2 {
+            return Result::Err(ErrorCode::SyntaxException(
+                "Lambda expect to have at least one parameter and a body",
+            ));
+        }
+
+        let params = args[..args.len() - 1]
+            .iter()
+            .map(|arg| match arg {
+                sqlparser::ast::Expr::Identifier(ident) => Ok(ident.value.clone()),
+                other => Result::Err(ErrorCode::SyntaxException(format!(
+                    "Lambda parameters must be identifiers, but got {}",
+                    other
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Expression::Lambda {
+            params,
+            body: Box::new(self.sql_to_rex(args[args.len() - 1], schema, select)?),
+        })
+    }
+
+    /// The date and time functions evaluate the DateTimes without a timezone, and parse the
+    /// strings, in the `timezone` setting of the session.
+    fn session_timezone_args(
+        &self,
+        op: &str,
+        args: Vec<Expression>,
+        schema: &DataSchema,
+    ) -> Result<Vec<Expression>> {
+        let tz = self.ctx.get_settings().get_timezone()?;
+        if tz == "UTC" {
+            return Ok(args);
+        }
+
+        match op.to_lowercase().as_str() {
+            "parsedatetime" | "str_to_date" if args.len() == 2 => {
+                let mut args = args;
+                args.push(Expression::create_literal(DataValue::String(Some(
+                    tz.into_bytes(),
+                ))));
+                Ok(args)
+            }
+            name if DateFunction::is_timezone_aware(name) => {
+                let schema = Arc::new(schema.clone());
+                Ok(args
+                    .into_iter()
+                    .map(|arg| {
+                        let data_type = match arg.to_data_type(&schema) {
+                            Ok(DataType::DateTime32(None)) => {
+                                DataType::DateTime32(Some(tz.clone()))
+                            }
+                            Ok(DataType::DateTime64(precision, None)) => {
+                                DataType::DateTime64(precision, Some(tz.clone()))
+                            }
+                            _ => return arg,
+                        };
+                        Expression::Cast {
+                            expr: Box::new(arg),
+                            data_type,
+                        }
+                    })
+                    .collect())
+            }
+            _ => Ok(args),
+        }
+    }
+
     pub fn subquery_to_rex(&self, subquery: &Query) -> Result<Expression> {
         let subquery = self.query_to_plan(subquery)?;
         let subquery_name = self.ctx.get_subquery_name(&subquery);
@@ -1154,6 +2703,15 @@ impl PlanParser {
         })
     }
 
+    /// `@@name` is replaced by the value of the system variable, named as it is written.
+    fn system_variable_to_rex(&self, name: &str) -> Result<Expression> {
+        let value = get_system_variable(&self.ctx, name)?;
+        Ok(Expression::Alias(
+            name.to_string(),
+            Box::new(Expression::create_literal(value)),
+        ))
+    }
+
     pub fn scalar_subquery_to_rex(&self, subquery: &Query) -> Result<Expression> {
         let subquery = self.query_to_plan(subquery)?;
         let subquery_name = self.ctx.get_subquery_name(&subquery);
@@ -1228,6 +2786,7 @@ impl PlanParser {
         input: &PlanNode,
         aggr_exprs: &[Expression],
         group_by_exprs: &[Expression],
+        grouping_sets: &[Vec<usize>],
     ) -> Result<PlanNode> {
         let aggr_exprs = aggr_exprs
             .iter()
@@ -1243,10 +2802,42 @@ impl PlanParser {
         // S1: Apply a fragment plan for distributed planners split.
         // S2: Apply a final aggregator plan.
         PlanBuilder::from(input)
-            .aggregate_partial(&aggr_exprs, &group_by_exprs)
+            .aggregate_partial_with_grouping_sets(&aggr_exprs, &group_by_exprs, grouping_sets)
             .and_then(|builder| {
-                builder.aggregate_final(input.schema(), &aggr_exprs, &group_by_exprs)
+                builder.aggregate_final_with_grouping_sets(
+                    input.schema(),
+                    &aggr_exprs,
+                    &group_by_exprs,
+                    grouping_sets,
+                )
+            })
+            .and_then(|builder| builder.build())
+    }
+
+    /// Compute the grouping() calls from the grouping id of the aggregated rows.
+    fn grouping(
+        &self,
+        input: &PlanNode,
+        grouping_exprs: &[Expression],
+        group_by_exprs: &[Expression],
+        aliases: &HashMap<String, Expression>,
+    ) -> Result<PlanNode> {
+        if grouping_exprs.is_empty() {
+            return Ok(input.clone());
+        }
+
+        // The columns are named after the calls, as they are referenced in the query.
+        let exprs = grouping_exprs
+            .iter()
+            .map(|expr| {
+                resolve_aliases_to_exprs(expr, aliases)
+                    .and_then(|resolved| grouping_to_expr(&resolved, group_by_exprs))
+                    .map(|grouping| Expression::Alias(expr.column_name(), Box::new(grouping)))
             })
+            .collect::<Result<Vec<_>>>()?;
+
+        PlanBuilder::from(input)
+            .expression(&exprs, "Grouping")
             .and_then(|builder| builder.build())
     }
 
@@ -1313,6 +2904,33 @@ impl PlanParser {
         }
     }
 
+    /// Compute the arrays and unfold them with an array join plan.
+    fn array_join(&self, input: &PlanNode, array_join_exprs: &[Expression]) -> Result<PlanNode> {
+        if array_join_exprs.is_empty() {
+            return Ok(input.clone());
+        }
+
+        let array_exprs = array_join_exprs
+            .iter()
+            .map(|expr| match expr {
+                Expression::ScalarFunction { op, args } if args.len() != 1 => {
+                    Result::Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                        "Function {} expect to have 1 arguments, but got {}",
+                        op,
+                        args.len()
+                    )))
+                }
+                Expression::ScalarFunction { args, .. } => Ok(args[0].clone()),
+                _ => unreachable!(),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = self.expression(input, &array_exprs, "Before ArrayJoin")?;
+        PlanBuilder::from(&plan)
+            .array_join(array_join_exprs)
+            .and_then(|builder| builder.build())
+    }
+
     /// Apply a expression against exprs.
     fn expression(&self, input: &PlanNode, exprs: &[Expression], desc: &str) -> Result<PlanNode> {
         let mut dedup_exprs = vec![];
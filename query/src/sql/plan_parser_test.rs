@@ -63,6 +63,42 @@ fn test_plan_parser() -> Result<()> {
             expect: "Create table default.t DataField { name: \"c1\", data_type: Int32, nullable: false }, DataField { name: \"c2\", data_type: Int64, nullable: false }, DataField { name: \"c3\", data_type: String, nullable: false }, engine: Parquet, if_not_exists:true, option: {\"location\": \"foo.parquet\"}",
             error: "",
         },
+        Test {
+            name: "create-table-as-select-passed",
+            sql: "CREATE TABLE t ENGINE = Memory AS SELECT number FROM numbers(10)",
+            expect: "Create table default.t DataField { name: \"number\", data_type: UInt64, nullable: false }, engine: Memory, if_not_exists:false, option: {}, as select",
+            error: "",
+        },
+        Test {
+            name: "create-table-as-select-default-engine",
+            sql: "CREATE TABLE t AS SELECT number FROM numbers(10)",
+            expect: "Create table default.t DataField { name: \"number\", data_type: UInt64, nullable: false }, engine: FUSE, if_not_exists:false, option: {}, as select",
+            error: "",
+        },
+        Test {
+            name: "create-table-as-select-with-columns",
+            sql: "CREATE TABLE t(c1 int) ENGINE = Memory AS SELECT number FROM numbers(10)",
+            expect: "",
+            error: "Code: 5, displayText = CREATE TABLE ... AS SELECT does not support column definitions, the columns are inferred from the query.",
+        },
+        Test {
+            name: "create-table-datetime64-passed",
+            sql: "CREATE TABLE t(c1 DateTime64, c2 DateTime64(6, 'Asia/Shanghai')) ENGINE = Memory",
+            expect: "Create table default.t DataField { name: \"c1\", data_type: DateTime64(3, None), nullable: false }, DataField { name: \"c2\", data_type: DateTime64(6, Some(\"Asia/Shanghai\")), nullable: false }, engine: Memory, if_not_exists:false, option: {}",
+            error: "",
+        },
+        Test {
+            name: "create-table-datetime64-bad-precision",
+            sql: "CREATE TABLE t(c1 DateTime64(10)) ENGINE = Memory",
+            expect: "",
+            error: "Code: 7, displayText = The precision of DateTime64 must be an integer between 0 and 9.",
+        },
+        Test {
+            name: "create-table-like-passed",
+            sql: "CREATE TABLE t LIKE system.numbers",
+            expect: "Create table default.t DataField { name: \"number\", data_type: UInt64, nullable: false }, engine: SystemNumbers, if_not_exists:false, option: {}",
+            error: "",
+        },
         Test {
             name: "drop-table-passed",
             sql: "DROP TABLE t1",
@@ -111,12 +147,30 @@ fn test_plan_parser() -> Result<()> {
             expect: "Projection: database():String\n  Expression: database(default):String (Before Projection)\n    ReadDataSource: scan partitions: [1], scan schema: [dummy:UInt8], statistics: [read_rows: 1, read_bytes: 1]",
             error: "",
         },
+        Test {
+            name: "to-timezone-unknown-timezone",
+            sql: "select toTimeZone(now(), 'Mars/Olympus')",
+            expect: "",
+            error: "Code: 6, displayText = Unknown timezone: \"Mars/Olympus\".",
+        },
+        Test {
+            name: "to-datetime64-bad-precision",
+            sql: "select toDateTime64(now(), 10)",
+            expect: "",
+            error: "Code: 6, displayText = The second argument of function toDateTime64 must be a constant integer between 0 and 9.",
+        },
         Test {
             name: "aggr-fail1",
             sql: "select number + 1, number + 3 from numbers(10) group by number + 2, number + 1",
             expect: "",
             error: "Code: 26, displayText = Column `number` is not under aggregate function and not in GROUP BY: While processing [(number + 1), (number + 3)].",
         },
+        Test {
+            name: "grouping-fail",
+            sql: "select grouping(name) from system.settings group by rollup(value)",
+            expect: "",
+            error: "Code: 26, displayText = Argument `name` of function grouping is not in GROUP BY.",
+        },
         Test {
             name: "unsupported-function",
             sql: "select unsupported()",
@@ -154,7 +208,7 @@ fn test_plan_parser() -> Result<()> {
             error: "Code: 25, displayText = Unknown table: 't'.",
         },
         Test {
-            name: "insert-subquery-not-supported",
+            name: "insert-select-unknown-table",
             sql: "insert into t select * from t",
             expect: "",
             error: "Code: 25, displayText = Unknown table: 't'.",
@@ -177,10 +231,63 @@ fn test_plan_parser() -> Result<()> {
         },
 
         Test {
-            name: "unimplemented-cte",
-            sql: "with t as ( select sum(number) n from system.numbers_mt(1000) )select * from t",
+            name: "cte-inline-passed",
+            sql: "with t as (select number from numbers(10) where number > 1) select * from t",
+            expect: "\
+            Projection: number:UInt64\
+            \n  Projection: number:UInt64\
+            \n    Filter: (number > 1)\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "cte-columns-mismatch",
+            sql: "with t(a, b) as (select number from numbers(10)) select * from t",
+            expect: "",
+            error: "Code: 6, displayText = Number of columns doesn't match, CTE 't' returns 1 columns, but 2 column names are specified.",
+        },
+        Test {
+            name: "cte-duplicate-name",
+            sql: "with t as (select 1), t as (select 2) select * from t",
+            expect: "",
+            error: "Code: 5, displayText = Duplicate CTE name 't'.",
+        },
+        Test {
+            name: "insert-format-unknown",
+            sql: "insert into t1 format xml",
+            expect: "",
+            error: "Code: 56, displayText = Unknown format: xml, expect one of CSV, TSV, JSONEachRow, Parquet, Values.",
+        },
+        Test {
+            name: "union-all-passed",
+            sql: "select number from numbers(10) union all select number + 1 from numbers(10)",
+            expect: "\
+            UnionAll\
+            \n  Projection: number:UInt64\
+            \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]\
+            \n  Projection: (number + 1) as number:UInt64\
+            \n    Projection: (number + 1):UInt64\
+            \n      Expression: (number + 1):UInt64 (Before Projection)\
+            \n        ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "union-columns-mismatch",
+            sql: "select 1 union all select 1, 2",
+            expect: "",
+            error: "Code: 6, displayText = Number of columns doesn't match, the left side of UNION returns 1 columns, but the right side returns 2.",
+        },
+        Test {
+            name: "intersect-all-unimplemented",
+            sql: "select 1 intersect all select 1",
+            expect: "",
+            error: "Code: 2, displayText = INTERSECT ALL is not yet implemented.",
+        },
+        Test {
+            name: "recursive-cte-union-distinct",
+            sql: "with recursive t(n) as (select 1 union select n + 1 from t where n < 3) select * from t",
             expect: "",
-            error: "Code: 2, displayText = CTE is not yet implement.",
+            error: "Code: 2, displayText = Recursive CTE 't' only supports UNION ALL.",
         },
         Test {
             name: "kleene-logic-null",
@@ -199,7 +306,131 @@ fn test_plan_parser() -> Result<()> {
             \n  Filter: (NULL AND true)\
             \n    ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
             error: "",
-        }
+        },
+        Test {
+            name: "array-join-passed",
+            sql: "select arrayJoin([number, 1]) from numbers(10)",
+            expect: "\
+            Projection: arrayJoin(array(number, 1)):UInt64\
+            \n  ArrayJoin: arrayJoin(array(number, 1))\
+            \n    Expression: array(number, 1):List(DataField { name: \"item\", data_type: UInt64, nullable: true }) (Before ArrayJoin)\
+            \n      ReadDataSource: scan partitions: [8], scan schema: [number:UInt64], statistics: [read_rows: 10, read_bytes: 80]",
+            error: "",
+        },
+        Test {
+            name: "lambda-capture-column-error",
+            sql: "select arrayMap(x -> x + number, [number]) from numbers(10)",
+            expect: "",
+            error: "Code: 6, displayText = Lambda can only refer to its parameters, but got column number.",
+        },
+        Test {
+            name: "copy-into-unknown-format",
+            sql: "copy into system.settings from '/tmp/data' file_format = (type = XML)",
+            expect: "",
+            error: "Code: 56, displayText = Unknown format: XML, expect one of CSV, TSV, JSONEachRow, Parquet, Values.",
+        },
+        Test {
+            name: "copy-into-validation-mode",
+            sql: "copy into system.settings from '/tmp/data' validation_mode = return_all",
+            expect: "",
+            error: "Code: 5, displayText = Unsupported VALIDATION_MODE: return_all, expect RETURN_ERRORS.",
+        },
+        Test {
+            name: "delete-passed",
+            sql: "delete from system.settings where name = 'max_threads'",
+            expect: "Delete from system.settings, where: (name = max_threads)",
+            error: "",
+        },
+        Test {
+            name: "delete-aggregate-error",
+            sql: "delete from system.settings where count(name) > 1",
+            expect: "",
+            error: "Code: 5, displayText = Aggregate functions, arrayJoin and subqueries are not allowed in DELETE and UPDATE: (count(name) > 1).",
+        },
+        Test {
+            name: "update-passed",
+            sql: "update system.settings set value = '1' where name = 'max_threads'",
+            expect: "Update system.settings set value = cast(1 as String), where: (name = max_threads)",
+            error: "",
+        },
+        Test {
+            name: "update-column-twice-error",
+            sql: "update system.settings set value = '1', value = '2'",
+            expect: "",
+            error: "Code: 6, displayText = Column value is updated more than once.",
+        },
+        Test {
+            name: "alter-table-add-column-passed",
+            sql: "alter table system.settings add column level int default 1",
+            expect: "Alter table system.settings add column level:Int32 default 1",
+            error: "",
+        },
+        Test {
+            name: "alter-table-rename-column-passed",
+            sql: "alter table system.settings rename column value to val",
+            expect: "Alter table system.settings rename column value to val",
+            error: "",
+        },
+        Test {
+            name: "rename-table-passed",
+            sql: "rename table system.settings to settings2",
+            expect: "Alter table system.settings rename to default.settings2",
+            error: "",
+        },
+        Test {
+            name: "alter-table-add-column-exists-error",
+            sql: "alter table system.settings add column name int",
+            expect: "",
+            error: "Code: 6, displayText = Column name already exists.",
+        },
+        Test {
+            name: "alter-table-modify-column-narrowing-error",
+            sql: "alter table system.settings modify column value int",
+            expect: "",
+            error: "Code: 6, displayText = Can not modify column value from String to Int32, only widening of the type is supported.",
+        },
+        Test {
+            name: "alter-table-default-not-constant-error",
+            sql: "alter table system.settings add column level int default name",
+            expect: "",
+            error: "Code: 6, displayText = DEFAULT of column level must be a constant, but got: name.",
+        },
+        Test {
+            name: "create-view-passed",
+            sql: "create view v as select name from system.settings",
+            expect: "Create view default.v, if_not_exists:false, as: SELECT name FROM system.settings",
+            error: "",
+        },
+        Test {
+            name: "create-materialized-view-passed",
+            sql: "create materialized view if not exists mv engine = Memory as select name from system.settings",
+            expect: "Create materialized view default.mv, to: default..inner.mv, engine: Memory, if_not_exists:true, as: SELECT name FROM system.settings",
+            error: "",
+        },
+        Test {
+            name: "create-materialized-view-join-error",
+            sql: "create materialized view mv as select a.name from system.settings a, system.tables b",
+            expect: "",
+            error: "Code: 5, displayText = The query of a materialized view must read from exactly one table: SELECT a.name FROM system.settings AS a, system.tables AS b.",
+        },
+        Test {
+            name: "create-materialized-view-target-columns-error",
+            sql: "create materialized view mv to system.tables as select name from system.settings",
+            expect: "",
+            error: "Code: 6, displayText = Number of columns doesn't match, the target table expect 3 columns, but the query returns 1.",
+        },
+        Test {
+            name: "select-unknown-system-variable-error",
+            sql: "select @@session.unknown_variable",
+            expect: "",
+            error: "Code: 20, displayText = Unknown system variable @@session.unknown_variable.",
+        },
+        Test {
+            name: "show-columns-unknown-table-error",
+            sql: "show full columns from system.unknown_table",
+            expect: "",
+            error: "Code: 25, displayText = Unknown table: 'unknown_table'.",
+        },
     ];
 
     let ctx = crate::tests::try_create_context()?;
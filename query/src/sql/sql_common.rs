@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_datavalues::prelude::*;
+use common_datavalues::Tz;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use sqlparser::ast::DataType as SQLDataType;
@@ -54,6 +55,34 @@ impl SQLCommon {
                     "FLOAT32" => Ok(DataType::Float32),
                     "FLOAT64" => Ok(DataType::Float64),
                     "STRING" => Ok(DataType::String),
+                    // DateTime64[(precision[, 'timezone'])], see DfParser::parse_data_type
+                    "DATETIME64" => {
+                        let precision = match obj.0.get(1) {
+                            None => Some(3),
+                            Some(precision) => {
+                                precision.value.parse::<u32>().ok().filter(|p| *p <= 9)
+                            }
+                        };
+                        let precision = precision.ok_or_else(|| {
+                            ErrorCode::IllegalDataType(
+                                "The precision of DateTime64 must be an integer between 0 and 9",
+                            )
+                        })?;
+
+                        let tz = match obj.0.get(2) {
+                            None => None,
+                            Some(tz) => {
+                                tz.value.parse::<Tz>().map_err(|_| {
+                                    ErrorCode::IllegalDataType(format!(
+                                        "Unknown timezone: {:?}",
+                                        tz.value
+                                    ))
+                                })?;
+                                Some(tz.value.clone())
+                            }
+                        };
+                        Ok(DataType::DateTime64(precision, tz))
+                    }
 
                     _ => Result::Err(ErrorCode::IllegalDataType(format!(
                         "The SQL data type {:?} is not implemented",
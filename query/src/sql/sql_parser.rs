@@ -15,42 +15,62 @@
 // Borrow from apache/arrow/rust/datafusion/src/sql/sql_parser
 // See notice.md
 
+use std::collections::HashMap;
 use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_planners::ExplainType;
+use common_planners::TransactionKind;
 use metrics::histogram;
 use sqlparser::ast::BinaryOperator;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
+use sqlparser::ast::DataType;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
 use sqlparser::ast::SqlOption;
 use sqlparser::ast::TableConstraint;
 use sqlparser::ast::Value;
 use sqlparser::dialect::keywords::Keyword;
 use sqlparser::dialect::Dialect;
 use sqlparser::dialect::GenericDialect;
+use sqlparser::parser::IsOptional;
 use sqlparser::parser::Parser;
 use sqlparser::parser::ParserError;
 use sqlparser::tokenizer::Token;
 use sqlparser::tokenizer::Tokenizer;
 use sqlparser::tokenizer::Whitespace;
 
+use crate::sql::sql_token_rewriter::TokenRewriter;
+use crate::sql::DfAlterTable;
+use crate::sql::DfAlterTableAction;
+use crate::sql::DfCopy;
 use crate::sql::DfCreateDatabase;
+use crate::sql::DfCreateResourceGroup;
 use crate::sql::DfCreateTable;
+use crate::sql::DfCreateView;
 use crate::sql::DfDescribeTable;
 use crate::sql::DfDropDatabase;
+use crate::sql::DfDropResourceGroup;
 use crate::sql::DfDropTable;
 use crate::sql::DfExplain;
 use crate::sql::DfHint;
+use crate::sql::DfInsertFormat;
 use crate::sql::DfKillStatement;
+use crate::sql::DfSetNames;
+use crate::sql::DfShowColumns;
 use crate::sql::DfShowCreateTable;
 use crate::sql::DfShowDatabases;
+use crate::sql::DfShowFilter;
+use crate::sql::DfShowFullTables;
+use crate::sql::DfShowIndex;
 use crate::sql::DfShowProcessList;
 use crate::sql::DfShowSettings;
 use crate::sql::DfShowTables;
+use crate::sql::DfShowVariables;
 use crate::sql::DfStatement;
+use crate::sql::DfTransaction;
 use crate::sql::DfTruncateTable;
 use crate::sql::DfUseDatabase;
 
@@ -76,7 +96,7 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let tokens = TokenRewriter::rewrite(tokenizer.tokenize()?)?;
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
@@ -161,48 +181,38 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_drop()
                     }
+                    Keyword::ALTER => {
+                        self.parser.next_token();
+                        self.parse_alter()
+                    }
                     Keyword::EXPLAIN => {
                         self.parser.next_token();
                         self.parse_explain()
                     }
                     Keyword::SHOW => {
                         self.parser.next_token();
-                        if self.consume_token("TABLES") {
-                            let tok = self.parser.next_token();
-                            match &tok {
-                                Token::EOF | Token::SemiColon => {
-                                    Ok(DfStatement::ShowTables(DfShowTables::All))
-                                }
-                                Token::Word(w) => match w.keyword {
-                                    Keyword::LIKE => Ok(DfStatement::ShowTables(
-                                        DfShowTables::Like(self.parser.parse_identifier()?),
-                                    )),
-                                    Keyword::WHERE => Ok(DfStatement::ShowTables(
-                                        DfShowTables::Where(self.parser.parse_expr()?),
-                                    )),
-                                    Keyword::FROM | Keyword::IN => Ok(DfStatement::ShowTables(
-                                        DfShowTables::FromOrIn(self.parser.parse_object_name()?),
-                                    )),
-                                    _ => self.expected("like or where", tok),
-                                },
-                                _ => self.expected("like or where", tok),
-                            }
-                        } else if self.consume_token("DATABASES") {
-                            self.parse_show_databases()
-                        } else if self.consume_token("SETTINGS") {
-                            Ok(DfStatement::ShowSettings(DfShowSettings))
-                        } else if self.consume_token("CREATE") {
-                            self.parse_show_create()
-                        } else if self.consume_token("PROCESSLIST") {
-                            Ok(DfStatement::ShowProcessList(DfShowProcessList))
-                        } else {
-                            self.expected("tables or settings", self.parser.peek_token())
-                        }
+                        self.parse_show()
                     }
+                    Keyword::SET => self.parse_set(),
                     Keyword::TRUNCATE => {
                         self.parser.next_token();
                         self.parse_truncate()
                     }
+                    Keyword::INSERT => self.parse_insert(),
+                    Keyword::COPY => {
+                        self.parser.next_token();
+                        self.parse_copy()
+                    }
+                    // RENAME may or may not be a keyword of the native parser
+                    _ if w.value.to_uppercase() == "RENAME" => self.parse_rename_table(),
+                    // Transaction control is handled by the session, not the native parser
+                    _ if matches!(
+                        w.value.to_uppercase().as_str(),
+                        "BEGIN" | "START" | "COMMIT" | "ROLLBACK"
+                    ) =>
+                    {
+                        self.parse_transaction()
+                    }
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
@@ -236,6 +246,10 @@ impl<'a> DfParser<'a> {
                     self.parser.next_token();
                     ExplainType::Graph
                 }
+                "ANALYZE" => {
+                    self.parser.next_token();
+                    ExplainType::Analyze
+                }
                 _ => ExplainType::Syntax,
             },
             _ => ExplainType::Syntax,
@@ -246,6 +260,267 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::Explain(explain_plan))
     }
 
+    /// Parse `INSERT INTO [TABLE] table [(columns)] FORMAT format`, whose data follows the
+    /// statement, the other inserts are left to the native parser.
+    fn parse_insert(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.is_insert_format() {
+            return Ok(DfStatement::Statement(self.parser.parse_statement()?));
+        }
+
+        self.parser.expect_keyword(Keyword::INSERT)?;
+        self.parser.expect_keyword(Keyword::INTO)?;
+        self.parser.parse_keyword(Keyword::TABLE);
+        let table_name = self.parser.parse_object_name()?;
+        let columns = self
+            .parser
+            .parse_parenthesized_column_list(IsOptional::Optional)?;
+
+        if !self.consume_token("FORMAT") {
+            return self.expected("FORMAT", self.parser.peek_token());
+        }
+        let format = self.parser.parse_identifier()?.value;
+
+        Ok(DfStatement::InsertFormat(DfInsertFormat {
+            table_name,
+            columns,
+            format,
+        }))
+    }
+
+    /// Parse `COPY INTO table [(columns)] FROM 'location' [options]`, the options are
+    /// `PATTERN = 'regex'`, `FILE_FORMAT = (KEY = value ...)`,
+    /// `VALIDATION_MODE = RETURN_ERRORS` and `FORCE = TRUE | FALSE`, in any order.
+    fn parse_copy(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::INTO)?;
+        let name = self.parser.parse_object_name()?;
+        let columns = self
+            .parser
+            .parse_parenthesized_column_list(IsOptional::Optional)?;
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let location = self.parser.parse_literal_string()?;
+
+        let mut copy = DfCopy {
+            name,
+            columns,
+            location,
+            pattern: None,
+            file_format: HashMap::new(),
+            validation_mode: None,
+            force: false,
+        };
+
+        loop {
+            if self.consume_token("PATTERN") {
+                self.parser.expect_token(&Token::Eq)?;
+                copy.pattern = Some(self.parser.parse_literal_string()?);
+            } else if self.consume_token("FILE_FORMAT") {
+                self.parser.expect_token(&Token::Eq)?;
+                copy.file_format = self.parse_copy_options()?;
+            } else if self.consume_token("VALIDATION_MODE") {
+                self.parser.expect_token(&Token::Eq)?;
+                copy.validation_mode = Some(self.parser.parse_identifier()?.value);
+            } else if self.consume_token("FORCE") {
+                self.parser.expect_token(&Token::Eq)?;
+                copy.force = match self.parser.next_token() {
+                    Token::Word(w) if w.keyword == Keyword::TRUE => true,
+                    Token::Word(w) if w.keyword == Keyword::FALSE => false,
+                    unexpected => return self.expected("TRUE or FALSE", unexpected),
+                };
+            } else {
+                break;
+            }
+        }
+
+        match self.parser.peek_token() {
+            Token::EOF | Token::SemiColon => Ok(DfStatement::Copy(copy)),
+            unexpected => self.expected("copy option", unexpected),
+        }
+    }
+
+    // Parse `(KEY = value ...)`, the values are words, strings or numbers.
+    fn parse_copy_options(&mut self) -> Result<HashMap<String, String>, ParserError> {
+        self.parser.expect_token(&Token::LParen)?;
+        let mut options = HashMap::new();
+        while !self.parser.consume_token(&Token::RParen) {
+            let key = self.parser.parse_identifier()?.value.to_lowercase();
+            self.parser.expect_token(&Token::Eq)?;
+            let value = match self.parser.next_token() {
+                Token::Word(w) => w.value,
+                Token::SingleQuotedString(s) => s,
+                Token::Number(n, _) => n,
+                unexpected => return self.expected("option value", unexpected),
+            };
+            options.insert(key, value);
+            self.parser.consume_token(&Token::Comma);
+        }
+        Ok(options)
+    }
+
+    // Look ahead for FORMAT out of the column list and before VALUES or the source query,
+    // then rewind to INSERT.
+    fn is_insert_format(&mut self) -> bool {
+        let mut consumed = 0;
+        let mut depth = 0;
+        let found = loop {
+            let token = self.parser.next_token();
+            consumed += 1;
+            match token {
+                Token::EOF | Token::SemiColon => break false,
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::Word(w) if depth == 0 && w.value.to_uppercase() == "FORMAT" => break true,
+                Token::Word(w)
+                    if matches!(w.keyword, Keyword::VALUES | Keyword::SELECT | Keyword::WITH) =>
+                {
+                    break false
+                }
+                _ => continue,
+            }
+        };
+
+        for _ in 0..consumed {
+            self.parser.prev_token();
+        }
+        found
+    }
+
+    fn parse_show(&mut self) -> Result<DfStatement, ParserError> {
+        let full = self.consume_token("FULL");
+        if self.consume_token("TABLES") {
+            if full {
+                return self.parse_show_full_tables();
+            }
+            let tok = self.parser.next_token();
+            match &tok {
+                Token::EOF | Token::SemiColon => Ok(DfStatement::ShowTables(DfShowTables::All)),
+                Token::Word(w) => match w.keyword {
+                    Keyword::LIKE => Ok(DfStatement::ShowTables(DfShowTables::Like(
+                        self.parser.parse_identifier()?,
+                    ))),
+                    Keyword::WHERE => Ok(DfStatement::ShowTables(DfShowTables::Where(
+                        self.parser.parse_expr()?,
+                    ))),
+                    Keyword::FROM | Keyword::IN => Ok(DfStatement::ShowTables(
+                        DfShowTables::FromOrIn(self.parser.parse_object_name()?),
+                    )),
+                    _ => self.expected("like or where", tok),
+                },
+                _ => self.expected("like or where", tok),
+            }
+        } else if self.consume_token("COLUMNS") || self.consume_token("FIELDS") {
+            self.parse_show_columns(full)
+        } else if self.consume_token("PROCESSLIST") {
+            Ok(DfStatement::ShowProcessList(DfShowProcessList))
+        } else if full {
+            self.expected("tables, columns or processlist", self.parser.peek_token())
+        } else if self.consume_token("DATABASES") {
+            self.parse_show_databases()
+        } else if self.consume_token("SETTINGS") {
+            Ok(DfStatement::ShowSettings(DfShowSettings))
+        } else if self.consume_token("CREATE") {
+            self.parse_show_create()
+        } else if self.consume_token("VARIABLES") {
+            self.parse_show_variables()
+        } else if self.consume_token("SESSION") || self.consume_token("GLOBAL") {
+            if !self.consume_token("VARIABLES") {
+                return self.expected("variables", self.parser.peek_token());
+            }
+            self.parse_show_variables()
+        } else if self.consume_token("INDEX")
+            || self.consume_token("INDEXES")
+            || self.consume_token("KEYS")
+        {
+            self.parse_show_index()
+        } else {
+            self.expected("tables or settings", self.parser.peek_token())
+        }
+    }
+
+    // The MySQL SHOW statements for the drivers and the BI tools.
+    fn parse_show_variables(&mut self) -> Result<DfStatement, ParserError> {
+        let filter = self.parse_show_filter()?;
+        Ok(DfStatement::ShowVariables(DfShowVariables { filter }))
+    }
+
+    fn parse_show_full_tables(&mut self) -> Result<DfStatement, ParserError> {
+        let database = self.parse_show_from()?;
+        let filter = self.parse_show_filter()?;
+        Ok(DfStatement::ShowFullTables(DfShowFullTables {
+            database,
+            filter,
+        }))
+    }
+
+    fn parse_show_columns(&mut self, full: bool) -> Result<DfStatement, ParserError> {
+        self.parser
+            .expect_one_of_keywords(&[Keyword::FROM, Keyword::IN])?;
+        let table = self.parser.parse_object_name()?;
+        let database = self.parse_show_from()?;
+        let filter = self.parse_show_filter()?;
+        Ok(DfStatement::ShowColumns(DfShowColumns {
+            full,
+            table,
+            database,
+            filter,
+        }))
+    }
+
+    fn parse_show_index(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser
+            .expect_one_of_keywords(&[Keyword::FROM, Keyword::IN])?;
+        let table = self.parser.parse_object_name()?;
+        let database = self.parse_show_from()?;
+        Ok(DfStatement::ShowIndex(DfShowIndex { table, database }))
+    }
+
+    fn parse_show_from(&mut self) -> Result<Option<ObjectName>, ParserError> {
+        match self
+            .parser
+            .parse_one_of_keywords(&[Keyword::FROM, Keyword::IN])
+        {
+            Some(_) => Ok(Some(self.parser.parse_object_name()?)),
+            None => Ok(None),
+        }
+    }
+
+    fn parse_show_filter(&mut self) -> Result<Option<DfShowFilter>, ParserError> {
+        if self.parser.parse_keyword(Keyword::LIKE) {
+            Ok(Some(DfShowFilter::Like(self.parser.parse_expr()?)))
+        } else if self.parser.parse_keyword(Keyword::WHERE) {
+            Ok(Some(DfShowFilter::Where(self.parser.parse_expr()?)))
+        } else if self.parser.peek_token() == Token::EOF
+            || self.parser.peek_token() == Token::SemiColon
+        {
+            Ok(None)
+        } else {
+            self.expected("like or where", self.parser.peek_token())
+        }
+    }
+
+    // `SET NAMES charset [COLLATE collation]`, the other SET statements are parsed by the native parser.
+    fn parse_set(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.next_token();
+        if !self.consume_token("NAMES") {
+            self.parser.prev_token();
+            return Ok(DfStatement::Statement(self.parser.parse_statement()?));
+        }
+
+        let charset = self.parse_set_names_value()?;
+        let collation = match self.consume_token("COLLATE") {
+            true => Some(self.parse_set_names_value()?),
+            false => None,
+        };
+        Ok(DfStatement::SetNames(DfSetNames { charset, collation }))
+    }
+
+    fn parse_set_names_value(&mut self) -> Result<String, ParserError> {
+        match self.parser.next_token() {
+            Token::Word(w) => Ok(w.value),
+            Token::SingleQuotedString(s) => Ok(s),
+            unexpected => self.expected("character set or collation", unexpected),
+        }
+    }
+
     // parse show databases where database = xxx or where database
     fn parse_show_databases(&mut self) -> Result<DfStatement, ParserError> {
         if self.parser.parse_keyword(Keyword::WHERE) {
@@ -313,6 +588,36 @@ impl<'a> DfParser<'a> {
         Ok((columns, constraints))
     }
 
+    /// The custom types of sqlparser can't have parameters, `DateTime64(precision[, 'timezone'])`
+    /// is parsed here and carried as `Custom([DateTime64, precision, 'timezone'])`.
+    fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        match self.parser.peek_token() {
+            Token::Word(w) if w.value.to_uppercase() == "DATETIME64" => {
+                self.parser.next_token();
+                let mut parts = vec![Ident::new(w.value)];
+                if self.parser.consume_token(&Token::LParen) {
+                    match self.parser.next_token() {
+                        Token::Number(n, _) => parts.push(Ident::new(n)),
+                        unexpected => return self.expected("precision of DateTime64", unexpected),
+                    }
+                    if self.parser.consume_token(&Token::Comma) {
+                        match self.parser.next_token() {
+                            Token::SingleQuotedString(tz) => {
+                                parts.push(Ident::with_quote('\'', tz))
+                            }
+                            unexpected => {
+                                return self.expected("timezone of DateTime64", unexpected)
+                            }
+                        }
+                    }
+                    self.parser.expect_token(&Token::RParen)?;
+                }
+                Ok(DataType::Custom(ObjectName(parts)))
+            }
+            _ => self.parser.parse_data_type(),
+        }
+    }
+
     /// This is a copy from sqlparser
     /// Parse a literal value (numbers, strings, date/time, booleans)
     fn parse_value(&mut self) -> Result<Value, ParserError> {
@@ -344,7 +649,7 @@ impl<'a> DfParser<'a> {
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parser.parse_identifier()?;
-        let data_type = self.parser.parse_data_type()?;
+        let data_type = self.parse_data_type()?;
         let collation = if self.parser.parse_keyword(Keyword::COLLATE) {
             Some(self.parser.parse_object_name()?)
         } else {
@@ -381,6 +686,12 @@ impl<'a> DfParser<'a> {
             Token::Word(w) => match w.keyword {
                 Keyword::TABLE => self.parse_create_table(),
                 Keyword::DATABASE => self.parse_create_database(),
+                Keyword::VIEW => self.parse_create_view(false),
+                Keyword::MATERIALIZED => {
+                    self.parser.expect_keyword(Keyword::VIEW)?;
+                    self.parse_create_view(true)
+                }
+                _ if w.value.to_uppercase() == "RESOURCE" => self.parse_create_resource_group(),
                 _ => self.expected("create statement", Token::Word(w)),
             },
             unexpected => self.expected("create statement", unexpected),
@@ -404,6 +715,53 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateDatabase(create))
     }
 
+    fn parse_create_view(&mut self, materialized: bool) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name()?;
+
+        let (mut to, mut engine) = (None, None);
+        if materialized {
+            if self.consume_token("TO") {
+                to = Some(self.parser.parse_object_name()?);
+            } else if self.consume_token("ENGINE") {
+                self.parser.expect_token(&Token::Eq)?;
+                engine = Some(self.parser.next_token().to_string());
+            }
+        }
+
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = Box::new(self.parser.parse_query()?);
+
+        Ok(DfStatement::CreateView(DfCreateView {
+            if_not_exists,
+            name,
+            materialized,
+            to,
+            engine,
+            query,
+        }))
+    }
+
+    fn parse_create_resource_group(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::GROUP)?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_identifier()?;
+        let options = match self.parser.parse_keyword(Keyword::WITH) {
+            true => self.parse_copy_options()?,
+            false => HashMap::new(),
+        };
+
+        Ok(DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists,
+            name,
+            options,
+        }))
+    }
+
     fn parse_describe(&mut self) -> Result<DfStatement, ParserError> {
         let table_name = self.parser.parse_object_name()?;
         let desc = DfDescribeTable { name: table_name };
@@ -415,7 +773,9 @@ impl<'a> DfParser<'a> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::DATABASE => self.parse_drop_database(),
-                Keyword::TABLE => self.parse_drop_table(),
+                // Views are kept as tables
+                Keyword::TABLE | Keyword::VIEW => self.parse_drop_table(),
+                _ if w.value.to_uppercase() == "RESOURCE" => self.parse_drop_resource_group(),
                 _ => self.expected("drop statement", Token::Word(w)),
             },
             unexpected => self.expected("drop statement", unexpected),
@@ -448,6 +808,69 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropTable(drop))
     }
 
+    /// Drop resource group.
+    fn parse_drop_resource_group(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::GROUP)?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_identifier()?;
+
+        Ok(DfStatement::DropResourceGroup(DfDropResourceGroup {
+            if_exists,
+            name,
+        }))
+    }
+
+    /// Alter table.
+    fn parse_alter(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+
+        let action = if self.consume_token("ADD") {
+            self.consume_token("COLUMN");
+            DfAlterTableAction::AddColumn(self.parse_column_def()?)
+        } else if self.consume_token("DROP") {
+            self.consume_token("COLUMN");
+            DfAlterTableAction::DropColumn(self.parser.parse_identifier()?)
+        } else if self.consume_token("MODIFY") {
+            self.consume_token("COLUMN");
+            DfAlterTableAction::ModifyColumn(self.parse_column_def()?)
+        } else if self.consume_token("RENAME") {
+            if self.consume_token("TO") {
+                DfAlterTableAction::RenameTable(self.parser.parse_object_name()?)
+            } else {
+                self.consume_token("COLUMN");
+                let old = self.parser.parse_identifier()?;
+                if !self.consume_token("TO") {
+                    return self.expected("TO", self.parser.peek_token());
+                }
+                let new = self.parser.parse_identifier()?;
+                DfAlterTableAction::RenameColumn { old, new }
+            }
+        } else {
+            return self.expected("ADD, DROP, MODIFY or RENAME", self.parser.peek_token());
+        };
+
+        Ok(DfStatement::AlterTable(DfAlterTable { name, action }))
+    }
+
+    // Parse 'RENAME TABLE a TO b'.
+    fn parse_rename_table(&mut self) -> Result<DfStatement, ParserError> {
+        if !self.consume_token("RENAME") {
+            return self.expected("Must RENAME", self.parser.peek_token());
+        }
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+        if !self.consume_token("TO") {
+            return self.expected("TO", self.parser.peek_token());
+        }
+        let new_name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::AlterTable(DfAlterTable {
+            name,
+            action: DfAlterTableAction::RenameTable(new_name),
+        }))
+    }
+
     // Parse 'use database' db name.
     fn parse_use_database(&mut self) -> Result<DfStatement, ParserError> {
         if !self.consume_token("USE") {
@@ -476,6 +899,31 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // Parse 'BEGIN [WORK | TRANSACTION]', 'START TRANSACTION', 'COMMIT [WORK]' or 'ROLLBACK [WORK]'.
+    fn parse_transaction(&mut self) -> Result<DfStatement, ParserError> {
+        let kind = if self.consume_token("BEGIN") {
+            if !self.consume_token("WORK") {
+                self.consume_token("TRANSACTION");
+            }
+            TransactionKind::Begin
+        } else if self.consume_token("START") {
+            if !self.consume_token("TRANSACTION") {
+                return self.expected("TRANSACTION", self.parser.peek_token());
+            }
+            TransactionKind::Begin
+        } else if self.consume_token("COMMIT") {
+            self.consume_token("WORK");
+            TransactionKind::Commit
+        } else if self.consume_token("ROLLBACK") {
+            self.consume_token("WORK");
+            TransactionKind::Rollback
+        } else {
+            return self.expected("BEGIN, START, COMMIT or ROLLBACK", self.parser.peek_token());
+        };
+
+        Ok(DfStatement::Transaction(DfTransaction { kind }))
+    }
+
     fn parse_database_engine(&mut self) -> Result<String, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
@@ -491,6 +939,21 @@ impl<'a> DfParser<'a> {
             self.parser
                 .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let table_name = self.parser.parse_object_name()?;
+
+        if self.consume_token("LIKE") {
+            let like = self.parser.parse_object_name()?;
+            let engine = self.parse_table_engine()?;
+            return Ok(DfStatement::CreateTable(DfCreateTable {
+                if_not_exists,
+                name: table_name,
+                columns: vec![],
+                engine,
+                options: vec![],
+                like: Some(like),
+                query: None,
+            }));
+        }
+
         let (columns, _) = self.parse_columns()?;
         let engine = self.parse_table_engine()?;
 
@@ -506,26 +969,33 @@ impl<'a> DfParser<'a> {
             })
         }
 
+        let query = match self.parser.parse_keyword(Keyword::AS) {
+            true => Some(Box::new(self.parser.parse_query()?)),
+            false => None,
+        };
+
         let create = DfCreateTable {
             if_not_exists,
             name: table_name,
             columns,
             engine,
             options: table_properties,
+            like: None,
+            query,
         };
 
         Ok(DfStatement::CreateTable(create))
     }
 
     /// Parses the set of valid formats
-    fn parse_table_engine(&mut self) -> Result<String, ParserError> {
+    fn parse_table_engine(&mut self) -> Result<Option<String>, ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
-            return Ok("NULL".to_string());
+            return Ok(None);
         }
 
         self.parser.expect_token(&Token::Eq)?;
-        Ok(self.parser.next_token().to_string())
+        Ok(Some(self.parser.next_token().to_string()))
     }
 
     fn parse_show_create(&mut self) -> Result<DfStatement, ParserError> {
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_exception::Result;
+use common_planners::ExplainType;
+use common_planners::TransactionKind;
 use sqlparser::ast::*;
 
 use crate::sql::sql_statement::DfDropDatabase;
@@ -124,11 +128,13 @@ fn create_table() -> Result<()> {
         if_not_exists: false,
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", DataType::Int)],
-        engine: "CSV".to_string(),
+        engine: Some("CSV".to_string()),
         options: vec![SqlOption {
             name: Ident::new("LOCATION".to_string()),
             value: Value::SingleQuotedString("/data/33.csv".into()),
         }],
+        like: None,
+        query: None,
     });
     expect_parse_ok(sql, expected)?;
 
@@ -142,11 +148,69 @@ fn create_table() -> Result<()> {
             make_column_def("c2", DataType::BigInt),
             make_column_def("c3", DataType::Varchar(Some(255))),
         ],
-        engine: "Parquet".to_string(),
+        engine: Some("Parquet".to_string()),
         options: vec![SqlOption {
             name: Ident::new("LOCATION".to_string()),
             value: Value::SingleQuotedString("foo.parquet".into()),
         }],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // positive case: the precision and the timezone of DateTime64
+    let sql = "CREATE TABLE t(c1 DateTime64, c2 DateTime64(6, 'Asia/Shanghai')) ENGINE = Fuse";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def(
+                "c1",
+                DataType::Custom(ObjectName(vec![Ident::new("DateTime64")])),
+            ),
+            make_column_def(
+                "c2",
+                DataType::Custom(ObjectName(vec![
+                    Ident::new("DateTime64"),
+                    Ident::new("6"),
+                    Ident::with_quote('\'', "Asia/Shanghai"),
+                ])),
+            ),
+        ],
+        engine: Some("Fuse".to_string()),
+        options: vec![],
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // positive case: the schema is inferred from the query
+    let sql = "CREATE TABLE t ENGINE = Memory AS SELECT a, b FROM t1";
+    let query = match DfParser::parse_sql("SELECT a, b FROM t1")?.0.remove(0) {
+        DfStatement::Statement(Statement::Query(query)) => query,
+        other => panic!("unexpected statement {:?}", other),
+    };
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![],
+        engine: Some("Memory".to_string()),
+        options: vec![],
+        like: None,
+        query: Some(query),
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // positive case: the schema, the engine and the options are cloned
+    let sql = "CREATE TABLE IF NOT EXISTS t LIKE db1.t1";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: true,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![],
+        engine: None,
+        options: vec![],
+        like: Some(ObjectName(vec![Ident::new("db1"), Ident::new("t1")])),
+        query: None,
     });
     expect_parse_ok(sql, expected)?;
 
@@ -175,6 +239,109 @@ fn drop_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn alter_table() -> Result<()> {
+    let alter = |action: DfAlterTableAction| {
+        DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            action,
+        })
+    };
+
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 ADD COLUMN c1 bigint",
+        alter(DfAlterTableAction::AddColumn(make_column_def(
+            "c1",
+            DataType::BigInt,
+        ))),
+    )?;
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 ADD c1 bigint",
+        alter(DfAlterTableAction::AddColumn(make_column_def(
+            "c1",
+            DataType::BigInt,
+        ))),
+    )?;
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 DROP COLUMN c1",
+        alter(DfAlterTableAction::DropColumn(Ident::new("c1"))),
+    )?;
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 RENAME COLUMN c1 TO c2",
+        alter(DfAlterTableAction::RenameColumn {
+            old: Ident::new("c1"),
+            new: Ident::new("c2"),
+        }),
+    )?;
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 MODIFY COLUMN c1 bigint",
+        alter(DfAlterTableAction::ModifyColumn(make_column_def(
+            "c1",
+            DataType::BigInt,
+        ))),
+    )?;
+    expect_parse_ok(
+        "ALTER TABLE db1.t1 RENAME TO db2.t2",
+        alter(DfAlterTableAction::RenameTable(ObjectName(vec![
+            Ident::new("db2"),
+            Ident::new("t2"),
+        ]))),
+    )?;
+    expect_parse_ok(
+        "RENAME TABLE db1.t1 TO db2.t2",
+        alter(DfAlterTableAction::RenameTable(ObjectName(vec![
+            Ident::new("db2"),
+            Ident::new("t2"),
+        ]))),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn create_view() -> Result<()> {
+    let query = match DfParser::parse_sql("SELECT a, b FROM t1")?.0.remove(0) {
+        DfStatement::Statement(Statement::Query(query)) => query,
+        other => panic!("unexpected statement {:?}", other),
+    };
+    let view = |materialized, to: Option<ObjectName>, engine: Option<&str>| {
+        DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+            materialized,
+            to,
+            engine: engine.map(|e| e.to_string()),
+            query: query.clone(),
+        })
+    };
+
+    expect_parse_ok(
+        "CREATE VIEW db1.v1 AS SELECT a, b FROM t1",
+        view(false, None, None),
+    )?;
+    expect_parse_ok(
+        "CREATE MATERIALIZED VIEW db1.v1 AS SELECT a, b FROM t1",
+        view(true, None, None),
+    )?;
+    expect_parse_ok(
+        "CREATE MATERIALIZED VIEW db1.v1 ENGINE = Memory AS SELECT a, b FROM t1",
+        view(true, None, Some("Memory")),
+    )?;
+    expect_parse_ok(
+        "CREATE MATERIALIZED VIEW db1.v1 TO t2 AS SELECT a, b FROM t1",
+        view(true, Some(ObjectName(vec![Ident::new("t2")])), None),
+    )?;
+    expect_parse_ok(
+        "DROP VIEW db1.v1",
+        DfStatement::DropTable(DfDropTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+        }),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn describe_table() -> Result<()> {
     {
@@ -298,6 +465,81 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn insert_format() -> Result<()> {
+    {
+        let sql = "INSERT INTO db1.t1 (a, b) FORMAT CSV";
+        let expected = DfStatement::InsertFormat(DfInsertFormat {
+            table_name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            columns: vec![Ident::new("a"), Ident::new("b")],
+            format: "CSV".to_string(),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "INSERT INTO TABLE t1 FORMAT JSONEachRow";
+        let expected = DfStatement::InsertFormat(DfInsertFormat {
+            table_name: ObjectName(vec![Ident::new("t1")]),
+            columns: vec![],
+            format: "JSONEachRow".to_string(),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    // A column named format is not the FORMAT clause.
+    {
+        let sql = "INSERT INTO t1 (format) VALUES (1)";
+        let statement = DfParser::parse_sql(sql)?.0.remove(0);
+        assert!(matches!(statement, DfStatement::Statement(_)));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn copy_test() -> Result<()> {
+    {
+        let sql = "COPY INTO db1.t1 FROM 's3://bucket/data/' PATTERN = '.*\\.csv' \
+                   FILE_FORMAT = (TYPE = CSV FIELD_DELIMITER = '|' SKIP_HEADER = 1) FORCE = TRUE";
+        let mut file_format = HashMap::new();
+        file_format.insert("type".to_string(), "CSV".to_string());
+        file_format.insert("field_delimiter".to_string(), "|".to_string());
+        file_format.insert("skip_header".to_string(), "1".to_string());
+        let expected = DfStatement::Copy(DfCopy {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            columns: vec![],
+            location: "s3://bucket/data/".to_string(),
+            pattern: Some(".*\\.csv".to_string()),
+            file_format,
+            validation_mode: None,
+            force: true,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "COPY INTO t1 (a, b) FROM '/tmp/data' VALIDATION_MODE = RETURN_ERRORS";
+        let expected = DfStatement::Copy(DfCopy {
+            name: ObjectName(vec![Ident::new("t1")]),
+            columns: vec![Ident::new("a"), Ident::new("b")],
+            location: "/tmp/data".to_string(),
+            pattern: None,
+            file_format: HashMap::new(),
+            validation_mode: Some("RETURN_ERRORS".to_string()),
+            force: false,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "COPY INTO t1 FROM '/tmp/data' FORCE = 1";
+        assert!(DfParser::parse_sql(sql).is_err());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -401,3 +643,223 @@ fn show_databases_test() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn grouping_sets_test() -> Result<()> {
+    let (expected, _) = DfParser::parse_sql(
+        "SELECT a, b, sum(c) FROM t GROUP BY groupingSets(tuple(a), tuple(a, b), tuple(), b)",
+    )?;
+    expect_parse_ok(
+        "SELECT a, b, sum(c) FROM t GROUP BY GROUPING SETS ((a), (a, b), (), b)",
+        expected[0].clone(),
+    )?;
+
+    let (expected, _) = DfParser::parse_sql(
+        "SELECT a FROM t GROUP BY a, groupingSets(tuple(a), tuple([1, 2])) ORDER BY a",
+    )?;
+    expect_parse_ok(
+        "SELECT a FROM t GROUP BY a, grouping sets ((a), ([1, 2])) ORDER BY a",
+        expected[0].clone(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn mysql_show_test() -> Result<()> {
+    let like = |pattern: &str| {
+        Some(DfShowFilter::Like(Expr::Value(Value::SingleQuotedString(
+            pattern.to_string(),
+        ))))
+    };
+
+    expect_parse_ok(
+        "SHOW VARIABLES",
+        DfStatement::ShowVariables(DfShowVariables { filter: None }),
+    )?;
+    expect_parse_ok(
+        "SHOW SESSION VARIABLES LIKE 'auto%'",
+        DfStatement::ShowVariables(DfShowVariables {
+            filter: like("auto%"),
+        }),
+    )?;
+    expect_parse_ok(
+        "SHOW GLOBAL VARIABLES WHERE Variable_name = 'autocommit'",
+        DfStatement::ShowVariables(DfShowVariables {
+            filter: Some(DfShowFilter::Where(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("Variable_name"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(Value::SingleQuotedString(
+                    "autocommit".to_string(),
+                ))),
+            })),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SHOW FULL TABLES FROM db1 LIKE 't%'",
+        DfStatement::ShowFullTables(DfShowFullTables {
+            database: Some(ObjectName(vec![Ident::new("db1")])),
+            filter: like("t%"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SHOW COLUMNS FROM t1",
+        DfStatement::ShowColumns(DfShowColumns {
+            full: false,
+            table: ObjectName(vec![Ident::new("t1")]),
+            database: None,
+            filter: None,
+        }),
+    )?;
+    expect_parse_ok(
+        "SHOW FULL FIELDS IN t1 FROM db1 LIKE 'c%'",
+        DfStatement::ShowColumns(DfShowColumns {
+            full: true,
+            table: ObjectName(vec![Ident::new("t1")]),
+            database: Some(ObjectName(vec![Ident::new("db1")])),
+            filter: like("c%"),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SHOW INDEX FROM db1.t1",
+        DfStatement::ShowIndex(DfShowIndex {
+            table: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            database: None,
+        }),
+    )?;
+    expect_parse_ok(
+        "SHOW KEYS IN t1 IN db1",
+        DfStatement::ShowIndex(DfShowIndex {
+            table: ObjectName(vec![Ident::new("t1")]),
+            database: Some(ObjectName(vec![Ident::new("db1")])),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "SHOW FULL PROCESSLIST",
+        DfStatement::ShowProcessList(DfShowProcessList),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn mysql_set_test() -> Result<()> {
+    expect_parse_ok(
+        "SET NAMES utf8mb4",
+        DfStatement::SetNames(DfSetNames {
+            charset: "utf8mb4".to_string(),
+            collation: None,
+        }),
+    )?;
+    expect_parse_ok(
+        "SET NAMES 'utf8mb4' COLLATE 'utf8mb4_general_ci'",
+        DfStatement::SetNames(DfSetNames {
+            charset: "utf8mb4".to_string(),
+            collation: Some("utf8mb4_general_ci".to_string()),
+        }),
+    )?;
+
+    let (expected, _) = DfParser::parse_sql("SET max_threads = 1")?;
+    expect_parse_ok("SET GLOBAL max_threads = 1", expected[0].clone())?;
+    expect_parse_ok("SET @@session.max_threads = 1", expected[0].clone())?;
+    expect_parse_ok("SET @@max_threads = 1", expected[0].clone())?;
+
+    Ok(())
+}
+
+#[test]
+fn explain_test() -> Result<()> {
+    let (query, _) = DfParser::parse_sql("SELECT number FROM numbers(10)")?;
+    let statement = match &query[0] {
+        DfStatement::Statement(statement) => statement.clone(),
+        _ => unreachable!(),
+    };
+
+    for (sql, typ) in [
+        (
+            "EXPLAIN SELECT number FROM numbers(10)",
+            ExplainType::Syntax,
+        ),
+        (
+            "EXPLAIN GRAPH SELECT number FROM numbers(10)",
+            ExplainType::Graph,
+        ),
+        (
+            "EXPLAIN PIPELINE SELECT number FROM numbers(10)",
+            ExplainType::Pipeline,
+        ),
+        (
+            "EXPLAIN ANALYZE SELECT number FROM numbers(10)",
+            ExplainType::Analyze,
+        ),
+    ] {
+        expect_parse_ok(
+            sql,
+            DfStatement::Explain(DfExplain {
+                typ,
+                statement: Box::new(statement.clone()),
+            }),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn transaction_test() -> Result<()> {
+    for (sql, kind) in [
+        ("BEGIN", TransactionKind::Begin),
+        ("begin work", TransactionKind::Begin),
+        ("BEGIN TRANSACTION", TransactionKind::Begin),
+        ("START TRANSACTION", TransactionKind::Begin),
+        ("COMMIT", TransactionKind::Commit),
+        ("COMMIT WORK", TransactionKind::Commit),
+        ("ROLLBACK", TransactionKind::Rollback),
+        ("rollback work", TransactionKind::Rollback),
+    ] {
+        expect_parse_ok(sql, DfStatement::Transaction(DfTransaction { kind }))?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn resource_group_test() -> Result<()> {
+    {
+        let sql = "CREATE RESOURCE GROUP IF NOT EXISTS etl WITH (max_concurrent_queries = 8, users = 'etl')";
+        let mut options = HashMap::new();
+        options.insert("max_concurrent_queries".to_string(), "8".to_string());
+        options.insert("users".to_string(), "etl".to_string());
+        let expected = DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists: true,
+            name: Ident::new("etl"),
+            options,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "CREATE RESOURCE GROUP adhoc";
+        let expected = DfStatement::CreateResourceGroup(DfCreateResourceGroup {
+            if_not_exists: false,
+            name: Ident::new("adhoc"),
+            options: HashMap::new(),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP RESOURCE GROUP IF EXISTS etl";
+        let expected = DfStatement::DropResourceGroup(DfDropResourceGroup {
+            if_exists: true,
+            name: Ident::new("etl"),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
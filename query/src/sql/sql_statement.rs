@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_planners::ExplainType;
+use common_planners::TransactionKind;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_till1;
 use nom::character::complete::digit1;
@@ -23,6 +26,7 @@ use sqlparser::ast::ColumnDef;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
 use sqlparser::ast::SqlOption;
 use sqlparser::ast::Statement as SQLStatement;
 
@@ -45,6 +49,49 @@ pub struct DfShowSettings;
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfShowProcessList;
 
+/// The `LIKE 'pattern'` or `WHERE expr` of the MySQL `SHOW` statements.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfShowFilter {
+    Like(Expr),
+    Where(Expr),
+}
+
+/// `SHOW [SESSION | GLOBAL] VARIABLES [filter]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfShowVariables {
+    pub filter: Option<DfShowFilter>,
+}
+
+/// `SHOW FULL TABLES [{FROM | IN} db] [filter]`, the tables with their types.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfShowFullTables {
+    pub database: Option<ObjectName>,
+    pub filter: Option<DfShowFilter>,
+}
+
+/// `SHOW [FULL] {COLUMNS | FIELDS} {FROM | IN} table [{FROM | IN} db] [filter]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfShowColumns {
+    pub full: bool,
+    pub table: ObjectName,
+    pub database: Option<ObjectName>,
+    pub filter: Option<DfShowFilter>,
+}
+
+/// `SHOW {INDEX | INDEXES | KEYS} {FROM | IN} table [{FROM | IN} db]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfShowIndex {
+    pub table: ObjectName,
+    pub database: Option<ObjectName>,
+}
+
+/// `SET NAMES charset [COLLATE collation]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfSetNames {
+    pub charset: String,
+    pub collation: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfExplain {
     pub typ: ExplainType,
@@ -56,14 +103,33 @@ pub struct DfShowCreateTable {
     pub name: ObjectName,
 }
 
+/// `CREATE TABLE name (columns) [ENGINE = engine] [options] [AS query]`,
+/// or `CREATE TABLE name LIKE other [ENGINE = engine]`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
     pub if_not_exists: bool,
     /// Table name
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
-    pub engine: String,
+    /// None if the ENGINE is not specified
+    pub engine: Option<String>,
     pub options: Vec<SqlOption>,
+    /// The table whose schema, engine and options are cloned
+    pub like: Option<ObjectName>,
+    /// The schema is inferred from the query, and its rows are inserted into the table
+    pub query: Option<Box<Query>>,
+}
+
+/// `CREATE [MATERIALIZED] VIEW [IF NOT EXISTS] name [TO target] [ENGINE = engine] AS query`,
+/// `TO` and `ENGINE` are only for the materialized views.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateView {
+    pub if_not_exists: bool,
+    pub name: ObjectName,
+    pub materialized: bool,
+    pub to: Option<ObjectName>,
+    pub engine: Option<String>,
+    pub query: Box<Query>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -77,6 +143,22 @@ pub struct DfDropTable {
     pub name: ObjectName,
 }
 
+/// `ALTER TABLE name action`, `RENAME TABLE a TO b` is parsed as `ALTER TABLE a RENAME TO b`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    pub name: ObjectName,
+    pub action: DfAlterTableAction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterTableAction {
+    AddColumn(ColumnDef),
+    DropColumn(Ident),
+    RenameColumn { old: Ident, new: Ident },
+    ModifyColumn(ColumnDef),
+    RenameTable(ObjectName),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfTruncateTable {
     pub name: ObjectName,
@@ -101,11 +183,51 @@ pub struct DfUseDatabase {
     pub name: ObjectName,
 }
 
+/// `INSERT INTO table [(columns)] FORMAT format`, the data is streamed by the client.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfInsertFormat {
+    pub table_name: ObjectName,
+    pub columns: Vec<Ident>,
+    pub format: String,
+}
+
+/// `COPY INTO table [(columns)] FROM 'location' [PATTERN = 'regex'] [FILE_FORMAT = (...)]`,
+/// the keys of `file_format` are in lowercase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopy {
+    pub name: ObjectName,
+    pub columns: Vec<Ident>,
+    pub location: String,
+    pub pattern: Option<String>,
+    pub file_format: HashMap<String, String>,
+    pub validation_mode: Option<String>,
+    pub force: bool,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfKillStatement {
     pub object_id: Ident,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfTransaction {
+    pub kind: TransactionKind,
+}
+
+/// The keys of `options` are in lowercase.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateResourceGroup {
+    pub if_not_exists: bool,
+    pub name: Ident,
+    pub options: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDropResourceGroup {
+    pub if_exists: bool,
+    pub name: Ident,
+}
+
 /// Tokens parsed by `DFParser` are converted into these values.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DfStatement {
@@ -121,14 +243,23 @@ pub enum DfStatement {
 
     // Tables.
     ShowTables(DfShowTables),
+    ShowFullTables(DfShowFullTables),
+    ShowColumns(DfShowColumns),
+    ShowIndex(DfShowIndex),
     ShowCreateTable(DfShowCreateTable),
     CreateTable(DfCreateTable),
+    CreateView(DfCreateView),
     DescribeTable(DfDescribeTable),
     DropTable(DfDropTable),
+    AlterTable(DfAlterTable),
     TruncateTable(DfTruncateTable),
+    InsertFormat(DfInsertFormat),
+    Copy(DfCopy),
 
     // Settings.
     ShowSettings(DfShowSettings),
+    ShowVariables(DfShowVariables),
+    SetNames(DfSetNames),
 
     // ProcessList
     ShowProcessList(DfShowProcessList),
@@ -136,6 +267,13 @@ pub enum DfStatement {
     // Kill
     KillQuery(DfKillStatement),
     KillConn(DfKillStatement),
+
+    // Transaction
+    Transaction(DfTransaction),
+
+    // Resource groups
+    CreateResourceGroup(DfCreateResourceGroup),
+    DropResourceGroup(DfDropResourceGroup),
 }
 
 /// Comment hints from SQL.
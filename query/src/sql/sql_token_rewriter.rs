@@ -0,0 +1,346 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sqlparser::dialect::keywords::Keyword;
+use sqlparser::parser::ParserError;
+use sqlparser::tokenizer::Token;
+use sqlparser::tokenizer::Word;
+
+/// The sqlparser we depend on has no array syntax, so the tokens are rewritten into function
+/// calls before parsing:
+///   `[a, b]`          => `array(a, b)`
+///   `arr[i]`          => `arrayElement(arr, i)`
+///   `x -> body`       => `lambda(x, body)`
+///   `(x, y) -> body`  => `lambda(x, y, body)`
+///
+/// Nor `GROUPING SETS`, whose sets are rewritten into tuples:
+///   `GROUPING SETS ((a), (a, b), ())` => `groupingSets(tuple(a), tuple(a, b), tuple())`
+///
+/// Nor the MySQL scopes of `SET`, which are dropped as the settings are of the session:
+///   `SET GLOBAL x = 1`, `SET @@session.x = 1` => `SET x = 1`
+pub struct TokenRewriter;
+
+impl TokenRewriter {
+    pub fn rewrite(tokens: Vec<Token>) -> Result<Vec<Token>, ParserError> {
+        let mut tokens = tokens;
+        while let Some(rewritten) = Self::rewrite_once(&tokens)? {
+            tokens = rewritten;
+        }
+        Ok(tokens)
+    }
+
+    // Rewrite the leftmost lambda, bracket or grouping sets, None if there is nothing to rewrite.
+    fn rewrite_once(tokens: &[Token]) -> Result<Option<Vec<Token>>, ParserError> {
+        for index in 0..tokens.len() {
+            match &tokens[index] {
+                Token::Word(w) if Self::is_grouping_sets(tokens, index, w) => {
+                    return Self::rewrite_grouping_sets(tokens, index).map(Some);
+                }
+                Token::Word(w) if Self::is_scoped_set(tokens, index, w) => {
+                    return Ok(Some(Self::rewrite_scoped_set(tokens, index)));
+                }
+                Token::Minus if matches!(tokens.get(index + 1), Some(Token::Gt)) => {
+                    return Self::rewrite_lambda(tokens, index).map(Some);
+                }
+                Token::LBracket => return Self::rewrite_bracket(tokens, index).map(Some),
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    fn rewrite_lambda(tokens: &[Token], arrow: usize) -> Result<Vec<Token>, ParserError> {
+        let (start, params) = match Self::prev_token(tokens, arrow) {
+            Some(pos) => match &tokens[pos] {
+                Token::Word(_) => (pos, vec![tokens[pos].clone()]),
+                Token::RParen => {
+                    let start = Self::matching_open(tokens, pos)?;
+                    let params = tokens[start + 1..pos]
+                        .iter()
+                        .filter(|token| !matches!(token, Token::Whitespace(_) | Token::Comma))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    (start, params)
+                }
+                other => {
+                    return Err(ParserError::ParserError(format!(
+                        "Expected lambda parameters before ->, found: {}",
+                        other
+                    )))
+                }
+            },
+            None => {
+                return Err(ParserError::ParserError(
+                    "Expected lambda parameters before ->".to_string(),
+                ))
+            }
+        };
+
+        if params.is_empty() || params.iter().any(|param| !matches!(param, Token::Word(_))) {
+            return Err(ParserError::ParserError(
+                "Lambda parameters must be identifiers".to_string(),
+            ));
+        }
+
+        let end = Self::expression_end(tokens, arrow + 2);
+        if tokens[arrow + 2..end]
+            .iter()
+            .all(|token| matches!(token, Token::Whitespace(_)))
+        {
+            return Err(ParserError::ParserError(
+                "Expected lambda body after ->".to_string(),
+            ));
+        }
+
+        let mut rewritten = tokens[..start].to_vec();
+        rewritten.push(Token::make_word("lambda", None));
+        rewritten.push(Token::LParen);
+        for param in params {
+            rewritten.push(param);
+            rewritten.push(Token::Comma);
+        }
+        rewritten.extend_from_slice(&tokens[arrow + 2..end]);
+        rewritten.push(Token::RParen);
+        rewritten.extend_from_slice(&tokens[end..]);
+        Ok(rewritten)
+    }
+
+    fn rewrite_bracket(tokens: &[Token], open: usize) -> Result<Vec<Token>, ParserError> {
+        let close = Self::matching_close(tokens, open)?;
+        let operand_start = match Self::prev_token(tokens, open) {
+            Some(pos) if Self::is_operand_end(&tokens[pos]) => {
+                Some(Self::operand_start(tokens, pos)?)
+            }
+            _ => None,
+        };
+
+        let mut rewritten = vec![];
+        match operand_start {
+            // `[a, b]` => `array(a, b)`
+            None => {
+                rewritten.extend_from_slice(&tokens[..open]);
+                rewritten.push(Token::make_word("array", None));
+                rewritten.push(Token::LParen);
+                rewritten.extend_from_slice(&tokens[open + 1..close]);
+                rewritten.push(Token::RParen);
+            }
+            // `arr[i]` => `arrayElement(arr, i)`
+            Some(start) => {
+                rewritten.extend_from_slice(&tokens[..start]);
+                rewritten.push(Token::make_word("arrayElement", None));
+                rewritten.push(Token::LParen);
+                rewritten.extend_from_slice(&tokens[start..open]);
+                rewritten.push(Token::Comma);
+                rewritten.extend_from_slice(&tokens[open + 1..close]);
+                rewritten.push(Token::RParen);
+            }
+        }
+        rewritten.extend_from_slice(&tokens[close + 1..]);
+        Ok(rewritten)
+    }
+
+    fn is_grouping_sets(tokens: &[Token], index: usize, word: &Word) -> bool {
+        word.quote_style.is_none()
+            && word.value.eq_ignore_ascii_case("GROUPING")
+            && matches!(
+                Self::next_token(tokens, index).map(|pos| &tokens[pos]),
+                Some(Token::Word(w)) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case("SETS")
+            )
+    }
+
+    fn rewrite_grouping_sets(tokens: &[Token], grouping: usize) -> Result<Vec<Token>, ParserError> {
+        let open = Self::next_token(tokens, grouping)
+            .and_then(|sets| Self::next_token(tokens, sets))
+            .filter(|pos| tokens[*pos] == Token::LParen)
+            .ok_or_else(|| {
+                ParserError::ParserError("Expected ( after GROUPING SETS".to_string())
+            })?;
+        let close = Self::matching_close(tokens, open)?;
+
+        let mut rewritten = tokens[..grouping].to_vec();
+        rewritten.push(Token::make_word("groupingSets", None));
+        rewritten.push(Token::LParen);
+        let mut depth = 0;
+        let mut set_start = true;
+        for token in &tokens[open + 1..close] {
+            match token {
+                Token::LParen if depth == 0 && set_start => {
+                    rewritten.push(Token::make_word("tuple", None));
+                    depth += 1;
+                }
+                Token::LParen | Token::LBracket => depth += 1,
+                Token::RParen | Token::RBracket => depth -= 1,
+                _ => {}
+            }
+            match token {
+                Token::Whitespace(_) => {}
+                Token::Comma if depth == 0 => set_start = true,
+                _ => set_start = false,
+            }
+            rewritten.push(token.clone());
+        }
+        rewritten.push(Token::RParen);
+        rewritten.extend_from_slice(&tokens[close + 1..]);
+        Ok(rewritten)
+    }
+
+    fn is_scoped_set(tokens: &[Token], index: usize, word: &Word) -> bool {
+        let statement_start = match Self::prev_token(tokens, index) {
+            None => true,
+            Some(pos) => tokens[pos] == Token::SemiColon,
+        };
+        statement_start
+            && word.keyword == Keyword::SET
+            && matches!(
+                Self::next_token(tokens, index).map(|pos| &tokens[pos]),
+                Some(Token::Word(w)) if w.quote_style.is_none()
+                    && (Self::is_scope(&w.value) || w.value.starts_with("@@"))
+            )
+    }
+
+    fn is_scope(value: &str) -> bool {
+        ["SESSION", "GLOBAL", "LOCAL"]
+            .iter()
+            .any(|scope| value.eq_ignore_ascii_case(scope))
+    }
+
+    // `SET SESSION x`, `SET @@session.x` and `SET @@x` => `SET x`
+    fn rewrite_scoped_set(tokens: &[Token], set: usize) -> Vec<Token> {
+        let mut rewritten = tokens.to_vec();
+        if let Some(pos) = Self::next_token(tokens, set) {
+            if let Token::Word(w) = &tokens[pos] {
+                let value = w.value.trim_start_matches('@');
+                let period = Self::next_token(tokens, pos).filter(|p| tokens[*p] == Token::Period);
+                match period {
+                    _ if !w.value.starts_with("@@") => {
+                        rewritten.remove(pos);
+                    }
+                    Some(period) if Self::is_scope(value) => {
+                        rewritten.drain(pos..=period);
+                    }
+                    _ => rewritten[pos] = Token::make_word(value, None),
+                }
+            }
+        }
+        rewritten
+    }
+
+    // A subscript follows an identifier, a parenthesized expression or a function call.
+    fn is_operand_end(token: &Token) -> bool {
+        match token {
+            Token::Word(w) => w.quote_style.is_some() || w.keyword == Keyword::NoKeyword,
+            Token::RParen => true,
+            _ => false,
+        }
+    }
+
+    fn operand_start(tokens: &[Token], end: usize) -> Result<usize, ParserError> {
+        let mut start = end;
+        if let Token::RParen = tokens[end] {
+            start = Self::matching_open(tokens, end)?;
+            // The function name of a call.
+            if let Some(pos) = Self::prev_token(tokens, start) {
+                if let Token::Word(w) = &tokens[pos] {
+                    if w.keyword == Keyword::NoKeyword {
+                        start = pos;
+                    }
+                }
+            }
+            return Ok(start);
+        }
+
+        // Compound identifier such as `t.arr`.
+        while let Some(period) = Self::prev_token(tokens, start) {
+            match (&tokens[period], Self::prev_token(tokens, period)) {
+                (Token::Period, Some(pos)) if matches!(tokens[pos], Token::Word(_)) => start = pos,
+                _ => break,
+            }
+        }
+        Ok(start)
+    }
+
+    // The end of the expression starting at `start`: the first comma, closing paren or bracket
+    // at the same level, or the end of the statement.
+    fn expression_end(tokens: &[Token], start: usize) -> usize {
+        let mut depth = 0;
+        for (index, token) in tokens.iter().enumerate().skip(start) {
+            match token {
+                Token::LParen | Token::LBracket => depth += 1,
+                Token::RParen | Token::RBracket if depth == 0 => return index,
+                Token::RParen | Token::RBracket => depth -= 1,
+                Token::Comma if depth == 0 => return index,
+                Token::SemiColon | Token::EOF => return index,
+                _ => {}
+            }
+        }
+        tokens.len()
+    }
+
+    fn next_token(tokens: &[Token], index: usize) -> Option<usize> {
+        (index + 1..tokens.len()).find(|pos| !matches!(tokens[*pos], Token::Whitespace(_)))
+    }
+
+    fn prev_token(tokens: &[Token], index: usize) -> Option<usize> {
+        (0..index)
+            .rev()
+            .find(|pos| !matches!(tokens[*pos], Token::Whitespace(_)))
+    }
+
+    fn matching_open(tokens: &[Token], close: usize) -> Result<usize, ParserError> {
+        let mut depth = 0;
+        for index in (0..close).rev() {
+            match tokens[index] {
+                Token::RParen | Token::RBracket => depth += 1,
+                Token::LParen | Token::LBracket if depth > 0 => depth -= 1,
+                Token::LParen | Token::LBracket
+                    if Self::is_pair(&tokens[index], &tokens[close]) =>
+                {
+                    return Ok(index)
+                }
+                Token::LParen | Token::LBracket => break,
+                _ => {}
+            }
+        }
+        Err(ParserError::ParserError(format!(
+            "Unmatched {}",
+            tokens[close]
+        )))
+    }
+
+    fn matching_close(tokens: &[Token], open: usize) -> Result<usize, ParserError> {
+        let mut depth = 0;
+        for (index, token) in tokens.iter().enumerate().skip(open + 1) {
+            match token {
+                Token::LParen | Token::LBracket => depth += 1,
+                Token::RParen | Token::RBracket if depth > 0 => depth -= 1,
+                Token::RParen | Token::RBracket if Self::is_pair(&tokens[open], token) => {
+                    return Ok(index)
+                }
+                Token::RParen | Token::RBracket => break,
+                _ => {}
+            }
+        }
+        Err(ParserError::ParserError(format!(
+            "Unmatched {}",
+            tokens[open]
+        )))
+    }
+
+    fn is_pair(open: &Token, close: &Token) -> bool {
+        matches!(
+            (open, close),
+            (Token::LParen, Token::RParen) | (Token::LBracket, Token::RBracket)
+        )
+    }
+}
@@ -53,6 +53,18 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn query_log_table(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config.clone();
+        new_config.query.query_log_table = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
+    pub fn query_log_flush_interval_ms(self, value: u64) -> SessionManagerBuilder {
+        let mut new_config = self.config.clone();
+        new_config.query.query_log_flush_interval_ms = value;
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn rpc_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config.clone();
         new_config.query.rpc_tls_server_key = value.into();
@@ -83,6 +95,27 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn handler_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config.clone();
+        new_config.query.handler_tls_server_key = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
+    pub fn handler_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config.clone();
+        new_config.query.handler_tls_server_cert = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
+    pub fn handler_tls_server_root_ca_cert(
+        self,
+        value: impl Into<String>,
+    ) -> SessionManagerBuilder {
+        let mut new_config = self.config.clone();
+        new_config.query.handler_tls_server_root_ca_cert = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn log_dir_with_relative(self, path: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config.clone();
         new_config.log.log_dir = env::current_dir()
@@ -94,10 +94,13 @@ impl StoreFlightImpl {
 #[async_trait::async_trait]
 impl FlightService for StoreFlightImpl {
     type HandshakeStream = FlightStream<HandshakeResponse>;
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn handshake(
         &self,
         request: Request<Streaming<HandshakeRequest>>,
     ) -> Result<Response<Self::HandshakeStream>, Status> {
+        common_tracing::extract_remote_span_as_parent(&request);
+
         let req = request
             .into_inner()
             .next()
@@ -156,6 +159,7 @@ impl FlightService for StoreFlightImpl {
 
     type DoGetStream =
         Pin<Box<dyn Stream<Item = Result<FlightData, tonic::Status>> + Send + Sync + 'static>>;
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_get(
         &self,
         request: Request<Ticket>,
@@ -163,6 +167,8 @@ impl FlightService for StoreFlightImpl {
         // Check token.
         let _claim = self.check_token(request.metadata())?;
 
+        common_tracing::extract_remote_span_as_parent(&request);
+
         // Action.
         let action: StoreDoGet = request.try_into()?;
         match action {
@@ -191,11 +197,15 @@ impl FlightService for StoreFlightImpl {
     }
 
     type DoPutStream = FlightStream<PutResult>;
+
+    #[tracing::instrument(level = "debug", skip(self, request))]
     async fn do_put(
         &self,
         request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
         let _claim = self.check_token(request.metadata())?;
+
+        common_tracing::extract_remote_span_as_parent(&request);
         let meta = request.metadata();
 
         let (db_name, tbl_name) =
@@ -91,6 +91,7 @@ async fn test_flight_restart() -> anyhow::Result<()> {
             table: table_name.to_string(),
             schema: schema.clone(),
             options: options.clone(),
+            as_select: None,
             engine: "JSON".to_string(),
         };
 
@@ -285,6 +286,7 @@ async fn test_flight_create_get_table() -> anyhow::Result<()> {
             table: tbl_name.to_string(),
             schema: schema.clone(),
             options: options.clone(),
+            as_select: None,
             engine: "JSON".to_string(),
         };
 
@@ -415,6 +417,7 @@ async fn test_flight_drop_table() -> anyhow::Result<()> {
             table: tbl_name.to_string(),
             schema: schema.clone(),
             options: options.clone(),
+            as_select: None,
             engine: "JSON".to_string(),
         };
 
@@ -512,6 +515,7 @@ async fn test_do_append() -> anyhow::Result<()> {
             table: tbl_name.to_string(),
             schema: schema.clone(),
             options: maplit::hashmap! {"opt‐1".into() => "val-1".into()},
+            as_select: None,
             engine: "PARQUET".to_string(),
         };
         client.create_table(plan.clone()).await.unwrap();
@@ -586,6 +590,7 @@ async fn test_scan_partition() -> anyhow::Result<()> {
             table: tbl_name.to_string(),
             schema: schema.clone(),
             options: maplit::hashmap! {"opt‐1".into() => "val-1".into()},
+            as_select: None,
             engine: "PARQUET".to_string(),
         };
         client.create_table(plan.clone()).await?;
@@ -1300,6 +1305,7 @@ async fn test_flight_get_database_meta_ddl_table() -> anyhow::Result<()> {
         table: "tbl1".to_string(),
         schema: schema.clone(),
         options: Default::default(),
+        as_select: None,
         engine: "JSON".to_string(),
     };
 
@@ -103,6 +103,8 @@ impl ActionHandler {
             // table
             StoreDoAction::CreateTable(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::DropTable(a) => s.serialize(self.handle(a).await?),
+            StoreDoAction::AlterTable(a) => s.serialize(self.handle(a).await?),
+            StoreDoAction::UpdateTables(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::GetTable(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::GetTableExt(a) => s.serialize(self.handle(a).await?),
             StoreDoAction::TruncateTable(a) => s.serialize(self.handle(a).await?),
@@ -24,16 +24,21 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_meta_api_vo::*;
+use common_metatypes::TableChange;
+use common_planners::AlterTableAction as AlterAction;
+use common_planners::AlterTablePlan;
 use common_planners::CreateDatabasePlan;
 use common_planners::CreateTablePlan;
 use common_planners::DropDatabasePlan;
 use common_planners::DropTablePlan;
+use common_store_api_sdk::meta_api_impl::AlterTableAction;
 use common_store_api_sdk::meta_api_impl::CreateDatabaseAction;
 use common_store_api_sdk::meta_api_impl::CreateTableAction;
 use common_store_api_sdk::meta_api_impl::DropDatabaseAction;
 use common_store_api_sdk::meta_api_impl::DropTableAction;
 use common_store_api_sdk::meta_api_impl::GetDatabaseAction;
 use common_store_api_sdk::meta_api_impl::GetTableAction;
+use common_store_api_sdk::meta_api_impl::UpdateTablesAction;
 use common_store_api_sdk::storage_api_impl::AppendResult;
 use common_store_api_sdk::storage_api_impl::TruncateTableAction;
 use common_store_api_sdk::storage_api_impl::TruncateTableResult;
@@ -358,6 +363,7 @@ async fn test_action_handler_create_table() -> anyhow::Result<()> {
             schema,
             engine: "JSON".to_string(),
             options: Default::default(),
+            as_select: None,
         };
         let want = match want {
             Ok(want_table_id) => Ok(CreateTableActionResult {
@@ -502,6 +508,7 @@ async fn test_action_handler_get_table() -> anyhow::Result<()> {
                 schema: schema.clone(),
                 engine: "JSON".to_string(),
                 options: Default::default(),
+                as_select: None,
             };
             let cta = CreateTableAction { plan };
             hdlr.handle(cta).await?;
@@ -611,6 +618,7 @@ async fn test_action_handler_drop_table() -> anyhow::Result<()> {
                 schema: schema.clone(),
                 engine: "JSON".to_string(),
                 options: Default::default(),
+                as_select: None,
             };
             let cta = CreateTableAction { plan };
             hdlr.handle(cta).await?;
@@ -649,6 +657,244 @@ async fn test_action_handler_drop_table() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_action_handler_alter_table() -> anyhow::Result<()> {
+    // - Bring up an ActionHandler backed with a Dfs
+    // - Add two tables.
+    // - Assert altering the schema and renaming the table.
+
+    let (_log_guards, ut_span) = init_store_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, hdlr) = bring_up_dfs_action_handler(hashmap! {}).await?;
+
+    {
+        // create db
+        let plan = CreateDatabasePlan {
+            db: "foo".to_string(),
+            if_not_exists: false,
+            engine: "Local".to_string(),
+            options: Default::default(),
+        };
+        let cba = CreateDatabaseAction { plan };
+        hdlr.handle(cba).await?;
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "number",
+        DataType::UInt64,
+        false,
+    )]));
+
+    for table in ["foo_t1", "foo_t2"] {
+        // create table
+        let plan = CreateTablePlan {
+            if_not_exists: false,
+            db: "foo".to_string(),
+            table: table.to_string(),
+            schema: schema.clone(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            as_select: None,
+        };
+        let cta = CreateTableAction { plan };
+        hdlr.handle(cta).await?;
+    }
+
+    let altered_schema = Arc::new(DataSchema::new(vec![
+        DataField::new("number", DataType::UInt64, false),
+        DataField::new("name", DataType::String, true),
+    ]));
+    let alter = |table: &str, action: AlterAction| AlterTableAction {
+        plan: AlterTablePlan {
+            db: "foo".to_string(),
+            table: table.to_string(),
+            action,
+            table_schema: altered_schema.clone(),
+        },
+    };
+    let rename = |db: &str, table: &str| AlterAction::RenameTable {
+        new_db: db.to_string(),
+        new_table: table.to_string(),
+    };
+
+    {
+        // alter the schema
+        let action = AlterAction::AddColumn {
+            field: DataField::new("name", DataType::String, true),
+            default: None,
+        };
+        let rst = hdlr.handle(alter("foo_t1", action)).await?;
+        assert_eq!(AlterTableActionResult {}, rst);
+
+        let got = hdlr
+            .handle(GetTableAction {
+                db: "foo".to_string(),
+                table: "foo_t1".to_string(),
+            })
+            .await?;
+        assert_eq!(altered_schema, got.schema);
+    }
+
+    {
+        // rename to an existing table
+        let rst = hdlr.handle(alter("foo_t1", rename("foo", "foo_t2"))).await;
+        let got: ErrorCode = rst.unwrap_err();
+        assert_eq!(ErrorCode::TableAlreadyExists("").code(), got.code());
+        assert_eq!("table exists: foo_t2", got.message());
+    }
+
+    {
+        // rename to an absent database
+        let rst = hdlr.handle(alter("foo_t1", rename("bar", "foo_t1"))).await;
+        let got: ErrorCode = rst.unwrap_err();
+        assert_eq!(ErrorCode::UnknownDatabase("").code(), got.code());
+        assert_eq!("database not found: bar", got.message());
+    }
+
+    {
+        // rename
+        hdlr.handle(alter("foo_t1", rename("foo", "foo_t3")))
+            .await?;
+
+        let got = hdlr
+            .handle(GetTableAction {
+                db: "foo".to_string(),
+                table: "foo_t3".to_string(),
+            })
+            .await?;
+        assert_eq!("foo_t3", got.name);
+        assert_eq!(altered_schema, got.schema);
+
+        let rst = hdlr
+            .handle(GetTableAction {
+                db: "foo".to_string(),
+                table: "foo_t1".to_string(),
+            })
+            .await;
+        assert_eq!("table not found: foo_t1", rst.unwrap_err().message());
+    }
+
+    {
+        // alter an absent table
+        let rst = hdlr.handle(alter("foo_t1", rename("foo", "foo_t4"))).await;
+        let got: ErrorCode = rst.unwrap_err();
+        assert_eq!(ErrorCode::UnknownTable("").code(), got.code());
+        assert_eq!("table not found: foo_t1", got.message());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_action_handler_update_tables() -> anyhow::Result<()> {
+    // - Bring up an ActionHandler backed with a Dfs
+    // - Add two tables.
+    // - Assert a batch of changes is applied all or nothing.
+
+    let (_log_guards, ut_span) = init_store_ut!();
+    let _ent = ut_span.enter();
+
+    let (_tc, hdlr) = bring_up_dfs_action_handler(hashmap! {}).await?;
+
+    {
+        // create db
+        let plan = CreateDatabasePlan {
+            db: "foo".to_string(),
+            if_not_exists: false,
+            engine: "Local".to_string(),
+            options: Default::default(),
+        };
+        let cba = CreateDatabaseAction { plan };
+        hdlr.handle(cba).await?;
+    }
+
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "number",
+        DataType::UInt64,
+        false,
+    )]));
+
+    for table in ["foo_t1", "foo_t2"] {
+        // create table
+        let plan = CreateTablePlan {
+            if_not_exists: false,
+            db: "foo".to_string(),
+            table: table.to_string(),
+            schema: schema.clone(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            as_select: None,
+        };
+        let cta = CreateTableAction { plan };
+        hdlr.handle(cta).await?;
+    }
+
+    let set_option = |value: Option<&str>| TableChange::SetOption {
+        db_name: "foo".to_string(),
+        table_name: "foo_t1".to_string(),
+        key: "k".to_string(),
+        value: value.map(|v| v.to_string()),
+    };
+    let drop_t2 = || TableChange::Drop {
+        db_name: "foo".to_string(),
+        table_name: "foo_t2".to_string(),
+    };
+    let get_table = |table: &str| GetTableAction {
+        db: "foo".to_string(),
+        table: table.to_string(),
+    };
+
+    {
+        // set an option and drop a table at once
+        let changes = vec![set_option(Some("v")), drop_t2()];
+        let rst = hdlr.handle(UpdateTablesAction { changes }).await?;
+        assert_eq!(UpdateTablesActionResult {}, rst);
+
+        let got = hdlr.handle(get_table("foo_t1")).await?;
+        assert_eq!(Some(&"v".to_string()), got.options.get("k"));
+
+        let rst = hdlr.handle(get_table("foo_t2")).await;
+        assert_eq!("table not found: foo_t2", rst.unwrap_err().message());
+    }
+
+    {
+        // a failed change discards the whole batch
+        let changes = vec![set_option(None), drop_t2()];
+        let rst = hdlr.handle(UpdateTablesAction { changes }).await;
+        let got: ErrorCode = rst.unwrap_err();
+        assert_eq!(ErrorCode::UnknownTable("").code(), got.code());
+        assert_eq!("table not found: foo.foo_t2", got.message());
+
+        let got = hdlr.handle(get_table("foo_t1")).await?;
+        assert_eq!(Some(&"v".to_string()), got.options.get("k"));
+    }
+
+    {
+        // an option is not set if it has been changed
+        let compare_and_set = |expected: &str| TableChange::CompareAndSetOption {
+            db_name: "foo".to_string(),
+            table_name: "foo_t1".to_string(),
+            key: "k".to_string(),
+            expected: Some(expected.to_string()),
+            value: "w".to_string(),
+        };
+        let changes = vec![compare_and_set("v")];
+        hdlr.handle(UpdateTablesAction { changes }).await?;
+
+        let changes = vec![compare_and_set("v")];
+        let rst = hdlr.handle(UpdateTablesAction { changes }).await;
+        let got: ErrorCode = rst.unwrap_err();
+        assert_eq!(ErrorCode::CommitTableConflict("").code(), got.code());
+        assert_eq!("table changed: foo.foo_t1", got.message());
+
+        let got = hdlr.handle(get_table("foo_t1")).await?;
+        assert_eq!(Some(&"w".to_string()), got.options.get("k"));
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_action_handler_truncate_table() -> anyhow::Result<()> {
     // - Bring up an ActionHandler backed with a Dfs
@@ -715,6 +961,7 @@ async fn test_action_handler_truncate_table() -> anyhow::Result<()> {
                 schema: schema.clone(),
                 engine: "JSON".to_string(),
                 options: Default::default(),
+                as_select: None,
             };
             let cta = CreateTableAction { plan };
             hdlr.handle(cta).await?;
@@ -23,14 +23,18 @@ use common_arrow::arrow_flight::utils::flight_data_from_arrow_schema;
 use common_arrow::arrow_flight::FlightData;
 use common_exception::ErrorCode;
 use common_meta_api_vo::*;
+use common_metatypes::Cmd::AlterTable;
 use common_metatypes::Cmd::CreateDatabase;
 use common_metatypes::Cmd::CreateTable;
 use common_metatypes::Cmd::DropDatabase;
 use common_metatypes::Cmd::DropTable;
+use common_metatypes::Cmd::UpdateTables;
 use common_metatypes::Database;
 use common_metatypes::LogEntry;
 use common_metatypes::Table;
+use common_metatypes::TableChangeError;
 use common_raft_store::state_machine::AppliedState;
+use common_store_api_sdk::meta_api_impl::AlterTableAction;
 use common_store_api_sdk::meta_api_impl::CreateDatabaseAction;
 use common_store_api_sdk::meta_api_impl::CreateTableAction;
 use common_store_api_sdk::meta_api_impl::DropDatabaseAction;
@@ -39,6 +43,7 @@ use common_store_api_sdk::meta_api_impl::GetDatabaseAction;
 use common_store_api_sdk::meta_api_impl::GetDatabaseMetaAction;
 use common_store_api_sdk::meta_api_impl::GetTableAction;
 use common_store_api_sdk::meta_api_impl::GetTableExtReq;
+use common_store_api_sdk::meta_api_impl::UpdateTablesAction;
 use log::info;
 
 use crate::executor::action_handler::RequestHandler;
@@ -265,6 +270,98 @@ impl RequestHandler<DropTableAction> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<AlterTableAction> for ActionHandler {
+    async fn handle(
+        &self,
+        act: AlterTableAction,
+    ) -> common_exception::Result<AlterTableActionResult> {
+        let plan = act.plan;
+        let (new_db_name, new_table_name) = plan.new_name();
+
+        if self.meta_node.get_database(&new_db_name).await.is_none() {
+            return Err(ErrorCode::UnknownDatabase(format!(
+                "database not found: {:}",
+                new_db_name
+            )));
+        }
+
+        let options = IpcWriteOptions::default();
+        let flight_data = flight_data_from_arrow_schema(&plan.table_schema.to_arrow(), &options);
+
+        let cr = LogEntry {
+            txid: None,
+            cmd: AlterTable {
+                db_name: plan.db.clone(),
+                table_name: plan.table.clone(),
+                new_db_name,
+                new_table_name: new_table_name.clone(),
+                schema: flight_data.data_header,
+            },
+        };
+
+        let rst = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        match rst {
+            AppliedState::Table { prev: None, .. } => Err(ErrorCode::UnknownTable(format!(
+                "table not found: {:}",
+                plan.table
+            ))),
+            AppliedState::Table { result: None, .. } => Err(ErrorCode::TableAlreadyExists(
+                format!("table exists: {}", new_table_name),
+            )),
+            AppliedState::Table { .. } => Ok(AlterTableActionResult {}),
+            _ => Err(ErrorCode::MetaNodeInternalError("not a Table result")),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTablesAction> for ActionHandler {
+    async fn handle(
+        &self,
+        act: UpdateTablesAction,
+    ) -> common_exception::Result<UpdateTablesActionResult> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTables {
+                changes: act.changes,
+            },
+        };
+
+        let rst = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        match rst {
+            AppliedState::TableChanges { error: None } => Ok(UpdateTablesActionResult {}),
+            AppliedState::TableChanges { error: Some(error) } => Err(match error {
+                TableChangeError::UnknownDatabase(db) => {
+                    ErrorCode::UnknownDatabase(format!("database not found: {:}", db))
+                }
+                TableChangeError::UnknownTable(table) => {
+                    ErrorCode::UnknownTable(format!("table not found: {:}", table))
+                }
+                TableChangeError::TableAlreadyExists(table) => {
+                    ErrorCode::TableAlreadyExists(format!("table exists: {}", table))
+                }
+                TableChangeError::Conflict(table) => {
+                    ErrorCode::CommitTableConflict(format!("table changed: {}", table))
+                }
+            }),
+            _ => Err(ErrorCode::MetaNodeInternalError(
+                "not a TableChanges result",
+            )),
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableAction> for ActionHandler {
     async fn handle(&self, act: GetTableAction) -> common_exception::Result<GetTableActionResult> {
@@ -0,0 +1,10 @@
+SELECT abs(-1), abs(toInt8(-128)), abs(2.5), sign(-3), sign(0), sign(4.2);
+SELECT round(3.14159, 2), floor(1.5), ceil(1.2), truncate(-1.7), round(1234, -2);
+SELECT sqrt(4), cbrt(27), pow(2, 10), exp(0), ln(1), log(2, 8), log2(8), log10(100);
+SELECT sin(0), cos(0), degrees(0), radians(0), pi();
+SELECT greatest(1, 5, 3), least(1, 5, 3), greatest(number, 1) FROM numbers(3) ORDER BY number;
+SELECT intDiv(7, 2), intDiv(-7, 2), intDiv(number, 2) FROM numbers(3) ORDER BY number;
+SELECT bitAnd(12, 10), bitOr(12, 10), bitXor(12, 10), bitShiftLeft(1, 10), bitShiftRight(1024, 3);
+SELECT toTypeName(abs(toInt32(-1))), toTypeName(sqrt(1)), toTypeName(intDiv(toUInt8(1), toUInt8(1))), toTypeName(rand());
+SELECT count(distinct rand()) > 1 FROM numbers(100);
+SELECT intDiv(1, 0); -- {ErrorCode 6}
@@ -0,0 +1,11 @@
+SELECT var_pop(number), var_samp(number), stddev_samp(number) FROM numbers(5);
+SELECT covar_pop(number, number * 2), covar_samp(number, number * 2), corr(number, number * 2) FROM numbers(10);
+SELECT quantile(0.9)(number), median(number), medianTDigest(number) FROM numbers(11);
+SELECT approx_count_distinct(number % 10), uniqExact(number % 10) FROM numbers(100);
+SELECT any(number), anyLast(number) FROM numbers(1);
+SELECT number % 2 AS k, var_pop(number), median(number) FROM numbers(10) GROUP BY k ORDER BY k;
+
+-- empty input
+SELECT var_samp(number), quantile(number), approx_count_distinct(number) FROM numbers(10) WHERE 1 = 2;
+
+SELECT quantile(2)(number) FROM numbers(10); -- {ErrorCode 6}
@@ -0,0 +1,37 @@
+select '===extract===';
+select toHour(toDateTime(1630833797)), toMinute(toDateTime(1630833797)), toSecond(toDateTime(1630833797));
+select toDayOfWeek(toDateTime(1630833797)), toQuarter(toDateTime(1630833797)); -- 2021-09-05 is a Sunday
+select toHour(toTimeZone(toDateTime(1630833797), 'Asia/Shanghai'));
+select toTimeZone(toDateTime(1630833797), 'Asia/Shanghai');
+select '===extract===';
+
+select '===format===';
+select formatDateTime(toDateTime(1630833797), '%Y-%m-%d %H:%M:%S');
+select date_format(toDateTime(1630833797), '%Y/%c/%e %H:%i:%s');
+select formatDateTime(toDate(18875), '%d/%m/%Y');
+select '===format===';
+
+select '===parse===';
+select parseDateTime('2021-09-05 09:23:17', '%Y-%m-%d %H:%M:%S');
+select str_to_date('05/09/2021', '%d/%m/%Y');
+select str_to_date('not a date', '%d/%m/%Y');
+select '===parse===';
+
+select '===unix===';
+select toUnixTimestamp(toDateTime(1630833797)), fromUnixTimestamp(1630833797);
+select '===unix===';
+
+select '===dateDiff===';
+select dateDiff('day', toDateTime(1630800000), toDateTime(1633046400)), dateDiff('month', toDateTime(1630800000), toDateTime(1633046400));
+select dateDiff('hour', toDate(18875), toDate(18876));
+select '===dateDiff===';
+
+select '===timezone===';
+set timezone = 'Asia/Shanghai';
+select toDateTime(1630833797);
+select toHour(toDateTime(1630833797)), formatDateTime(toDateTime(1630833797), '%Y-%m-%d %H:%M:%S');
+select toUnixTimestamp(parseDateTime('2021-09-05 17:23:17', '%Y-%m-%d %H:%M:%S'));
+select toStartOfDay(toDateTime(1630833797)), toStartOfHour(toDateTime(1630833797)), toStartOfWeek(toDateTime(1630800000));
+set timezone = 'UTC';
+select toDateTime(1630833797);
+select '===timezone===';
@@ -0,0 +1,26 @@
+select '===cast===';
+select toDateTime64('2021-09-05 09:23:17.123456');
+select toDateTime64('2021-09-05 09:23:17.123456', 6);
+select toDateTime64('2021-09-05 09:23:17.5', 1), toDateTime64(1630833797, 0);
+select toDateTime64('2021-09-05 09:23:17.123', 3, 'Asia/Shanghai');
+select toDateTime32(toDateTime64('2021-09-05 09:23:17.999')), toDate(toDateTime64('2021-09-05 09:23:17.999'));
+select toString(toDateTime64('2021-09-05 09:23:17.120', 2));
+select toTypeName(toDateTime64('2021-09-05 09:23:17', 6, 'UTC'));
+select '===cast===';
+
+select '===extract===';
+select toHour(toDateTime64('2021-09-05 09:23:17.123')), toSecond(toDateTime64('2021-09-05 09:23:17.123'));
+select toStartOfMinute(toDateTime64('2021-09-05 09:23:17.123'));
+select '===extract===';
+
+select '===arithmetic===';
+select toDateTime64('2021-09-05 09:23:17.123') + interval '1' day;
+select addMonths(toDateTime64('2021-09-05 09:23:17.123'), 1);
+select addSeconds(toDateTime64('2021-09-05 09:23:17.123'), -3);
+select '===arithmetic===';
+
+select '===compare===';
+select toDateTime64('2021-09-05 09:23:17.123') > toDateTime(1630833797);
+select toDateTime64('2021-09-05 09:23:17.123') = '2021-09-05 09:23:17.123';
+select toDateTime64('2021-09-05 09:23:17.123', 3) = toDateTime64('2021-09-05 09:23:17.123', 6);
+select '===compare===';
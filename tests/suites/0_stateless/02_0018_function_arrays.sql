@@ -0,0 +1,21 @@
+select '===constructor===';
+select [1, 2, 3], array('a', 'b');
+select length([1, 2, 3]), length(array(1));
+select '===constructor===';
+
+select '===access===';
+select [10, 20, 30][2], [10, 20, 30][-1], [10, 20, 30][4];
+select has([1, 2, 3], 2), has([1, 2, 3], 4), indexOf(['a', 'b'], 'b'), indexOf(['a', 'b'], 'c');
+select '===access===';
+
+select '===transform===';
+select arrayConcat([1, 2], [3]), arraySlice([1, 2, 3, 4], 2, 2);
+select arrayDistinct([1, 1, 2, 3, 2]), arraySort([3, 1, 2]);
+select arrayMap(x -> x * 2, [1, 2, 3]), arrayFilter(x -> x > 1, [1, 2, 3]);
+select arrayMap((x, y) -> x + y, [1, 2], [10, 20]);
+select '===transform===';
+
+select '===array join===';
+select arrayJoin([1, 2, 3]) as n;
+select number, unnest([number, number * 10]) as v from numbers(2) order by number, v;
+select '===array join===';
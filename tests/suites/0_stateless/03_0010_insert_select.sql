@@ -0,0 +1,14 @@
+DROP DATABASE IF EXISTS db1;
+CREATE DATABASE db1;
+USE db1;
+
+CREATE TABLE IF NOT EXISTS t1(a UInt32, b UInt64, c String) Engine = Memory;
+CREATE TABLE IF NOT EXISTS t2(a Int64, b String) Engine = Memory;
+
+INSERT INTO t1 SELECT number, number * 2, toString(number) FROM numbers(5) WHERE number > 1;
+SELECT * FROM t1 ORDER BY a;
+
+INSERT INTO t2 SELECT b, c FROM t1 WHERE a < 4;
+SELECT * FROM t2 ORDER BY a;
+
+DROP DATABASE db1;
@@ -0,0 +1,13 @@
+WITH t AS (SELECT number FROM numbers(5) WHERE number > 1) SELECT * FROM t ORDER BY number;
+WITH t AS (SELECT number % 3 AS k, number FROM numbers(10)), s AS (SELECT k, sum(number) AS total FROM t GROUP BY k) SELECT k, total FROM s ORDER BY k;
+WITH t(a) AS (SELECT number FROM numbers(3)) SELECT a FROM t WHERE a IN (SELECT a FROM t WHERE a > 0) ORDER BY a;
+WITH t(a, b) AS (SELECT number FROM numbers(3)) SELECT a FROM t; -- {ErrorCode 6}
+
+SET enable_cte_materialization = 1;
+WITH t AS (SELECT number FROM numbers(3)) SELECT number FROM t WHERE number IN (SELECT number + 1 FROM t) ORDER BY number;
+SET enable_cte_materialization = 0;
+
+WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 5) SELECT n FROM t ORDER BY n;
+WITH RECURSIVE t AS (SELECT number AS n FROM numbers(2) UNION ALL SELECT n + 2 FROM t WHERE n < 6) SELECT n FROM t ORDER BY n;
+SET max_recursive_cte_iterations = 10;
+WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t) SELECT n FROM t; -- {ErrorCode 55}
@@ -0,0 +1,9 @@
+SELECT number FROM numbers(3) UNION ALL SELECT number + 10 FROM numbers(2) ORDER BY number;
+SELECT toUInt8(number) AS a FROM numbers(2) UNION ALL SELECT toInt64(number) - 5 FROM numbers(2) ORDER BY a;
+SELECT number % 3 AS a FROM numbers(10) UNION SELECT number FROM numbers(5) ORDER BY a;
+SELECT number % 3 AS a FROM numbers(10) UNION DISTINCT SELECT 5 ORDER BY a DESC LIMIT 2;
+SELECT number AS a FROM numbers(10) INTERSECT SELECT number * 2 FROM numbers(10) ORDER BY a;
+SELECT number AS a FROM numbers(10) EXCEPT SELECT number * 2 FROM numbers(10) ORDER BY a;
+SELECT count(*) FROM (SELECT number FROM numbers(1000) UNION ALL SELECT number FROM numbers(24));
+SELECT number FROM numbers(3) UNION ALL SELECT number, number FROM numbers(3); -- {ErrorCode 6}
+SELECT number FROM numbers(3) EXCEPT ALL SELECT number FROM numbers(3); -- {ErrorCode 2}